@@ -3,7 +3,7 @@
 // Shows: click, fill, check, select, file upload
 // Note: Uses Google search to demonstrate real interactions
 
-use playwright_rs::Playwright;
+use playwright_rs::prelude::*;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -15,7 +15,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     page.goto("https://www.google.com", None).await?;
 
     // Find search input and interact
-    let search = page.locator("textarea[name=q]").await;
+    let search = page.locator("textarea[name=q]");
 
     // Click to focus
     search.click(None).await?;
@@ -2,7 +2,7 @@
 //
 // Shows: Playwright initialization, browser launch, navigation, cleanup
 
-use playwright_rs::Playwright;
+use playwright_rs::prelude::*;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
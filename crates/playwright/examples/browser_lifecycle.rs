@@ -2,7 +2,7 @@
 //
 // Shows: Multiple browser types, contexts, pages, proper cleanup order
 
-use playwright_rs::Playwright;
+use playwright_rs::prelude::*;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
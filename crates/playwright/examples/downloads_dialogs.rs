@@ -7,7 +7,7 @@
 // - Handling JavaScript dialogs (alert, confirm, prompt)
 // - Dialog types and response methods
 
-use playwright_rs::protocol::Playwright;
+use playwright_rs::prelude::*;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -133,7 +133,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     )
     .await?;
 
-    let checkbox = page.locator("#terms").await;
+    let checkbox = page.locator("#terms");
 
     // Set to checked using boolean
     println!("  Setting checkbox to checked...");
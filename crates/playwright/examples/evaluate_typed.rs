@@ -6,7 +6,7 @@
 //
 // Usage: cargo run --example evaluate_typed
 
-use playwright_rs::Playwright;
+use playwright_rs::prelude::*;
 use serde::{Deserialize, Serialize};
 
 // ============================================================================
@@ -2,7 +2,7 @@
 //
 // Shows: Locator API, chaining, nested locators, element queries
 
-use playwright_rs::Playwright;
+use playwright_rs::prelude::*;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -13,13 +13,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     page.goto("https://example.com", None).await?;
 
     // Basic locator - find and query element
-    let heading = page.locator("h1").await;
+    let heading = page.locator("h1");
     let heading_text = heading.text_content().await?;
     let is_visible = heading.is_visible().await?;
     println!("Heading: {:?} (visible: {})", heading_text, is_visible);
 
     // Count matching elements
-    let paragraphs = page.locator("p").await;
+    let paragraphs = page.locator("p");
     let count = paragraphs.count().await?;
     println!("Found {} paragraphs", count);
 
@@ -32,7 +32,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     // Nested locators - scope search within element
-    let body = page.locator("body").await;
+    let body = page.locator("body");
     let links = body.locator("a");
     let link_count = links.count().await?;
     println!("Found {} links in body", link_count);
@@ -8,7 +8,7 @@
 // 3. Playwright Inspector opens
 // 4. Execution pauses until you click "Resume" in the Inspector
 
-use playwright_rs::{LaunchOptions, Playwright};
+use playwright_rs::prelude::*;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
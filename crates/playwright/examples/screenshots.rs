@@ -4,7 +4,7 @@
 // PLAYWRIGHT_DRIVER_PATH=./drivers/playwright-1.56.1-mac-arm64 \
 //     cargo run --package playwright --example screenshots
 
-use playwright_rs::protocol::{Playwright, ScreenshotClip, ScreenshotOptions, ScreenshotType};
+use playwright_rs::prelude::*;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -49,7 +49,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     // Example 5: Element screenshot
-    let heading = page.locator("h1").await;
+    let heading = page.locator("h1");
     let element_bytes = heading.screenshot(None).await?;
     println!("✓ Element screenshot: {} bytes", element_bytes.len());
 
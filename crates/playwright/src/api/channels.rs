@@ -0,0 +1,230 @@
+// Browser channel discovery
+//
+// Locates branded browsers installed on the system (Google Chrome, Microsoft Edge)
+// that can be launched with `LaunchOptions::channel()`. Paths match the locations
+// used by Playwright's own registry for each platform.
+//
+// See: https://playwright.dev/docs/browsers#google-chrome--microsoft-edge
+
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+
+/// A branded browser channel found on this machine.
+///
+/// `name` can be passed directly to `LaunchOptions::channel()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledChannel {
+    /// Channel name (e.g., "chrome", "chrome-beta", "msedge")
+    pub name: &'static str,
+    /// Path to the browser executable
+    pub executable_path: PathBuf,
+    /// Browser version, when it can be determined
+    ///
+    /// Read from `--version` on Linux and macOS. Windows builds of Chrome and
+    /// Edge do not support `--version`; there it is the name of the version
+    /// directory installed next to the executable (e.g. `120.0.6099.109`).
+    pub version: Option<String>,
+}
+
+/// Channel names supported by Playwright for Chromium, in discovery order
+pub const CHROMIUM_CHANNELS: [&str; 8] = [
+    "chrome",
+    "chrome-beta",
+    "chrome-dev",
+    "chrome-canary",
+    "msedge",
+    "msedge-beta",
+    "msedge-dev",
+    "msedge-canary",
+];
+
+/// Lists the branded browser channels installed on this machine.
+///
+/// Only channels with an executable at the platform's standard install location
+/// are returned. Channels installed elsewhere can still be launched by passing
+/// `LaunchOptions::executable_path()`.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::api::{installed_channels, LaunchOptions};
+///
+/// let channels = installed_channels().await;
+/// for channel in &channels {
+///     println!("{} {:?} at {}", channel.name, channel.version, channel.executable_path.display());
+/// }
+///
+/// if let Some(chrome) = channels.iter().find(|c| c.name == "chrome") {
+///     let options = LaunchOptions::default().channel(chrome.name.to_string());
+///     let browser = playwright.chromium().launch_with_options(options).await?;
+/// }
+/// ```
+pub async fn installed_channels() -> Vec<InstalledChannel> {
+    let mut channels = Vec::new();
+    for name in CHROMIUM_CHANNELS {
+        for executable_path in channel_executable_candidates(name) {
+            if !is_file(&executable_path).await {
+                continue;
+            }
+            let version = read_version(&executable_path).await;
+            channels.push(InstalledChannel {
+                name,
+                executable_path,
+                version,
+            });
+            break;
+        }
+    }
+    channels
+}
+
+async fn is_file(path: &Path) -> bool {
+    tokio::fs::metadata(path)
+        .await
+        .is_ok_and(|metadata| metadata.is_file())
+}
+
+/// Returns the standard install locations for a channel on the current platform
+fn channel_executable_candidates(channel: &str) -> Vec<PathBuf> {
+    if cfg!(target_os = "linux") {
+        let path = match channel {
+            "chrome" => "/opt/google/chrome/chrome",
+            "chrome-beta" => "/opt/google/chrome-beta/chrome",
+            "chrome-dev" => "/opt/google/chrome-unstable/chrome",
+            "msedge" => "/opt/microsoft/msedge/msedge",
+            "msedge-beta" => "/opt/microsoft/msedge-beta/msedge",
+            "msedge-dev" => "/opt/microsoft/msedge-dev/msedge",
+            _ => return Vec::new(),
+        };
+        vec![PathBuf::from(path)]
+    } else if cfg!(target_os = "macos") {
+        let app = match channel {
+            "chrome" => "Google Chrome.app/Contents/MacOS/Google Chrome",
+            "chrome-beta" => "Google Chrome Beta.app/Contents/MacOS/Google Chrome Beta",
+            "chrome-dev" => "Google Chrome Dev.app/Contents/MacOS/Google Chrome Dev",
+            "chrome-canary" => "Google Chrome Canary.app/Contents/MacOS/Google Chrome Canary",
+            "msedge" => "Microsoft Edge.app/Contents/MacOS/Microsoft Edge",
+            "msedge-beta" => "Microsoft Edge Beta.app/Contents/MacOS/Microsoft Edge Beta",
+            "msedge-dev" => "Microsoft Edge Dev.app/Contents/MacOS/Microsoft Edge Dev",
+            "msedge-canary" => "Microsoft Edge Canary.app/Contents/MacOS/Microsoft Edge Canary",
+            _ => return Vec::new(),
+        };
+        vec![PathBuf::from("/Applications").join(app)]
+    } else if cfg!(windows) {
+        let suffix = match channel {
+            "chrome" => "Google\\Chrome\\Application\\chrome.exe",
+            "chrome-beta" => "Google\\Chrome Beta\\Application\\chrome.exe",
+            "chrome-dev" => "Google\\Chrome Dev\\Application\\chrome.exe",
+            "chrome-canary" => "Google\\Chrome SxS\\Application\\chrome.exe",
+            "msedge" => "Microsoft\\Edge\\Application\\msedge.exe",
+            "msedge-beta" => "Microsoft\\Edge Beta\\Application\\msedge.exe",
+            "msedge-dev" => "Microsoft\\Edge Dev\\Application\\msedge.exe",
+            "msedge-canary" => "Microsoft\\Edge SxS\\Application\\msedge.exe",
+            _ => return Vec::new(),
+        };
+        ["LOCALAPPDATA", "PROGRAMFILES", "PROGRAMFILES(X86)"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .map(|root| PathBuf::from(root).join(suffix))
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
+/// Runs `<executable> --version` and extracts the version number
+///
+/// On Windows, where the executable does not print its version, finds the
+/// newest version directory next to it instead.
+async fn read_version(executable: &Path) -> Option<String> {
+    if cfg!(windows) {
+        return newest_version_dir(executable.parent()?).await;
+    }
+    let output = Command::new(executable)
+        .arg("--version")
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Returns the highest version among directory names like `120.0.6099.109`
+async fn newest_version_dir(dir: &Path) -> Option<String> {
+    let mut entries = tokio::fs::read_dir(dir).await.ok()?;
+    let mut names = Vec::new();
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        if entry
+            .file_type()
+            .await
+            .is_ok_and(|file_type| file_type.is_dir())
+        {
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    newest_version(names.iter().map(String::as_str))
+}
+
+/// Picks the highest dotted version number, comparing numerically
+fn newest_version<'a>(names: impl Iterator<Item = &'a str>) -> Option<String> {
+    names
+        .filter_map(|name| {
+            let parts: Option<Vec<u32>> = name.split('.').map(|part| part.parse().ok()).collect();
+            parts
+                .filter(|parts| parts.len() > 1)
+                .map(|parts| (parts, name))
+        })
+        .max()
+        .map(|(_, name)| name.to_string())
+}
+
+/// Extracts the dotted version number from output like "Google Chrome 120.0.6099.109"
+fn parse_version(output: &str) -> Option<String> {
+    output
+        .split_whitespace()
+        .find(|token| token.contains('.') && token.chars().all(|c| c.is_ascii_digit() || c == '.'))
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(
+            parse_version("Google Chrome 120.0.6099.109 \n"),
+            Some("120.0.6099.109".to_string())
+        );
+        assert_eq!(
+            parse_version("Microsoft Edge 121.0.2277.83 beta"),
+            Some("121.0.2277.83".to_string())
+        );
+        assert_eq!(parse_version("no version here"), None);
+    }
+
+    #[test]
+    fn test_newest_version() {
+        let names = [
+            "119.0.6045.200",
+            "SetupMetrics",
+            "120.0.6099.9",
+            "120.0.6099.109",
+        ];
+        assert_eq!(
+            newest_version(names.into_iter()),
+            Some("120.0.6099.109".to_string())
+        );
+        assert_eq!(newest_version(["Locales", "42"].into_iter()), None);
+    }
+
+    #[tokio::test]
+    async fn test_installed_channels_are_known() {
+        for channel in installed_channels().await {
+            assert!(CHROMIUM_CHANNELS.contains(&channel.name));
+            assert!(channel.executable_path.is_file());
+        }
+    }
+}
@@ -0,0 +1,377 @@
+// Context profiles - Reusable browser context configurations
+//
+// A ContextProfile bundles the settings that make up a browser "fingerprint"
+// (viewport, user agent, locale, timezone, geolocation, permissions, ...) so a
+// fleet of workers can load the same configuration from a TOML or JSON file
+// instead of repeating builder calls.
+
+use crate::error::{Error, Result};
+use crate::protocol::{BrowserContextOptions, Geolocation, Viewport};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A named, serializable set of browser context settings.
+///
+/// Profiles can be saved to and loaded from TOML or JSON (chosen by file
+/// extension) and converted into [`BrowserContextOptions`].
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::ContextProfile;
+///
+/// // profiles/berlin-desktop.toml:
+/// //   name = "berlin-desktop"
+/// //   user_agent = "Mozilla/5.0 ..."
+/// //   locale = "de-DE"
+/// //   timezone_id = "Europe/Berlin"
+/// //   permissions = ["geolocation"]
+/// //   [viewport]
+/// //   width = 1920
+/// //   height = 1080
+/// //   [geolocation]
+/// //   latitude = 52.52
+/// //   longitude = 13.405
+/// let profile = ContextProfile::load("profiles/berlin-desktop.toml").await?;
+/// let context = browser.new_context_with_options(profile.to_context_options()).await?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContextProfile {
+    /// Profile name, for logging and lookup
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Viewport size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub viewport: Option<Viewport>,
+
+    /// User agent string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+
+    /// Locale (e.g., "en-GB")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+
+    /// Timezone identifier (e.g., "Europe/Berlin")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_id: Option<String>,
+
+    /// Geolocation coordinates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geolocation: Option<Geolocation>,
+
+    /// Permissions to grant (e.g., "geolocation")
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<String>,
+
+    /// Preferred color scheme ("light", "dark", "no-preference")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_scheme: Option<String>,
+
+    /// Device scale factor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_scale_factor: Option<f64>,
+
+    /// Whether the meta viewport tag is respected
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mobile: Option<bool>,
+
+    /// Whether the viewport supports touch events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_touch: Option<bool>,
+
+    /// Extra HTTP headers sent with every request
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub extra_http_headers: HashMap<String, String>,
+}
+
+/// Serialization format for profile files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProfileFormat {
+    Toml,
+    Json,
+}
+
+impl ProfileFormat {
+    fn from_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Ok(Self::Toml),
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Ok(Self::Json),
+            _ => Err(Error::InvalidArgument(format!(
+                "Unsupported profile file '{}': expected a .toml or .json extension",
+                path.display()
+            ))),
+        }
+    }
+}
+
+impl ContextProfile {
+    /// Creates an empty profile
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the profile name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the viewport size
+    pub fn viewport(mut self, width: u32, height: u32) -> Self {
+        self.viewport = Some(Viewport { width, height });
+        self
+    }
+
+    /// Set the user agent
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Set the locale
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Set the timezone
+    pub fn timezone_id(mut self, timezone_id: impl Into<String>) -> Self {
+        self.timezone_id = Some(timezone_id.into());
+        self
+    }
+
+    /// Set the geolocation
+    pub fn geolocation(mut self, latitude: f64, longitude: f64) -> Self {
+        self.geolocation = Some(Geolocation {
+            latitude,
+            longitude,
+            accuracy: None,
+        });
+        self
+    }
+
+    /// Add a permission to grant
+    pub fn permission(mut self, permission: impl Into<String>) -> Self {
+        self.permissions.push(permission.into());
+        self
+    }
+
+    /// Set the preferred color scheme
+    pub fn color_scheme(mut self, color_scheme: impl Into<String>) -> Self {
+        self.color_scheme = Some(color_scheme.into());
+        self
+    }
+
+    /// Set the device scale factor
+    pub fn device_scale_factor(mut self, factor: f64) -> Self {
+        self.device_scale_factor = Some(factor);
+        self
+    }
+
+    /// Set whether to emulate a mobile device
+    pub fn is_mobile(mut self, is_mobile: bool) -> Self {
+        self.is_mobile = Some(is_mobile);
+        self
+    }
+
+    /// Set whether touch events are supported
+    pub fn has_touch(mut self, has_touch: bool) -> Self {
+        self.has_touch = Some(has_touch);
+        self
+    }
+
+    /// Add an extra HTTP header
+    pub fn extra_http_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extra_http_headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Parses a profile from TOML
+    pub fn from_toml_str(s: &str) -> Result<Self> {
+        toml::from_str(s)
+            .map_err(|e| Error::InvalidArgument(format!("Invalid TOML context profile: {}", e)))
+    }
+
+    /// Serializes the profile to TOML
+    pub fn to_toml_string(&self) -> Result<String> {
+        toml::to_string_pretty(self).map_err(|e| {
+            Error::InvalidArgument(format!("Failed to serialize context profile: {}", e))
+        })
+    }
+
+    /// Parses a profile from JSON
+    pub fn from_json_str(s: &str) -> Result<Self> {
+        serde_json::from_str(s)
+            .map_err(|e| Error::InvalidArgument(format!("Invalid JSON context profile: {}", e)))
+    }
+
+    /// Serializes the profile to pretty-printed JSON
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Loads a profile from a `.toml` or `.json` file
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let format = ProfileFormat::from_path(path)?;
+        let content = tokio::fs::read_to_string(path).await.map_err(|e| {
+            Error::InvalidArgument(format!(
+                "Failed to read context profile '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        match format {
+            ProfileFormat::Toml => Self::from_toml_str(&content),
+            ProfileFormat::Json => Self::from_json_str(&content),
+        }
+    }
+
+    /// Saves the profile to a `.toml` or `.json` file
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let content = match ProfileFormat::from_path(path)? {
+            ProfileFormat::Toml => self.to_toml_string()?,
+            ProfileFormat::Json => self.to_json_string()?,
+        };
+        tokio::fs::write(path, content).await?;
+        Ok(())
+    }
+
+    /// Converts the profile into options for `Browser::new_context_with_options()`
+    pub fn to_context_options(&self) -> BrowserContextOptions {
+        let mut builder = BrowserContextOptions::builder();
+        if let Some(viewport) = &self.viewport {
+            builder = builder.viewport(viewport.clone());
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent.clone());
+        }
+        if let Some(locale) = &self.locale {
+            builder = builder.locale(locale.clone());
+        }
+        if let Some(timezone_id) = &self.timezone_id {
+            builder = builder.timezone_id(timezone_id.clone());
+        }
+        if let Some(geolocation) = &self.geolocation {
+            builder = builder.geolocation(geolocation.clone());
+        }
+        if !self.permissions.is_empty() {
+            builder = builder.permissions(self.permissions.clone());
+        }
+        if let Some(color_scheme) = &self.color_scheme {
+            builder = builder.color_scheme(color_scheme.clone());
+        }
+        if let Some(factor) = self.device_scale_factor {
+            builder = builder.device_scale_factor(factor);
+        }
+        if let Some(is_mobile) = self.is_mobile {
+            builder = builder.is_mobile(is_mobile);
+        }
+        if let Some(has_touch) = self.has_touch {
+            builder = builder.has_touch(has_touch);
+        }
+        if !self.extra_http_headers.is_empty() {
+            builder = builder.extra_http_headers(self.extra_http_headers.clone());
+        }
+        builder.build()
+    }
+}
+
+impl From<&ContextProfile> for BrowserContextOptions {
+    fn from(profile: &ContextProfile) -> Self {
+        profile.to_context_options()
+    }
+}
+
+impl From<ContextProfile> for BrowserContextOptions {
+    fn from(profile: ContextProfile) -> Self {
+        profile.to_context_options()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_profile() -> ContextProfile {
+        ContextProfile::new()
+            .name("berlin-desktop")
+            .viewport(1920, 1080)
+            .user_agent("TestAgent/1.0")
+            .locale("de-DE")
+            .timezone_id("Europe/Berlin")
+            .geolocation(52.52, 13.405)
+            .permission("geolocation")
+            .extra_http_header("X-Worker", "7")
+    }
+
+    #[test]
+    fn test_toml_roundtrip() {
+        let profile = sample_profile();
+        let toml = profile.to_toml_string().unwrap();
+        assert!(toml.contains("timezone_id = \"Europe/Berlin\""));
+        assert_eq!(ContextProfile::from_toml_str(&toml).unwrap(), profile);
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let profile = sample_profile();
+        let json = profile.to_json_string().unwrap();
+        assert_eq!(ContextProfile::from_json_str(&json).unwrap(), profile);
+    }
+
+    #[test]
+    fn test_partial_toml() {
+        let profile = ContextProfile::from_toml_str("locale = \"fr-FR\"").unwrap();
+        assert_eq!(profile.locale.as_deref(), Some("fr-FR"));
+        assert!(profile.viewport.is_none());
+        assert!(profile.permissions.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_toml_is_invalid_argument() {
+        let err = ContextProfile::from_toml_str("viewport = 3").unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+    }
+
+    #[test]
+    fn test_to_context_options() {
+        let options = sample_profile().to_context_options();
+        assert_eq!(options.locale.as_deref(), Some("de-DE"));
+        assert_eq!(options.timezone_id.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(options.viewport.unwrap().width, 1920);
+        assert_eq!(options.permissions.unwrap(), vec!["geolocation"]);
+        assert_eq!(
+            options.extra_http_headers.unwrap().get("X-Worker"),
+            Some(&"7".to_string())
+        );
+        assert!(options.user_agent.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load() {
+        let profile = sample_profile();
+        for ext in ["toml", "json"] {
+            let path = std::env::temp_dir().join(format!(
+                "playwright_rs_profile_{}.{}",
+                std::process::id(),
+                ext
+            ));
+            profile.save(&path).await.unwrap();
+            assert_eq!(ContextProfile::load(&path).await.unwrap(), profile);
+            std::fs::remove_file(&path).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unknown_extension_is_rejected() {
+        let err = ContextProfile::load("profile.yaml").await.unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+    }
+}
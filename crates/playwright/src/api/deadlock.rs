@@ -0,0 +1,231 @@
+// Deadlock detection configuration - Diagnosing calls that never complete
+//
+// A call whose response never arrives while the driver keeps talking is the
+// hardest failure to debug in a binding: nothing errors, one await simply never
+// returns. DeadlockDetection configures a watchdog that reports such calls
+// together with every other call in flight and the last protocol messages,
+// which is usually enough to tell a lost response from a handler that is
+// waiting on itself.
+//
+// Requires the `deadlock-detection` feature; keeping the message history costs
+// a copy of every message.
+
+use crate::server::connection::PendingCall;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Callback invoked when a call appears deadlocked
+pub type DeadlockHandler = Arc<dyn Fn(&DeadlockReport) + Send + Sync>;
+
+/// Settings for detecting calls that never receive a response.
+///
+/// Pass to [`PlaywrightConfig::deadlock_detection`](crate::PlaywrightConfig::deadlock_detection).
+/// Unlike [`KeepAlive`](crate::KeepAlive), which reports a driver that has gone
+/// silent, this reports individual calls that stay unanswered for `threshold`
+/// while the transport is still open, even if other traffic keeps flowing.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::{DeadlockDetection, Playwright, PlaywrightConfig};
+/// use std::time::Duration;
+///
+/// let detection = DeadlockDetection::new(Duration::from_secs(60))
+///     .on_deadlock(|report| eprintln!("{}", report));
+/// let playwright =
+///     Playwright::launch_with_config(PlaywrightConfig::new().deadlock_detection(detection))
+///         .await?;
+/// ```
+#[derive(Clone)]
+pub struct DeadlockDetection {
+    /// How long a call may wait for its response before it is reported
+    pub threshold: Duration,
+
+    /// How often pending calls are checked
+    pub check_interval: Duration,
+
+    /// Number of recent protocol messages kept for the report
+    pub history: usize,
+
+    on_deadlock: Option<DeadlockHandler>,
+}
+
+impl DeadlockDetection {
+    /// Default number of recent messages kept for the report
+    pub const DEFAULT_HISTORY: usize = 50;
+
+    /// Creates settings reporting calls unanswered for `threshold`
+    ///
+    /// The check interval defaults to 5 seconds, or a quarter of the threshold
+    /// if that is shorter.
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            check_interval: Duration::from_secs(5).min(threshold / 4),
+            history: Self::DEFAULT_HISTORY,
+            on_deadlock: None,
+        }
+    }
+
+    /// Sets how often pending calls are checked
+    pub fn check_interval(mut self, interval: Duration) -> Self {
+        self.check_interval = interval;
+        self
+    }
+
+    /// Sets how many recent protocol messages are kept for the report
+    pub fn history(mut self, messages: usize) -> Self {
+        self.history = messages;
+        self
+    }
+
+    /// Sets the callback invoked with each report
+    ///
+    /// Each stuck call is reported once. Reports are also logged at `error` level.
+    pub fn on_deadlock<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&DeadlockReport) + Send + Sync + 'static,
+    {
+        self.on_deadlock = Some(Arc::new(handler));
+        self
+    }
+
+    pub(crate) fn handler(&self) -> Option<&DeadlockHandler> {
+        self.on_deadlock.as_ref()
+    }
+}
+
+impl fmt::Debug for DeadlockDetection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeadlockDetection")
+            .field("threshold", &self.threshold)
+            .field("check_interval", &self.check_interval)
+            .field("history", &self.history)
+            .field("on_deadlock", &self.on_deadlock.is_some())
+            .finish()
+    }
+}
+
+/// Direction of a recorded protocol message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageDirection {
+    /// Sent to the driver
+    Sent,
+    /// Received from the driver
+    Received,
+}
+
+/// A recent protocol message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentMessage {
+    /// Which way the message went
+    pub direction: MessageDirection,
+    /// Time between the message and the report
+    pub age: Duration,
+    /// The message as JSON, truncated if long
+    pub summary: String,
+}
+
+/// Diagnostics for calls that never received a response.
+///
+/// `Display` renders the stuck calls with their backtraces (when captured,
+/// see [`PendingCall::backtrace`]), then the recent messages, oldest first.
+#[derive(Debug, Clone)]
+pub struct DeadlockReport {
+    /// Calls newly found waiting longer than the threshold, oldest first
+    pub stuck: Vec<PendingCall>,
+    /// Every call still waiting, including `stuck`, oldest first
+    pub pending: Vec<PendingCall>,
+    /// Time since the last message was received from the driver
+    pub idle_for: Duration,
+    /// The last protocol messages, oldest first
+    pub recent_messages: Vec<RecentMessage>,
+}
+
+impl fmt::Display for DeadlockReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Possible deadlock: {} call(s) without a response ({} pending, last message received {:?} ago)",
+            self.stuck.len(),
+            self.pending.len(),
+            self.idle_for
+        )?;
+        for call in &self.pending {
+            let marker = if self.stuck.iter().any(|stuck| stuck.id == call.id) {
+                "*"
+            } else {
+                " "
+            };
+            writeln!(
+                f,
+                "{} #{} {}.{} waiting {:?}",
+                marker, call.id, call.guid, call.method, call.elapsed
+            )?;
+            if let Some(backtrace) = &call.backtrace {
+                for line in backtrace.lines() {
+                    writeln!(f, "      {}", line)?;
+                }
+            }
+        }
+        writeln!(f, "Recent messages (oldest first):")?;
+        for message in &self.recent_messages {
+            let arrow = match message.direction {
+                MessageDirection::Sent => "->",
+                MessageDirection::Received => "<-",
+            };
+            writeln!(
+                f,
+                "  {} {:>8.1?} ago {}",
+                arrow, message.age, message.summary
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deadlock_detection_defaults() {
+        let detection = DeadlockDetection::new(Duration::from_secs(60));
+        assert_eq!(detection.check_interval, Duration::from_secs(5));
+        assert_eq!(detection.history, DeadlockDetection::DEFAULT_HISTORY);
+        assert!(detection.handler().is_none());
+
+        let short = DeadlockDetection::new(Duration::from_secs(2)).history(5);
+        assert_eq!(short.check_interval, Duration::from_millis(500));
+        assert_eq!(short.history, 5);
+    }
+
+    #[test]
+    fn test_deadlock_report_display() {
+        let call = |id, method: &str| PendingCall {
+            id,
+            guid: "frame@1".to_string(),
+            method: method.to_string(),
+            elapsed: Duration::from_secs(id.into()),
+            backtrace: None,
+        };
+        let report = DeadlockReport {
+            stuck: vec![call(7, "goto")],
+            pending: vec![call(7, "goto"), call(9, "title")],
+            idle_for: Duration::from_millis(20),
+            recent_messages: vec![RecentMessage {
+                direction: MessageDirection::Sent,
+                age: Duration::from_secs(7),
+                summary: r#"{"id":7,"method":"goto"}"#.to_string(),
+            }],
+        };
+
+        let text = report.to_string();
+        assert!(text.starts_with("Possible deadlock: 1 call(s) without a response (2 pending"));
+        assert!(text.contains("* #7 frame@1.goto waiting 7s"));
+        assert!(text.contains("  #9 frame@1.title waiting 9s"));
+        assert!(text.contains(r#"-> "#));
+        assert!(text.contains(r#"{"id":7,"method":"goto"}"#));
+    }
+}
@@ -0,0 +1,393 @@
+// Environment diagnostics
+//
+// `doctor()` checks that Playwright can run on this machine: the driver can be
+// located, each browser build is installed, and the driver's host validation
+// passes. The driver validates dependencies when it launches a browser,
+// checking every binary and library of the build (WebKit's executable is only
+// a wrapper script), and fails with "Host system is missing dependencies"
+// listing the packages or libraries to install. Missing dependencies are the
+// most common failure when running inside Docker or minimal CI images.
+//
+// Each problem is reported with the command that fixes it.
+
+use crate::protocol::Playwright;
+use crate::server::driver::get_driver_executable;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Start of the driver's launch error for failed host validation
+const MISSING_DEPENDENCIES: &str = "Host system is missing dependencies";
+
+/// Result of checking a single browser type.
+#[derive(Debug, Clone)]
+pub struct BrowserCheck {
+    /// Browser name ("chromium", "firefox", or "webkit")
+    pub name: String,
+    /// Path to the Playwright-managed browser executable
+    pub executable_path: PathBuf,
+    /// Whether the executable exists
+    pub installed: bool,
+    /// Missing dependencies reported by the driver's host validation: the
+    /// packages to install on distributions it knows, shared library names
+    /// otherwise
+    pub missing_libraries: Vec<String>,
+    /// Error launching the installed browser for any other reason
+    pub launch_error: Option<String>,
+}
+
+impl BrowserCheck {
+    /// Returns true if the browser is installed and launches
+    pub fn is_ok(&self) -> bool {
+        self.installed && self.missing_libraries.is_empty() && self.launch_error.is_none()
+    }
+}
+
+/// Report produced by [`doctor()`].
+///
+/// The `Display` implementation renders a human-readable summary with the
+/// commands needed to fix each problem.
+#[derive(Debug, Clone)]
+pub struct DoctorReport {
+    /// Node executable and `cli.js` of the located driver, if found
+    pub driver: Option<(PathBuf, PathBuf)>,
+    /// Error encountered while starting the driver, if any
+    pub driver_error: Option<String>,
+    /// Whether we appear to be running inside a container
+    pub in_container: bool,
+    /// Per-browser results (empty if the driver could not be started)
+    pub browsers: Vec<BrowserCheck>,
+}
+
+impl DoctorReport {
+    /// Returns true if the driver started and every browser passed its checks
+    pub fn is_healthy(&self) -> bool {
+        self.driver.is_some()
+            && self.driver_error.is_none()
+            && self.browsers.iter().all(BrowserCheck::is_ok)
+    }
+
+    /// Returns actionable fix suggestions, one per problem found
+    pub fn suggestions(&self) -> Vec<String> {
+        let mut suggestions = Vec::new();
+
+        let Some((node, cli)) = &self.driver else {
+            let mut suggestion = "Playwright driver not found. Rebuild playwright-rs with network \
+                 access so build.rs can download it, or set PLAYWRIGHT_DRIVER_PATH."
+                .to_string();
+            if let Some(error) = &self.driver_error {
+                suggestion.push_str(&format!(" ({})", error));
+            }
+            suggestions.push(suggestion);
+            return suggestions;
+        };
+        let cli_command = format!("{} {}", node.display(), cli.display());
+
+        if let Some(error) = &self.driver_error {
+            suggestions.push(format!("Playwright driver failed to start: {}", error));
+        }
+
+        for browser in &self.browsers {
+            if !browser.installed {
+                suggestions.push(format!(
+                    "{} is not installed. Run: {} install {}",
+                    browser.name, cli_command, browser.name
+                ));
+            } else if !browser.missing_libraries.is_empty() {
+                suggestions.push(format!(
+                    "{} is missing system libraries ({}). Run: {} install-deps {}",
+                    browser.name,
+                    browser.missing_libraries.join(", "),
+                    cli_command,
+                    browser.name
+                ));
+            } else if let Some(error) = &browser.launch_error {
+                suggestions.push(format!("{} failed to launch: {}", browser.name, error));
+            }
+        }
+
+        if self.in_container {
+            suggestions.push(
+                "Running in a container: launch with LaunchOptions::docker_defaults() and run \
+                 the container with --ipc=host (or a larger --shm-size)."
+                    .to_string(),
+            );
+        }
+
+        suggestions
+    }
+}
+
+impl fmt::Display for DoctorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.driver {
+            Some((node, cli)) => writeln!(f, "driver: {} {}", node.display(), cli.display())?,
+            None => writeln!(f, "driver: not found")?,
+        }
+        writeln!(
+            f,
+            "container: {}",
+            if self.in_container { "yes" } else { "no" }
+        )?;
+        for browser in &self.browsers {
+            let status = if !browser.installed {
+                "not installed"
+            } else if !browser.missing_libraries.is_empty() {
+                "missing libraries"
+            } else if browser.launch_error.is_some() {
+                "launch failed"
+            } else {
+                "ok"
+            };
+            writeln!(
+                f,
+                "{}: {} ({})",
+                browser.name,
+                status,
+                browser.executable_path.display()
+            )?;
+        }
+        for suggestion in self.suggestions() {
+            writeln!(f, "- {}", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks whether Playwright and its browsers can run on this machine.
+///
+/// Starts the driver to find each browser's executable, verifies it is installed,
+/// and launches it headless so the driver validates the host's system
+/// dependencies. Problems are reported in the returned [`DoctorReport`] rather
+/// than as errors.
+///
+/// # Example
+///
+/// ```ignore
+/// let report = playwright_rs::api::doctor().await;
+/// if !report.is_healthy() {
+///     eprintln!("{}", report);
+/// }
+/// ```
+pub async fn doctor() -> DoctorReport {
+    let in_container = is_containerized();
+
+    let driver = match get_driver_executable() {
+        Ok(paths) => paths,
+        Err(e) => {
+            return DoctorReport {
+                driver: None,
+                driver_error: Some(e.to_string()),
+                in_container,
+                browsers: Vec::new(),
+            }
+        }
+    };
+
+    let playwright = match Playwright::launch().await {
+        Ok(playwright) => playwright,
+        Err(e) => {
+            return DoctorReport {
+                driver: Some(driver),
+                driver_error: Some(e.to_string()),
+                in_container,
+                browsers: Vec::new(),
+            }
+        }
+    };
+
+    let mut browsers = Vec::new();
+    for browser_type in [
+        playwright.chromium(),
+        playwright.firefox(),
+        playwright.webkit(),
+    ] {
+        let executable_path = PathBuf::from(browser_type.executable_path());
+        let installed = tokio::fs::metadata(&executable_path)
+            .await
+            .is_ok_and(|metadata| metadata.is_file());
+        let mut check = BrowserCheck {
+            name: browser_type.name().to_string(),
+            executable_path,
+            installed,
+            missing_libraries: Vec::new(),
+            launch_error: None,
+        };
+        if installed {
+            match browser_type.launch().await {
+                Ok(browser) => {
+                    let _ = browser.close().await;
+                }
+                Err(e) => {
+                    let message = e.to_string();
+                    if message.contains(MISSING_DEPENDENCIES) {
+                        check.missing_libraries = parse_missing_dependencies(&message);
+                    }
+                    if check.missing_libraries.is_empty() {
+                        check.launch_error = Some(message);
+                    }
+                }
+            }
+        }
+        browsers.push(check);
+    }
+
+    let _ = playwright.shutdown().await;
+
+    DoctorReport {
+        driver: Some(driver),
+        driver_error: None,
+        in_container,
+        browsers,
+    }
+}
+
+/// Returns true if the process appears to run inside a Docker/OCI container.
+pub fn is_containerized() -> bool {
+    if !cfg!(target_os = "linux") {
+        return false;
+    }
+    if Path::new("/.dockerenv").exists() || Path::new("/run/.containerenv").exists() {
+        return true;
+    }
+    std::fs::read_to_string("/proc/1/cgroup")
+        .map(|cgroup| {
+            ["docker", "containerd", "kubepods", "podman", "lxc"]
+                .iter()
+                .any(|marker| cgroup.contains(marker))
+        })
+        .unwrap_or(false)
+}
+
+/// Extracts what to install from the driver's missing dependencies error
+///
+/// The driver names packages in an `apt-get install` command (with `\`
+/// continuation lines) on distributions it knows, and lists shared libraries
+/// under `Missing libraries:` otherwise. The message may be framed in a box.
+fn parse_missing_dependencies(message: &str) -> Vec<String> {
+    let mut missing = BTreeSet::new();
+    let mut in_install = false;
+    let mut in_libraries = false;
+    for line in message.lines() {
+        let line = line.trim_matches(|c: char| {
+            c.is_whitespace() || matches!(c, '║' | '╔' | '╚' | '═' | '╗' | '╝')
+        });
+        let words = if let Some((_, packages)) = line.split_once("apt-get install") {
+            in_install = true;
+            packages
+        } else if in_install {
+            line
+        } else if line == "Missing libraries:" {
+            in_libraries = true;
+            continue;
+        } else if in_libraries && !line.is_empty() && !line.contains(' ') {
+            line
+        } else {
+            in_libraries = false;
+            continue;
+        };
+        in_install = in_install && words.ends_with('\\');
+        missing.extend(
+            words
+                .split(|c: char| c.is_whitespace() || c == '\\')
+                .filter(|word| !word.is_empty() && !word.starts_with('-'))
+                .map(str::to_string),
+        );
+    }
+    missing.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_missing_packages() {
+        let message = "browserType.launch: \n\
+            ╔══════════════════════════════════════════════════════╗\n\
+            ║ Host system is missing dependencies to run browsers. ║\n\
+            ║ Please install them with the following command:      ║\n\
+            ║                                                      ║\n\
+            ║     sudo npx playwright install-deps                 ║\n\
+            ║                                                      ║\n\
+            ║ Alternatively, use apt:                              ║\n\
+            ║     sudo apt-get install libwoff1\\                   ║\n\
+            ║         libgstreamer-gl1.0-0\\                        ║\n\
+            ║         libwoff1                                     ║\n\
+            ║                                                      ║\n\
+            ║ <3 Playwright Team                                   ║\n\
+            ╚══════════════════════════════════════════════════════╝";
+        assert_eq!(
+            parse_missing_dependencies(message),
+            vec!["libgstreamer-gl1.0-0", "libwoff1"]
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_libraries() {
+        let message = "Host system is missing dependencies to run browsers.\n\
+                       Missing libraries:\n\
+                       \x20   libicui18n.so.66\n\
+                       \x20   libicudata.so.66\n\
+                       \x20   libicui18n.so.66\n\
+                       \n\
+                       Call log:";
+        assert_eq!(
+            parse_missing_dependencies(message),
+            vec!["libicudata.so.66", "libicui18n.so.66"]
+        );
+    }
+
+    #[test]
+    fn test_report_suggestions() {
+        let report = DoctorReport {
+            driver: Some((PathBuf::from("node"), PathBuf::from("cli.js"))),
+            driver_error: None,
+            in_container: false,
+            browsers: vec![
+                BrowserCheck {
+                    name: "chromium".to_string(),
+                    executable_path: PathBuf::from("/x/chrome"),
+                    installed: true,
+                    missing_libraries: vec!["libnss3".to_string()],
+                    launch_error: None,
+                },
+                BrowserCheck {
+                    name: "webkit".to_string(),
+                    executable_path: PathBuf::from("/x/webkit"),
+                    installed: false,
+                    missing_libraries: Vec::new(),
+                    launch_error: None,
+                },
+                BrowserCheck {
+                    name: "firefox".to_string(),
+                    executable_path: PathBuf::from("/x/firefox"),
+                    installed: true,
+                    missing_libraries: Vec::new(),
+                    launch_error: Some("Browser closed".to_string()),
+                },
+            ],
+        };
+
+        assert!(!report.is_healthy());
+        let suggestions = report.suggestions();
+        assert_eq!(suggestions.len(), 3);
+        assert!(suggestions[0].contains("node cli.js install-deps chromium"));
+        assert!(suggestions[1].contains("node cli.js install webkit"));
+        assert_eq!(suggestions[2], "firefox failed to launch: Browser closed");
+    }
+
+    #[test]
+    fn test_report_without_driver() {
+        let report = DoctorReport {
+            driver: None,
+            driver_error: Some("Playwright server not found".to_string()),
+            in_container: false,
+            browsers: Vec::new(),
+        };
+        assert!(!report.is_healthy());
+        let suggestion = &report.suggestions()[0];
+        assert!(suggestion.contains("PLAYWRIGHT_DRIVER_PATH"));
+        assert!(suggestion.ends_with("(Playwright server not found)"));
+    }
+}
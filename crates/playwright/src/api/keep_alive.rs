@@ -0,0 +1,138 @@
+// Keep-alive configuration - Detecting stalled driver connections
+//
+// Long-idle sessions (e.g. scrapers waiting between batches) can lose their
+// connection without any error surfacing until the next call hangs. KeepAlive
+// configures a watchdog that reports such stalls through an `on_stalled` callback.
+//
+// Note: the driver is currently reached over a local pipe, which has no ping/pong
+// frames. Stalls are detected from protocol traffic: a call that receives no
+// inbound message for `idle_timeout`, or a transport that closes unexpectedly.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Callback invoked when the connection to the driver stalls
+pub type StallHandler = Arc<dyn Fn(StallEvent) + Send + Sync>;
+
+/// Keep-alive and idle-timeout settings for the driver connection.
+///
+/// Pass to [`PlaywrightConfig::keep_alive`](crate::PlaywrightConfig::keep_alive).
+/// Idle connections with no calls in flight are never reported; a stall is a
+/// call that has gone `idle_timeout` without hearing from the driver, or a
+/// transport that closed while Playwright was still in use.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::{KeepAlive, Playwright, PlaywrightConfig};
+/// use std::time::Duration;
+///
+/// let keep_alive = KeepAlive::new(Duration::from_secs(60)).on_stalled(|event| {
+///     eprintln!("driver stalled: {:?} after {:?}", event.reason, event.idle_for);
+/// });
+/// let playwright =
+///     Playwright::launch_with_config(PlaywrightConfig::new().keep_alive(keep_alive)).await?;
+/// ```
+#[derive(Clone)]
+pub struct KeepAlive {
+    /// How long a pending call may go without inbound traffic before it is reported
+    pub idle_timeout: Duration,
+
+    /// How often the connection is checked
+    pub check_interval: Duration,
+
+    on_stalled: Option<StallHandler>,
+}
+
+impl KeepAlive {
+    /// Default interval between connection checks
+    pub const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+    /// Creates keep-alive settings with the given idle timeout
+    ///
+    /// The check interval defaults to 5 seconds, or a quarter of the idle
+    /// timeout if that is shorter.
+    pub fn new(idle_timeout: Duration) -> Self {
+        Self {
+            idle_timeout,
+            check_interval: Self::DEFAULT_CHECK_INTERVAL.min(idle_timeout / 4),
+            on_stalled: None,
+        }
+    }
+
+    /// Sets how often the connection is checked
+    pub fn check_interval(mut self, interval: Duration) -> Self {
+        self.check_interval = interval;
+        self
+    }
+
+    /// Sets the callback invoked when a stall is detected
+    ///
+    /// Called once per stall: an unresponsive connection is reported again only
+    /// after traffic has resumed in between. Stalls are also logged at `warn` level.
+    pub fn on_stalled<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(StallEvent) + Send + Sync + 'static,
+    {
+        self.on_stalled = Some(Arc::new(handler));
+        self
+    }
+
+    pub(crate) fn stall_handler(&self) -> Option<&StallHandler> {
+        self.on_stalled.as_ref()
+    }
+}
+
+impl fmt::Debug for KeepAlive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeepAlive")
+            .field("idle_timeout", &self.idle_timeout)
+            .field("check_interval", &self.check_interval)
+            .field("on_stalled", &self.on_stalled.is_some())
+            .finish()
+    }
+}
+
+/// Why the connection was reported as stalled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StallReason {
+    /// Calls are pending but nothing has been received for `idle_timeout`
+    Unresponsive,
+    /// The transport closed while Playwright was still in use
+    TransportClosed,
+}
+
+/// Details passed to the [`KeepAlive::on_stalled`] callback
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StallEvent {
+    /// Why the stall was reported
+    pub reason: StallReason,
+    /// Time since the last message was received from the driver
+    pub idle_for: Duration,
+    /// Number of calls still waiting for a response
+    pub pending_calls: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keep_alive_defaults() {
+        let keep_alive = KeepAlive::new(Duration::from_secs(60));
+        assert_eq!(keep_alive.idle_timeout, Duration::from_secs(60));
+        assert_eq!(keep_alive.check_interval, Duration::from_secs(5));
+        assert!(keep_alive.stall_handler().is_none());
+
+        let short = KeepAlive::new(Duration::from_secs(2));
+        assert_eq!(short.check_interval, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_keep_alive_debug_hides_callback() {
+        let keep_alive = KeepAlive::new(Duration::from_secs(1)).on_stalled(|_| {});
+        let debug = format!("{:?}", keep_alive);
+        assert!(debug.contains("on_stalled: true"));
+    }
+}
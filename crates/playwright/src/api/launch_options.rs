@@ -6,6 +6,7 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// Options for launching a browser
 ///
@@ -69,13 +70,21 @@ pub struct LaunchOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proxy: Option<ProxySettings>,
 
-    /// Slow down operations by N milliseconds
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub slow_mo: Option<f64>,
-
-    /// Timeout for browser launch in milliseconds (default: DEFAULT_TIMEOUT_MS)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timeout: Option<f64>,
+    /// Slow down operations by this much
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::protocol::options::millis"
+    )]
+    pub slow_mo: Option<Duration>,
+
+    /// Timeout for browser launch (default: [`DEFAULT_TIMEOUT`](crate::DEFAULT_TIMEOUT))
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::protocol::options::millis"
+    )]
+    pub timeout: Option<Duration>,
 
     /// Directory to save traces
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -118,6 +127,25 @@ impl LaunchOptions {
         Self::default()
     }
 
+    /// Creates LaunchOptions suited to running inside a Docker container
+    ///
+    /// Applies the settings recommended by Playwright's Docker guide:
+    /// - Headless mode
+    /// - Chromium sandbox disabled (containers usually run as root without user namespaces)
+    /// - `--disable-dev-shm-usage` so Chromium doesn't exhaust Docker's small default `/dev/shm`
+    ///
+    /// Further options can be chained as usual; note that `args()` replaces the
+    /// argument list, so include `--disable-dev-shm-usage` when overriding it.
+    /// Use `api::doctor()` to check for missing system libraries inside the image.
+    ///
+    /// See: <https://playwright.dev/docs/docker>
+    pub fn docker_defaults() -> Self {
+        Self::default()
+            .headless(true)
+            .chromium_sandbox(false)
+            .args(vec!["--disable-dev-shm-usage".to_string()])
+    }
+
     /// Set additional arguments to pass to browser instance
     pub fn args(mut self, args: Vec<String>) -> Self {
         self.args = Some(args);
@@ -154,6 +182,14 @@ impl LaunchOptions {
         self
     }
 
+    /// Set a single environment variable for the browser process
+    pub fn env_var(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
     /// Set path to custom browser executable
     pub fn executable_path(mut self, path: String) -> Self {
         self.executable_path = Some(path);
@@ -202,15 +238,15 @@ impl LaunchOptions {
         self
     }
 
-    /// Slow down operations by N milliseconds
-    pub fn slow_mo(mut self, ms: f64) -> Self {
-        self.slow_mo = Some(ms);
+    /// Slow down operations by `delay`
+    pub fn slow_mo(mut self, delay: Duration) -> Self {
+        self.slow_mo = Some(delay);
         self
     }
 
-    /// Set timeout for browser launch in milliseconds
-    pub fn timeout(mut self, ms: f64) -> Self {
-        self.timeout = Some(ms);
+    /// Set timeout for browser launch
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
         self
     }
 
@@ -277,11 +313,11 @@ mod tests {
     fn test_launch_options_builder() {
         let opts = LaunchOptions::default()
             .headless(false)
-            .slow_mo(100.0)
+            .slow_mo(Duration::from_millis(100))
             .args(vec!["--no-sandbox".to_string()]);
 
         assert_eq!(opts.headless, Some(false));
-        assert_eq!(opts.slow_mo, Some(100.0));
+        assert_eq!(opts.slow_mo, Some(Duration::from_millis(100)));
         assert_eq!(opts.args, Some(vec!["--no-sandbox".to_string()]));
     }
 
@@ -308,6 +344,31 @@ mod tests {
         assert!(names.contains(&"BAZ"));
     }
 
+    #[test]
+    fn test_launch_options_docker_defaults() {
+        let opts = LaunchOptions::docker_defaults().slow_mo(Duration::from_millis(10));
+
+        assert_eq!(opts.headless, Some(true));
+        assert_eq!(opts.chromium_sandbox, Some(false));
+        assert!(opts
+            .args
+            .as_ref()
+            .unwrap()
+            .contains(&"--disable-dev-shm-usage".to_string()));
+        assert_eq!(opts.slow_mo, Some(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn test_launch_options_env_var() {
+        let opts = LaunchOptions::default()
+            .env_var("FOO", "bar")
+            .env_var("BAZ", "qux");
+
+        let env = opts.env.unwrap();
+        assert_eq!(env.get("FOO"), Some(&"bar".to_string()));
+        assert_eq!(env.get("BAZ"), Some(&"qux".to_string()));
+    }
+
     #[test]
     fn test_launch_options_normalize_ignore_default_args_bool() {
         let opts = LaunchOptions::default().ignore_default_args(IgnoreDefaultArgs::Bool(true));
@@ -334,6 +395,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_launch_options_normalize_durations() {
+        let normalized = LaunchOptions::default()
+            .slow_mo(Duration::from_millis(250))
+            .normalize();
+        assert_eq!(normalized["slowMo"], 250.0);
+        assert_eq!(normalized["timeout"], crate::DEFAULT_TIMEOUT_MS);
+    }
+
     #[test]
     fn test_proxy_settings() {
         let proxy = ProxySettings {
@@ -351,8 +421,8 @@ mod tests {
     fn test_builder_pattern_chaining() {
         let opts = LaunchOptions::new()
             .headless(true)
-            .slow_mo(50.0)
-            .timeout(60000.0)
+            .slow_mo(Duration::from_millis(50))
+            .timeout(Duration::from_secs(60))
             .args(vec![
                 "--no-sandbox".to_string(),
                 "--disable-gpu".to_string(),
@@ -360,8 +430,8 @@ mod tests {
             .channel("chrome".to_string());
 
         assert_eq!(opts.headless, Some(true));
-        assert_eq!(opts.slow_mo, Some(50.0));
-        assert_eq!(opts.timeout, Some(60000.0));
+        assert_eq!(opts.slow_mo, Some(Duration::from_millis(50)));
+        assert_eq!(opts.timeout, Some(Duration::from_secs(60)));
         assert_eq!(opts.args.as_ref().unwrap().len(), 2);
         assert_eq!(opts.channel, Some("chrome".to_string()));
     }
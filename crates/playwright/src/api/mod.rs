@@ -3,6 +3,24 @@
 // This module contains high-level API types that are used across the protocol layer.
 // These types provide builder patterns and ergonomic interfaces for protocol operations.
 
+pub mod channels;
+pub mod context_profile;
+#[cfg(feature = "deadlock-detection")]
+pub mod deadlock;
+pub mod doctor;
+pub mod keep_alive;
 pub mod launch_options;
+pub mod playwright_config;
+pub mod retry;
 
+pub use channels::{installed_channels, InstalledChannel, CHROMIUM_CHANNELS};
+pub use context_profile::ContextProfile;
+#[cfg(feature = "deadlock-detection")]
+pub use deadlock::{
+    DeadlockDetection, DeadlockHandler, DeadlockReport, MessageDirection, RecentMessage,
+};
+pub use doctor::{doctor, is_containerized, BrowserCheck, DoctorReport};
+pub use keep_alive::{KeepAlive, StallEvent, StallHandler, StallReason};
 pub use launch_options::{IgnoreDefaultArgs, LaunchOptions, ProxySettings};
+pub use playwright_config::PlaywrightConfig;
+pub use retry::{retrying, RetryPolicy};
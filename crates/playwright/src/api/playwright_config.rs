@@ -0,0 +1,180 @@
+// Configuration for Playwright::launch_with_config()
+//
+// Controls how the Playwright driver (Node.js server) process is spawned,
+// without requiring callers to mutate the process-global environment.
+
+#[cfg(feature = "deadlock-detection")]
+use crate::api::deadlock::DeadlockDetection;
+use crate::api::keep_alive::KeepAlive;
+use std::collections::HashMap;
+
+/// Options for launching the Playwright driver process.
+///
+/// Environment variables set here are passed only to the driver process (and
+/// inherited by the browsers it launches). Use `LaunchOptions::env()` to set
+/// variables for a single browser instead.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::{Playwright, PlaywrightConfig};
+///
+/// let config = PlaywrightConfig::new()
+///     .debug("pw:api,pw:browser")
+///     .env_var("HTTPS_PROXY", "http://proxy.internal:3128");
+/// let playwright = Playwright::launch_with_config(config).await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PlaywrightConfig {
+    /// Extra environment variables for the driver process
+    pub env: HashMap<String, String>,
+
+    /// Number of driver log lines retained by `Playwright::driver_logs()`
+    pub driver_log_capacity: Option<usize>,
+
+    /// Include browser process output (`DEBUG=pw:browser`) in the driver log
+    pub capture_browser_output: bool,
+
+    /// Run the driver (and therefore headed browsers) inside a private Xvfb server (Linux only)
+    pub xvfb: bool,
+
+    /// Stall detection for the driver connection
+    pub keep_alive: Option<KeepAlive>,
+
+    /// Record protocol calls for `Playwright::recorded_session()` and `Playwright::replay()`
+    pub record_session: bool,
+
+    /// Reporting of calls whose response never arrives
+    #[cfg(feature = "deadlock-detection")]
+    pub deadlock_detection: Option<DeadlockDetection>,
+}
+
+impl PlaywrightConfig {
+    /// Creates a new PlaywrightConfig with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a single environment variable for the driver process
+    pub fn env_var(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set environment variables for the driver process
+    pub fn env(mut self, env: HashMap<String, String>) -> Self {
+        self.env.extend(env);
+        self
+    }
+
+    /// Set the `DEBUG` channels for the driver (e.g., "pw:api", "pw:browser*")
+    ///
+    /// Takes precedence over [`capture_browser_output`](Self::capture_browser_output).
+    pub fn debug(self, channels: impl Into<String>) -> Self {
+        self.env_var("DEBUG", channels)
+    }
+
+    /// Enable the Playwright Inspector (`PWDEBUG=1`)
+    ///
+    /// Browsers launch headed and scripts pause before each action.
+    pub fn pwdebug(self, enabled: bool) -> Self {
+        if enabled {
+            self.env_var("PWDEBUG", "1")
+        } else {
+            let mut config = self;
+            config.env.remove("PWDEBUG");
+            config
+        }
+    }
+
+    /// Start a private Xvfb server and run the driver against it (Linux only)
+    ///
+    /// Lets headed browsers launch on machines without a display, like
+    /// `xvfb-run` does. Requires the `Xvfb` binary (e.g. `apt-get install xvfb`).
+    /// The server is stopped when Playwright shuts down.
+    pub fn xvfb(mut self, enabled: bool) -> Self {
+        self.xvfb = enabled;
+        self
+    }
+
+    /// Watch the driver connection for stalls
+    ///
+    /// See [`KeepAlive`] for what counts as a stall.
+    pub fn keep_alive(mut self, keep_alive: KeepAlive) -> Self {
+        self.keep_alive = Some(keep_alive);
+        self
+    }
+
+    /// Report calls whose response never arrives
+    ///
+    /// See [`DeadlockDetection`] for what is reported.
+    #[cfg(feature = "deadlock-detection")]
+    pub fn deadlock_detection(mut self, detection: DeadlockDetection) -> Self {
+        self.deadlock_detection = Some(detection);
+        self
+    }
+
+    /// Record every protocol call so the session can be saved and replayed
+    ///
+    /// Also required on the instance that replays a session. See
+    /// [`RecordedSession`](crate::protocol::RecordedSession).
+    pub fn record_session(mut self, enabled: bool) -> Self {
+        self.record_session = enabled;
+        self
+    }
+
+    /// Capture browser process output in the driver log
+    ///
+    /// Starts the driver with `DEBUG=pw:browser` unless `DEBUG` is already set,
+    /// so `Playwright::driver_logs()`, launch errors and crash reports include
+    /// what the browser printed and how it exited. The captured browser lines
+    /// are kept in memory only and not echoed to stderr.
+    pub fn capture_browser_output(mut self, enabled: bool) -> Self {
+        self.capture_browser_output = enabled;
+        self
+    }
+
+    /// Set the number of driver log lines retained in memory
+    pub fn driver_log_capacity(mut self, lines: usize) -> Self {
+        self.driver_log_capacity = Some(lines);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_playwright_config_builder() {
+        let config = PlaywrightConfig::new()
+            .debug("pw:api")
+            .pwdebug(true)
+            .env_var("HTTP_PROXY", "http://proxy:8080")
+            .driver_log_capacity(50)
+            .xvfb(true)
+            .capture_browser_output(true)
+            .record_session(true);
+
+        assert_eq!(config.env.get("DEBUG"), Some(&"pw:api".to_string()));
+        assert_eq!(config.env.get("PWDEBUG"), Some(&"1".to_string()));
+        assert_eq!(
+            config.env.get("HTTP_PROXY"),
+            Some(&"http://proxy:8080".to_string())
+        );
+        assert_eq!(config.driver_log_capacity, Some(50));
+        assert!(config.xvfb);
+        assert!(config.capture_browser_output);
+        assert!(config.record_session);
+        assert!(config.keep_alive.is_none());
+
+        let config = config.keep_alive(KeepAlive::new(std::time::Duration::from_secs(30)));
+        assert_eq!(
+            config.keep_alive.as_ref().map(|k| k.idle_timeout),
+            Some(std::time::Duration::from_secs(30))
+        );
+
+        let config = config.pwdebug(false);
+        assert!(!config.env.contains_key("PWDEBUG"));
+    }
+}
@@ -0,0 +1,197 @@
+// Retrying actions - Riding out transient failures
+//
+// Long-running bots (monitoring, scraping) hit failures that go away on their
+// own: an element re-rendered between resolving and acting on it, a navigation
+// that destroyed the execution context, a slow backend pushing an action past
+// its timeout. `retrying` re-runs an action on those, as classified by
+// Error::is_transient, and returns every other error straight away so logical
+// failures (strict mode violations, bad arguments, closed targets, failed
+// assertions) are never masked by retries.
+
+use crate::error::Result;
+use std::future::Future;
+use std::time::Duration;
+
+/// How often and how patiently [`retrying`] re-runs an action.
+///
+/// The delay before each retry starts at `initial_delay` and is multiplied by
+/// `backoff` after every attempt, up to `max_delay`.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(5)
+///     .initial_delay(Duration::from_millis(500))
+///     .backoff(2.0)
+///     .max_delay(Duration::from_secs(5));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first
+    pub max_attempts: u32,
+
+    /// Delay before the first retry
+    pub initial_delay: Duration,
+
+    /// Factor applied to the delay after each retry
+    pub backoff: f64,
+
+    /// Upper bound for the delay between attempts
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a policy making at most `max_attempts` attempts
+    ///
+    /// Retries start after 250ms and back off by a factor of 2, up to 5 seconds.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            initial_delay: Duration::from_millis(250),
+            backoff: 2.0,
+            max_delay: Duration::from_secs(5),
+        }
+    }
+
+    /// Sets the delay before the first retry
+    pub fn initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Sets the factor applied to the delay after each retry (1.0 for a fixed delay)
+    pub fn backoff(mut self, factor: f64) -> Self {
+        self.backoff = factor;
+        self
+    }
+
+    /// Sets the upper bound for the delay between attempts
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Delay before retry number `retry` (1 for the first retry)
+    fn delay_before(&self, retry: u32) -> Duration {
+        let factor = self.backoff.max(1.0).powi(retry.saturating_sub(1) as i32);
+        self.initial_delay
+            .mul_f64(factor.min(u32::MAX as f64))
+            .min(self.max_delay)
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts with the default delays
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+/// Runs `action`, re-running it on transient errors as allowed by `policy`.
+///
+/// Only errors for which [`Error::is_transient`](crate::Error::is_transient)
+/// holds are retried: timeouts and elements or execution contexts that went
+/// away mid-action. Any other error, and the last transient one once the
+/// attempts are used up, is returned as is.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::{retrying, RetryPolicy};
+///
+/// let buy = page.locator("#buy");
+/// retrying(|| buy.click(None), RetryPolicy::default()).await?;
+/// ```
+pub async fn retrying<T, F, Fut>(mut action: F, policy: RetryPolicy) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 1;
+    loop {
+        match action().await {
+            Err(e) if e.is_transient() && attempt < policy.max_attempts => {
+                let delay = policy.delay_before(attempt);
+                tracing::warn!(
+                    "Attempt {} of {} failed, retrying in {:?}: {}",
+                    attempt,
+                    policy.max_attempts,
+                    delay,
+                    e
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_backs_off_to_max() {
+        let policy = RetryPolicy::new(10)
+            .initial_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(500));
+        assert_eq!(policy.delay_before(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_before(2), Duration::from_millis(200));
+        assert_eq!(policy.delay_before(3), Duration::from_millis(400));
+        assert_eq!(policy.delay_before(4), Duration::from_millis(500));
+        assert_eq!(policy.delay_before(60), Duration::from_millis(500));
+
+        let fixed = policy.backoff(1.0);
+        assert_eq!(fixed.delay_before(5), Duration::from_millis(100));
+    }
+
+    #[cfg(feature = "mock")]
+    #[tokio::test]
+    async fn test_retrying_rides_out_transient_errors() {
+        let driver = crate::mock::MockDriver::new();
+        driver
+            .time_out_once("click")
+            .fail_once("click", "Element is not attached to the DOM");
+        let page = driver.new_page().await.unwrap();
+        driver.clear_calls();
+
+        let button = page.locator("button");
+        let policy = RetryPolicy::new(3).initial_delay(Duration::from_millis(1));
+        retrying(|| button.click(None), policy).await.unwrap();
+        assert_eq!(driver.calls_to("click").len(), 3);
+    }
+
+    #[cfg(feature = "mock")]
+    #[tokio::test]
+    async fn test_retrying_stops_on_logical_errors_and_exhaustion() {
+        let driver = crate::mock::MockDriver::new();
+        driver.fail_once(
+            "click",
+            "strict mode violation: locator('button') resolved to 2 elements:",
+        );
+        let page = driver.new_page().await.unwrap();
+        driver.clear_calls();
+
+        let button = page.locator("button");
+        let policy = RetryPolicy::new(3).initial_delay(Duration::from_millis(1));
+        let result = retrying(|| button.click(None), policy.clone()).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::StrictModeViolation { .. })
+        ));
+        assert_eq!(driver.calls_to("click").len(), 1);
+
+        driver.clear_calls();
+        driver
+            .time_out_once("click")
+            .time_out_once("click")
+            .time_out_once("click");
+        let result = retrying(|| button.click(None), policy).await;
+        assert!(matches!(result, Err(crate::Error::Timeout(_))));
+        assert_eq!(driver.calls_to("click").len(), 3);
+    }
+}
@@ -14,14 +14,51 @@ const DEFAULT_ASSERTION_TIMEOUT: Duration = Duration::from_secs(5);
 /// Default polling interval for assertions (100ms)
 const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
+/// Reads the element's text and the expected text, both in Unicode NFC
+const NFC_TEXT_SCRIPT: &str =
+    "(element, expected) => [element.innerText.normalize('NFC'), expected.normalize('NFC')]";
+
+/// Soft hyphen, an invisible line-break hint common in German and Dutch text
+const SOFT_HYPHEN: char = '\u{00AD}';
+
+/// How text assertions normalize text before comparing it
+///
+/// Whitespace is always collapsed: runs of whitespace become a single space
+/// and the text is trimmed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct TextNormalization {
+    ignore_case: bool,
+    unicode_nfc: bool,
+    ignore_soft_hyphens: bool,
+}
+
+impl TextNormalization {
+    /// Applies everything but NFC, which runs in the browser
+    fn normalize(&self, text: &str) -> String {
+        let text = if self.ignore_soft_hyphens {
+            text.replace(SOFT_HYPHEN, "")
+        } else {
+            text.to_string()
+        };
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if self.ignore_case {
+            text.to_lowercase()
+        } else {
+            text
+        }
+    }
+}
+
 /// Creates an expectation for a locator with auto-retry behavior.
 ///
 /// Assertions will retry until they pass or timeout (default: 5 seconds).
+/// Accepts a `Locator` or a `&Locator`; a reference is cloned, so the
+/// locator stays usable for further actions and assertions.
 ///
 /// # Example
 ///
 /// ```ignore
-/// use playwright_rs::{expect, protocol::Playwright};
+/// use playwright_rs::{expect, Playwright};
 /// use std::time::Duration;
 ///
 /// #[tokio::main]
@@ -32,50 +69,56 @@ const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(100);
 ///
 ///     // Test to_be_visible and to_be_hidden
 ///     page.goto("data:text/html,<button id='btn'>Click me</button><div id='hidden' style='display:none'>Hidden</div>", None).await?;
-///     expect(page.locator("#btn").await).to_be_visible().await?;
-///     expect(page.locator("#hidden").await).to_be_hidden().await?;
+///     expect(page.locator("#btn")).to_be_visible().await?;
+///     expect(page.locator("#hidden")).to_be_hidden().await?;
+///
+///     // Borrow the locator instead of re-creating it for each assertion
+///     let button = page.locator("#btn");
+///     expect(&button).to_be_visible().await?;
+///     button.click(None).await?;
+///     expect(&button).to_be_enabled().await?;
 ///
 ///     // Test not() negation
-///     expect(page.locator("#btn").await).not().to_be_hidden().await?;
-///     expect(page.locator("#hidden").await).not().to_be_visible().await?;
+///     expect(page.locator("#btn")).not().to_be_hidden().await?;
+///     expect(page.locator("#hidden")).not().to_be_visible().await?;
 ///
 ///     // Test with_timeout()
 ///     page.goto("data:text/html,<div id='element'>Visible</div>", None).await?;
-///     expect(page.locator("#element").await)
+///     expect(page.locator("#element"))
 ///         .with_timeout(Duration::from_secs(10))
 ///         .to_be_visible()
 ///         .await?;
 ///
 ///     // Test to_be_enabled and to_be_disabled
 ///     page.goto("data:text/html,<button id='enabled'>Enabled</button><button id='disabled' disabled>Disabled</button>", None).await?;
-///     expect(page.locator("#enabled").await).to_be_enabled().await?;
-///     expect(page.locator("#disabled").await).to_be_disabled().await?;
+///     expect(page.locator("#enabled")).to_be_enabled().await?;
+///     expect(page.locator("#disabled")).to_be_disabled().await?;
 ///
 ///     // Test to_be_checked and to_be_unchecked
 ///     page.goto("data:text/html,<input type='checkbox' id='checked' checked><input type='checkbox' id='unchecked'>", None).await?;
-///     expect(page.locator("#checked").await).to_be_checked().await?;
-///     expect(page.locator("#unchecked").await).to_be_unchecked().await?;
+///     expect(page.locator("#checked")).to_be_checked().await?;
+///     expect(page.locator("#unchecked")).to_be_unchecked().await?;
 ///
 ///     // Test to_be_editable
 ///     page.goto("data:text/html,<input type='text' id='editable'>", None).await?;
-///     expect(page.locator("#editable").await).to_be_editable().await?;
+///     expect(page.locator("#editable")).to_be_editable().await?;
 ///
 ///     // Test to_be_focused
 ///     page.goto("data:text/html,<input type='text' id='input'>", None).await?;
 ///     page.evaluate::<(), ()>("document.getElementById('input').focus()", None).await?;
-///     expect(page.locator("#input").await).to_be_focused().await?;
+///     expect(page.locator("#input")).to_be_focused().await?;
 ///
 ///     // Test to_contain_text
 ///     page.goto("data:text/html,<div id='content'>Hello World</div>", None).await?;
-///     expect(page.locator("#content").await).to_contain_text("Hello").await?;
-///     expect(page.locator("#content").await).to_contain_text("World").await?;
+///     expect(page.locator("#content")).to_contain_text("Hello").await?;
+///     expect(page.locator("#content")).to_contain_text("World").await?;
 ///
 ///     // Test to_have_text
-///     expect(page.locator("#content").await).to_have_text("Hello World").await?;
+///     expect(page.locator("#content")).to_have_text("Hello World").await?;
 ///
 ///     // Test to_have_value
 ///     page.goto("data:text/html,<input type='text' id='input' value='test value'>", None).await?;
-///     expect(page.locator("#input").await).to_have_value("test value").await?;
+///     expect(page.locator("#input")).to_have_value("test value").await?;
 ///
 ///     browser.close().await?;
 ///     Ok(())
@@ -83,21 +126,30 @@ const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(100);
 /// ```
 ///
 /// See: <https://playwright.dev/docs/test-assertions>
-pub fn expect(locator: Locator) -> Expectation {
-    Expectation::new(locator)
+pub fn expect(locator: impl Into<Locator>) -> Expectation {
+    Expectation::new(locator.into())
 }
 
 /// Expectation wraps a locator and provides assertion methods with auto-retry.
+///
+/// Assertions borrow the expectation, so one configured with a custom timeout
+/// or poll interval can run several of them:
+///
+/// ```ignore
+/// let total = expect(page.locator("#total")).with_timeout(Duration::from_secs(10));
+/// total.to_be_visible().await?;
+/// total.to_contain_text("$").await?;
+/// total.clone().not().to_have_text("$0.00").await?;
+/// ```
+#[derive(Clone)]
 pub struct Expectation {
     locator: Locator,
     timeout: Duration,
     poll_interval: Duration,
     negate: bool,
+    text: TextNormalization,
 }
 
-// Allow clippy::wrong_self_convention for to_* methods that consume self
-// This matches Playwright's expect API pattern where assertions are chained and consumed
-#[allow(clippy::wrong_self_convention)]
 impl Expectation {
     /// Creates a new expectation for the given locator.
     pub(crate) fn new(locator: Locator) -> Self {
@@ -106,11 +158,11 @@ impl Expectation {
             timeout: DEFAULT_ASSERTION_TIMEOUT,
             poll_interval: DEFAULT_POLL_INTERVAL,
             negate: false,
+            text: TextNormalization::default(),
         }
     }
 
     /// Sets a custom timeout for this assertion.
-    ///
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
         self
@@ -126,6 +178,8 @@ impl Expectation {
 
     /// Negates the assertion.
     ///
+    /// Consumes the expectation; clone it first to keep the positive one.
+    ///
     /// Note: We intentionally use `.not()` method instead of implementing `std::ops::Not`
     /// to match Playwright's API across all language bindings (JS/Python/Java/.NET).
     #[allow(clippy::should_implement_trait)]
@@ -134,12 +188,66 @@ impl Expectation {
         self
     }
 
+    /// Compares text case-insensitively in text assertions.
+    ///
+    /// Both sides are lowercased with Unicode rules, so `"ÉCOLE"` matches
+    /// `"école"`, while `"STRASSE"` and `"straße"` still differ.
+    ///
+    /// Applies to [`to_have_text`](Self::to_have_text),
+    /// [`to_contain_text`](Self::to_contain_text) and their regex variants.
+    pub fn ignore_case(mut self) -> Self {
+        self.text.ignore_case = true;
+        self
+    }
+
+    /// Compares text in Unicode normalization form C (NFC) in text assertions.
+    ///
+    /// Precomposed and decomposed spellings of the same character, such as
+    /// `"é"` (U+00E9) and `"e\u{301}"`, then compare equal. Both the element's
+    /// text and the expected text are normalized, by the browser. Regex
+    /// patterns are not normalized and should be written in NFC.
+    pub fn normalize_unicode(mut self) -> Self {
+        self.text.unicode_nfc = true;
+        self
+    }
+
+    /// Ignores soft hyphens (U+00AD) in text assertions.
+    ///
+    /// Hyphenation hints inserted by CMSs or translation tools are invisible
+    /// unless a word wraps, but would otherwise break exact matches.
+    pub fn ignore_soft_hyphens(mut self) -> Self {
+        self.text.ignore_soft_hyphens = true;
+        self
+    }
+
+    /// Reads the element's text, normalized as configured, along with
+    /// `expected` normalized the same way.
+    async fn read_text(&self, expected: &str) -> Result<(String, String)> {
+        let (actual, expected) = if self.text.unicode_nfc {
+            self.locator
+                .evaluate::<_, (String, String)>(NFC_TEXT_SCRIPT, Some(&expected))
+                .await?
+        } else {
+            (self.locator.inner_text().await?, expected.to_string())
+        };
+        Ok((self.text.normalize(&actual), self.text.normalize(&expected)))
+    }
+
+    /// Compiles a text assertion pattern, honoring [`ignore_case`](Self::ignore_case).
+    #[cfg(feature = "regex")]
+    fn text_regex(&self, pattern: &str) -> Result<regex::Regex> {
+        regex::RegexBuilder::new(pattern)
+            .case_insensitive(self.text.ignore_case)
+            .build()
+            .map_err(|e| crate::error::Error::InvalidArgument(format!("Invalid regex: {}", e)))
+    }
+
     /// Asserts that the element is visible.
     ///
     /// This assertion will retry until the element becomes visible or timeout.
     ///
     /// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-be-visible>
-    pub async fn to_be_visible(self) -> Result<()> {
+    pub async fn to_be_visible(&self) -> Result<()> {
         let start = std::time::Instant::now();
         let selector = self.locator.selector().to_string();
 
@@ -179,12 +287,12 @@ impl Expectation {
     /// This assertion will retry until the element becomes hidden or timeout.
     ///
     /// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-be-hidden>
-    pub async fn to_be_hidden(self) -> Result<()> {
+    pub async fn to_be_hidden(&self) -> Result<()> {
         // to_be_hidden is the opposite of to_be_visible
         // Use negation to reuse the visibility logic
         let negated = Expectation {
             negate: !self.negate, // Flip negation
-            ..self
+            ..self.clone()
         };
         negated.to_be_visible().await
     }
@@ -192,18 +300,18 @@ impl Expectation {
     /// Asserts that the element has the specified text content (exact match).
     ///
     /// This assertion will retry until the element has the exact text or timeout.
-    /// Text is trimmed before comparison.
+    /// Whitespace is collapsed and trimmed before comparison; see
+    /// [`ignore_case`](Self::ignore_case), [`normalize_unicode`](Self::normalize_unicode)
+    /// and [`ignore_soft_hyphens`](Self::ignore_soft_hyphens) for more lenient matching.
     ///
     /// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-have-text>
-    pub async fn to_have_text(self, expected: &str) -> Result<()> {
+    pub async fn to_have_text(&self, expected: &str) -> Result<()> {
         let start = std::time::Instant::now();
         let selector = self.locator.selector().to_string();
-        let expected = expected.trim();
 
         loop {
             // Get text content (using inner_text for consistency with Playwright)
-            let actual_text = self.locator.inner_text().await?;
-            let actual = actual_text.trim();
+            let (actual, expected) = self.read_text(expected).await?;
 
             // Check if condition matches (with negation support)
             let matches = if self.negate {
@@ -240,21 +348,20 @@ impl Expectation {
     /// Asserts that the element's text matches the specified regex pattern.
     ///
     /// This assertion will retry until the element's text matches the pattern or timeout.
-    pub async fn to_have_text_regex(self, pattern: &str) -> Result<()> {
+    #[cfg(feature = "regex")]
+    pub async fn to_have_text_regex(&self, pattern: &str) -> Result<()> {
         let start = std::time::Instant::now();
         let selector = self.locator.selector().to_string();
-        let re = regex::Regex::new(pattern)
-            .map_err(|e| crate::error::Error::InvalidArgument(format!("Invalid regex: {}", e)))?;
+        let re = self.text_regex(pattern)?;
 
         loop {
-            let actual_text = self.locator.inner_text().await?;
-            let actual = actual_text.trim();
+            let (actual, _) = self.read_text("").await?;
 
             // Check if condition matches (with negation support)
             let matches = if self.negate {
-                !re.is_match(actual)
+                !re.is_match(&actual)
             } else {
-                re.is_match(actual)
+                re.is_match(&actual)
             };
 
             if matches {
@@ -285,21 +392,21 @@ impl Expectation {
     /// Asserts that the element contains the specified text (substring match).
     ///
     /// This assertion will retry until the element contains the text or timeout.
+    /// Text is normalized as in [`to_have_text`](Self::to_have_text).
     ///
     /// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-contain-text>
-    pub async fn to_contain_text(self, expected: &str) -> Result<()> {
+    pub async fn to_contain_text(&self, expected: &str) -> Result<()> {
         let start = std::time::Instant::now();
         let selector = self.locator.selector().to_string();
 
         loop {
-            let actual_text = self.locator.inner_text().await?;
-            let actual = actual_text.trim();
+            let (actual, expected) = self.read_text(expected).await?;
 
             // Check if condition matches (with negation support)
             let matches = if self.negate {
-                !actual.contains(expected)
+                !actual.contains(&expected)
             } else {
-                actual.contains(expected)
+                actual.contains(&expected)
             };
 
             if matches {
@@ -330,21 +437,20 @@ impl Expectation {
     /// Asserts that the element's text contains a substring matching the regex pattern.
     ///
     /// This assertion will retry until the element contains the pattern or timeout.
-    pub async fn to_contain_text_regex(self, pattern: &str) -> Result<()> {
+    #[cfg(feature = "regex")]
+    pub async fn to_contain_text_regex(&self, pattern: &str) -> Result<()> {
         let start = std::time::Instant::now();
         let selector = self.locator.selector().to_string();
-        let re = regex::Regex::new(pattern)
-            .map_err(|e| crate::error::Error::InvalidArgument(format!("Invalid regex: {}", e)))?;
+        let re = self.text_regex(pattern)?;
 
         loop {
-            let actual_text = self.locator.inner_text().await?;
-            let actual = actual_text.trim();
+            let (actual, _) = self.read_text("").await?;
 
             // Check if condition matches (with negation support)
             let matches = if self.negate {
-                !re.is_match(actual)
+                !re.is_match(&actual)
             } else {
-                re.is_match(actual)
+                re.is_match(&actual)
             };
 
             if matches {
@@ -372,12 +478,81 @@ impl Expectation {
         }
     }
 
+    /// Asserts that the element's text is a number within `tolerance` of `expected`.
+    ///
+    /// This assertion will retry until the number matches or timeout. The first
+    /// number in the text is used, so currency symbols and units around it are
+    /// ignored, and grouping and decimal separators of common locales are
+    /// understood: `"$1,234.50"`, `"1.234,50 €"`, `"1 234,5"` and `"CHF 1'234.50"`
+    /// all read as 1234.5. A single `.` or `,` followed by exactly three digits,
+    /// as in `"1,234"` or `"1.234"`, is taken as a thousands separator.
+    /// A minus sign or a number in parentheses, as in `"(12.00)"`, is negative.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// expect(page.locator("#total")).to_have_number(1234.5, 0.005).await?;
+    /// expect(page.locator("#cpu")).not().to_have_number(100.0, 5.0).await?;
+    /// ```
+    pub async fn to_have_number(&self, expected: f64, tolerance: f64) -> Result<()> {
+        let start = std::time::Instant::now();
+        let selector = self.locator.selector().to_string();
+
+        loop {
+            let text = self.locator.inner_text().await?;
+            let actual = parse_number(&text);
+
+            // Check if condition matches (with negation support)
+            let within = actual.is_some_and(|actual| (actual - expected).abs() <= tolerance);
+            let matches = if self.negate { !within } else { within };
+
+            if matches {
+                return Ok(());
+            }
+
+            // Check timeout
+            if start.elapsed() >= self.timeout {
+                let message = match actual {
+                    _ if self.negate => format!(
+                        "Expected element '{}' NOT to have number {} (±{}), but had '{}' after {:?}",
+                        selector,
+                        expected,
+                        tolerance,
+                        text.trim(),
+                        self.timeout
+                    ),
+                    Some(actual) => format!(
+                        "Expected element '{}' to have number {} (±{}), but had {} ('{}') after {:?}",
+                        selector,
+                        expected,
+                        tolerance,
+                        actual,
+                        text.trim(),
+                        self.timeout
+                    ),
+                    None => format!(
+                        "Expected element '{}' to have number {} (±{}), but its text '{}' had no number after {:?}",
+                        selector,
+                        expected,
+                        tolerance,
+                        text.trim(),
+                        self.timeout
+                    ),
+                };
+                return Err(crate::error::Error::AssertionTimeout(message));
+            }
+
+            // Wait before next poll
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
     /// Asserts that the input element has the specified value.
     ///
     /// This assertion will retry until the input has the exact value or timeout.
     ///
     /// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-have-value>
-    pub async fn to_have_value(self, expected: &str) -> Result<()> {
+    pub async fn to_have_value(&self, expected: &str) -> Result<()> {
         let start = std::time::Instant::now();
         let selector = self.locator.selector().to_string();
 
@@ -416,10 +591,88 @@ impl Expectation {
         }
     }
 
+    /// Asserts that the form's named controls have the expected values.
+    ///
+    /// `expected` serializes to a JSON object keyed by control name, the
+    /// shape [`Page::fill_form`](crate::protocol::Page::fill_form) takes:
+    /// strings for text controls, selects and radio groups, booleans for
+    /// checkboxes, lists for multi-selects and checkbox groups. Only the
+    /// listed fields are compared; all of them are read in one round trip per
+    /// poll (see [`Locator::form_values`]) and the failure message lists every
+    /// field that differs.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// expect(page.locator("form#signup"))
+    ///     .to_have_form_values(&serde_json::json!({
+    ///         "email": "ada@example.com",
+    ///         "plan": "pro",
+    ///         "terms": true,
+    ///     }))
+    ///     .await?;
+    /// ```
+    pub async fn to_have_form_values(&self, expected: &impl serde::Serialize) -> Result<()> {
+        let start = std::time::Instant::now();
+        let selector = self.locator.selector().to_string();
+        let expected = serde_json::to_value(expected)?;
+        let expected = expected.as_object().ok_or_else(|| {
+            crate::error::Error::InvalidArgument(
+                "Expected form values must serialize to a JSON object".to_string(),
+            )
+        })?;
+
+        loop {
+            // The form may not be rendered yet; keep polling until the timeout
+            let diff = self
+                .locator
+                .form_values()
+                .await
+                .map(|actual| crate::protocol::form::diff_form_values(expected, &actual));
+
+            // Check if condition matches (with negation support)
+            let matches = match &diff {
+                Ok(diff) => diff.is_empty() != self.negate,
+                Err(_) => false,
+            };
+
+            if matches {
+                return Ok(());
+            }
+
+            // Check timeout
+            if start.elapsed() >= self.timeout {
+                let message = match diff {
+                    Err(e) => format!(
+                        "Expected form '{}' to have values, but it could not be read after {:?}: {}",
+                        selector, self.timeout, e
+                    ),
+                    Ok(_) if self.negate => format!(
+                        "Expected form '{}' NOT to have values {}, but it did after {:?}",
+                        selector,
+                        serde_json::Value::Object(expected.clone()),
+                        self.timeout
+                    ),
+                    Ok(diff) => format!(
+                        "Expected form '{}' to have values, but after {:?}:\n{}",
+                        selector,
+                        self.timeout,
+                        diff.join("\n")
+                    ),
+                };
+                return Err(crate::error::Error::AssertionTimeout(message));
+            }
+
+            // Wait before next poll
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
     /// Asserts that the input element's value matches the specified regex pattern.
     ///
     /// This assertion will retry until the input value matches the pattern or timeout.
-    pub async fn to_have_value_regex(self, pattern: &str) -> Result<()> {
+    #[cfg(feature = "regex")]
+    pub async fn to_have_value_regex(&self, pattern: &str) -> Result<()> {
         let start = std::time::Instant::now();
         let selector = self.locator.selector().to_string();
         let re = regex::Regex::new(pattern)
@@ -460,13 +713,119 @@ impl Expectation {
         }
     }
 
+    /// Asserts that the element has the attribute with the specified value.
+    ///
+    /// This assertion will retry until the attribute has the exact value or timeout.
+    /// A missing attribute never matches, so `.not()` passes when it is absent.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// expect(page.locator("a.docs")).to_have_attribute("href", "/docs").await?;
+    /// expect(page.locator("#save")).not().to_have_attribute("aria-busy", "true").await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-have-attribute>
+    pub async fn to_have_attribute(&self, name: &str, expected: &str) -> Result<()> {
+        let start = std::time::Instant::now();
+        let selector = self.locator.selector().to_string();
+
+        loop {
+            let actual = self.locator.get_attribute(name).await?;
+
+            // Check if condition matches (with negation support)
+            let has_value = actual.as_deref() == Some(expected);
+            let matches = if self.negate { !has_value } else { has_value };
+
+            if matches {
+                return Ok(());
+            }
+
+            // Check timeout
+            if start.elapsed() >= self.timeout {
+                let message = if self.negate {
+                    format!(
+                        "Expected element '{}' NOT to have attribute '{}' with value '{}', but it did after {:?}",
+                        selector, name, expected, self.timeout
+                    )
+                } else {
+                    format!(
+                        "Expected element '{}' to have attribute '{}' with value '{}', but {} after {:?}",
+                        selector,
+                        name,
+                        expected,
+                        describe_attribute(actual.as_deref()),
+                        self.timeout
+                    )
+                };
+                return Err(crate::error::Error::AssertionTimeout(message));
+            }
+
+            // Wait before next poll
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Asserts that the element has the attribute with a value matching the regex pattern.
+    ///
+    /// This assertion will retry until the attribute value matches the pattern or timeout.
+    /// A missing attribute never matches, so `.not()` passes when it is absent.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// expect(page.locator("a.checkout")).to_have_attribute_regex("href", r"^https://").await?;
+    /// ```
+    #[cfg(feature = "regex")]
+    pub async fn to_have_attribute_regex(&self, name: &str, pattern: &str) -> Result<()> {
+        let start = std::time::Instant::now();
+        let selector = self.locator.selector().to_string();
+        let re = regex::Regex::new(pattern)
+            .map_err(|e| crate::error::Error::InvalidArgument(format!("Invalid regex: {}", e)))?;
+
+        loop {
+            let actual = self.locator.get_attribute(name).await?;
+
+            // Check if condition matches (with negation support)
+            let is_match = actual.as_deref().is_some_and(|value| re.is_match(value));
+            let matches = if self.negate { !is_match } else { is_match };
+
+            if matches {
+                return Ok(());
+            }
+
+            // Check timeout
+            if start.elapsed() >= self.timeout {
+                let message = if self.negate {
+                    format!(
+                        "Expected element '{}' NOT to have attribute '{}' matching pattern '{}', but it did after {:?}",
+                        selector, name, pattern, self.timeout
+                    )
+                } else {
+                    format!(
+                        "Expected element '{}' to have attribute '{}' matching pattern '{}', but {} after {:?}",
+                        selector,
+                        name,
+                        pattern,
+                        describe_attribute(actual.as_deref()),
+                        self.timeout
+                    )
+                };
+                return Err(crate::error::Error::AssertionTimeout(message));
+            }
+
+            // Wait before next poll
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
     /// Asserts that the element is enabled.
     ///
     /// This assertion will retry until the element is enabled or timeout.
     /// An element is enabled if it does not have the "disabled" attribute.
     ///
     /// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-be-enabled>
-    pub async fn to_be_enabled(self) -> Result<()> {
+    pub async fn to_be_enabled(&self) -> Result<()> {
         let start = std::time::Instant::now();
         let selector = self.locator.selector().to_string();
 
@@ -507,12 +866,12 @@ impl Expectation {
     /// An element is disabled if it has the "disabled" attribute.
     ///
     /// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-be-disabled>
-    pub async fn to_be_disabled(self) -> Result<()> {
+    pub async fn to_be_disabled(&self) -> Result<()> {
         // to_be_disabled is the opposite of to_be_enabled
         // Use negation to reuse the enabled logic
         let negated = Expectation {
             negate: !self.negate, // Flip negation
-            ..self
+            ..self.clone()
         };
         negated.to_be_enabled().await
     }
@@ -522,7 +881,7 @@ impl Expectation {
     /// This assertion will retry until the element is checked or timeout.
     ///
     /// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-be-checked>
-    pub async fn to_be_checked(self) -> Result<()> {
+    pub async fn to_be_checked(&self) -> Result<()> {
         let start = std::time::Instant::now();
         let selector = self.locator.selector().to_string();
 
@@ -562,12 +921,12 @@ impl Expectation {
     /// This assertion will retry until the element is unchecked or timeout.
     ///
     /// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-be-checked>
-    pub async fn to_be_unchecked(self) -> Result<()> {
+    pub async fn to_be_unchecked(&self) -> Result<()> {
         // to_be_unchecked is the opposite of to_be_checked
         // Use negation to reuse the checked logic
         let negated = Expectation {
             negate: !self.negate, // Flip negation
-            ..self
+            ..self.clone()
         };
         negated.to_be_checked().await
     }
@@ -578,7 +937,7 @@ impl Expectation {
     /// An element is editable if it is enabled and does not have the "readonly" attribute.
     ///
     /// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-be-editable>
-    pub async fn to_be_editable(self) -> Result<()> {
+    pub async fn to_be_editable(&self) -> Result<()> {
         let start = std::time::Instant::now();
         let selector = self.locator.selector().to_string();
 
@@ -622,7 +981,7 @@ impl Expectation {
     /// This assertion will retry until the element becomes focused or timeout.
     ///
     /// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-be-focused>
-    pub async fn to_be_focused(self) -> Result<()> {
+    pub async fn to_be_focused(&self) -> Result<()> {
         let start = std::time::Instant::now();
         let selector = self.locator.selector().to_string();
 
@@ -658,10 +1017,145 @@ impl Expectation {
     }
 }
 
+/// Describes an attribute value for assertion failure messages
+fn describe_attribute(value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!("had '{}'", value),
+        None => "the attribute was missing".to_string(),
+    }
+}
+
+/// Characters used to group digits: thin, narrow no-break and no-break
+/// spaces, plain spaces and apostrophes (Swiss German)
+fn is_group_separator(c: char) -> bool {
+    matches!(
+        c,
+        ' ' | '\u{00A0}' | '\u{2009}' | '\u{202F}' | '\'' | '\u{2019}'
+    )
+}
+
+/// Parses the first number in `text`, accepting the grouping and decimal
+/// separators of common locales (see [`Expectation::to_have_number`])
+fn parse_number(text: &str) -> Option<f64> {
+    let chars: Vec<char> = text.chars().collect();
+    let first_digit = chars.iter().position(char::is_ascii_digit)?;
+
+    // Sign: a minus (ASCII or U+2212) or an opening parenthesis right before
+    // the digits, or before a currency symbol in front of them
+    let sign = chars[..first_digit]
+        .iter()
+        .rev()
+        .find(|c| !c.is_whitespace() && !"$€£¥₹₩₽¢".contains(**c));
+    let negative = matches!(sign, Some('-' | '\u{2212}' | '('));
+
+    // Digits and separators, as long as every separator is followed by a digit
+    let mut end = first_digit;
+    while end < chars.len() {
+        let c = chars[end];
+        let next_is_digit = chars.get(end + 1).is_some_and(char::is_ascii_digit);
+        if c.is_ascii_digit() || ((c == '.' || c == ',' || is_group_separator(c)) && next_is_digit)
+        {
+            end += 1;
+        } else {
+            break;
+        }
+    }
+    let token: String = chars[first_digit..end]
+        .iter()
+        .filter(|c| !is_group_separator(**c))
+        .collect();
+
+    // The last of '.' and ',' is the decimal separator when both occur. A lone
+    // kind is decimal only if it occurs once and is not followed by exactly
+    // three digits, unless the integer part is zero ("0,125").
+    let decimal = match (token.rfind('.'), token.rfind(',')) {
+        (Some(dot), Some(comma)) => Some(dot.max(comma)),
+        (Some(at), None) | (None, Some(at)) => {
+            let separator = token.as_bytes()[at] as char;
+            let once = token.matches(separator).count() == 1;
+            let three_digits = token.len() - at - 1 == 3;
+            let zero = token[..at].trim_start_matches('0').is_empty();
+            (once && (!three_digits || zero)).then_some(at)
+        }
+        (None, None) => None,
+    };
+    let normalized: String = token
+        .char_indices()
+        .filter_map(|(i, c)| match c {
+            '.' | ',' if Some(i) == decimal => Some('.'),
+            '.' | ',' => None,
+            c => Some(c),
+        })
+        .collect();
+
+    let value: f64 = normalized.parse().ok()?;
+    Some(if negative { -value } else { value })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_text_normalization() {
+        let exact = TextNormalization::default();
+        assert_eq!(exact.normalize("  Hello \n\t World  "), "Hello World");
+        assert_eq!(
+            exact.normalize("Silben\u{00AD}trennung"),
+            "Silben\u{00AD}trennung"
+        );
+
+        let lenient = TextNormalization {
+            ignore_case: true,
+            ignore_soft_hyphens: true,
+            ..TextNormalization::default()
+        };
+        assert_eq!(
+            lenient.normalize("Silben\u{00AD}TRENNUNG"),
+            "silbentrennung"
+        );
+        assert_eq!(lenient.normalize("ÉCOLE   Ünïcödé"), "école ünïcödé");
+        // Removing a soft hyphen never joins words separated by whitespace
+        assert_eq!(lenient.normalize("a \u{00AD} b"), "a b");
+    }
+
+    #[test]
+    fn test_describe_attribute() {
+        assert_eq!(describe_attribute(Some("/docs")), "had '/docs'");
+        assert_eq!(describe_attribute(Some("")), "had ''");
+        assert_eq!(describe_attribute(None), "the attribute was missing");
+    }
+
+    #[test]
+    fn test_parse_number_locales() {
+        assert_eq!(parse_number("$1,234.50"), Some(1234.5));
+        assert_eq!(parse_number("1.234,50 €"), Some(1234.5));
+        assert_eq!(parse_number("1\u{202F}234,5"), Some(1234.5));
+        assert_eq!(parse_number("1 234 567"), Some(1234567.0));
+        assert_eq!(parse_number("CHF 1'234.50"), Some(1234.5));
+        assert_eq!(parse_number("12,5 %"), Some(12.5));
+        assert_eq!(parse_number("2.75"), Some(2.75));
+        assert_eq!(parse_number("0,125"), Some(0.125));
+        assert_eq!(parse_number("1,234"), Some(1234.0));
+        assert_eq!(parse_number("1.234.567"), Some(1234567.0));
+        assert_eq!(parse_number("42"), Some(42.0));
+    }
+
+    #[test]
+    fn test_parse_number_signs_and_surroundings() {
+        assert_eq!(parse_number("-7.5°C"), Some(-7.5));
+        assert_eq!(parse_number("\u{2212}3"), Some(-3.0));
+        assert_eq!(parse_number("(12.00)"), Some(-12.0));
+        assert_eq!(parse_number("-$5"), Some(-5.0));
+        assert_eq!(parse_number("Page (of 3)"), Some(3.0));
+        assert_eq!(parse_number("Sale - ends in 2 days"), Some(2.0));
+        assert_eq!(parse_number("Total: 99 items, 3 pages"), Some(99.0));
+        assert_eq!(parse_number("Sold out."), None);
+        // A trailing separator is punctuation, not part of the number
+        assert_eq!(parse_number("Price 10."), Some(10.0));
+        assert_eq!(parse_number("1, 2, 3"), Some(1.0));
+    }
+
     #[test]
     fn test_expectation_defaults() {
         // Verify default timeout and poll interval constants
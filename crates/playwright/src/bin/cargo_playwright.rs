@@ -0,0 +1,393 @@
+// cargo-playwright - Test development helpers
+//
+// `watch` polls the package for changes and reruns the tests they affect, so
+// the edit-run loop doesn't need a manual `cargo test` each time:
+//
+//     cargo install --path crates/playwright --features cli
+//     cargo playwright watch                      # in the package directory
+//     cargo playwright watch -- --features axum   # extra `cargo test` arguments
+//     cargo playwright watch --last-failed        # only harness tests that failed
+//
+// A change to tests/<name>.rs reruns only `cargo test --test <name>`; changes
+// to src/, shared test modules (tests/<dir>/...), Cargo.toml or anything else
+// watched rerun the whole suite. Edits made while tests run trigger another run.
+//
+// Each run is a fresh `cargo test`, so cargo's incremental build is reused but
+// browsers are still launched by the test processes.
+//
+// With --last-failed, runs set PLAYWRIGHT_LAST_FAILED=1 so harness::test()
+// bodies run only for tests that failed the last time they ran.
+//
+// `list` runs the suite with PLAYWRIGHT_LIST=1 and prints every harness::test()
+// with its project, tags and declared URLs (or, with --json, the full
+// listings) without running the bodies:
+//
+//     cargo playwright list --json -- --test checkout_test
+//
+// Tests not written with harness::test() still run; narrow the run with
+// cargo test arguments if they launch browsers.
+//
+// Usage: cargo-playwright watch [--poll-ms N] [--last-failed] [-- CARGO_TEST_ARGS...]
+//        cargo-playwright list [--json] [-- CARGO_TEST_ARGS...]
+
+use playwright_rs::harness::listing::{read_listings, LIST_ENV};
+use playwright_rs::harness::report::REPORT_DIR_ENV;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+
+const USAGE: &str = "\
+Usage: cargo playwright watch [--poll-ms N] [--last-failed] [-- CARGO_TEST_ARGS...]
+       cargo playwright list [--json] [-- CARGO_TEST_ARGS...]";
+
+enum Subcommand {
+    Watch(WatchArgs),
+    List(ListArgs),
+}
+
+/// Paths watched relative to the package root
+const WATCHED: [&str; 5] = ["src", "tests", "Cargo.toml", "build.rs", "playwright.toml"];
+
+struct WatchArgs {
+    poll: Duration,
+    last_failed: bool,
+    cargo_args: Vec<String>,
+}
+
+struct ListArgs {
+    json: bool,
+    cargo_args: Vec<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Subcommand, String> {
+    // `cargo playwright ...` invokes us as `cargo-playwright playwright ...`
+    let args = match args.first().map(String::as_str) {
+        Some("playwright") => &args[1..],
+        _ => args,
+    };
+    match args.first().map(String::as_str) {
+        Some("watch") => parse_watch_args(&args[1..]).map(Subcommand::Watch),
+        Some("list") => parse_list_args(&args[1..]).map(Subcommand::List),
+        Some("-h" | "--help") | None => Err(USAGE.to_string()),
+        Some(other) => Err(format!("Unknown command '{}'\n{}", other, USAGE)),
+    }
+}
+
+fn parse_list_args(args: &[String]) -> Result<ListArgs, String> {
+    let mut list = ListArgs {
+        json: false,
+        cargo_args: Vec::new(),
+    };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--json" => list.json = true,
+            "--" => {
+                list.cargo_args = iter.cloned().collect();
+                break;
+            }
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            other => return Err(format!("Unknown option: {}\n{}", other, USAGE)),
+        }
+    }
+    Ok(list)
+}
+
+fn parse_watch_args(args: &[String]) -> Result<WatchArgs, String> {
+    let mut watch = WatchArgs {
+        poll: Duration::from_millis(500),
+        last_failed: false,
+        cargo_args: Vec::new(),
+    };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--poll-ms" => {
+                let ms = iter
+                    .next()
+                    .ok_or_else(|| "--poll-ms requires a value".to_string())?;
+                let ms: u64 = ms
+                    .parse()
+                    .map_err(|_| format!("Invalid --poll-ms '{}'", ms))?;
+                watch.poll = Duration::from_millis(ms.max(50));
+            }
+            "--last-failed" => watch.last_failed = true,
+            "--" => {
+                watch.cargo_args = iter.cloned().collect();
+                break;
+            }
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            other => return Err(format!("Unknown option: {}\n{}", other, USAGE)),
+        }
+    }
+    Ok(watch)
+}
+
+/// Modification time of every watched file
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+fn snapshot(root: &Path) -> Snapshot {
+    fn visit(path: &Path, files: &mut Snapshot) {
+        let Ok(metadata) = std::fs::metadata(path) else {
+            return;
+        };
+        if metadata.is_dir() {
+            let Ok(entries) = std::fs::read_dir(path) else {
+                return;
+            };
+            for entry in entries.flatten() {
+                visit(&entry.path(), files);
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            files.insert(path.to_path_buf(), modified);
+        }
+    }
+
+    let mut files = Snapshot::new();
+    for name in WATCHED {
+        visit(&root.join(name), &mut files);
+    }
+    files
+}
+
+/// Files added, removed or modified between two snapshots
+fn changed(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .chain(
+            before
+                .keys()
+                .filter(|path| !after.contains_key(*path))
+                .cloned(),
+        )
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Integration test targets to rerun, or `None` for the whole suite
+fn affected_targets(root: &Path, changed: &[PathBuf]) -> Option<Vec<String>> {
+    let tests = root.join("tests");
+    let mut targets = Vec::new();
+    for path in changed {
+        let relative = path.strip_prefix(&tests).ok()?;
+        let mut components = relative.components();
+        let (Some(file), None) = (components.next(), components.next()) else {
+            // A shared module such as tests/common/mod.rs
+            return None;
+        };
+        let file = Path::new(file.as_os_str());
+        if file.extension().and_then(|e| e.to_str()) != Some("rs") {
+            return None;
+        }
+        let name = file.file_stem()?.to_string_lossy().to_string();
+        // Deleted targets no longer exist; other tests may have used them
+        if !path.exists() {
+            return None;
+        }
+        if !targets.contains(&name) {
+            targets.push(name);
+        }
+    }
+    Some(targets)
+}
+
+fn run_tests(targets: Option<&[String]>, args: &WatchArgs) {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command.arg("test");
+    if let Some(targets) = targets {
+        for target in targets {
+            command.args(["--test", target]);
+        }
+    }
+    command.args(&args.cargo_args);
+    if args.last_failed {
+        command.env("PLAYWRIGHT_LAST_FAILED", "1");
+    }
+
+    match targets {
+        Some(targets) => println!("[watch] cargo test --test {}", targets.join(" --test ")),
+        None => println!("[watch] cargo test (all targets)"),
+    }
+    match command.status() {
+        Ok(status) if status.success() => println!("[watch] passed, waiting for changes..."),
+        Ok(status) => println!("[watch] failed ({}), waiting for changes...", status),
+        Err(e) => eprintln!("[watch] failed to run cargo: {}", e),
+    }
+}
+
+fn watch(args: WatchArgs) -> Result<(), String> {
+    let root = std::env::current_dir().map_err(|e| e.to_string())?;
+    if !root.join("Cargo.toml").exists() {
+        return Err(format!(
+            "No Cargo.toml in {}; run watch from the package directory",
+            root.display()
+        ));
+    }
+
+    let mut last = snapshot(&root);
+    run_tests(None, &args);
+    loop {
+        std::thread::sleep(args.poll);
+        let current = snapshot(&root);
+        if changed(&last, &current).is_empty() {
+            continue;
+        }
+        // Let editors finish writing (and formatters run) before testing
+        let mut settled = current;
+        loop {
+            std::thread::sleep(args.poll);
+            let next = snapshot(&root);
+            if changed(&settled, &next).is_empty() {
+                break;
+            }
+            settled = next;
+        }
+
+        let paths = changed(&last, &settled);
+        last = settled;
+        for path in &paths {
+            let shown = path.strip_prefix(&root).unwrap_or(path);
+            println!("[watch] changed: {}", shown.display());
+        }
+        match affected_targets(&root, &paths) {
+            Some(targets) if targets.is_empty() => {}
+            targets => run_tests(targets.as_deref(), &args),
+        }
+    }
+}
+
+fn list(args: ListArgs) -> Result<(), String> {
+    let dir = std::env::temp_dir().join(format!("cargo-playwright-list-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let status = Command::new(cargo)
+        .arg("test")
+        .args(&args.cargo_args)
+        .env(LIST_ENV, "1")
+        .env(REPORT_DIR_ENV, &dir)
+        .stdout(Stdio::null())
+        .status()
+        .map_err(|e| format!("Failed to run cargo: {}", e))?;
+    let listings = read_listings(&dir).map_err(|e| e.to_string());
+    let _ = std::fs::remove_dir_all(&dir);
+    let listings = listings?;
+    if !status.success() {
+        eprintln!("warning: cargo test exited with {}", status);
+    }
+
+    if args.json {
+        let json = serde_json::to_string_pretty(&listings).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    } else {
+        for listing in &listings {
+            println!("  {}", listing.summary());
+        }
+        println!("Total: {} tests", listings.len());
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = parse_args(&args).and_then(|subcommand| match subcommand {
+        Subcommand::Watch(args) => watch(args),
+        Subcommand::List(args) => list(args),
+    });
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        std::process::exit(2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    fn watch_args(args: &[&str]) -> Result<WatchArgs, String> {
+        match parse_args(&strings(args))? {
+            Subcommand::Watch(args) => Ok(args),
+            Subcommand::List(_) => panic!("Expected watch"),
+        }
+    }
+
+    #[test]
+    fn test_parse_list_args() {
+        let Ok(Subcommand::List(args)) =
+            parse_args(&strings(&["playwright", "list", "--json", "--", "--lib"]))
+        else {
+            panic!("Expected list");
+        };
+        assert!(args.json);
+        assert_eq!(args.cargo_args, strings(&["--lib"]));
+        assert!(parse_args(&strings(&["list", "--poll-ms", "5"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_watch_args() {
+        let args = watch_args(&[
+            "playwright",
+            "watch",
+            "--poll-ms",
+            "200",
+            "--last-failed",
+            "--",
+            "--features",
+            "axum",
+        ])
+        .unwrap();
+        assert_eq!(args.poll, Duration::from_millis(200));
+        assert!(args.last_failed);
+        assert_eq!(args.cargo_args, strings(&["--features", "axum"]));
+
+        assert!(parse_args(&strings(&["watch", "--poll-ms"])).is_err());
+        assert!(parse_args(&strings(&["serve"])).is_err());
+        assert!(parse_args(&[]).is_err());
+    }
+
+    #[test]
+    fn test_changed() {
+        let now = SystemTime::now();
+        let later = now + Duration::from_secs(1);
+        let before: Snapshot = [("a.rs", now), ("b.rs", now)]
+            .into_iter()
+            .map(|(p, t)| (PathBuf::from(p), t))
+            .collect();
+        let after: Snapshot = [("a.rs", later), ("c.rs", now)]
+            .into_iter()
+            .map(|(p, t)| (PathBuf::from(p), t))
+            .collect();
+        assert_eq!(
+            changed(&before, &after),
+            ["a.rs", "b.rs", "c.rs"].map(PathBuf::from)
+        );
+        assert!(changed(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn test_affected_targets() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let tests = root.join("tests");
+        assert_eq!(
+            affected_targets(
+                root,
+                &[tests.join("locator_test.rs"), tests.join("locator_test.rs")]
+            ),
+            Some(strings(&["locator_test"]))
+        );
+        assert_eq!(affected_targets(root, &[tests.join("common/mod.rs")]), None);
+        assert_eq!(affected_targets(root, &[root.join("src/lib.rs")]), None);
+        assert_eq!(
+            affected_targets(root, &[tests.join("removed_test.rs")]),
+            None
+        );
+    }
+}
@@ -0,0 +1,205 @@
+// playwright-repl - Interactive page exploration
+//
+// Opens a page and reads commands from stdin, for trying out selectors
+// without writing a program. Requires the `repl` feature:
+//
+//     cargo run --features repl --bin playwright-repl -- --headed https://example.com
+//
+// Usage: playwright-repl [--browser chromium|firefox|webkit] [--headed] [URL]
+
+use playwright_rs::{LaunchOptions, Page, Playwright};
+use std::path::Path;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+const HELP: &str = "\
+Commands:
+  goto <url>                 Navigate to a URL
+  click <selector>           Click an element
+  fill <selector> <text>     Fill an input (selector must not contain spaces)
+  press <selector> <key>     Press a key on an element
+  text <selector>            Print the element's text content
+  count <selector>           Print how many elements match
+  visible <selector>         Print whether the element is visible
+  eval <expression>          Evaluate JavaScript and print the result
+  screenshot [path]          Save a screenshot (default: screenshot.png)
+  url | title                Print the current URL or title
+  help                       Show this help
+  quit | exit                Close the browser and exit";
+
+struct Args {
+    browser: String,
+    headed: bool,
+    url: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        browser: "chromium".to_string(),
+        headed: false,
+        url: None,
+    };
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--browser" => {
+                args.browser = iter
+                    .next()
+                    .ok_or_else(|| "--browser requires a value".to_string())?;
+            }
+            "--headed" => args.headed = true,
+            "-h" | "--help" => {
+                return Err(
+                    "Usage: playwright-repl [--browser chromium|firefox|webkit] [--headed] [URL]"
+                        .to_string(),
+                )
+            }
+            url if !url.starts_with('-') => args.url = Some(url.to_string()),
+            other => return Err(format!("Unknown option: {}", other)),
+        }
+    }
+    Ok(args)
+}
+
+/// Splits "cmd rest of line" into the command and its argument string
+fn split_command(line: &str) -> (&str, &str) {
+    match line.split_once(char::is_whitespace) {
+        Some((cmd, rest)) => (cmd, rest.trim()),
+        None => (line, ""),
+    }
+}
+
+fn require<'a>(value: &'a str, usage: &str) -> Result<&'a str, String> {
+    if value.is_empty() {
+        Err(format!("usage: {}", usage))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Runs one command, returning the text to print
+async fn run_command(
+    page: &Page,
+    cmd: &str,
+    rest: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    Ok(match cmd {
+        "help" => HELP.to_string(),
+        "goto" => {
+            let url = require(rest, "goto <url>")?;
+            match page.goto(url, None).await? {
+                Some(response) => format!("{} {}", response.status(), response.url()),
+                None => format!("navigated to {}", url),
+            }
+        }
+        "click" => {
+            page.locator(require(rest, "click <selector>")?)
+                .click(None)
+                .await?;
+            "ok".to_string()
+        }
+        "fill" | "press" => {
+            let usage = if cmd == "fill" {
+                "fill <selector> <text>"
+            } else {
+                "press <selector> <key>"
+            };
+            let (selector, value) = split_command(require(rest, usage)?);
+            let locator = page.locator(selector);
+            if cmd == "fill" {
+                locator.fill(value, None).await?;
+            } else {
+                locator.press(require(value, usage)?, None).await?;
+            }
+            "ok".to_string()
+        }
+        "text" => {
+            let text = page
+                .locator(require(rest, "text <selector>")?)
+                .text_content()
+                .await?;
+            text.unwrap_or_else(|| "(null)".to_string())
+        }
+        "count" => page
+            .locator(require(rest, "count <selector>")?)
+            .count()
+            .await?
+            .to_string(),
+        "visible" => page
+            .locator(require(rest, "visible <selector>")?)
+            .is_visible()
+            .await?
+            .to_string(),
+        "eval" => {
+            page.evaluate_value(require(rest, "eval <expression>")?)
+                .await?
+        }
+        "screenshot" => {
+            let path = if rest.is_empty() {
+                "screenshot.png"
+            } else {
+                rest
+            };
+            let bytes = page.screenshot_to_file(Path::new(path), None).await?;
+            format!("saved {} ({} bytes)", path, bytes.len())
+        }
+        "url" => page.url(),
+        "title" => page.title().await?,
+        other => format!("unknown command '{}', type 'help'", other),
+    })
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
+    };
+
+    let playwright = Playwright::launch().await?;
+    let browser_type = match args.browser.as_str() {
+        "chromium" => playwright.chromium(),
+        "firefox" => playwright.firefox(),
+        "webkit" => playwright.webkit(),
+        other => {
+            eprintln!("Unknown browser '{}'", other);
+            std::process::exit(2);
+        }
+    };
+    let browser = browser_type
+        .launch_with_options(LaunchOptions::new().headless(!args.headed))
+        .await?;
+    let page = browser.new_page().await?;
+    if let Some(url) = &args.url {
+        page.goto(url, None).await?;
+    }
+
+    println!(
+        "playwright-repl ({}), type 'help' for commands",
+        args.browser
+    );
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let mut stdout = tokio::io::stdout();
+    loop {
+        stdout.write_all(b"> ").await?;
+        stdout.flush().await?;
+        let Some(line) = lines.next_line().await? else {
+            break;
+        };
+        let (cmd, rest) = split_command(line.trim());
+        match cmd {
+            "" => continue,
+            "quit" | "exit" => break,
+            _ => match run_command(&page, cmd, rest).await {
+                Ok(output) => println!("{}", output),
+                Err(e) => println!("error: {}", e),
+            },
+        }
+    }
+
+    browser.close().await?;
+    playwright.shutdown().await?;
+    Ok(())
+}
@@ -1,11 +1,11 @@
-// Error types for playwright-core
+// Error types for playwright-rs
 
 use thiserror::Error;
 
-/// Result type alias for playwright-core operations
+/// Result type alias for playwright-rs operations
 pub type Result<T> = std::result::Result<T, Error>;
 
-/// Errors that can occur when using playwright-core
+/// Errors that can occur when using playwright-rs
 #[derive(Debug, Error)]
 pub enum Error {
     /// Playwright server binary was not found
@@ -24,6 +24,14 @@ pub enum Error {
     #[error("Failed to launch Playwright server: {0}. Check that Node.js is installed.")]
     LaunchFailed(String),
 
+    /// A headed browser could not be launched because no display server is available
+    ///
+    /// Typical on CI machines, containers, and WSL without WSLg.
+    /// Contains guidance on running headless or under Xvfb, followed by the
+    /// original launch error.
+    #[error("Cannot launch a headed browser: no X server or $DISPLAY available. {0}")]
+    DisplayNotAvailable(String),
+
     /// Server error (runtime issue with Playwright server)
     #[error("Server error: {0}")]
     ServerError(String),
@@ -92,7 +100,136 @@ pub enum Error {
     #[error("Element not found: selector '{0}'")]
     ElementNotFound(String),
 
+    /// A strict locator action matched more than one element
+    ///
+    /// `selector` is the locator as rendered by Playwright, e.g.
+    /// `locator('button')`. `candidates` lists the matched elements in
+    /// document order as the driver describes them (at most 10), each with a
+    /// suggested unique locator. Narrow the locator, e.g. with `first()`,
+    /// `nth()` or a `filter()`.
+    #[error(
+        "Strict mode violation: {selector} resolved to {count} elements:{}",
+        render_candidates(.candidates, *.count)
+    )]
+    StrictModeViolation {
+        selector: String,
+        count: usize,
+        candidates: Vec<String>,
+    },
+
     /// Assertion timeout (expect API)
     #[error("Assertion timeout: {0}")]
     AssertionTimeout(String),
+
+    /// The installed driver is too old for the requested API
+    ///
+    /// Update the driver, or unset `PLAYWRIGHT_DRIVER_PATH` to use the one
+    /// bundled with playwright-rs.
+    #[error(
+        "{feature} requires Playwright driver {required} or newer, but {installed} is installed"
+    )]
+    UnsupportedByDriver {
+        feature: &'static str,
+        required: crate::protocol::DriverVersion,
+        installed: crate::protocol::DriverVersion,
+    },
+
+    /// The driver or browser crashed while the operation was in flight
+    ///
+    /// The report carries the exit code or signal, the last driver log lines
+    /// and any minidumps found.
+    #[error("{0}")]
+    Crashed(Box<crate::protocol::CrashReport>),
+
+    /// An action failed and diagnostics were captured
+    ///
+    /// Returned instead of the original error when the browser context has a
+    /// [`DiagnosticsPolicy`](crate::protocol::DiagnosticsPolicy). Use
+    /// [`Error::inner`] to inspect the original error.
+    #[error("{error} ({diagnostics})")]
+    WithDiagnostics {
+        error: Box<Error>,
+        diagnostics: crate::protocol::Diagnostics,
+    },
+}
+
+impl Error {
+    /// Returns the diagnostics captured for this error, if any
+    pub fn diagnostics(&self) -> Option<&crate::protocol::Diagnostics> {
+        match self {
+            Error::WithDiagnostics { diagnostics, .. } => Some(diagnostics),
+            _ => None,
+        }
+    }
+
+    /// Whether the error is likely to go away if the action is retried.
+    ///
+    /// True for timeouts and for elements, frames or execution contexts that
+    /// were detached or destroyed mid-action, e.g. by a re-render or a
+    /// navigation. Logical failures such as strict mode violations, invalid
+    /// arguments, failed assertions and closed targets are never transient.
+    /// Used by [`retrying`](crate::retrying).
+    pub fn is_transient(&self) -> bool {
+        const TRANSIENT_MESSAGES: &[&str] = &[
+            "not attached to the DOM",
+            "is detached",
+            "was detached",
+            "Execution context was destroyed",
+            "Cannot find context with specified id",
+        ];
+        match self.inner() {
+            Error::Timeout(_) | Error::NavigationTimeout { .. } => true,
+            Error::ProtocolError(message) => TRANSIENT_MESSAGES
+                .iter()
+                .any(|transient| message.contains(transient)),
+            _ => false,
+        }
+    }
+
+    /// Returns the original error, unwrapping [`Error::WithDiagnostics`]
+    pub fn inner(&self) -> &Error {
+        match self {
+            Error::WithDiagnostics { error, .. } => error.inner(),
+            other => other,
+        }
+    }
+}
+
+/// Numbered candidate list of a strict mode violation, one per line
+fn render_candidates(candidates: &[String], count: usize) -> String {
+    let mut rendered: String = candidates
+        .iter()
+        .enumerate()
+        .map(|(i, candidate)| format!("\n    {}) {}", i + 1, candidate))
+        .collect();
+    if count > candidates.len() {
+        rendered.push_str("\n    ...");
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_transient() {
+        assert!(Error::Timeout("30000ms exceeded".into()).is_transient());
+        assert!(Error::ProtocolError("Element is not attached to the DOM".into()).is_transient());
+        assert!(Error::ProtocolError(
+            "Execution context was destroyed, most likely because of a navigation".into()
+        )
+        .is_transient());
+
+        assert!(!Error::ProtocolError("Unknown engine \"foo\"".into()).is_transient());
+        assert!(!Error::InvalidArgument("bad".into()).is_transient());
+        assert!(!Error::AssertionTimeout("expected visible".into()).is_transient());
+        assert!(!Error::ChannelClosed.is_transient());
+        assert!(!Error::StrictModeViolation {
+            selector: "locator('a')".into(),
+            count: 2,
+            candidates: Vec::new(),
+        }
+        .is_transient());
+    }
 }
@@ -0,0 +1,346 @@
+// Trace, video and screenshot retention
+//
+// Playwright-style artifact policies for tests run through harness::test().
+// Contexts opened with TestInfo::new_context() are traced and/or recorded as
+// the policy asks; when the body finishes the harness takes the final
+// screenshots, saves or discards the trace, closes the contexts and deletes
+// videos that should not be kept. Green runs under `retain-on-failure` or
+// `on-first-retry` therefore leave nothing on disk.
+//
+// Artifacts go to `$PLAYWRIGHT_OUTPUT_DIR/<test-name>[-retryN]/`
+// (default `test-results/`) and the kept ones are listed as attachments of the
+// test's report entry:
+//
+//     trace.zip            npx playwright show-trace trace.zip
+//     videos/*.webm
+//     test-failed-1.png    (or test-finished-1.png)
+//     attachments/*        files attached with TestInfo::attach()
+//
+// Examples:
+//
+//     PLAYWRIGHT_TRACE=retain-on-failure cargo test
+//     PLAYWRIGHT_TRACE=on-first-retry PLAYWRIGHT_VIDEO=retain-on-failure cargo test
+
+use crate::error::{Error, Result};
+use crate::harness::report::Attachment;
+use crate::protocol::{BrowserContext, TracingStartOptions};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Environment variable naming the artifact output directory
+pub const OUTPUT_DIR_ENV: &str = "PLAYWRIGHT_OUTPUT_DIR";
+
+/// Environment variable with the trace [`ArtifactMode`]
+pub const TRACE_ENV: &str = "PLAYWRIGHT_TRACE";
+
+/// Environment variable with the video [`ArtifactMode`]
+pub const VIDEO_ENV: &str = "PLAYWRIGHT_VIDEO";
+
+/// Environment variable with the screenshot [`ArtifactMode`]
+pub const SCREENSHOT_ENV: &str = "PLAYWRIGHT_SCREENSHOT";
+
+/// Output directory used when `PLAYWRIGHT_OUTPUT_DIR` is not set
+const DEFAULT_OUTPUT_DIR: &str = "test-results";
+
+/// When an artifact is recorded and whether it is kept
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ArtifactMode {
+    /// Never record
+    #[default]
+    Off,
+    /// Record and keep for every attempt
+    On,
+    /// Record every attempt, keep only failed ones
+    RetainOnFailure,
+    /// Record and keep only the first retry of a test
+    OnFirstRetry,
+}
+
+impl ArtifactMode {
+    /// Whether to record during attempt `retry` (0 for the first run)
+    pub fn should_record(self, retry: u32) -> bool {
+        match self {
+            ArtifactMode::Off => false,
+            ArtifactMode::On | ArtifactMode::RetainOnFailure => true,
+            ArtifactMode::OnFirstRetry => retry == 1,
+        }
+    }
+
+    /// Whether to keep what attempt `retry` recorded
+    pub fn should_retain(self, retry: u32, failed: bool) -> bool {
+        match self {
+            ArtifactMode::RetainOnFailure => failed,
+            _ => self.should_record(retry),
+        }
+    }
+}
+
+impl FromStr for ArtifactMode {
+    type Err = Error;
+
+    /// Parses the names used by Playwright's config: `off`, `on`,
+    /// `retain-on-failure` (alias `only-on-failure`) and `on-first-retry`
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "off" | "" => Ok(ArtifactMode::Off),
+            "on" => Ok(ArtifactMode::On),
+            "retain-on-failure" | "only-on-failure" => Ok(ArtifactMode::RetainOnFailure),
+            "on-first-retry" => Ok(ArtifactMode::OnFirstRetry),
+            other => Err(Error::InvalidArgument(format!(
+                "Unknown artifact mode '{}' (expected off, on, retain-on-failure or on-first-retry)",
+                other
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for ArtifactMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ArtifactMode::Off => "off",
+            ArtifactMode::On => "on",
+            ArtifactMode::RetainOnFailure => "retain-on-failure",
+            ArtifactMode::OnFirstRetry => "on-first-retry",
+        })
+    }
+}
+
+/// Which artifacts a test records and keeps.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::harness::{self, ArtifactMode, ArtifactPolicy};
+///
+/// harness::test("checkout")
+///     .retries(1)
+///     .artifacts(
+///         ArtifactPolicy::new()
+///             .trace(ArtifactMode::OnFirstRetry)
+///             .screenshot(ArtifactMode::RetainOnFailure),
+///     )
+///     .run(|info| async move {
+///         let context = info.new_context(&browser, None).await?;
+///         let page = context.new_page().await?;
+///         // ...
+///         Ok(())
+///     })
+///     .await
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArtifactPolicy {
+    /// Playwright trace (`trace.zip`)
+    pub trace: ArtifactMode,
+    /// Video of every page
+    pub video: ArtifactMode,
+    /// Screenshot of every open page when the test ends
+    pub screenshot: ArtifactMode,
+}
+
+impl ArtifactPolicy {
+    /// A policy that records nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the trace mode
+    pub fn trace(mut self, mode: ArtifactMode) -> Self {
+        self.trace = mode;
+        self
+    }
+
+    /// Sets the video mode
+    pub fn video(mut self, mode: ArtifactMode) -> Self {
+        self.video = mode;
+        self
+    }
+
+    /// Sets the screenshot mode
+    pub fn screenshot(mut self, mode: ArtifactMode) -> Self {
+        self.screenshot = mode;
+        self
+    }
+
+    /// Reads `PLAYWRIGHT_TRACE`, `PLAYWRIGHT_VIDEO` and `PLAYWRIGHT_SCREENSHOT`;
+    /// unset variables mean `off`
+    pub fn from_env() -> Result<Self> {
+        let mode = |var: &str| match std::env::var(var) {
+            Ok(value) => value.parse(),
+            Err(_) => Ok(ArtifactMode::Off),
+        };
+        Ok(Self {
+            trace: mode(TRACE_ENV)?,
+            video: mode(VIDEO_ENV)?,
+            screenshot: mode(SCREENSHOT_ENV)?,
+        })
+    }
+}
+
+/// Root directory for test artifacts, from `PLAYWRIGHT_OUTPUT_DIR`
+pub fn output_root() -> PathBuf {
+    std::env::var_os(OUTPUT_DIR_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_OUTPUT_DIR))
+}
+
+/// Artifact directory of attempt `retry` of the test called `name`
+pub(crate) fn output_dir(name: &str, retry: u32) -> PathBuf {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let mut slug = slug.trim_matches('-').to_string();
+    if slug.is_empty() {
+        slug.push_str("test");
+    }
+    if retry > 0 {
+        slug.push_str(&format!("-retry{}", retry));
+    }
+    output_root().join(slug)
+}
+
+/// Options for the trace recorded by [`finish`]
+pub(crate) fn trace_options(name: &str) -> TracingStartOptions {
+    TracingStartOptions::builder()
+        .screenshots(true)
+        .snapshots(true)
+        .title(name)
+        .build()
+}
+
+/// Applies `policy` to the contexts a finished attempt opened, then closes
+/// them; returns the artifacts that were kept
+pub(crate) async fn finish(
+    policy: ArtifactPolicy,
+    contexts: Vec<BrowserContext>,
+    dir: &Path,
+    retry: u32,
+    failed: bool,
+) -> Vec<Attachment> {
+    let mut kept = Vec::new();
+    let mut screenshot = 0;
+    for (index, context) in contexts.iter().enumerate() {
+        if policy.screenshot.should_retain(retry, failed) {
+            for page in context.pages() {
+                screenshot += 1;
+                let file = dir.join(format!(
+                    "test-{}-{}.png",
+                    if failed { "failed" } else { "finished" },
+                    screenshot
+                ));
+                match save_screenshot(&page, &file).await {
+                    Ok(()) => kept.push(Attachment::new("screenshot", "image/png", file)),
+                    Err(e) => {
+                        tracing::warn!("Failed to save screenshot {}: {}", file.display(), e)
+                    }
+                }
+            }
+        }
+
+        if policy.trace.should_record(retry) {
+            let file = if index == 0 {
+                dir.join("trace.zip")
+            } else {
+                dir.join(format!("trace-{}.zip", index))
+            };
+            let keep = policy.trace.should_retain(retry, failed);
+            let stopped = match context.tracing().await {
+                Ok(tracing) => tracing.stop(keep.then_some(file.as_path())).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = stopped {
+                tracing::warn!("Failed to stop tracing: {}", e);
+            } else if keep {
+                tracing::info!("Trace saved to {}", file.display());
+                kept.push(Attachment::new("trace", "application/zip", file));
+            }
+        }
+
+        // Closing finalizes the videos
+        if let Err(e) = context.close().await {
+            tracing::debug!("Failed to close test context: {}", e);
+        }
+    }
+
+    let videos = dir.join("videos");
+    if policy.video.should_record(retry) {
+        if policy.video.should_retain(retry, failed) {
+            let mut files: Vec<PathBuf> = std::fs::read_dir(&videos)
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .collect();
+            files.sort();
+            kept.extend(
+                files
+                    .into_iter()
+                    .map(|file| Attachment::new("video", "video/webm", file)),
+            );
+        } else {
+            let _ = std::fs::remove_dir_all(&videos);
+        }
+    }
+    // Leave no empty directories behind on green runs
+    let _ = std::fs::remove_dir(dir);
+    kept
+}
+
+async fn save_screenshot(page: &crate::protocol::Page, file: &Path) -> Result<()> {
+    if let Some(dir) = file.parent() {
+        tokio::fs::create_dir_all(dir).await?;
+    }
+    page.screenshot_to_file(file, None).await.map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_record_and_retain() {
+        use ArtifactMode::*;
+
+        assert!(!Off.should_record(0));
+        assert!(On.should_record(0) && On.should_retain(0, false));
+        assert!(RetainOnFailure.should_record(0));
+        assert!(!RetainOnFailure.should_retain(0, false));
+        assert!(RetainOnFailure.should_retain(2, true));
+        assert!(!OnFirstRetry.should_record(0));
+        assert!(OnFirstRetry.should_record(1) && OnFirstRetry.should_retain(1, false));
+        assert!(!OnFirstRetry.should_record(2));
+    }
+
+    #[test]
+    fn test_mode_parse() {
+        for mode in [
+            ArtifactMode::Off,
+            ArtifactMode::On,
+            ArtifactMode::RetainOnFailure,
+            ArtifactMode::OnFirstRetry,
+        ] {
+            assert_eq!(mode.to_string().parse::<ArtifactMode>().unwrap(), mode);
+        }
+        assert_eq!(
+            "only-on-failure".parse::<ArtifactMode>().unwrap(),
+            ArtifactMode::RetainOnFailure
+        );
+        assert!("sometimes".parse::<ArtifactMode>().is_err());
+    }
+
+    #[test]
+    fn test_output_dir() {
+        let root = output_root();
+        assert_eq!(
+            output_dir("Checkout: pays with card!", 0),
+            root.join("checkout-pays-with-card")
+        );
+        assert_eq!(output_dir("login", 2), root.join("login-retry2"));
+        assert_eq!(output_dir("???", 0), root.join("test"));
+    }
+}
@@ -0,0 +1,293 @@
+// BDD support - World fixture and built-in steps for Gherkin test runners
+//
+// BrowserWorld holds the Browser/BrowserContext/Page a scenario runs against,
+// and run_step() implements the step phrases most suites start with ("I go to",
+// "I click", "I fill ... with", "I should see"). It has no dependency on a
+// particular runner: with the `cucumber` crate, wrap BrowserWorld in your World
+// type and forward unmatched steps to run_step().
+
+use crate::assertions::expect;
+use crate::error::{Error, Result};
+use crate::protocol::{
+    Browser, BrowserContext, BrowserContextOptions, GetByRoleOptions, Locator, Page,
+};
+use regex::Regex;
+use std::fmt;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// How long `should see` / `url should contain` steps wait
+const STEP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Per-scenario browser state for BDD runners.
+///
+/// # Example
+///
+/// With the `cucumber` crate:
+///
+/// ```ignore
+/// use cucumber::{given, World};
+/// use playwright_rs::harness::BrowserWorld;
+///
+/// #[derive(Debug, World)]
+/// #[world(init = Self::new)]
+/// struct AppWorld {
+///     browser: BrowserWorld,
+/// }
+///
+/// impl AppWorld {
+///     async fn new() -> playwright_rs::Result<Self> {
+///         let browser = shared_browser().await; // launched once per run
+///         Ok(Self { browser: BrowserWorld::new(&browser, Some(options())).await? })
+///     }
+/// }
+///
+/// // Built-in phrases: I go to "...", I click "...", I fill "..." with "...", ...
+/// #[given(regex = r"^(I .+|the URL .+)$")]
+/// async fn builtin(world: &mut AppWorld, step: String) {
+///     assert!(world.browser.run_step(&step).await.unwrap(), "Unknown step: {}", step);
+/// }
+/// ```
+pub struct BrowserWorld {
+    browser: Browser,
+    options: Option<BrowserContextOptions>,
+    context: BrowserContext,
+    page: Page,
+}
+
+impl BrowserWorld {
+    /// Opens a fresh context (created with `options`) and page on `browser`
+    pub async fn new(browser: &Browser, options: Option<BrowserContextOptions>) -> Result<Self> {
+        let (context, page) = open(browser, options.clone()).await?;
+        Ok(Self {
+            browser: browser.clone(),
+            options,
+            context,
+            page,
+        })
+    }
+
+    /// The scenario's page
+    pub fn page(&self) -> &Page {
+        &self.page
+    }
+
+    /// The scenario's browser context
+    pub fn context(&self) -> &BrowserContext {
+        &self.context
+    }
+
+    /// Closes the context and starts over with a fresh one, e.g. between scenarios
+    pub async fn reset(&mut self) -> Result<()> {
+        self.context.close().await?;
+        let (context, page) = open(&self.browser, self.options.clone()).await?;
+        self.context = context;
+        self.page = page;
+        Ok(())
+    }
+
+    /// Closes the scenario's context
+    pub async fn close(self) -> Result<()> {
+        self.context.close().await
+    }
+
+    /// Navigates to `url` (relative URLs resolve against the context's `base_url`)
+    pub async fn go_to(&self, url: &str) -> Result<()> {
+        self.page.goto(url, None).await.map(|_| ())
+    }
+
+    /// Clicks the button or link named `name`, falling back to any element with that text
+    pub async fn click(&self, name: &str) -> Result<()> {
+        self.find_clickable(name).await?.click(None).await
+    }
+
+    /// Fills the field labelled `field` (or with that placeholder) with `value`
+    pub async fn fill(&self, field: &str, value: &str) -> Result<()> {
+        let mut locator = self.page.get_by_label(field, false);
+        if locator.count().await? == 0 {
+            locator = self.page.get_by_placeholder(field, false);
+        }
+        locator.first().fill(value, None).await
+    }
+
+    /// Presses `key` on the focused element
+    pub async fn press(&self, key: &str) -> Result<()> {
+        self.page.keyboard().press(key, None).await
+    }
+
+    /// Waits until `text` is visible on the page
+    pub async fn should_see(&self, text: &str) -> Result<()> {
+        let locator = self.page.get_by_text(text, false).first();
+        expect(locator)
+            .with_timeout(STEP_TIMEOUT)
+            .to_be_visible()
+            .await
+    }
+
+    /// Waits until no element showing `text` is visible
+    pub async fn should_not_see(&self, text: &str) -> Result<()> {
+        let locator = self.page.get_by_text(text, false).first();
+        expect(locator)
+            .with_timeout(STEP_TIMEOUT)
+            .to_be_hidden()
+            .await
+    }
+
+    /// Waits until the page URL contains `fragment`
+    pub async fn url_should_contain(&self, fragment: &str) -> Result<()> {
+        let deadline = tokio::time::Instant::now() + STEP_TIMEOUT;
+        loop {
+            let url = self.page.url();
+            if url.contains(fragment) {
+                return Ok(());
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Err(Error::AssertionTimeout(format!(
+                    "Expected URL to contain '{}', but it was '{}'",
+                    fragment, url
+                )));
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    /// Runs a built-in step, returning `Ok(false)` if the text matches none of them.
+    ///
+    /// A leading Gherkin keyword (`Given`, `When`, `Then`, `And`, `But`) is ignored.
+    /// Supported steps:
+    ///
+    /// - `I go to "<url>"` (also `I visit` / `I am on`)
+    /// - `I click "<name>"` (also `I click on`)
+    /// - `I fill "<field>" with "<value>"` (also `I fill in`)
+    /// - `I press "<key>"`
+    /// - `I should see "<text>"` / `I should not see "<text>"`
+    /// - `the URL should contain "<fragment>"`
+    pub async fn run_step(&self, step: &str) -> Result<bool> {
+        let Some((kind, args)) = parse_step(step) else {
+            return Ok(false);
+        };
+        match kind {
+            StepKind::GoTo => self.go_to(&args[0]).await?,
+            StepKind::Click => self.click(&args[0]).await?,
+            StepKind::Fill => self.fill(&args[0], &args[1]).await?,
+            StepKind::Press => self.press(&args[0]).await?,
+            StepKind::See => self.should_see(&args[0]).await?,
+            StepKind::NotSee => self.should_not_see(&args[0]).await?,
+            StepKind::UrlContains => self.url_should_contain(&args[0]).await?,
+        }
+        Ok(true)
+    }
+
+    async fn find_clickable(&self, name: &str) -> Result<Locator> {
+        for role in ["button", "link"] {
+            let options = GetByRoleOptions::builder().name(name).build();
+            let locator = self.page.get_by_role(role, Some(options));
+            if locator.count().await? > 0 {
+                return Ok(locator.first());
+            }
+        }
+        Ok(self.page.get_by_text(name, false).first())
+    }
+}
+
+impl fmt::Debug for BrowserWorld {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BrowserWorld")
+            .field("url", &self.page.url())
+            .finish()
+    }
+}
+
+async fn open(
+    browser: &Browser,
+    options: Option<BrowserContextOptions>,
+) -> Result<(BrowserContext, Page)> {
+    let context = match options {
+        Some(options) => browser.new_context_with_options(options).await?,
+        None => browser.new_context().await?,
+    };
+    let page = context.new_page().await?;
+    Ok((context, page))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StepKind {
+    GoTo,
+    Click,
+    Fill,
+    Press,
+    See,
+    NotSee,
+    UrlContains,
+}
+
+fn steps() -> &'static [(Regex, StepKind)] {
+    static STEPS: OnceLock<Vec<(Regex, StepKind)>> = OnceLock::new();
+    STEPS.get_or_init(|| {
+        [
+            (r#"^I (?:go to|visit|am on) "(.*)"$"#, StepKind::GoTo),
+            (r#"^I click(?: on)? "(.*)"$"#, StepKind::Click),
+            (r#"^I fill(?: in)? "(.*)" with "(.*)"$"#, StepKind::Fill),
+            (r#"^I press "(.*)"$"#, StepKind::Press),
+            (r#"^I should see "(.*)"$"#, StepKind::See),
+            (r#"^I should not see "(.*)"$"#, StepKind::NotSee),
+            (r#"^the URL should contain "(.*)"$"#, StepKind::UrlContains),
+        ]
+        .into_iter()
+        .map(|(pattern, kind)| (Regex::new(pattern).expect("valid step pattern"), kind))
+        .collect()
+    })
+}
+
+fn parse_step(step: &str) -> Option<(StepKind, Vec<String>)> {
+    let mut step = step.trim();
+    for keyword in ["Given ", "When ", "Then ", "And ", "But "] {
+        if let Some(rest) = step.strip_prefix(keyword) {
+            step = rest.trim_start();
+            break;
+        }
+    }
+    steps().iter().find_map(|(regex, kind)| {
+        regex.captures(step).map(|captures| {
+            let args = captures
+                .iter()
+                .skip(1)
+                .map(|m| m.map_or(String::new(), |m| m.as_str().to_string()))
+                .collect();
+            (*kind, args)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_step() {
+        assert_eq!(
+            parse_step(r#"Given I am on "/login""#),
+            Some((StepKind::GoTo, vec!["/login".to_string()]))
+        );
+        assert_eq!(
+            parse_step(r#"When I fill in "Email" with "ada@example.com""#),
+            Some((
+                StepKind::Fill,
+                vec!["Email".to_string(), "ada@example.com".to_string()]
+            ))
+        );
+        assert_eq!(
+            parse_step(r#"And I click on "Sign in""#),
+            Some((StepKind::Click, vec!["Sign in".to_string()]))
+        );
+        assert_eq!(
+            parse_step(r#"Then I should not see "Error""#),
+            Some((StepKind::NotSee, vec!["Error".to_string()]))
+        );
+        assert_eq!(
+            parse_step(r#"the URL should contain "/dashboard""#),
+            Some((StepKind::UrlContains, vec!["/dashboard".to_string()]))
+        );
+        assert_eq!(parse_step("I do something custom"), None);
+    }
+}
@@ -0,0 +1,211 @@
+// Global setup and teardown
+//
+// Work that should happen once per test run rather than once per test:
+// seeding a database, logging in and saving storage state, starting a shared
+// service. A `static GlobalSetup<T>` runs its setup the first time any test
+// asks for it and hands every test the same `Arc<T>`. Teardowns run once when
+// the test process exits, after all tests have finished.
+//
+// Cargo runs each integration test file as its own process, so "once per run"
+// means once per test binary. Every #[tokio::test] also has its own runtime:
+// T should hold plain data (paths, URLs, ids), not live browser handles, and
+// teardowns run on a fresh runtime at exit.
+
+use crate::error::Result;
+use parking_lot::Mutex;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Once};
+use tokio::sync::OnceCell;
+
+/// A teardown waiting for process exit
+type Teardown = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send>;
+
+static TEARDOWNS: Mutex<Vec<Teardown>> = Mutex::new(Vec::new());
+static REGISTER_EXIT_HOOK: Once = Once::new();
+
+/// Once-per-run setup whose result is shared by every test.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::harness::GlobalSetup;
+///
+/// struct Fixtures {
+///     storage_state: std::path::PathBuf,
+/// }
+///
+/// static FIXTURES: GlobalSetup<Fixtures> = GlobalSetup::new();
+///
+/// async fn fixtures() -> playwright_rs::Result<std::sync::Arc<Fixtures>> {
+///     FIXTURES
+///         .get_or_init_with_teardown(
+///             || async {
+///                 seed_database().await?;
+///                 let storage_state = log_in_and_save_state().await?;
+///                 Ok(Fixtures { storage_state })
+///             },
+///             |fixtures| async move {
+///                 let _ = std::fs::remove_file(&fixtures.storage_state);
+///             },
+///         )
+///         .await
+/// }
+///
+/// #[tokio::test]
+/// async fn dashboard_loads() -> playwright_rs::Result<()> {
+///     let fixtures = fixtures().await?;
+///     // ... create a context from fixtures.storage_state
+///     Ok(())
+/// }
+/// ```
+pub struct GlobalSetup<T> {
+    value: OnceCell<Arc<T>>,
+}
+
+impl<T> GlobalSetup<T> {
+    /// Creates an empty setup slot, usable in a `static`
+    pub const fn new() -> Self {
+        Self {
+            value: OnceCell::const_new(),
+        }
+    }
+
+    /// The setup result, if setup has already run successfully
+    pub fn get(&self) -> Option<Arc<T>> {
+        self.value.get().cloned()
+    }
+}
+
+impl<T: Send + Sync + 'static> GlobalSetup<T> {
+    /// Runs `setup` the first time it is called and returns the shared result.
+    ///
+    /// Concurrent callers wait for the first setup to finish. If setup fails
+    /// the error is returned and the next caller tries again.
+    pub async fn get_or_init<F, Fut>(&self, setup: F) -> Result<Arc<T>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.value
+            .get_or_try_init(|| async { setup().await.map(Arc::new) })
+            .await
+            .cloned()
+    }
+
+    /// Like [`get_or_init`](Self::get_or_init), also registering `teardown` to
+    /// run with the result when the test process exits.
+    pub async fn get_or_init_with_teardown<F, Fut, D, DFut>(
+        &self,
+        setup: F,
+        teardown: D,
+    ) -> Result<Arc<T>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+        D: FnOnce(Arc<T>) -> DFut + Send + 'static,
+        DFut: Future<Output = ()> + Send + 'static,
+    {
+        self.value
+            .get_or_try_init(|| async {
+                let value = Arc::new(setup().await?);
+                let shared = Arc::clone(&value);
+                register_teardown(Box::new(move || Box::pin(teardown(shared))));
+                Ok(value)
+            })
+            .await
+            .cloned()
+    }
+}
+
+impl<T> Default for GlobalSetup<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Registers `teardown` to run once when the test process exits.
+///
+/// Teardowns run in reverse registration order on a fresh runtime.
+pub fn global_teardown<F, Fut>(teardown: F)
+where
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    register_teardown(Box::new(move || Box::pin(teardown())));
+}
+
+fn register_teardown(teardown: Teardown) {
+    TEARDOWNS.lock().push(teardown);
+    REGISTER_EXIT_HOOK.call_once(|| {
+        extern "C" {
+            fn atexit(callback: extern "C" fn()) -> std::os::raw::c_int;
+        }
+        // SAFETY: atexit is provided by the C runtime on every supported
+        // platform and `run_teardowns` is a plain extern "C" fn.
+        unsafe {
+            atexit(run_teardowns);
+        }
+    });
+}
+
+/// Runs pending teardowns; called at process exit
+extern "C" fn run_teardowns() {
+    let teardowns = std::mem::take(&mut *TEARDOWNS.lock());
+    if teardowns.is_empty() {
+        return;
+    }
+    // Run on a separate thread: the exiting thread may still be inside a runtime
+    let _ = std::thread::spawn(move || {
+        let runtime = match tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        {
+            Ok(runtime) => runtime,
+            Err(e) => {
+                tracing::error!("Failed to start runtime for global teardown: {}", e);
+                return;
+            }
+        };
+        runtime.block_on(async {
+            for teardown in teardowns.into_iter().rev() {
+                teardown().await;
+            }
+        });
+    })
+    .join();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_setup_runs_once() {
+        static SETUP: GlobalSetup<String> = GlobalSetup::new();
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        assert!(SETUP.get().is_none());
+        let setup = || async {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            Ok("seeded".to_string())
+        };
+        let (a, b) = tokio::join!(SETUP.get_or_init(setup), SETUP.get_or_init(setup));
+        assert!(Arc::ptr_eq(&a.unwrap(), &b.unwrap()));
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+        assert_eq!(SETUP.get().as_deref().map(String::as_str), Some("seeded"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_setup_is_retried() {
+        static SETUP: GlobalSetup<u32> = GlobalSetup::new();
+
+        let err = SETUP
+            .get_or_init(|| async { Err(crate::Error::InvalidArgument("database down".into())) })
+            .await;
+        assert!(err.is_err());
+        let value = SETUP.get_or_init(|| async { Ok(7) }).await.unwrap();
+        assert_eq!(*value, 7);
+    }
+}
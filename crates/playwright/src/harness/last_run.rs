@@ -0,0 +1,111 @@
+// Last-run state
+//
+// Every test run through harness::test() records whether it failed in
+// `$PLAYWRIGHT_OUTPUT_DIR/.last-run.json` (default `test-results/`), whether or
+// not a report is written. With PLAYWRIGHT_LAST_FAILED=1, or an installed
+// TestFilter::last_failed(), only the tests that failed the last time they ran
+// are selected, so a large suite can be iterated on one failure at a time.
+// A test leaves the list once it passes.
+//
+// Example: PLAYWRIGHT_LAST_FAILED=1 cargo test
+
+use crate::error::Result;
+use crate::harness::artifacts::output_root;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Environment variable selecting only the tests that failed last time
+pub const LAST_FAILED_ENV: &str = "PLAYWRIGHT_LAST_FAILED";
+
+/// File in the output directory holding the last-run state
+const LAST_RUN_FILE: &str = ".last-run.json";
+
+/// Serializes read-modify-write of the state file between concurrent tests
+static LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LastRun {
+    failed_tests: Vec<String>,
+}
+
+/// Path of the last-run state file
+pub fn last_run_file() -> PathBuf {
+    output_root().join(LAST_RUN_FILE)
+}
+
+/// Tests that failed the last time they ran, as `[project] name` or `name`
+pub fn failed_tests() -> Result<Vec<String>> {
+    read(&last_run_file()).map(|state| state.failed_tests)
+}
+
+/// Key identifying a test in the state file
+pub(crate) fn test_key(name: &str, project: Option<&str>) -> String {
+    match project {
+        Some(project) => format!("[{}] {}", project, name),
+        None => name.to_string(),
+    }
+}
+
+/// Records whether the test called `key` failed
+pub(crate) fn record(key: &str, failed: bool) {
+    let file = last_run_file();
+    let _guard = LOCK.lock();
+    if let Err(e) = update(&file, key, failed) {
+        tracing::warn!("Failed to update {}: {}", file.display(), e);
+    }
+}
+
+fn update(file: &Path, key: &str, failed: bool) -> Result<()> {
+    let mut state = read(file)?;
+    let known = state.failed_tests.iter().any(|t| t == key);
+    match (failed, known) {
+        (true, false) => state.failed_tests.push(key.to_string()),
+        (false, true) => state.failed_tests.retain(|t| t != key),
+        _ => return Ok(()),
+    }
+    if let Some(dir) = file.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(file, serde_json::to_string_pretty(&state)?)?;
+    Ok(())
+}
+
+fn read(file: &Path) -> Result<LastRun> {
+    match std::fs::read_to_string(file) {
+        Ok(content) => Ok(serde_json::from_str(&content)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(LastRun::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_tracks_failures() {
+        let file = std::env::temp_dir()
+            .join(format!("playwright_rs_last_run_{}", std::process::id()))
+            .join(LAST_RUN_FILE);
+        let failed = || read(&file).unwrap().failed_tests;
+
+        assert!(failed().is_empty());
+        update(&file, "checkout", true).unwrap();
+        update(&file, "[webkit] login", true).unwrap();
+        update(&file, "checkout", true).unwrap();
+        assert_eq!(failed(), ["checkout", "[webkit] login"]);
+        update(&file, "checkout", false).unwrap();
+        update(&file, "never failed", false).unwrap();
+        assert_eq!(failed(), ["[webkit] login"]);
+
+        std::fs::remove_dir_all(file.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_test_key() {
+        assert_eq!(test_key("login", None), "login");
+        assert_eq!(test_key("login", Some("webkit")), "[webkit] login");
+    }
+}
@@ -0,0 +1,146 @@
+// Test listing (dry run)
+//
+// Every selected harness::test() registers its metadata - name, project, tags,
+// annotations and the URLs and selectors it declares with TestCase::url() /
+// TestCase::selector() - in a process-wide registry. With PLAYWRIGHT_LIST=1 the
+// bodies are not run: each test is printed instead and, when
+// PLAYWRIGHT_REPORT_DIR is set, appended to `list.jsonl` there, so CI
+// partitioning tools and coverage audits can see the suite without a browser.
+//
+// Examples:
+//
+//     PLAYWRIGHT_LIST=1 cargo test -- --nocapture
+//     cargo playwright list --json                # collects list.jsonl for you
+
+use crate::error::Result;
+use crate::harness::report::{self, Annotation};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Environment variable switching the harness to listing tests instead of running them
+pub const LIST_ENV: &str = "PLAYWRIGHT_LIST";
+
+/// File in the report directory holding one JSON listing per line
+const LIST_FILE: &str = "list.jsonl";
+
+static REGISTRY: Mutex<Vec<TestListing>> = Mutex::new(Vec::new());
+
+/// Metadata of a test, as registered by [`TestCase::run`](crate::harness::TestCase::run)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestListing {
+    /// Test name
+    pub name: String,
+    /// Project the test runs in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Tags, without the leading `@`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Annotations, including skip/fixme/slow
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Annotation>,
+    /// URLs the test declares it visits
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<String>,
+    /// Selectors the test declares it uses
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selectors: Vec<String>,
+}
+
+impl TestListing {
+    /// One-line summary, e.g. `[webkit] checkout @smoke (/cart, /checkout)`
+    pub fn summary(&self) -> String {
+        let mut line = String::new();
+        if let Some(project) = &self.project {
+            line.push_str(&format!("[{}] ", project));
+        }
+        line.push_str(&self.name);
+        for tag in &self.tags {
+            line.push_str(&format!(" @{}", tag));
+        }
+        if !self.urls.is_empty() {
+            line.push_str(&format!(" ({})", self.urls.join(", ")));
+        }
+        line
+    }
+}
+
+/// Whether `PLAYWRIGHT_LIST` asks for listing instead of running (`1` or `true`)
+pub fn is_listing() -> bool {
+    matches!(std::env::var(LIST_ENV).as_deref(), Ok("1" | "true"))
+}
+
+/// Tests registered so far by this process, in registration order
+pub fn registered_tests() -> Vec<TestListing> {
+    REGISTRY.lock().clone()
+}
+
+/// Registers `listing`; in list mode also prints it and appends it to the report directory
+pub(crate) fn register(listing: TestListing, listing_mode: bool) {
+    if listing_mode {
+        println!("{}", listing.summary());
+        if let Some(dir) = report::report_dir() {
+            if let Err(e) = report::append_json(&dir, LIST_FILE, &listing) {
+                tracing::warn!("Failed to record test listing in {}: {}", dir.display(), e);
+            }
+        }
+    }
+    let mut registry = REGISTRY.lock();
+    if !registry.contains(&listing) {
+        registry.push(listing);
+    }
+}
+
+/// Reads the listings recorded in `dir`; a missing file yields none
+pub fn read_listings(dir: &Path) -> Result<Vec<TestListing>> {
+    report::read_json_lines(&dir.join(LIST_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing(name: &str) -> TestListing {
+        TestListing {
+            name: name.to_string(),
+            project: Some("webkit".to_string()),
+            tags: vec!["smoke".to_string()],
+            annotations: Vec::new(),
+            urls: vec!["/cart".to_string(), "/checkout".to_string()],
+            selectors: vec!["#pay".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        assert_eq!(
+            listing("checkout").summary(),
+            "[webkit] checkout @smoke (/cart, /checkout)"
+        );
+    }
+
+    #[test]
+    fn test_register_deduplicates() {
+        let name = format!("listed {}", std::process::id());
+        register(listing(&name), false);
+        register(listing(&name), false);
+        let count = registered_tests().iter().filter(|l| l.name == name).count();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_read_listings() {
+        let dir = std::env::temp_dir().join(format!("playwright_rs_list_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(read_listings(&dir).unwrap().is_empty());
+
+        report::append_json(&dir, LIST_FILE, &listing("a")).unwrap();
+        report::append_json(&dir, LIST_FILE, &listing("b")).unwrap();
+        let listings = read_listings(&dir).unwrap();
+        assert_eq!(listings.len(), 2);
+        assert_eq!(listings[1], listing("b"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,132 @@
+// Multi-browser matrix runner
+//
+// Runs a test body once per browser type, honoring the BROWSERS environment
+// variable so CI jobs and local runs can narrow the matrix without code changes.
+//
+// Example: BROWSERS=chromium,webkit cargo test
+
+use crate::error::{Error, Result};
+use crate::protocol::{BrowserType, Playwright};
+use std::future::Future;
+
+/// Environment variable used to filter the browser matrix.
+///
+/// A comma-separated list of browser names (`chromium`, `firefox`, `webkit`).
+/// When unset or empty, all three browsers are used.
+pub const BROWSERS_ENV: &str = "BROWSERS";
+
+/// All browser names supported by Playwright, in matrix order
+pub(crate) const ALL_BROWSERS: [&str; 3] = ["chromium", "firefox", "webkit"];
+
+/// Returns the browser names selected by the `BROWSERS` environment variable.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidArgument` if the variable names an unknown browser.
+pub fn selected_browsers() -> Result<Vec<&'static str>> {
+    parse_browsers(std::env::var(BROWSERS_ENV).ok().as_deref())
+}
+
+/// Parses a `BROWSERS` filter value into browser names.
+fn parse_browsers(filter: Option<&str>) -> Result<Vec<&'static str>> {
+    let filter = match filter.map(str::trim) {
+        Some(f) if !f.is_empty() => f,
+        _ => return Ok(ALL_BROWSERS.to_vec()),
+    };
+
+    let mut selected = Vec::new();
+    for name in filter.split(',').map(|n| n.trim().to_ascii_lowercase()) {
+        if name.is_empty() {
+            continue;
+        }
+        let browser = ALL_BROWSERS.iter().find(|b| **b == name).ok_or_else(|| {
+            Error::InvalidArgument(format!(
+                "Unknown browser '{}' in {}; expected one of: {}",
+                name,
+                BROWSERS_ENV,
+                ALL_BROWSERS.join(", ")
+            ))
+        })?;
+        if !selected.contains(browser) {
+            selected.push(*browser);
+        }
+    }
+
+    Ok(selected)
+}
+
+/// Runs `f` once for each browser type selected by the `BROWSERS` environment variable.
+///
+/// Browsers run sequentially in the order chromium, firefox, webkit. The first
+/// error stops the matrix and is returned; the failing browser is logged.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::harness::for_each_browser;
+/// use playwright_rs::Playwright;
+///
+/// #[tokio::test]
+/// async fn title_is_set() -> playwright_rs::Result<()> {
+///     let playwright = Playwright::launch().await?;
+///     for_each_browser(&playwright, |browser_type| async move {
+///         let browser = browser_type.launch().await?;
+///         let page = browser.new_page().await?;
+///         page.goto("data:text/html,<title>Hi</title>", None).await?;
+///         assert_eq!(page.title().await?, "Hi");
+///         browser.close().await
+///     })
+///     .await
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns the first error produced by `f`, or `Error::InvalidArgument` if
+/// `BROWSERS` contains an unknown browser name.
+pub async fn for_each_browser<'a, F, Fut>(playwright: &'a Playwright, mut f: F) -> Result<()>
+where
+    F: FnMut(&'a BrowserType) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    for name in selected_browsers()? {
+        let browser_type = match name {
+            "chromium" => playwright.chromium(),
+            "firefox" => playwright.firefox(),
+            _ => playwright.webkit(),
+        };
+
+        tracing::debug!("Running browser matrix entry: {}", name);
+        if let Err(e) = f(browser_type).await {
+            tracing::error!("Browser matrix entry '{}' failed: {}", name, e);
+            return Err(e);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_browsers_defaults_to_all() {
+        assert_eq!(parse_browsers(None).unwrap(), ALL_BROWSERS.to_vec());
+        assert_eq!(parse_browsers(Some("  ")).unwrap(), ALL_BROWSERS.to_vec());
+    }
+
+    #[test]
+    fn test_parse_browsers_filter() {
+        assert_eq!(
+            parse_browsers(Some("WebKit, chromium,,webkit")).unwrap(),
+            vec!["webkit", "chromium"]
+        );
+    }
+
+    #[test]
+    fn test_parse_browsers_unknown() {
+        let err = parse_browsers(Some("chromium,safari")).unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(msg) if msg.contains("safari")));
+    }
+}
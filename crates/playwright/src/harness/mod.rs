@@ -0,0 +1,36 @@
+// Test harness utilities
+//
+// Helpers for writing browser tests with playwright-rs: running the same test
+// body against several browsers or configured projects, tagged and annotated
+// tests with steps, timeouts, retries, last-failed selection, dry-run listing,
+// JSON/HTML reports and trace/video/screenshot retention, once-per-run
+// setup/teardown, booting the app under test in-process, BDD world fixtures,
+// and other boilerplate that the crate's own integration tests repeat.
+//
+// These helpers are optional - they only build on the public API.
+
+pub mod artifacts;
+pub mod bdd;
+pub mod global;
+pub mod last_run;
+pub mod listing;
+pub mod matrix;
+pub mod projects;
+#[cfg(feature = "proptest")]
+pub mod property;
+pub mod report;
+pub mod step;
+pub mod test_app;
+pub mod test_case;
+pub mod timeout;
+
+pub use artifacts::{ArtifactMode, ArtifactPolicy};
+pub use bdd::BrowserWorld;
+pub use global::{global_teardown, GlobalSetup};
+pub use listing::TestListing;
+pub use matrix::{for_each_browser, selected_browsers, BROWSERS_ENV};
+pub use projects::{for_each_project, Project, TestConfig};
+pub use report::{Annotation, StepResult, TestResult, TestStatus};
+pub use step::step;
+pub use test_app::{Shutdown, TestApp};
+pub use test_case::{test, TestCase, TestFilter, TestInfo};
@@ -0,0 +1,347 @@
+// Browser projects
+//
+// A project is a named browser + context configuration ("chromium-desktop",
+// "webkit-mobile", ...) declared in `playwright.toml`. for_each_project()
+// launches each selected project's browser and runs the test body against it;
+// tests run with TestCase::project() are reported under the project's name and
+// get the project's context options from TestInfo::new_context().
+//
+//     # playwright.toml
+//     [[projects]]
+//     name = "chromium-desktop"
+//     browser = "chromium"
+//     use = { viewport = { width = 1280, height = 720 } }
+//
+//     [[projects]]
+//     name = "webkit-mobile"
+//     browser = "webkit"
+//     [projects.use]
+//     viewport = { width = 390, height = 844 }
+//     is_mobile = true
+//     has_touch = true
+//
+// The `use` table takes the fields of a ContextProfile. The config is read from
+// $PLAYWRIGHT_CONFIG, else `playwright.toml` in the working directory (the
+// package root under `cargo test`); without one there is a project per browser
+// selected by BROWSERS.
+//
+// Example: PLAYWRIGHT_PROJECT=webkit-mobile cargo test
+
+use crate::api::{ContextProfile, LaunchOptions};
+use crate::error::{Error, Result};
+use crate::harness::matrix::{selected_browsers, ALL_BROWSERS};
+use crate::protocol::{Browser, BrowserContextOptions, BrowserType, Playwright};
+use serde::Deserialize;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+
+/// Environment variable with the path of the config file
+pub const CONFIG_ENV: &str = "PLAYWRIGHT_CONFIG";
+
+/// Environment variable selecting projects by name (comma-separated)
+pub const PROJECT_ENV: &str = "PLAYWRIGHT_PROJECT";
+
+/// Config file used when `PLAYWRIGHT_CONFIG` is not set
+const DEFAULT_CONFIG_FILE: &str = "playwright.toml";
+
+/// A named browser and context configuration to run tests against
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Project {
+    /// Project name, e.g. `"webkit-mobile"`
+    pub name: String,
+    /// Browser to launch: `chromium`, `firefox` or `webkit`
+    #[serde(default = "default_browser")]
+    pub browser: String,
+    /// Browser distribution channel, e.g. `"chrome"` or `"msedge"`
+    #[serde(default)]
+    pub channel: Option<String>,
+    /// Whether to launch headless (Playwright's default when unset)
+    #[serde(default)]
+    pub headless: Option<bool>,
+    /// Options for contexts opened with `TestInfo::new_context`
+    #[serde(default, rename = "use")]
+    pub context: ContextProfile,
+}
+
+fn default_browser() -> String {
+    "chromium".to_string()
+}
+
+impl Project {
+    /// Creates a project running `browser` with default context options
+    pub fn new(name: impl Into<String>, browser: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            browser: browser.into(),
+            channel: None,
+            headless: None,
+            context: ContextProfile::default(),
+        }
+    }
+
+    /// Sets the context options
+    pub fn context(mut self, profile: ContextProfile) -> Self {
+        self.context = profile;
+        self
+    }
+
+    /// The project's browser type
+    pub fn browser_type<'a>(&self, playwright: &'a Playwright) -> &'a BrowserType {
+        match self.browser.as_str() {
+            "firefox" => playwright.firefox(),
+            "webkit" => playwright.webkit(),
+            _ => playwright.chromium(),
+        }
+    }
+
+    /// Launches the project's browser
+    pub async fn launch(&self, playwright: &Playwright) -> Result<Browser> {
+        let mut options = LaunchOptions::new();
+        if let Some(channel) = &self.channel {
+            options = options.channel(channel.clone());
+        }
+        if let Some(headless) = self.headless {
+            options = options.headless(headless);
+        }
+        self.browser_type(playwright)
+            .launch_with_options(options)
+            .await
+    }
+
+    /// Options for the project's browser contexts
+    pub fn context_options(&self) -> BrowserContextOptions {
+        self.context.to_context_options()
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(Error::InvalidArgument(
+                "Project name must not be empty".to_string(),
+            ));
+        }
+        if !ALL_BROWSERS.contains(&self.browser.as_str()) {
+            return Err(Error::InvalidArgument(format!(
+                "Unknown browser '{}' in project '{}'; expected one of: {}",
+                self.browser,
+                self.name,
+                ALL_BROWSERS.join(", ")
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Projects declared in `playwright.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TestConfig {
+    /// Projects in run order
+    pub projects: Vec<Project>,
+}
+
+impl TestConfig {
+    /// Parses a config from TOML
+    pub fn from_toml_str(s: &str) -> Result<Self> {
+        let config: Self = toml::from_str(s)
+            .map_err(|e| Error::InvalidArgument(format!("Invalid test config: {}", e)))?;
+        for (i, project) in config.projects.iter().enumerate() {
+            project.validate()?;
+            if config.projects[..i].iter().any(|p| p.name == project.name) {
+                return Err(Error::InvalidArgument(format!(
+                    "Duplicate project name '{}'",
+                    project.name
+                )));
+            }
+        }
+        Ok(config)
+    }
+
+    /// Loads a config from a TOML file
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = tokio::fs::read_to_string(path).await.map_err(|e| {
+            Error::InvalidArgument(format!(
+                "Failed to read test config '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Self::from_toml_str(&content)
+    }
+
+    /// Loads `$PLAYWRIGHT_CONFIG` or `./playwright.toml`; without either,
+    /// one project per browser selected by `BROWSERS`
+    pub async fn from_env() -> Result<Self> {
+        let path = match std::env::var_os(CONFIG_ENV).filter(|p| !p.is_empty()) {
+            Some(path) => PathBuf::from(path),
+            None if Path::new(DEFAULT_CONFIG_FILE).exists() => PathBuf::from(DEFAULT_CONFIG_FILE),
+            None => {
+                return Ok(Self {
+                    projects: selected_browsers()?
+                        .into_iter()
+                        .map(|browser| Project::new(browser, browser))
+                        .collect(),
+                })
+            }
+        };
+        Self::load(path).await
+    }
+
+    /// Projects selected by `PLAYWRIGHT_PROJECT` (all when unset)
+    pub fn selected_projects(&self) -> Result<Vec<&Project>> {
+        self.filter_projects(std::env::var(PROJECT_ENV).ok().as_deref())
+    }
+
+    fn filter_projects(&self, filter: Option<&str>) -> Result<Vec<&Project>> {
+        let filter = match filter.map(str::trim) {
+            Some(f) if !f.is_empty() => f,
+            _ => return Ok(self.projects.iter().collect()),
+        };
+        let mut selected: Vec<&Project> = Vec::new();
+        for name in filter.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let project = self
+                .projects
+                .iter()
+                .find(|p| p.name == name)
+                .ok_or_else(|| {
+                    Error::InvalidArgument(format!(
+                        "Unknown project '{}' in {}; configured projects: {}",
+                        name,
+                        PROJECT_ENV,
+                        self.projects
+                            .iter()
+                            .map(|p| p.name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))
+                })?;
+            if !selected.iter().any(|p| p.name == project.name) {
+                selected.push(project);
+            }
+        }
+        Ok(selected)
+    }
+}
+
+/// Runs `f` once per selected project with the project's browser.
+///
+/// Projects run sequentially in config order. Each browser is closed after its
+/// run; the first error stops the run and is returned.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::harness::{self, for_each_project};
+///
+/// #[tokio::test]
+/// async fn checkout() -> playwright_rs::Result<()> {
+///     let playwright = Playwright::launch().await?;
+///     for_each_project(&playwright, |project, browser| async move {
+///         harness::test("checkout completes")
+///             .project(&project)
+///             .run(|info| {
+///                 let browser = browser.clone();
+///                 async move {
+///                     // Uses the project's viewport, locale, ...
+///                     let page = info.new_context(&browser, None).await?.new_page().await?;
+///                     // ...
+///                     Ok(())
+///                 }
+///             })
+///             .await
+///     })
+///     .await
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns the first error produced by `f` or a launch, or
+/// `Error::InvalidArgument` for an invalid config or unknown project name.
+pub async fn for_each_project<F, Fut>(playwright: &Playwright, mut f: F) -> Result<()>
+where
+    F: FnMut(Project, Browser) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let config = TestConfig::from_env().await?;
+    for project in config.selected_projects()? {
+        tracing::debug!("Running project: {}", project.name);
+        let browser = project.launch(playwright).await?;
+        let outcome = f(project.clone(), browser.clone()).await;
+        if let Err(e) = browser.close().await {
+            tracing::debug!(
+                "Failed to close browser of project '{}': {}",
+                project.name,
+                e
+            );
+        }
+        if let Err(e) = outcome {
+            tracing::error!("Project '{}' failed: {}", project.name, e);
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::Viewport;
+
+    const CONFIG: &str = r#"
+        [[projects]]
+        name = "chromium-desktop"
+        use = { viewport = { width = 1280, height = 720 } }
+
+        [[projects]]
+        name = "webkit-mobile"
+        browser = "webkit"
+        [projects.use]
+        is_mobile = true
+        has_touch = true
+    "#;
+
+    #[test]
+    fn test_parse_config() {
+        let config = TestConfig::from_toml_str(CONFIG).unwrap();
+        assert_eq!(config.projects.len(), 2);
+        let desktop = &config.projects[0];
+        assert_eq!(desktop.browser, "chromium");
+        assert_eq!(
+            desktop.context.viewport,
+            Some(Viewport {
+                width: 1280,
+                height: 720
+            })
+        );
+        let mobile = &config.projects[1];
+        assert_eq!(mobile.browser, "webkit");
+        assert_eq!(mobile.context.is_mobile, Some(true));
+        assert_eq!(mobile.context_options().has_touch, Some(true));
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let unknown_browser = "[[projects]]\nname = \"safari\"\nbrowser = \"safari\"";
+        assert!(matches!(
+            TestConfig::from_toml_str(unknown_browser),
+            Err(Error::InvalidArgument(msg)) if msg.contains("safari")
+        ));
+        let duplicate = "[[projects]]\nname = \"a\"\n[[projects]]\nname = \"a\"";
+        assert!(TestConfig::from_toml_str(duplicate).is_err());
+        assert!(TestConfig::from_toml_str("[[projects]]\nbrowser = \"webkit\"").is_err());
+    }
+
+    #[test]
+    fn test_filter_projects() {
+        let config = TestConfig::from_toml_str(CONFIG).unwrap();
+        assert_eq!(config.filter_projects(None).unwrap().len(), 2);
+        let selected = config
+            .filter_projects(Some("webkit-mobile, webkit-mobile"))
+            .unwrap();
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].name, "webkit-mobile");
+        assert!(config.filter_projects(Some("firefox")).is_err());
+    }
+}
@@ -0,0 +1,330 @@
+// Property-based UI testing (requires the `proptest` feature)
+//
+// Generates hostile input strings and random interaction sequences, drives them
+// through locators, and shrinks failures to a minimal reproduction. proptest's
+// own runner is synchronous, so check() runs the generate/shrink loop itself
+// and awaits the test body between steps - no nested runtime is needed.
+//
+// Example:
+//
+//     let selectors = vec!["#name".to_string(), "#email".to_string(), "#submit".to_string()];
+//     check(Config::with_cases(32), interactions(selectors, 1..8), |steps| async {
+//         page.goto(&form_url, None).await?;
+//         for step in &steps {
+//             step.apply(&page).await?;
+//         }
+//         let error_banner = page.locator(".crash").count().await?;
+//         if error_banner > 0 { return Err("form crashed".into()); }
+//         Ok(())
+//     })
+//     .await?;
+
+use crate::error::Result;
+use crate::protocol::Page;
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use std::fmt;
+use std::future::Future;
+use std::ops::Range;
+
+pub use proptest::test_runner::Config;
+
+/// Inputs that commonly break form handling
+const EDGE_CASES: &[&str] = &[
+    "",
+    " ",
+    "  padded  ",
+    "0",
+    "-1",
+    "1e309",
+    "NaN",
+    "null",
+    "undefined",
+    "' OR '1'='1",
+    "\"; DROP TABLE users; --",
+    "<script>alert(1)</script>",
+    "<img src=x onerror=alert(1)>",
+    "${7*7}{{7*7}}",
+    "\u{202e}gnp.exe",
+    "e\u{0301}\u{0301}\u{0301}",
+    "😀👍🏽👨‍👩‍👧",
+    "𝔘𝔫𝔦𝔠𝔬𝔡𝔢",
+    "日本語テキスト",
+    "line one\nline two",
+    "tab\tseparated",
+];
+
+/// Keys pressed by [`Action::Press`]
+const KEYS: &[&str] = &[
+    "Enter",
+    "Tab",
+    "Escape",
+    "Backspace",
+    "Delete",
+    "ArrowLeft",
+    "ArrowRight",
+    "Home",
+    "End",
+    "Control+a",
+];
+
+/// Strings likely to expose input handling bugs.
+///
+/// Mixes known edge cases (injection payloads, combining characters, RTL
+/// overrides, emoji), whitespace, arbitrary printable Unicode and long inputs.
+pub fn tricky_text() -> impl Strategy<Value = String> {
+    prop_oneof![
+        3 => proptest::sample::select(EDGE_CASES).prop_map(String::from),
+        2 => "\\PC{0,32}",
+        2 => "[a-zA-Z0-9 ]{0,16}",
+        1 => "[ \t\n]{1,4}",
+        1 => (256usize..4096).prop_map(|len| "x".repeat(len)),
+    ]
+}
+
+/// Something done to an element
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// `locator.fill(text)`
+    Fill(String),
+    /// `locator.press(key)`
+    Press(String),
+    /// `locator.click()`
+    Click,
+    /// `locator.clear()`
+    Clear,
+}
+
+/// Random actions, weighted towards filling in text
+pub fn action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        4 => tricky_text().prop_map(Action::Fill),
+        2 => proptest::sample::select(KEYS).prop_map(|key| Action::Press(key.to_string())),
+        1 => Just(Action::Click),
+        1 => Just(Action::Clear),
+    ]
+}
+
+/// An action applied to the element matching `selector`.
+///
+/// Displays as the Rust code that performs it, so a shrunk failure can be
+/// pasted into a regular test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interaction {
+    /// Selector passed to `page.locator()`
+    pub selector: String,
+    /// What to do with the element
+    pub action: Action,
+}
+
+impl Interaction {
+    /// Performs the interaction on `page`
+    pub async fn apply(&self, page: &Page) -> Result<()> {
+        let locator = page.locator(&self.selector);
+        match &self.action {
+            Action::Fill(text) => locator.fill(text, None).await,
+            Action::Press(key) => locator.press(key, None).await,
+            Action::Click => locator.click(None).await,
+            Action::Clear => locator.clear(None).await,
+        }
+    }
+}
+
+impl fmt::Display for Interaction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "page.locator({:?}).", self.selector)?;
+        match &self.action {
+            Action::Fill(text) => write!(f, "fill({:?}, None)", text)?,
+            Action::Press(key) => write!(f, "press({:?}, None)", key)?,
+            Action::Click => f.write_str("click(None)")?,
+            Action::Clear => f.write_str("clear(None)")?,
+        }
+        f.write_str(".await?;")
+    }
+}
+
+/// Sequences of `len` interactions with elements picked from `selectors`.
+///
+/// # Panics
+///
+/// Generating values panics if `selectors` is empty.
+pub fn interactions(
+    selectors: Vec<String>,
+    len: Range<usize>,
+) -> impl Strategy<Value = Vec<Interaction>> {
+    proptest::collection::vec(
+        (proptest::sample::select(selectors), action())
+            .prop_map(|(selector, action)| Interaction { selector, action }),
+        len,
+    )
+}
+
+/// Rust code replaying `interactions`, one statement per line
+pub fn reproduction(interactions: &[Interaction]) -> String {
+    interactions
+        .iter()
+        .map(|step| step.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Result of one test case; any error (including [`crate::Error`]) fails it
+pub type CaseResult = std::result::Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+/// A property that failed, with the smallest input that still fails.
+#[derive(Debug)]
+pub struct PropertyFailure<T> {
+    /// Minimal failing input after shrinking
+    pub minimal: T,
+    /// Error returned for the minimal input
+    pub error: Box<dyn std::error::Error + Send + Sync>,
+    /// Cases that passed before the failure
+    pub passed: u32,
+    /// Shrink steps taken
+    pub shrink_steps: u32,
+}
+
+impl<T: fmt::Debug> fmt::Display for PropertyFailure<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Property failed after {} passing case(s): {}\nMinimal input ({} shrink step(s)): {:#?}",
+            self.passed, self.error, self.shrink_steps, self.minimal
+        )
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for PropertyFailure<T> {}
+
+/// Runs `test` against values generated by `strategy`, shrinking the first failure.
+///
+/// Up to `config.cases` values are tried; on failure the input is simplified for
+/// up to `config.max_shrink_iters` steps, keeping the smallest value that still
+/// fails. Test bodies should reset page state (e.g. navigate) at the start of
+/// each case. Panics in `test` are not caught.
+///
+/// # Errors
+///
+/// Returns a [`PropertyFailure`] holding the minimal failing input.
+pub async fn check<S, F, Fut>(
+    config: Config,
+    strategy: S,
+    mut test: F,
+) -> std::result::Result<(), PropertyFailure<S::Value>>
+where
+    S: Strategy,
+    F: FnMut(S::Value) -> Fut,
+    Fut: Future<Output = CaseResult>,
+{
+    let max_shrink_iters = config.max_shrink_iters;
+    let cases = config.cases;
+    let mut runner = TestRunner::new(config);
+
+    for passed in 0..cases {
+        let mut tree = match strategy.new_tree(&mut runner) {
+            Ok(tree) => tree,
+            // Strategy rejected too many values; nothing more to try
+            Err(_) => return Ok(()),
+        };
+        let error = match test(tree.current()).await {
+            Ok(()) => continue,
+            Err(error) => error,
+        };
+
+        let mut failure = PropertyFailure {
+            minimal: tree.current(),
+            error,
+            passed,
+            shrink_steps: 0,
+        };
+        while failure.shrink_steps < max_shrink_iters && tree.simplify() {
+            loop {
+                failure.shrink_steps += 1;
+                match test(tree.current()).await {
+                    Err(error) => {
+                        failure.minimal = tree.current();
+                        failure.error = error;
+                        break;
+                    }
+                    Ok(()) => {
+                        if failure.shrink_steps >= max_shrink_iters || !tree.complicate() {
+                            return Err(failure);
+                        }
+                    }
+                }
+            }
+        }
+        return Err(failure);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_check_passes() {
+        let result = check(Config::with_cases(16), 0u32..100, |n| async move {
+            assert!(n < 100);
+            Ok(())
+        })
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_check_shrinks_to_minimal_failure() {
+        let failure = check(Config::with_cases(256), 0u32..10_000, |n| async move {
+            if n >= 42 {
+                Err(format!("{} is too big", n).into())
+            } else {
+                Ok(())
+            }
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(failure.minimal, 42);
+        assert_eq!(failure.error.to_string(), "42 is too big");
+    }
+
+    #[tokio::test]
+    async fn test_shrinks_interaction_sequences() {
+        let selectors = vec!["#a".to_string(), "#b".to_string()];
+        let failure = check(
+            Config::with_cases(256),
+            interactions(selectors, 1..10),
+            |steps| async move {
+                if steps.iter().any(|s| s.action == Action::Click) {
+                    Err("clicked".into())
+                } else {
+                    Ok(())
+                }
+            },
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(failure.minimal.len(), 1);
+        assert_eq!(failure.minimal[0].action, Action::Click);
+    }
+
+    #[test]
+    fn test_reproduction() {
+        let steps = vec![
+            Interaction {
+                selector: "#email".into(),
+                action: Action::Fill("a\"b\n".into()),
+            },
+            Interaction {
+                selector: "#email".into(),
+                action: Action::Press("Enter".into()),
+            },
+        ];
+        assert_eq!(
+            reproduction(&steps),
+            "page.locator(\"#email\").fill(\"a\\\"b\\n\", None).await?;\n\
+             page.locator(\"#email\").press(\"Enter\", None).await?;"
+        );
+    }
+}
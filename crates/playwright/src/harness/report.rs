@@ -0,0 +1,440 @@
+// Test reports
+//
+// Tests run through harness::test() append one JSON line per result to
+// `$PLAYWRIGHT_REPORT_DIR/results.jsonl`. When the test process exits, the
+// accumulated results are rendered to `report.json` and `index.html` in the
+// same directory, so every test binary of a `cargo test` run contributes to a
+// single report. Without PLAYWRIGHT_REPORT_DIR nothing is written.
+
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Once;
+
+/// Environment variable naming the report directory
+pub const REPORT_DIR_ENV: &str = "PLAYWRIGHT_REPORT_DIR";
+
+/// File holding one JSON result per line
+const RESULTS_FILE: &str = "results.jsonl";
+
+static RENDER_ON_EXIT: Once = Once::new();
+
+/// Outcome of a test
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestStatus {
+    /// The body returned `Ok`
+    Passed,
+    /// The body returned an error
+    Failed,
+    /// The test was skipped or filtered out
+    Skipped,
+}
+
+/// A test annotation such as `skip`, `fixme`, `slow` or a custom type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Annotation {
+    /// Annotation type, e.g. `"slow"` or `"issue"`
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Optional description, e.g. a skip reason or issue URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Annotation {
+    /// Creates an annotation
+    pub fn new(kind: impl Into<String>, description: Option<String>) -> Self {
+        Self {
+            kind: kind.into(),
+            description,
+        }
+    }
+}
+
+/// One test result in the report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestResult {
+    /// Test name
+    pub name: String,
+    /// Project the test ran in, e.g. `"webkit-mobile"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Outcome
+    pub status: TestStatus,
+    /// Tags, e.g. `smoke`
+    pub tags: Vec<String>,
+    /// Annotations, including skip/fixme/slow
+    pub annotations: Vec<Annotation>,
+    /// Wall-clock duration in milliseconds
+    pub duration_ms: u64,
+    /// Error message for failed tests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Retries needed before the reported attempt
+    #[serde(default)]
+    pub retry: u32,
+    /// Steps of the reported attempt
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub steps: Vec<StepResult>,
+    /// Files attached by the test and artifacts kept by the harness
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+}
+
+/// A file attached to a test result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attachment {
+    /// Display name, e.g. `"api-response.json"` or `"trace"`
+    pub name: String,
+    /// MIME type, e.g. `"application/json"`
+    pub content_type: String,
+    /// Absolute path of the attached file
+    pub path: PathBuf,
+}
+
+impl Attachment {
+    /// Creates an attachment; a relative `path` is resolved against the
+    /// current directory so the report can link to it from anywhere
+    pub fn new(
+        name: impl Into<String>,
+        content_type: impl Into<String>,
+        path: impl Into<PathBuf>,
+    ) -> Self {
+        let path = path.into();
+        let path = if path.is_relative() {
+            std::env::current_dir()
+                .map(|cwd| cwd.join(&path))
+                .unwrap_or(path)
+        } else {
+            path
+        };
+        Self {
+            name: name.into(),
+            content_type: content_type.into(),
+            path,
+        }
+    }
+}
+
+/// A [`step`](crate::harness::step) in the report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepResult {
+    /// Step title
+    pub title: String,
+    /// Wall-clock duration in milliseconds
+    pub duration_ms: u64,
+    /// Error message if the step failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Nested steps
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub steps: Vec<StepResult>,
+}
+
+/// Report directory from `PLAYWRIGHT_REPORT_DIR`, if set
+pub fn report_dir() -> Option<PathBuf> {
+    std::env::var_os(REPORT_DIR_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Appends `result` to the report, if a report directory is configured
+pub(crate) fn record(result: &TestResult) {
+    let Some(dir) = report_dir() else {
+        return;
+    };
+    if let Err(e) = append(&dir, result) {
+        tracing::warn!("Failed to record test result in {}: {}", dir.display(), e);
+        return;
+    }
+    RENDER_ON_EXIT.call_once(move || {
+        crate::harness::global::global_teardown(move || async move {
+            if let Err(e) = write_reports(&dir) {
+                tracing::warn!("Failed to write test report: {}", e);
+            }
+        });
+    });
+}
+
+fn append(dir: &Path, result: &TestResult) -> Result<()> {
+    append_json(dir, RESULTS_FILE, result)
+}
+
+/// Appends `value` as one JSON line to `file` in `dir`
+pub(crate) fn append_json<T: Serialize>(dir: &Path, file: &str, value: &T) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let mut line = serde_json::to_string(value)?;
+    line.push('\n');
+    // A single append-mode write keeps lines from concurrent test binaries intact
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(file))?
+        .write_all(line.as_bytes())?;
+    Ok(())
+}
+
+/// Reads all results recorded in `dir`; a missing file yields no results
+pub fn read_results(dir: &Path) -> Result<Vec<TestResult>> {
+    read_json_lines(&dir.join(RESULTS_FILE))
+}
+
+/// Reads one JSON value per non-empty line; a missing file yields none
+pub(crate) fn read_json_lines<T: serde::de::DeserializeOwned>(file: &Path) -> Result<Vec<T>> {
+    let content = match std::fs::read_to_string(file) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(Into::into))
+        .collect()
+}
+
+/// Renders `report.json` and `index.html` from the results recorded in `dir`
+pub fn write_reports(dir: &Path) -> Result<()> {
+    let results = read_results(dir)?;
+    std::fs::write(
+        dir.join("report.json"),
+        serde_json::to_string_pretty(&results)?,
+    )?;
+    std::fs::write(dir.join("index.html"), render_html(&results))?;
+    Ok(())
+}
+
+/// Renders results as a standalone HTML page
+pub fn render_html(results: &[TestResult]) -> String {
+    let count = |status| results.iter().filter(|r| r.status == status).count();
+    let mut rows = String::new();
+    for result in results {
+        let tags: String = result
+            .tags
+            .iter()
+            .map(|tag| format!(r#"<span class="tag">@{}</span>"#, escape_html(tag)))
+            .collect();
+        let annotations: String = result
+            .annotations
+            .iter()
+            .map(|a| match &a.description {
+                Some(description) => format!(
+                    "<li><b>{}</b>: {}</li>",
+                    escape_html(&a.kind),
+                    escape_html(description)
+                ),
+                None => format!("<li><b>{}</b></li>", escape_html(&a.kind)),
+            })
+            .collect();
+        let project = result
+            .project
+            .as_deref()
+            .map(|p| format!("<b>[{}]</b> ", escape_html(p)))
+            .unwrap_or_default();
+        let retry = if result.retry > 0 {
+            format!(" <i>retry #{}</i>", result.retry)
+        } else {
+            String::new()
+        };
+        let status = match result.status {
+            TestStatus::Passed => "passed",
+            TestStatus::Failed => "failed",
+            TestStatus::Skipped => "skipped",
+        };
+        rows.push_str(&format!(
+            "<tr class=\"{status}\"><td>{status}</td><td>{project}{name}{retry} {tags}<ul>{annotations}</ul>{steps}{attachments}{error}</td><td>{duration} ms</td></tr>\n",
+            status = status,
+            project = project,
+            name = escape_html(&result.name),
+            retry = retry,
+            tags = tags,
+            steps = render_steps(&result.steps),
+            attachments = render_attachments(&result.attachments),
+            annotations = annotations,
+            error = result
+                .error
+                .as_deref()
+                .map(|e| format!("<pre>{}</pre>", escape_html(e)))
+                .unwrap_or_default(),
+            duration = result.duration_ms,
+        ));
+    }
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Playwright test report</title>
+<style>
+body {{ font-family: sans-serif; margin: 2em; }}
+table {{ border-collapse: collapse; width: 100%; }}
+td {{ border-bottom: 1px solid #ddd; padding: 4px 8px; vertical-align: top; }}
+tr.passed td:first-child {{ color: #1a7f37; }}
+tr.failed td:first-child {{ color: #cf222e; }}
+tr.skipped td:first-child {{ color: #9a6700; }}
+.tag {{ background: #ddf4ff; border-radius: 4px; padding: 0 4px; margin-left: 4px; font-size: 0.85em; }}
+ul, ol {{ margin: 0; font-size: 0.85em; }}
+ol.steps li.failed {{ color: #cf222e; }}
+pre {{ background: #fff1f0; padding: 4px; white-space: pre-wrap; }}
+</style>
+</head>
+<body>
+<h1>Playwright test report</h1>
+<p>{passed} passed, {failed} failed, {skipped} skipped</p>
+<table>
+{rows}</table>
+</body>
+</html>
+"#,
+        passed = count(TestStatus::Passed),
+        failed = count(TestStatus::Failed),
+        skipped = count(TestStatus::Skipped),
+        rows = rows
+    )
+}
+
+fn render_steps(steps: &[StepResult]) -> String {
+    if steps.is_empty() {
+        return String::new();
+    }
+    let items: String = steps
+        .iter()
+        .map(|step| {
+            format!(
+                "<li class=\"{}\">{} <small>{} ms</small>{}</li>",
+                if step.error.is_some() {
+                    "failed"
+                } else {
+                    "passed"
+                },
+                escape_html(&step.title),
+                step.duration_ms,
+                render_steps(&step.steps)
+            )
+        })
+        .collect();
+    format!("<ol class=\"steps\">{}</ol>", items)
+}
+
+fn render_attachments(attachments: &[Attachment]) -> String {
+    if attachments.is_empty() {
+        return String::new();
+    }
+    let items: String = attachments
+        .iter()
+        .map(|a| {
+            format!(
+                "<li><a href=\"{}\">{}</a> <small>{}</small></li>",
+                escape_html(&a.path.display().to_string()),
+                escape_html(&a.name),
+                escape_html(&a.content_type)
+            )
+        })
+        .collect();
+    format!("<ul class=\"attachments\">{}</ul>", items)
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(name: &str, status: TestStatus) -> TestResult {
+        TestResult {
+            name: name.to_string(),
+            project: None,
+            status,
+            tags: vec!["smoke".to_string()],
+            annotations: vec![Annotation::new("slow", None)],
+            duration_ms: 12,
+            error: None,
+            retry: 0,
+            steps: Vec::new(),
+            attachments: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_append_and_read_results() {
+        let dir = std::env::temp_dir().join(format!("playwright_rs_report_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(read_results(&dir).unwrap().is_empty());
+        append(&dir, &result("a", TestStatus::Passed)).unwrap();
+        append(&dir, &result("b", TestStatus::Skipped)).unwrap();
+        let results = read_results(&dir).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].status, TestStatus::Skipped);
+
+        write_reports(&dir).unwrap();
+        let html = std::fs::read_to_string(dir.join("index.html")).unwrap();
+        assert!(html.contains("1 passed, 0 failed, 1 skipped"));
+        assert!(dir.join("report.json").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_render_html_escapes() {
+        let mut failed = result("<script>", TestStatus::Failed);
+        failed.error = Some("expected \"a\" & got b".to_string());
+        let html = render_html(&[failed]);
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(html.contains("expected &quot;a&quot; &amp; got b"));
+        assert!(html.contains(r#"<span class="tag">@smoke</span>"#));
+        assert!(html.contains("<li><b>slow</b></li>"));
+    }
+
+    #[test]
+    fn test_render_project() {
+        let mut passed = result("checkout", TestStatus::Passed);
+        passed.project = Some("webkit-mobile".to_string());
+        assert!(render_html(&[passed]).contains("<td><b>[webkit-mobile]</b> checkout"));
+    }
+
+    #[test]
+    fn test_attachments() {
+        let attachment = Attachment::new("api-response.json", "application/json", "out/api.json");
+        assert!(attachment.path.is_absolute());
+        assert!(attachment.path.ends_with("out/api.json"));
+
+        let mut failed = result("api", TestStatus::Failed);
+        failed.attachments = vec![attachment.clone()];
+        let html = render_html(&[failed.clone()]);
+        assert!(html.contains(&format!(
+            r#"<ul class="attachments"><li><a href="{}">api-response.json</a> <small>application/json</small></li></ul>"#,
+            attachment.path.display()
+        )));
+
+        let line = serde_json::to_string(&failed).unwrap();
+        assert_eq!(serde_json::from_str::<TestResult>(&line).unwrap(), failed);
+    }
+
+    #[test]
+    fn test_render_nested_steps() {
+        let mut passed = result("login", TestStatus::Passed);
+        passed.steps = vec![StepResult {
+            title: "fill <form>".to_string(),
+            duration_ms: 5,
+            error: None,
+            steps: vec![StepResult {
+                title: "type email".to_string(),
+                duration_ms: 2,
+                error: Some("boom".to_string()),
+                steps: Vec::new(),
+            }],
+        }];
+        let html = render_html(&[passed]);
+        assert!(html.contains(
+            r#"<ol class="steps"><li class="passed">fill &lt;form&gt; <small>5 ms</small><ol class="steps"><li class="failed">type email <small>2 ms</small></li></ol></li></ol>"#
+        ));
+    }
+}
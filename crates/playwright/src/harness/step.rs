@@ -0,0 +1,224 @@
+// Test steps
+//
+// step("fill login form", async { ... }) splits a long test into named, timed
+// sections. Inside harness::test() steps nest in the JSON/HTML report and, when
+// the test is traced, show up as groups in the trace viewer, so a failure
+// points at the step it happened in. Outside a harness test a step just runs
+// its body.
+//
+// Steps are tracked per test task: steps awaited concurrently (e.g. with
+// tokio::join!) or inside spawned tasks are not nested correctly.
+
+use crate::error::Result;
+use crate::harness::report::StepResult;
+use crate::protocol::{BrowserContext, Tracing};
+use parking_lot::Mutex;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Instant;
+
+tokio::task_local! {
+    static RECORDER: Arc<StepRecorder>;
+}
+
+/// Runs `body` as a named step of the current test.
+///
+/// The step's duration and outcome are recorded in the test report, nested
+/// under any enclosing step. The body's result is returned unchanged.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::harness::{self, step};
+///
+/// harness::test("user can log in")
+///     .run(|info| async move {
+///         let page = info.new_context(&browser, None).await?.new_page().await?;
+///         step("open login page", async {
+///             page.goto("/login", None).await?;
+///             Ok(())
+///         })
+///         .await?;
+///         step("fill login form", async {
+///             page.get_by_label("Email", false).fill("ada@example.com", None).await?;
+///             page.get_by_label("Password", false).fill("hunter2", None).await
+///         })
+///         .await?;
+///         Ok(())
+///     })
+///     .await
+/// ```
+pub async fn step<T, F>(title: impl Into<String>, body: F) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    let title = title.into();
+    let Ok(recorder) = RECORDER.try_with(Arc::clone) else {
+        return body.await;
+    };
+    recorder.begin(title).await;
+    let outcome = body.await;
+    recorder
+        .end(outcome.as_ref().err().map(ToString::to_string))
+        .await;
+    outcome
+}
+
+/// Collects the steps of one test attempt
+pub(crate) struct StepRecorder {
+    /// Open steps, innermost last; the first entry collects top-level steps
+    open: Mutex<Vec<OpenStep>>,
+    /// Contexts of the test, whose traces get a group per step
+    contexts: Arc<Mutex<Vec<BrowserContext>>>,
+    traced: bool,
+}
+
+struct OpenStep {
+    title: String,
+    start: Instant,
+    steps: Vec<StepResult>,
+    /// Traces that opened a group for this step
+    groups: Vec<Tracing>,
+}
+
+impl OpenStep {
+    fn new(title: String) -> Self {
+        Self {
+            title,
+            start: Instant::now(),
+            steps: Vec::new(),
+            groups: Vec::new(),
+        }
+    }
+
+    fn into_result(self, error: Option<String>) -> StepResult {
+        StepResult {
+            title: self.title,
+            duration_ms: self.start.elapsed().as_millis() as u64,
+            error,
+            steps: self.steps,
+        }
+    }
+}
+
+impl StepRecorder {
+    pub(crate) fn new(contexts: Arc<Mutex<Vec<BrowserContext>>>, traced: bool) -> Self {
+        Self {
+            open: Mutex::new(vec![OpenStep::new(String::new())]),
+            contexts,
+            traced,
+        }
+    }
+
+    /// Runs `body` with this recorder as the current test's
+    pub(crate) async fn scope<F: Future>(self: Arc<Self>, body: F) -> F::Output {
+        RECORDER.scope(self, body).await
+    }
+
+    async fn begin(&self, title: String) {
+        let mut step = OpenStep::new(title);
+        if self.traced {
+            let contexts = self.contexts.lock().clone();
+            for context in contexts {
+                let grouped = match context.tracing().await {
+                    Ok(tracing) => tracing.group(&step.title).await.map(|_| tracing),
+                    Err(e) => Err(e),
+                };
+                match grouped {
+                    Ok(tracing) => step.groups.push(tracing),
+                    Err(e) => tracing::debug!("Failed to open trace group: {}", e),
+                }
+            }
+        }
+        self.open.lock().push(step);
+    }
+
+    async fn end(&self, error: Option<String>) {
+        let step = {
+            let mut open = self.open.lock();
+            if open.len() < 2 {
+                return;
+            }
+            open.pop().expect("step is open")
+        };
+        for tracing in &step.groups {
+            if let Err(e) = tracing.group_end().await {
+                tracing::debug!("Failed to close trace group: {}", e);
+            }
+        }
+        let result = step.into_result(error);
+        tracing::debug!("Step '{}' took {} ms", result.title, result.duration_ms);
+        let mut open = self.open.lock();
+        if let Some(parent) = open.last_mut() {
+            parent.steps.push(result);
+        }
+    }
+
+    /// The recorded steps; steps cut short (e.g. by a timeout) are marked failed
+    pub(crate) fn finish(&self) -> Vec<StepResult> {
+        let mut open = self.open.lock();
+        while open.len() > 1 {
+            let step = open.pop().expect("step is open");
+            let result = step.into_result(Some("Step did not finish".to_string()));
+            open.last_mut().expect("root step").steps.push(result);
+        }
+        std::mem::take(&mut open[0].steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[tokio::test]
+    async fn test_steps_nest() {
+        let recorder = Arc::new(StepRecorder::new(Arc::default(), false));
+        let outcome: Result<u32> = Arc::clone(&recorder)
+            .scope(async {
+                step("outer", async {
+                    step("inner ok", async { Ok(()) }).await?;
+                    step("inner fails", async {
+                        Err::<(), _>(Error::InvalidArgument("bad".into()))
+                    })
+                    .await
+                })
+                .await
+                .ok();
+                step("second", async { Ok(7) }).await
+            })
+            .await;
+        assert_eq!(outcome.unwrap(), 7);
+
+        let steps = recorder.finish();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].title, "outer");
+        assert!(steps[0].error.as_deref().unwrap().contains("bad"));
+        let inner: Vec<_> = steps[0].steps.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(inner, ["inner ok", "inner fails"]);
+        assert!(steps[0].steps[0].error.is_none());
+        assert_eq!(steps[1].title, "second");
+    }
+
+    #[tokio::test]
+    async fn test_unfinished_steps_are_closed() {
+        let recorder = Arc::new(StepRecorder::new(Arc::default(), false));
+        let hung = Arc::clone(&recorder).scope(step("hangs", async {
+            std::future::pending::<Result<()>>().await
+        }));
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(10), hung)
+                .await
+                .is_err()
+        );
+
+        let steps = recorder.finish();
+        assert_eq!(steps[0].title, "hangs");
+        assert_eq!(steps[0].error.as_deref(), Some("Step did not finish"));
+    }
+
+    #[tokio::test]
+    async fn test_step_outside_test_runs_body() {
+        assert_eq!(step("standalone", async { Ok(1) }).await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,201 @@
+// Full-stack test apps
+//
+// Boots the application under test in-process on an ephemeral port, so a test
+// can drive the real backend and frontend together without a fixed port or an
+// external process. The app's URL becomes the context's base_url, and the app
+// shuts down gracefully when that context is closed (or the TestApp dropped).
+//
+// TestApp::serve works with any server that can run on a tokio TcpListener
+// (hyper, axum, warp, ...); TestApp::axum is a shortcut behind the `axum` feature.
+
+use crate::error::{Error, Result};
+use crate::protocol::{Browser, BrowserContext, BrowserContextOptions};
+use parking_lot::Mutex;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+/// Resolves when the [`TestApp`] is asked to shut down.
+///
+/// Pass it to the server's graceful shutdown hook.
+#[derive(Debug)]
+pub struct Shutdown(oneshot::Receiver<()>);
+
+impl Future for Shutdown {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // A dropped sender also means shut down
+        Pin::new(&mut self.0).poll(cx).map(|_| ())
+    }
+}
+
+/// An application server running in the test process.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::harness::TestApp;
+///
+/// let app = TestApp::axum(my_app::router()).await?;
+/// let context = app.new_context(&browser).await?;
+/// let page = context.new_page().await?;
+/// page.goto("/login", None).await?; // resolved against the app URL
+/// context.close().await?; // also stops the app
+/// ```
+///
+/// Any server that can run on a tokio listener works with [`TestApp::serve`]:
+///
+/// ```ignore
+/// let app = TestApp::serve(|listener, shutdown| async move {
+///     axum::serve(listener, router).with_graceful_shutdown(shutdown).await
+/// })
+/// .await?;
+/// ```
+#[derive(Debug)]
+pub struct TestApp {
+    url: String,
+    shutdown: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+    task: Option<JoinHandle<std::io::Result<()>>>,
+}
+
+impl TestApp {
+    /// Binds `127.0.0.1:0` and runs `serve` on the listener in a background task.
+    ///
+    /// `serve` receives the bound listener and a [`Shutdown`] future, and should
+    /// return once the server has stopped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Io` if the listener cannot be bound.
+    pub async fn serve<F, Fut>(serve: F) -> Result<Self>
+    where
+        F: FnOnce(TcpListener, Shutdown) -> Fut,
+        Fut: Future<Output = std::io::Result<()>> + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let (sender, receiver) = oneshot::channel();
+        let task = tokio::spawn(serve(listener, Shutdown(receiver)));
+
+        Ok(Self {
+            url,
+            shutdown: Arc::new(Mutex::new(Some(sender))),
+            task: Some(task),
+        })
+    }
+
+    /// Serves an axum `Router`.
+    #[cfg(feature = "axum")]
+    pub async fn axum(router: axum::Router) -> Result<Self> {
+        Self::serve(|listener, shutdown| async move {
+            axum::serve(listener, router)
+                .with_graceful_shutdown(shutdown)
+                .await
+        })
+        .await
+    }
+
+    /// Base URL of the app, e.g. `http://127.0.0.1:49152`
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Context options with `base_url` set to the app URL
+    pub fn context_options(&self) -> BrowserContextOptions {
+        BrowserContextOptions::builder()
+            .base_url(self.url.clone())
+            .build()
+    }
+
+    /// Creates a context whose `base_url` is the app URL.
+    ///
+    /// Closing the context with [`BrowserContext::close`] shuts the app down.
+    pub async fn new_context(&self, browser: &Browser) -> Result<BrowserContext> {
+        let context = browser
+            .new_context_with_options(self.context_options())
+            .await?;
+        let shutdown = Arc::clone(&self.shutdown);
+        context.add_close_hook(move || signal(&shutdown));
+        Ok(context)
+    }
+
+    /// Shuts the app down and waits for the server to stop.
+    ///
+    /// # Errors
+    ///
+    /// Returns the server's I/O error, or `Error::ServerError` if it panicked.
+    pub async fn shutdown(mut self) -> Result<()> {
+        signal(&self.shutdown);
+        match self.task.take() {
+            Some(task) => task
+                .await
+                .map_err(|e| Error::ServerError(format!("Test app task failed: {}", e)))?
+                .map_err(Error::from),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for TestApp {
+    fn drop(&mut self) {
+        signal(&self.shutdown);
+    }
+}
+
+fn signal(shutdown: &Mutex<Option<oneshot::Sender<()>>>) {
+    if let Some(sender) = shutdown.lock().take() {
+        let _ = sender.send(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[tokio::test]
+    async fn test_serve_and_shutdown() {
+        let app = TestApp::serve(|listener, shutdown| async move {
+            tokio::select! {
+                accepted = listener.accept() => {
+                    let (mut socket, _) = accepted?;
+                    socket.write_all(b"hello").await?;
+                }
+                _ = shutdown => {}
+            }
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        assert!(app.url().starts_with("http://127.0.0.1:"));
+        let mut socket = tokio::net::TcpStream::connect(app.url().trim_start_matches("http://"))
+            .await
+            .unwrap();
+        let mut greeting = String::new();
+        socket.read_to_string(&mut greeting).await.unwrap();
+        assert_eq!(greeting, "hello");
+
+        app.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_signal() {
+        let app = TestApp::serve(|_listener, shutdown| async move {
+            shutdown.await;
+            Ok(())
+        })
+        .await
+        .unwrap();
+        assert_eq!(app.context_options().base_url.as_deref(), Some(app.url()));
+        tokio::time::timeout(std::time::Duration::from_secs(5), app.shutdown())
+            .await
+            .expect("server should stop once signalled")
+            .unwrap();
+    }
+}
@@ -0,0 +1,711 @@
+// Annotated test cases
+//
+// harness::test("name") wraps the body of a regular #[tokio::test] with
+// Playwright-style metadata: tags (`@smoke`), skip/fixme/slow annotations and
+// custom annotations. Tests are filtered by tag or name via environment
+// variables (or an installed TestFilter), can run in a browser Project, each
+// attempt is bounded by a timeout, failed tests can be retried, traces, videos
+// and screenshots are kept according to an ArtifactPolicy, and every result is
+// recorded in the JSON/HTML report, with the test's attachments, when
+// PLAYWRIGHT_REPORT_DIR is set.
+//
+// Examples:
+//
+//     PLAYWRIGHT_TAGS=smoke cargo test          # only tests tagged smoke
+//     PLAYWRIGHT_TAGS=-flaky cargo test         # everything except flaky
+//     PLAYWRIGHT_GREP='checkout|cart' cargo test
+//     PLAYWRIGHT_LAST_FAILED=1 cargo test      # only tests that failed last time
+//     PLAYWRIGHT_LIST=1 cargo test -- --nocapture   # list tests without running them
+
+use crate::error::{Error, Result};
+use crate::harness::artifacts::{self, ArtifactPolicy};
+use crate::harness::last_run::{self, LAST_FAILED_ENV};
+use crate::harness::listing::{self, TestListing};
+use crate::harness::projects::Project;
+use crate::harness::report::{self, Annotation, Attachment, TestResult, TestStatus};
+use crate::harness::step::StepRecorder;
+use crate::harness::timeout;
+use crate::protocol::{Browser, BrowserContext, BrowserContextOptions, RecordVideo};
+use crate::server::channel_owner::ChannelOwner;
+use parking_lot::{Mutex, RwLock};
+use regex::Regex;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Environment variable selecting tests by tag (comma-separated, `-tag` excludes)
+pub const TAGS_ENV: &str = "PLAYWRIGHT_TAGS";
+
+/// Environment variable selecting tests by a regex over the name and `@tags`
+pub const GREP_ENV: &str = "PLAYWRIGHT_GREP";
+
+static INSTALLED_FILTER: RwLock<Option<TestFilter>> = RwLock::new(None);
+
+/// Starts describing a test.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::harness;
+///
+/// #[tokio::test]
+/// async fn checkout() -> playwright_rs::Result<()> {
+///     harness::test("checkout completes")
+///         .tag("smoke")
+///         .slow()
+///         .annotate("issue", "https://example.com/issues/42")
+///         .run(|info| async move {
+///             // ... drive the browser
+///             Ok(())
+///         })
+///         .await
+/// }
+/// ```
+pub fn test(name: impl Into<String>) -> TestCase {
+    TestCase {
+        name: name.into(),
+        tags: Vec::new(),
+        annotations: Vec::new(),
+        skipped: false,
+        retries: 0,
+        artifacts: None,
+        timeout: None,
+        project: None,
+        urls: Vec::new(),
+        selectors: Vec::new(),
+    }
+}
+
+/// A test with its tags and annotations, ready to [`run`](TestCase::run).
+#[derive(Debug, Clone)]
+pub struct TestCase {
+    name: String,
+    tags: Vec<String>,
+    annotations: Vec<Annotation>,
+    skipped: bool,
+    retries: u32,
+    artifacts: Option<ArtifactPolicy>,
+    timeout: Option<Duration>,
+    project: Option<Project>,
+    urls: Vec<String>,
+    selectors: Vec<String>,
+}
+
+impl TestCase {
+    /// Adds a tag; a leading `@` is optional
+    pub fn tag(mut self, tag: impl AsRef<str>) -> Self {
+        let tag = tag.as_ref().trim_start_matches('@').to_string();
+        if !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+        self
+    }
+
+    /// Skips the test, recording `reason`
+    pub fn skip(self, reason: impl Into<String>) -> Self {
+        self.skip_if(true, reason)
+    }
+
+    /// Skips the test when `condition` holds, e.g. for one browser or platform
+    pub fn skip_if(mut self, condition: bool, reason: impl Into<String>) -> Self {
+        if condition {
+            self.skipped = true;
+            self.annotations
+                .push(Annotation::new("skip", Some(reason.into())));
+        }
+        self
+    }
+
+    /// Marks the test as known broken; it is skipped and reported as `fixme`
+    pub fn fixme(mut self, reason: impl Into<String>) -> Self {
+        self.skipped = true;
+        self.annotations
+            .push(Annotation::new("fixme", Some(reason.into())));
+        self
+    }
+
+    /// Marks the test as slow, tripling its timeout
+    pub fn slow(mut self) -> Self {
+        self.annotations.push(Annotation::new("slow", None));
+        self
+    }
+
+    /// Adds a custom annotation, e.g. `("issue", url)`
+    pub fn annotate(mut self, kind: impl Into<String>, description: impl Into<String>) -> Self {
+        self.annotations
+            .push(Annotation::new(kind, Some(description.into())));
+        self
+    }
+
+    /// Sets the timeout of each attempt, overriding `PLAYWRIGHT_TEST_TIMEOUT`
+    /// (default 30s); `Duration::ZERO` disables it
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Re-runs a failing body up to `retries` more times
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Sets the trace/video/screenshot policy, overriding `PLAYWRIGHT_TRACE`,
+    /// `PLAYWRIGHT_VIDEO` and `PLAYWRIGHT_SCREENSHOT`
+    pub fn artifacts(mut self, policy: ArtifactPolicy) -> Self {
+        self.artifacts = Some(policy);
+        self
+    }
+
+    /// Runs the test in `project`: it is reported under the project's name and
+    /// [`TestInfo::new_context`] uses the project's context options
+    pub fn project(mut self, project: &Project) -> Self {
+        self.project = Some(project.clone());
+        self
+    }
+
+    /// Declares a URL the test visits, for [test listings](crate::harness::listing)
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.urls.push(url.into());
+        self
+    }
+
+    /// Declares a selector the test uses, for [test listings](crate::harness::listing)
+    pub fn selector(mut self, selector: impl Into<String>) -> Self {
+        self.selectors.push(selector.into());
+        self
+    }
+
+    /// The test's metadata as listed in dry runs
+    pub fn listing(&self) -> TestListing {
+        TestListing {
+            name: self.name.clone(),
+            project: self.project.as_ref().map(|p| p.name.clone()),
+            tags: self.tags.clone(),
+            annotations: self.annotations.clone(),
+            urls: self.urls.clone(),
+            selectors: self.selectors.clone(),
+        }
+    }
+
+    /// Whether the active [`TestFilter`] selects this test
+    pub fn is_selected(&self) -> Result<bool> {
+        let filter = TestFilter::active()?;
+        Ok(filter.matches(&self.name, &self.tags) && filter.matches_last_failed(&self.key()))
+    }
+
+    /// Key of the test in the last-run state
+    fn key(&self) -> String {
+        last_run::test_key(&self.name, self.project.as_ref().map(|p| p.name.as_str()))
+    }
+
+    /// Runs `body` unless the test is filtered out or skipped, and records the result.
+    ///
+    /// Filtered-out tests return `Ok(())` without being reported; skipped and
+    /// fixme tests are reported as skipped. Selected tests are registered with
+    /// their [`listing`](Self::listing); with `PLAYWRIGHT_LIST=1` that is all
+    /// that happens. A failing body is run again up to
+    /// [`retries`](Self::retries) times; the last attempt is reported.
+    ///
+    /// An attempt that exceeds its [`timeout`](Self::timeout) is dropped and
+    /// fails with `Error::Timeout`, listing the protocol calls still pending on
+    /// contexts opened with [`TestInfo::new_context`] and screenshots of their
+    /// pages.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last attempt's error, or `Error::InvalidArgument` for an
+    /// invalid `PLAYWRIGHT_GREP` pattern, artifact mode or timeout.
+    pub async fn run<F, Fut>(self, mut body: F) -> Result<()>
+    where
+        F: FnMut(TestInfo) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        if !self.is_selected()? {
+            tracing::info!("Test '{}' filtered out", self.name);
+            return Ok(());
+        }
+        let listing_mode = listing::is_listing();
+        listing::register(self.listing(), listing_mode);
+        if listing_mode {
+            return Ok(());
+        }
+
+        let mut result = TestResult {
+            name: self.name.clone(),
+            project: self.project.as_ref().map(|p| p.name.clone()),
+            status: TestStatus::Skipped,
+            tags: self.tags.clone(),
+            annotations: self.annotations.clone(),
+            duration_ms: 0,
+            error: None,
+            retry: 0,
+            steps: Vec::new(),
+            attachments: Vec::new(),
+        };
+        if self.skipped {
+            tracing::info!("Test '{}' skipped", self.name);
+            report::record(&result);
+            return Ok(());
+        }
+
+        let policy = match self.artifacts {
+            Some(policy) => policy,
+            None => ArtifactPolicy::from_env()?,
+        };
+        let slow = self.annotations.iter().any(|a| a.kind == "slow");
+        let limit = timeout::resolve(self.timeout, slow)?;
+        let start = Instant::now();
+        let mut retry = 0;
+        let outcome = loop {
+            let info = TestInfo {
+                name: self.name.clone(),
+                tags: self.tags.clone(),
+                annotations: self.annotations.clone(),
+                retry,
+                timeout: limit,
+                project: self.project.clone(),
+                artifacts: policy,
+                contexts: Arc::default(),
+                attachments: Arc::default(),
+            };
+            let contexts = Arc::clone(&info.contexts);
+            let attachments = Arc::clone(&info.attachments);
+            let dir = info.output_dir();
+            let recorder = Arc::new(StepRecorder::new(
+                Arc::clone(&contexts),
+                policy.trace.should_record(retry),
+            ));
+            let attempt = Arc::clone(&recorder).scope(body(info));
+            let outcome = match limit {
+                Some(limit) => match tokio::time::timeout(limit, attempt).await {
+                    Ok(outcome) => outcome,
+                    Err(_) => {
+                        let open = contexts.lock().clone();
+                        Err(timeout::expired(&self.name, limit, &open, &dir).await)
+                    }
+                },
+                None => attempt.await,
+            };
+            result.steps = recorder.finish();
+            let contexts = std::mem::take(&mut *contexts.lock());
+            if limit.is_some() {
+                for context in &contexts {
+                    context.connection().capture_call_stacks(false);
+                }
+            }
+            let kept = artifacts::finish(policy, contexts, &dir, retry, outcome.is_err()).await;
+            result.attachments = std::mem::take(&mut *attachments.lock());
+            result.attachments.extend(kept);
+
+            match outcome {
+                Err(e) if retry < self.retries => {
+                    tracing::warn!("Test '{}' failed, retrying: {}", self.name, e);
+                    retry += 1;
+                }
+                outcome => break outcome,
+            }
+        };
+        last_run::record(&self.key(), outcome.is_err());
+        result.duration_ms = start.elapsed().as_millis() as u64;
+        result.retry = retry;
+        match &outcome {
+            Ok(()) => result.status = TestStatus::Passed,
+            Err(e) => {
+                result.status = TestStatus::Failed;
+                result.error = Some(e.to_string());
+            }
+        }
+        report::record(&result);
+        outcome
+    }
+}
+
+/// Metadata of the running test, passed to the test body.
+#[derive(Debug, Clone)]
+pub struct TestInfo {
+    /// Test name
+    pub name: String,
+    /// Tags, without the leading `@`
+    pub tags: Vec<String>,
+    /// Annotations declared on the test
+    pub annotations: Vec<Annotation>,
+    /// Attempt number: 0 for the first run, 1 for the first retry, ...
+    pub retry: u32,
+    /// Timeout of this attempt, already tripled for slow tests
+    pub timeout: Option<Duration>,
+    /// Project the test runs in
+    pub project: Option<Project>,
+    artifacts: ArtifactPolicy,
+    /// Contexts opened with `new_context`, finished by the harness
+    contexts: Arc<Mutex<Vec<BrowserContext>>>,
+    /// Files attached with `attach`/`attach_file`
+    attachments: Arc<Mutex<Vec<Attachment>>>,
+}
+
+impl TestInfo {
+    /// Directory receiving this attempt's artifacts
+    pub fn output_dir(&self) -> PathBuf {
+        match &self.project {
+            Some(project) => {
+                artifacts::output_dir(&format!("{} {}", self.name, project.name), self.retry)
+            }
+            None => artifacts::output_dir(&self.name, self.retry),
+        }
+    }
+
+    /// Opens a context whose trace, video and screenshots follow the test's
+    /// [`ArtifactPolicy`].
+    ///
+    /// Without `options` the context gets the [`project`](Self::project)'s
+    /// options, if any; explicit `options` replace them.
+    ///
+    /// The harness closes the context when the body returns, after saving or
+    /// discarding its artifacts.
+    pub async fn new_context(
+        &self,
+        browser: &Browser,
+        options: Option<BrowserContextOptions>,
+    ) -> Result<BrowserContext> {
+        let mut options = options
+            .or_else(|| self.project.as_ref().map(Project::context_options))
+            .unwrap_or_default();
+        if self.artifacts.video.should_record(self.retry) {
+            options.record_video = Some(RecordVideo {
+                dir: self.output_dir().join("videos").display().to_string(),
+                size: None,
+            });
+        }
+        let context = browser.new_context_with_options(options).await?;
+        // Registered first so the harness closes it even if tracing fails to start
+        self.contexts.lock().push(context.clone());
+        // Pending calls listed on timeout show where they were made
+        if self.timeout.is_some() {
+            context.connection().capture_call_stacks(true);
+        }
+        if self.artifacts.trace.should_record(self.retry) {
+            context
+                .tracing()
+                .await?
+                .start(Some(artifacts::trace_options(&self.name)))
+                .await?;
+        }
+        Ok(context)
+    }
+
+    /// Attaches `body` to the test's report entry.
+    ///
+    /// The content is written to `attachments/` in the [`output_dir`](Self::output_dir)
+    /// and listed with the test's traces, videos and screenshots in the report.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let response = page.evaluate_value("JSON.stringify(window.lastResponse)").await?;
+    /// info.attach("api-response.json", response, "application/json").await?;
+    /// ```
+    pub async fn attach(
+        &self,
+        name: &str,
+        body: impl AsRef<[u8]>,
+        content_type: &str,
+    ) -> Result<()> {
+        let file = self.attachment_path(name)?;
+        tokio::fs::write(&file, body).await?;
+        self.attachments
+            .lock()
+            .push(Attachment::new(name, content_type, file));
+        Ok(())
+    }
+
+    /// Attaches a copy of the file at `path`, so it survives later cleanup
+    pub async fn attach_file(&self, name: &str, path: &Path, content_type: &str) -> Result<()> {
+        let file = self.attachment_path(name)?;
+        tokio::fs::copy(path, &file).await?;
+        self.attachments
+            .lock()
+            .push(Attachment::new(name, content_type, file));
+        Ok(())
+    }
+
+    /// Unused path for an attachment called `name`, creating its directory
+    fn attachment_path(&self, name: &str) -> Result<PathBuf> {
+        let file_name: String = name
+            .chars()
+            .map(|c| match c {
+                '/' | '\\' | ':' => '-',
+                c => c,
+            })
+            .collect();
+        if file_name.trim_matches('.').is_empty() {
+            return Err(Error::InvalidArgument(format!(
+                "Invalid attachment name '{}'",
+                name
+            )));
+        }
+        let dir = self.output_dir().join("attachments");
+        std::fs::create_dir_all(&dir)?;
+        let taken = self.attachments.lock().len();
+        let file = dir.join(&file_name);
+        Ok(if file.exists() {
+            dir.join(format!("{}-{}", taken + 1, file_name))
+        } else {
+            file
+        })
+    }
+
+    /// Whether the test was marked [`slow`](TestCase::slow)
+    pub fn is_slow(&self) -> bool {
+        self.annotations.iter().any(|a| a.kind == "slow")
+    }
+}
+
+/// Selects tests by tag, name and last-run outcome.
+///
+/// Read from `PLAYWRIGHT_TAGS` / `PLAYWRIGHT_GREP` / `PLAYWRIGHT_LAST_FAILED`
+/// unless one was installed with [`TestFilter::install`].
+#[derive(Debug, Clone, Default)]
+pub struct TestFilter {
+    include_tags: Vec<String>,
+    exclude_tags: Vec<String>,
+    grep: Option<Regex>,
+    /// Tests that failed last time, when only those are selected
+    last_failed: Option<Vec<String>>,
+}
+
+impl TestFilter {
+    /// A filter that selects every test
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a tag list such as `"smoke,-flaky"`; `-tag` excludes a tag
+    pub fn tags(mut self, spec: &str) -> Self {
+        for tag in spec.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            match tag.strip_prefix('-') {
+                Some(excluded) => self
+                    .exclude_tags
+                    .push(excluded.trim_start_matches('@').to_string()),
+                None => self
+                    .include_tags
+                    .push(tag.trim_start_matches('@').to_string()),
+            }
+        }
+        self
+    }
+
+    /// Only selects tests whose name or `@tags` match `pattern`
+    pub fn grep(mut self, pattern: &str) -> Result<Self> {
+        self.grep = Some(Regex::new(pattern).map_err(|e| {
+            Error::InvalidArgument(format!("Invalid test grep pattern '{}': {}", pattern, e))
+        })?);
+        Ok(self)
+    }
+
+    /// Only selects tests that failed the last time they ran, as recorded in
+    /// [`last_run_file`](crate::harness::last_run::last_run_file)
+    pub fn last_failed(mut self) -> Result<Self> {
+        let failed = last_run::failed_tests()?;
+        if failed.is_empty() {
+            tracing::info!("No failed tests recorded; selecting none");
+        }
+        self.last_failed = Some(failed);
+        Ok(self)
+    }
+
+    /// Builds a filter from `PLAYWRIGHT_TAGS`, `PLAYWRIGHT_GREP` and
+    /// `PLAYWRIGHT_LAST_FAILED` (`1` or `true`)
+    pub fn from_env() -> Result<Self> {
+        let mut filter = Self::new();
+        if let Ok(tags) = std::env::var(TAGS_ENV) {
+            filter = filter.tags(&tags);
+        }
+        if let Ok(grep) = std::env::var(GREP_ENV) {
+            if !grep.is_empty() {
+                filter = filter.grep(&grep)?;
+            }
+        }
+        match std::env::var(LAST_FAILED_ENV).as_deref() {
+            Ok("1" | "true") => filter.last_failed(),
+            _ => Ok(filter),
+        }
+    }
+
+    /// Uses this filter for the rest of the process instead of the environment
+    pub fn install(self) {
+        *INSTALLED_FILTER.write() = Some(self);
+    }
+
+    /// The installed filter, or the one from the environment
+    pub(crate) fn active() -> Result<Self> {
+        match INSTALLED_FILTER.read().clone() {
+            Some(filter) => Ok(filter),
+            None => Self::from_env(),
+        }
+    }
+
+    /// Whether the last-failed selection, if any, includes the test `key`
+    fn matches_last_failed(&self, key: &str) -> bool {
+        match &self.last_failed {
+            Some(failed) => failed.iter().any(|t| t == key),
+            None => true,
+        }
+    }
+
+    /// Whether a test with `name` and `tags` is selected
+    pub fn matches(&self, name: &str, tags: &[String]) -> bool {
+        if tags.iter().any(|t| self.exclude_tags.contains(t)) {
+            return false;
+        }
+        if !self.include_tags.is_empty() && !tags.iter().any(|t| self.include_tags.contains(t)) {
+            return false;
+        }
+        match &self.grep {
+            Some(grep) => {
+                let title = std::iter::once(name.to_string())
+                    .chain(tags.iter().map(|t| format!("@{}", t)))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                grep.is_match(&title)
+            }
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(list: &[&str]) -> Vec<String> {
+        list.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn test_filter_tags() {
+        let filter = TestFilter::new().tags("smoke, @critical, -flaky");
+        assert!(filter.matches("a", &tags(&["smoke"])));
+        assert!(filter.matches("a", &tags(&["critical", "slowpoke"])));
+        assert!(!filter.matches("a", &tags(&["smoke", "flaky"])));
+        assert!(!filter.matches("a", &tags(&[])));
+
+        let exclude_only = TestFilter::new().tags("-flaky");
+        assert!(exclude_only.matches("a", &tags(&[])));
+        assert!(!exclude_only.matches("a", &tags(&["flaky"])));
+    }
+
+    #[test]
+    fn test_filter_grep() {
+        let filter = TestFilter::new().grep("checkout|@smoke").unwrap();
+        assert!(filter.matches("checkout works", &[]));
+        assert!(filter.matches("login", &tags(&["smoke"])));
+        assert!(!filter.matches("login", &[]));
+        assert!(TestFilter::new().grep("(").is_err());
+    }
+
+    #[test]
+    fn test_filter_last_failed() {
+        let filter = TestFilter {
+            last_failed: Some(tags(&["checkout", "[webkit] login"])),
+            ..TestFilter::new()
+        };
+        assert!(filter.matches_last_failed("checkout"));
+        assert!(filter.matches_last_failed("[webkit] login"));
+        assert!(!filter.matches_last_failed("login"));
+        assert!(TestFilter::new().matches_last_failed("login"));
+    }
+
+    #[test]
+    fn test_case_builder() {
+        let case = test("t")
+            .tag("@smoke")
+            .tag("smoke")
+            .slow()
+            .skip_if(false, "not on this platform");
+        assert_eq!(case.tags, vec!["smoke"]);
+        assert!(!case.skipped);
+        assert_eq!(case.annotations, vec![Annotation::new("slow", None)]);
+
+        let fixme = test("t").fixme("broken since #12");
+        assert!(fixme.skipped);
+        assert_eq!(fixme.annotations[0].kind, "fixme");
+    }
+
+    #[tokio::test]
+    async fn test_skipped_body_does_not_run() {
+        test("skipped")
+            .skip("not ready")
+            .run(|_| async { panic!("skipped test body must not run") })
+            .await
+            .unwrap();
+
+        let mut ran = false;
+        test("runs")
+            .slow()
+            .run(|info| {
+                ran = true;
+                assert!(info.is_slow());
+                async { Ok(()) }
+            })
+            .await
+            .unwrap();
+        assert!(ran);
+    }
+
+    #[tokio::test]
+    async fn test_attach() {
+        let info = TestInfo {
+            name: format!("attach {}", std::process::id()),
+            tags: Vec::new(),
+            annotations: Vec::new(),
+            retry: 0,
+            timeout: None,
+            project: None,
+            artifacts: ArtifactPolicy::new(),
+            contexts: Arc::default(),
+            attachments: Arc::default(),
+        };
+        info.attach("api/response.json", r#"{"ok":true}"#, "application/json")
+            .await
+            .unwrap();
+        info.attach("api/response.json", "again", "application/json")
+            .await
+            .unwrap();
+        assert!(info.attach("..", "", "text/plain").await.is_err());
+
+        let attachments = info.attachments.lock().clone();
+        assert_eq!(attachments.len(), 2);
+        assert_eq!(attachments[0].name, "api/response.json");
+        assert!(attachments[0]
+            .path
+            .ends_with("attachments/api-response.json"));
+        assert!(attachments[1]
+            .path
+            .ends_with("attachments/2-api-response.json"));
+        assert_eq!(
+            std::fs::read_to_string(&attachments[0].path).unwrap(),
+            r#"{"ok":true}"#
+        );
+        std::fs::remove_dir_all(info.output_dir()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_timeout_fails_hung_body() {
+        let err = test("hangs")
+            .timeout(Duration::from_millis(50))
+            .run(|info| async move {
+                assert_eq!(info.timeout, Some(Duration::from_millis(50)));
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                Ok(())
+            })
+            .await
+            .unwrap_err();
+        match err {
+            Error::Timeout(message) => {
+                assert!(message.starts_with("Test 'hangs' timed out after 50ms"))
+            }
+            other => panic!("Expected timeout, got {:?}", other),
+        }
+    }
+}
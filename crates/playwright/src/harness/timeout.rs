@@ -0,0 +1,167 @@
+// Test timeouts
+//
+// harness::test() bounds every attempt by a timeout (30s unless configured,
+// tripled for slow tests). When it expires the body is dropped and, instead of
+// a bare hang, the test fails with what it was waiting on: the protocol calls
+// still pending on its contexts' connections (with call stacks when
+// RUST_BACKTRACE is set) and a screenshot of every open page. Call stacks are
+// only recorded on a context's connection while its test has a timeout.
+
+use crate::error::{Error, Result};
+use crate::protocol::BrowserContext;
+use crate::server::channel_owner::ChannelOwner;
+use crate::server::connection::{ConnectionLike, PendingCall};
+use std::fmt::Write as _;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Environment variable with the test timeout in milliseconds (`0` disables it)
+pub const TIMEOUT_ENV: &str = "PLAYWRIGHT_TEST_TIMEOUT";
+
+/// Timeout used when neither the test nor `PLAYWRIGHT_TEST_TIMEOUT` sets one
+pub const DEFAULT_TEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Slow tests get this many times the timeout
+const SLOW_MULTIPLIER: u32 = 3;
+
+/// How long to wait for each diagnostic screenshot of a possibly stuck page
+const SCREENSHOT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Effective timeout: `explicit`, else the environment, else the default;
+/// zero disables it
+pub(crate) fn resolve(explicit: Option<Duration>, slow: bool) -> Result<Option<Duration>> {
+    let timeout = match explicit {
+        Some(timeout) => timeout,
+        None => match std::env::var(TIMEOUT_ENV) {
+            Ok(ms) => Duration::from_millis(ms.trim().parse().map_err(|_| {
+                Error::InvalidArgument(format!(
+                    "Invalid {} '{}': expected milliseconds",
+                    TIMEOUT_ENV, ms
+                ))
+            })?),
+            Err(_) => DEFAULT_TEST_TIMEOUT,
+        },
+    };
+    if timeout.is_zero() {
+        return Ok(None);
+    }
+    Ok(Some(if slow {
+        timeout * SLOW_MULTIPLIER
+    } else {
+        timeout
+    }))
+}
+
+/// Builds the timeout error, capturing pending calls and screenshots
+pub(crate) async fn expired(
+    name: &str,
+    timeout: Duration,
+    contexts: &[BrowserContext],
+    dir: &Path,
+) -> Error {
+    let mut connections: Vec<Arc<dyn ConnectionLike>> = Vec::new();
+    for context in contexts {
+        let connection = context.connection();
+        if !connections
+            .iter()
+            .any(|c| Arc::as_ptr(c) as *const () == Arc::as_ptr(&connection) as *const ())
+        {
+            connections.push(connection);
+        }
+    }
+    let pending: Vec<PendingCall> = connections
+        .iter()
+        .flat_map(|c| c.pending_call_details())
+        .collect();
+
+    let mut screenshots = Vec::new();
+    for page in contexts.iter().flat_map(|c| c.pages()) {
+        let file = dir.join(format!("test-timeout-{}.png", screenshots.len() + 1));
+        let capture = async {
+            tokio::fs::create_dir_all(dir).await?;
+            page.screenshot_to_file(&file, None).await
+        };
+        match tokio::time::timeout(SCREENSHOT_TIMEOUT, capture).await {
+            Ok(Ok(_)) => screenshots.push(file),
+            Ok(Err(e)) => tracing::warn!("Failed to capture timeout screenshot: {}", e),
+            Err(_) => tracing::warn!("Timeout screenshot of {} did not complete", page.url()),
+        }
+    }
+
+    Error::Timeout(render(name, timeout, &pending, &screenshots))
+}
+
+fn render(
+    name: &str,
+    timeout: Duration,
+    pending: &[PendingCall],
+    screenshots: &[std::path::PathBuf],
+) -> String {
+    let mut message = format!("Test '{}' timed out after {:?}", name, timeout);
+    if pending.is_empty() {
+        message.push_str("\nNo protocol calls were pending");
+    } else {
+        message.push_str("\nPending protocol calls:");
+        for call in pending {
+            let _ = write!(
+                message,
+                "\n  - {}.{} (waiting {:.1}s)",
+                call.guid,
+                call.method,
+                call.elapsed.as_secs_f64()
+            );
+            if let Some(backtrace) = &call.backtrace {
+                for line in backtrace.lines() {
+                    let _ = write!(message, "\n      {}", line);
+                }
+            }
+        }
+        if pending.iter().all(|call| call.backtrace.is_none()) {
+            message.push_str("\n  (set RUST_BACKTRACE=1 to capture where each call was made)");
+        }
+    }
+    for screenshot in screenshots {
+        let _ = write!(message, "\nScreenshot: {}", screenshot.display());
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let second = Duration::from_secs(1);
+        assert_eq!(resolve(Some(second), false).unwrap(), Some(second));
+        assert_eq!(resolve(Some(second), true).unwrap(), Some(second * 3));
+        assert_eq!(resolve(Some(Duration::ZERO), true).unwrap(), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let pending = vec![PendingCall {
+            id: 3,
+            guid: "frame@1".to_string(),
+            method: "click".to_string(),
+            elapsed: Duration::from_millis(1500),
+            backtrace: None,
+        }];
+        let message = render(
+            "checkout",
+            Duration::from_secs(2),
+            &pending,
+            &[Path::new("out/test-timeout-1.png").to_path_buf()],
+        );
+        assert_eq!(
+            message,
+            "Test 'checkout' timed out after 2s\n\
+             Pending protocol calls:\n  \
+             - frame@1.click (waiting 1.5s)\n  \
+             (set RUST_BACKTRACE=1 to capture where each call was made)\n\
+             Screenshot: out/test-timeout-1.png"
+        );
+        assert!(render("idle", Duration::from_secs(1), &[], &[]).contains("No protocol calls"));
+    }
+}
@@ -7,7 +7,7 @@
 //! ## Basic Navigation and Interaction
 //!
 //! ```ignore
-//! use playwright_rs::{Playwright, SelectOption};
+//! use playwright_rs::prelude::*;
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -25,12 +25,12 @@
 //!     ).await;
 //!
 //!     // Query elements with locators
-//!     let heading = page.locator("#title").await;
+//!     let heading = page.locator("#title");
 //!     let text = heading.text_content().await?;
 //!     assert_eq!(text, Some("Welcome".to_string()));
 //!
 //!     // Click button and verify result
-//!     let button = page.locator("#btn").await;
+//!     let button = page.locator("#btn");
 //!     button.click(None).await?;
 //!     let button_text = button.text_content().await?;
 //!     assert_eq!(button_text, Some("Clicked".to_string()));
@@ -43,7 +43,7 @@
 //! ## Form Interaction
 //!
 //! ```ignore
-//! use playwright_rs::{Playwright, SelectOption};
+//! use playwright_rs::prelude::*;
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -66,17 +66,17 @@
 //!     ).await;
 //!
 //!     // Fill text input
-//!     let name = page.locator("#name").await;
+//!     let name = page.locator("#name");
 //!     name.fill("John Doe", None).await?;
 //!     assert_eq!(name.input_value(None).await?, "John Doe");
 //!
 //!     // Check checkbox
-//!     let checkbox = page.locator("#agree").await;
+//!     let checkbox = page.locator("#agree");
 //!     checkbox.set_checked(true, None).await?;
 //!     assert!(checkbox.is_checked().await?);
 //!
 //!     // Select option
-//!     let select = page.locator("#country").await;
+//!     let select = page.locator("#country");
 //!     select.select_option("uk", None).await?;
 //!     assert_eq!(select.input_value(None).await?, "uk");
 //!
@@ -88,7 +88,7 @@
 //! ## Element Screenshots
 //!
 //! ```ignore
-//! use playwright_rs::Playwright;
+//! use playwright_rs::prelude::*;
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -105,7 +105,7 @@
 //!     ).await;
 //!
 //!     // Take screenshot of specific element
-//!     let element = page.locator("#box").await;
+//!     let element = page.locator("#box");
 //!     let screenshot = element.screenshot(None).await?;
 //!     assert!(!screenshot.is_empty());
 //!
@@ -117,7 +117,7 @@
 //! ## Assertions (expect API)
 //!
 //! ```ignore
-//! use playwright_rs::{expect, Playwright};
+//! use playwright_rs::prelude::*;
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -135,14 +135,14 @@
 //!     ).await;
 //!
 //!     // Assert button states with auto-retry
-//!     let enabled_btn = page.locator("#enabled").await;
+//!     let enabled_btn = page.locator("#enabled");
 //!     expect(enabled_btn.clone()).to_be_enabled().await?;
 //!
-//!     let disabled_btn = page.locator("#disabled").await;
+//!     let disabled_btn = page.locator("#disabled");
 //!     expect(disabled_btn).to_be_disabled().await?;
 //!
 //!     // Assert checkbox state
-//!     let checkbox = page.locator("#checked").await;
+//!     let checkbox = page.locator("#checked");
 //!     expect(checkbox).to_be_checked().await?;
 //!
 //!     browser.close().await?;
@@ -157,6 +157,11 @@ pub mod server;
 pub mod api;
 mod assertions;
 mod error;
+#[cfg(feature = "harness")]
+pub mod harness;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod prelude;
 pub mod protocol;
 
 /// Default timeout in milliseconds for Playwright operations.
@@ -167,6 +172,11 @@ pub mod protocol;
 /// See: <https://playwright.dev/docs/test-timeouts>
 pub const DEFAULT_TIMEOUT_MS: f64 = 30000.0;
 
+/// Default timeout for Playwright operations, as a [`Duration`](std::time::Duration).
+///
+/// Same value as [`DEFAULT_TIMEOUT_MS`]; option structs take their timeouts as `Duration`.
+pub const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(30_000);
+
 // Re-export error types
 pub use error::{Error, Result};
 
@@ -174,32 +184,118 @@ pub use error::{Error, Result};
 pub use assertions::expect;
 
 // Re-export Playwright main entry point and browser API
-pub use protocol::{Browser, BrowserContext, BrowserType, Page, Playwright, Response};
+pub use protocol::{Browser, BrowserContext, BrowserType, Frame, Page, Playwright, Response};
+
+// Re-export page event and network types
+pub use protocol::{Dialog, Download, Request, ResponseObject};
+
+// Re-export page sub-objects
+pub use protocol::{Clock, Keyboard, Mouse, Selectors, ShadowRoot};
 
 // Re-export Locator and element APIs
-pub use protocol::{ElementHandle, Locator};
+pub use protocol::{BoundingBox, ElementHandle, ElementHandleGuard, FrameLocator, Locator};
 
 // Re-export navigation and page options
-pub use protocol::{GotoOptions, WaitUntil};
+pub use protocol::{
+    AddStyleTagOptions, ExpectRequest, GotoOptions, Navigation, NavigationKind,
+    NavigationWithRequest, WaitUntil,
+};
+
+// Re-export locator query types
+pub use protocol::{FilterOptions, GetByRoleOptions, StabilityOptions, TextMatch};
+
+// Re-export locator descriptions and DOM snapshots
+pub use protocol::{
+    DomDiff, DomNode, DomSnapshot, ElementPreview, LocatorDescription, NodeChange, SelectorStep,
+};
+
+// Re-export data extraction, form filling and pagination
+pub use protocol::{
+    Batch, BatchKey, BatchResults, ExtractSchema, Field, FormMapping, FormTarget, Pages,
+};
 
 // Re-export action options
 pub use protocol::{
-    CheckOptions, ClickOptions, FillOptions, HoverOptions, PressOptions, SelectOptions,
+    CheckOptions, ClickOptions, DispatchEventOptions, DragToOptions, FillOptions, HoverOptions,
+    IntoOptions, KeyboardModifier, KeyboardOptions, MouseButton, MouseOptions, Position,
+    PressOptions, SelectOptions, SetInputFilesOptions, TapOptions,
 };
 
+// Re-export init script source type
+pub use protocol::Script;
+
 // Re-export form and input types
-pub use protocol::{FilePayload, SelectOption};
+pub use protocol::{FilePayload, InputFiles, SelectOption};
 
 // Re-export screenshot types
-pub use protocol::{ScreenshotClip, ScreenshotOptions, ScreenshotType};
+pub use protocol::{ScreenshotAnimations, ScreenshotClip, ScreenshotOptions, ScreenshotType};
 
 // Re-export browser context options and storage state types
+#[cfg(feature = "video")]
+pub use protocol::RecordVideo;
 pub use protocol::{
     BrowserContextOptions, Cookie, Geolocation, LocalStorageItem, Origin, StorageState, Viewport,
 };
 
+// Re-export context pooling, sessions and determinism controls
+pub use protocol::{ContextPool, Determinism, PooledContext, RecyclePolicy, SessionManager};
+
+// Re-export network emulation and raw CDP access
+pub use protocol::{CDPSession, NetworkConditions};
+
+// Re-export HTTP requests outside the page and link checking
+#[cfg(feature = "api-request")]
+pub use protocol::{
+    APIRequestContext, APIResponse, FetchOptions, LinkCheckOptions, LinkCheckResult, LinkReport,
+    LinkStatus,
+};
+
+// Re-export tracing
+#[cfg(feature = "trace")]
+pub use protocol::{Tracing, TracingStartOptions};
+
 // Re-export routing types
-pub use protocol::{FulfillOptions, Route};
+pub use protocol::{ContinueOptions, FulfillOptions, Route};
+
+// Re-export driver version detection
+pub use protocol::{Capability, DriverCapabilities, DriverVersion};
+
+// Re-export crash reports
+pub use protocol::{CrashKind, CrashReport};
+
+// Re-export diagnostics, health checks and memory monitoring
+pub use protocol::{
+    Diagnostics, DiagnosticsPolicy, DrainReport, HealthReport, HeapMonitor, HeapSample,
+    PlaywrightStats,
+};
+
+// Re-export session recording and replay
+pub use protocol::{RecordedSession, ReplayOptions, ReplayReport, SessionEntry};
+
+// Re-export WebAssembly hydration and panic detection
+pub use protocol::{Hydration, PanicKind, WasmPanic};
+
+// Re-export experimental component testing
+pub use protocol::{ComponentDescriptor, ComponentTesting, DevServer, DevServerCommand};
+
+// Re-export the transport traits for custom connections
+pub use server::transport::{BoxFuture, Transport, TransportReceiver};
+
+// Re-export deadlock diagnostics
+#[cfg(feature = "deadlock-detection")]
+pub use api::{DeadlockDetection, DeadlockReport, MessageDirection, RecentMessage};
+#[cfg(feature = "deadlock-detection")]
+pub use server::connection::PendingCall;
+
+// Re-export launch options and channel discovery
+pub use api::{
+    installed_channels, ContextProfile, IgnoreDefaultArgs, InstalledChannel, KeepAlive,
+    LaunchOptions, PlaywrightConfig, ProxySettings, StallEvent, StallHandler, StallReason,
+    CHROMIUM_CHANNELS,
+};
+
+// Re-export environment checks
+pub use api::{doctor, is_containerized, BrowserCheck, DoctorReport};
 
-// Re-export launch options
-pub use api::LaunchOptions;
+// Re-export the retry helper
+pub use api::{retrying, RetryPolicy};
@@ -0,0 +1,383 @@
+//! Test doubles for unit-testing code that drives Playwright.
+//!
+//! [`MockDriver`] stands in for the Playwright driver behind a real
+//! [`Playwright`] handle, so application code runs unchanged but no driver or
+//! browser is started. Every protocol call is recorded, and calls answer with
+//! responses scripted per protocol method. Launching a browser, creating a
+//! context and opening a page work out of the box; everything else answers
+//! with an empty result unless scripted.
+//!
+//! Scripted results use the driver's wire format, e.g. `title` answers
+//! `{"value": "..."}`. Run once against a real browser with
+//! `PlaywrightConfig::record_session` or `DEBUG=pw:protocol` to see the
+//! messages a piece of code exchanges.
+//!
+//! The module is behind the opt-in `mock` feature; enable it for tests only:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! playwright-rs = { version = "0.7", features = ["mock"] }
+//! ```
+//!
+//! # Example
+//!
+//! ```ignore
+//! use playwright_rs::mock::MockDriver;
+//! use serde_json::json;
+//!
+//! async fn sign_in(page: &Page, user: &str) -> Result<String> {
+//!     page.locator("#user").fill(user, None).await?;
+//!     page.locator("button[type=submit]").click(None).await?;
+//!     page.title().await
+//! }
+//!
+//! #[tokio::test]
+//! async fn fills_the_form_and_reads_the_title() {
+//!     let driver = MockDriver::new();
+//!     driver.respond("title", json!({"value": "Dashboard"}));
+//!     let page = driver.new_page().await.unwrap();
+//!
+//!     assert_eq!(sign_in(&page, "ada").await.unwrap(), "Dashboard");
+//!     let fills = driver.calls_to("fill");
+//!     assert_eq!(fills[0].params["selector"], "#user");
+//!     assert_eq!(fills[0].params["value"], "ada");
+//! }
+//! ```
+
+use crate::error::Result;
+use crate::protocol::{Page, Playwright};
+use crate::server::transport::{BoxFuture, Transport, TransportReceiver};
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// A protocol call received by a [`MockDriver`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockCall {
+    /// Target object GUID, e.g. `"frame@3"`
+    pub guid: String,
+    /// Protocol method, e.g. `"goto"` or `"click"`
+    pub method: String,
+    /// Method arguments
+    pub params: Value,
+}
+
+/// Scripted answer to a call
+#[derive(Debug, Clone)]
+enum Reply {
+    Result(Value),
+    Error { name: &'static str, message: String },
+}
+
+#[derive(Default)]
+struct MockState {
+    calls: Vec<MockCall>,
+    once: HashMap<String, VecDeque<Reply>>,
+    always: HashMap<String, Reply>,
+    last_guid: u32,
+}
+
+impl MockState {
+    fn next_guid(&mut self, type_name: &str) -> String {
+        self.last_guid += 1;
+        format!("{}@mock{}", type_name.to_lowercase(), self.last_guid)
+    }
+
+    fn scripted(&mut self, method: &str) -> Option<Reply> {
+        self.once
+            .get_mut(method)
+            .and_then(VecDeque::pop_front)
+            .or_else(|| self.always.get(method).cloned())
+    }
+}
+
+/// A fake Playwright driver that records calls and returns scripted responses.
+///
+/// Cloning shares the same recording and script.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Clone, Default)]
+pub struct MockDriver {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockDriver {
+    /// Creates a driver with nothing scripted
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers every call to `method` with `result`.
+    ///
+    /// Replaces an earlier `respond` for the same method; responses queued
+    /// with [`respond_once`](Self::respond_once) are used first.
+    pub fn respond(&self, method: &str, result: Value) -> &Self {
+        self.state
+            .lock()
+            .always
+            .insert(method.to_string(), Reply::Result(result));
+        self
+    }
+
+    /// Answers the next call to `method` with `result`.
+    ///
+    /// Queued responses are used in order, before any [`respond`](Self::respond).
+    pub fn respond_once(&self, method: &str, result: Value) -> &Self {
+        self.queue(method, Reply::Result(result))
+    }
+
+    /// Fails the next call to `method` with a protocol error.
+    pub fn fail_once(&self, method: &str, message: &str) -> &Self {
+        self.queue(
+            method,
+            Reply::Error {
+                name: "Error",
+                message: message.to_string(),
+            },
+        )
+    }
+
+    /// Fails the next call to `method` with [`Error::Timeout`](crate::Error::Timeout).
+    pub fn time_out_once(&self, method: &str) -> &Self {
+        self.queue(
+            method,
+            Reply::Error {
+                name: "TimeoutError",
+                message: format!("Timeout exceeded while running {}", method),
+            },
+        )
+    }
+
+    fn queue(&self, method: &str, reply: Reply) -> &Self {
+        self.state
+            .lock()
+            .once
+            .entry(method.to_string())
+            .or_default()
+            .push_back(reply);
+        self
+    }
+
+    /// All calls received so far, in order.
+    ///
+    /// Includes the calls made while connecting and opening pages, such as
+    /// `initialize`, `launch` and `newPage`.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.state.lock().calls.clone()
+    }
+
+    /// Calls to `method` received so far, in order
+    pub fn calls_to(&self, method: &str) -> Vec<MockCall> {
+        self.state
+            .lock()
+            .calls
+            .iter()
+            .filter(|call| call.method == method)
+            .cloned()
+            .collect()
+    }
+
+    /// Forgets the calls received so far; the script is kept
+    pub fn clear_calls(&self) {
+        self.state.lock().calls.clear();
+    }
+
+    /// Connects a [`Playwright`] handle to this driver.
+    pub async fn connect(&self) -> Result<Playwright> {
+        let (messages, message_rx) = mpsc::unbounded_channel();
+        Playwright::connect_with_transport(
+            MockTransport {
+                state: Arc::clone(&self.state),
+                messages,
+            },
+            MockReceiver,
+            message_rx,
+        )
+        .await
+    }
+
+    /// Connects, launches Chromium and opens a page.
+    pub async fn new_page(&self) -> Result<Page> {
+        let playwright = self.connect().await?;
+        let browser = playwright.chromium().launch().await?;
+        browser.new_page().await
+    }
+}
+
+impl std::fmt::Debug for MockDriver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockDriver")
+            .field("calls", &self.state.lock().calls.len())
+            .finish()
+    }
+}
+
+/// Answers requests in-process, pushing responses and `__create__` events
+/// straight to the connection's channel
+struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+    messages: mpsc::UnboundedSender<Value>,
+}
+
+impl MockTransport {
+    fn create(&self, parent: &str, type_name: &str, guid: &str, initializer: Value) {
+        let _ = self.messages.send(json!({
+            "guid": parent,
+            "method": "__create__",
+            "params": {"type": type_name, "guid": guid, "initializer": initializer},
+        }));
+    }
+
+    /// Built-in answers for the calls that create objects
+    fn lifecycle(&self, state: &mut MockState, guid: &str, method: &str) -> Value {
+        match method {
+            "initialize" => {
+                let mut types = serde_json::Map::new();
+                for name in ["chromium", "firefox", "webkit"] {
+                    let type_guid = format!("browserType@{}", name);
+                    self.create(
+                        guid,
+                        "BrowserType",
+                        &type_guid,
+                        json!({"name": name, "executablePath": ""}),
+                    );
+                    types.insert(name.to_string(), json!({"guid": type_guid}));
+                }
+                self.create(guid, "Playwright", "playwright", Value::Object(types));
+                json!({"playwright": {"guid": "playwright"}})
+            }
+            "launch" => {
+                let browser = state.next_guid("Browser");
+                let name = guid.strip_prefix("browserType@").unwrap_or("chromium");
+                self.create(
+                    guid,
+                    "Browser",
+                    &browser,
+                    json!({"name": name, "version": "0.0.0-mock"}),
+                );
+                json!({"browser": {"guid": browser}})
+            }
+            "newContext" => {
+                let context = state.next_guid("BrowserContext");
+                self.create(guid, "BrowserContext", &context, json!({}));
+                json!({"context": {"guid": context}})
+            }
+            "newPage" => {
+                let frame = state.next_guid("Frame");
+                let page = state.next_guid("Page");
+                self.create(
+                    guid,
+                    "Frame",
+                    &frame,
+                    json!({"url": "about:blank", "name": "", "loadStates": ["domcontentloaded", "load"]}),
+                );
+                self.create(
+                    guid,
+                    "Page",
+                    &page,
+                    json!({"mainFrame": {"guid": frame}, "isClosed": false}),
+                );
+                // Like the driver, announce the page on its context before answering
+                let _ = self.messages.send(json!({
+                    "guid": guid,
+                    "method": "page",
+                    "params": {"page": {"guid": page}},
+                }));
+                json!({"page": {"guid": page}})
+            }
+            "newCDPSession" | "newBrowserCDPSession" => {
+                let session = state.next_guid("CDPSession");
+                self.create(guid, "CDPSession", &session, json!({}));
+                json!({"session": {"guid": session}})
+            }
+            _ => json!({}),
+        }
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&mut self, message: Value) -> BoxFuture<'_, Result<()>> {
+        let id = message["id"].clone();
+        let guid = message["guid"].as_str().unwrap_or_default().to_string();
+        let method = message["method"].as_str().unwrap_or_default().to_string();
+
+        let mut state = self.state.lock();
+        state.calls.push(MockCall {
+            guid: guid.clone(),
+            method: method.clone(),
+            params: message.get("params").cloned().unwrap_or(Value::Null),
+        });
+        let response = match state.scripted(&method) {
+            Some(Reply::Result(result)) => json!({"id": id, "result": result}),
+            Some(Reply::Error { name, message }) => {
+                json!({"id": id, "error": {"error": {"name": name, "message": message}}})
+            }
+            None => json!({"id": id, "result": self.lifecycle(&mut state, &guid, &method)}),
+        };
+        drop(state);
+
+        let _ = self.messages.send(response);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Nothing to read: [`MockTransport`] answers on the connection's channel directly
+struct MockReceiver;
+
+impl TransportReceiver for MockReceiver {
+    fn run(self: Box<Self>) -> BoxFuture<'static, Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mock_driver_opens_page() {
+        let driver = MockDriver::new();
+        let page = driver.new_page().await.unwrap();
+
+        assert_eq!(page.url(), "about:blank");
+        let methods: Vec<_> = driver.calls().into_iter().map(|c| c.method).collect();
+        assert!(methods.contains(&"launch".to_string()));
+        assert!(methods.contains(&"newPage".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_mock_driver_records_and_scripts_calls() {
+        let driver = MockDriver::new();
+        driver.respond("title", json!({"value": "Dashboard"}));
+        driver.respond_once("title", json!({"value": "Loading"}));
+        let page = driver.new_page().await.unwrap();
+        driver.clear_calls();
+
+        page.locator("#user").fill("ada", None).await.unwrap();
+        assert_eq!(page.title().await.unwrap(), "Loading");
+        assert_eq!(page.title().await.unwrap(), "Dashboard");
+
+        let fills = driver.calls_to("fill");
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].params["selector"], "#user");
+        assert_eq!(fills[0].params["value"], "ada");
+        assert_eq!(driver.calls_to("title").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_mock_driver_scripted_errors() {
+        let driver = MockDriver::new();
+        driver
+            .time_out_once("click")
+            .fail_once("click", "Element is detached");
+        let page = driver.new_page().await.unwrap();
+
+        let first = page.locator("button").click(None).await;
+        assert!(matches!(first, Err(crate::Error::Timeout(_))));
+        let second = page.locator("button").click(None).await;
+        assert!(second.is_err());
+        page.locator("button").click(None).await.unwrap();
+    }
+}
@@ -0,0 +1,36 @@
+//! The most commonly used types, for glob import.
+//!
+//! Brings in the entry point, the page and locator APIs, `expect`, the common
+//! action, filter and text-matching options and the error type. `Result` is
+//! left out on purpose: a glob import would shadow the std prelude's
+//! two-parameter `Result` that `fn main() -> Result<(), Box<dyn Error>>`
+//! relies on; use
+//! [`playwright_rs::Result`](crate::Result) by path.
+//!
+//! ```ignore
+//! use playwright_rs::prelude::*;
+//!
+//! let playwright = Playwright::launch().await?;
+//! let browser = playwright.chromium().launch().await?;
+//! let page = browser.new_page().await?;
+//! page.goto("https://example.com", None).await?;
+//! page.locator("a").click(ClickOptions::builder().timeout(DEFAULT_TIMEOUT).build()).await?;
+//! expect(page.locator("h1")).to_have_text("Example Domain").await?;
+//! ```
+
+pub use crate::assertions::expect;
+pub use crate::error::Error;
+pub use crate::DEFAULT_TIMEOUT;
+
+pub use crate::api::LaunchOptions;
+pub use crate::protocol::{
+    Browser, BrowserContext, BrowserContextOptions, BrowserType, ElementHandle, Frame,
+    FrameLocator, Locator, Page, Playwright, Response, Route,
+};
+
+pub use crate::protocol::{
+    CheckOptions, ClickOptions, DragToOptions, FilePayload, FillOptions, FilterOptions,
+    FulfillOptions, GetByRoleOptions, GotoOptions, HoverOptions, InputFiles, IntoOptions,
+    PressOptions, ScreenshotClip, ScreenshotOptions, ScreenshotType, SelectOption, SelectOptions,
+    SetInputFilesOptions, TapOptions, TextMatch, WaitUntil,
+};
@@ -1,551 +1,195 @@
 // Action options for various Locator methods
 //
-// Provides configuration for fill, press, check, hover, and select actions.
+// Provides configuration for fill, press, check, hover, tap, drag, select,
+// dispatch_event and set_input_files actions.
 
 use super::click::{KeyboardModifier, Position};
-
-/// Fill options
-///
-/// Configuration options for fill() action.
-///
-/// See: <https://playwright.dev/docs/api/class-locator#locator-fill>
-#[derive(Debug, Clone, Default)]
-pub struct FillOptions {
-    /// Whether to bypass actionability checks
-    pub force: Option<bool>,
-    /// Maximum time in milliseconds
-    pub timeout: Option<f64>,
-}
-
-impl FillOptions {
-    /// Create a new builder for FillOptions
-    pub fn builder() -> FillOptionsBuilder {
-        FillOptionsBuilder::default()
+use super::options::options;
+use std::time::Duration;
+
+options! {
+    /// Fill options
+    ///
+    /// Configuration options for fill() action.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-fill>
+    pub struct FillOptions => FillOptionsBuilder {
+        /// Whether to bypass actionability checks
+        pub force: bool,
+        /// Maximum time
+        #[serde(with = "super::options::millis")]
+        pub timeout: Duration = crate::DEFAULT_TIMEOUT,
     }
-
-    /// Convert options to JSON value for protocol
-    pub(crate) fn to_json(&self) -> serde_json::Value {
-        let mut json = serde_json::json!({});
-
-        if let Some(force) = self.force {
-            json["force"] = serde_json::json!(force);
-        }
-
-        // Timeout is required in Playwright 1.56.1+
-        if let Some(timeout) = self.timeout {
-            json["timeout"] = serde_json::json!(timeout);
-        } else {
-            json["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
-        }
-
-        json
-    }
-}
-
-/// Builder for FillOptions
-#[derive(Debug, Clone, Default)]
-pub struct FillOptionsBuilder {
-    force: Option<bool>,
-    timeout: Option<f64>,
 }
 
-impl FillOptionsBuilder {
-    /// Bypass actionability checks
-    pub fn force(mut self, force: bool) -> Self {
-        self.force = Some(force);
-        self
-    }
-
-    /// Set timeout in milliseconds
-    pub fn timeout(mut self, timeout: f64) -> Self {
-        self.timeout = Some(timeout);
-        self
-    }
-
-    /// Build the FillOptions
-    pub fn build(self) -> FillOptions {
-        FillOptions {
-            force: self.force,
-            timeout: self.timeout,
-        }
+options! {
+    /// Press options
+    ///
+    /// Configuration options for press() action.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-press>
+    pub struct PressOptions => PressOptionsBuilder {
+        /// Time to wait between keydown and keyup
+        #[serde(with = "super::options::millis")]
+        pub delay: Duration,
+        /// Maximum time
+        #[serde(with = "super::options::millis")]
+        pub timeout: Duration = crate::DEFAULT_TIMEOUT,
     }
 }
 
-/// Press options
-///
-/// Configuration options for press() action.
-///
-/// See: <https://playwright.dev/docs/api/class-locator#locator-press>
-#[derive(Debug, Clone, Default)]
-pub struct PressOptions {
-    /// Time to wait between keydown and keyup in milliseconds
-    pub delay: Option<f64>,
-    /// Maximum time in milliseconds
-    pub timeout: Option<f64>,
-}
-
-impl PressOptions {
-    /// Create a new builder for PressOptions
-    pub fn builder() -> PressOptionsBuilder {
-        PressOptionsBuilder::default()
-    }
-
-    /// Convert options to JSON value for protocol
-    pub(crate) fn to_json(&self) -> serde_json::Value {
-        let mut json = serde_json::json!({});
-
-        if let Some(delay) = self.delay {
-            json["delay"] = serde_json::json!(delay);
-        }
-
-        // Timeout is required in Playwright 1.56.1+
-        if let Some(timeout) = self.timeout {
-            json["timeout"] = serde_json::json!(timeout);
-        } else {
-            json["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
-        }
-
-        json
+options! {
+    /// Check options
+    ///
+    /// Configuration options for check() and uncheck() actions.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-check>
+    pub struct CheckOptions => CheckOptionsBuilder {
+        /// Whether to bypass actionability checks
+        pub force: bool,
+        /// Position to click relative to element top-left corner
+        pub position: Position,
+        /// Maximum time
+        #[serde(with = "super::options::millis")]
+        pub timeout: Duration = crate::DEFAULT_TIMEOUT,
+        /// Perform actionability checks without checking
+        pub trial: bool,
     }
 }
 
-/// Builder for PressOptions
-#[derive(Debug, Clone, Default)]
-pub struct PressOptionsBuilder {
-    delay: Option<f64>,
-    timeout: Option<f64>,
-}
-
-impl PressOptionsBuilder {
-    /// Set delay between keydown and keyup in milliseconds
-    pub fn delay(mut self, delay: f64) -> Self {
-        self.delay = Some(delay);
-        self
-    }
-
-    /// Set timeout in milliseconds
-    pub fn timeout(mut self, timeout: f64) -> Self {
-        self.timeout = Some(timeout);
-        self
-    }
-
-    /// Build the PressOptions
-    pub fn build(self) -> PressOptions {
-        PressOptions {
-            delay: self.delay,
-            timeout: self.timeout,
-        }
+options! {
+    /// Hover options
+    ///
+    /// Configuration options for hover() action.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-hover>
+    pub struct HoverOptions => HoverOptionsBuilder {
+        /// Whether to bypass actionability checks
+        pub force: bool,
+        /// Modifier keys to press during hover
+        pub modifiers: Vec<KeyboardModifier>,
+        /// Position to hover relative to element top-left corner
+        pub position: Position,
+        /// Maximum time
+        #[serde(with = "super::options::millis")]
+        pub timeout: Duration = crate::DEFAULT_TIMEOUT,
+        /// Perform actionability checks without hovering
+        pub trial: bool,
     }
 }
 
-/// Check options
-///
-/// Configuration options for check() and uncheck() actions.
-///
-/// See: <https://playwright.dev/docs/api/class-locator#locator-check>
-#[derive(Debug, Clone, Default)]
-pub struct CheckOptions {
-    /// Whether to bypass actionability checks
-    pub force: Option<bool>,
-    /// Position to click relative to element top-left corner
-    pub position: Option<Position>,
-    /// Maximum time in milliseconds
-    pub timeout: Option<f64>,
-    /// Perform actionability checks without checking
-    pub trial: Option<bool>,
-}
-
-impl CheckOptions {
-    /// Create a new builder for CheckOptions
-    pub fn builder() -> CheckOptionsBuilder {
-        CheckOptionsBuilder::default()
-    }
-
-    /// Convert options to JSON value for protocol
-    pub(crate) fn to_json(&self) -> serde_json::Value {
-        let mut json = serde_json::json!({});
-
-        if let Some(force) = self.force {
-            json["force"] = serde_json::json!(force);
-        }
-
-        if let Some(position) = &self.position {
-            json["position"] = serde_json::to_value(position).unwrap();
-        }
-
-        // Timeout is required in Playwright 1.56.1+
-        if let Some(timeout) = self.timeout {
-            json["timeout"] = serde_json::json!(timeout);
-        } else {
-            json["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
-        }
-
-        if let Some(trial) = self.trial {
-            json["trial"] = serde_json::json!(trial);
-        }
-
-        json
+options! {
+    /// Tap options
+    ///
+    /// Configuration options for tap() action.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-tap>
+    pub struct TapOptions => TapOptionsBuilder {
+        /// Whether to bypass actionability checks
+        pub force: bool,
+        /// Modifier keys to press during the tap
+        pub modifiers: Vec<KeyboardModifier>,
+        /// Position to tap relative to element top-left corner
+        pub position: Position,
+        /// Maximum time
+        #[serde(with = "super::options::millis")]
+        pub timeout: Duration = crate::DEFAULT_TIMEOUT,
+        /// Perform actionability checks without tapping
+        pub trial: bool,
     }
 }
 
-/// Builder for CheckOptions
-#[derive(Debug, Clone, Default)]
-pub struct CheckOptionsBuilder {
-    force: Option<bool>,
-    position: Option<Position>,
-    timeout: Option<f64>,
-    trial: Option<bool>,
-}
-
-impl CheckOptionsBuilder {
-    /// Bypass actionability checks
-    pub fn force(mut self, force: bool) -> Self {
-        self.force = Some(force);
-        self
-    }
-
-    /// Set position to click relative to element top-left corner
-    pub fn position(mut self, position: Position) -> Self {
-        self.position = Some(position);
-        self
-    }
-
-    /// Set timeout in milliseconds
-    pub fn timeout(mut self, timeout: f64) -> Self {
-        self.timeout = Some(timeout);
-        self
-    }
-
-    /// Perform actionability checks without checking
-    pub fn trial(mut self, trial: bool) -> Self {
-        self.trial = Some(trial);
-        self
-    }
-
-    /// Build the CheckOptions
-    pub fn build(self) -> CheckOptions {
-        CheckOptions {
-            force: self.force,
-            position: self.position,
-            timeout: self.timeout,
-            trial: self.trial,
-        }
+options! {
+    /// Drag options
+    ///
+    /// Configuration options for drag_to() action.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-drag-to>
+    pub struct DragToOptions => DragToOptionsBuilder {
+        /// Whether to bypass actionability checks
+        pub force: bool,
+        /// Point to grab, relative to the source element's top-left corner
+        pub source_position: Position,
+        /// Point to drop at, relative to the target element's top-left corner
+        pub target_position: Position,
+        /// Maximum time
+        #[serde(with = "super::options::millis")]
+        pub timeout: Duration = crate::DEFAULT_TIMEOUT,
+        /// Perform actionability checks without dragging
+        pub trial: bool,
     }
 }
 
-/// Hover options
-///
-/// Configuration options for hover() action.
-///
-/// See: <https://playwright.dev/docs/api/class-locator#locator-hover>
-#[derive(Debug, Clone, Default)]
-pub struct HoverOptions {
-    /// Whether to bypass actionability checks
-    pub force: Option<bool>,
-    /// Modifier keys to press during hover
-    pub modifiers: Option<Vec<KeyboardModifier>>,
-    /// Position to hover relative to element top-left corner
-    pub position: Option<Position>,
-    /// Maximum time in milliseconds
-    pub timeout: Option<f64>,
-    /// Perform actionability checks without hovering
-    pub trial: Option<bool>,
-}
-
-impl HoverOptions {
-    /// Create a new builder for HoverOptions
-    pub fn builder() -> HoverOptionsBuilder {
-        HoverOptionsBuilder::default()
-    }
-
-    /// Convert options to JSON value for protocol
-    pub(crate) fn to_json(&self) -> serde_json::Value {
-        let mut json = serde_json::json!({});
-
-        if let Some(force) = self.force {
-            json["force"] = serde_json::json!(force);
-        }
-
-        if let Some(modifiers) = &self.modifiers {
-            json["modifiers"] = serde_json::to_value(modifiers).unwrap();
-        }
-
-        if let Some(position) = &self.position {
-            json["position"] = serde_json::to_value(position).unwrap();
-        }
-
-        // Timeout is required in Playwright 1.56.1+
-        if let Some(timeout) = self.timeout {
-            json["timeout"] = serde_json::json!(timeout);
-        } else {
-            json["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
-        }
-
-        if let Some(trial) = self.trial {
-            json["trial"] = serde_json::json!(trial);
-        }
-
-        json
+options! {
+    /// Select options
+    ///
+    /// Configuration options for select_option() action.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-select-option>
+    pub struct SelectOptions => SelectOptionsBuilder {
+        /// Whether to bypass actionability checks
+        pub force: bool,
+        /// Maximum time
+        #[serde(with = "super::options::millis")]
+        pub timeout: Duration = crate::DEFAULT_TIMEOUT,
     }
 }
 
-/// Builder for HoverOptions
-#[derive(Debug, Clone, Default)]
-pub struct HoverOptionsBuilder {
-    force: Option<bool>,
-    modifiers: Option<Vec<KeyboardModifier>>,
-    position: Option<Position>,
-    timeout: Option<f64>,
-    trial: Option<bool>,
-}
-
-impl HoverOptionsBuilder {
-    /// Bypass actionability checks
-    pub fn force(mut self, force: bool) -> Self {
-        self.force = Some(force);
-        self
+options! {
+    /// Dispatch event options
+    ///
+    /// Configuration options for dispatch_event() action.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-dispatch-event>
+    pub struct DispatchEventOptions => DispatchEventOptionsBuilder {
+        /// Maximum time
+        #[serde(with = "super::options::millis")]
+        pub timeout: Duration = crate::DEFAULT_TIMEOUT,
     }
-
-    /// Set modifier keys to press during hover
-    pub fn modifiers(mut self, modifiers: Vec<KeyboardModifier>) -> Self {
-        self.modifiers = Some(modifiers);
-        self
-    }
-
-    /// Set position to hover relative to element top-left corner
-    pub fn position(mut self, position: Position) -> Self {
-        self.position = Some(position);
-        self
-    }
-
-    /// Set timeout in milliseconds
-    pub fn timeout(mut self, timeout: f64) -> Self {
-        self.timeout = Some(timeout);
-        self
-    }
-
-    /// Perform actionability checks without hovering
-    pub fn trial(mut self, trial: bool) -> Self {
-        self.trial = Some(trial);
-        self
-    }
-
-    /// Build the HoverOptions
-    pub fn build(self) -> HoverOptions {
-        HoverOptions {
-            force: self.force,
-            modifiers: self.modifiers,
-            position: self.position,
-            timeout: self.timeout,
-            trial: self.trial,
-        }
-    }
-}
-
-/// Select options
-///
-/// Configuration options for select_option() action.
-///
-/// See: <https://playwright.dev/docs/api/class-locator#locator-select-option>
-#[derive(Debug, Clone, Default)]
-pub struct SelectOptions {
-    /// Whether to bypass actionability checks
-    pub force: Option<bool>,
-    /// Maximum time in milliseconds
-    pub timeout: Option<f64>,
 }
 
-impl SelectOptions {
-    /// Create a new builder for SelectOptions
-    pub fn builder() -> SelectOptionsBuilder {
-        SelectOptionsBuilder::default()
-    }
-
-    /// Convert options to JSON value for protocol
-    pub(crate) fn to_json(&self) -> serde_json::Value {
-        let mut json = serde_json::json!({});
-
-        if let Some(force) = self.force {
-            json["force"] = serde_json::json!(force);
-        }
-
-        // Timeout is required in Playwright 1.56.1+
-        if let Some(timeout) = self.timeout {
-            json["timeout"] = serde_json::json!(timeout);
-        } else {
-            json["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
-        }
-
-        json
-    }
-}
-
-/// Builder for SelectOptions
-#[derive(Debug, Clone, Default)]
-pub struct SelectOptionsBuilder {
-    force: Option<bool>,
-    timeout: Option<f64>,
-}
-
-impl SelectOptionsBuilder {
-    /// Bypass actionability checks
-    pub fn force(mut self, force: bool) -> Self {
-        self.force = Some(force);
-        self
-    }
-
-    /// Set timeout in milliseconds
-    pub fn timeout(mut self, timeout: f64) -> Self {
-        self.timeout = Some(timeout);
-        self
-    }
-
-    /// Build the SelectOptions
-    pub fn build(self) -> SelectOptions {
-        SelectOptions {
-            force: self.force,
-            timeout: self.timeout,
-        }
+options! {
+    /// Set input files options
+    ///
+    /// Configuration options for set_input_files() action.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
+    pub struct SetInputFilesOptions => SetInputFilesOptionsBuilder {
+        /// Maximum time
+        #[serde(with = "super::options::millis")]
+        pub timeout: Duration = crate::DEFAULT_TIMEOUT,
     }
 }
 
-/// Keyboard options
-///
-/// Configuration options for keyboard.press() and keyboard.type_text() methods.
-///
-/// See: <https://playwright.dev/docs/api/class-keyboard#keyboard-press>
-#[derive(Debug, Clone, Default)]
-pub struct KeyboardOptions {
-    /// Time to wait between key presses in milliseconds
-    pub delay: Option<f64>,
-}
-
-impl KeyboardOptions {
-    /// Create a new builder for KeyboardOptions
-    pub fn builder() -> KeyboardOptionsBuilder {
-        KeyboardOptionsBuilder::default()
-    }
-
-    /// Convert options to JSON value for protocol
-    pub(crate) fn to_json(&self) -> serde_json::Value {
-        let mut json = serde_json::json!({});
-
-        if let Some(delay) = self.delay {
-            json["delay"] = serde_json::json!(delay);
-        }
-
-        json
-    }
-}
-
-/// Builder for KeyboardOptions
-#[derive(Debug, Clone, Default)]
-pub struct KeyboardOptionsBuilder {
-    delay: Option<f64>,
-}
-
-impl KeyboardOptionsBuilder {
-    /// Set delay between key presses in milliseconds
-    pub fn delay(mut self, delay: f64) -> Self {
-        self.delay = Some(delay);
-        self
-    }
-
-    /// Build the KeyboardOptions
-    pub fn build(self) -> KeyboardOptions {
-        KeyboardOptions { delay: self.delay }
-    }
-}
-
-/// Mouse options
-///
-/// Configuration options for mouse methods.
-///
-/// See: <https://playwright.dev/docs/api/class-mouse>
-#[derive(Debug, Clone, Default)]
-pub struct MouseOptions {
-    /// Mouse button to use
-    pub button: Option<super::click::MouseButton>,
-    /// Number of clicks
-    pub click_count: Option<u32>,
-    /// Time to wait between mousedown and mouseup in milliseconds
-    pub delay: Option<f64>,
-    /// Number of intermediate mousemove events (for move operations)
-    pub steps: Option<u32>,
-}
-
-impl MouseOptions {
-    /// Create a new builder for MouseOptions
-    pub fn builder() -> MouseOptionsBuilder {
-        MouseOptionsBuilder::default()
-    }
-
-    /// Convert options to JSON value for protocol
-    pub(crate) fn to_json(&self) -> serde_json::Value {
-        let mut json = serde_json::json!({});
-
-        if let Some(button) = &self.button {
-            json["button"] = serde_json::to_value(button).unwrap();
-        }
-
-        if let Some(click_count) = self.click_count {
-            json["clickCount"] = serde_json::json!(click_count);
-        }
-
-        if let Some(delay) = self.delay {
-            json["delay"] = serde_json::json!(delay);
-        }
-
-        if let Some(steps) = self.steps {
-            json["steps"] = serde_json::json!(steps);
-        }
-
-        json
+options! {
+    /// Keyboard options
+    ///
+    /// Configuration options for keyboard.press() and keyboard.type_text() methods.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-keyboard#keyboard-press>
+    pub struct KeyboardOptions => KeyboardOptionsBuilder {
+        /// Time to wait between key presses
+        #[serde(with = "super::options::millis")]
+        pub delay: Duration,
     }
 }
 
-/// Builder for MouseOptions
-#[derive(Debug, Clone, Default)]
-pub struct MouseOptionsBuilder {
-    button: Option<super::click::MouseButton>,
-    click_count: Option<u32>,
-    delay: Option<f64>,
-    steps: Option<u32>,
-}
-
-impl MouseOptionsBuilder {
-    /// Set the mouse button
-    pub fn button(mut self, button: super::click::MouseButton) -> Self {
-        self.button = Some(button);
-        self
-    }
-
-    /// Set the number of clicks
-    pub fn click_count(mut self, click_count: u32) -> Self {
-        self.click_count = Some(click_count);
-        self
-    }
-
-    /// Set delay between mousedown and mouseup in milliseconds
-    pub fn delay(mut self, delay: f64) -> Self {
-        self.delay = Some(delay);
-        self
-    }
-
-    /// Set number of intermediate mousemove events
-    pub fn steps(mut self, steps: u32) -> Self {
-        self.steps = Some(steps);
-        self
-    }
-
-    /// Build the MouseOptions
-    pub fn build(self) -> MouseOptions {
-        MouseOptions {
-            button: self.button,
-            click_count: self.click_count,
-            delay: self.delay,
-            steps: self.steps,
-        }
+options! {
+    /// Mouse options
+    ///
+    /// Configuration options for mouse methods.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-mouse>
+    pub struct MouseOptions => MouseOptionsBuilder {
+        /// Mouse button to use
+        pub button: super::click::MouseButton,
+        /// Number of clicks
+        pub click_count: u32,
+        /// Time to wait between mousedown and mouseup
+        #[serde(with = "super::options::millis")]
+        pub delay: Duration,
+        /// Number of intermediate mousemove events (for move operations)
+        pub steps: u32,
     }
 }
 
@@ -556,7 +200,10 @@ mod tests {
 
     #[test]
     fn test_fill_options_builder() {
-        let options = FillOptions::builder().force(true).timeout(5000.0).build();
+        let options = FillOptions::builder()
+            .force(true)
+            .timeout(Duration::from_millis(5000))
+            .build();
 
         let json = options.to_json();
         assert_eq!(json["force"], true);
@@ -565,7 +212,10 @@ mod tests {
 
     #[test]
     fn test_press_options_builder() {
-        let options = PressOptions::builder().delay(100.0).timeout(3000.0).build();
+        let options = PressOptions::builder()
+            .delay(Duration::from_millis(100))
+            .timeout(Duration::from_millis(3000))
+            .build();
 
         let json = options.to_json();
         assert_eq!(json["delay"], 100.0);
@@ -577,7 +227,7 @@ mod tests {
         let options = CheckOptions::builder()
             .force(true)
             .position(Position { x: 5.0, y: 10.0 })
-            .timeout(2000.0)
+            .timeout(Duration::from_millis(2000))
             .trial(true)
             .build();
 
@@ -595,7 +245,7 @@ mod tests {
             .force(true)
             .modifiers(vec![KeyboardModifier::Shift])
             .position(Position { x: 10.0, y: 20.0 })
-            .timeout(4000.0)
+            .timeout(Duration::from_millis(4000))
             .trial(false)
             .build();
 
@@ -608,9 +258,45 @@ mod tests {
         assert_eq!(json["trial"], false);
     }
 
+    #[test]
+    fn test_tap_options_builder() {
+        let options = TapOptions::builder()
+            .modifiers(vec![KeyboardModifier::Alt])
+            .position(Position { x: 3.0, y: 4.0 })
+            .trial(true)
+            .build();
+
+        let json = options.to_json();
+        assert_eq!(json["modifiers"], serde_json::json!(["Alt"]));
+        assert_eq!(json["position"]["x"], 3.0);
+        assert_eq!(json["position"]["y"], 4.0);
+        assert_eq!(json["timeout"], crate::DEFAULT_TIMEOUT_MS);
+        assert_eq!(json["trial"], true);
+        assert!(json.get("force").is_none());
+    }
+
+    #[test]
+    fn test_drag_to_options_builder() {
+        let options = DragToOptions::builder()
+            .source_position(Position { x: 5.0, y: 5.0 })
+            .target_position(Position { x: 30.0, y: 40.0 })
+            .trial(true)
+            .build();
+
+        let json = options.to_json();
+        assert_eq!(json["sourcePosition"]["x"], 5.0);
+        assert_eq!(json["targetPosition"]["y"], 40.0);
+        assert_eq!(json["trial"], true);
+        assert_eq!(json["timeout"], crate::DEFAULT_TIMEOUT_MS);
+        assert!(json.get("force").is_none());
+    }
+
     #[test]
     fn test_select_options_builder() {
-        let options = SelectOptions::builder().force(true).timeout(6000.0).build();
+        let options = SelectOptions::builder()
+            .force(true)
+            .timeout(Duration::from_millis(6000))
+            .build();
 
         let json = options.to_json();
         assert_eq!(json["force"], true);
@@ -619,7 +305,9 @@ mod tests {
 
     #[test]
     fn test_keyboard_options_builder() {
-        let options = KeyboardOptions::builder().delay(50.0).build();
+        let options = KeyboardOptions::builder()
+            .delay(Duration::from_millis(50))
+            .build();
 
         let json = options.to_json();
         assert_eq!(json["delay"], 50.0);
@@ -630,7 +318,7 @@ mod tests {
         let options = MouseOptions::builder()
             .button(MouseButton::Right)
             .click_count(2)
-            .delay(100.0)
+            .delay(Duration::from_millis(100))
             .steps(10)
             .build();
 
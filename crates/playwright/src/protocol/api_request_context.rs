@@ -0,0 +1,431 @@
+// APIRequestContext - HTTP requests sharing a browser context's cookies
+//
+// Every BrowserContext has an APIRequestContext (initializer field
+// `requestContext`). Requests are made by the Playwright server, so they share
+// the context's cookie jar and proxy settings and are not subject to CORS.
+//
+// Response bodies stay on the server until read or disposed; APIResponse fetches
+// the body on demand via `fetchResponseBody` and releases it with
+// `disposeAPIResponse`.
+//
+// See: https://playwright.dev/docs/api/class-apirequestcontext
+
+use crate::error::{Error, Result};
+use crate::server::channel::Channel;
+use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
+use base64::Engine;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Makes HTTP requests from the Playwright server.
+///
+/// Obtain one with [`BrowserContext::request`](crate::protocol::BrowserContext::request).
+///
+/// # Example
+///
+/// ```ignore
+/// let request = context.request().await?;
+/// let response = request.get("https://example.com/api/health", None).await?;
+/// assert!(response.ok());
+/// println!("{}", response.text().await?);
+/// ```
+///
+/// See: <https://playwright.dev/docs/api/class-apirequestcontext>
+#[derive(Clone)]
+pub struct APIRequestContext {
+    base: ChannelOwnerImpl,
+}
+
+impl APIRequestContext {
+    /// Creates a new APIRequestContext from protocol initialization
+    pub fn new(
+        parent: Arc<dyn ChannelOwner>,
+        type_name: String,
+        guid: Arc<str>,
+        initializer: Value,
+    ) -> Result<Self> {
+        let base = ChannelOwnerImpl::new(
+            ParentOrConnection::Parent(parent),
+            type_name,
+            guid,
+            initializer,
+        );
+
+        Ok(Self { base })
+    }
+
+    fn channel(&self) -> &Channel {
+        self.base.channel()
+    }
+
+    /// Sends a request to `url`.
+    ///
+    /// The method defaults to GET. Responses with error statuses are returned
+    /// as-is unless [`FetchOptions::fail_on_status_code`] is set.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-apirequestcontext#api-request-context-fetch>
+    pub async fn fetch(
+        &self,
+        url: &str,
+        options: impl crate::protocol::IntoOptions<FetchOptions>,
+    ) -> Result<APIResponse> {
+        let options = options.into_options().unwrap_or_default();
+        let params = options.to_params(url);
+
+        #[derive(Deserialize)]
+        struct FetchResult {
+            response: Value,
+        }
+
+        let result: FetchResult = self.channel().send("fetch", params).await?;
+        APIResponse::from_protocol(self.clone(), &result.response)
+    }
+
+    /// Sends a GET request.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-apirequestcontext#api-request-context-get>
+    pub async fn get(
+        &self,
+        url: &str,
+        options: impl crate::protocol::IntoOptions<FetchOptions>,
+    ) -> Result<APIResponse> {
+        let options = options.into_options().unwrap_or_default().method("GET");
+        self.fetch(url, Some(options)).await
+    }
+
+    /// Sends a HEAD request.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-apirequestcontext#api-request-context-head>
+    pub async fn head(
+        &self,
+        url: &str,
+        options: impl crate::protocol::IntoOptions<FetchOptions>,
+    ) -> Result<APIResponse> {
+        let options = options.into_options().unwrap_or_default().method("HEAD");
+        self.fetch(url, Some(options)).await
+    }
+
+    async fn response_body(&self, fetch_uid: &str) -> Result<Vec<u8>> {
+        let result: Value = self
+            .channel()
+            .send("fetchResponseBody", json!({ "fetchUid": fetch_uid }))
+            .await?;
+        match result.get("binary").and_then(|b| b.as_str()) {
+            Some(encoded) => base64::prelude::BASE64_STANDARD
+                .decode(encoded)
+                .map_err(|e| Error::ProtocolError(format!("Failed to decode body: {}", e))),
+            None => Err(Error::ProtocolError(
+                "Response body is no longer available".to_string(),
+            )),
+        }
+    }
+
+    async fn dispose_response(&self, fetch_uid: &str) -> Result<()> {
+        self.channel()
+            .send_no_result("disposeAPIResponse", json!({ "fetchUid": fetch_uid }))
+            .await
+    }
+}
+
+/// Options for [`APIRequestContext::fetch`].
+///
+/// See: <https://playwright.dev/docs/api/class-apirequestcontext#api-request-context-fetch>
+#[derive(Debug, Clone, Default)]
+pub struct FetchOptions {
+    /// HTTP method (default: GET)
+    pub method: Option<String>,
+    /// Extra request headers
+    pub headers: Option<HashMap<String, String>>,
+    /// Raw request body
+    pub data: Option<Vec<u8>>,
+    /// Request timeout (default: 30 seconds)
+    pub timeout: Option<Duration>,
+    /// Return an error for non-2xx/3xx statuses
+    pub fail_on_status_code: Option<bool>,
+    /// Ignore HTTPS errors
+    pub ignore_https_errors: Option<bool>,
+    /// Maximum redirects to follow (0 disables following)
+    pub max_redirects: Option<u32>,
+}
+
+impl FetchOptions {
+    /// Creates empty options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the HTTP method
+    pub fn method(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into());
+        self
+    }
+
+    /// Adds a request header
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the raw request body
+    pub fn data(mut self, data: impl Into<Vec<u8>>) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+
+    /// Sets the request timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns an error for non-2xx/3xx statuses
+    pub fn fail_on_status_code(mut self, fail: bool) -> Self {
+        self.fail_on_status_code = Some(fail);
+        self
+    }
+
+    /// Ignores HTTPS errors
+    pub fn ignore_https_errors(mut self, ignore: bool) -> Self {
+        self.ignore_https_errors = Some(ignore);
+        self
+    }
+
+    /// Sets the maximum number of redirects to follow
+    pub fn max_redirects(mut self, max_redirects: u32) -> Self {
+        self.max_redirects = Some(max_redirects);
+        self
+    }
+
+    fn to_params(&self, url: &str) -> Value {
+        let timeout_ms = self
+            .timeout
+            .map(|t| t.as_millis() as f64)
+            .unwrap_or(crate::DEFAULT_TIMEOUT_MS);
+        let mut params = json!({
+            "url": url,
+            "timeout": timeout_ms,
+        });
+        if let Some(method) = &self.method {
+            params["method"] = json!(method);
+        }
+        if let Some(headers) = &self.headers {
+            let headers: Vec<Value> = headers
+                .iter()
+                .map(|(name, value)| json!({ "name": name, "value": value }))
+                .collect();
+            params["headers"] = json!(headers);
+        }
+        if let Some(data) = &self.data {
+            params["postData"] = json!(base64::prelude::BASE64_STANDARD.encode(data));
+        }
+        if let Some(fail) = self.fail_on_status_code {
+            params["failOnStatusCode"] = json!(fail);
+        }
+        if let Some(ignore) = self.ignore_https_errors {
+            params["ignoreHTTPSErrors"] = json!(ignore);
+        }
+        if let Some(max_redirects) = self.max_redirects {
+            params["maxRedirects"] = json!(max_redirects);
+        }
+        params
+    }
+}
+
+/// Response to an [`APIRequestContext`] request.
+///
+/// The body is held by the server until read with [`body`](APIResponse::body)
+/// or released with [`dispose`](APIResponse::dispose).
+///
+/// See: <https://playwright.dev/docs/api/class-apiresponse>
+#[derive(Clone)]
+pub struct APIResponse {
+    context: APIRequestContext,
+    fetch_uid: String,
+    url: String,
+    status: u16,
+    status_text: String,
+    headers: HashMap<String, String>,
+}
+
+impl APIResponse {
+    fn from_protocol(context: APIRequestContext, response: &Value) -> Result<Self> {
+        let field = |name: &str| {
+            response[name]
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| Error::ProtocolError(format!("APIResponse missing {}", name)))
+        };
+        let headers = response["headers"]
+            .as_array()
+            .map(|headers| {
+                headers
+                    .iter()
+                    .filter_map(|h| {
+                        Some((
+                            h["name"].as_str()?.to_lowercase(),
+                            h["value"].as_str()?.to_string(),
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            fetch_uid: field("fetchUid")?,
+            url: field("url")?,
+            status: response["status"]
+                .as_u64()
+                .ok_or_else(|| Error::ProtocolError("APIResponse missing status".to_string()))?
+                as u16,
+            status_text: response["statusText"].as_str().unwrap_or("").to_string(),
+            headers,
+            context,
+        })
+    }
+
+    /// Final URL after redirects
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// HTTP status code
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// HTTP status text
+    pub fn status_text(&self) -> &str {
+        &self.status_text
+    }
+
+    /// True for statuses 200-299
+    pub fn ok(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Response headers, with lowercase names
+    pub fn headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+
+    /// Reads the response body.
+    pub async fn body(&self) -> Result<Vec<u8>> {
+        self.context.response_body(&self.fetch_uid).await
+    }
+
+    /// Reads the response body as UTF-8 text.
+    pub async fn text(&self) -> Result<String> {
+        String::from_utf8(self.body().await?)
+            .map_err(|e| Error::ProtocolError(format!("Response body is not UTF-8: {}", e)))
+    }
+
+    /// Releases the response body held by the server.
+    pub async fn dispose(&self) -> Result<()> {
+        self.context.dispose_response(&self.fetch_uid).await
+    }
+}
+
+impl std::fmt::Debug for APIResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("APIResponse")
+            .field("url", &self.url)
+            .field("status", &self.status)
+            .finish()
+    }
+}
+
+impl ChannelOwner for APIRequestContext {
+    fn guid(&self) -> &str {
+        self.base.guid()
+    }
+
+    fn type_name(&self) -> &str {
+        self.base.type_name()
+    }
+
+    fn parent(&self) -> Option<Arc<dyn ChannelOwner>> {
+        self.base.parent()
+    }
+
+    fn connection(&self) -> Arc<dyn crate::server::connection::ConnectionLike> {
+        self.base.connection()
+    }
+
+    fn initializer(&self) -> &Value {
+        self.base.initializer()
+    }
+
+    fn channel(&self) -> &Channel {
+        self.base.channel()
+    }
+
+    fn dispose(&self, reason: crate::server::channel_owner::DisposeReason) {
+        self.base.dispose(reason)
+    }
+
+    fn adopt(&self, child: Arc<dyn ChannelOwner>) {
+        self.base.adopt(child)
+    }
+
+    fn add_child(&self, guid: Arc<str>, child: Arc<dyn ChannelOwner>) {
+        self.base.add_child(guid, child)
+    }
+
+    fn remove_child(&self, guid: &str) {
+        self.base.remove_child(guid)
+    }
+
+    fn on_event(&self, _method: &str, _params: Value) {
+        // APIRequestContext events are not needed yet
+    }
+
+    fn was_collected(&self) -> bool {
+        self.base.was_collected()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl std::fmt::Debug for APIRequestContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("APIRequestContext")
+            .field("guid", &self.guid())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fetch_options_params() {
+        let params = FetchOptions::new()
+            .method("HEAD")
+            .header("X-Test", "1")
+            .timeout(Duration::from_secs(5))
+            .max_redirects(3)
+            .to_params("https://example.com");
+        assert_eq!(params["url"], "https://example.com");
+        assert_eq!(params["method"], "HEAD");
+        assert_eq!(params["timeout"], 5000.0);
+        assert_eq!(params["maxRedirects"], 3);
+        assert_eq!(params["headers"][0]["name"], "X-Test");
+        assert!(params.get("failOnStatusCode").is_none());
+    }
+
+    #[test]
+    fn test_fetch_options_default_timeout() {
+        let params = FetchOptions::default().to_params("https://example.com");
+        assert_eq!(params["timeout"], crate::DEFAULT_TIMEOUT_MS);
+        assert!(params.get("method").is_none());
+    }
+}
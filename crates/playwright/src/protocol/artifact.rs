@@ -3,13 +3,15 @@
 //
 // Artifact protocol object
 //
-// Artifacts represent downloaded files. The Artifact is wrapped by
-// the Download class which adds URL and filename from event params.
+// Artifacts represent files produced by the driver: downloads (wrapped by
+// the Download class which adds URL and filename from event params) and
+// trace archives returned by Tracing.
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::any::Any;
+use std::path::Path;
 use std::sync::Arc;
 
 /// Artifact is the protocol object for downloaded files.
@@ -38,6 +40,23 @@ impl Artifact {
 
         Ok(Self { base })
     }
+
+    /// Copies the artifact to `path`
+    #[cfg_attr(not(feature = "trace"), allow(dead_code))]
+    pub(crate) async fn save_as(&self, path: &Path) -> Result<()> {
+        let path = path
+            .to_str()
+            .ok_or_else(|| Error::InvalidArgument("Invalid path".to_string()))?;
+        self.channel()
+            .send_no_result("saveAs", json!({ "path": path }))
+            .await
+    }
+
+    /// Deletes the artifact's file on the driver side
+    #[cfg_attr(not(feature = "trace"), allow(dead_code))]
+    pub(crate) async fn delete(&self) -> Result<()> {
+        self.channel().send_no_result("delete", json!({})).await
+    }
 }
 
 impl ChannelOwner for Artifact {
@@ -0,0 +1,271 @@
+// Batch reads - Several read-only queries in one protocol round trip
+//
+// A Batch collects read-only operations (text, attributes, counts, visibility)
+// and runs them with a single evaluate() call. Each added operation returns a
+// typed BatchKey used to read its value from the BatchResults, so callers get
+// typed values without one round trip per query. Aimed at remote-driver setups
+// where latency dominates.
+
+use crate::error::{Error, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::marker::PhantomData;
+
+/// JavaScript that runs every operation of a [`Batch`] and returns an array of results
+pub(crate) const BATCH_SCRIPT: &str = r#"(ops) => {
+  const isVisible = (el) => {
+    const style = getComputedStyle(el);
+    if (style.visibility === 'hidden' || style.display === 'none') return false;
+    return !!(el.offsetWidth || el.offsetHeight || el.getClientRects().length);
+  };
+  const read = (el, op) => {
+    switch (op.kind) {
+      case 'textContent': return el.textContent;
+      case 'innerText': return el.innerText;
+      case 'innerHtml': return el.innerHTML;
+      case 'attribute': return el.getAttribute(op.attribute);
+      case 'inputValue': return el.value === undefined ? null : el.value;
+      case 'isVisible': return isVisible(el);
+      case 'isChecked': return !!el.checked;
+      default: return null;
+    }
+  };
+  return ops.map((op) => {
+    const all = Array.from(document.querySelectorAll(op.selector));
+    if (op.kind === 'count') return all.length;
+    if (op.all) return all.map((el) => read(el, op));
+    if (all.length === 0) return op.kind === 'isVisible' ? false : null;
+    return read(all[0], op);
+  });
+}"#;
+
+/// Kind of read performed by a batch operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum BatchOpKind {
+    TextContent,
+    InnerText,
+    InnerHtml,
+    Attribute,
+    InputValue,
+    IsVisible,
+    IsChecked,
+    Count,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct BatchOp {
+    kind: BatchOpKind,
+    selector: String,
+    all: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attribute: Option<String>,
+}
+
+/// Typed handle to the result of one operation in a [`Batch`]
+#[derive(Debug)]
+pub struct BatchKey<T> {
+    index: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for BatchKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BatchKey<T> {}
+
+/// A set of read-only queries executed in a single round trip.
+///
+/// Selectors are plain CSS selectors. Single-element reads use the first match
+/// and return `None` (or `false` for visibility) when nothing matches.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::Batch;
+///
+/// let mut batch = Batch::new();
+/// let heading = batch.text_content("h1");
+/// let items = batch.count("li.result");
+/// let links = batch.all_attributes("a.result-link", "href");
+///
+/// let results = page.run_batch(&batch).await?;
+/// let heading: Option<String> = results.get(heading)?;
+/// let items: usize = results.get(items)?;
+/// let links: Vec<Option<String>> = results.get(links)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Batch {
+    ops: Vec<BatchOp>,
+}
+
+impl Batch {
+    /// Creates an empty batch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push<T>(
+        &mut self,
+        kind: BatchOpKind,
+        selector: &str,
+        all: bool,
+        attribute: Option<&str>,
+    ) -> BatchKey<T> {
+        self.ops.push(BatchOp {
+            kind,
+            selector: selector.to_string(),
+            all,
+            attribute: attribute.map(str::to_string),
+        });
+        BatchKey {
+            index: self.ops.len() - 1,
+            _marker: PhantomData,
+        }
+    }
+
+    /// `textContent` of the first match
+    pub fn text_content(&mut self, selector: &str) -> BatchKey<Option<String>> {
+        self.push(BatchOpKind::TextContent, selector, false, None)
+    }
+
+    /// `innerText` of the first match
+    pub fn inner_text(&mut self, selector: &str) -> BatchKey<Option<String>> {
+        self.push(BatchOpKind::InnerText, selector, false, None)
+    }
+
+    /// `innerHTML` of the first match
+    pub fn inner_html(&mut self, selector: &str) -> BatchKey<Option<String>> {
+        self.push(BatchOpKind::InnerHtml, selector, false, None)
+    }
+
+    /// Attribute value of the first match
+    pub fn get_attribute(&mut self, selector: &str, name: &str) -> BatchKey<Option<String>> {
+        self.push(BatchOpKind::Attribute, selector, false, Some(name))
+    }
+
+    /// `value` of the first matching input, textarea, or select
+    pub fn input_value(&mut self, selector: &str) -> BatchKey<Option<String>> {
+        self.push(BatchOpKind::InputValue, selector, false, None)
+    }
+
+    /// Whether the first match is visible (`false` if nothing matches)
+    pub fn is_visible(&mut self, selector: &str) -> BatchKey<bool> {
+        self.push(BatchOpKind::IsVisible, selector, false, None)
+    }
+
+    /// Whether the first match is a checked checkbox or radio (`None` if nothing matches)
+    pub fn is_checked(&mut self, selector: &str) -> BatchKey<Option<bool>> {
+        self.push(BatchOpKind::IsChecked, selector, false, None)
+    }
+
+    /// Number of matching elements
+    pub fn count(&mut self, selector: &str) -> BatchKey<usize> {
+        self.push(BatchOpKind::Count, selector, false, None)
+    }
+
+    /// `textContent` of every match
+    pub fn all_text_contents(&mut self, selector: &str) -> BatchKey<Vec<String>> {
+        self.push(BatchOpKind::TextContent, selector, true, None)
+    }
+
+    /// `innerText` of every match
+    pub fn all_inner_texts(&mut self, selector: &str) -> BatchKey<Vec<String>> {
+        self.push(BatchOpKind::InnerText, selector, true, None)
+    }
+
+    /// Attribute value of every match
+    pub fn all_attributes(&mut self, selector: &str, name: &str) -> BatchKey<Vec<Option<String>>> {
+        self.push(BatchOpKind::Attribute, selector, true, Some(name))
+    }
+
+    /// Number of operations in the batch
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// True if the batch has no operations
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Operations as sent to the page
+    pub(crate) fn ops_json(&self) -> Result<Value> {
+        Ok(serde_json::to_value(&self.ops)?)
+    }
+}
+
+/// Results of a [`Batch`], read with the keys returned when building it
+#[derive(Debug, Clone)]
+pub struct BatchResults {
+    values: Vec<Value>,
+}
+
+impl BatchResults {
+    pub(crate) fn new(values: Vec<Value>) -> Self {
+        Self { values }
+    }
+
+    /// Returns the typed value for `key`
+    pub fn get<T: DeserializeOwned>(&self, key: BatchKey<T>) -> Result<T> {
+        let value = self.values.get(key.index).cloned().ok_or_else(|| {
+            Error::InvalidArgument(format!(
+                "Batch key {} does not belong to these results",
+                key.index
+            ))
+        })?;
+        serde_json::from_value(value)
+            .map_err(|e| Error::ProtocolError(format!("Unexpected batch result type: {}", e)))
+    }
+
+    /// Raw JSON results, in the order operations were added
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_batch_keys_and_ops() {
+        let mut batch = Batch::new();
+        let heading = batch.text_content("h1");
+        let links = batch.all_attributes("a", "href");
+        let count = batch.count("li");
+
+        assert_eq!(batch.len(), 3);
+        assert_eq!(
+            batch.ops_json().unwrap(),
+            json!([
+                {"kind": "textContent", "selector": "h1", "all": false},
+                {"kind": "attribute", "selector": "a", "all": true, "attribute": "href"},
+                {"kind": "count", "selector": "li", "all": false}
+            ])
+        );
+
+        let results = BatchResults::new(vec![json!("Title"), json!(["/a", null]), json!(3)]);
+        assert_eq!(results.get(heading).unwrap().as_deref(), Some("Title"));
+        assert_eq!(
+            results.get(links).unwrap(),
+            vec![Some("/a".to_string()), None]
+        );
+        assert_eq!(results.get(count).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_foreign_key_is_rejected() {
+        let mut batch = Batch::new();
+        batch.count("p");
+        let key = batch.count("li");
+
+        let results = BatchResults::new(vec![json!(1)]);
+        assert!(matches!(results.get(key), Err(Error::InvalidArgument(_))));
+    }
+}
@@ -3,9 +3,11 @@
 // Represents a browser instance created by BrowserType.launch()
 
 use crate::error::Result;
-use crate::protocol::{BrowserContext, Page};
+use crate::protocol::crash::{CrashKind, CrashReport};
+use crate::protocol::{BrowserContext, CDPSession, Page};
 use crate::server::channel::Channel;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
+use parking_lot::Mutex;
 use serde::Deserialize;
 use serde_json::Value;
 use std::any::Any;
@@ -21,7 +23,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 /// # Example
 ///
 /// ```ignore
-/// use playwright_rs::protocol::Playwright;
+/// use playwright_rs::Playwright;
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -56,6 +58,9 @@ pub struct Browser {
     version: String,
     name: String,
     is_connected: Arc<AtomicBool>,
+    /// Set by close(), so the resulting disconnect is not taken for a crash
+    closing: Arc<AtomicBool>,
+    crash_report: Arc<Mutex<Option<CrashReport>>>,
 }
 
 impl Browser {
@@ -110,6 +115,8 @@ impl Browser {
             version,
             name,
             is_connected: Arc::new(AtomicBool::new(true)),
+            closing: Arc::new(AtomicBool::new(false)),
+            crash_report: Arc::new(Mutex::new(None)),
         })
     }
 
@@ -139,6 +146,15 @@ impl Browser {
         self.is_connected.load(Ordering::SeqCst)
     }
 
+    /// Returns the crash report if the browser disconnected without `close()`.
+    ///
+    /// Carries the browser's exit code or signal, the last driver log lines
+    /// and, for Chromium, any minidumps found. `None` while the browser is
+    /// connected or after it was closed normally.
+    pub fn crash_report(&self) -> Option<CrashReport> {
+        self.crash_report.lock().clone()
+    }
+
     /// Returns the channel for sending protocol messages
     ///
     /// Used internally for sending RPC calls to the browser.
@@ -250,6 +266,9 @@ impl Browser {
             options.storage_state_path = None; // Clear path since we've converted to inline
         }
 
+        // Network throttling is applied client-side after the context is created
+        let network_conditions = options.network_conditions.take();
+
         // Convert options to JSON
         let options_json = serde_json::to_value(options).map_err(|e| {
             crate::error::Error::ProtocolError(format!(
@@ -275,6 +294,8 @@ impl Browser {
                 ))
             })?;
 
+        context.set_network_conditions(network_conditions);
+
         Ok(context.clone())
     }
 
@@ -299,6 +320,15 @@ impl Browser {
         context.new_page().await
     }
 
+    /// Creates a Chrome DevTools Protocol session attached to the browser target.
+    ///
+    /// Chromium only. Other browsers return a protocol error.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-browser#browser-new-browser-cdp-session>
+    pub async fn new_browser_cdp_session(&self) -> Result<CDPSession> {
+        CDPSession::create(self, "newBrowserCDPSession", serde_json::json!({})).await
+    }
+
     /// Closes the browser and all of its pages (if any were opened).
     ///
     /// This is a graceful operation that sends a close command to the browser
@@ -312,6 +342,8 @@ impl Browser {
     ///
     /// See: <https://playwright.dev/docs/api/class-browser#browser-close>
     pub async fn close(&self) -> Result<()> {
+        self.closing.store(true, Ordering::SeqCst);
+
         // Send close RPC to server
         // The protocol expects an empty object as params
         let result = self
@@ -379,6 +411,17 @@ impl ChannelOwner for Browser {
     fn on_event(&self, method: &str, params: Value) {
         if method == "disconnected" {
             self.is_connected.store(false, Ordering::SeqCst);
+            if !self.closing.load(Ordering::SeqCst) {
+                let logs = self
+                    .connection()
+                    .driver_logs()
+                    .map(|logs| logs.lines())
+                    .unwrap_or_default();
+                let report = CrashReport::from_logs(CrashKind::Browser, self.name.clone(), &logs);
+                tracing::error!("{}", report);
+                self.connection().record_crash(report.clone());
+                *self.crash_report.lock() = Some(report);
+            }
         }
         self.base.on_event(method, params)
     }
@@ -406,4 +449,4 @@ impl std::fmt::Debug for Browser {
 // - A real Connection with object registry
 // - Protocol messages from the server
 // - BrowserType.launch() to create Browser objects
-// See: crates/playwright-core/tests/browser_launch_integration.rs (Phase 2 Slice 3)
+// See: crates/playwright/tests/browser_launch_integration.rs (Phase 2 Slice 3)
@@ -5,14 +5,22 @@
 // cache, and local storage.
 
 use crate::error::Result;
-use crate::protocol::Page;
+use crate::protocol::init_script::InitScriptRegistry;
+use crate::protocol::readiness::ReadinessRegistry;
+use crate::protocol::wasm::WASM_HYDRATED;
+#[cfg(feature = "api-request")]
+use crate::protocol::APIRequestContext;
+#[cfg(feature = "trace")]
+use crate::protocol::Tracing;
+use crate::protocol::{CDPSession, DiagnosticsPolicy, Hydration, NetworkConditions, Page, Script};
 use crate::server::channel::Channel;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::any::Any;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 
 /// BrowserContext represents an isolated browser session.
 ///
@@ -23,7 +31,7 @@ use std::sync::Arc;
 /// # Example
 ///
 /// ```ignore
-/// use playwright_rs::protocol::Playwright;
+/// use playwright_rs::Playwright;
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -50,6 +58,35 @@ use std::sync::Arc;
 #[derive(Clone)]
 pub struct BrowserContext {
     base: ChannelOwnerImpl,
+    /// Content hashes of registered init scripts
+    init_scripts: Arc<InitScriptRegistry>,
+    /// Network conditions applied to every page in the context (Chromium only)
+    network_conditions: Arc<Mutex<Option<NetworkConditions>>>,
+    /// Pages announced while `new_page()` calls are in flight
+    pending_pages: Arc<Mutex<PendingPages>>,
+    /// Named readiness conditions awaited by `Page::wait_for_ready`
+    readiness: Arc<ReadinessRegistry>,
+    /// Artifacts captured when locator actions fail
+    diagnostics: Arc<Mutex<Option<DiagnosticsPolicy>>>,
+    /// Whether locator actions are retried once after a navigation destroys their document
+    retry_on_navigation: Arc<AtomicBool>,
+    /// Teardown callbacks run by `close()`
+    close_hooks: Arc<Mutex<Vec<CloseHook>>>,
+}
+
+/// Teardown callback run when the context is closed
+type CloseHook = Box<dyn FnOnce() + Send>;
+
+/// Pages the driver announced while `new_page()` was waiting for its answer
+///
+/// The driver sends the context's `page` event before answering `newPage`, so
+/// the event handler cannot tell a page being opened by `new_page()` from a
+/// popup. It leaves such pages here: `new_page()` throttles its own page before
+/// returning, and the last call in flight throttles the rest.
+#[derive(Default)]
+struct PendingPages {
+    new_page_calls: usize,
+    announced: Vec<String>,
 }
 
 impl BrowserContext {
@@ -81,7 +118,16 @@ impl BrowserContext {
             initializer,
         );
 
-        let context = Self { base };
+        let context = Self {
+            base,
+            init_scripts: Arc::new(InitScriptRegistry::default()),
+            network_conditions: Arc::new(Mutex::new(None)),
+            pending_pages: Arc::new(Mutex::new(PendingPages::default())),
+            readiness: Arc::new(ReadinessRegistry::default()),
+            diagnostics: Arc::new(Mutex::new(None)),
+            retry_on_navigation: Arc::new(AtomicBool::new(false)),
+            close_hooks: Arc::new(Mutex::new(Vec::new())),
+        };
 
         // Enable dialog event subscription
         // Dialog events need to be explicitly subscribed to via updateSubscription command
@@ -108,6 +154,23 @@ impl BrowserContext {
         self.base.channel()
     }
 
+    /// Returns the clock controlling time for pages in this context.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-clock>
+    pub fn clock(&self) -> crate::protocol::Clock {
+        crate::protocol::Clock::new(self.clone())
+    }
+
+    // Internal clock method (called by Clock struct)
+
+    pub(crate) async fn send_clock(&self, method: &str, params: serde_json::Value) -> Result<()> {
+        crate::protocol::capabilities::require(
+            &*self.connection(),
+            crate::protocol::Capability::Clock,
+        )?;
+        self.channel().send_no_result(method, params).await
+    }
+
     /// Adds a script which would be evaluated in one of the following scenarios:
     ///
     /// - Whenever a page is created in the browser context or is navigated.
@@ -116,6 +179,9 @@ impl BrowserContext {
     /// The script is evaluated after the document was created but before any of its scripts
     /// were run. This is useful to amend the JavaScript environment, e.g. to seed Math.random.
     ///
+    /// Scripts can be inline source or a [`Script::Path`]; file contents are cached, and
+    /// registering a script whose content was already added to this context is a no-op.
+    ///
     /// # Arguments
     ///
     /// * `script` - Script to be evaluated in all pages in the browser context.
@@ -127,10 +193,22 @@ impl BrowserContext {
     /// - Communication with browser process fails
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-add-init-script>
-    pub async fn add_init_script(&self, script: &str) -> Result<()> {
-        self.channel()
-            .send_no_result("addInitScript", serde_json::json!({ "source": script }))
-            .await
+    pub async fn add_init_script(&self, script: impl Into<Script>) -> Result<()> {
+        let source = script.into().resolve().await?;
+
+        // Skip scripts already registered on this context
+        let Some(hash) = self.init_scripts.insert(&source) else {
+            return Ok(());
+        };
+
+        let result = self
+            .channel()
+            .send_no_result("addInitScript", serde_json::json!({ "source": &*source }))
+            .await;
+        if result.is_err() {
+            self.init_scripts.remove(hash);
+        }
+        result
     }
 
     /// Creates a new page in this browser context.
@@ -159,10 +237,30 @@ impl BrowserContext {
         }
 
         // Send newPage RPC to server
-        let response: NewPageResponse = self
-            .channel()
-            .send("newPage", serde_json::json!({}))
-            .await?;
+        self.pending_pages.lock().unwrap().new_page_calls += 1;
+        let response: Result<NewPageResponse> =
+            self.channel().send("newPage", serde_json::json!({})).await;
+        let others = {
+            let mut pending = self.pending_pages.lock().unwrap();
+            pending.new_page_calls -= 1;
+            if let Ok(response) = &response {
+                pending
+                    .announced
+                    .retain(|guid| guid.as_str() != &*response.page.guid);
+            }
+            if pending.new_page_calls == 0 {
+                std::mem::take(&mut pending.announced)
+            } else {
+                Vec::new()
+            }
+        };
+        let conditions = self.network_conditions.lock().unwrap().clone();
+        if let Some(conditions) = &conditions {
+            for guid in others {
+                self.throttle_in_background(guid, conditions.clone());
+            }
+        }
+        let response = response?;
 
         // Retrieve the Page object from the connection registry
         let page_arc = self.connection().get_object(&response.page.guid).await?;
@@ -175,7 +273,220 @@ impl BrowserContext {
             ))
         })?;
 
-        Ok(page.clone())
+        let page = page.clone();
+        if let Some(conditions) = conditions {
+            page.emulate_network_conditions(Some(conditions)).await?;
+        }
+
+        Ok(page)
+    }
+
+    /// Returns the API request context bound to this browser context.
+    ///
+    /// Requests share the context's cookies.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-request>
+    #[cfg(feature = "api-request")]
+    pub async fn request(&self) -> Result<APIRequestContext> {
+        let guid = self.base.initializer()["requestContext"]["guid"]
+            .as_str()
+            .ok_or_else(|| {
+                crate::error::Error::ProtocolError(
+                    "BrowserContext missing requestContext".to_string(),
+                )
+            })?;
+        let object = self.connection().get_object(guid).await?;
+        object
+            .as_any()
+            .downcast_ref::<APIRequestContext>()
+            .cloned()
+            .ok_or_else(|| {
+                crate::error::Error::ProtocolError(format!(
+                    "Expected APIRequestContext object, got {}",
+                    object.type_name()
+                ))
+            })
+    }
+
+    /// Returns the context's cookies and local storage.
+    ///
+    /// Pass the result to `BrowserContextOptions::builder().storage_state(...)`
+    /// to create contexts that start from this state.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-storage-state>
+    pub async fn storage_state(&self) -> Result<StorageState> {
+        self.channel()
+            .send("storageState", serde_json::json!({}))
+            .await
+    }
+
+    /// Emulates network conditions for every page in this context (Chromium only).
+    ///
+    /// Applies to pages created afterwards, including popups. Pass `None` to stop
+    /// throttling new pages; use `Page::emulate_network_conditions(None)` to reset
+    /// an existing page.
+    ///
+    /// Usually set once via `BrowserContextOptions::builder().network_conditions(...)`.
+    pub fn set_network_conditions(&self, conditions: Option<NetworkConditions>) {
+        *self.network_conditions.lock().unwrap() = conditions;
+    }
+
+    /// Applies network conditions to a page not opened by `new_page()`, e.g. a popup
+    fn throttle_in_background(&self, page_guid: String, conditions: NetworkConditions) {
+        let connection = self.connection();
+        tokio::spawn(async move {
+            let page_arc = match connection.get_object(&page_guid).await {
+                Ok(obj) => obj,
+                Err(_) => return,
+            };
+            let page = match page_arc.as_any().downcast_ref::<Page>() {
+                Some(p) => p.clone(),
+                None => return,
+            };
+            if let Err(e) = page.emulate_network_conditions(Some(conditions)).await {
+                tracing::warn!("Failed to apply network conditions: {}", e);
+            }
+        });
+    }
+
+    /// Captures a screenshot and DOM snapshot when a locator action in this
+    /// context fails with a timeout or strict mode violation.
+    ///
+    /// The failing action returns [`Error::WithDiagnostics`](crate::error::Error::WithDiagnostics) carrying the
+    /// captured file paths. Pass `None` to stop capturing.
+    pub fn set_diagnostics_policy(&self, policy: Option<DiagnosticsPolicy>) {
+        *self.diagnostics.lock().unwrap() = policy;
+    }
+
+    /// Returns the current diagnostics policy
+    pub fn diagnostics_policy(&self) -> Option<DiagnosticsPolicy> {
+        self.diagnostics.lock().unwrap().clone()
+    }
+
+    /// Retries locator actions once when a navigation interrupts them.
+    ///
+    /// An action racing a reload or redirect can fail with "Execution context
+    /// was destroyed". When enabled, such an action waits for the new
+    /// document's `DOMContentLoaded` and runs once more against it. Off by
+    /// default, since a retried click or fill may repeat an effect that
+    /// already happened before the navigation.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// context.set_retry_on_navigation(true);
+    /// page.locator("#refresh-status").click(None).await?;
+    /// ```
+    pub fn set_retry_on_navigation(&self, enabled: bool) {
+        self.retry_on_navigation.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Whether locator actions are retried after an interrupting navigation
+    pub fn retries_on_navigation(&self) -> bool {
+        self.retry_on_navigation.load(Ordering::SeqCst)
+    }
+
+    /// Registers a named readiness condition for pages in this context.
+    ///
+    /// `predicate` is a JavaScript expression or (possibly async) function that
+    /// is truthy once the app is ready. Registering the same name again replaces
+    /// the predicate. Await conditions with [`Page::wait_for_ready`] or
+    /// [`GotoOptions::wait_for_ready`](crate::protocol::GotoOptions::wait_for_ready).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// context.add_readiness_condition("hydrated", "() => window.__APP_HYDRATED__ === true");
+    /// context.add_readiness_condition("fonts", "document.fonts.status === 'loaded'");
+    ///
+    /// let page = context.new_page().await?;
+    /// page.goto(url, Some(GotoOptions::new().wait_for_ready(["hydrated"]))).await?;
+    /// ```
+    pub fn add_readiness_condition(&self, name: impl Into<String>, predicate: impl Into<String>) {
+        self.readiness.insert(name.into(), predicate.into());
+    }
+
+    /// Removes a readiness condition, returning whether it was registered
+    pub fn remove_readiness_condition(&self, name: &str) -> bool {
+        self.readiness.remove(name)
+    }
+
+    /// Names of the registered readiness conditions
+    pub fn readiness_conditions(&self) -> Vec<String> {
+        self.readiness.names()
+    }
+
+    pub(crate) fn readiness(&self) -> &ReadinessRegistry {
+        &self.readiness
+    }
+
+    /// Prepares the context for testing a Rust/WASM frontend.
+    ///
+    /// Installs an init script that tracks WASM instantiation and captures
+    /// panics (see [`Page::wasm_panics`](crate::protocol::Page::wasm_panics)),
+    /// and registers `hydration` as the [`WASM_HYDRATED`](crate::protocol::wasm::WASM_HYDRATED)
+    /// readiness condition awaited by
+    /// [`Page::wait_for_hydration`](crate::protocol::Page::wait_for_hydration).
+    /// Call it before opening the pages under test.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use playwright_rs::{DevServer, DevServerCommand, Hydration};
+    ///
+    /// let server = DevServer::start(DevServerCommand::trunk("./frontend", 8081)).await?;
+    /// context.enable_wasm(Hydration::TrunkStarted).await?;
+    ///
+    /// let page = context.new_page().await?;
+    /// page.goto(server.url(), None).await?;
+    /// page.wait_for_hydration(None).await?;
+    /// page.locator("button").click(None).await?;
+    /// assert!(page.wasm_panics().await?.is_empty());
+    /// ```
+    pub async fn enable_wasm(&self, hydration: Hydration) -> Result<()> {
+        self.add_init_script(crate::protocol::wasm::HOOKS_SCRIPT)
+            .await?;
+        self.add_readiness_condition(WASM_HYDRATED, hydration.predicate());
+        Ok(())
+    }
+
+    /// Returns the open pages in the context.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-pages>
+    pub fn pages(&self) -> Vec<Page> {
+        self.base
+            .children()
+            .iter()
+            .filter_map(|child| child.as_any().downcast_ref::<Page>().cloned())
+            .collect()
+    }
+
+    /// Returns the context's trace recorder.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-tracing>
+    #[cfg(feature = "trace")]
+    pub async fn tracing(&self) -> Result<Tracing> {
+        let guid = self
+            .initializer()
+            .get("tracing")
+            .and_then(|t| t.get("guid"))
+            .and_then(|g| g.as_str())
+            .ok_or_else(|| {
+                crate::error::Error::ProtocolError(
+                    "BrowserContext initializer has no tracing".to_string(),
+                )
+            })?;
+        let tracing_arc = self.connection().get_object(guid).await?;
+        let tracing = tracing_arc
+            .as_any()
+            .downcast_ref::<Tracing>()
+            .ok_or_else(|| {
+                crate::error::Error::ProtocolError(format!(
+                    "Expected Tracing object, got {}",
+                    tracing_arc.type_name()
+                ))
+            })?;
+        Ok(tracing.clone())
     }
 
     /// Closes the browser context and all its pages.
@@ -192,9 +503,22 @@ impl BrowserContext {
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-close>
     pub async fn close(&self) -> Result<()> {
         // Send close RPC to server
-        self.channel()
+        let result = self
+            .channel()
             .send_no_result("close", serde_json::json!({}))
-            .await
+            .await;
+
+        let hooks = std::mem::take(&mut *self.close_hooks.lock().unwrap());
+        for hook in hooks {
+            hook();
+        }
+        result
+    }
+
+    /// Registers a callback run once when [`close`](Self::close) is called
+    #[cfg(feature = "harness")]
+    pub(crate) fn add_close_hook(&self, hook: impl FnOnce() + Send + 'static) {
+        self.close_hooks.lock().unwrap().push(Box::new(hook));
     }
 
     /// Pauses the browser context.
@@ -205,6 +529,27 @@ impl BrowserContext {
             .send_no_result("pause", serde_json::Value::Null)
             .await
     }
+
+    /// Creates a Chrome DevTools Protocol session attached to the given page.
+    ///
+    /// Chromium only. Other browsers return a protocol error.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let session = context.new_cdp_session(&page).await?;
+    /// session.network().enable().await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-new-cdp-session>
+    pub async fn new_cdp_session(&self, page: &Page) -> Result<CDPSession> {
+        CDPSession::create(
+            self,
+            "newCDPSession",
+            serde_json::json!({ "page": { "guid": page.guid() } }),
+        )
+        .await
+    }
 }
 
 impl ChannelOwner for BrowserContext {
@@ -295,6 +640,26 @@ impl ChannelOwner for BrowserContext {
                     });
                 }
             }
+            "page" => {
+                // Apply context-wide network throttling to new pages. While new_page()
+                // calls are in flight the page may be theirs, so they handle it
+                let conditions = self.network_conditions.lock().unwrap().clone();
+                if let (Some(conditions), Some(page_guid)) = (
+                    conditions,
+                    params
+                        .get("page")
+                        .and_then(|v| v.get("guid"))
+                        .and_then(|v| v.as_str()),
+                ) {
+                    let mut pending = self.pending_pages.lock().unwrap();
+                    if pending.new_page_calls > 0 {
+                        pending.announced.push(page_guid.to_string());
+                    } else {
+                        drop(pending);
+                        self.throttle_in_background(page_guid.to_string(), conditions);
+                    }
+                }
+            }
             _ => {
                 // Other events will be handled in future phases
             }
@@ -321,7 +686,7 @@ impl std::fmt::Debug for BrowserContext {
 /// Viewport dimensions for browser context.
 ///
 /// See: <https://playwright.dev/docs/api/class-browser#browser-new-context>
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Viewport {
     /// Page width in pixels
     pub width: u32,
@@ -332,7 +697,7 @@ pub struct Viewport {
 /// Geolocation coordinates.
 ///
 /// See: <https://playwright.dev/docs/api/class-browser#browser-new-context>
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Geolocation {
     /// Latitude between -90 and 90
     pub latitude: f64,
@@ -405,6 +770,21 @@ pub struct StorageState {
     pub origins: Vec<Origin>,
 }
 
+/// Video recording settings for a browser context.
+///
+/// Videos are written to `dir` when their page or context closes.
+///
+/// See: <https://playwright.dev/docs/api/class-browser#browser-new-context-option-record-video>
+#[cfg(feature = "video")]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RecordVideo {
+    /// Directory to put videos into
+    pub dir: String,
+    /// Video frame size (default: the viewport scaled down to fit 800x800)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<Viewport>,
+}
+
 /// Options for creating a new browser context.
 ///
 /// Allows customizing viewport, user agent, locale, timezone, geolocation,
@@ -497,6 +877,16 @@ pub struct BrowserContextOptions {
     /// This is handled by the builder and converted to storage_state during serialization.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub storage_state_path: Option<String>,
+
+    /// Records a video of every page in the context
+    #[cfg(feature = "video")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record_video: Option<RecordVideo>,
+
+    /// Network throttling applied to every page in the context (Chromium only).
+    /// Applied client-side via CDP, not sent with the context options.
+    #[serde(skip)]
+    pub network_conditions: Option<NetworkConditions>,
 }
 
 impl BrowserContextOptions {
@@ -529,6 +919,9 @@ pub struct BrowserContextOptionsBuilder {
     base_url: Option<String>,
     storage_state: Option<StorageState>,
     storage_state_path: Option<String>,
+    #[cfg(feature = "video")]
+    record_video: Option<RecordVideo>,
+    network_conditions: Option<NetworkConditions>,
 }
 
 impl BrowserContextOptionsBuilder {
@@ -655,7 +1048,7 @@ impl BrowserContextOptionsBuilder {
     /// # Example
     ///
     /// ```rust
-    /// use playwright_rs::protocol::{BrowserContextOptions, Cookie, StorageState, Origin, LocalStorageItem};
+    /// use playwright_rs::{BrowserContextOptions, Cookie, StorageState, Origin, LocalStorageItem};
     ///
     /// let storage_state = StorageState {
     ///     cookies: vec![Cookie {
@@ -700,7 +1093,7 @@ impl BrowserContextOptionsBuilder {
     /// # Example
     ///
     /// ```rust
-    /// use playwright_rs::protocol::BrowserContextOptions;
+    /// use playwright_rs::BrowserContextOptions;
     ///
     /// let options = BrowserContextOptions::builder()
     ///     .storage_state_path("auth.json".to_string())
@@ -737,6 +1130,29 @@ impl BrowserContextOptionsBuilder {
         self
     }
 
+    /// Records videos of the context's pages into `record_video.dir`
+    #[cfg(feature = "video")]
+    pub fn record_video(mut self, record_video: RecordVideo) -> Self {
+        self.record_video = Some(record_video);
+        self
+    }
+
+    /// Throttles every page in the context (Chromium only).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use playwright_rs::{BrowserContextOptions, NetworkConditions};
+    ///
+    /// let options = BrowserContextOptions::builder()
+    ///     .network_conditions(NetworkConditions::fast_3g())
+    ///     .build();
+    /// ```
+    pub fn network_conditions(mut self, conditions: NetworkConditions) -> Self {
+        self.network_conditions = Some(conditions);
+        self
+    }
+
     /// Builds the BrowserContextOptions
     pub fn build(self) -> BrowserContextOptions {
         BrowserContextOptions {
@@ -760,6 +1176,42 @@ impl BrowserContextOptionsBuilder {
             base_url: self.base_url,
             storage_state: self.storage_state,
             storage_state_path: self.storage_state_path,
+            #[cfg(feature = "video")]
+            record_video: self.record_video,
+            network_conditions: self.network_conditions,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "mock")]
+    #[tokio::test]
+    async fn test_new_page_emulates_network_conditions_once() {
+        use crate::protocol::{BrowserContextOptions, NetworkConditions};
+
+        let driver = crate::mock::MockDriver::new();
+        let playwright = driver.connect().await.unwrap();
+        let browser = playwright.chromium().launch().await.unwrap();
+        let context = browser
+            .new_context_with_options(
+                BrowserContextOptions::builder()
+                    .network_conditions(NetworkConditions::fast_3g())
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        context.new_page().await.unwrap();
+        context.new_page().await.unwrap();
+        // Give a duplicate background application the chance to run
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let emulations = driver
+            .calls_to("send")
+            .into_iter()
+            .filter(|call| call.params["method"] == "Network.emulateNetworkConditions")
+            .count();
+        assert_eq!(emulations, 2);
+    }
+}
@@ -28,8 +28,9 @@ use std::sync::Arc;
 /// # Example
 ///
 /// ```ignore
-/// # use playwright_rs::protocol::Playwright;
+/// # use playwright_rs::Playwright;
 /// # use playwright_rs::api::LaunchOptions;
+/// # use std::time::Duration;
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let playwright = Playwright::launch().await?;
@@ -48,7 +49,7 @@ use std::sync::Arc;
 /// // Launch with custom options
 /// let options = LaunchOptions::default()
 ///     .headless(true)
-///     .slow_mo(100.0)
+///     .slow_mo(Duration::from_millis(100))
 ///     .args(vec!["--no-sandbox".to_string()]);
 ///
 /// let browser2 = chromium.launch_with_options(options).await?;
@@ -124,6 +125,12 @@ impl BrowserType {
     }
 
     /// Returns the path to the browser executable.
+    ///
+    /// This is the Playwright-managed browser build. Use
+    /// [`installed_channels()`](crate::api::installed_channels) to find branded
+    /// browsers (Chrome, Edge) installed on the system.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-browsertype#browser-type-executable-path>
     pub fn executable_path(&self) -> &str {
         &self.executable_path
     }
@@ -158,6 +165,10 @@ impl BrowserType {
     /// - Invalid options
     /// - Browser process fails to start
     ///
+    /// Headed launches that fail because no display server is available return
+    /// `Error::DisplayNotAvailable` with suggestions (headless mode, Xvfb, WSLg)
+    /// and the original error.
+    ///
     /// See: <https://playwright.dev/docs/api/class-browsertype#browser-type-launch>
     pub async fn launch_with_options(&self, options: LaunchOptions) -> Result<Browser> {
         // Add Windows CI-specific browser args to prevent hanging
@@ -196,7 +207,8 @@ impl BrowserType {
 
                     // Increase timeout for Windows CI (slower startup)
                     if options.timeout.is_none() {
-                        options.timeout = Some(60000.0); // 60 seconds for Windows CI
+                        options.timeout = Some(std::time::Duration::from_secs(60));
+                        // 60 seconds for Windows CI
                     }
                 }
                 options
@@ -211,8 +223,18 @@ impl BrowserType {
         // Normalize options for protocol transmission
         let params = options.normalize();
 
-        // Send launch RPC to server
-        let response: LaunchResponse = self.base.channel().send("launch", params).await?;
+        // Send launch RPC to server, attaching recent driver output on failure
+        let response: LaunchResponse =
+            self.base
+                .channel()
+                .send("launch", params)
+                .await
+                .map_err(|e| {
+                    crate::server::display::map_launch_error(
+                        e,
+                        self.connection().driver_logs().as_ref(),
+                    )
+                })?;
 
         // Get browser object from registry
         let browser_arc = self.connection().get_object(&response.browser.guid).await?;
@@ -315,4 +337,4 @@ impl std::fmt::Debug for BrowserType {
 // Note: BrowserType testing is done via integration tests since it requires:
 // - A real Connection with object registry
 // - Protocol messages from the server
-// See: crates/playwright-core/tests/connection_integration.rs
+// See: crates/playwright/tests/connection_integration.rs
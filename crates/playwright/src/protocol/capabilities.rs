@@ -0,0 +1,190 @@
+// Driver capabilities - Gating newer APIs on the installed driver version
+//
+// The crate bundles a driver, but PLAYWRIGHT_DRIVER_PATH and npm installs let
+// an older one be used. Calling an API the driver predates fails deep in the
+// driver with an opaque "Unknown method" error, so APIs added in later driver
+// releases check the version first and fail with a clear message instead.
+//
+// The version is read from the driver's package.json when the driver is
+// launched. Connections whose driver version is unknown (custom transports,
+// the mock driver) assume everything is supported and let the driver decide.
+
+use crate::error::{Error, Result};
+use crate::server::connection::ConnectionLike;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// A Playwright driver version, e.g. `1.56.1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DriverVersion {
+    /// Major version
+    pub major: u32,
+    /// Minor version
+    pub minor: u32,
+    /// Patch version
+    pub patch: u32,
+}
+
+impl DriverVersion {
+    /// Creates a version from its components
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Reads the version of the driver whose `cli.js` is at `cli_js`.
+    ///
+    /// Returns `None` if the driver's `package.json` is missing or has no
+    /// parsable version.
+    pub fn from_cli_js(cli_js: &Path) -> Option<Self> {
+        let package = std::fs::read_to_string(cli_js.parent()?.join("package.json")).ok()?;
+        let package: serde_json::Value = serde_json::from_str(&package).ok()?;
+        package["version"].as_str()?.parse().ok()
+    }
+}
+
+impl FromStr for DriverVersion {
+    type Err = Error;
+
+    /// Parses `1.56.1`, `1.56` or `Version 1.56.1`; pre-release suffixes
+    /// such as `-alpha-2024-01-01` are ignored
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::InvalidArgument(format!("Invalid driver version: '{}'", s));
+        let version = s.trim();
+        let version = version.strip_prefix("Version ").unwrap_or(version);
+        let version = version.split(['-', '+']).next().unwrap_or_default();
+
+        let mut parts = version.split('.').map(str::parse::<u32>);
+        let major = parts.next().and_then(|p| p.ok()).ok_or_else(invalid)?;
+        let minor = parts.next().and_then(|p| p.ok()).ok_or_else(invalid)?;
+        let patch = match parts.next() {
+            Some(patch) => patch.map_err(|_| invalid())?,
+            None => 0,
+        };
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(Self::new(major, minor, patch))
+    }
+}
+
+impl fmt::Display for DriverVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// An API that needs a minimum driver version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Capability {
+    /// Clock emulation (`BrowserContext::clock`), driver 1.45
+    Clock,
+    /// ARIA snapshots (`Locator::aria_snapshot`), driver 1.49
+    AriaSnapshot,
+}
+
+impl Capability {
+    /// Oldest driver version that supports this capability
+    pub fn min_version(self) -> DriverVersion {
+        match self {
+            Capability::Clock => DriverVersion::new(1, 45, 0),
+            Capability::AriaSnapshot => DriverVersion::new(1, 49, 0),
+        }
+    }
+
+    /// Human-readable name used in error messages
+    pub fn name(self) -> &'static str {
+        match self {
+            Capability::Clock => "Clock emulation",
+            Capability::AriaSnapshot => "ARIA snapshots",
+        }
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// What the driver behind a connection supports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DriverCapabilities {
+    /// Driver version, `None` if unknown
+    pub version: Option<DriverVersion>,
+}
+
+impl DriverCapabilities {
+    /// Whether the driver supports `capability`.
+    ///
+    /// Always true when the driver version is unknown.
+    pub fn supports(&self, capability: Capability) -> bool {
+        self.version
+            .map_or(true, |version| version >= capability.min_version())
+    }
+
+    /// Fails with [`Error::UnsupportedByDriver`] if the driver is too old for `capability`
+    pub fn require(&self, capability: Capability) -> Result<()> {
+        match self.version {
+            Some(installed) if !self.supports(capability) => Err(Error::UnsupportedByDriver {
+                feature: capability.name(),
+                required: capability.min_version(),
+                installed,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Checks `capability` against the driver behind `connection`
+pub(crate) fn require(connection: &dyn ConnectionLike, capability: Capability) -> Result<()> {
+    DriverCapabilities {
+        version: connection.driver_version(),
+    }
+    .require(capability)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_driver_version() {
+        let v = |s: &str| s.parse::<DriverVersion>().unwrap();
+        assert_eq!(v("1.56.1"), DriverVersion::new(1, 56, 1));
+        assert_eq!(v("Version 1.49.0\n"), DriverVersion::new(1, 49, 0));
+        assert_eq!(v("1.50"), DriverVersion::new(1, 50, 0));
+        assert_eq!(v("1.57.0-alpha-2025-10-01"), DriverVersion::new(1, 57, 0));
+        assert!("".parse::<DriverVersion>().is_err());
+        assert!("1".parse::<DriverVersion>().is_err());
+        assert!("1.x.0".parse::<DriverVersion>().is_err());
+        assert!("1.2.3.4".parse::<DriverVersion>().is_err());
+        assert_eq!(DriverVersion::new(1, 56, 1).to_string(), "1.56.1");
+    }
+
+    #[test]
+    fn test_capability_gating() {
+        let old = DriverCapabilities {
+            version: Some(DriverVersion::new(1, 47, 2)),
+        };
+        assert!(old.supports(Capability::Clock));
+        assert!(!old.supports(Capability::AriaSnapshot));
+        assert!(old.require(Capability::Clock).is_ok());
+
+        let err = old.require(Capability::AriaSnapshot).unwrap_err();
+        assert!(matches!(err, Error::UnsupportedByDriver { .. }));
+        assert_eq!(
+            err.to_string(),
+            "ARIA snapshots requires Playwright driver 1.49.0 or newer, but 1.47.2 is installed"
+        );
+
+        let unknown = DriverCapabilities::default();
+        assert!(unknown.supports(Capability::AriaSnapshot));
+        assert!(unknown.require(Capability::Clock).is_ok());
+    }
+}
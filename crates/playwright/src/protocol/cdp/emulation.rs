@@ -0,0 +1,113 @@
+// CDP Emulation domain
+//
+// See: https://chromedevtools.github.io/devtools-protocol/tot/Emulation/
+
+use crate::error::Result;
+use crate::protocol::CDPSession;
+use serde::Serialize;
+use serde_json::json;
+
+/// Typed wrapper for the CDP `Emulation` domain.
+///
+/// Obtained via [`CDPSession::emulation()`].
+///
+/// See: <https://chromedevtools.github.io/devtools-protocol/tot/Emulation/>
+#[derive(Debug, Clone, Copy)]
+pub struct Emulation<'a> {
+    session: &'a CDPSession,
+}
+
+impl<'a> Emulation<'a> {
+    pub(crate) fn new(session: &'a CDPSession) -> Self {
+        Self { session }
+    }
+
+    /// Overrides the device screen dimensions and scale factor
+    pub async fn set_device_metrics_override(&self, metrics: &DeviceMetrics) -> Result<()> {
+        self.session
+            .send(
+                "Emulation.setDeviceMetricsOverride",
+                Some(serde_json::to_value(metrics)?),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Clears a device metrics override
+    pub async fn clear_device_metrics_override(&self) -> Result<()> {
+        self.session
+            .send("Emulation.clearDeviceMetricsOverride", None)
+            .await?;
+        Ok(())
+    }
+
+    /// Slows down the CPU by the given factor (1 = no throttling, 4 = 4x slower)
+    pub async fn set_cpu_throttling_rate(&self, rate: f64) -> Result<()> {
+        self.session
+            .send(
+                "Emulation.setCPUThrottlingRate",
+                Some(json!({ "rate": rate })),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Overrides the timezone (IANA id, e.g. "Europe/Berlin"); an empty string restores the default
+    pub async fn set_timezone_override(&self, timezone_id: &str) -> Result<()> {
+        self.session
+            .send(
+                "Emulation.setTimezoneOverride",
+                Some(json!({ "timezoneId": timezone_id })),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Overrides the ICU locale (e.g. "de-DE"); `None` restores the default
+    pub async fn set_locale_override(&self, locale: Option<&str>) -> Result<()> {
+        let params = match locale {
+            Some(locale) => json!({ "locale": locale }),
+            None => json!({}),
+        };
+        self.session
+            .send("Emulation.setLocaleOverride", Some(params))
+            .await?;
+        Ok(())
+    }
+
+    /// Emulates a CSS media type (e.g. "print"); an empty string disables emulation
+    pub async fn set_emulated_media(&self, media: &str) -> Result<()> {
+        self.session
+            .send(
+                "Emulation.setEmulatedMedia",
+                Some(json!({ "media": media })),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Enables or disables touch event emulation
+    pub async fn set_touch_emulation_enabled(&self, enabled: bool) -> Result<()> {
+        self.session
+            .send(
+                "Emulation.setTouchEmulationEnabled",
+                Some(json!({ "enabled": enabled })),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Parameters for `Emulation.setDeviceMetricsOverride`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceMetrics {
+    /// Viewport width in CSS pixels
+    pub width: u32,
+    /// Viewport height in CSS pixels
+    pub height: u32,
+    /// Device scale factor
+    pub device_scale_factor: f64,
+    /// Whether to emulate a mobile device
+    pub mobile: bool,
+}
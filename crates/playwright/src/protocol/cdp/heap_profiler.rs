@@ -0,0 +1,125 @@
+// CDP HeapProfiler domain
+//
+// Heap snapshots arrive as `HeapProfiler.addHeapSnapshotChunk` events while
+// `HeapProfiler.takeHeapSnapshot` runs; `take_snapshot()` joins the chunks.
+//
+// See: https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/
+
+use crate::error::Result;
+use crate::protocol::CDPSession;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+
+/// Typed wrapper for the CDP `HeapProfiler` domain, plus `Runtime.getHeapUsage`.
+///
+/// Obtained via [`CDPSession::heap_profiler()`].
+///
+/// See: <https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/>
+#[derive(Debug, Clone, Copy)]
+pub struct HeapProfiler<'a> {
+    session: &'a CDPSession,
+}
+
+impl<'a> HeapProfiler<'a> {
+    pub(crate) fn new(session: &'a CDPSession) -> Self {
+        Self { session }
+    }
+
+    /// Enables the heap profiler
+    pub async fn enable(&self) -> Result<()> {
+        self.session.send("HeapProfiler.enable", None).await?;
+        Ok(())
+    }
+
+    /// Disables the heap profiler
+    pub async fn disable(&self) -> Result<()> {
+        self.session.send("HeapProfiler.disable", None).await?;
+        Ok(())
+    }
+
+    /// Forces a garbage collection
+    pub async fn collect_garbage(&self) -> Result<()> {
+        self.session
+            .send("HeapProfiler.collectGarbage", None)
+            .await?;
+        Ok(())
+    }
+
+    /// Returns current JS heap usage
+    pub async fn get_heap_usage(&self) -> Result<HeapUsage> {
+        self.session.send_as("Runtime.getHeapUsage", None).await
+    }
+
+    /// Takes a heap snapshot and returns it as a `.heapsnapshot` JSON string.
+    ///
+    /// The result can be saved to disk and loaded in Chrome DevTools' Memory panel.
+    /// Snapshots of large applications can be hundreds of megabytes.
+    pub async fn take_snapshot(&self) -> Result<String> {
+        let chunks: Arc<Mutex<String>> = Arc::new(Mutex::new(String::new()));
+
+        let chunks_clone = Arc::clone(&chunks);
+        let handler_id = self.session.add_event_handler(
+            "HeapProfiler.addHeapSnapshotChunk",
+            move |params: Value| {
+                if let Some(chunk) = params.get("chunk").and_then(|v| v.as_str()) {
+                    chunks_clone.lock().unwrap().push_str(chunk);
+                }
+                async { Ok(()) }
+            },
+        );
+
+        // Chunks are all delivered before the command's response
+        let result = self
+            .session
+            .send(
+                "HeapProfiler.takeHeapSnapshot",
+                Some(json!({ "reportProgress": false })),
+            )
+            .await;
+        self.session.remove_event_handler(handler_id);
+        result?;
+
+        let snapshot = std::mem::take(&mut *chunks.lock().unwrap());
+        Ok(snapshot)
+    }
+}
+
+/// JS heap usage as reported by `Runtime.getHeapUsage`
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeapUsage {
+    /// Used JS heap size in bytes
+    #[serde(deserialize_with = "deserialize_bytes")]
+    pub used_size: u64,
+    /// Allocated JS heap size in bytes
+    #[serde(deserialize_with = "deserialize_bytes")]
+    pub total_size: u64,
+}
+
+/// CDP reports byte counts as JSON numbers that may be floats
+fn deserialize_bytes<'de, D>(deserializer: D) -> std::result::Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = f64::deserialize(deserializer)?;
+    Ok(value.max(0.0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heap_usage_deserialization() {
+        let usage: HeapUsage = serde_json::from_value(json!({
+            "usedSize": 1234567.0,
+            "totalSize": 2345678,
+            "embedderHeapUsedSize": 0,
+            "backingStorageSize": 0
+        }))
+        .unwrap();
+        assert_eq!(usage.used_size, 1_234_567);
+        assert_eq!(usage.total_size, 2_345_678);
+    }
+}
@@ -0,0 +1,22 @@
+// Typed CDP domains
+//
+// Thin, typed wrappers over CDPSession for the Chrome DevTools Protocol domains
+// most tests need: Network, Performance, Emulation, Tracing, and HeapProfiler. Anything not
+// covered here is still reachable through CDPSession::send().
+//
+// See: https://chromedevtools.github.io/devtools-protocol/
+
+pub mod emulation;
+pub mod heap_profiler;
+pub mod network;
+pub mod performance;
+pub mod tracing;
+
+pub use emulation::{DeviceMetrics, Emulation};
+pub use heap_profiler::{HeapProfiler, HeapUsage};
+pub use network::{
+    CdpRequest, CdpResponse, LoadingFailed, LoadingFinished, Network, NetworkConditions,
+    RequestWillBeSent, ResponseReceived,
+};
+pub use performance::{Metric, Performance};
+pub use tracing::{Tracing, TracingStartOptions};
@@ -0,0 +1,377 @@
+// CDP Network domain
+//
+// See: https://chromedevtools.github.io/devtools-protocol/tot/Network/
+
+use crate::error::Result;
+use crate::protocol::CDPSession;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::future::Future;
+
+/// Typed wrapper for the CDP `Network` domain.
+///
+/// Obtained via [`CDPSession::network()`]. Events are only emitted after
+/// [`enable()`](Self::enable).
+///
+/// See: <https://chromedevtools.github.io/devtools-protocol/tot/Network/>
+#[derive(Debug, Clone, Copy)]
+pub struct Network<'a> {
+    session: &'a CDPSession,
+}
+
+impl<'a> Network<'a> {
+    pub(crate) fn new(session: &'a CDPSession) -> Self {
+        Self { session }
+    }
+
+    /// Enables network tracking; network events will now be delivered
+    pub async fn enable(&self) -> Result<()> {
+        self.session.send("Network.enable", None).await?;
+        Ok(())
+    }
+
+    /// Disables network tracking
+    pub async fn disable(&self) -> Result<()> {
+        self.session.send("Network.disable", None).await?;
+        Ok(())
+    }
+
+    /// Toggles ignoring the browser cache for each request
+    pub async fn set_cache_disabled(&self, disabled: bool) -> Result<()> {
+        self.session
+            .send(
+                "Network.setCacheDisabled",
+                Some(json!({ "cacheDisabled": disabled })),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Clears the browser cache
+    pub async fn clear_browser_cache(&self) -> Result<()> {
+        self.session.send("Network.clearBrowserCache", None).await?;
+        Ok(())
+    }
+
+    /// Sends the given headers with every request
+    pub async fn set_extra_http_headers(&self, headers: HashMap<String, String>) -> Result<()> {
+        self.session
+            .send(
+                "Network.setExtraHTTPHeaders",
+                Some(json!({ "headers": headers })),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Emulates network conditions (latency, throughput, offline)
+    pub async fn emulate_network_conditions(&self, conditions: &NetworkConditions) -> Result<()> {
+        self.session
+            .send(
+                "Network.emulateNetworkConditions",
+                Some(serde_json::to_value(conditions)?),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the body of a response, decoded from base64 when needed
+    pub async fn get_response_body(&self, request_id: &str) -> Result<Vec<u8>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ResponseBody {
+            body: String,
+            base64_encoded: bool,
+        }
+
+        let response: ResponseBody = self
+            .session
+            .send_as(
+                "Network.getResponseBody",
+                Some(json!({ "requestId": request_id })),
+            )
+            .await?;
+
+        if response.base64_encoded {
+            use base64::Engine;
+            base64::engine::general_purpose::STANDARD
+                .decode(&response.body)
+                .map_err(|e| {
+                    crate::error::Error::ProtocolError(format!(
+                        "Failed to decode response body: {}",
+                        e
+                    ))
+                })
+        } else {
+            Ok(response.body.into_bytes())
+        }
+    }
+
+    /// Registers a handler for `Network.requestWillBeSent`
+    pub async fn on_request_will_be_sent<F, Fut>(&self, handler: F) -> Result<()>
+    where
+        F: Fn(RequestWillBeSent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.session
+            .on_typed("Network.requestWillBeSent", handler)
+            .await
+    }
+
+    /// Registers a handler for `Network.responseReceived`
+    pub async fn on_response_received<F, Fut>(&self, handler: F) -> Result<()>
+    where
+        F: Fn(ResponseReceived) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.session
+            .on_typed("Network.responseReceived", handler)
+            .await
+    }
+
+    /// Registers a handler for `Network.loadingFinished`
+    pub async fn on_loading_finished<F, Fut>(&self, handler: F) -> Result<()>
+    where
+        F: Fn(LoadingFinished) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.session
+            .on_typed("Network.loadingFinished", handler)
+            .await
+    }
+
+    /// Registers a handler for `Network.loadingFailed`
+    pub async fn on_loading_failed<F, Fut>(&self, handler: F) -> Result<()>
+    where
+        F: Fn(LoadingFailed) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.session
+            .on_typed("Network.loadingFailed", handler)
+            .await
+    }
+}
+
+/// Parameters for `Network.emulateNetworkConditions`
+///
+/// Throughput values are in bytes per second; `-1` disables throttling.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkConditions {
+    /// True to emulate internet disconnection
+    pub offline: bool,
+    /// Minimum latency from request sent to response headers received (ms)
+    pub latency: f64,
+    /// Maximal aggregated download throughput (bytes/sec)
+    pub download_throughput: f64,
+    /// Maximal aggregated upload throughput (bytes/sec)
+    pub upload_throughput: f64,
+}
+
+impl NetworkConditions {
+    /// Chrome DevTools "Slow 3G" preset: 2000ms latency, ~400 kbit/s down and up
+    pub fn slow_3g() -> Self {
+        Self {
+            offline: false,
+            latency: 2000.0,
+            download_throughput: 50_000.0,
+            upload_throughput: 50_000.0,
+        }
+    }
+
+    /// Chrome DevTools "Fast 3G" preset: 562.5ms latency, ~1.44 Mbit/s down, ~675 kbit/s up
+    pub fn fast_3g() -> Self {
+        Self {
+            offline: false,
+            latency: 562.5,
+            download_throughput: 180_000.0,
+            upload_throughput: 84_375.0,
+        }
+    }
+
+    /// Emulates a disconnected network
+    pub fn offline() -> Self {
+        Self {
+            offline: true,
+            latency: 0.0,
+            download_throughput: -1.0,
+            upload_throughput: -1.0,
+        }
+    }
+
+    /// Disables throttling
+    pub fn no_throttling() -> Self {
+        Self {
+            offline: false,
+            latency: 0.0,
+            download_throughput: -1.0,
+            upload_throughput: -1.0,
+        }
+    }
+}
+
+/// HTTP request data as reported by CDP
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CdpRequest {
+    /// Request URL
+    pub url: String,
+    /// HTTP method
+    pub method: String,
+    /// Request headers
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// POST data, if any
+    #[serde(default)]
+    pub post_data: Option<String>,
+}
+
+/// HTTP response data as reported by CDP
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CdpResponse {
+    /// Response URL
+    pub url: String,
+    /// HTTP status code
+    pub status: u16,
+    /// HTTP status text
+    #[serde(default)]
+    pub status_text: String,
+    /// Response headers
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Resource MIME type
+    #[serde(default)]
+    pub mime_type: String,
+    /// Whether the response was served from the disk cache
+    #[serde(default)]
+    pub from_disk_cache: bool,
+}
+
+/// `Network.requestWillBeSent` event
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestWillBeSent {
+    /// Request identifier
+    pub request_id: String,
+    /// Request data
+    pub request: CdpRequest,
+    /// Timestamp (seconds, monotonic)
+    pub timestamp: f64,
+    /// Resource type (e.g., "Document", "XHR", "Fetch")
+    #[serde(default, rename = "type")]
+    pub resource_type: Option<String>,
+}
+
+/// `Network.responseReceived` event
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseReceived {
+    /// Request identifier
+    pub request_id: String,
+    /// Response data
+    pub response: CdpResponse,
+    /// Timestamp (seconds, monotonic)
+    pub timestamp: f64,
+    /// Resource type (e.g., "Document", "XHR", "Fetch")
+    #[serde(default, rename = "type")]
+    pub resource_type: Option<String>,
+}
+
+/// `Network.loadingFinished` event
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadingFinished {
+    /// Request identifier
+    pub request_id: String,
+    /// Timestamp (seconds, monotonic)
+    pub timestamp: f64,
+    /// Total bytes received for this request
+    pub encoded_data_length: f64,
+}
+
+/// `Network.loadingFailed` event
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadingFailed {
+    /// Request identifier
+    pub request_id: String,
+    /// Timestamp (seconds, monotonic)
+    pub timestamp: f64,
+    /// Error message, e.g. "net::ERR_NAME_NOT_RESOLVED"
+    pub error_text: String,
+    /// True if the request was canceled
+    #[serde(default)]
+    pub canceled: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_conditions_serialization() {
+        let conditions = NetworkConditions {
+            offline: false,
+            latency: 100.0,
+            download_throughput: 1000.0,
+            upload_throughput: 500.0,
+        };
+        let value = serde_json::to_value(&conditions).unwrap();
+        assert_eq!(value["downloadThroughput"], 1000.0);
+        assert_eq!(value["uploadThroughput"], 500.0);
+        assert_eq!(value["offline"], false);
+    }
+
+    #[test]
+    fn test_network_condition_presets() {
+        assert!(NetworkConditions::slow_3g().latency > NetworkConditions::fast_3g().latency);
+        assert!(NetworkConditions::offline().offline);
+        assert_eq!(NetworkConditions::no_throttling().download_throughput, -1.0);
+    }
+
+    #[test]
+    fn test_request_will_be_sent_deserialization() {
+        let event: RequestWillBeSent = serde_json::from_value(json!({
+            "requestId": "1000.1",
+            "loaderId": "L1",
+            "documentURL": "https://example.com/",
+            "request": {
+                "url": "https://example.com/",
+                "method": "GET",
+                "headers": {"Accept": "text/html"},
+                "initialPriority": "VeryHigh"
+            },
+            "timestamp": 12.5,
+            "wallTime": 1700000000.0,
+            "type": "Document"
+        }))
+        .unwrap();
+
+        assert_eq!(event.request_id, "1000.1");
+        assert_eq!(event.request.method, "GET");
+        assert_eq!(event.resource_type.as_deref(), Some("Document"));
+    }
+
+    #[test]
+    fn test_response_received_deserialization() {
+        let event: ResponseReceived = serde_json::from_value(json!({
+            "requestId": "1000.1",
+            "timestamp": 13.0,
+            "type": "Document",
+            "response": {
+                "url": "https://example.com/",
+                "status": 200,
+                "statusText": "OK",
+                "headers": {"content-type": "text/html"},
+                "mimeType": "text/html"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(event.response.status, 200);
+        assert_eq!(event.response.mime_type, "text/html");
+        assert!(!event.response.from_disk_cache);
+    }
+}
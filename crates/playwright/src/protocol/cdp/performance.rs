@@ -0,0 +1,71 @@
+// CDP Performance domain
+//
+// See: https://chromedevtools.github.io/devtools-protocol/tot/Performance/
+
+use crate::error::Result;
+use crate::protocol::CDPSession;
+use serde::Deserialize;
+use serde_json::json;
+
+/// Typed wrapper for the CDP `Performance` domain.
+///
+/// Obtained via [`CDPSession::performance()`]. Call [`enable()`](Self::enable)
+/// before reading metrics.
+///
+/// See: <https://chromedevtools.github.io/devtools-protocol/tot/Performance/>
+#[derive(Debug, Clone, Copy)]
+pub struct Performance<'a> {
+    session: &'a CDPSession,
+}
+
+impl<'a> Performance<'a> {
+    pub(crate) fn new(session: &'a CDPSession) -> Self {
+        Self { session }
+    }
+
+    /// Enables collecting and reporting metrics
+    pub async fn enable(&self) -> Result<()> {
+        self.session.send("Performance.enable", None).await?;
+        Ok(())
+    }
+
+    /// Disables collecting and reporting metrics
+    pub async fn disable(&self) -> Result<()> {
+        self.session.send("Performance.disable", None).await?;
+        Ok(())
+    }
+
+    /// Returns current values of run-time metrics
+    /// (e.g. "JSHeapUsedSize", "Nodes", "LayoutCount", "TaskDuration")
+    pub async fn get_metrics(&self) -> Result<Vec<Metric>> {
+        #[derive(Deserialize)]
+        struct GetMetricsResponse {
+            metrics: Vec<Metric>,
+        }
+
+        let response: GetMetricsResponse = self
+            .session
+            .send_as("Performance.getMetrics", Some(json!({})))
+            .await?;
+        Ok(response.metrics)
+    }
+
+    /// Returns the value of a single metric, if reported
+    pub async fn get_metric(&self, name: &str) -> Result<Option<f64>> {
+        Ok(self
+            .get_metrics()
+            .await?
+            .into_iter()
+            .find(|m| m.name == name)
+            .map(|m| m.value))
+    }
+}
+
+/// A single run-time metric
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Metric {
+    /// Metric name
+    pub name: String,
+    /// Metric value
+    pub value: f64,
+}
@@ -0,0 +1,112 @@
+// CDP Tracing domain
+//
+// Records a Chrome trace (viewable in chrome://tracing or Perfetto).
+// Trace data arrives as `Tracing.dataCollected` events until
+// `Tracing.tracingComplete`; `end()` collects them.
+//
+// See: https://chromedevtools.github.io/devtools-protocol/tot/Tracing/
+
+use crate::error::{Error, Result};
+use crate::protocol::CDPSession;
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Default time to wait for trace data after `Tracing.end`
+const TRACING_COMPLETE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Typed wrapper for the CDP `Tracing` domain.
+///
+/// Obtained via [`CDPSession::tracing()`].
+///
+/// # Example
+///
+/// ```ignore
+/// let session = context.new_cdp_session(&page).await?;
+/// session.tracing().start(TracingStartOptions::default()).await?;
+/// page.goto("https://example.com", None).await?;
+/// let events = session.tracing().end().await?;
+/// ```
+///
+/// See: <https://chromedevtools.github.io/devtools-protocol/tot/Tracing/>
+#[derive(Debug, Clone, Copy)]
+pub struct Tracing<'a> {
+    session: &'a CDPSession,
+}
+
+impl<'a> Tracing<'a> {
+    pub(crate) fn new(session: &'a CDPSession) -> Self {
+        Self { session }
+    }
+
+    /// Starts trace recording
+    pub async fn start(&self, options: TracingStartOptions) -> Result<()> {
+        let mut params = json!({ "transferMode": "ReportEvents" });
+        if !options.categories.is_empty() {
+            params["traceConfig"] = json!({ "includedCategories": options.categories });
+        }
+        self.session.send("Tracing.start", Some(params)).await?;
+        Ok(())
+    }
+
+    /// Stops trace recording and returns the collected trace events
+    pub async fn end(&self) -> Result<Vec<Value>> {
+        let events: Arc<Mutex<Vec<Value>>> = Arc::new(Mutex::new(Vec::new()));
+        let (complete_tx, complete_rx) = tokio::sync::oneshot::channel::<()>();
+        let complete_tx = Arc::new(Mutex::new(Some(complete_tx)));
+
+        let events_clone = Arc::clone(&events);
+        let data_id =
+            self.session
+                .add_event_handler("Tracing.dataCollected", move |params: Value| {
+                    if let Some(Value::Array(chunk)) = params.get("value") {
+                        events_clone.lock().unwrap().extend(chunk.iter().cloned());
+                    }
+                    async { Ok(()) }
+                });
+        let complete_id =
+            self.session
+                .add_event_handler("Tracing.tracingComplete", move |_params: Value| {
+                    if let Some(tx) = complete_tx.lock().unwrap().take() {
+                        let _ = tx.send(());
+                    }
+                    async { Ok(()) }
+                });
+
+        let result = async {
+            self.session.send("Tracing.end", None).await?;
+            tokio::time::timeout(TRACING_COMPLETE_TIMEOUT, complete_rx)
+                .await
+                .map_err(|_| Error::Timeout("Waiting for Tracing.tracingComplete".to_string()))?
+                .map_err(|_| Error::ChannelClosed)
+        }
+        .await;
+
+        self.session.remove_event_handler(data_id);
+        self.session.remove_event_handler(complete_id);
+        result?;
+
+        let events = std::mem::take(&mut *events.lock().unwrap());
+        Ok(events)
+    }
+}
+
+/// Options for [`Tracing::start`]
+#[derive(Debug, Clone, Default)]
+pub struct TracingStartOptions {
+    /// Trace categories to include (e.g. "devtools.timeline"); empty uses Chrome's defaults
+    pub categories: Vec<String>,
+}
+
+impl TracingStartOptions {
+    /// Creates options with Chrome's default categories
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a trace category
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.categories.push(category.into());
+        self
+    }
+}
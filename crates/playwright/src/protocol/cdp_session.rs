@@ -0,0 +1,374 @@
+// Copyright 2024 Paul Adamson
+// Licensed under the Apache License, Version 2.0
+//
+// CDPSession protocol object
+//
+// Raw Chrome DevTools Protocol session, created via
+// BrowserContext::new_cdp_session() or Browser::new_browser_cdp_session().
+// Chromium only.
+//
+// Typed wrappers for common domains live in `protocol::cdp`.
+
+use crate::error::{Error, Result};
+use crate::protocol::cdp::{Emulation, HeapProfiler, Network, Performance, Tracing};
+use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::any::Any;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Type alias for boxed CDP event handler future
+type CdpEventHandlerFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
+
+/// CDP event handler - receives the event's params
+type CdpEventHandler = Arc<dyn Fn(Value) -> CdpEventHandlerFuture + Send + Sync>;
+
+/// Registered handler for a single CDP event name
+#[derive(Clone)]
+struct CdpEventHandlerEntry {
+    id: u64,
+    method: String,
+    handler: CdpEventHandler,
+}
+
+/// CDPSession sends raw Chrome DevTools Protocol commands and receives CDP events.
+///
+/// Only available for Chromium-based browsers. For the most common domains,
+/// prefer the typed wrappers returned by [`network()`](Self::network),
+/// [`performance()`](Self::performance), [`emulation()`](Self::emulation),
+/// and [`tracing()`](Self::tracing).
+///
+/// # Example
+///
+/// ```ignore
+/// let session = context.new_cdp_session(&page).await?;
+///
+/// // Raw command
+/// let version = session.send("Browser.getVersion", None).await?;
+///
+/// // Typed domain wrapper
+/// session.performance().enable().await?;
+/// let metrics = session.performance().get_metrics().await?;
+/// ```
+///
+/// See: <https://playwright.dev/docs/api/class-cdpsession>
+#[derive(Clone)]
+pub struct CDPSession {
+    base: ChannelOwnerImpl,
+    /// CDP event handlers, keyed by CDP method name (e.g., "Network.requestWillBeSent")
+    event_handlers: Arc<Mutex<Vec<CdpEventHandlerEntry>>>,
+    /// Source of handler ids for removal
+    next_handler_id: Arc<AtomicU64>,
+}
+
+impl CDPSession {
+    /// Creates a new CDPSession from protocol initialization
+    ///
+    /// This is called by the object factory when the server sends a `__create__` message
+    /// for a CDPSession object.
+    pub fn new(
+        parent: Arc<dyn ChannelOwner>,
+        type_name: String,
+        guid: Arc<str>,
+        initializer: Value,
+    ) -> Result<Self> {
+        let base = ChannelOwnerImpl::new(
+            ParentOrConnection::Parent(parent),
+            type_name,
+            guid,
+            initializer,
+        );
+
+        Ok(Self {
+            base,
+            event_handlers: Arc::new(Mutex::new(Vec::new())),
+            next_handler_id: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// Sends a session-creating RPC on `owner` and returns the created CDPSession.
+    ///
+    /// Used by `BrowserContext::new_cdp_session()` and `Browser::new_browser_cdp_session()`.
+    pub(crate) async fn create(
+        owner: &dyn ChannelOwner,
+        method: &str,
+        params: Value,
+    ) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct NewSessionResponse {
+            session: GuidRef,
+        }
+
+        #[derive(Deserialize)]
+        struct GuidRef {
+            #[serde(deserialize_with = "crate::server::connection::deserialize_arc_str")]
+            guid: Arc<str>,
+        }
+
+        let response: NewSessionResponse = owner.channel().send(method, params).await?;
+        let session_arc = owner
+            .connection()
+            .get_object(&response.session.guid)
+            .await?;
+        let session = session_arc
+            .as_any()
+            .downcast_ref::<CDPSession>()
+            .ok_or_else(|| {
+                Error::ProtocolError(format!(
+                    "Expected CDPSession object, got {}",
+                    session_arc.type_name()
+                ))
+            })?;
+
+        Ok(session.clone())
+    }
+
+    /// Sends a raw CDP command and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - CDP method name, e.g. "Network.enable"
+    /// * `params` - Optional command parameters
+    ///
+    /// See: <https://playwright.dev/docs/api/class-cdpsession#cdp-session-send>
+    pub async fn send(&self, method: &str, params: Option<Value>) -> Result<Value> {
+        #[derive(Deserialize)]
+        struct SendResponse {
+            #[serde(default)]
+            result: Value,
+        }
+
+        let response: SendResponse = self
+            .channel()
+            .send(
+                "send",
+                json!({
+                    "method": method,
+                    "params": params.unwrap_or_else(|| json!({})),
+                }),
+            )
+            .await?;
+
+        Ok(response.result)
+    }
+
+    /// Sends a CDP command and deserializes its result into `T`.
+    pub async fn send_as<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Option<Value>,
+    ) -> Result<T> {
+        let result = self.send(method, params).await?;
+        serde_json::from_value(result).map_err(|e| {
+            Error::ProtocolError(format!("Failed to parse result of {}: {}", method, e))
+        })
+    }
+
+    /// Detaches the session from its target.
+    ///
+    /// The session can no longer be used after detaching.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-cdpsession#cdp-session-detach>
+    pub async fn detach(&self) -> Result<()> {
+        self.channel().send_no_result("detach", json!({})).await
+    }
+
+    /// Registers a handler for a raw CDP event.
+    ///
+    /// The handler receives the event's `params` object. Most events are only
+    /// emitted after their domain has been enabled (e.g. `Network.enable`).
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - CDP event name, e.g. "Network.responseReceived"
+    /// * `handler` - Async closure that receives the event params
+    ///
+    /// See: <https://playwright.dev/docs/api/class-cdpsession#cdp-session-on>
+    pub async fn on<F, Fut>(&self, method: &str, handler: F) -> Result<()>
+    where
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.add_event_handler(method, handler);
+        Ok(())
+    }
+
+    /// Registers a handler for a CDP event, deserializing params into `T`.
+    ///
+    /// Events whose params fail to parse are logged and skipped.
+    pub async fn on_typed<T, F, Fut>(&self, method: &str, handler: F) -> Result<()>
+    where
+        T: DeserializeOwned + Send + 'static,
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let handler = Arc::new(handler);
+        let event_name = method.to_string();
+        self.add_event_handler(method, move |params: Value| {
+            let handler = Arc::clone(&handler);
+            let event_name = event_name.clone();
+            async move {
+                match serde_json::from_value::<T>(params) {
+                    Ok(event) => handler(event).await,
+                    Err(e) => {
+                        tracing::warn!("Failed to parse CDP event {}: {}", event_name, e);
+                        Ok(())
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// Network domain: request/response events, caching, throttling
+    pub fn network(&self) -> Network<'_> {
+        Network::new(self)
+    }
+
+    /// Performance domain: runtime metrics
+    pub fn performance(&self) -> Performance<'_> {
+        Performance::new(self)
+    }
+
+    /// Emulation domain: device metrics, CPU throttling, timezone, locale
+    pub fn emulation(&self) -> Emulation<'_> {
+        Emulation::new(self)
+    }
+
+    /// HeapProfiler domain: heap snapshots, garbage collection, heap usage
+    pub fn heap_profiler(&self) -> HeapProfiler<'_> {
+        HeapProfiler::new(self)
+    }
+
+    /// Tracing domain: Chrome trace recording
+    pub fn tracing(&self) -> Tracing<'_> {
+        Tracing::new(self)
+    }
+
+    /// Stores a handler and returns its id
+    pub(crate) fn add_event_handler<F, Fut>(&self, method: &str, handler: F) -> u64
+    where
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let id = self.next_handler_id.fetch_add(1, Ordering::Relaxed);
+        let handler: CdpEventHandler =
+            Arc::new(move |params: Value| -> CdpEventHandlerFuture { Box::pin(handler(params)) });
+        self.event_handlers
+            .lock()
+            .unwrap()
+            .push(CdpEventHandlerEntry {
+                id,
+                method: method.to_string(),
+                handler,
+            });
+        id
+    }
+
+    /// Removes a handler previously returned by `add_event_handler`
+    pub(crate) fn remove_event_handler(&self, id: u64) {
+        self.event_handlers.lock().unwrap().retain(|e| e.id != id);
+    }
+
+    /// Dispatches a CDP event to matching handlers
+    fn on_cdp_event(&self, method: &str, params: Value) {
+        let handlers: Vec<CdpEventHandler> = self
+            .event_handlers
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|e| e.method == method)
+            .map(|e| Arc::clone(&e.handler))
+            .collect();
+
+        if handlers.is_empty() {
+            return;
+        }
+
+        // Create the futures here so synchronous handler work runs in event order
+        let futures: Vec<CdpEventHandlerFuture> = handlers
+            .iter()
+            .map(|handler| handler(params.clone()))
+            .collect();
+        let method = method.to_string();
+        tokio::spawn(async move {
+            for future in futures {
+                if let Err(e) = future.await {
+                    tracing::warn!("CDP event handler error ({}): {}", method, e);
+                }
+            }
+        });
+    }
+}
+
+impl ChannelOwner for CDPSession {
+    fn guid(&self) -> &str {
+        self.base.guid()
+    }
+
+    fn type_name(&self) -> &str {
+        self.base.type_name()
+    }
+
+    fn parent(&self) -> Option<Arc<dyn ChannelOwner>> {
+        self.base.parent()
+    }
+
+    fn connection(&self) -> Arc<dyn crate::server::connection::ConnectionLike> {
+        self.base.connection()
+    }
+
+    fn initializer(&self) -> &Value {
+        self.base.initializer()
+    }
+
+    fn channel(&self) -> &crate::server::channel::Channel {
+        self.base.channel()
+    }
+
+    fn dispose(&self, reason: crate::server::channel_owner::DisposeReason) {
+        self.base.dispose(reason)
+    }
+
+    fn adopt(&self, child: Arc<dyn ChannelOwner>) {
+        self.base.adopt(child)
+    }
+
+    fn add_child(&self, guid: Arc<str>, child: Arc<dyn ChannelOwner>) {
+        self.base.add_child(guid, child)
+    }
+
+    fn remove_child(&self, guid: &str) {
+        self.base.remove_child(guid)
+    }
+
+    fn on_event(&self, method: &str, params: Value) {
+        // CDP events arrive wrapped as {method, params}
+        if method == "event" {
+            if let Some(cdp_method) = params.get("method").and_then(|v| v.as_str()) {
+                let cdp_params = params.get("params").cloned().unwrap_or(Value::Null);
+                self.on_cdp_event(cdp_method, cdp_params);
+            }
+        }
+    }
+
+    fn was_collected(&self) -> bool {
+        self.base.was_collected()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl std::fmt::Debug for CDPSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CDPSession")
+            .field("guid", &self.guid())
+            .finish()
+    }
+}
@@ -0,0 +1,120 @@
+// Clock - Controlling time inside a browser context
+//
+// Wraps the driver's clock emulation (Date, timers, requestAnimationFrame).
+// Times are milliseconds since the Unix epoch.
+//
+// See: https://playwright.dev/docs/api/class-clock
+
+use crate::error::Result;
+use crate::protocol::BrowserContext;
+use std::time::Duration;
+
+/// Clock controls time for every page in a browser context.
+///
+/// Obtain it via [`BrowserContext::clock`] or [`Page::clock`](crate::protocol::Page::clock).
+///
+/// # Example
+///
+/// ```ignore
+/// use std::time::Duration;
+///
+/// // Freeze Date.now() while letting timers run normally
+/// context.clock().set_fixed_time(1_704_067_200_000.0).await?;
+///
+/// // Or take full control of timers
+/// context.clock().install(Some(1_704_067_200_000.0)).await?;
+/// page.goto("https://example.com", None).await?;
+/// context.clock().run_for(Duration::from_secs(60)).await?;
+/// ```
+///
+/// See: <https://playwright.dev/docs/api/class-clock>
+#[derive(Clone)]
+pub struct Clock {
+    context: BrowserContext,
+}
+
+impl Clock {
+    /// Creates a new Clock for the given context
+    pub(crate) fn new(context: BrowserContext) -> Self {
+        Self { context }
+    }
+
+    /// Installs fake timers, optionally starting at `time` (ms since epoch).
+    ///
+    /// Must be called before other methods that manipulate timers.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-clock#clock-install>
+    pub async fn install(&self, time: Option<f64>) -> Result<()> {
+        let params = match time {
+            Some(time) => serde_json::json!({ "timeNumber": time }),
+            None => serde_json::json!({}),
+        };
+        self.context.send_clock("clockInstall", params).await
+    }
+
+    /// Makes `Date.now()` and `new Date()` always return `time`, keeping timers running.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-clock#clock-set-fixed-time>
+    pub async fn set_fixed_time(&self, time: f64) -> Result<()> {
+        self.context
+            .send_clock(
+                "clockSetFixedTime",
+                serde_json::json!({ "timeNumber": time }),
+            )
+            .await
+    }
+
+    /// Sets the wall-clock time without firing timers.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-clock#clock-set-system-time>
+    pub async fn set_system_time(&self, time: f64) -> Result<()> {
+        self.context
+            .send_clock(
+                "clockSetSystemTime",
+                serde_json::json!({ "timeNumber": time }),
+            )
+            .await
+    }
+
+    /// Advances time, firing due timers at most once each.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-clock#clock-fast-forward>
+    pub async fn fast_forward(&self, ticks: Duration) -> Result<()> {
+        self.context
+            .send_clock(
+                "clockFastForward",
+                serde_json::json!({ "ticksNumber": ticks.as_millis() as f64 }),
+            )
+            .await
+    }
+
+    /// Advances time, firing every timer that becomes due.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-clock#clock-run-for>
+    pub async fn run_for(&self, ticks: Duration) -> Result<()> {
+        self.context
+            .send_clock(
+                "clockRunFor",
+                serde_json::json!({ "ticksNumber": ticks.as_millis() as f64 }),
+            )
+            .await
+    }
+
+    /// Advances time to `time` (ms since epoch) and pauses; no timers fire until resumed.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-clock#clock-pause-at>
+    pub async fn pause_at(&self, time: f64) -> Result<()> {
+        self.context
+            .send_clock("clockPauseAt", serde_json::json!({ "timeNumber": time }))
+            .await
+    }
+
+    /// Resumes timers after [`pause_at`](Clock::pause_at).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-clock#clock-resume>
+    pub async fn resume(&self) -> Result<()> {
+        self.context
+            .send_clock("clockResume", serde_json::json!({}))
+            .await
+    }
+}
@@ -0,0 +1,484 @@
+// Component testing - Mounting frontend components served by a dev server
+//
+// Experimental. Mirrors @playwright/experimental-ct: a dev server (typically
+// Vite) serves a test harness page, and each test mounts one component into it
+// and drives it through an ordinary Locator.
+//
+// The harness contract is deliberately small. The page at `index_path` must
+// define two globals:
+//
+//   window.__pwMount = async (name, props, root, hooksConfig) => { ... }
+//   window.__pwUnmount = async (root) => { ... }
+//
+// where `name` identifies a component in a registry the harness builds (e.g. an
+// object of imported components), `props` is the JSON props object and `root`
+// is the mount element (`#root` by default). A React harness is a few lines:
+//
+//   import { createRoot } from 'react-dom/client';
+//   import * as components from '../src/components';
+//   let app;
+//   window.__pwMount = async (name, props, root) => {
+//     app = createRoot(root);
+//     app.render(React.createElement(components[name], props));
+//   };
+//   window.__pwUnmount = async () => app?.unmount();
+
+use crate::error::{Error, Result};
+use crate::protocol::{Locator, Page};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::process::{Child, Command};
+
+/// Waits for the harness, then mounts the component
+const MOUNT_SCRIPT: &str = r#"async ({ name, props, rootSelector, hooksConfig, timeout }) => {
+  const deadline = Date.now() + timeout;
+  while (typeof window.__pwMount !== 'function') {
+    if (Date.now() > deadline) {
+      throw new Error('Component harness did not define window.__pwMount');
+    }
+    await new Promise((resolve) => setTimeout(resolve, 50));
+  }
+  const root = document.querySelector(rootSelector);
+  if (!root) throw new Error(`Mount root ${rootSelector} not found`);
+  await window.__pwMount(name, props, root, hooksConfig);
+}"#;
+
+/// Unmounts the current component, if the harness supports it
+const UNMOUNT_SCRIPT: &str = r#"async ({ rootSelector }) => {
+  if (typeof window.__pwUnmount === 'function') {
+    await window.__pwUnmount(document.querySelector(rootSelector));
+  }
+}"#;
+
+/// A component to mount: its registry name, props and optional hooks config.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::ComponentDescriptor;
+///
+/// let button = ComponentDescriptor::new("Button")
+///     .prop("label", "Save")
+///     .prop("disabled", false);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentDescriptor {
+    /// Component name in the harness registry
+    pub name: String,
+    /// Props passed to the component
+    pub props: serde_json::Map<String, Value>,
+    /// Extra configuration passed to the harness (e.g. router or theme setup)
+    pub hooks_config: Option<Value>,
+}
+
+impl ComponentDescriptor {
+    /// Creates a descriptor with no props
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            props: serde_json::Map::new(),
+            hooks_config: None,
+        }
+    }
+
+    /// Sets one prop
+    ///
+    /// Values that fail to serialize are stored as `null`.
+    pub fn prop(mut self, name: impl Into<String>, value: impl Serialize) -> Self {
+        self.props.insert(
+            name.into(),
+            serde_json::to_value(value).unwrap_or(Value::Null),
+        );
+        self
+    }
+
+    /// Replaces all props with the fields of a serializable struct
+    pub fn props<T: Serialize>(mut self, props: &T) -> Result<Self> {
+        match serde_json::to_value(props)? {
+            Value::Object(map) => {
+                self.props = map;
+                Ok(self)
+            }
+            _ => Err(Error::InvalidArgument(
+                "Component props must serialize to a JSON object".to_string(),
+            )),
+        }
+    }
+
+    /// Sets the hooks config passed to the harness
+    pub fn hooks_config(mut self, config: impl Serialize) -> Self {
+        self.hooks_config = serde_json::to_value(config).ok();
+        self
+    }
+}
+
+/// Command that starts a dev server.
+#[derive(Debug, Clone)]
+pub struct DevServerCommand {
+    /// Program to run (e.g. `npx`)
+    pub program: String,
+    /// Program arguments
+    pub args: Vec<String>,
+    /// Working directory (the frontend project)
+    pub cwd: Option<PathBuf>,
+    /// Extra environment variables
+    pub env: Vec<(String, String)>,
+    /// URL the server listens on once ready
+    pub url: String,
+    /// How long to wait for the server to accept connections (default: 60 seconds)
+    pub startup_timeout: Duration,
+}
+
+impl DevServerCommand {
+    /// Creates a command that serves `url`
+    pub fn new(program: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            cwd: None,
+            env: Vec::new(),
+            url: url.into(),
+            startup_timeout: Duration::from_secs(60),
+        }
+    }
+
+    /// `npx vite --port <port> --strictPort` in `project_dir`
+    pub fn vite(project_dir: impl Into<PathBuf>, port: u16) -> Self {
+        Self::new("npx", format!("http://localhost:{}", port))
+            .args(["vite", "--port", &port.to_string(), "--strictPort"])
+            .cwd(project_dir)
+    }
+
+    /// `trunk serve --port <port>` in `project_dir`.
+    ///
+    /// The first build compiles the WASM bundle, so the startup timeout is
+    /// raised to five minutes.
+    pub fn trunk(project_dir: impl Into<PathBuf>, port: u16) -> Self {
+        Self::new("trunk", format!("http://127.0.0.1:{}", port))
+            .args(["serve", "--port", &port.to_string()])
+            .cwd(project_dir)
+            .startup_timeout(Duration::from_secs(300))
+    }
+
+    /// `cargo leptos serve` in `project_dir`, listening on `port`.
+    ///
+    /// The port is passed via `LEPTOS_SITE_ADDR`, overriding `Cargo.toml`.
+    pub fn cargo_leptos(project_dir: impl Into<PathBuf>, port: u16) -> Self {
+        let address = format!("127.0.0.1:{}", port);
+        Self::new("cargo", format!("http://{}", address))
+            .args(["leptos", "serve"])
+            .env("LEPTOS_SITE_ADDR", address)
+            .cwd(project_dir)
+            .startup_timeout(Duration::from_secs(300))
+    }
+
+    /// `dx serve --port <port>` (Dioxus CLI) in `project_dir`
+    pub fn dioxus(project_dir: impl Into<PathBuf>, port: u16) -> Self {
+        Self::new("dx", format!("http://127.0.0.1:{}", port))
+            .args(["serve", "--port", &port.to_string()])
+            .cwd(project_dir)
+            .startup_timeout(Duration::from_secs(300))
+    }
+
+    /// Appends arguments
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the working directory
+    pub fn cwd(mut self, cwd: impl Into<PathBuf>) -> Self {
+        self.cwd = Some(cwd.into());
+        self
+    }
+
+    /// Sets an environment variable for the server process
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets how long to wait for the server to start
+    pub fn startup_timeout(mut self, timeout: Duration) -> Self {
+        self.startup_timeout = timeout;
+        self
+    }
+}
+
+/// A dev server serving the component harness.
+///
+/// Servers started with [`DevServer::start`] are killed when dropped.
+#[derive(Debug)]
+pub struct DevServer {
+    url: String,
+    child: Option<Child>,
+}
+
+impl DevServer {
+    /// Uses a dev server that is already running at `url`
+    pub fn external(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            child: None,
+        }
+    }
+
+    /// Starts a dev server and waits until it accepts connections.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::LaunchFailed` if the process cannot be spawned or exits
+    /// early, and `Error::Timeout` if it does not start listening in time.
+    pub async fn start(command: DevServerCommand) -> Result<Self> {
+        let address = socket_address(&command.url)?;
+        let mut process = Command::new(&command.program);
+        process
+            .args(&command.args)
+            .envs(command.env.iter().map(|(k, v)| (k, v)))
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .kill_on_drop(true);
+        if let Some(cwd) = &command.cwd {
+            process.current_dir(cwd);
+        }
+        let mut child = process.spawn().map_err(|e| {
+            Error::LaunchFailed(format!(
+                "Failed to start dev server '{}': {}",
+                command.program, e
+            ))
+        })?;
+
+        let deadline = tokio::time::Instant::now() + command.startup_timeout;
+        loop {
+            if tokio::net::TcpStream::connect(&address).await.is_ok() {
+                break;
+            }
+            if let Ok(Some(status)) = child.try_wait() {
+                return Err(Error::LaunchFailed(format!(
+                    "Dev server '{}' exited with {}",
+                    command.program, status
+                )));
+            }
+            if tokio::time::Instant::now() >= deadline {
+                let _ = child.start_kill();
+                return Err(Error::Timeout(format!(
+                    "Dev server did not listen on {} within {:?}",
+                    command.url, command.startup_timeout
+                )));
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        Ok(Self {
+            url: command.url,
+            child: Some(child),
+        })
+    }
+
+    /// Base URL of the server
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Stops the server if this handle started it
+    pub async fn stop(mut self) -> Result<()> {
+        if let Some(child) = self.child.as_mut() {
+            child.kill().await?;
+        }
+        Ok(())
+    }
+}
+
+/// `host:port` for a `http(s)://host[:port]/...` URL
+fn socket_address(url: &str) -> Result<String> {
+    let invalid = || Error::InvalidArgument(format!("Invalid dev server URL '{}'", url));
+    let (scheme, rest) = url.split_once("://").ok_or_else(invalid)?;
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    if authority.is_empty() {
+        return Err(invalid());
+    }
+    if authority
+        .rsplit_once(':')
+        .is_some_and(|(_, port)| port.parse::<u16>().is_ok())
+    {
+        return Ok(authority.to_string());
+    }
+    let port = match scheme {
+        "http" => 80,
+        "https" => 443,
+        _ => return Err(invalid()),
+    };
+    Ok(format!("{}:{}", authority, port))
+}
+
+/// Mounts components from a dev server's harness page (experimental).
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::{ComponentDescriptor, ComponentTesting, DevServer, DevServerCommand};
+///
+/// let server = DevServer::start(DevServerCommand::vite("./frontend", 3100)).await?;
+/// let ct = ComponentTesting::new(&server);
+///
+/// let page = browser.new_page().await?;
+/// let button = ct
+///     .mount(&page, &ComponentDescriptor::new("Button").prop("label", "Save"))
+///     .await?;
+/// button.locator("button").click(None).await?;
+/// ct.unmount(&page).await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ComponentTesting {
+    base_url: String,
+    index_path: String,
+    root_selector: String,
+    timeout: Duration,
+}
+
+impl ComponentTesting {
+    /// Uses the harness at `<server>/playwright/index.html` with mount root `#root`
+    pub fn new(server: &DevServer) -> Self {
+        Self::with_url(server.url())
+    }
+
+    /// Uses a harness served from `base_url`
+    pub fn with_url(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            index_path: "/playwright/index.html".to_string(),
+            root_selector: "#root".to_string(),
+            timeout: Duration::from_millis(crate::DEFAULT_TIMEOUT_MS as u64),
+        }
+    }
+
+    /// Sets the harness page path
+    pub fn index_path(mut self, path: impl Into<String>) -> Self {
+        let path = path.into();
+        self.index_path = if path.starts_with('/') {
+            path
+        } else {
+            format!("/{}", path)
+        };
+        self
+    }
+
+    /// Sets the selector of the mount root element
+    pub fn root_selector(mut self, selector: impl Into<String>) -> Self {
+        self.root_selector = selector.into();
+        self
+    }
+
+    /// Sets how long to wait for the harness to load
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// URL of the harness page
+    pub fn index_url(&self) -> String {
+        format!("{}{}", self.base_url, self.index_path)
+    }
+
+    /// Mounts `component` and returns a locator for the mount root.
+    ///
+    /// Navigates `page` to the harness first unless it is already there.
+    pub async fn mount(&self, page: &Page, component: &ComponentDescriptor) -> Result<Locator> {
+        let index_url = self.index_url();
+        if !page.url().starts_with(&index_url) {
+            page.goto(&index_url, None).await?;
+        }
+        let arg = serde_json::json!({
+            "name": component.name,
+            "props": component.props,
+            "rootSelector": self.root_selector,
+            "hooksConfig": component.hooks_config,
+            "timeout": self.timeout.as_millis() as u64,
+        });
+        page.main_frame()
+            .await?
+            .evaluate(MOUNT_SCRIPT, Some(&arg))
+            .await?;
+        Ok(page.locator(&self.root_selector))
+    }
+
+    /// Unmounts the current component.
+    pub async fn unmount(&self, page: &Page) -> Result<()> {
+        let arg = serde_json::json!({ "rootSelector": self.root_selector });
+        page.main_frame()
+            .await?
+            .evaluate(UNMOUNT_SCRIPT, Some(&arg))
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_socket_address() {
+        assert_eq!(
+            socket_address("http://localhost:5173").unwrap(),
+            "localhost:5173"
+        );
+        assert_eq!(
+            socket_address("http://127.0.0.1:3000/app").unwrap(),
+            "127.0.0.1:3000"
+        );
+        assert_eq!(
+            socket_address("https://example.com/").unwrap(),
+            "example.com:443"
+        );
+        assert!(socket_address("localhost:5173").is_err());
+    }
+
+    #[test]
+    fn test_descriptor_props() {
+        #[derive(Serialize)]
+        struct Props {
+            label: &'static str,
+            count: u32,
+        }
+        let component = ComponentDescriptor::new("Counter")
+            .props(&Props {
+                label: "Clicks",
+                count: 2,
+            })
+            .unwrap()
+            .prop("step", 5);
+        assert_eq!(component.props["label"], "Clicks");
+        assert_eq!(component.props["count"], 2);
+        assert_eq!(component.props["step"], 5);
+        assert!(ComponentDescriptor::new("X").props(&42).is_err());
+    }
+
+    #[test]
+    fn test_index_url() {
+        let ct = ComponentTesting::with_url("http://localhost:3100/").index_path("ct.html");
+        assert_eq!(ct.index_url(), "http://localhost:3100/ct.html");
+    }
+
+    #[test]
+    fn test_wasm_dev_server_commands() {
+        let trunk = DevServerCommand::trunk("./app", 8081);
+        assert_eq!(trunk.program, "trunk");
+        assert_eq!(trunk.args, ["serve", "--port", "8081"]);
+        assert_eq!(trunk.url, "http://127.0.0.1:8081");
+
+        let leptos = DevServerCommand::cargo_leptos("./app", 3000);
+        assert_eq!(leptos.args, ["leptos", "serve"]);
+        assert_eq!(
+            leptos.env,
+            [("LEPTOS_SITE_ADDR".to_string(), "127.0.0.1:3000".to_string())]
+        );
+    }
+}
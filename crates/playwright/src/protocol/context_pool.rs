@@ -0,0 +1,304 @@
+// Context pool - Reusing browser contexts with automatic recycling
+//
+// Long-running automation services reuse contexts to avoid the cost of creating
+// one per job, but contexts slowly accumulate memory (caches, detached DOM,
+// service workers). ContextPool hands out contexts and transparently replaces
+// those that exceed a RecyclePolicy (pages served, age, JS heap usage).
+
+use crate::error::Result;
+use crate::protocol::{Browser, BrowserContext, BrowserContextOptions, Page};
+use parking_lot::Mutex;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Limits after which a pooled context is closed and replaced.
+///
+/// Limits are checked when a context is handed out, so a context is never
+/// recycled while in use. Unset limits are not enforced.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::RecyclePolicy;
+/// use std::time::Duration;
+///
+/// let policy = RecyclePolicy::new()
+///     .max_pages(200)
+///     .max_age(Duration::from_secs(30 * 60))
+///     .max_memory_bytes(512 * 1024 * 1024);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecyclePolicy {
+    /// Maximum number of pages opened through the context
+    pub max_pages: Option<usize>,
+    /// Maximum time since the context was created
+    pub max_age: Option<Duration>,
+    /// Maximum combined JS heap usage of the context's open pages (Chromium only)
+    pub max_memory_bytes: Option<u64>,
+}
+
+impl RecyclePolicy {
+    /// Creates a policy with no limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recycle after this many pages have been opened through the context
+    pub fn max_pages(mut self, pages: usize) -> Self {
+        self.max_pages = Some(pages);
+        self
+    }
+
+    /// Recycle once the context is older than `age`
+    pub fn max_age(mut self, age: Duration) -> Self {
+        self.max_age = Some(age);
+        self
+    }
+
+    /// Recycle once the context's pages use more than `bytes` of JS heap
+    ///
+    /// Measured via CDP, so only enforced for Chromium. Pages whose heap cannot
+    /// be read (other browsers, closed pages) count as zero.
+    pub fn max_memory_bytes(mut self, bytes: u64) -> Self {
+        self.max_memory_bytes = Some(bytes);
+        self
+    }
+
+    fn is_exhausted(&self, pages_served: usize, age: Duration) -> bool {
+        self.max_pages.is_some_and(|max| pages_served >= max)
+            || self.max_age.is_some_and(|max| age >= max)
+    }
+}
+
+struct PoolEntry {
+    context: BrowserContext,
+    created_at: Instant,
+    pages_served: usize,
+    pages: Vec<Page>,
+}
+
+impl PoolEntry {
+    async fn heap_usage(&self) -> u64 {
+        let mut total = 0;
+        for page in &self.pages {
+            if let Ok(usage) = page.heap_usage().await {
+                total += usage.used_size;
+            }
+        }
+        total
+    }
+}
+
+struct PoolInner {
+    browser: Browser,
+    options: Option<BrowserContextOptions>,
+    policy: RecyclePolicy,
+    idle: Mutex<Vec<PoolEntry>>,
+    recycled: Mutex<usize>,
+}
+
+/// A pool of browser contexts recycled according to a [`RecyclePolicy`].
+///
+/// [`acquire`](ContextPool::acquire) returns an idle context, creating one if
+/// none is available. Contexts go back to the pool when the returned
+/// [`PooledContext`] is dropped. Open pages through
+/// [`PooledContext::new_page`] so they count towards the policy.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::{ContextPool, RecyclePolicy};
+///
+/// let pool = ContextPool::new(browser.clone(), None, RecyclePolicy::new().max_pages(100));
+///
+/// loop {
+///     let context = pool.acquire().await?;
+///     let page = context.new_page().await?;
+///     page.goto("https://example.com", None).await?;
+///     page.close().await?;
+/// } // context returns to the pool here
+/// ```
+#[derive(Clone)]
+pub struct ContextPool {
+    inner: Arc<PoolInner>,
+}
+
+impl ContextPool {
+    /// Creates a pool of contexts on `browser`, created with `options`
+    pub fn new(
+        browser: Browser,
+        options: Option<BrowserContextOptions>,
+        policy: RecyclePolicy,
+    ) -> Self {
+        Self {
+            inner: Arc::new(PoolInner {
+                browser,
+                options,
+                policy,
+                idle: Mutex::new(Vec::new()),
+                recycled: Mutex::new(0),
+            }),
+        }
+    }
+
+    /// Returns an idle context, replacing any that exceed the policy
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a new context cannot be created.
+    pub async fn acquire(&self) -> Result<PooledContext> {
+        loop {
+            let entry = self.inner.idle.lock().pop();
+            let Some(entry) = entry else {
+                break;
+            };
+            if self.should_recycle(&entry).await {
+                self.recycle(entry).await;
+                continue;
+            }
+            return Ok(self.lease(entry));
+        }
+
+        let context = match &self.inner.options {
+            Some(options) => {
+                self.inner
+                    .browser
+                    .new_context_with_options(options.clone())
+                    .await?
+            }
+            None => self.inner.browser.new_context().await?,
+        };
+        Ok(self.lease(PoolEntry {
+            context,
+            created_at: Instant::now(),
+            pages_served: 0,
+            pages: Vec::new(),
+        }))
+    }
+
+    /// Number of contexts waiting in the pool
+    pub fn idle_count(&self) -> usize {
+        self.inner.idle.lock().len()
+    }
+
+    /// Number of contexts closed so far because they exceeded the policy
+    pub fn recycled_count(&self) -> usize {
+        *self.inner.recycled.lock()
+    }
+
+    /// Closes all idle contexts
+    ///
+    /// Contexts currently in use are returned to the (now empty) pool as usual.
+    pub async fn close(&self) -> Result<()> {
+        let idle = std::mem::take(&mut *self.inner.idle.lock());
+        for entry in idle {
+            entry.context.close().await?;
+        }
+        Ok(())
+    }
+
+    async fn should_recycle(&self, entry: &PoolEntry) -> bool {
+        let policy = &self.inner.policy;
+        if policy.is_exhausted(entry.pages_served, entry.created_at.elapsed()) {
+            return true;
+        }
+        match policy.max_memory_bytes {
+            Some(max) => entry.heap_usage().await > max,
+            None => false,
+        }
+    }
+
+    async fn recycle(&self, entry: PoolEntry) {
+        tracing::debug!(
+            "Recycling pooled context after {} page(s), {:?}",
+            entry.pages_served,
+            entry.created_at.elapsed()
+        );
+        *self.inner.recycled.lock() += 1;
+        if let Err(e) = entry.context.close().await {
+            tracing::debug!("Failed to close recycled context: {}", e);
+        }
+    }
+
+    fn lease(&self, entry: PoolEntry) -> PooledContext {
+        PooledContext {
+            entry: Some(entry),
+            pool: Arc::clone(&self.inner),
+        }
+    }
+}
+
+/// A context leased from a [`ContextPool`], returned to the pool on drop.
+///
+/// Dereferences to [`BrowserContext`].
+pub struct PooledContext {
+    entry: Option<PoolEntry>,
+    pool: Arc<PoolInner>,
+}
+
+impl PooledContext {
+    /// Creates a new page, counting it towards the pool's recycle policy
+    pub async fn new_page(&mut self) -> Result<Page> {
+        let entry = self.entry.as_mut().expect("entry present until drop");
+        let page = entry.context.new_page().await?;
+        entry.pages_served += 1;
+        if self.pool.policy.max_memory_bytes.is_some() {
+            entry.pages.push(page.clone());
+        }
+        Ok(page)
+    }
+
+    /// Number of pages opened through this context since it was created
+    pub fn pages_served(&self) -> usize {
+        self.entry.as_ref().map_or(0, |entry| entry.pages_served)
+    }
+
+    /// Time since this context was created
+    pub fn age(&self) -> Duration {
+        self.entry
+            .as_ref()
+            .map_or(Duration::ZERO, |entry| entry.created_at.elapsed())
+    }
+}
+
+impl Deref for PooledContext {
+    type Target = BrowserContext;
+
+    fn deref(&self) -> &BrowserContext {
+        &self
+            .entry
+            .as_ref()
+            .expect("entry present until drop")
+            .context
+    }
+}
+
+impl Drop for PooledContext {
+    fn drop(&mut self) {
+        if let Some(entry) = self.entry.take() {
+            self.pool.idle.lock().push(entry);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recycle_policy_limits() {
+        let policy = RecyclePolicy::new()
+            .max_pages(10)
+            .max_age(Duration::from_secs(60));
+
+        assert!(!policy.is_exhausted(9, Duration::from_secs(59)));
+        assert!(policy.is_exhausted(10, Duration::ZERO));
+        assert!(policy.is_exhausted(0, Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_default_policy_never_exhausts() {
+        assert!(!RecyclePolicy::new().is_exhausted(usize::MAX, Duration::MAX));
+    }
+}
@@ -0,0 +1,242 @@
+// Crash reports - What is known about a crashed driver, browser or page
+//
+// Collected automatically when the driver process exits while in use, when a
+// browser disconnects without close() being called, and when a page crashes.
+// Exit codes and signals come from the driver's exit status, or for browsers
+// from the `<process did exit: exitCode=..., signal=...>` line the driver logs
+// under `DEBUG=pw:browser` (see `PlaywrightConfig::capture_browser_output`). Minidumps are found on a
+// best-effort basis: `.dmp` paths mentioned in the log and Chromium's Crashpad
+// database inside the browser profile, if it still exists.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Number of trailing driver log lines kept in a report
+pub const CRASH_LOG_TAIL_LINES: usize = 30;
+
+/// What crashed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CrashKind {
+    /// The Playwright driver process exited while in use
+    Driver,
+    /// A browser process exited or disconnected without `close()`
+    Browser,
+    /// A page's renderer process crashed
+    Page,
+}
+
+impl fmt::Display for CrashKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CrashKind::Driver => "driver",
+            CrashKind::Browser => "browser",
+            CrashKind::Page => "page",
+        })
+    }
+}
+
+/// Diagnostics collected for a crash.
+///
+/// Returned by [`Playwright::crash_reports`](crate::protocol::Playwright::crash_reports)
+/// and [`Browser::crash_report`](crate::protocol::Browser::crash_report), and
+/// carried by [`Error::Crashed`](crate::Error::Crashed) for calls cut short by
+/// a driver crash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrashReport {
+    /// What crashed
+    pub kind: CrashKind,
+    /// Which browser or page crashed, e.g. `"chromium"` or the page URL;
+    /// empty for the driver
+    pub target: String,
+    /// Process exit code, if it exited normally
+    pub exit_code: Option<i32>,
+    /// Signal that terminated the process, e.g. `"SIGSEGV"`
+    pub signal: Option<String>,
+    /// Last driver log lines (driver stderr and browser output)
+    pub stderr_tail: Vec<String>,
+    /// Crash dumps found for the crash (Chromium only)
+    pub minidumps: Vec<PathBuf>,
+}
+
+impl CrashReport {
+    /// Builds a report from the driver log, taking the exit code and signal
+    /// from the last process exit line in it
+    ///
+    /// Page crashes leave the browser process running, so they get neither.
+    pub(crate) fn from_logs(kind: CrashKind, target: impl Into<String>, logs: &[String]) -> Self {
+        let (exit_code, signal) = logs
+            .iter()
+            .rev()
+            .filter(|_| kind != CrashKind::Page)
+            .find_map(|line| parse_process_exit(line))
+            .unwrap_or((None, None));
+        let skip = logs.len().saturating_sub(CRASH_LOG_TAIL_LINES);
+        Self {
+            kind,
+            target: target.into(),
+            exit_code,
+            signal,
+            stderr_tail: logs[skip..].to_vec(),
+            minidumps: find_minidumps(logs),
+        }
+    }
+
+    /// Builds a report for a driver process that exited with `status`
+    pub(crate) fn driver_exit(status: Option<std::process::ExitStatus>, logs: &[String]) -> Self {
+        let mut report = Self::from_logs(CrashKind::Driver, "", logs);
+        report.exit_code = status.and_then(|status| status.code());
+        report.signal = status.and_then(exit_signal);
+        report
+    }
+}
+
+impl fmt::Display for CrashReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.target.is_empty() {
+            write!(f, "{} crashed", self.kind)?;
+        } else {
+            write!(f, "{} {} crashed", self.kind, self.target)?;
+        }
+        match (&self.exit_code, &self.signal) {
+            (_, Some(signal)) => write!(f, " (signal {})", signal)?,
+            (Some(code), None) => write!(f, " (exit code {})", code)?,
+            (None, None) => {}
+        }
+        for dump in &self.minidumps {
+            write!(f, "\nMinidump: {}", dump.display())?;
+        }
+        if !self.stderr_tail.is_empty() {
+            write!(
+                f,
+                "\n\nDriver log (last {} lines):\n{}",
+                self.stderr_tail.len(),
+                self.stderr_tail.join("\n")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses `<process did exit: exitCode=null, signal=SIGSEGV>`
+fn parse_process_exit(line: &str) -> Option<(Option<i32>, Option<String>)> {
+    let details = line.split("<process did exit: ").nth(1)?;
+    let details = details.split('>').next()?;
+    let mut exit_code = None;
+    let mut signal = None;
+    for field in details.split(", ") {
+        match field.split_once('=') {
+            Some(("exitCode", code)) => exit_code = code.parse().ok(),
+            Some(("signal", name)) if name != "null" => signal = Some(name.to_string()),
+            _ => {}
+        }
+    }
+    Some((exit_code, signal))
+}
+
+/// Minidumps mentioned in the log or left in a Crashpad database of a profile
+/// named in the log
+fn find_minidumps(logs: &[String]) -> Vec<PathBuf> {
+    let mut dumps: Vec<PathBuf> = Vec::new();
+    for line in logs {
+        for token in line.split_whitespace() {
+            let token = token.trim_matches(|c| matches!(c, '"' | '\'' | ',' | '(' | ')'));
+            if token.ends_with(".dmp") {
+                dumps.push(PathBuf::from(token));
+            }
+            if let Some(profile) = token.strip_prefix("--user-data-dir=") {
+                dumps.extend(crashpad_dumps(Path::new(profile)));
+            }
+        }
+    }
+    dumps.sort();
+    dumps.dedup();
+    dumps
+}
+
+fn crashpad_dumps(profile: &Path) -> Vec<PathBuf> {
+    ["pending", "completed"]
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(profile.join("Crashpad").join(dir)).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "dmp"))
+        .collect()
+}
+
+#[cfg(unix)]
+fn exit_signal(status: std::process::ExitStatus) -> Option<String> {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal().map(|signal| {
+        match signal {
+            6 => "SIGABRT",
+            9 => "SIGKILL",
+            11 => "SIGSEGV",
+            15 => "SIGTERM",
+            _ => return format!("signal {}", signal),
+        }
+        .to_string()
+    })
+}
+
+#[cfg(not(unix))]
+fn exit_signal(_status: std::process::ExitStatus) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_report_from_browser_logs() {
+        let logs = lines(&[
+            "pw:browser <launching> /ms-playwright/chrome --headless --user-data-dir=/nonexistent/profile",
+            "pw:browser [pid=42][err] Received signal 11 SEGV_MAPERR",
+            "pw:browser [pid=42][err] Crash dump written to /tmp/dumps/3f2a.dmp",
+            "pw:browser [pid=42] <process did exit: exitCode=null, signal=SIGSEGV>",
+        ]);
+        let report = CrashReport::from_logs(CrashKind::Browser, "chromium", &logs);
+
+        assert_eq!(report.exit_code, None);
+        assert_eq!(report.signal.as_deref(), Some("SIGSEGV"));
+        assert_eq!(report.minidumps, vec![PathBuf::from("/tmp/dumps/3f2a.dmp")]);
+        assert_eq!(report.stderr_tail.len(), 4);
+
+        let text = report.to_string();
+        assert!(text.starts_with("browser chromium crashed (signal SIGSEGV)"));
+        assert!(text.contains("Minidump: /tmp/dumps/3f2a.dmp"));
+        assert!(text.contains("SEGV_MAPERR"));
+    }
+
+    #[test]
+    fn test_parse_process_exit() {
+        assert_eq!(
+            parse_process_exit("[pid=7] <process did exit: exitCode=1, signal=null>"),
+            Some((Some(1), None))
+        );
+        assert_eq!(parse_process_exit("[pid=7][out] ready"), None);
+
+        let logs = lines(&["[pid=7] <process did exit: exitCode=134, signal=null>"]);
+        let report = CrashReport::from_logs(CrashKind::Browser, "firefox", &logs);
+        assert_eq!(
+            report.to_string().lines().next(),
+            Some("browser firefox crashed (exit code 134)")
+        );
+    }
+
+    #[test]
+    fn test_log_tail_is_bounded() {
+        let logs: Vec<String> = (0..100).map(|i| format!("line {}", i)).collect();
+        let report = CrashReport::driver_exit(None, &logs);
+        assert_eq!(report.stderr_tail.len(), CRASH_LOG_TAIL_LINES);
+        assert_eq!(
+            report.stderr_tail.last().map(String::as_str),
+            Some("line 99")
+        );
+        assert!(report.to_string().starts_with("driver crashed\n"));
+    }
+}
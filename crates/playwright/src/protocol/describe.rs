@@ -0,0 +1,272 @@
+// Locator descriptions - Explaining what a locator matches
+//
+// Locator::describe() answers "why did my locator not match": it counts
+// matches for every step of the selector chain (so the step where matching
+// drops to zero stands out) and previews the elements the full selector matches.
+
+use crate::error::Result;
+use crate::protocol::Frame;
+use serde::Deserialize;
+use std::fmt;
+
+/// Maximum number of matched elements previewed
+const MAX_PREVIEWS: usize = 10;
+
+/// Maximum length of an element's text preview, in characters
+const MAX_TEXT_PREVIEW: usize = 80;
+
+/// Builds previews for matched elements; receives the element array from evalOnSelectorAll
+const PREVIEW_SCRIPT: &str = r#"(elements, { max, maxText }) => elements.slice(0, max).map((el) => {
+  const style = getComputedStyle(el);
+  const visible = style.visibility !== 'hidden' && style.display !== 'none' &&
+    !!(el.offsetWidth || el.offsetHeight || el.getClientRects().length);
+  let text = (el.innerText || el.textContent || '').replace(/\s+/g, ' ').trim();
+  if (text.length > maxText) text = text.slice(0, maxText) + '…';
+  return {
+    tag: el.tagName.toLowerCase(),
+    id: el.id || null,
+    classes: Array.from(el.classList || []),
+    text,
+    visible,
+  };
+})"#;
+
+/// What a locator matched, from [`Locator::describe`](crate::protocol::Locator::describe).
+///
+/// The `Display` implementation prints a readable report:
+///
+/// ```text
+/// locator "#checkout >> button.primary" matched 0 elements
+///   #checkout           1
+///   button.primary      0   <- no matches from here
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocatorDescription {
+    /// The full selector
+    pub selector: String,
+    /// Number of elements matched by the full selector
+    pub count: usize,
+    /// Match counts for each step of the selector chain
+    pub steps: Vec<SelectorStep>,
+    /// Previews of the first matched elements (at most 10)
+    pub elements: Vec<ElementPreview>,
+}
+
+impl LocatorDescription {
+    /// The first step of the chain that matched nothing, if any
+    pub fn first_failing_step(&self) -> Option<&SelectorStep> {
+        self.steps.iter().find(|step| step.count == 0)
+    }
+}
+
+/// One step of a `>>`-separated selector chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorStep {
+    /// This step's selector part (e.g. `button.primary`)
+    pub part: String,
+    /// The chain up to and including this step
+    pub selector: String,
+    /// Number of elements the chain up to this step matches
+    pub count: usize,
+}
+
+/// Short summary of a matched element
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ElementPreview {
+    /// Lowercase tag name
+    pub tag: String,
+    /// `id` attribute, if set
+    pub id: Option<String>,
+    /// CSS classes
+    pub classes: Vec<String>,
+    /// Whitespace-collapsed text, truncated to 80 characters
+    pub text: String,
+    /// Whether the element is visible
+    pub visible: bool,
+}
+
+impl fmt::Display for ElementPreview {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}", self.tag)?;
+        if let Some(id) = &self.id {
+            write!(f, " id=\"{}\"", id)?;
+        }
+        if !self.classes.is_empty() {
+            write!(f, " class=\"{}\"", self.classes.join(" "))?;
+        }
+        write!(f, "> {:?}", self.text)?;
+        if !self.visible {
+            write!(f, " (hidden)")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for LocatorDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "locator {:?} matched {} element{}",
+            self.selector,
+            self.count,
+            if self.count == 1 { "" } else { "s" }
+        )?;
+        if self.steps.len() > 1 {
+            let width = self.steps.iter().map(|s| s.part.len()).max().unwrap_or(0);
+            let failing = self.first_failing_step().map(|s| s.selector.as_str());
+            for step in &self.steps {
+                write!(f, "  {:width$}  {}", step.part, step.count, width = width)?;
+                if failing == Some(step.selector.as_str()) {
+                    write!(f, "   <- no matches from here")?;
+                }
+                writeln!(f)?;
+            }
+        }
+        for element in &self.elements {
+            writeln!(f, "  {}", element)?;
+        }
+        if self.count > self.elements.len() {
+            writeln!(f, "  … and {} more", self.count - self.elements.len())?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits a selector on top-level `>>` separators, ignoring any inside quotes
+pub(crate) fn split_chain(selector: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut chars = selector.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(q) => {
+                if c == '\\' {
+                    current.push(c);
+                    if let Some(next) = chars.next() {
+                        current.push(next);
+                    }
+                    continue;
+                }
+                if c == q {
+                    quote = None;
+                }
+                current.push(c);
+            }
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                current.push(c);
+            }
+            None if c == '>' && chars.peek() == Some(&'>') => {
+                chars.next();
+                parts.push(current.trim().to_string());
+                current.clear();
+            }
+            None => current.push(c),
+        }
+    }
+    parts.push(current.trim().to_string());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+pub(crate) async fn describe(frame: &Frame, selector: &str) -> Result<LocatorDescription> {
+    let parts = split_chain(selector);
+    let mut steps = Vec::with_capacity(parts.len());
+    let mut chain = String::new();
+    for part in parts {
+        if !chain.is_empty() {
+            chain.push_str(" >> ");
+        }
+        chain.push_str(&part);
+        let count = frame.locator_count(&chain).await?;
+        steps.push(SelectorStep {
+            part,
+            selector: chain.clone(),
+            count,
+        });
+    }
+
+    let count = match steps.last() {
+        Some(step) => step.count,
+        None => frame.locator_count(selector).await?,
+    };
+    let elements = if count == 0 {
+        Vec::new()
+    } else {
+        let value = frame
+            .eval_on_selector_all(
+                selector,
+                PREVIEW_SCRIPT,
+                &serde_json::json!({ "max": MAX_PREVIEWS, "maxText": MAX_TEXT_PREVIEW }),
+            )
+            .await?;
+        serde_json::from_value(value)?
+    };
+
+    Ok(LocatorDescription {
+        selector: selector.to_string(),
+        count,
+        steps,
+        elements,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_chain() {
+        assert_eq!(split_chain("#form"), vec!["#form"]);
+        assert_eq!(
+            split_chain("#form >> button >> nth=0"),
+            vec!["#form", "button", "nth=0"]
+        );
+        assert_eq!(
+            split_chain(r#"text="a >> b" >> span"#),
+            vec![r#"text="a >> b""#, "span"]
+        );
+    }
+
+    #[test]
+    fn test_display_marks_failing_step() {
+        let description = LocatorDescription {
+            selector: "#form >> button".to_string(),
+            count: 0,
+            steps: vec![
+                SelectorStep {
+                    part: "#form".to_string(),
+                    selector: "#form".to_string(),
+                    count: 1,
+                },
+                SelectorStep {
+                    part: "button".to_string(),
+                    selector: "#form >> button".to_string(),
+                    count: 0,
+                },
+            ],
+            elements: Vec::new(),
+        };
+        let report = description.to_string();
+        assert!(report.starts_with("locator \"#form >> button\" matched 0 elements"));
+        assert!(report.contains("button  0   <- no matches from here"));
+        assert_eq!(description.first_failing_step().unwrap().part, "button");
+    }
+
+    #[test]
+    fn test_element_preview_display() {
+        let preview = ElementPreview {
+            tag: "button".to_string(),
+            id: Some("buy".to_string()),
+            classes: vec!["btn".to_string(), "primary".to_string()],
+            text: "Buy now".to_string(),
+            visible: false,
+        };
+        assert_eq!(
+            preview.to_string(),
+            r#"<button id="buy" class="btn primary"> "Buy now" (hidden)"#
+        );
+    }
+}
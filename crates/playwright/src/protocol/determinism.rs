@@ -0,0 +1,173 @@
+// Determinism - Reproducible pages for visual and logic tests
+//
+// Bundles the usual sources of flakiness into one helper: wall-clock time
+// (via Clock), Math.random (replaced by a seeded PRNG in an init script), and
+// CSS animations/transitions (disabled by an injected stylesheet).
+
+use crate::error::Result;
+use crate::protocol::BrowserContext;
+
+/// Seeded PRNG (mulberry32) replacing Math.random; `__SEED__` is substituted
+const SEEDED_RANDOM_SCRIPT: &str = r#"(() => {
+  let state = __SEED__ >>> 0;
+  Math.random = function () {
+    state = (state + 0x6D2B79F5) >>> 0;
+    let t = state;
+    t = Math.imul(t ^ (t >>> 15), t | 1);
+    t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+  };
+})();"#;
+
+/// Stylesheet disabling animations, transitions and the blinking caret
+const DISABLE_ANIMATIONS_SCRIPT: &str = r#"(() => {
+  const css = '*, *::before, *::after { animation-duration: 0s !important; animation-delay: 0s !important; animation-iteration-count: 1 !important; transition-duration: 0s !important; transition-delay: 0s !important; caret-color: transparent !important; scroll-behavior: auto !important; }';
+  const inject = () => {
+    const style = document.createElement('style');
+    style.setAttribute('data-playwright-determinism', '');
+    style.textContent = css;
+    (document.head || document.documentElement).appendChild(style);
+  };
+  if (document.documentElement) inject();
+  else document.addEventListener('DOMContentLoaded', inject, { once: true });
+})();"#;
+
+/// Preset that makes pages in a context reproducible.
+///
+/// [`apply`](Determinism::apply) must run before pages are created (init scripts
+/// and the clock only affect documents loaded afterwards).
+///
+/// By default the clock is fixed at 2024-01-01T00:00:00Z with timers running
+/// normally, `Math.random` is seeded with `42`, and animations are disabled.
+/// Enable [`fake_timers`](Determinism::fake_timers) to also control
+/// `setTimeout`/`setInterval` through [`BrowserContext::clock`].
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::Determinism;
+///
+/// let context = browser.new_context().await?;
+/// Determinism::new().seed(7).apply(&context).await?;
+/// let page = context.new_page().await?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Determinism {
+    /// Fixed wall-clock time in milliseconds since the Unix epoch, `None` to leave time alone
+    pub time: Option<f64>,
+    /// Install fake timers instead of only fixing `Date`
+    pub fake_timers: bool,
+    /// Seed for `Math.random`, `None` to leave it alone
+    pub seed: Option<u32>,
+    /// Disable CSS animations and transitions
+    pub disable_animations: bool,
+}
+
+impl Default for Determinism {
+    fn default() -> Self {
+        Self {
+            time: Some(Self::DEFAULT_TIME),
+            fake_timers: false,
+            seed: Some(42),
+            disable_animations: true,
+        }
+    }
+}
+
+impl Determinism {
+    /// Default fixed time: 2024-01-01T00:00:00Z
+    pub const DEFAULT_TIME: f64 = 1_704_067_200_000.0;
+
+    /// Creates the default preset
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the fixed time (ms since epoch)
+    pub fn time(mut self, time: f64) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Leaves the browser clock untouched
+    pub fn real_time(mut self) -> Self {
+        self.time = None;
+        self
+    }
+
+    /// Installs fake timers paused at the fixed time
+    ///
+    /// Timers then only advance through `context.clock().run_for(...)` and friends.
+    pub fn fake_timers(mut self, enabled: bool) -> Self {
+        self.fake_timers = enabled;
+        self
+    }
+
+    /// Sets the `Math.random` seed
+    pub fn seed(mut self, seed: u32) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Leaves `Math.random` untouched
+    pub fn unseeded(mut self) -> Self {
+        self.seed = None;
+        self
+    }
+
+    /// Enables or disables animation suppression
+    pub fn disable_animations(mut self, disable: bool) -> Self {
+        self.disable_animations = disable;
+        self
+    }
+
+    /// Applies the preset to every page subsequently created in `context`
+    pub async fn apply(&self, context: &BrowserContext) -> Result<()> {
+        if let Some(time) = self.time {
+            let clock = context.clock();
+            if self.fake_timers {
+                clock.install(Some(time)).await?;
+                clock.pause_at(time).await?;
+            } else {
+                clock.set_fixed_time(time).await?;
+            }
+        }
+        if let Some(seed) = self.seed {
+            context.add_init_script(seeded_random_script(seed)).await?;
+        }
+        if self.disable_animations {
+            context.add_init_script(DISABLE_ANIMATIONS_SCRIPT).await?;
+        }
+        Ok(())
+    }
+}
+
+fn seeded_random_script(seed: u32) -> String {
+    SEEDED_RANDOM_SCRIPT.replace("__SEED__", &seed.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let preset = Determinism::new();
+        assert_eq!(preset.time, Some(Determinism::DEFAULT_TIME));
+        assert_eq!(preset.seed, Some(42));
+        assert!(preset.disable_animations);
+        assert!(!preset.fake_timers);
+
+        let preset = preset.real_time().unseeded().disable_animations(false);
+        assert_eq!(preset.time, None);
+        assert_eq!(preset.seed, None);
+        assert!(!preset.disable_animations);
+    }
+
+    #[test]
+    fn test_seeded_random_script() {
+        let script = seeded_random_script(1234);
+        assert!(script.contains("let state = 1234 >>> 0;"));
+        assert!(!script.contains("__SEED__"));
+    }
+}
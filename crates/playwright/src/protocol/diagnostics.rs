@@ -0,0 +1,309 @@
+// Diagnostics - Screenshots and DOM snapshots attached to failed actions
+//
+// When a DiagnosticsPolicy is set on a BrowserContext, locator actions that fail
+// with a timeout or strict mode violation capture a screenshot and/or the frame's
+// DOM into the policy's output directory. The original error is wrapped in
+// Error::WithDiagnostics, which carries the paths of the captured files.
+
+use crate::error::{Error, Result};
+use crate::protocol::{BrowserContext, Frame, ScreenshotOptions};
+use crate::server::channel_owner::ChannelOwner;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Upper bound on the time spent capturing each artifact, so a hung page
+/// doesn't delay reporting the original error
+const CAPTURE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Maximum length of the selector-derived part of artifact file names
+const MAX_SLUG_LEN: usize = 40;
+
+/// Distinguishes artifacts captured within the same millisecond
+static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// Controls which diagnostics are captured when a locator action fails.
+///
+/// Set per context with [`BrowserContext::set_diagnostics_policy`]. Failed
+/// actions then return [`Error::WithDiagnostics`], whose
+/// [`diagnostics()`](Error::diagnostics) holds the captured file paths.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::DiagnosticsPolicy;
+///
+/// context.set_diagnostics_policy(Some(DiagnosticsPolicy::new("test-results/failures")));
+///
+/// if let Err(err) = page.locator("#missing").click(None).await {
+///     if let Some(diagnostics) = err.diagnostics() {
+///         println!("screenshot: {:?}", diagnostics.screenshot);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticsPolicy {
+    /// Directory artifacts are written to (created if missing)
+    pub output_dir: PathBuf,
+    /// Capture a PNG screenshot of the frame (default: true)
+    pub screenshot: bool,
+    /// Capture the frame's HTML (default: true)
+    pub dom_snapshot: bool,
+    /// Capture on timeouts (default: true)
+    pub on_timeout: bool,
+    /// Capture on strict mode violations (default: true)
+    pub on_strict_violation: bool,
+}
+
+impl DiagnosticsPolicy {
+    /// Creates a policy capturing screenshots and DOM snapshots into `output_dir`
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+            screenshot: true,
+            dom_snapshot: true,
+            on_timeout: true,
+            on_strict_violation: true,
+        }
+    }
+
+    /// Sets whether to capture a screenshot
+    pub fn screenshot(mut self, enabled: bool) -> Self {
+        self.screenshot = enabled;
+        self
+    }
+
+    /// Sets whether to capture a DOM snapshot
+    pub fn dom_snapshot(mut self, enabled: bool) -> Self {
+        self.dom_snapshot = enabled;
+        self
+    }
+
+    /// Sets whether timeouts trigger a capture
+    pub fn on_timeout(mut self, enabled: bool) -> Self {
+        self.on_timeout = enabled;
+        self
+    }
+
+    /// Sets whether strict mode violations trigger a capture
+    pub fn on_strict_violation(mut self, enabled: bool) -> Self {
+        self.on_strict_violation = enabled;
+        self
+    }
+
+    /// Whether `error` should trigger a capture under this policy
+    pub(crate) fn applies_to(&self, error: &Error) -> bool {
+        if !self.screenshot && !self.dom_snapshot {
+            return false;
+        }
+        match error {
+            Error::Timeout(_) => self.on_timeout,
+            Error::StrictModeViolation { .. } => self.on_strict_violation,
+            _ => false,
+        }
+    }
+}
+
+/// Artifacts captured for a failed action.
+///
+/// A path is `None` when that artifact was disabled by the policy or could
+/// not be captured (for example because the page had crashed).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Diagnostics {
+    /// PNG screenshot of the frame
+    pub screenshot: Option<PathBuf>,
+    /// HTML of the frame's document
+    pub dom_snapshot: Option<PathBuf>,
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(path) = &self.screenshot {
+            parts.push(format!("screenshot: {}", path.display()));
+        }
+        if let Some(path) = &self.dom_snapshot {
+            parts.push(format!("DOM snapshot: {}", path.display()));
+        }
+        if parts.is_empty() {
+            write!(f, "no diagnostics captured")
+        } else {
+            write!(f, "{}", parts.join(", "))
+        }
+    }
+}
+
+/// Wraps `error` with diagnostics if the frame's context has a matching policy
+pub(crate) async fn attach(frame: &Frame, selector: &str, error: Error) -> Error {
+    let Some(policy) = owning_context(frame).and_then(|c| c.diagnostics_policy()) else {
+        return error;
+    };
+    if !policy.applies_to(&error) {
+        return error;
+    }
+
+    let diagnostics = capture(frame, selector, &policy).await;
+    Error::WithDiagnostics {
+        error: Box::new(error),
+        diagnostics,
+    }
+}
+
+/// Finds the BrowserContext a frame belongs to by walking its parents
+pub(crate) fn owning_context(frame: &Frame) -> Option<BrowserContext> {
+    let mut current = frame.parent();
+    while let Some(owner) = current {
+        if let Some(context) = owner.as_any().downcast_ref::<BrowserContext>() {
+            return Some(context.clone());
+        }
+        current = owner.parent();
+    }
+    None
+}
+
+async fn capture(frame: &Frame, selector: &str, policy: &DiagnosticsPolicy) -> Diagnostics {
+    let mut diagnostics = Diagnostics::default();
+    if let Err(e) = tokio::fs::create_dir_all(&policy.output_dir).await {
+        tracing::warn!(
+            "Failed to create diagnostics directory {}: {}",
+            policy.output_dir.display(),
+            e
+        );
+        return diagnostics;
+    }
+
+    let stem = artifact_stem(selector);
+    if policy.screenshot {
+        let path = policy.output_dir.join(format!("{}.png", stem));
+        diagnostics.screenshot = save(&path, capture_screenshot(frame)).await;
+    }
+    if policy.dom_snapshot {
+        let path = policy.output_dir.join(format!("{}.html", stem));
+        diagnostics.dom_snapshot = save(&path, capture_dom(frame)).await;
+    }
+    diagnostics
+}
+
+async fn save(
+    path: &Path,
+    content: impl std::future::Future<Output = Result<Vec<u8>>>,
+) -> Option<PathBuf> {
+    let result = match tokio::time::timeout(CAPTURE_TIMEOUT, content).await {
+        Ok(Ok(bytes)) => tokio::fs::write(path, bytes).await.map_err(Error::from),
+        Ok(Err(e)) => Err(e),
+        Err(_) => Err(Error::Timeout(format!(
+            "Capturing diagnostics exceeded {:?}",
+            CAPTURE_TIMEOUT
+        ))),
+    };
+    match result {
+        Ok(()) => Some(path.to_path_buf()),
+        Err(e) => {
+            tracing::warn!("Failed to capture {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+async fn capture_screenshot(frame: &Frame) -> Result<Vec<u8>> {
+    let root = frame
+        .query_selector(":root")
+        .await?
+        .ok_or_else(|| Error::ElementNotFound(":root".to_string()))?;
+    let options = ScreenshotOptions::builder()
+        .timeout(CAPTURE_TIMEOUT)
+        .build();
+    root.screenshot(Some(options)).await
+}
+
+async fn capture_dom(frame: &Frame) -> Result<Vec<u8>> {
+    let html = frame
+        .evaluate::<()>("document.documentElement.outerHTML", None)
+        .await?;
+    Ok(html.as_str().unwrap_or_default().as_bytes().to_vec())
+}
+
+/// File name stem: `<unix millis>-<sequence>-<selector slug>`
+fn artifact_stem(selector: &str) -> String {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    let sequence = SEQUENCE.fetch_add(1, Ordering::Relaxed);
+    format!("{}-{}-{}", millis, sequence, slug(selector))
+}
+
+fn slug(selector: &str) -> String {
+    let mut slug = String::new();
+    for c in selector.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.len() >= MAX_SLUG_LEN {
+            break;
+        }
+    }
+    let slug = slug.trim_matches('-');
+    if slug.is_empty() {
+        "action".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_applies_to() {
+        let policy = DiagnosticsPolicy::new("out");
+        assert!(policy.applies_to(&Error::Timeout("30000ms exceeded".into())));
+        assert!(policy.applies_to(&Error::StrictModeViolation {
+            selector: "locator('p')".into(),
+            count: 4,
+            candidates: Vec::new(),
+        }));
+        assert!(!policy.applies_to(&Error::ProtocolError("other".into())));
+        assert!(!policy.applies_to(&Error::ChannelClosed));
+
+        let policy = DiagnosticsPolicy::new("out").on_timeout(false);
+        assert!(!policy.applies_to(&Error::Timeout("30000ms exceeded".into())));
+
+        let policy = DiagnosticsPolicy::new("out")
+            .screenshot(false)
+            .dom_snapshot(false);
+        assert!(!policy.applies_to(&Error::Timeout("30000ms exceeded".into())));
+    }
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(
+            slug("#checkout >> button.primary"),
+            "checkout-button-primary"
+        );
+        assert_eq!(slug(">>"), "action");
+        assert!(slug(&"a".repeat(100)).len() <= MAX_SLUG_LEN);
+    }
+
+    #[test]
+    fn test_error_with_diagnostics() {
+        let err = Error::WithDiagnostics {
+            error: Box::new(Error::Timeout("click timed out".into())),
+            diagnostics: Diagnostics {
+                screenshot: Some(PathBuf::from("out/1.png")),
+                dom_snapshot: None,
+            },
+        };
+        assert_eq!(
+            err.to_string(),
+            "Timeout: click timed out (screenshot: out/1.png)"
+        );
+        assert!(matches!(err.inner(), Error::Timeout(_)));
+        assert!(err.diagnostics().is_some());
+        assert!(Error::ChannelClosed.diagnostics().is_none());
+    }
+}
@@ -0,0 +1,255 @@
+// DOM snapshots and diffs - Detecting what an action changed
+//
+// A DomSnapshot is a normalized, flattened view of a frame's DOM: one entry per
+// element, keyed by a stable path (`body > div#main > ul > li[2]`), holding its
+// attributes and own text. Script/style content is skipped, whitespace is
+// collapsed, and form state (value, checked) is recorded as pseudo-attributes.
+//
+// Diffing two snapshots reports added, removed and changed elements, which makes
+// "this click changed nothing" easy to detect in tests and monitoring bots.
+
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Maximum number of elements captured in a snapshot
+pub(crate) const MAX_NODES: usize = 10_000;
+
+/// JavaScript that flattens the document into `[{ path, attributes, text }]`
+pub(crate) const SNAPSHOT_SCRIPT: &str = r#"(maxNodes) => {
+  const SKIP = new Set(['SCRIPT', 'STYLE', 'NOSCRIPT', 'TEMPLATE']);
+  const nodes = [];
+  const collapse = (s) => s.replace(/\s+/g, ' ').trim();
+  const segment = (el) => {
+    let s = el.tagName.toLowerCase();
+    if (el.id) return s + '#' + el.id;
+    const parent = el.parentElement;
+    if (parent) {
+      const same = Array.from(parent.children).filter((c) => c.tagName === el.tagName);
+      if (same.length > 1) s += '[' + (same.indexOf(el) + 1) + ']';
+    }
+    return s;
+  };
+  const walk = (el, path) => {
+    if (nodes.length >= maxNodes || SKIP.has(el.tagName)) return;
+    const attributes = {};
+    for (const attr of Array.from(el.attributes)) attributes[attr.name] = attr.value;
+    if ('value' in el && typeof el.value === 'string' && el.tagName !== 'BUTTON' && el.tagName !== 'OPTION') {
+      attributes[':value'] = el.value;
+    }
+    if (el.type === 'checkbox' || el.type === 'radio') attributes[':checked'] = String(el.checked);
+    const text = collapse(Array.from(el.childNodes)
+      .filter((n) => n.nodeType === Node.TEXT_NODE)
+      .map((n) => n.textContent)
+      .join(' '));
+    nodes.push({ path, attributes, text });
+    for (const child of Array.from(el.children)) walk(child, path + ' > ' + segment(child));
+  };
+  if (document.documentElement) walk(document.documentElement, 'html');
+  return nodes;
+}"#;
+
+/// One element of a [`DomSnapshot`]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DomNode {
+    /// Stable path from the root, e.g. `html > body > ul#list > li[2]`
+    pub path: String,
+    /// Attributes, plus `:value` / `:checked` for form controls
+    pub attributes: BTreeMap<String, String>,
+    /// Whitespace-collapsed text of the element's own text nodes
+    pub text: String,
+}
+
+/// Normalized snapshot of a frame's DOM.
+///
+/// Capture with [`Page::snapshot_dom`](crate::protocol::Page::snapshot_dom) and
+/// compare with [`DomSnapshot::diff`], or use
+/// [`Page::diff_dom`](crate::protocol::Page::diff_dom) to wrap an action.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DomSnapshot {
+    /// Elements in document order
+    pub nodes: Vec<DomNode>,
+}
+
+impl DomSnapshot {
+    pub(crate) fn from_nodes(nodes: Vec<DomNode>) -> Self {
+        Self { nodes }
+    }
+
+    /// Looks up an element by path
+    pub fn get(&self, path: &str) -> Option<&DomNode> {
+        self.nodes.iter().find(|node| node.path == path)
+    }
+
+    /// Structural differences from `self` (before) to `after`
+    pub fn diff(&self, after: &DomSnapshot) -> DomDiff {
+        let before_by_path: HashMap<&str, &DomNode> =
+            self.nodes.iter().map(|n| (n.path.as_str(), n)).collect();
+        let after_by_path: HashMap<&str, &DomNode> =
+            after.nodes.iter().map(|n| (n.path.as_str(), n)).collect();
+
+        let mut diff = DomDiff::default();
+        for node in &self.nodes {
+            if !after_by_path.contains_key(node.path.as_str()) {
+                diff.removed.push(node.path.clone());
+            }
+        }
+        for node in &after.nodes {
+            match before_by_path.get(node.path.as_str()) {
+                None => diff.added.push(node.path.clone()),
+                Some(before) if *before != node => diff.changed.push(NodeChange::new(before, node)),
+                Some(_) => {}
+            }
+        }
+        diff
+    }
+}
+
+/// Differences between two [`DomSnapshot`]s
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DomDiff {
+    /// Paths of elements present only after
+    pub added: Vec<String>,
+    /// Paths of elements present only before
+    pub removed: Vec<String>,
+    /// Elements whose attributes or text changed
+    pub changed: Vec<NodeChange>,
+}
+
+impl DomDiff {
+    /// True if nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Total number of added, removed and changed elements
+    pub fn len(&self) -> usize {
+        self.added.len() + self.removed.len() + self.changed.len()
+    }
+}
+
+impl fmt::Display for DomDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "no DOM changes");
+        }
+        for path in &self.removed {
+            writeln!(f, "- {}", path)?;
+        }
+        for path in &self.added {
+            writeln!(f, "+ {}", path)?;
+        }
+        for change in &self.changed {
+            write!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// A changed element in a [`DomDiff`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeChange {
+    /// Path of the element
+    pub path: String,
+    /// Changed attributes: name -> (before, after); `None` means absent
+    pub attributes: BTreeMap<String, (Option<String>, Option<String>)>,
+    /// Text before and after, if it changed
+    pub text: Option<(String, String)>,
+}
+
+impl NodeChange {
+    fn new(before: &DomNode, after: &DomNode) -> Self {
+        let mut attributes = BTreeMap::new();
+        for (name, value) in &before.attributes {
+            let new_value = after.attributes.get(name);
+            if new_value != Some(value) {
+                attributes.insert(name.clone(), (Some(value.clone()), new_value.cloned()));
+            }
+        }
+        for (name, value) in &after.attributes {
+            if !before.attributes.contains_key(name) {
+                attributes.insert(name.clone(), (None, Some(value.clone())));
+            }
+        }
+        let text = (before.text != after.text).then(|| (before.text.clone(), after.text.clone()));
+        Self {
+            path: after.path.clone(),
+            attributes,
+            text,
+        }
+    }
+}
+
+impl fmt::Display for NodeChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "~ {}", self.path)?;
+        if let Some((before, after)) = &self.text {
+            writeln!(f, "    text: {:?} -> {:?}", before, after)?;
+        }
+        for (name, (before, after)) in &self.attributes {
+            writeln!(f, "    {}: {:?} -> {:?}", name, before, after)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(path: &str, attrs: &[(&str, &str)], text: &str) -> DomNode {
+        DomNode {
+            path: path.to_string(),
+            attributes: attrs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_identical_snapshots_have_empty_diff() {
+        let snapshot =
+            DomSnapshot::from_nodes(vec![node("html", &[], ""), node("html > body", &[], "Hi")]);
+        let diff = snapshot.diff(&snapshot.clone());
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "no DOM changes\n");
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_changed() {
+        let before = DomSnapshot::from_nodes(vec![
+            node("html > body > p#status", &[("class", "idle")], "Idle"),
+            node("html > body > div#toast", &[], "Saved"),
+        ]);
+        let after = DomSnapshot::from_nodes(vec![
+            node(
+                "html > body > p#status",
+                &[("class", "busy"), ("aria-busy", "true")],
+                "Working",
+            ),
+            node("html > body > ul > li[1]", &[], "Row"),
+        ]);
+        let diff = before.diff(&after);
+        assert_eq!(diff.removed, vec!["html > body > div#toast"]);
+        assert_eq!(diff.added, vec!["html > body > ul > li[1]"]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.len(), 3);
+
+        let change = &diff.changed[0];
+        assert_eq!(
+            change.text,
+            Some(("Idle".to_string(), "Working".to_string()))
+        );
+        assert_eq!(
+            change.attributes.get("class"),
+            Some(&(Some("idle".to_string()), Some("busy".to_string())))
+        );
+        assert_eq!(
+            change.attributes.get("aria-busy"),
+            Some(&(None, Some("true".to_string())))
+        );
+        assert!(diff.to_string().contains("~ html > body > p#status"));
+    }
+}
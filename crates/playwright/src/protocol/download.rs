@@ -102,7 +102,7 @@ impl Download {
     /// # Example
     ///
     /// ```ignore
-    /// # use playwright_rs::protocol::Download;
+    /// # use playwright_rs::Download;
     /// # async fn example(download: Download) -> Result<(), Box<dyn std::error::Error>> {
     /// download.save_as("/path/to/save/file.pdf").await?;
     /// # Ok(())
@@ -1,7 +1,9 @@
 // ElementHandle protocol object
 //
-// Represents a DOM element in the page. Supports element-specific operations like screenshots.
-// ElementHandles are created via query_selector methods and are protocol objects with GUIDs.
+// Represents a DOM element in the page. Unlike a Locator, a handle is bound to
+// one DOM node: it keeps acting on that node across DOM mutations and fails
+// once the node is detached. ElementHandles are created via query_selector
+// methods and are protocol objects with GUIDs.
 
 use crate::error::Result;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
@@ -9,12 +11,51 @@ use base64::Engine;
 use serde::Deserialize;
 use serde_json::Value;
 use std::any::Any;
+use std::ops::Deref;
 use std::sync::Arc;
 
+/// Position and size of an element, in CSS pixels relative to the main frame viewport.
+///
+/// Returned by [`Locator::bounding_box`](crate::protocol::Locator::bounding_box)
+/// and [`ElementHandle::bounding_box`].
+///
+/// See: <https://playwright.dev/docs/api/class-locator#locator-bounding-box>
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct BoundingBox {
+    /// X coordinate of the top-left corner
+    pub x: f64,
+    /// Y coordinate of the top-left corner
+    pub y: f64,
+    /// Width of the element
+    pub width: f64,
+    /// Height of the element
+    pub height: f64,
+}
+
+impl BoundingBox {
+    /// Center point of the box, e.g. as a target for `page.mouse()`
+    pub fn center(&self) -> (f64, f64) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+}
+
 /// ElementHandle represents a DOM element in the page.
 ///
 /// ElementHandles are created via `page.query_selector()` or `frame.query_selector()`.
-/// They are protocol objects that allow element-specific operations like taking screenshots.
+/// Prefer [`Locator`](crate::protocol::Locator) for most work: handles pin one DOM
+/// node, which is what you want for stable references across mutations, but a
+/// re-render leaves them pointing at a detached node. Dispose handles that are
+/// no longer needed, or the node stays pinned until its page goes away.
+///
+/// # Example
+///
+/// ```ignore
+/// let row = page.query_selector("tr.selected").await?.expect("no selected row");
+/// let id = row.get_attribute("data-id").await?;
+/// row.click(None).await?;
+/// let frame = row.owner_frame().await?;
+/// row.dispose().await?;
+/// ```
 ///
 /// See: <https://playwright.dev/docs/api/class-elementhandle>
 #[derive(Clone)]
@@ -50,7 +91,7 @@ impl ElementHandle {
     /// # Example
     ///
     /// ```ignore
-    /// # use playwright_rs::protocol::Playwright;
+    /// # use playwright_rs::Playwright;
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let playwright = Playwright::launch().await?;
@@ -67,8 +108,9 @@ impl ElementHandle {
     /// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-screenshot>
     pub async fn screenshot(
         &self,
-        options: Option<crate::protocol::ScreenshotOptions>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::ScreenshotOptions>,
     ) -> Result<Vec<u8>> {
+        let options = options.into_options();
         let params = if let Some(opts) = options {
             opts.to_json()
         } else {
@@ -98,6 +140,230 @@ impl ElementHandle {
 
         Ok(bytes)
     }
+
+    /// Returns the element's bounding box, or `None` if it is not visible.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-bounding-box>
+    pub async fn bounding_box(&self) -> Result<Option<BoundingBox>> {
+        #[derive(Deserialize)]
+        struct BoundingBoxResponse {
+            value: Option<BoundingBox>,
+        }
+
+        let response: BoundingBoxResponse = self
+            .base
+            .channel()
+            .send("boundingBox", serde_json::json!({}))
+            .await?;
+
+        Ok(response.value)
+    }
+
+    /// Clicks the element.
+    ///
+    /// Waits for the element to be actionable, like
+    /// [`Locator::click`](crate::protocol::Locator::click), but always acts on
+    /// this node even if the DOM changes. Fails if the node was detached.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-click>
+    pub async fn click(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::ClickOptions>,
+    ) -> Result<()> {
+        let params = with_options(
+            serde_json::json!({}),
+            options.into_options().map(|options| options.to_json()),
+        );
+        self.base.channel().send_no_result("click", params).await
+    }
+
+    /// Fills the element (an `<input>`, `<textarea>` or `[contenteditable]`) with text.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-fill>
+    pub async fn fill(
+        &self,
+        text: &str,
+        options: impl crate::protocol::IntoOptions<crate::protocol::FillOptions>,
+    ) -> Result<()> {
+        let params = with_options(
+            serde_json::json!({ "value": text }),
+            options.into_options().map(|options| options.to_json()),
+        );
+        self.base.channel().send_no_result("fill", params).await
+    }
+
+    /// Returns the value of an attribute, or `None` if it is not set.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-get-attribute>
+    pub async fn get_attribute(&self, name: &str) -> Result<Option<String>> {
+        #[derive(Deserialize)]
+        struct GetAttributeResponse {
+            value: Option<String>,
+        }
+
+        let response: GetAttributeResponse = self
+            .base
+            .channel()
+            .send("getAttribute", serde_json::json!({ "name": name }))
+            .await?;
+
+        Ok(response.value)
+    }
+
+    /// Returns the frame containing the element, or `None` if it was detached
+    /// from its document.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-owner-frame>
+    pub async fn owner_frame(&self) -> Result<Option<crate::protocol::Frame>> {
+        #[derive(Deserialize)]
+        struct OwnerFrameResponse {
+            frame: Option<FrameRef>,
+        }
+        #[derive(Deserialize)]
+        struct FrameRef {
+            guid: String,
+        }
+
+        let response: OwnerFrameResponse = self
+            .base
+            .channel()
+            .send("ownerFrame", serde_json::json!({}))
+            .await?;
+        let Some(frame) = response.frame else {
+            return Ok(None);
+        };
+
+        let object = self.connection().get_object(&frame.guid).await?;
+        let frame = object
+            .as_any()
+            .downcast_ref::<crate::protocol::Frame>()
+            .ok_or_else(|| {
+                crate::error::Error::ProtocolError(format!(
+                    "Expected Frame object, got {}",
+                    object.type_name()
+                ))
+            })?;
+        Ok(Some(frame.clone()))
+    }
+
+    /// Releases the element handle on the server.
+    ///
+    /// The handle cannot be used after it has been disposed. Handles are otherwise
+    /// kept alive by the server until their owning page or frame goes away.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-jshandle#js-handle-dispose>
+    pub async fn dispose(&self) -> Result<()> {
+        self.base
+            .channel()
+            .send_no_result("dispose", serde_json::json!({}))
+            .await
+    }
+
+    /// Queues a `dispose` call on the current tokio runtime without awaiting it.
+    ///
+    /// Used by [`ElementHandleGuard`]'s `Drop` implementation, which cannot block on
+    /// async work. If no runtime is available (e.g. the runtime is shutting down),
+    /// the handle is left for the server to clean up with its page.
+    fn dispose_in_background(&self) {
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                let channel = self.base.channel().clone();
+                let guid = self.guid().to_string();
+                runtime.spawn(async move {
+                    if let Err(e) = channel
+                        .send_no_result("dispose", serde_json::json!({}))
+                        .await
+                    {
+                        tracing::debug!("Background dispose of {} failed: {}", guid, e);
+                    }
+                });
+            }
+            Err(_) => {
+                tracing::debug!(
+                    "No tokio runtime available, skipping dispose of {}",
+                    self.guid()
+                );
+            }
+        }
+    }
+}
+
+/// Merges action options into `params`, defaulting the timeout the driver requires
+fn with_options(mut params: Value, options: Option<Value>) -> Value {
+    match options.as_ref().and_then(Value::as_object) {
+        Some(options) => {
+            if let Some(params) = params.as_object_mut() {
+                params.extend(options.clone());
+            }
+        }
+        None => params["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS),
+    }
+    params
+}
+
+/// An [`ElementHandle`] that is disposed automatically when dropped.
+///
+/// Returned by `Locator::element_handles()`. Element handles pin DOM nodes on the
+/// server side, so handles that are never disposed leak driver memory for the
+/// lifetime of the page. The guard dereferences to [`ElementHandle`] and, when it
+/// goes out of scope, queues a `dispose` call on the tokio runtime in the background.
+///
+/// Call [`ElementHandleGuard::dispose`] to release the handle and observe errors, or
+/// [`ElementHandleGuard::into_inner`] to opt out of automatic disposal.
+pub struct ElementHandleGuard {
+    handle: Option<Arc<ElementHandle>>,
+}
+
+impl ElementHandleGuard {
+    /// Wraps an element handle so it is disposed on drop
+    pub(crate) fn new(handle: Arc<ElementHandle>) -> Self {
+        Self {
+            handle: Some(handle),
+        }
+    }
+
+    /// Disposes the handle now and waits for the server to acknowledge it.
+    pub async fn dispose(mut self) -> Result<()> {
+        match self.handle.take() {
+            Some(handle) => handle.dispose().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Releases the handle from the guard without disposing it.
+    ///
+    /// The caller becomes responsible for calling [`ElementHandle::dispose`].
+    pub fn into_inner(mut self) -> Arc<ElementHandle> {
+        self.handle
+            .take()
+            .expect("ElementHandleGuard always holds a handle until consumed")
+    }
+}
+
+impl Deref for ElementHandleGuard {
+    type Target = ElementHandle;
+
+    fn deref(&self) -> &ElementHandle {
+        self.handle
+            .as_ref()
+            .expect("ElementHandleGuard always holds a handle until consumed")
+    }
+}
+
+impl Drop for ElementHandleGuard {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.dispose_in_background();
+        }
+    }
+}
+
+impl std::fmt::Debug for ElementHandleGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ElementHandleGuard")
+            .field("handle", &self.handle)
+            .finish()
+    }
 }
 
 impl ChannelOwner for ElementHandle {
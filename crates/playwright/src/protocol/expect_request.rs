@@ -0,0 +1,121 @@
+// Expect request - Wait for a sub-resource request while navigating
+//
+// Used by Page::goto_expecting_request(). A temporary route observes the first
+// request matching a glob pattern while goto() runs; the request can be blocked,
+// and navigation can be stopped as soon as the request is seen. Whatever was
+// captured is returned even if the navigation itself did not complete.
+
+use crate::error::Error;
+use crate::protocol::{GotoOptions, Request, Response};
+use std::time::Duration;
+
+/// What to wait for during [`Page::goto_expecting_request()`](crate::protocol::Page::goto_expecting_request).
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::ExpectRequest;
+///
+/// // Measure a third-party call without letting it through
+/// let result = page
+///     .goto_expecting_request(
+///         "https://shop.example.com",
+///         ExpectRequest::new("**/analytics/collect*").abort_request(true),
+///     )
+///     .await?;
+/// println!("analytics fired: {}", result.request.url());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExpectRequest {
+    /// Glob pattern the request URL must match (e.g. "**/api/config.json")
+    pub pattern: String,
+    /// Block matching requests instead of letting them through
+    pub abort_request: bool,
+    /// Stop waiting for (and stop loading) the navigation once the request is seen
+    pub stop_navigation: bool,
+    /// How long to wait for the request (default: Playwright's default timeout)
+    pub timeout: Option<Duration>,
+    /// Options for the underlying navigation
+    pub goto_options: Option<GotoOptions>,
+}
+
+impl ExpectRequest {
+    /// Waits for the first request whose URL matches `pattern`
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            abort_request: false,
+            stop_navigation: false,
+            timeout: None,
+            goto_options: None,
+        }
+    }
+
+    /// Abort matching requests (with error code "blockedbyclient") instead of continuing them
+    pub fn abort_request(mut self, abort: bool) -> Self {
+        self.abort_request = abort;
+        self
+    }
+
+    /// Stop the navigation as soon as the matching request is seen
+    pub fn stop_navigation(mut self, stop: bool) -> Self {
+        self.stop_navigation = stop;
+        self
+    }
+
+    /// Set how long to wait for the matching request
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set options for the underlying navigation
+    pub fn goto_options(mut self, options: GotoOptions) -> Self {
+        self.goto_options = Some(options);
+        self
+    }
+}
+
+/// Result of [`Page::goto_expecting_request()`](crate::protocol::Page::goto_expecting_request).
+///
+/// The navigation may be incomplete: it can be stopped on purpose, or fail because
+/// a blocked request was required. Check [`is_partial()`](Self::is_partial).
+#[derive(Debug)]
+pub struct NavigationWithRequest {
+    /// The first request that matched the pattern
+    pub request: Request,
+    /// Response to the matched request; `None` if it was aborted or failed
+    pub response: Option<Response>,
+    /// Main document response, if the navigation completed
+    pub navigation: Option<Response>,
+    /// Error from the navigation, if it failed after the request was captured
+    pub navigation_error: Option<Error>,
+    /// True if the navigation was stopped because of `stop_navigation`
+    pub navigation_stopped: bool,
+}
+
+impl NavigationWithRequest {
+    /// True if the navigation did not run to completion
+    pub fn is_partial(&self) -> bool {
+        self.navigation_stopped || self.navigation_error.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expect_request_builder() {
+        let expect = ExpectRequest::new("**/*.js")
+            .abort_request(true)
+            .stop_navigation(true)
+            .timeout(Duration::from_secs(5));
+
+        assert_eq!(expect.pattern, "**/*.js");
+        assert!(expect.abort_request);
+        assert!(expect.stop_navigation);
+        assert_eq!(expect.timeout, Some(Duration::from_secs(5)));
+        assert!(expect.goto_options.is_none());
+    }
+}
@@ -0,0 +1,270 @@
+// Structured data extraction - Schema-driven scraping in one round trip
+//
+// An ExtractSchema maps output field names to CSS selectors and what to read
+// from the matched element (text, attribute, HTML, ...). The whole schema is
+// sent to the page as a single evaluate() call and the resulting JSON object is
+// deserialized into the caller's type, instead of one protocol call per field.
+
+use serde::Serialize;
+
+/// JavaScript that walks an [`ExtractSchema`] and builds the result object
+pub(crate) const EXTRACT_SCRIPT: &str = r#"(schema) => {
+  const toNumber = (text) => {
+    if (text == null) return null;
+    const n = parseFloat(String(text).replace(/[^0-9.eE+-]/g, ''));
+    return Number.isNaN(n) ? null : n;
+  };
+  const read = (el, field) => {
+    if (!el) return null;
+    switch (field.source) {
+      case 'text': return (el.textContent || '').trim();
+      case 'innerText': return el.innerText;
+      case 'html': return el.innerHTML;
+      case 'value': return el.value === undefined ? null : el.value;
+      case 'attribute': return el.getAttribute(field.attribute);
+      case 'number': return toNumber(el.textContent);
+      case 'object': return extract(el, field.schema);
+      default: return null;
+    }
+  };
+  const select = (root, selector) =>
+    selector === ':scope' ? root : root.querySelector(selector);
+  const selectAll = (root, selector) =>
+    selector === ':scope' ? [root] : Array.from(root.querySelectorAll(selector));
+  const extract = (root, schema) => {
+    const out = {};
+    for (const [name, field] of schema.fields) {
+      if (field.source === 'count') {
+        out[name] = selectAll(root, field.selector).length;
+      } else if (field.all) {
+        out[name] = selectAll(root, field.selector).map((el) => read(el, field));
+      } else {
+        out[name] = read(select(root, field.selector), field);
+      }
+    }
+    return out;
+  };
+  return extract(document, schema);
+}"#;
+
+/// What to read from a matched element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum FieldSource {
+    Text,
+    InnerText,
+    Html,
+    Value,
+    Attribute,
+    Number,
+    Count,
+    Object,
+}
+
+/// A single field of an [`ExtractSchema`].
+///
+/// Selectors are plain CSS selectors evaluated relative to the enclosing element
+/// (the document at the top level). Use `":scope"` to read the enclosing element
+/// itself inside [`Field::list`] or [`Field::object`].
+///
+/// Missing elements produce `null`, so map optional data to `Option<T>` fields.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Field {
+    selector: String,
+    source: FieldSource,
+    all: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attribute: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    schema: Option<ExtractSchema>,
+}
+
+impl Field {
+    fn new(selector: impl Into<String>, source: FieldSource) -> Self {
+        Self {
+            selector: selector.into(),
+            source,
+            all: false,
+            attribute: None,
+            schema: None,
+        }
+    }
+
+    /// Trimmed `textContent` of the first match
+    pub fn text(selector: impl Into<String>) -> Self {
+        Self::new(selector, FieldSource::Text)
+    }
+
+    /// Rendered `innerText` of the first match
+    pub fn inner_text(selector: impl Into<String>) -> Self {
+        Self::new(selector, FieldSource::InnerText)
+    }
+
+    /// `innerHTML` of the first match
+    pub fn html(selector: impl Into<String>) -> Self {
+        Self::new(selector, FieldSource::Html)
+    }
+
+    /// `value` of the first matching input, textarea, or select
+    pub fn value(selector: impl Into<String>) -> Self {
+        Self::new(selector, FieldSource::Value)
+    }
+
+    /// Attribute of the first match (e.g. `href`, `src`)
+    pub fn attr(selector: impl Into<String>, attribute: impl Into<String>) -> Self {
+        let mut field = Self::new(selector, FieldSource::Attribute);
+        field.attribute = Some(attribute.into());
+        field
+    }
+
+    /// Number parsed from the text of the first match, ignoring currency symbols
+    /// and other non-numeric characters (`"$1,299.00"` becomes `1299.0`)
+    pub fn number(selector: impl Into<String>) -> Self {
+        Self::new(selector, FieldSource::Number)
+    }
+
+    /// Number of elements matching the selector
+    pub fn count(selector: impl Into<String>) -> Self {
+        Self::new(selector, FieldSource::Count)
+    }
+
+    /// Nested object extracted from the first match using `schema`
+    pub fn object(selector: impl Into<String>, schema: ExtractSchema) -> Self {
+        let mut field = Self::new(selector, FieldSource::Object);
+        field.schema = Some(schema);
+        field
+    }
+
+    /// Array of objects, one per match, each extracted using `schema`
+    pub fn list(selector: impl Into<String>, schema: ExtractSchema) -> Self {
+        Self::object(selector, schema).all()
+    }
+
+    /// Read every match instead of the first, producing an array
+    pub fn all(mut self) -> Self {
+        self.all = true;
+        self
+    }
+}
+
+/// Mapping of output field names to [`Field`]s, evaluated in one round trip.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::{ExtractSchema, Field};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Product {
+///     title: String,
+///     price: Option<f64>,
+///     image: Option<String>,
+///     tags: Vec<String>,
+///     reviews: Vec<Review>,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Review {
+///     author: String,
+///     stars: f64,
+/// }
+///
+/// let schema = ExtractSchema::new()
+///     .field("title", Field::text("h1"))
+///     .field("price", Field::number(".price"))
+///     .field("image", Field::attr("img.hero", "src"))
+///     .field("tags", Field::text(".tag").all())
+///     .field(
+///         "reviews",
+///         Field::list(
+///             ".review",
+///             ExtractSchema::new()
+///                 .field("author", Field::text(".author"))
+///                 .field("stars", Field::attr(":scope", "data-stars")),
+///         ),
+///     );
+///
+/// let product: Product = page.extract(&schema).await?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ExtractSchema {
+    fields: Vec<(String, Field)>,
+}
+
+impl ExtractSchema {
+    /// Creates an empty schema
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a field; later fields with the same name replace earlier ones
+    pub fn field(mut self, name: impl Into<String>, field: Field) -> Self {
+        let name = name.into();
+        self.fields.retain(|(existing, _)| existing != &name);
+        self.fields.push((name, field));
+        self
+    }
+
+    /// Number of top-level fields
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// True if the schema has no fields
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_schema_serialization() {
+        let schema = ExtractSchema::new()
+            .field("title", Field::text("h1"))
+            .field("link", Field::attr("a", "href"))
+            .field("tags", Field::text(".tag").all());
+
+        let value = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "fields": [
+                    ["title", {"selector": "h1", "source": "text", "all": false}],
+                    ["link", {"selector": "a", "source": "attribute", "all": false, "attribute": "href"}],
+                    ["tags", {"selector": ".tag", "source": "text", "all": true}]
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_nested_list_serialization() {
+        let schema = ExtractSchema::new().field(
+            "items",
+            Field::list(
+                "li",
+                ExtractSchema::new().field("n", Field::number(":scope")),
+            ),
+        );
+
+        let value = serde_json::to_value(&schema).unwrap();
+        let items = &value["fields"][0][1];
+        assert_eq!(items["source"], "object");
+        assert_eq!(items["all"], true);
+        assert_eq!(items["schema"]["fields"][0][1]["source"], "number");
+    }
+
+    #[test]
+    fn test_field_replaces_same_name() {
+        let schema = ExtractSchema::new()
+            .field("title", Field::text("h1"))
+            .field("title", Field::text("h2"));
+        assert_eq!(schema.len(), 1);
+        assert_eq!(schema.fields[0].1, Field::text("h2"));
+    }
+}
@@ -1,9 +1,15 @@
 // FilePayload protocol type
 //
 // Represents a file to be uploaded with explicit name, MIME type, and buffer.
+// InputFiles is what Locator::set_input_files() accepts: paths read from disk
+// or in-memory payloads, sent to the driver as base64 payloads either way.
 //
 // See: https://playwright.dev/docs/api/class-locator#locator-set-input-files
 
+use crate::error::{Error, Result};
+use base64::{engine::general_purpose, Engine as _};
+use std::path::{Path, PathBuf};
+
 /// FilePayload represents a file for advanced file uploads.
 ///
 /// Allows explicit control over filename, MIME type, and file contents
@@ -12,7 +18,7 @@
 /// # Example
 ///
 /// ```ignore
-/// # use playwright_rs::protocol::FilePayload;
+/// # use playwright_rs::FilePayload;
 /// let file = FilePayload::builder()
 ///     .name("document.pdf".to_string())
 ///     .mime_type("application/pdf".to_string())
@@ -21,7 +27,7 @@
 /// ```
 ///
 /// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FilePayload {
     /// File name
     pub name: String,
@@ -80,3 +86,184 @@ impl FilePayloadBuilder {
         }
     }
 }
+
+/// Files to set on an `<input type=file>`, for
+/// [`Locator::set_input_files`](crate::protocol::Locator::set_input_files).
+///
+/// Converts from a single path (`&str`, `&Path`, `PathBuf`), a slice, array or
+/// `Vec` of paths, or one or more [`FilePayload`]s. An empty list clears the
+/// input; [`InputFiles::none`] spells that out without a type annotation.
+///
+/// # Example
+///
+/// ```ignore
+/// upload.set_input_files("fixtures/avatar.png", None).await?;
+/// upload.set_input_files(["a.txt", "b.txt"], None).await?;
+/// upload.set_input_files(InputFiles::none(), None).await?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputFiles {
+    /// Files read from disk when the action runs
+    Paths(Vec<PathBuf>),
+    /// Files with explicit name, MIME type and contents
+    Payloads(Vec<FilePayload>),
+}
+
+impl InputFiles {
+    /// No files: clears the input's selection
+    pub fn none() -> Self {
+        InputFiles::Paths(Vec::new())
+    }
+
+    /// Whether there are no files, i.e. the input will be cleared
+    pub fn is_empty(&self) -> bool {
+        match self {
+            InputFiles::Paths(paths) => paths.is_empty(),
+            InputFiles::Payloads(payloads) => payloads.is_empty(),
+        }
+    }
+
+    /// Protocol `payloads`, reading path contents from disk
+    pub(crate) fn to_payloads(&self) -> Result<Vec<serde_json::Value>> {
+        match self {
+            InputFiles::Paths(paths) => paths
+                .iter()
+                .map(|path| {
+                    let name = path.file_name().and_then(|n| n.to_str()).ok_or_else(|| {
+                        Error::InvalidArgument(format!("Invalid file path: {}", path.display()))
+                    })?;
+                    let buffer = std::fs::read(path)?;
+                    Ok(serde_json::json!({
+                        "name": name,
+                        "buffer": general_purpose::STANDARD.encode(buffer),
+                    }))
+                })
+                .collect(),
+            InputFiles::Payloads(payloads) => Ok(payloads
+                .iter()
+                .map(|file| {
+                    serde_json::json!({
+                        "name": file.name,
+                        "mimeType": file.mime_type,
+                        "buffer": general_purpose::STANDARD.encode(&file.buffer),
+                    })
+                })
+                .collect()),
+        }
+    }
+}
+
+impl From<&str> for InputFiles {
+    fn from(path: &str) -> Self {
+        InputFiles::Paths(vec![PathBuf::from(path)])
+    }
+}
+
+impl From<&Path> for InputFiles {
+    fn from(path: &Path) -> Self {
+        InputFiles::Paths(vec![path.to_path_buf()])
+    }
+}
+
+impl From<PathBuf> for InputFiles {
+    fn from(path: PathBuf) -> Self {
+        InputFiles::Paths(vec![path])
+    }
+}
+
+impl From<&PathBuf> for InputFiles {
+    fn from(path: &PathBuf) -> Self {
+        InputFiles::Paths(vec![path.clone()])
+    }
+}
+
+impl<P: AsRef<Path>> From<&[P]> for InputFiles {
+    fn from(paths: &[P]) -> Self {
+        InputFiles::Paths(paths.iter().map(|p| p.as_ref().to_path_buf()).collect())
+    }
+}
+
+impl<P: AsRef<Path>, const N: usize> From<[P; N]> for InputFiles {
+    fn from(paths: [P; N]) -> Self {
+        InputFiles::from(&paths[..])
+    }
+}
+
+impl<P: AsRef<Path>, const N: usize> From<&[P; N]> for InputFiles {
+    fn from(paths: &[P; N]) -> Self {
+        InputFiles::from(&paths[..])
+    }
+}
+
+impl<P: AsRef<Path>> From<Vec<P>> for InputFiles {
+    fn from(paths: Vec<P>) -> Self {
+        InputFiles::from(&paths[..])
+    }
+}
+
+impl From<FilePayload> for InputFiles {
+    fn from(payload: FilePayload) -> Self {
+        InputFiles::Payloads(vec![payload])
+    }
+}
+
+impl From<Vec<FilePayload>> for InputFiles {
+    fn from(payloads: Vec<FilePayload>) -> Self {
+        InputFiles::Payloads(payloads)
+    }
+}
+
+impl From<&[FilePayload]> for InputFiles {
+    fn from(payloads: &[FilePayload]) -> Self {
+        InputFiles::Payloads(payloads.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_files_from_paths() {
+        let expected = InputFiles::Paths(vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")]);
+        assert_eq!(InputFiles::from(["a.txt", "b.txt"]), expected);
+        assert_eq!(
+            InputFiles::from(vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")]),
+            expected
+        );
+        assert_eq!(
+            InputFiles::from("a.txt"),
+            InputFiles::Paths(vec![PathBuf::from("a.txt")])
+        );
+        assert!(InputFiles::from(Vec::<PathBuf>::new()).is_empty());
+        assert!(InputFiles::none().to_payloads().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_input_files_payloads() {
+        let path =
+            std::env::temp_dir().join(format!("playwright_rs_upload_{}.txt", std::process::id()));
+        std::fs::write(&path, "hi").unwrap();
+        let payloads = InputFiles::from(&path).to_payloads().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(payloads[0]["buffer"], "aGk=");
+        assert_eq!(
+            payloads[0]["name"],
+            path.file_name().unwrap().to_str().unwrap()
+        );
+
+        let file = FilePayload::builder()
+            .name("data.json".to_string())
+            .mime_type("application/json".to_string())
+            .buffer(b"hi".to_vec())
+            .build();
+        assert_eq!(
+            InputFiles::from(file).to_payloads().unwrap(),
+            vec![
+                serde_json::json!({"name": "data.json", "mimeType": "application/json", "buffer": "aGk="})
+            ]
+        );
+
+        assert!(InputFiles::from("missing/file.txt").to_payloads().is_err());
+    }
+}
@@ -0,0 +1,166 @@
+// Locator filtering - Locator::filter()
+//
+// Narrows a locator to the matches that contain (or don't contain) some text
+// or some inner element, e.g. "the row that contains 'Product 2'". Builds the
+// same `internal:has-text` / `internal:has` selector parts as the other
+// bindings, appended to the filtered locator with `>>`.
+//
+// See: https://playwright.dev/docs/locators#filtering-locators
+
+use crate::protocol::get_by::escape_for_text_selector;
+use crate::protocol::{Locator, TextMatch};
+
+/// Options for [`Locator::filter`].
+///
+/// Text matches are case-insensitive substrings of the element's text,
+/// including its descendants; regexes match as written. Inner locators are
+/// resolved relative to each matched element and must come from the same frame.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::FilterOptions;
+///
+/// let rows = page.locator("tr");
+/// let row = rows.filter(
+///     FilterOptions::builder()
+///         .has_text("Product 2")
+///         .has_not(page.get_by_role("button", None))
+///         .build(),
+/// );
+/// ```
+///
+/// See: <https://playwright.dev/docs/api/class-locator#locator-filter>
+#[derive(Debug, Clone, Default)]
+pub struct FilterOptions {
+    /// Keep elements containing this text
+    pub has_text: Option<TextMatch>,
+    /// Keep elements not containing this text
+    pub has_not_text: Option<TextMatch>,
+    /// Keep elements containing an element matching this locator
+    pub has: Option<Locator>,
+    /// Keep elements not containing an element matching this locator
+    pub has_not: Option<Locator>,
+}
+
+impl FilterOptions {
+    /// Create a new builder for FilterOptions
+    pub fn builder() -> FilterOptionsBuilder {
+        FilterOptionsBuilder::default()
+    }
+
+    /// Selector parts to append, in upstream order
+    pub(crate) fn selector_parts(&self) -> Vec<String> {
+        let mut parts = Vec::new();
+        if let Some(text) = &self.has_text {
+            parts.push(format!(
+                "internal:has-text={}",
+                escape_for_text_selector(text, false)
+            ));
+        }
+        if let Some(text) = &self.has_not_text {
+            parts.push(format!(
+                "internal:has-not-text={}",
+                escape_for_text_selector(text, false)
+            ));
+        }
+        if let Some(has) = &self.has {
+            parts.push(format!("internal:has={}", quote_selector(has.selector())));
+        }
+        if let Some(has_not) = &self.has_not {
+            parts.push(format!(
+                "internal:has-not={}",
+                quote_selector(has_not.selector())
+            ));
+        }
+        parts
+    }
+}
+
+/// JSON-quotes a selector for embedding in `internal:has`, `internal:and`, ...
+pub(crate) fn quote_selector(selector: &str) -> String {
+    serde_json::Value::String(selector.to_string()).to_string()
+}
+
+/// Builder for FilterOptions
+#[derive(Debug, Clone, Default)]
+pub struct FilterOptionsBuilder {
+    options: FilterOptions,
+}
+
+impl FilterOptionsBuilder {
+    /// Keep elements containing `text` (a string or [`regex::Regex`])
+    pub fn has_text(mut self, text: impl Into<TextMatch>) -> Self {
+        self.options.has_text = Some(text.into());
+        self
+    }
+
+    /// Keep elements whose text matches `regex`
+    #[cfg(feature = "regex")]
+    pub fn has_text_regex(self, regex: &regex::Regex) -> Self {
+        self.has_text(regex)
+    }
+
+    /// Keep elements not containing `text`
+    pub fn has_not_text(mut self, text: impl Into<TextMatch>) -> Self {
+        self.options.has_not_text = Some(text.into());
+        self
+    }
+
+    /// Keep elements containing a match of `locator`
+    pub fn has(mut self, locator: Locator) -> Self {
+        self.options.has = Some(locator);
+        self
+    }
+
+    /// Keep elements not containing a match of `locator`
+    pub fn has_not(mut self, locator: Locator) -> Self {
+        self.options.has_not = Some(locator);
+        self
+    }
+
+    /// Build the FilterOptions
+    pub fn build(self) -> FilterOptions {
+        self.options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_selector_parts() {
+        let options = FilterOptions::builder()
+            .has_text("Product 2")
+            .has_not_text("Sold out")
+            .build();
+        assert_eq!(
+            options.selector_parts(),
+            vec![
+                r#"internal:has-text="Product 2"i"#.to_string(),
+                r#"internal:has-not-text="Sold out"i"#.to_string(),
+            ]
+        );
+        assert!(FilterOptions::default().selector_parts().is_empty());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_text_regex_selector_parts() {
+        let regex = regex::Regex::new(r"(?i)^total: \d+$").unwrap();
+        let options = FilterOptions::builder().has_text_regex(&regex).build();
+        assert_eq!(
+            options.selector_parts(),
+            vec![r"internal:has-text=/^total: \d+$/i".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_quote_inner_selector() {
+        assert_eq!(
+            quote_selector(r#"internal:role=button[name="Buy"i]"#),
+            r#""internal:role=button[name=\"Buy\"i]""#
+        );
+    }
+}
@@ -0,0 +1,384 @@
+// Form autofill - Filling forms from serde structs
+//
+// Page::fill_form serializes a struct to a JSON object and fills one form control
+// per field. A FormMapping says where each field goes (CSS selector or label);
+// unmapped fields default to `[name="<field>"]`. Each value is applied according
+// to the control it lands on and then read back to validate it stuck:
+//
+// - strings/numbers: fill() text controls, select_option() on <select>
+// - booleans: check()/uncheck()
+// - arrays: select_option_multiple()
+// - null: skipped
+
+use crate::error::{Error, Result};
+use crate::protocol::{Frame, Locator};
+use serde_json::{Map, Value};
+use std::sync::Arc;
+
+/// Reports the tag and input type of the elements matching a selector
+const KIND_SCRIPT: &str =
+    "(elements) => elements.map((e) => ({ tag: e.tagName.toLowerCase(), type: (e.type || '').toLowerCase() }))";
+
+/// Reads every named control under a form (or any container) in one call.
+///
+/// Checkboxes read as booleans, or as the list of checked values when several
+/// share a name; radio groups as the checked value or null; multi-selects as
+/// the list of selected values; everything else as its value. Buttons and
+/// file inputs are skipped.
+pub(crate) const FORM_VALUES_SCRIPT: &str = r#"(root) => {
+    const controls = root instanceof HTMLFormElement
+        ? Array.from(root.elements)
+        : Array.from(root.querySelectorAll('input, select, textarea'));
+    const skipped = ['submit', 'button', 'reset', 'image', 'file', 'fieldset', 'output'];
+    const named = controls.filter((c) => c.name && !skipped.includes((c.type || '').toLowerCase()));
+    const checkboxes = {};
+    for (const c of named) {
+        if (c.type === 'checkbox') checkboxes[c.name] = (checkboxes[c.name] || 0) + 1;
+    }
+    const values = {};
+    for (const c of named) {
+        if (c.type === 'checkbox' && checkboxes[c.name] > 1) {
+            values[c.name] = values[c.name] || [];
+            if (c.checked) values[c.name].push(c.value);
+        } else if (c.type === 'checkbox') {
+            values[c.name] = c.checked;
+        } else if (c.type === 'radio') {
+            if (!(c.name in values)) values[c.name] = null;
+            if (c.checked) values[c.name] = c.value;
+        } else if (c.type === 'select-multiple') {
+            values[c.name] = Array.from(c.selectedOptions).map((o) => o.value);
+        } else {
+            values[c.name] = c.value;
+        }
+    }
+    return values;
+}"#;
+
+/// Lists the expected fields whose form value differs, one line per field
+///
+/// Only fields in `expected` are compared. Numbers match their decimal text
+/// and lists match regardless of order.
+pub(crate) fn diff_form_values(
+    expected: &Map<String, Value>,
+    actual: &Map<String, Value>,
+) -> Vec<String> {
+    expected
+        .iter()
+        .filter_map(|(field, want)| match actual.get(field) {
+            None => Some(format!(
+                "  {}: expected {}, but the form has no such field",
+                field, want
+            )),
+            Some(got) if !form_value_matches(want, got) => {
+                Some(format!("  {}: expected {}, got {}", field, want, got))
+            }
+            Some(_) => None,
+        })
+        .collect()
+}
+
+fn form_value_matches(expected: &Value, actual: &Value) -> bool {
+    match (expected, actual) {
+        (Value::Number(n), Value::String(s)) => s.parse::<f64>().ok() == n.as_f64(),
+        (Value::Array(want), Value::Array(got)) => {
+            let sorted = |items: &[Value]| {
+                let mut items: Vec<String> = items
+                    .iter()
+                    .map(|item| match item {
+                        Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    })
+                    .collect();
+                items.sort();
+                items
+            };
+            sorted(want) == sorted(got)
+        }
+        _ => expected == actual,
+    }
+}
+
+/// Where a form field is filled
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormTarget {
+    /// Any Playwright selector
+    Selector(String),
+    /// The control labelled with this text (case-insensitive substring match)
+    Label(String),
+}
+
+impl FormTarget {
+    fn selector(&self) -> String {
+        match self {
+            FormTarget::Selector(selector) => selector.clone(),
+            FormTarget::Label(text) => format!(
+                "internal:label={}i",
+                serde_json::to_string(text).unwrap_or_default()
+            ),
+        }
+    }
+}
+
+/// Maps struct fields to form controls for [`Page::fill_form`](crate::protocol::Page::fill_form).
+///
+/// Mapped fields are filled in the order they were added; remaining fields
+/// follow, targeting `[name="<field>"]`, in the order serde_json yields them
+/// (alphabetical).
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::FormMapping;
+///
+/// #[derive(serde::Serialize)]
+/// struct Signup { email: String, full_name: String, plan: String, terms: bool }
+///
+/// let mapping = FormMapping::new()
+///     .selector("email", "#email")
+///     .label("full_name", "Full name");
+/// page.fill_form(&signup, Some(&mapping)).await?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormMapping {
+    fields: Vec<(String, FormTarget)>,
+    only_mapped: bool,
+}
+
+impl FormMapping {
+    /// Creates an empty mapping; every field targets `[name="<field>"]`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps `field` to `target`
+    pub fn field(mut self, field: impl Into<String>, target: FormTarget) -> Self {
+        let field = field.into();
+        self.fields.retain(|(name, _)| *name != field);
+        self.fields.push((field, target));
+        self
+    }
+
+    /// Maps `field` to a selector
+    pub fn selector(self, field: impl Into<String>, selector: impl Into<String>) -> Self {
+        self.field(field, FormTarget::Selector(selector.into()))
+    }
+
+    /// Maps `field` to the control with this label
+    pub fn label(self, field: impl Into<String>, label: impl Into<String>) -> Self {
+        self.field(field, FormTarget::Label(label.into()))
+    }
+
+    /// Skips fields that are not mapped instead of using `[name="<field>"]`
+    pub fn only_mapped(mut self, only_mapped: bool) -> Self {
+        self.only_mapped = only_mapped;
+        self
+    }
+
+    /// Resolves the fill plan: `(field, selector, value)` in fill order
+    pub(crate) fn plan(&self, data: &Value) -> Result<Vec<(String, String, Value)>> {
+        let object = data.as_object().ok_or_else(|| {
+            Error::InvalidArgument("fill_form data must serialize to a JSON object".to_string())
+        })?;
+
+        let mut plan = Vec::new();
+        for (field, target) in &self.fields {
+            let value = object.get(field).ok_or_else(|| {
+                Error::InvalidArgument(format!("Mapped form field '{}' is not in the data", field))
+            })?;
+            plan.push((field.clone(), target.selector(), value.clone()));
+        }
+        if !self.only_mapped {
+            for (field, value) in object {
+                if !self.fields.iter().any(|(name, _)| name == field) {
+                    let selector = format!("[name={}]", serde_json::to_string(field)?);
+                    plan.push((field.clone(), selector, value.clone()));
+                }
+            }
+        }
+        Ok(plan)
+    }
+}
+
+pub(crate) async fn fill_form(
+    frame: Arc<Frame>,
+    data: &Value,
+    mapping: &FormMapping,
+) -> Result<()> {
+    for (field, selector, value) in mapping.plan(data)? {
+        let locator = Locator::new(Arc::clone(&frame), selector.clone());
+        fill_field(&frame, &locator, &value)
+            .await
+            .map_err(|e| match e {
+                Error::InvalidArgument(message) => Error::InvalidArgument(format!(
+                    "Form field '{}' ({}): {}",
+                    field, selector, message
+                )),
+                other => other,
+            })?;
+    }
+    Ok(())
+}
+
+async fn fill_field(frame: &Frame, locator: &Locator, value: &Value) -> Result<()> {
+    let (tag, input_type) = element_kind(frame, locator.selector()).await?;
+    match value {
+        Value::Null => Ok(()),
+        Value::Bool(checked) => {
+            locator.set_checked(*checked, None).await?;
+            let actual = locator.is_checked().await?;
+            expect_eq(&checked.to_string(), &actual.to_string())
+        }
+        Value::String(_) | Value::Number(_) => {
+            let text = match value {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            if tag == "select" {
+                let selected = locator.select_option(text.as_str(), None).await?;
+                if selected.is_empty() {
+                    return Err(Error::InvalidArgument(format!(
+                        "no option matches {:?}",
+                        text
+                    )));
+                }
+                Ok(())
+            } else if input_type == "checkbox" || input_type == "radio" {
+                Err(Error::InvalidArgument(format!(
+                    "{} input expects a boolean, got {}",
+                    input_type, value
+                )))
+            } else {
+                locator.fill(&text, None).await?;
+                let actual = locator.input_value(None).await?;
+                expect_eq(&text, &actual)
+            }
+        }
+        Value::Array(items) => {
+            let values: Vec<String> = items
+                .iter()
+                .map(|item| match item {
+                    Value::String(s) => Ok(s.clone()),
+                    Value::Number(n) => Ok(n.to_string()),
+                    other => Err(Error::InvalidArgument(format!(
+                        "unsupported list item {}",
+                        other
+                    ))),
+                })
+                .collect::<Result<_>>()?;
+            let refs: Vec<&str> = values.iter().map(String::as_str).collect();
+            let selected = locator.select_option_multiple(&refs, None).await?;
+            if selected.len() != values.len() {
+                return Err(Error::InvalidArgument(format!(
+                    "expected {} selected options, got {:?}",
+                    values.len(),
+                    selected
+                )));
+            }
+            Ok(())
+        }
+        Value::Object(_) => Err(Error::InvalidArgument(
+            "nested objects are not supported".to_string(),
+        )),
+    }
+}
+
+/// Tag and input type of the element, or empty strings if nothing matches yet
+async fn element_kind(frame: &Frame, selector: &str) -> Result<(String, String)> {
+    let kinds = frame
+        .eval_on_selector_all(selector, KIND_SCRIPT, &())
+        .await?;
+    let first = kinds.get(0);
+    let read = |key: &str| {
+        first
+            .and_then(|kind| kind[key].as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    Ok((read("tag"), read("type")))
+}
+
+fn expect_eq(expected: &str, actual: &str) -> Result<()> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(Error::InvalidArgument(format!(
+            "expected {:?} after filling, found {:?}",
+            expected, actual
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_plan_orders_mapped_fields_first() {
+        let data = json!({ "age": 30, "email": "a@b.c", "name": "Ada" });
+        let mapping = FormMapping::new()
+            .label("name", "Full name")
+            .selector("email", "#email");
+        let plan = mapping.plan(&data).unwrap();
+        let order: Vec<(&str, &str)> = plan
+            .iter()
+            .map(|(field, selector, _)| (field.as_str(), selector.as_str()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("name", "internal:label=\"Full name\"i"),
+                ("email", "#email"),
+                ("age", "[name=\"age\"]"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_only_mapped() {
+        let data = json!({ "age": 30, "email": "a@b.c" });
+        let mapping = FormMapping::new()
+            .selector("email", "#email")
+            .only_mapped(true);
+        assert_eq!(mapping.plan(&data).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_diff_form_values() {
+        let actual = json!({
+            "email": "ada@example.com",
+            "age": "36",
+            "terms": false,
+            "plan": null,
+            "toppings": ["ham", "olives"]
+        });
+        let actual = actual.as_object().unwrap();
+
+        let matching =
+            json!({ "email": "ada@example.com", "age": 36, "toppings": ["olives", "ham"] });
+        assert!(diff_form_values(matching.as_object().unwrap(), actual).is_empty());
+
+        let expected = json!({ "terms": true, "plan": "pro", "phone": "555" });
+        assert_eq!(
+            diff_form_values(expected.as_object().unwrap(), actual),
+            vec![
+                "  phone: expected \"555\", but the form has no such field",
+                "  plan: expected \"pro\", got null",
+                "  terms: expected true, got false",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_errors() {
+        let mapping = FormMapping::new().selector("missing", "#missing");
+        assert!(matches!(
+            mapping.plan(&json!({})),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            FormMapping::new().plan(&json!("not an object")),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
@@ -4,14 +4,21 @@
 // Navigation and DOM operations happen on frames, not directly on pages.
 
 use crate::error::{Error, Result};
-use crate::protocol::page::{GotoOptions, Response};
+use crate::protocol::navigation::Navigation;
+use crate::protocol::page::{GotoOptions, Response, WaitUntil};
 use crate::protocol::{parse_result, serialize_argument, serialize_null};
 use crate::server::channel::Channel;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
 use serde::Deserialize;
 use serde_json::Value;
 use std::any::Any;
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
+
+/// Navigations buffered per subscriber before the oldest are dropped
+const NAVIGATION_BUFFER: usize = 64;
 
 /// Frame represents a frame within a page.
 ///
@@ -24,6 +31,12 @@ use std::sync::Arc;
 #[derive(Clone)]
 pub struct Frame {
     base: ChannelOwnerImpl,
+    /// Lifecycle states reached by the current document ("load", "networkidle", ...)
+    load_states: Arc<watch::Sender<HashSet<String>>>,
+    /// URL of the last committed navigation
+    url: Arc<parking_lot::RwLock<String>>,
+    /// Committed navigations, for `Page::on_url_changed` and friends
+    navigations: broadcast::Sender<Navigation>,
 }
 
 impl Frame {
@@ -37,6 +50,23 @@ impl Frame {
         guid: Arc<str>,
         initializer: Value,
     ) -> Result<Self> {
+        let load_states: HashSet<String> = initializer
+            .get("loadStates")
+            .and_then(|v| v.as_array())
+            .map(|states| {
+                states
+                    .iter()
+                    .filter_map(|s| s.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let url = initializer
+            .get("url")
+            .and_then(|v| v.as_str())
+            .unwrap_or("about:blank")
+            .to_string();
+
         let base = ChannelOwnerImpl::new(
             ParentOrConnection::Parent(parent),
             type_name,
@@ -44,7 +74,12 @@ impl Frame {
             initializer,
         );
 
-        Ok(Self { base })
+        Ok(Self {
+            base,
+            load_states: Arc::new(watch::channel(load_states).0),
+            url: Arc::new(parking_lot::RwLock::new(url)),
+            navigations: broadcast::channel(NAVIGATION_BUFFER).0,
+        })
     }
 
     /// Returns the channel for sending protocol messages
@@ -52,6 +87,21 @@ impl Frame {
         self.base.channel()
     }
 
+    /// Returns the URL of the frame's last committed navigation.
+    ///
+    /// Unlike [`Page::url`](crate::protocol::Page::url) before a response
+    /// arrives, this follows same-document navigations too.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-frame#frame-url>
+    pub fn url(&self) -> String {
+        self.url.read().clone()
+    }
+
+    /// Subscribes to the frame's committed navigations
+    pub(crate) fn subscribe_navigations(&self) -> broadcast::Receiver<Navigation> {
+        self.navigations.subscribe()
+    }
+
     /// Navigates the frame to the specified URL.
     ///
     /// This is the actual protocol method for navigation. Page.goto() delegates to this.
@@ -65,7 +115,12 @@ impl Frame {
     /// * `options` - Optional navigation options (timeout, wait_until)
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-goto>
-    pub async fn goto(&self, url: &str, options: Option<GotoOptions>) -> Result<Option<Response>> {
+    pub async fn goto(
+        &self,
+        url: &str,
+        options: impl crate::protocol::IntoOptions<GotoOptions>,
+    ) -> Result<Option<Response>> {
+        let options = options.into_options();
         // Build params manually using json! macro
         let mut params = serde_json::json!({
             "url": url,
@@ -104,61 +159,10 @@ impl Frame {
 
         // If navigation returned a response, get the Response object from the connection
         if let Some(response_ref) = goto_result.response {
-            // The server returns a Response GUID, but the __create__ message might not have
-            // arrived yet. Retry a few times to wait for the object to be created.
-            // TODO(Phase 4+): Implement proper GUID replacement like Python's _replace_guids_with_channels
-            //   - Eliminates retry loop for better performance
-            //   - See: playwright-python's _replace_guids_with_channels method
-            let response_arc = {
-                let mut attempts = 0;
-                let max_attempts = 20; // 20 * 50ms = 1 second max wait
-                loop {
-                    match self.connection().get_object(&response_ref.guid).await {
-                        Ok(obj) => break obj,
-                        Err(_) if attempts < max_attempts => {
-                            attempts += 1;
-                            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
-                        }
-                        Err(e) => return Err(e),
-                    }
-                }
-            };
-
-            // Note: ResponseObject protocol object exists (crates/playwright-core/src/protocol/response.rs)
-            // We extract Response data from its initializer rather than wrapping the protocol object
-            let initializer = response_arc.initializer();
-
-            // Extract response data from initializer
-            let status = initializer["status"].as_u64().ok_or_else(|| {
-                crate::error::Error::ProtocolError("Response missing status".to_string())
-            })? as u16;
-
-            // Convert headers from array format to HashMap
-            let headers = initializer["headers"]
-                .as_array()
-                .ok_or_else(|| {
-                    crate::error::Error::ProtocolError("Response missing headers".to_string())
-                })?
-                .iter()
-                .filter_map(|h| {
-                    let name = h["name"].as_str()?;
-                    let value = h["value"].as_str()?;
-                    Some((name.to_string(), value.to_string()))
-                })
-                .collect();
-
-            Ok(Some(Response {
-                url: initializer["url"]
-                    .as_str()
-                    .ok_or_else(|| {
-                        crate::error::Error::ProtocolError("Response missing url".to_string())
-                    })?
-                    .to_string(),
-                status,
-                status_text: initializer["statusText"].as_str().unwrap_or("").to_string(),
-                ok: (200..300).contains(&status), // Compute ok from status code
-                headers,
-            }))
+            let response =
+                crate::protocol::response::resolve_response(self.connection(), &response_ref.guid)
+                    .await?;
+            Ok(Some(response))
         } else {
             // Navigation returned null (e.g., data URLs, about:blank)
             // This is a valid result, not an error
@@ -313,6 +317,66 @@ impl Frame {
         Ok(response.elements.len())
     }
 
+    /// Evaluates a function with the single element matching `selector`.
+    ///
+    /// The function receives the element and `arg`. Strict: fails if the
+    /// selector matches more than one element.
+    pub(crate) async fn eval_on_selector<T: serde::Serialize>(
+        &self,
+        selector: &str,
+        expression: &str,
+        arg: &T,
+    ) -> Result<Value> {
+        #[derive(Deserialize)]
+        struct EvaluateResult {
+            value: Value,
+        }
+
+        let result: EvaluateResult = self
+            .channel()
+            .send(
+                "evalOnSelector",
+                serde_json::json!({
+                    "selector": selector,
+                    "strict": true,
+                    "expression": expression,
+                    "isFunction": true,
+                    "arg": serialize_argument(arg),
+                }),
+            )
+            .await?;
+        Ok(parse_result(&result.value))
+    }
+
+    /// Evaluates a function with every element matching `selector`.
+    ///
+    /// The function receives the array of elements and `arg`.
+    pub(crate) async fn eval_on_selector_all<T: serde::Serialize>(
+        &self,
+        selector: &str,
+        expression: &str,
+        arg: &T,
+    ) -> Result<Value> {
+        #[derive(Deserialize)]
+        struct EvaluateResult {
+            value: Value,
+        }
+
+        let result: EvaluateResult = self
+            .channel()
+            .send(
+                "evalOnSelectorAll",
+                serde_json::json!({
+                    "selector": selector,
+                    "expression": expression,
+                    "isFunction": true,
+                    "arg": serialize_argument(arg),
+                }),
+            )
+            .await?;
+        Ok(parse_result(&result.value))
+    }
+
     /// Returns the text content of the element.
     pub(crate) async fn locator_text_content(&self, selector: &str) -> Result<Option<String>> {
         #[derive(Deserialize)]
@@ -357,6 +421,31 @@ impl Frame {
         Ok(response.value)
     }
 
+    /// Returns the ARIA snapshot of the element.
+    pub(crate) async fn locator_aria_snapshot(&self, selector: &str) -> Result<String> {
+        #[derive(Deserialize)]
+        struct AriaSnapshotResponse {
+            snapshot: String,
+        }
+
+        crate::protocol::capabilities::require(
+            &*self.connection(),
+            crate::protocol::Capability::AriaSnapshot,
+        )?;
+        let response: AriaSnapshotResponse = self
+            .channel()
+            .send(
+                "ariaSnapshot",
+                serde_json::json!({
+                    "selector": selector,
+                    "timeout": crate::DEFAULT_TIMEOUT_MS
+                }),
+            )
+            .await?;
+
+        Ok(response.snapshot)
+    }
+
     /// Returns the inner HTML of the element.
     pub(crate) async fn locator_inner_html(&self, selector: &str) -> Result<String> {
         #[derive(Deserialize)]
@@ -747,6 +836,60 @@ impl Frame {
         self.channel().send_no_result("hover", params).await
     }
 
+    pub(crate) async fn locator_tap(
+        &self,
+        selector: &str,
+        options: Option<crate::protocol::TapOptions>,
+    ) -> Result<()> {
+        let mut params = options.unwrap_or_default().to_json();
+        params["selector"] = serde_json::json!(selector);
+        params["strict"] = serde_json::json!(true);
+
+        self.channel().send_no_result("tap", params).await
+    }
+
+    pub(crate) async fn locator_drag_to(
+        &self,
+        source: &str,
+        target: &str,
+        options: Option<crate::protocol::DragToOptions>,
+    ) -> Result<()> {
+        let mut params = serde_json::json!({
+            "source": source,
+            "target": target,
+            "strict": true
+        });
+
+        if let Some(opts) = options {
+            let opts_json = opts.to_json();
+            if let Some(obj) = params.as_object_mut() {
+                if let Some(opts_obj) = opts_json.as_object() {
+                    obj.extend(opts_obj.clone());
+                }
+            }
+        } else {
+            params["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
+        }
+
+        self.channel().send_no_result("dragAndDrop", params).await
+    }
+
+    pub(crate) async fn locator_dispatch_event(
+        &self,
+        selector: &str,
+        event_type: &str,
+        event_init: serde_json::Value,
+        options: Option<crate::protocol::DispatchEventOptions>,
+    ) -> Result<()> {
+        let mut params = options.unwrap_or_default().to_json();
+        params["selector"] = serde_json::json!(selector);
+        params["strict"] = serde_json::json!(true);
+        params["type"] = serde_json::json!(event_type);
+        params["eventInit"] = event_init;
+
+        self.channel().send_no_result("dispatchEvent", params).await
+    }
+
     pub(crate) async fn locator_input_value(&self, selector: &str) -> Result<String> {
         #[derive(Deserialize)]
         struct InputValueResponse {
@@ -841,173 +984,15 @@ impl Frame {
     pub(crate) async fn locator_set_input_files(
         &self,
         selector: &str,
-        file: &std::path::PathBuf,
+        files: &crate::protocol::InputFiles,
+        options: Option<crate::protocol::SetInputFilesOptions>,
     ) -> Result<()> {
-        use base64::{engine::general_purpose, Engine as _};
-        use std::io::Read;
-
-        // Read file contents
-        let mut file_handle = std::fs::File::open(file)?;
-        let mut buffer = Vec::new();
-        file_handle.read_to_end(&mut buffer)?;
+        let mut params = options.unwrap_or_default().to_json();
+        params["selector"] = serde_json::json!(selector);
+        params["strict"] = serde_json::json!(true);
+        params["payloads"] = serde_json::json!(files.to_payloads()?);
 
-        // Base64 encode the file contents
-        let base64_content = general_purpose::STANDARD.encode(&buffer);
-
-        // Get file name
-        let file_name = file
-            .file_name()
-            .and_then(|n| n.to_str())
-            .ok_or_else(|| crate::error::Error::InvalidArgument("Invalid file path".to_string()))?;
-
-        self.channel()
-            .send_no_result(
-                "setInputFiles",
-                serde_json::json!({
-                    "selector": selector,
-                    "strict": true,
-                    "timeout": crate::DEFAULT_TIMEOUT_MS,  // Required in Playwright 1.56.1+
-                    "payloads": [{
-                        "name": file_name,
-                        "buffer": base64_content
-                    }]
-                }),
-            )
-            .await
-    }
-
-    pub(crate) async fn locator_set_input_files_multiple(
-        &self,
-        selector: &str,
-        files: &[&std::path::PathBuf],
-    ) -> Result<()> {
-        use base64::{engine::general_purpose, Engine as _};
-        use std::io::Read;
-
-        // If empty array, clear the files
-        if files.is_empty() {
-            return self
-                .channel()
-                .send_no_result(
-                    "setInputFiles",
-                    serde_json::json!({
-                        "selector": selector,
-                        "strict": true,
-                        "timeout": crate::DEFAULT_TIMEOUT_MS,  // Required in Playwright 1.56.1+
-                        "payloads": []
-                    }),
-                )
-                .await;
-        }
-
-        // Read and encode each file
-        let mut file_objects = Vec::new();
-        for file_path in files {
-            let mut file_handle = std::fs::File::open(file_path)?;
-            let mut buffer = Vec::new();
-            file_handle.read_to_end(&mut buffer)?;
-
-            let base64_content = general_purpose::STANDARD.encode(&buffer);
-            let file_name = file_path
-                .file_name()
-                .and_then(|n| n.to_str())
-                .ok_or_else(|| {
-                    crate::error::Error::InvalidArgument("Invalid file path".to_string())
-                })?;
-
-            file_objects.push(serde_json::json!({
-                "name": file_name,
-                "buffer": base64_content
-            }));
-        }
-
-        self.channel()
-            .send_no_result(
-                "setInputFiles",
-                serde_json::json!({
-                    "selector": selector,
-                    "strict": true,
-                    "timeout": crate::DEFAULT_TIMEOUT_MS,  // Required in Playwright 1.56.1+
-                    "payloads": file_objects
-                }),
-            )
-            .await
-    }
-
-    pub(crate) async fn locator_set_input_files_payload(
-        &self,
-        selector: &str,
-        file: crate::protocol::FilePayload,
-    ) -> Result<()> {
-        use base64::{engine::general_purpose, Engine as _};
-
-        // Base64 encode the file contents
-        let base64_content = general_purpose::STANDARD.encode(&file.buffer);
-
-        self.channel()
-            .send_no_result(
-                "setInputFiles",
-                serde_json::json!({
-                    "selector": selector,
-                    "strict": true,
-                    "timeout": crate::DEFAULT_TIMEOUT_MS,
-                    "payloads": [{
-                        "name": file.name,
-                        "mimeType": file.mime_type,
-                        "buffer": base64_content
-                    }]
-                }),
-            )
-            .await
-    }
-
-    pub(crate) async fn locator_set_input_files_payload_multiple(
-        &self,
-        selector: &str,
-        files: &[crate::protocol::FilePayload],
-    ) -> Result<()> {
-        use base64::{engine::general_purpose, Engine as _};
-
-        // If empty array, clear the files
-        if files.is_empty() {
-            return self
-                .channel()
-                .send_no_result(
-                    "setInputFiles",
-                    serde_json::json!({
-                        "selector": selector,
-                        "strict": true,
-                        "timeout": crate::DEFAULT_TIMEOUT_MS,
-                        "payloads": []
-                    }),
-                )
-                .await;
-        }
-
-        // Encode each file
-        let file_objects: Vec<_> = files
-            .iter()
-            .map(|file| {
-                let base64_content = general_purpose::STANDARD.encode(&file.buffer);
-                serde_json::json!({
-                    "name": file.name,
-                    "mimeType": file.mime_type,
-                    "buffer": base64_content
-                })
-            })
-            .collect();
-
-        self.channel()
-            .send_no_result(
-                "setInputFiles",
-                serde_json::json!({
-                    "selector": selector,
-                    "strict": true,
-                    "timeout": crate::DEFAULT_TIMEOUT_MS,
-                    "payloads": file_objects
-                }),
-            )
-            .await
+        self.channel().send_no_result("setInputFiles", params).await
     }
 
     /// Evaluates JavaScript expression in the frame context (without return value).
@@ -1103,7 +1088,7 @@ impl Frame {
     ///
     /// ```ignore
     /// use serde_json::json;
-    /// use playwright_rs::protocol::Playwright;
+    /// use playwright_rs::Playwright;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -1153,6 +1138,123 @@ impl Frame {
         Ok(parse_result(&result.value))
     }
 
+    /// Waits until the frame's current document reaches `state`.
+    ///
+    /// Returns immediately if the state was already reached. `state` defaults to
+    /// [`WaitUntil::Load`] and `timeout` to 30 seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Timeout` if the state is not reached in time.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-frame#frame-wait-for-load-state>
+    pub async fn wait_for_load_state(
+        &self,
+        state: Option<WaitUntil>,
+        timeout: Option<Duration>,
+    ) -> Result<()> {
+        let state = state.unwrap_or(WaitUntil::Load);
+        let timeout = timeout.unwrap_or(Duration::from_millis(crate::DEFAULT_TIMEOUT_MS as u64));
+        let mut states = self.load_states.subscribe();
+
+        let reached = tokio::time::timeout(timeout, async {
+            states
+                .wait_for(|s| s.contains(state.as_str()))
+                .await
+                .map(|_| ())
+        })
+        .await;
+        match reached {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(_)) => Err(Error::ChannelClosed),
+            Err(_) => Err(Error::Timeout(format!(
+                "Waiting for load state \"{}\" exceeded {:?}",
+                state.as_str(),
+                timeout
+            ))),
+        }
+    }
+
+    /// Waits until the frame stops changing; see [`StabilityOptions`](crate::protocol::StabilityOptions).
+    pub async fn wait_for_stable(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::StabilityOptions>,
+    ) -> Result<()> {
+        let options = options.into_options().unwrap_or_default();
+        let started = tokio::time::Instant::now();
+
+        if options.network_idle {
+            self.wait_for_load_state(Some(WaitUntil::NetworkIdle), Some(options.timeout))
+                .await?;
+        }
+
+        let remaining = options.timeout.saturating_sub(started.elapsed());
+        let value = self
+            .evaluate(
+                crate::protocol::stability::STABILITY_SCRIPT,
+                Some(&options.script_arg(remaining)),
+            )
+            .await?;
+        if value.as_bool() == Some(true) {
+            Ok(())
+        } else {
+            Err(Error::Timeout(format!(
+                "Page did not stay quiet for {:?} within {:?}",
+                options.quiet_period, options.timeout
+            )))
+        }
+    }
+
+    /// Extracts structured data described by `schema` in a single round trip.
+    ///
+    /// See [`ExtractSchema`](crate::protocol::ExtractSchema) for an example.
+    pub async fn extract<T: serde::de::DeserializeOwned>(
+        &self,
+        schema: &crate::protocol::ExtractSchema,
+    ) -> Result<T> {
+        let value = self
+            .evaluate(crate::protocol::extract::EXTRACT_SCRIPT, Some(schema))
+            .await?;
+        serde_json::from_value(value).map_err(|e| {
+            Error::InvalidArgument(format!(
+                "Extracted data does not match the target type: {}",
+                e
+            ))
+        })
+    }
+
+    /// Captures a normalized snapshot of the frame's DOM.
+    ///
+    /// See [`DomSnapshot`](crate::protocol::DomSnapshot).
+    pub async fn snapshot_dom(&self) -> Result<crate::protocol::DomSnapshot> {
+        let value = self
+            .evaluate(
+                crate::protocol::dom_diff::SNAPSHOT_SCRIPT,
+                Some(&crate::protocol::dom_diff::MAX_NODES),
+            )
+            .await?;
+        Ok(crate::protocol::DomSnapshot::from_nodes(
+            serde_json::from_value(value)?,
+        ))
+    }
+
+    /// Runs every read in `batch` with a single round trip.
+    ///
+    /// See [`Batch`](crate::protocol::Batch) for an example.
+    pub async fn run_batch(
+        &self,
+        batch: &crate::protocol::Batch,
+    ) -> Result<crate::protocol::BatchResults> {
+        let value = self
+            .evaluate(
+                crate::protocol::batch::BATCH_SCRIPT,
+                Some(&batch.ops_json()?),
+            )
+            .await?;
+        let values: Vec<Value> = serde_json::from_value(value)?;
+        Ok(crate::protocol::BatchResults::new(values))
+    }
+
     /// Adds a `<style>` tag into the page with the desired content.
     ///
     /// # Arguments
@@ -1164,7 +1266,7 @@ impl Frame {
     /// # Example
     ///
     /// ```no_run
-    /// # use playwright_rs::protocol::{Playwright, AddStyleTagOptions};
+    /// # use playwright_rs::{Playwright, AddStyleTagOptions};
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let playwright = Playwright::launch().await?;
@@ -1172,7 +1274,7 @@ impl Frame {
     /// # let context = browser.new_context().await?;
     /// # let page = context.new_page().await?;
     /// # let frame = page.main_frame().await?;
-    /// use playwright_rs::protocol::AddStyleTagOptions;
+    /// use playwright_rs::AddStyleTagOptions;
     ///
     /// // With inline CSS
     /// frame.add_style_tag(
@@ -1285,9 +1387,27 @@ impl ChannelOwner for Frame {
         self.base.remove_child(guid)
     }
 
-    fn on_event(&self, _method: &str, _params: Value) {
-        // TODO: Handle frame events in future phases
-        // Events: loadstate, navigated, etc.
+    fn on_event(&self, method: &str, params: Value) {
+        if method == "navigated" {
+            if let Some(navigation) = Navigation::from_event(&params) {
+                *self.url.write() = navigation.url.clone();
+                // No subscribers is fine
+                let _ = self.navigations.send(navigation);
+            }
+        }
+        if method == "loadstate" {
+            // Event format: {add: "load"} or {remove: "networkidle"}
+            if let Some(state) = params.get("add").and_then(|v| v.as_str()) {
+                self.load_states.send_modify(|states| {
+                    states.insert(state.to_string());
+                });
+            }
+            if let Some(state) = params.get("remove").and_then(|v| v.as_str()) {
+                self.load_states.send_modify(|states| {
+                    states.remove(state);
+                });
+            }
+        }
     }
 
     fn was_collected(&self) -> bool {
@@ -0,0 +1,182 @@
+// FrameLocator - Locating elements inside iframes
+//
+// A FrameLocator points at an <iframe> and resolves locators inside its
+// document. Like Locator it is lazy: nothing is queried until an action runs,
+// and the iframe is re-resolved each time, so it survives the frame
+// navigating or being re-created. The selector sent to the driver enters the
+// frame with `internal:control=enter-frame`, the same as the other bindings.
+//
+// See: https://playwright.dev/docs/api/class-framelocator
+
+use crate::protocol::locator::FrameRef;
+use crate::protocol::{GetByRoleOptions, Locator, TextMatch};
+
+/// Selector part that switches from the iframe element to its document
+const ENTER_FRAME: &str = "internal:control=enter-frame";
+
+/// Locates elements inside an iframe.
+///
+/// Created with [`Page::frame_locator`](crate::protocol::Page::frame_locator)
+/// or [`Locator::frame_locator`]; iframes can be nested.
+///
+/// # Example
+///
+/// ```ignore
+/// let payments = page.frame_locator("iframe#payments");
+/// payments.get_by_label("Card number", false).fill("4242 4242 4242 4242", None).await?;
+/// payments.get_by_role("button", None).click(None).await?;
+///
+/// // Nested iframes
+/// let widget = page.frame_locator("#outer").frame_locator("#inner");
+/// ```
+///
+/// See: <https://playwright.dev/docs/api/class-framelocator>
+#[derive(Clone)]
+pub struct FrameLocator {
+    frame: FrameRef,
+    /// Selector of the iframe element, in `frame`
+    selector: String,
+}
+
+impl FrameLocator {
+    /// Creates a frame locator for the iframe matching `selector` in `frame`
+    pub(crate) fn new(frame: impl Into<FrameRef>, selector: String) -> Self {
+        Self {
+            frame: frame.into(),
+            selector,
+        }
+    }
+
+    /// Selector of the iframe element
+    pub fn selector(&self) -> &str {
+        &self.selector
+    }
+
+    /// Returns a locator for the iframe element itself.
+    ///
+    /// Use it to act on or assert about the `<iframe>` rather than its
+    /// content. The inverse is [`Locator::content_frame`].
+    ///
+    /// See: <https://playwright.dev/docs/api/class-framelocator#frame-locator-owner>
+    pub fn owner(&self) -> Locator {
+        Locator::new(self.frame.clone(), self.selector.clone())
+    }
+
+    /// Creates a locator for elements inside the iframe.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-framelocator#frame-locator-locator>
+    pub fn locator(&self, selector: &str) -> Locator {
+        Locator::new(
+            self.frame.clone(),
+            format!("{} >> {} >> {}", self.selector, ENTER_FRAME, selector),
+        )
+    }
+
+    /// Locates a nested iframe inside this one.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-framelocator#frame-locator-frame-locator>
+    pub fn frame_locator(&self, selector: &str) -> FrameLocator {
+        FrameLocator::new(
+            self.frame.clone(),
+            format!("{} >> {} >> {}", self.selector, ENTER_FRAME, selector),
+        )
+    }
+
+    /// Narrows to the first matching iframe.
+    pub fn first(&self) -> FrameLocator {
+        self.nth(0)
+    }
+
+    /// Narrows to the last matching iframe.
+    pub fn last(&self) -> FrameLocator {
+        self.nth(-1)
+    }
+
+    /// Narrows to the nth matching iframe (0-indexed, negative from the end).
+    pub fn nth(&self, index: i32) -> FrameLocator {
+        FrameLocator::new(
+            self.frame.clone(),
+            format!("{} >> nth={}", self.selector, index),
+        )
+    }
+
+    /// Locates elements by ARIA role inside the iframe.
+    ///
+    /// See [`Page::get_by_role`](crate::protocol::Page::get_by_role).
+    pub fn get_by_role(
+        &self,
+        role: &str,
+        options: impl crate::protocol::IntoOptions<GetByRoleOptions>,
+    ) -> Locator {
+        let options = options.into_options();
+        self.locator(&crate::protocol::get_by::role_selector(
+            role,
+            options.as_ref(),
+        ))
+    }
+
+    /// Locates elements by their text inside the iframe.
+    ///
+    /// See [`Page::get_by_text`](crate::protocol::Page::get_by_text).
+    pub fn get_by_text(&self, text: impl Into<TextMatch>, exact: bool) -> Locator {
+        self.locator(&crate::protocol::get_by::text_selector(&text.into(), exact))
+    }
+
+    /// Locates form controls by label inside the iframe.
+    ///
+    /// See [`Page::get_by_label`](crate::protocol::Page::get_by_label).
+    pub fn get_by_label(&self, text: impl Into<TextMatch>, exact: bool) -> Locator {
+        self.locator(&crate::protocol::get_by::label_selector(
+            &text.into(),
+            exact,
+        ))
+    }
+
+    /// Locates inputs by placeholder text inside the iframe.
+    ///
+    /// See [`Page::get_by_placeholder`](crate::protocol::Page::get_by_placeholder).
+    pub fn get_by_placeholder(&self, text: impl Into<TextMatch>, exact: bool) -> Locator {
+        self.locator(&crate::protocol::get_by::placeholder_selector(
+            &text.into(),
+            exact,
+        ))
+    }
+
+    /// Locates elements by alt text inside the iframe.
+    ///
+    /// See [`Page::get_by_alt_text`](crate::protocol::Page::get_by_alt_text).
+    pub fn get_by_alt_text(&self, text: impl Into<TextMatch>, exact: bool) -> Locator {
+        self.locator(&crate::protocol::get_by::alt_text_selector(
+            &text.into(),
+            exact,
+        ))
+    }
+
+    /// Locates elements by `title` attribute inside the iframe.
+    ///
+    /// See [`Page::get_by_title`](crate::protocol::Page::get_by_title).
+    pub fn get_by_title(&self, text: impl Into<TextMatch>, exact: bool) -> Locator {
+        self.locator(&crate::protocol::get_by::title_selector(
+            &text.into(),
+            exact,
+        ))
+    }
+
+    /// Locates elements by test id inside the iframe.
+    ///
+    /// See [`Page::get_by_test_id`](crate::protocol::Page::get_by_test_id).
+    pub fn get_by_test_id(&self, test_id: impl Into<TextMatch>) -> Locator {
+        self.locator(&crate::protocol::get_by::test_id_selector(
+            &crate::protocol::selectors::test_id_attribute(),
+            &test_id.into(),
+        ))
+    }
+}
+
+impl std::fmt::Debug for FrameLocator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FrameLocator")
+            .field("selector", &self.selector)
+            .finish()
+    }
+}
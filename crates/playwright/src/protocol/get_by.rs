@@ -0,0 +1,454 @@
+// User-facing locators - get_by_role(), get_by_text() and friends
+//
+// Builds the `internal:*` selectors that Playwright's other bindings generate
+// for their get_by_* methods (see locatorUtils.ts upstream), so Rust locators
+// resolve exactly like JS/Python ones. String matches are case-insensitive
+// substrings unless `exact` is set; regex matches are sent as JS regex literals.
+//
+// See: https://playwright.dev/docs/locators
+
+/// Text to match: a string (substring or exact) or a regular expression.
+///
+/// Strings convert via `From`, and so do [`regex::Regex`] values; a leading
+/// inline flag group such as `(?i)` becomes the JavaScript regex flags.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::TextMatch;
+///
+/// let by_text: TextMatch = "Sign in".into();
+/// let by_regex: TextMatch = regex::Regex::new(r"(?i)^sign\s+in$").unwrap().into();
+/// let explicit = TextMatch::regex(r"^Sign in$", "i");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextMatch {
+    /// Plain text
+    Text(String),
+    /// JavaScript regular expression source and flags
+    Regex {
+        /// Pattern source (without surrounding slashes)
+        pattern: String,
+        /// Flags such as `i`, `m`, `s`
+        flags: String,
+    },
+}
+
+impl TextMatch {
+    /// Creates a regex matcher from JavaScript regex source and flags
+    pub fn regex(pattern: impl Into<String>, flags: impl Into<String>) -> Self {
+        TextMatch::Regex {
+            pattern: pattern.into(),
+            flags: flags.into(),
+        }
+    }
+}
+
+impl From<&str> for TextMatch {
+    fn from(text: &str) -> Self {
+        TextMatch::Text(text.to_string())
+    }
+}
+
+impl From<String> for TextMatch {
+    fn from(text: String) -> Self {
+        TextMatch::Text(text)
+    }
+}
+
+impl From<&String> for TextMatch {
+    fn from(text: &String) -> Self {
+        TextMatch::Text(text.clone())
+    }
+}
+
+#[cfg(feature = "regex")]
+impl From<&regex::Regex> for TextMatch {
+    fn from(regex: &regex::Regex) -> Self {
+        let source = regex.as_str();
+        // Translate a leading inline flag group, e.g. "(?i)", into JS flags
+        if let Some(rest) = source.strip_prefix("(?") {
+            if let Some(end) = rest.find(')') {
+                let flags = &rest[..end];
+                if !flags.is_empty() && flags.chars().all(|c| matches!(c, 'i' | 'm' | 's')) {
+                    return TextMatch::regex(&rest[end + 1..], flags);
+                }
+            }
+        }
+        TextMatch::regex(source, "")
+    }
+}
+
+#[cfg(feature = "regex")]
+impl From<regex::Regex> for TextMatch {
+    fn from(regex: regex::Regex) -> Self {
+        TextMatch::from(&regex)
+    }
+}
+
+/// `/source/flags`, escaped so it can be embedded in a selector
+fn escape_regex_for_selector(pattern: &str, flags: &str) -> String {
+    // JS regex literals escape "/" in the source
+    let mut literal = String::from("/");
+    let mut escaped = false;
+    for c in pattern.chars() {
+        if c == '/' && !escaped {
+            literal.push('\\');
+        }
+        literal.push(c);
+        escaped = c == '\\' && !escaped;
+    }
+    literal.push('/');
+    literal.push_str(flags);
+
+    // Quotes preceded by an even number of backslashes get one more
+    let mut out = String::with_capacity(literal.len());
+    let mut backslashes = 0;
+    for c in literal.chars() {
+        if matches!(c, '"' | '\'' | '`') && backslashes % 2 == 0 {
+            out.push('\\');
+        }
+        backslashes = if c == '\\' { backslashes + 1 } else { 0 };
+        out.push(c);
+    }
+    out.replace(">>", "\\>\\>")
+}
+
+/// Escapes a value for `internal:attr` / role name matching
+pub(crate) fn escape_for_attribute_selector(value: &TextMatch, exact: bool) -> String {
+    match value {
+        TextMatch::Text(text) => format!(
+            "\"{}\"{}",
+            text.replace('\\', "\\\\").replace('"', "\\\""),
+            if exact { "s" } else { "i" }
+        ),
+        TextMatch::Regex { pattern, flags } => escape_regex_for_selector(pattern, flags),
+    }
+}
+
+/// Escapes a value for `internal:text` matching
+pub(crate) fn escape_for_text_selector(value: &TextMatch, exact: bool) -> String {
+    match value {
+        TextMatch::Text(text) => format!(
+            "{}{}",
+            serde_json::Value::String(text.clone()),
+            if exact { "s" } else { "i" }
+        ),
+        TextMatch::Regex { pattern, flags } => escape_regex_for_selector(pattern, flags),
+    }
+}
+
+/// `internal:text=...`
+pub(crate) fn text_selector(text: &TextMatch, exact: bool) -> String {
+    format!("internal:text={}", escape_for_text_selector(text, exact))
+}
+
+/// `internal:label=...`
+pub(crate) fn label_selector(text: &TextMatch, exact: bool) -> String {
+    format!("internal:label={}", escape_for_text_selector(text, exact))
+}
+
+/// `internal:attr=[<name>=...]`, as used by get_by_placeholder() and friends
+fn attribute_text_selector(name: &str, text: &TextMatch, exact: bool) -> String {
+    format!(
+        "internal:attr=[{}={}]",
+        name,
+        escape_for_attribute_selector(text, exact)
+    )
+}
+
+/// `internal:attr=[placeholder=...]`
+pub(crate) fn placeholder_selector(text: &TextMatch, exact: bool) -> String {
+    attribute_text_selector("placeholder", text, exact)
+}
+
+/// `internal:attr=[alt=...]`
+pub(crate) fn alt_text_selector(text: &TextMatch, exact: bool) -> String {
+    attribute_text_selector("alt", text, exact)
+}
+
+/// `internal:attr=[title=...]`
+pub(crate) fn title_selector(text: &TextMatch, exact: bool) -> String {
+    attribute_text_selector("title", text, exact)
+}
+
+/// `internal:testid=[<attribute>="..."s]`; test ids always match exactly
+pub(crate) fn test_id_selector(attribute: &str, test_id: &TextMatch) -> String {
+    format!(
+        "internal:testid=[{}={}]",
+        attribute,
+        escape_for_attribute_selector(test_id, true)
+    )
+}
+
+/// Options for [`Locator::get_by_role`](crate::protocol::Locator::get_by_role).
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::GetByRoleOptions;
+///
+/// let submit = page
+///     .get_by_role("button", Some(GetByRoleOptions::builder().name("Submit").exact(true).build()));
+/// ```
+///
+/// See: <https://playwright.dev/docs/api/class-page#page-get-by-role>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GetByRoleOptions {
+    /// Match the accessible name (substring, case-insensitive unless `exact`)
+    pub name: Option<TextMatch>,
+    /// Match `name` exactly (case-sensitive, whole string)
+    pub exact: Option<bool>,
+    /// `aria-checked` / native checkbox state
+    pub checked: Option<bool>,
+    /// `aria-disabled` / native disabled state
+    pub disabled: Option<bool>,
+    /// `aria-expanded` state
+    pub expanded: Option<bool>,
+    /// Include elements hidden from the accessibility tree
+    pub include_hidden: Option<bool>,
+    /// Heading level (`aria-level` or `<h1>`-`<h6>`)
+    pub level: Option<u32>,
+    /// `aria-pressed` state
+    pub pressed: Option<bool>,
+    /// `aria-selected` state
+    pub selected: Option<bool>,
+}
+
+impl GetByRoleOptions {
+    /// Create a new builder for GetByRoleOptions
+    pub fn builder() -> GetByRoleOptionsBuilder {
+        GetByRoleOptionsBuilder::default()
+    }
+}
+
+/// Builder for GetByRoleOptions
+#[derive(Debug, Clone, Default)]
+pub struct GetByRoleOptionsBuilder {
+    options: GetByRoleOptions,
+}
+
+impl GetByRoleOptionsBuilder {
+    /// Match the accessible name
+    pub fn name(mut self, name: impl Into<TextMatch>) -> Self {
+        self.options.name = Some(name.into());
+        self
+    }
+
+    /// Match the name exactly
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.options.exact = Some(exact);
+        self
+    }
+
+    /// Match the checked state
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.options.checked = Some(checked);
+        self
+    }
+
+    /// Match the disabled state
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.options.disabled = Some(disabled);
+        self
+    }
+
+    /// Match the expanded state
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.options.expanded = Some(expanded);
+        self
+    }
+
+    /// Include hidden elements
+    pub fn include_hidden(mut self, include_hidden: bool) -> Self {
+        self.options.include_hidden = Some(include_hidden);
+        self
+    }
+
+    /// Match the heading level
+    pub fn level(mut self, level: u32) -> Self {
+        self.options.level = Some(level);
+        self
+    }
+
+    /// Match the pressed state
+    pub fn pressed(mut self, pressed: bool) -> Self {
+        self.options.pressed = Some(pressed);
+        self
+    }
+
+    /// Match the selected state
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.options.selected = Some(selected);
+        self
+    }
+
+    /// Build the GetByRoleOptions
+    pub fn build(self) -> GetByRoleOptions {
+        self.options
+    }
+}
+
+/// `internal:role=<role>[prop=value]...`, with properties in upstream order
+pub(crate) fn role_selector(role: &str, options: Option<&GetByRoleOptions>) -> String {
+    let mut selector = format!("internal:role={}", role);
+    let Some(options) = options else {
+        return selector;
+    };
+    let mut prop = |name: &str, value: String| selector.push_str(&format!("[{}={}]", name, value));
+    if let Some(checked) = options.checked {
+        prop("checked", checked.to_string());
+    }
+    if let Some(disabled) = options.disabled {
+        prop("disabled", disabled.to_string());
+    }
+    if let Some(selected) = options.selected {
+        prop("selected", selected.to_string());
+    }
+    if let Some(expanded) = options.expanded {
+        prop("expanded", expanded.to_string());
+    }
+    if let Some(include_hidden) = options.include_hidden {
+        prop("include-hidden", include_hidden.to_string());
+    }
+    if let Some(level) = options.level {
+        prop("level", level.to_string());
+    }
+    if let Some(name) = &options.name {
+        prop(
+            "name",
+            escape_for_attribute_selector(name, options.exact.unwrap_or(false)),
+        );
+    }
+    if let Some(pressed) = options.pressed {
+        prop("pressed", pressed.to_string());
+    }
+    selector
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_selector() {
+        assert_eq!(role_selector("button", None), "internal:role=button");
+        let options = GetByRoleOptions::builder()
+            .name("Submit \"now\"")
+            .exact(true)
+            .checked(false)
+            .level(2)
+            .include_hidden(true)
+            .pressed(true)
+            .build();
+        assert_eq!(
+            role_selector("heading", Some(&options)),
+            r#"internal:role=heading[checked=false][include-hidden=true][level=2][name="Submit \"now\""s][pressed=true]"#
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_role_name_regex() {
+        let options = GetByRoleOptions::builder()
+            .name(regex::Regex::new(r"(?i)^sign/in 'now'$").unwrap())
+            .build();
+        assert_eq!(
+            role_selector("link", Some(&options)),
+            r#"internal:role=link[name=/^sign\/in \'now\'$/i]"#
+        );
+    }
+
+    #[test]
+    fn test_text_selector() {
+        assert_eq!(
+            text_selector(&"Sign in".into(), false),
+            r#"internal:text="Sign in"i"#
+        );
+        assert_eq!(
+            text_selector(&"Say \"hi\"\n".into(), true),
+            r#"internal:text="Say \"hi\"\n"s"#
+        );
+        assert_eq!(
+            text_selector(&TextMatch::regex("^Sign in$", "i"), true),
+            "internal:text=/^Sign in$/i"
+        );
+    }
+
+    #[test]
+    fn test_label_selector() {
+        assert_eq!(
+            label_selector(&"Email".into(), true),
+            r#"internal:label="Email"s"#
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_label_selector_regex() {
+        assert_eq!(
+            label_selector(&regex::Regex::new("(?i)e-?mail").unwrap().into(), false),
+            "internal:label=/e-?mail/i"
+        );
+    }
+
+    #[test]
+    fn test_placeholder_selector() {
+        assert_eq!(
+            placeholder_selector(&"you@example.com".into(), false),
+            r#"internal:attr=[placeholder="you@example.com"i]"#
+        );
+        assert_eq!(
+            placeholder_selector(&TextMatch::regex("^Search", ""), true),
+            "internal:attr=[placeholder=/^Search/]"
+        );
+    }
+
+    #[test]
+    fn test_alt_text_selector() {
+        assert_eq!(
+            alt_text_selector(&"Company \"logo\"".into(), true),
+            r#"internal:attr=[alt="Company \"logo\""s]"#
+        );
+    }
+
+    #[test]
+    fn test_title_selector() {
+        assert_eq!(
+            title_selector(&"Issues count".into(), false),
+            r#"internal:attr=[title="Issues count"i]"#
+        );
+        assert_eq!(
+            title_selector(&TextMatch::regex(r"\d+ issues", "i"), false),
+            r"internal:attr=[title=/\d+ issues/i]"
+        );
+    }
+
+    #[test]
+    fn test_test_id_selector() {
+        assert_eq!(
+            test_id_selector("data-testid", &"submit".into()),
+            r#"internal:testid=[data-testid="submit"s]"#
+        );
+        assert_eq!(
+            test_id_selector("data-qa", &TextMatch::regex("^row-", "")),
+            "internal:testid=[data-qa=/^row-/]"
+        );
+    }
+
+    #[test]
+    fn test_escape_regex_for_selector() {
+        assert_eq!(escape_regex_for_selector("a>>b", ""), r"/a\>\>b/");
+        assert_eq!(escape_regex_for_selector(r#"\"x"#, "g"), r#"/\"x/g"#);
+        assert_eq!(escape_regex_for_selector(r"a\/b", ""), r"/a\/b/");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_text_match_from_regex_without_flags() {
+        assert_eq!(
+            TextMatch::from(regex::Regex::new("^Save$").unwrap()),
+            TextMatch::regex("^Save$", "")
+        );
+    }
+}
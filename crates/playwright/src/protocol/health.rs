@@ -0,0 +1,119 @@
+// Health checks - Liveness/readiness probes and graceful drain
+//
+// Services that embed Playwright in a pod need to answer liveness and
+// readiness probes and to shut down cleanly on preStop. HealthReport is a
+// cheap snapshot (no protocol round trip) of the driver process, its transport
+// and the open browsers; Playwright::drain() stops accepting work, lets
+// in-flight pages finish, then closes browsers and the driver.
+
+use std::time::Duration;
+
+/// Snapshot of driver and browser health, from [`Playwright::health`](crate::protocol::Playwright::health).
+///
+/// # Example
+///
+/// ```ignore
+/// use std::time::Duration;
+///
+/// // GET /livez
+/// let live = playwright.health().await.is_live(Duration::from_secs(30));
+/// // GET /readyz
+/// let ready = playwright.health().await.is_ready(1, Duration::from_secs(30));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthReport {
+    /// The driver process is still running (`None` when not launched by this process)
+    pub driver_running: Option<bool>,
+    /// The connection to the driver is open
+    pub transport_open: bool,
+    /// Protocol calls waiting for a response
+    pub pending_calls: usize,
+    /// Time since the last message was received from the driver
+    pub idle_for: Duration,
+    /// Open browsers
+    pub browsers: usize,
+    /// Open browsers that are still connected
+    pub connected_browsers: usize,
+    /// Open pages across all browsers
+    pub open_pages: usize,
+    /// [`Playwright::drain`](crate::protocol::Playwright::drain) has been called
+    pub draining: bool,
+}
+
+impl HealthReport {
+    /// Liveness: the driver is running and answers calls.
+    ///
+    /// A connection counts as stuck when calls are pending and nothing has
+    /// been received for `stall_after`.
+    pub fn is_live(&self, stall_after: Duration) -> bool {
+        self.driver_running != Some(false)
+            && self.transport_open
+            && !(self.pending_calls > 0 && self.idle_for >= stall_after)
+    }
+
+    /// Readiness: live, not draining, and at least `min_browsers` connected browsers.
+    pub fn is_ready(&self, min_browsers: usize, stall_after: Duration) -> bool {
+        self.is_live(stall_after) && !self.draining && self.connected_browsers >= min_browsers
+    }
+}
+
+/// Outcome of [`Playwright::drain`](crate::protocol::Playwright::drain)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrainReport {
+    /// Time spent waiting for open pages to close
+    pub waited: Duration,
+    /// Pages still open when the grace period ended (closed with their browser)
+    pub abandoned_pages: usize,
+    /// Browsers closed
+    pub browsers_closed: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn healthy() -> HealthReport {
+        HealthReport {
+            driver_running: Some(true),
+            transport_open: true,
+            pending_calls: 0,
+            idle_for: Duration::from_secs(600),
+            browsers: 1,
+            connected_browsers: 1,
+            open_pages: 0,
+            draining: false,
+        }
+    }
+
+    #[test]
+    fn test_idle_connection_is_live() {
+        let stall = Duration::from_secs(30);
+        assert!(healthy().is_live(stall));
+        assert!(healthy().is_ready(1, stall));
+        assert!(!healthy().is_ready(2, stall));
+    }
+
+    #[test]
+    fn test_unhealthy_states() {
+        let stall = Duration::from_secs(30);
+
+        let stuck = HealthReport {
+            pending_calls: 1,
+            ..healthy()
+        };
+        assert!(!stuck.is_live(stall));
+
+        let exited = HealthReport {
+            driver_running: Some(false),
+            ..healthy()
+        };
+        assert!(!exited.is_live(stall));
+
+        let draining = HealthReport {
+            draining: true,
+            ..healthy()
+        };
+        assert!(draining.is_live(stall));
+        assert!(!draining.is_ready(0, stall));
+    }
+}
@@ -0,0 +1,148 @@
+// Heap monitor - Samples JS heap usage over time (Chromium only)
+//
+// Started with Page::monitor_heap(). A background task polls
+// Runtime.getHeapUsage at a fixed interval until stop() is called,
+// giving a time series for spotting leaks in long-lived SPAs.
+
+use crate::error::{Error, Result};
+use crate::protocol::cdp::HeapUsage;
+use crate::protocol::CDPSession;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+/// A single heap usage measurement
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeapSample {
+    /// Time since the monitor started
+    pub elapsed: Duration,
+    /// Heap usage at that time
+    pub usage: HeapUsage,
+}
+
+/// Periodically samples a page's JS heap usage.
+///
+/// Created by [`Page::monitor_heap()`](crate::protocol::Page::monitor_heap).
+/// Sampling stops when [`stop()`](Self::stop) is called or the monitor is dropped.
+///
+/// # Example
+///
+/// ```ignore
+/// let monitor = page.monitor_heap(Duration::from_millis(500)).await?;
+/// for _ in 0..20 {
+///     page.locator("#open-dialog").click(None).await?;
+///     page.locator("#close-dialog").click(None).await?;
+/// }
+/// let samples = monitor.stop().await?;
+/// let growth = HeapMonitor::growth(&samples);
+/// assert!(growth < 5_000_000, "heap grew by {} bytes", growth);
+/// ```
+#[derive(Debug)]
+pub struct HeapMonitor {
+    samples: Arc<Mutex<Vec<HeapSample>>>,
+    error: Arc<Mutex<Option<Error>>>,
+    task: JoinHandle<()>,
+}
+
+impl HeapMonitor {
+    /// Starts sampling on the given session
+    pub(crate) fn start(session: CDPSession, interval: Duration) -> Self {
+        let samples = Arc::new(Mutex::new(Vec::new()));
+        let error = Arc::new(Mutex::new(None));
+
+        let samples_clone = Arc::clone(&samples);
+        let error_clone = Arc::clone(&error);
+        let task = tokio::spawn(async move {
+            let start = Instant::now();
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                match session.heap_profiler().get_heap_usage().await {
+                    Ok(usage) => samples_clone.lock().unwrap().push(HeapSample {
+                        elapsed: start.elapsed(),
+                        usage,
+                    }),
+                    Err(e) => {
+                        // Page closed or session detached; keep what we have
+                        *error_clone.lock().unwrap() = Some(e);
+                        break;
+                    }
+                }
+            }
+        });
+
+        Self {
+            samples,
+            error,
+            task,
+        }
+    }
+
+    /// Returns the samples collected so far
+    pub fn samples(&self) -> Vec<HeapSample> {
+        self.samples.lock().unwrap().clone()
+    }
+
+    /// Stops sampling and returns all samples.
+    ///
+    /// # Errors
+    ///
+    /// Returns the sampling error if sampling failed before any sample was taken.
+    pub async fn stop(self) -> Result<Vec<HeapSample>> {
+        self.task.abort();
+        let samples = self.samples.lock().unwrap().clone();
+        if samples.is_empty() {
+            if let Some(e) = self.error.lock().unwrap().take() {
+                return Err(e);
+            }
+        }
+        Ok(samples)
+    }
+
+    /// Used heap size of the last sample minus the first, in bytes (negative if it shrank)
+    pub fn growth(samples: &[HeapSample]) -> i64 {
+        match (samples.first(), samples.last()) {
+            (Some(first), Some(last)) => last.usage.used_size as i64 - first.usage.used_size as i64,
+            _ => 0,
+        }
+    }
+
+    /// Largest used heap size across the samples, in bytes
+    pub fn peak(samples: &[HeapSample]) -> u64 {
+        samples.iter().map(|s| s.usage.used_size).max().unwrap_or(0)
+    }
+}
+
+impl Drop for HeapMonitor {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(ms: u64, used: u64) -> HeapSample {
+        HeapSample {
+            elapsed: Duration::from_millis(ms),
+            usage: HeapUsage {
+                used_size: used,
+                total_size: used * 2,
+            },
+        }
+    }
+
+    #[test]
+    fn test_growth_and_peak() {
+        let samples = vec![sample(0, 1000), sample(100, 5000), sample(200, 3000)];
+        assert_eq!(HeapMonitor::growth(&samples), 2000);
+        assert_eq!(HeapMonitor::peak(&samples), 5000);
+
+        let shrinking = vec![sample(0, 3000), sample(100, 1000)];
+        assert_eq!(HeapMonitor::growth(&shrinking), -2000);
+
+        assert_eq!(HeapMonitor::growth(&[]), 0);
+        assert_eq!(HeapMonitor::peak(&[]), 0);
+    }
+}
@@ -0,0 +1,202 @@
+// Init scripts - Sources for page.add_init_script() and context.add_init_script()
+//
+// Init scripts can be given inline or loaded from a file. File-based scripts are
+// read once and cached process-wide (keyed by path and modification time), so the
+// same script can be registered on many pages and contexts without re-reading it.
+//
+// Each Page/BrowserContext tracks a content hash of the scripts it has registered
+// and skips duplicate registrations.
+//
+// See: https://playwright.dev/docs/api/class-page#page-add-init-script
+
+use crate::error::{Error, Result};
+use parking_lot::Mutex;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use std::time::SystemTime;
+
+/// Source of an init script.
+///
+/// Strings convert into [`Script::Source`], so existing calls such as
+/// `page.add_init_script("window.x = 1")` keep working.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::Script;
+///
+/// page.add_init_script("window.injected = 123;").await?;
+/// page.add_init_script(Script::Path("./scripts/mock-clock.js".into())).await?;
+/// ```
+///
+/// See: <https://playwright.dev/docs/api/class-page#page-add-init-script>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Script {
+    /// Inline JavaScript source
+    Source(String),
+    /// Path to a JavaScript file, read (and cached) when the script is registered
+    Path(PathBuf),
+}
+
+impl From<&str> for Script {
+    fn from(source: &str) -> Self {
+        Script::Source(source.to_string())
+    }
+}
+
+impl From<String> for Script {
+    fn from(source: String) -> Self {
+        Script::Source(source)
+    }
+}
+
+impl From<&String> for Script {
+    fn from(source: &String) -> Self {
+        Script::Source(source.clone())
+    }
+}
+
+impl From<PathBuf> for Script {
+    fn from(path: PathBuf) -> Self {
+        Script::Path(path)
+    }
+}
+
+impl From<&Path> for Script {
+    fn from(path: &Path) -> Self {
+        Script::Path(path.to_path_buf())
+    }
+}
+
+impl Script {
+    /// Resolves the script to the JavaScript source sent to the server.
+    ///
+    /// File contents get a `//# sourceURL=` suffix so stack traces point at the
+    /// original file, matching playwright-python.
+    pub(crate) async fn resolve(&self) -> Result<Arc<str>> {
+        match self {
+            Script::Source(source) => Ok(Arc::from(source.as_str())),
+            Script::Path(path) => read_cached(path).await,
+        }
+    }
+}
+
+/// Cache key for file-based scripts: path plus last modification time
+type CacheKey = (PathBuf, Option<SystemTime>);
+
+fn file_cache() -> &'static Mutex<HashMap<CacheKey, Arc<str>>> {
+    static CACHE: OnceLock<Mutex<HashMap<CacheKey, Arc<str>>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+async fn read_cached(path: &Path) -> Result<Arc<str>> {
+    let modified = tokio::fs::metadata(path)
+        .await
+        .and_then(|m| m.modified())
+        .ok();
+    let key = (path.to_path_buf(), modified);
+
+    if let Some(source) = file_cache().lock().get(&key) {
+        return Ok(Arc::clone(source));
+    }
+
+    let content = tokio::fs::read_to_string(path).await.map_err(|e| {
+        Error::InvalidArgument(format!(
+            "Failed to read init script '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    let source: Arc<str> = Arc::from(format!(
+        "{}\n//# sourceURL={}",
+        content,
+        path.display().to_string().replace('\n', "")
+    ));
+
+    let mut cache = file_cache().lock();
+    // Drop stale entries for the same path so edits don't accumulate
+    cache.retain(|(cached_path, _), _| cached_path != path);
+    cache.insert(key, Arc::clone(&source));
+    Ok(source)
+}
+
+/// Tracks which init scripts have been registered on a Page or BrowserContext.
+///
+/// Scripts are identified by a hash of their resolved source, so the same file
+/// or inline string registered twice is only sent to the server once.
+#[derive(Debug, Default)]
+pub(crate) struct InitScriptRegistry {
+    hashes: Mutex<HashSet<u64>>,
+}
+
+impl InitScriptRegistry {
+    /// Records a script, returning its hash if it was not registered before.
+    pub(crate) fn insert(&self, source: &str) -> Option<u64> {
+        let hash = content_hash(source);
+        self.hashes.lock().insert(hash).then_some(hash)
+    }
+
+    /// Forgets a script, e.g. after a failed registration
+    pub(crate) fn remove(&self, hash: u64) {
+        self.hashes.lock().remove(&hash);
+    }
+}
+
+fn content_hash(source: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_from_str() {
+        assert_eq!(
+            Script::from("window.x = 1"),
+            Script::Source("window.x = 1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_registry_skips_duplicates() {
+        let registry = InitScriptRegistry::default();
+        let hash = registry.insert("window.x = 1").expect("first insert");
+        assert!(registry.insert("window.x = 1").is_none());
+        assert!(registry.insert("window.y = 2").is_some());
+
+        registry.remove(hash);
+        assert!(registry.insert("window.x = 1").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_path_script_is_read_and_cached() {
+        let path = std::env::temp_dir().join(format!(
+            "playwright_rs_init_script_{}.js",
+            std::process::id()
+        ));
+        std::fs::write(&path, "window.fromFile = true;").unwrap();
+
+        let script = Script::Path(path.clone());
+        let first = script.resolve().await.unwrap();
+        assert!(first.starts_with("window.fromFile = true;"));
+        assert!(first.contains("//# sourceURL="));
+
+        let second = script.resolve().await.unwrap();
+        assert!(Arc::ptr_eq(&first, &second), "second read should hit cache");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_missing_path_is_invalid_argument() {
+        let script = Script::Path(PathBuf::from("/definitely/not/here.js"));
+        let err = script.resolve().await.unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+    }
+}
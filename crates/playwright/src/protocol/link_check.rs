@@ -0,0 +1,263 @@
+// Link checking - Finding broken links on a page
+//
+// Collects the absolute http(s) URLs of all `<a href>` elements on the page,
+// de-duplicates them (ignoring fragments) and checks each with a HEAD request
+// through the context's APIRequestContext, at most `concurrency` at a time.
+// Servers that reject HEAD (405/501) are retried with GET.
+
+use crate::error::{Error, Result};
+use crate::protocol::{APIRequestContext, FetchOptions};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// JavaScript returning the resolved `href` of every anchor
+pub(crate) const LINKS_SCRIPT: &str =
+    "Array.from(document.querySelectorAll('a[href]'), (a) => a.href)";
+
+/// Options for [`Page::check_links`](crate::protocol::Page::check_links).
+#[derive(Debug, Clone)]
+pub struct LinkCheckOptions {
+    /// Maximum number of requests in flight (default: 8)
+    pub concurrency: usize,
+    /// Per-request timeout (default: 10 seconds)
+    pub timeout: Duration,
+    /// Only check links on the page's own origin (default: false)
+    pub same_origin_only: bool,
+}
+
+impl Default for LinkCheckOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 8,
+            timeout: Duration::from_secs(10),
+            same_origin_only: false,
+        }
+    }
+}
+
+impl LinkCheckOptions {
+    /// Creates options with the defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of requests in flight
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Sets the per-request timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Only check links on the page's own origin
+    pub fn same_origin_only(mut self, same_origin_only: bool) -> Self {
+        self.same_origin_only = same_origin_only;
+        self
+    }
+}
+
+/// Outcome of checking one link
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkStatus {
+    /// The server responded with this status code
+    Status(u16),
+    /// The request failed (DNS, connection, TLS, timeout, ...)
+    Failed(String),
+}
+
+/// Result of checking one link
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkCheckResult {
+    /// The checked URL (without fragment)
+    pub url: String,
+    /// Response status or failure reason
+    pub status: LinkStatus,
+}
+
+impl LinkCheckResult {
+    /// True if the request failed or returned a 4xx/5xx status
+    pub fn is_broken(&self) -> bool {
+        match self.status {
+            LinkStatus::Status(code) => code >= 400,
+            LinkStatus::Failed(_) => true,
+        }
+    }
+}
+
+/// Results of [`Page::check_links`](crate::protocol::Page::check_links), in page order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinkReport {
+    /// One result per unique link
+    pub results: Vec<LinkCheckResult>,
+}
+
+impl LinkReport {
+    /// Links that failed or returned a 4xx/5xx status
+    pub fn broken(&self) -> impl Iterator<Item = &LinkCheckResult> {
+        self.results.iter().filter(|r| r.is_broken())
+    }
+
+    /// True if no link is broken
+    pub fn is_ok(&self) -> bool {
+        self.broken().next().is_none()
+    }
+}
+
+/// Filters raw hrefs down to unique, fragment-free http(s) URLs in page order
+pub(crate) fn collect_links(
+    hrefs: Vec<String>,
+    page_url: &str,
+    same_origin_only: bool,
+) -> Vec<String> {
+    let page_origin = origin(page_url);
+    let mut seen = HashSet::new();
+    hrefs
+        .into_iter()
+        .filter(|href| href.starts_with("http://") || href.starts_with("https://"))
+        .map(|href| match href.find('#') {
+            Some(index) => href[..index].to_string(),
+            None => href,
+        })
+        .filter(|url| !same_origin_only || origin(url) == page_origin)
+        .filter(|url| seen.insert(url.clone()))
+        .collect()
+}
+
+/// `scheme://host[:port]` of an absolute URL
+fn origin(url: &str) -> Option<&str> {
+    let rest_start = url.find("://")? + 3;
+    let end = url[rest_start..]
+        .find(['/', '?', '#'])
+        .map(|i| rest_start + i)
+        .unwrap_or(url.len());
+    Some(&url[..end])
+}
+
+pub(crate) async fn check_links(
+    request: APIRequestContext,
+    urls: Vec<String>,
+    options: &LinkCheckOptions,
+) -> Result<LinkReport> {
+    if options.concurrency == 0 {
+        return Err(Error::InvalidArgument(
+            "Link check concurrency must be at least 1".to_string(),
+        ));
+    }
+
+    let semaphore = Arc::new(Semaphore::new(options.concurrency));
+    let mut tasks = JoinSet::new();
+    for (index, url) in urls.into_iter().enumerate() {
+        let request = request.clone();
+        let semaphore = Arc::clone(&semaphore);
+        let timeout = options.timeout;
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let status = check_one(&request, &url, timeout).await;
+            (index, LinkCheckResult { url, status })
+        });
+    }
+
+    let mut results = Vec::with_capacity(tasks.len());
+    while let Some(joined) = tasks.join_next().await {
+        let result =
+            joined.map_err(|e| Error::ProtocolError(format!("Link check task failed: {}", e)))?;
+        results.push(result);
+    }
+    results.sort_by_key(|(index, _)| *index);
+
+    Ok(LinkReport {
+        results: results.into_iter().map(|(_, result)| result).collect(),
+    })
+}
+
+async fn check_one(request: &APIRequestContext, url: &str, timeout: Duration) -> LinkStatus {
+    let options = || FetchOptions::new().timeout(timeout);
+    let head = match request.head(url, Some(options())).await {
+        Ok(response) => response,
+        Err(e) => return LinkStatus::Failed(e.to_string()),
+    };
+    let _ = head.dispose().await;
+
+    // Some servers don't implement HEAD
+    if head.status() == 405 || head.status() == 501 {
+        return match request.get(url, Some(options())).await {
+            Ok(response) => {
+                let _ = response.dispose().await;
+                LinkStatus::Status(response.status())
+            }
+            Err(e) => LinkStatus::Failed(e.to_string()),
+        };
+    }
+    LinkStatus::Status(head.status())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_links() {
+        let hrefs = vec![
+            "https://example.com/a".to_string(),
+            "https://example.com/a#section".to_string(),
+            "mailto:someone@example.com".to_string(),
+            "javascript:void(0)".to_string(),
+            "https://other.org/b".to_string(),
+            "http://example.com:8080/c".to_string(),
+        ];
+        assert_eq!(
+            collect_links(hrefs.clone(), "https://example.com/", false),
+            vec![
+                "https://example.com/a",
+                "https://other.org/b",
+                "http://example.com:8080/c"
+            ]
+        );
+        assert_eq!(
+            collect_links(hrefs, "https://example.com/page", true),
+            vec!["https://example.com/a"]
+        );
+    }
+
+    #[test]
+    fn test_origin() {
+        assert_eq!(
+            origin("https://example.com/a?b"),
+            Some("https://example.com")
+        );
+        assert_eq!(
+            origin("http://localhost:3000"),
+            Some("http://localhost:3000")
+        );
+        assert_eq!(origin("about:blank"), None);
+    }
+
+    #[test]
+    fn test_report_broken() {
+        let report = LinkReport {
+            results: vec![
+                LinkCheckResult {
+                    url: "https://example.com/ok".to_string(),
+                    status: LinkStatus::Status(200),
+                },
+                LinkCheckResult {
+                    url: "https://example.com/missing".to_string(),
+                    status: LinkStatus::Status(404),
+                },
+                LinkCheckResult {
+                    url: "https://nowhere.invalid/".to_string(),
+                    status: LinkStatus::Failed("DNS".to_string()),
+                },
+            ],
+        };
+        assert!(!report.is_ok());
+        assert_eq!(report.broken().count(), 2);
+    }
+}
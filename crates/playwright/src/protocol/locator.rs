@@ -15,9 +15,21 @@
 //
 // See: https://playwright.dev/docs/api/class-locator
 
-use crate::error::Result;
-use crate::protocol::Frame;
+use crate::error::{Error, Result};
+use crate::protocol::{Frame, InputFiles};
+use crate::server::channel_owner::ChannelOwner;
+use crate::server::connection::ConnectionLike;
 use std::sync::Arc;
+use std::time::Duration;
+
+/// Polling interval for [`Locator::first_matching`] and [`Locator::deep`]
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Selector for iframe/frame elements
+const FRAME_SELECTOR: &str = ":is(iframe, frame)";
+
+/// How many levels of nested frames [`Locator::deep`] searches
+const MAX_FRAME_DEPTH: usize = 4;
 
 /// Locator represents a way to find element(s) on the page at any given moment.
 ///
@@ -27,7 +39,7 @@ use std::sync::Arc;
 /// # Examples
 ///
 /// ```ignore
-/// use playwright_rs::protocol::{Playwright, SelectOption};
+/// use playwright_rs::{Playwright, SelectOption};
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -40,7 +52,7 @@ use std::sync::Arc;
 ///         "data:text/html,<input type='checkbox' id='cb'>",
 ///         None
 ///     ).await;
-///     let checkbox = page.locator("#cb").await;
+///     let checkbox = page.locator("#cb");
 ///     checkbox.set_checked(true, None).await?;
 ///     assert!(checkbox.is_checked().await?);
 ///     checkbox.set_checked(false, None).await?;
@@ -55,7 +67,7 @@ use std::sync::Arc;
 ///         </select>",
 ///         None
 ///     ).await;
-///     let select = page.locator("#fruits").await;
+///     let select = page.locator("#fruits");
 ///     select.select_option("banana", None).await?;
 ///     assert_eq!(select.input_value(None).await?, "banana");
 ///     select.select_option(SelectOption::Label("Apple".to_string()), None).await?;
@@ -73,7 +85,7 @@ use std::sync::Arc;
 ///         </select>",
 ///         None
 ///     ).await;
-///     let multi = page.locator("#colors").await;
+///     let multi = page.locator("#colors");
 ///     let selected = multi.select_option_multiple(&["red", "blue"], None).await?;
 ///     assert_eq!(selected.len(), 2);
 ///     assert!(selected.contains(&"red".to_string()));
@@ -84,7 +96,7 @@ use std::sync::Arc;
 ///         "data:text/html,<h1 id='title'>Hello World</h1>",
 ///         None
 ///     ).await;
-///     let heading = page.locator("#title").await;
+///     let heading = page.locator("#title");
 ///     let screenshot = heading.screenshot(None).await?;
 ///     assert!(!screenshot.is_empty());
 ///
@@ -93,19 +105,76 @@ use std::sync::Arc;
 /// }
 /// ```
 ///
+/// # Shadow DOM
+///
+/// Selectors pierce open shadow roots: `page.locator("user-card button")`
+/// matches a button rendered inside `<user-card>`'s shadow tree, and the same
+/// holds for `text=`, `role=`, `internal:label=`, placeholder, alt text, title
+/// and test id selectors. Exceptions are XPath (`xpath=...`), which only sees
+/// the light DOM, and closed shadow roots, which no engine can enter. Use
+/// [`Locator::shadow_root`] to inspect a host's shadow tree directly.
+///
 /// See: <https://playwright.dev/docs/api/class-locator>
 #[derive(Clone)]
 pub struct Locator {
-    frame: Arc<Frame>,
+    frame: FrameRef,
     selector: String,
 }
 
+/// The frame a locator or frame locator resolves in
+///
+/// Locators created from a page refer to its main frame by GUID and look it
+/// up in the object registry when an action runs, so creating one never
+/// fails; the action does if the frame is gone.
+#[derive(Clone)]
+pub(crate) enum FrameRef {
+    Resolved(Arc<Frame>),
+    Lazy {
+        guid: Arc<str>,
+        connection: Arc<dyn ConnectionLike>,
+    },
+}
+
+impl FrameRef {
+    /// GUID of the frame, known without resolving it
+    pub(crate) fn guid(&self) -> &str {
+        match self {
+            FrameRef::Resolved(frame) => frame.guid(),
+            FrameRef::Lazy { guid, .. } => guid,
+        }
+    }
+
+    /// Returns the frame, looking it up if needed
+    pub(crate) fn resolve(&self) -> Result<Arc<Frame>> {
+        match self {
+            FrameRef::Resolved(frame) => Ok(Arc::clone(frame)),
+            FrameRef::Lazy { guid, connection } => connection
+                .find_object(guid)
+                .and_then(|object| object.as_any().downcast_ref::<Frame>().cloned())
+                .map(Arc::new)
+                .ok_or_else(|| Error::TargetClosed {
+                    target_type: "Frame".to_string(),
+                    context: format!("Object not found: {}", guid),
+                }),
+        }
+    }
+}
+
+impl From<Arc<Frame>> for FrameRef {
+    fn from(frame: Arc<Frame>) -> Self {
+        FrameRef::Resolved(frame)
+    }
+}
+
 impl Locator {
     /// Creates a new Locator (internal use only)
     ///
     /// Use `page.locator()` or `frame.locator()` to create locators in application code.
-    pub(crate) fn new(frame: Arc<Frame>, selector: String) -> Self {
-        Self { frame, selector }
+    pub(crate) fn new(frame: impl Into<FrameRef>, selector: String) -> Self {
+        Self {
+            frame: frame.into(),
+            selector,
+        }
     }
 
     /// Returns the selector string for this locator
@@ -113,130 +182,770 @@ impl Locator {
         &self.selector
     }
 
+    /// Returns the frame this locator resolves in
+    pub(crate) fn frame(&self) -> Result<Arc<Frame>> {
+        self.frame.resolve()
+    }
+
+    /// Returns the GUID of the frame this locator resolves in
+    pub(crate) fn frame_guid(&self) -> &str {
+        self.frame.guid()
+    }
+
+    /// Returns the open shadow root of the first matching element.
+    ///
+    /// Returns `Ok(None)` if the element has no shadow root or its root is
+    /// closed. Does not wait for the element to appear.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::ElementNotFound` if nothing matches.
+    pub async fn shadow_root(&self) -> Result<Option<crate::protocol::ShadowRoot>> {
+        crate::protocol::shadow_root::shadow_root(self).await
+    }
+
     /// Creates a locator for the first matching element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-first>
     pub fn first(&self) -> Locator {
-        Locator::new(
-            Arc::clone(&self.frame),
-            format!("{} >> nth=0", self.selector),
-        )
+        Locator::new(self.frame.clone(), format!("{} >> nth=0", self.selector))
     }
 
     /// Creates a locator for the last matching element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-last>
     pub fn last(&self) -> Locator {
-        Locator::new(
-            Arc::clone(&self.frame),
-            format!("{} >> nth=-1", self.selector),
-        )
+        Locator::new(self.frame.clone(), format!("{} >> nth=-1", self.selector))
     }
 
     /// Creates a locator for the nth matching element (0-indexed).
     ///
+    /// Negative indices count from the end: `nth(-1)` is the same as [`last`](Self::last).
+    /// Like every locator, the result is re-resolved on each action, so it
+    /// follows the list as it changes.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let rows = page.locator("table tbody tr");
+    /// assert_eq!(rows.nth(2).text_content().await?.as_deref(), Some("Third"));
+    /// ```
+    ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-nth>
     pub fn nth(&self, index: i32) -> Locator {
         Locator::new(
-            Arc::clone(&self.frame),
+            self.frame.clone(),
             format!("{} >> nth={}", self.selector, index),
         )
     }
 
     /// Creates a sub-locator within this locator's subtree.
     ///
+    /// `selector` may use any selector engine (`css=`, `xpath=`, `text=`, ...)
+    /// and calls can be chained to any depth, which keeps page-object code
+    /// scoped to its component:
+    ///
+    /// ```ignore
+    /// let card = page.locator(".product-card").nth(1);
+    /// let buy = card.locator("css=.actions").locator("button");
+    /// buy.click(None).await?;
+    /// ```
+    ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-locator>
     pub fn locator(&self, selector: &str) -> Locator {
         Locator::new(
-            Arc::clone(&self.frame),
+            self.frame.clone(),
             format!("{} >> {}", self.selector, selector),
         )
     }
 
+    /// Locates an iframe within this locator's subtree, to reach elements inside it.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-frame-locator>
+    pub fn frame_locator(&self, selector: &str) -> crate::protocol::FrameLocator {
+        crate::protocol::FrameLocator::new(
+            self.frame.clone(),
+            format!("{} >> {}", self.selector, selector),
+        )
+    }
+
+    /// Returns a frame locator for the iframe this locator points to.
+    ///
+    /// Useful when an iframe was found with a regular locator, e.g. by role or
+    /// test id, and elements inside it are needed next. The inverse is
+    /// [`FrameLocator::owner`](crate::protocol::FrameLocator::owner).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-content-frame>
+    pub fn content_frame(&self) -> crate::protocol::FrameLocator {
+        crate::protocol::FrameLocator::new(self.frame.clone(), self.selector.clone())
+    }
+
+    /// Narrows this locator to matches that contain (or don't contain) some text
+    /// or inner element.
+    ///
+    /// Filters can be chained; each call appends to the selector.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use playwright_rs::FilterOptions;
+    ///
+    /// // The row that contains "Product 2" and has an "Add to cart" button
+    /// let row = page
+    ///     .locator("li")
+    ///     .filter(FilterOptions::builder().has_text("Product 2").build())
+    ///     .filter(
+    ///         FilterOptions::builder()
+    ///             .has(page.get_by_role("button", None))
+    ///             .build(),
+    ///     );
+    /// row.get_by_role("button", None).click(None).await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-filter>
+    pub fn filter(&self, options: crate::protocol::FilterOptions) -> Locator {
+        for inner in [&options.has, &options.has_not].into_iter().flatten() {
+            self.warn_if_other_frame(inner);
+        }
+        let mut selector = self.selector.clone();
+        for part in options.selector_parts() {
+            selector.push_str(" >> ");
+            selector.push_str(&part);
+        }
+        Locator::new(self.frame.clone(), selector)
+    }
+
+    /// Matches elements that match both this locator and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // A button that also carries the "subscribe" test id
+    /// let subscribe = page
+    ///     .get_by_role("button", None)
+    ///     .and_(&page.get_by_test_id("subscribe"));
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-and>
+    pub fn and_(&self, other: &Locator) -> Locator {
+        self.combine("internal:and", other)
+    }
+
+    /// Matches elements that match this locator, `other`, or both.
+    ///
+    /// Useful when the UI can be in one of several states:
+    ///
+    /// ```ignore
+    /// let new_email = page
+    ///     .get_by_role("button", Some(GetByRoleOptions::builder().name("New").build()));
+    /// let dialog = page.get_by_text("Confirm security settings", false);
+    /// expect(new_email.or_(&dialog).first()).to_be_visible().await?;
+    /// ```
+    ///
+    /// Note that when both match, the result is not strict-mode safe; narrow
+    /// it with [`first`](Self::first).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-or>
+    pub fn or_(&self, other: &Locator) -> Locator {
+        self.combine("internal:or", other)
+    }
+
+    fn combine(&self, engine: &str, other: &Locator) -> Locator {
+        self.warn_if_other_frame(other);
+        Locator::new(
+            self.frame.clone(),
+            format!(
+                "{} >> {}={}",
+                self.selector,
+                engine,
+                crate::protocol::filter::quote_selector(&other.selector)
+            ),
+        )
+    }
+
+    /// Inner locators are resolved in this locator's frame
+    fn warn_if_other_frame(&self, inner: &Locator) {
+        if inner.frame.guid() != self.frame.guid() {
+            tracing::warn!(
+                "Inner locator '{}' belongs to a different frame than '{}'",
+                inner.selector,
+                self.selector
+            );
+        }
+    }
+
+    /// Locates elements by ARIA role, within this locator's subtree.
+    ///
+    /// See [`Page::get_by_role`](crate::protocol::Page::get_by_role).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-get-by-role>
+    pub fn get_by_role(
+        &self,
+        role: &str,
+        options: impl crate::protocol::IntoOptions<crate::protocol::GetByRoleOptions>,
+    ) -> Locator {
+        let options = options.into_options();
+        self.locator(&crate::protocol::get_by::role_selector(
+            role,
+            options.as_ref(),
+        ))
+    }
+
+    /// Locates elements by their text, within this locator's subtree.
+    ///
+    /// See [`Page::get_by_text`](crate::protocol::Page::get_by_text).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-get-by-text>
+    pub fn get_by_text(&self, text: impl Into<crate::protocol::TextMatch>, exact: bool) -> Locator {
+        self.locator(&crate::protocol::get_by::text_selector(&text.into(), exact))
+    }
+
+    /// Locates form controls by label, within this locator's subtree.
+    ///
+    /// See [`Page::get_by_label`](crate::protocol::Page::get_by_label).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-get-by-label>
+    pub fn get_by_label(
+        &self,
+        text: impl Into<crate::protocol::TextMatch>,
+        exact: bool,
+    ) -> Locator {
+        self.locator(&crate::protocol::get_by::label_selector(
+            &text.into(),
+            exact,
+        ))
+    }
+
+    /// Locates inputs by placeholder text, within this locator's subtree.
+    ///
+    /// See [`Page::get_by_placeholder`](crate::protocol::Page::get_by_placeholder).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-get-by-placeholder>
+    pub fn get_by_placeholder(
+        &self,
+        text: impl Into<crate::protocol::TextMatch>,
+        exact: bool,
+    ) -> Locator {
+        self.locator(&crate::protocol::get_by::placeholder_selector(
+            &text.into(),
+            exact,
+        ))
+    }
+
+    /// Locates elements by alt text, within this locator's subtree.
+    ///
+    /// See [`Page::get_by_alt_text`](crate::protocol::Page::get_by_alt_text).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-get-by-alt-text>
+    pub fn get_by_alt_text(
+        &self,
+        text: impl Into<crate::protocol::TextMatch>,
+        exact: bool,
+    ) -> Locator {
+        self.locator(&crate::protocol::get_by::alt_text_selector(
+            &text.into(),
+            exact,
+        ))
+    }
+
+    /// Locates elements by `title` attribute, within this locator's subtree.
+    ///
+    /// See [`Page::get_by_title`](crate::protocol::Page::get_by_title).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-get-by-title>
+    pub fn get_by_title(
+        &self,
+        text: impl Into<crate::protocol::TextMatch>,
+        exact: bool,
+    ) -> Locator {
+        self.locator(&crate::protocol::get_by::title_selector(
+            &text.into(),
+            exact,
+        ))
+    }
+
+    /// Locates elements by test id, within this locator's subtree.
+    ///
+    /// See [`Page::get_by_test_id`](crate::protocol::Page::get_by_test_id).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-get-by-test-id>
+    pub fn get_by_test_id(&self, test_id: impl Into<crate::protocol::TextMatch>) -> Locator {
+        self.locator(&crate::protocol::get_by::test_id_selector(
+            &crate::protocol::selectors::test_id_attribute(),
+            &test_id.into(),
+        ))
+    }
+
+    /// Waits until one of several locator variants has a visible match.
+    ///
+    /// Returns the index of the first variant (in slice order) that became
+    /// visible, together with that locator. Useful for pages served in more than
+    /// one variant, such as A/B-tested frontends:
+    ///
+    /// ```ignore
+    /// let (variant, button) = Locator::first_matching(
+    ///     &[page.locator("#checkout-a"), page.locator("#checkout-b")],
+    ///     None,
+    /// )
+    /// .await?;
+    /// tracing::info!("checkout variant {}", variant);
+    /// button.click(None).await?;
+    /// ```
+    ///
+    /// `timeout` defaults to 30 seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if `variants` is empty, and
+    /// `Error::Timeout` if no variant becomes visible in time.
+    pub async fn first_matching(
+        variants: &[Locator],
+        timeout: Option<Duration>,
+    ) -> Result<(usize, Locator)> {
+        if variants.is_empty() {
+            return Err(Error::InvalidArgument(
+                "first_matching() requires at least one locator".to_string(),
+            ));
+        }
+
+        let timeout = timeout.unwrap_or(Duration::from_millis(crate::DEFAULT_TIMEOUT_MS as u64));
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            for (index, variant) in variants.iter().enumerate() {
+                if variant.first().is_visible().await? {
+                    return Ok((index, variant.clone()));
+                }
+            }
+            if tokio::time::Instant::now() >= deadline {
+                let selectors: Vec<&str> = variants.iter().map(Locator::selector).collect();
+                return Err(Error::Timeout(format!(
+                    "None of {:?} became visible within {:?}",
+                    selectors, timeout
+                )));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Finds this locator's elements even when they live inside iframes.
+    ///
+    /// Searches the document first, then nested frames depth-first (up to 4
+    /// levels, same- or cross-origin), and returns a locator that enters the
+    /// first frame containing a match. The result is still lazy: it
+    /// re-resolves through the same frame path on every action. Open shadow
+    /// roots need no special handling, since Playwright's selector engines
+    /// already pierce them.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // The payment form is rendered inside an embedded widget's iframe
+    /// let card = page.locator("input[name=card-number]").deep(None).await?;
+    /// card.fill("4242 4242 4242 4242", None).await?;
+    /// ```
+    ///
+    /// `timeout` defaults to 30 seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Timeout` if no frame contains a match in time.
+    pub async fn deep(&self, timeout: Option<Duration>) -> Result<Locator> {
+        let timeout = timeout.unwrap_or(Duration::from_millis(crate::DEFAULT_TIMEOUT_MS as u64));
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            if let Some(selector) = self.find_in_frames().await? {
+                return Ok(Locator::new(self.frame.clone(), selector));
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Err(Error::Timeout(format!(
+                    "'{}' did not match in the page or any frame within {:?}",
+                    self.selector, timeout
+                )));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Depth-first search over frame paths; returns the first selector with a match
+    async fn find_in_frames(&self) -> Result<Option<String>> {
+        let frame = self.frame()?;
+        // Each entry is a selector prefix that enters a frame, with its depth
+        let mut stack = vec![(String::new(), 0)];
+        while let Some((prefix, depth)) = stack.pop() {
+            let selector = format!("{}{}", prefix, self.selector);
+            if frame.locator_count(&selector).await? > 0 {
+                return Ok(Some(selector));
+            }
+            if depth == MAX_FRAME_DEPTH {
+                continue;
+            }
+            let frames = frame
+                .locator_count(&format!("{}{}", prefix, FRAME_SELECTOR))
+                .await?;
+            // Push in reverse so the first frame is searched first
+            for index in (0..frames).rev() {
+                stack.push((
+                    format!(
+                        "{}{} >> nth={} >> internal:control=enter-frame >> ",
+                        prefix, FRAME_SELECTOR, index
+                    ),
+                    depth + 1,
+                ));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Explains what this locator matches, for debugging selectors.
+    ///
+    /// Reports the match count of every step of the `>>` chain and previews
+    /// (tag, id, classes, text, visibility) of the first matched elements.
+    /// Does not wait for elements to appear.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let button = page.locator("#checkout >> button.primary");
+    /// if button.count().await? == 0 {
+    ///     eprintln!("{}", button.describe().await?);
+    /// }
+    /// ```
+    pub async fn describe(&self) -> Result<crate::protocol::LocatorDescription> {
+        let frame = self.frame()?;
+        crate::protocol::describe::describe(&frame, &self.selector).await
+    }
+
     /// Returns the number of elements matching this locator.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-count>
     pub async fn count(&self) -> Result<usize> {
-        self.frame.locator_count(&self.selector).await
+        self.frame()?.locator_count(&self.selector).await
+    }
+
+    /// Returns one locator per element currently matching this locator.
+    ///
+    /// The list is a snapshot: locator `i` is `nth(i)`, so if elements are
+    /// added or removed afterwards the locators shift with them. Wait for the
+    /// list to settle (e.g. with an assertion on the count) before calling this.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for item in page.locator("li").all().await? {
+    ///     println!("{:?}", item.text_content().await?);
+    /// }
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-all>
+    pub async fn all(&self) -> Result<Vec<Locator>> {
+        let count = self.count().await?;
+        Ok((0..count).map(|i| self.nth(i as i32)).collect())
+    }
+
+    /// Returns element handles for every element matching this locator.
+    ///
+    /// This is an escape hatch for advanced DOM manipulation; prefer locator methods
+    /// where possible. Each handle is wrapped in an [`ElementHandleGuard`] which
+    /// disposes the handle on the server when dropped, so handles do not leak in the
+    /// driver for the lifetime of the page.
+    ///
+    /// [`ElementHandleGuard`]: crate::protocol::ElementHandleGuard
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-element-handles>
+    pub async fn element_handles(&self) -> Result<Vec<crate::protocol::ElementHandleGuard>> {
+        let handles = self.frame()?.query_selector_all(&self.selector).await?;
+        Ok(handles
+            .into_iter()
+            .map(crate::protocol::ElementHandleGuard::new)
+            .collect())
     }
 
     /// Returns the text content of the element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-text-content>
     pub async fn text_content(&self) -> Result<Option<String>> {
-        self.frame.locator_text_content(&self.selector).await
+        self.frame()?.locator_text_content(&self.selector).await
     }
 
     /// Returns the inner text of the element (visible text).
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-inner-text>
     pub async fn inner_text(&self) -> Result<String> {
-        self.frame.locator_inner_text(&self.selector).await
+        self.frame()?.locator_inner_text(&self.selector).await
+    }
+
+    /// Returns the accessibility tree of the element as a YAML ARIA snapshot.
+    ///
+    /// Requires Playwright driver 1.49 or newer; older drivers fail with
+    /// [`Error::UnsupportedByDriver`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let snapshot = page.locator("nav").aria_snapshot().await?;
+    /// assert!(snapshot.contains("- link \"Home\""));
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-aria-snapshot>
+    pub async fn aria_snapshot(&self) -> Result<String> {
+        let frame = self.frame()?;
+        self.act(&frame, || frame.locator_aria_snapshot(&self.selector))
+            .await
+    }
+
+    /// Walks a paginated list, yielding this locator once per page.
+    ///
+    /// The first page is yielded as is; each further page is reached by
+    /// clicking `next_button` and waiting for this locator's content to change.
+    /// Iteration ends when `next_button` is missing, hidden or disabled.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut pages = page.locator("#results").iter_pages(page.locator("a[rel=next]"));
+    /// let mut titles = Vec::new();
+    /// while let Some(list) = pages.next().await? {
+    ///     titles.extend(list.locator("h2").all_text_contents().await?);
+    /// }
+    /// ```
+    pub fn iter_pages(&self, next_button: Locator) -> crate::protocol::Pages {
+        crate::protocol::Pages::new(self.clone(), next_button)
+    }
+
+    /// Returns the text content of every matching element, in one round-trip.
+    ///
+    /// Elements without text content yield an empty string. Returns an empty
+    /// list if nothing matches; does not wait for elements to appear.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-all-text-contents>
+    pub async fn all_text_contents(&self) -> Result<Vec<String>> {
+        self.eval_texts("elements => elements.map(e => e.textContent || '')")
+            .await
+    }
+
+    /// Returns the visible text of every matching element, in one round-trip.
+    ///
+    /// Returns an empty list if nothing matches; does not wait for elements to
+    /// appear.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let names = page.locator("table tr td:first-child").all_inner_texts().await?;
+    /// assert_eq!(names, ["Ada", "Grace", "Linus"]);
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-all-inner-texts>
+    pub async fn all_inner_texts(&self) -> Result<Vec<String>> {
+        self.eval_texts("elements => elements.map(e => e.innerText)")
+            .await
+    }
+
+    async fn eval_texts(&self, script: &str) -> Result<Vec<String>> {
+        let value = self
+            .frame()?
+            .eval_on_selector_all(&self.selector, script, &())
+            .await?;
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Evaluates a function with the matching element and returns its result.
+    ///
+    /// The function receives the element as its first parameter and `arg`
+    /// (serialized with serde) as its second. The return value is
+    /// deserialized into `R`, so it must be JSON-serializable in the page.
+    /// Strict: fails if the locator matches more than one element.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// #[derive(Deserialize)]
+    /// struct Size { width: f64, height: f64 }
+    ///
+    /// let size: Size = page
+    ///     .locator("#canvas")
+    ///     .evaluate("(el, scale) => ({ width: el.width * scale, height: el.height * scale })", Some(&2))
+    ///     .await?;
+    /// let tag: String = page.locator("h1").evaluate("el => el.tagName", None::<&()>).await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-evaluate>
+    pub async fn evaluate<A: serde::Serialize, R: serde::de::DeserializeOwned>(
+        &self,
+        expression: &str,
+        arg: Option<&A>,
+    ) -> Result<R> {
+        let frame = self.frame()?;
+        let value = self
+            .act(&frame, || {
+                frame.eval_on_selector(&self.selector, expression, &arg)
+            })
+            .await?;
+        serde_json::from_value(value).map_err(Error::from)
+    }
+
+    /// Reads the values of all named controls in the form this locator points to.
+    ///
+    /// Works on a `<form>` or any container of inputs, in a single round trip.
+    /// Checkboxes read as booleans (or, when several share a name, the list
+    /// of checked values), radio groups as the checked value or `null`,
+    /// multi-selects as the list of selected values and other controls as
+    /// their value. Buttons and file inputs are skipped.
+    ///
+    /// See also [`Expectation::to_have_form_values`](crate::assertions::Expectation::to_have_form_values).
+    pub async fn form_values(&self) -> Result<serde_json::Map<String, serde_json::Value>> {
+        self.evaluate(crate::protocol::form::FORM_VALUES_SCRIPT, None::<&()>)
+            .await
+    }
+
+    /// Evaluates a function with every matching element and returns its result.
+    ///
+    /// The function receives the array of matched elements as its first
+    /// parameter and `arg` as its second, in a single round trip. Unlike
+    /// [`evaluate`](Self::evaluate) it is not strict, and an empty match
+    /// passes an empty array rather than failing. Does not wait for elements
+    /// to appear.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let hrefs: Vec<String> = page
+    ///     .locator("nav a")
+    ///     .evaluate_all("links => links.map(a => a.href)", None::<&()>)
+    ///     .await?;
+    ///
+    /// let colors: Vec<String> = page
+    ///     .locator(".badge")
+    ///     .evaluate_all(
+    ///         "(els, prop) => els.map(e => getComputedStyle(e).getPropertyValue(prop))",
+    ///         Some(&"color"),
+    ///     )
+    ///     .await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-evaluate-all>
+    pub async fn evaluate_all<A: serde::Serialize, R: serde::de::DeserializeOwned>(
+        &self,
+        expression: &str,
+        arg: Option<&A>,
+    ) -> Result<R> {
+        let frame = self.frame()?;
+        let value = self
+            .act(&frame, || {
+                frame.eval_on_selector_all(&self.selector, expression, &arg)
+            })
+            .await?;
+        serde_json::from_value(value).map_err(Error::from)
     }
 
     /// Returns the inner HTML of the element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-inner-html>
     pub async fn inner_html(&self) -> Result<String> {
-        self.frame.locator_inner_html(&self.selector).await
+        self.frame()?.locator_inner_html(&self.selector).await
     }
 
     /// Returns the value of the specified attribute.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-get-attribute>
     pub async fn get_attribute(&self, name: &str) -> Result<Option<String>> {
-        self.frame.locator_get_attribute(&self.selector, name).await
+        self.frame()?
+            .locator_get_attribute(&self.selector, name)
+            .await
     }
 
     /// Returns whether the element is visible.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-is-visible>
     pub async fn is_visible(&self) -> Result<bool> {
-        self.frame.locator_is_visible(&self.selector).await
+        self.frame()?.locator_is_visible(&self.selector).await
     }
 
     /// Returns whether the element is enabled.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-is-enabled>
     pub async fn is_enabled(&self) -> Result<bool> {
-        self.frame.locator_is_enabled(&self.selector).await
+        self.frame()?.locator_is_enabled(&self.selector).await
     }
 
     /// Returns whether the checkbox or radio button is checked.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-is-checked>
     pub async fn is_checked(&self) -> Result<bool> {
-        self.frame.locator_is_checked(&self.selector).await
+        self.frame()?.locator_is_checked(&self.selector).await
     }
 
     /// Returns whether the element is editable.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-is-editable>
     pub async fn is_editable(&self) -> Result<bool> {
-        self.frame.locator_is_editable(&self.selector).await
+        self.frame()?.locator_is_editable(&self.selector).await
     }
 
     /// Returns whether the element is focused (currently has focus).
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-is-focused>
     pub async fn is_focused(&self) -> Result<bool> {
-        self.frame.locator_is_focused(&self.selector).await
+        self.frame()?.locator_is_focused(&self.selector).await
     }
 
     // Action methods
 
+    /// Runs an action, retrying it once against the new document if a
+    /// navigation destroyed its execution context and the browser context
+    /// allows it, then attaches diagnostics to a failure
+    async fn act<T, F, Fut>(&self, frame: &Arc<Frame>, action: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        let mut result = action().await;
+        if matches!(&result, Err(e) if is_context_destroyed(e)) && retries_on_navigation(frame) {
+            tracing::debug!(
+                "Execution context of '{}' destroyed by a navigation, retrying",
+                self.selector
+            );
+            // The retry reports its own error if the new document never loads
+            let _ = frame
+                .wait_for_load_state(Some(crate::protocol::WaitUntil::DomContentLoaded), None)
+                .await;
+            result = action().await;
+        }
+        match result {
+            // Attach diagnostics if the context has a `DiagnosticsPolicy`
+            Err(e) => Err(crate::protocol::diagnostics::attach(frame, &self.selector, e).await),
+            ok => ok,
+        }
+    }
+
     /// Clicks the element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-click>
-    pub async fn click(&self, options: Option<crate::protocol::ClickOptions>) -> Result<()> {
-        self.frame.locator_click(&self.selector, options).await
+    pub async fn click(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::ClickOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_click(&self.selector, options.clone())
+        })
+        .await
     }
 
     /// Double clicks the element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-dblclick>
-    pub async fn dblclick(&self, options: Option<crate::protocol::ClickOptions>) -> Result<()> {
-        self.frame.locator_dblclick(&self.selector, options).await
+    pub async fn dblclick(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::ClickOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_dblclick(&self.selector, options.clone())
+        })
+        .await
     }
 
     /// Fills the element with text.
@@ -245,16 +954,29 @@ impl Locator {
     pub async fn fill(
         &self,
         text: &str,
-        options: Option<crate::protocol::FillOptions>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::FillOptions>,
     ) -> Result<()> {
-        self.frame.locator_fill(&self.selector, text, options).await
+        let options = options.into_options();
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_fill(&self.selector, text, options.clone())
+        })
+        .await
     }
 
     /// Clears the element's value.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-clear>
-    pub async fn clear(&self, options: Option<crate::protocol::FillOptions>) -> Result<()> {
-        self.frame.locator_clear(&self.selector, options).await
+    pub async fn clear(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::FillOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_clear(&self.selector, options.clone())
+        })
+        .await
     }
 
     /// Presses a key on the element.
@@ -263,9 +985,14 @@ impl Locator {
     pub async fn press(
         &self,
         key: &str,
-        options: Option<crate::protocol::PressOptions>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::PressOptions>,
     ) -> Result<()> {
-        self.frame.locator_press(&self.selector, key, options).await
+        let options = options.into_options();
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_press(&self.selector, key, options.clone())
+        })
+        .await
     }
 
     /// Ensures the checkbox or radio button is checked.
@@ -273,8 +1000,16 @@ impl Locator {
     /// This method is idempotent - if already checked, does nothing.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-check>
-    pub async fn check(&self, options: Option<crate::protocol::CheckOptions>) -> Result<()> {
-        self.frame.locator_check(&self.selector, options).await
+    pub async fn check(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::CheckOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_check(&self.selector, options.clone())
+        })
+        .await
     }
 
     /// Ensures the checkbox is unchecked.
@@ -282,8 +1017,16 @@ impl Locator {
     /// This method is idempotent - if already unchecked, does nothing.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-uncheck>
-    pub async fn uncheck(&self, options: Option<crate::protocol::CheckOptions>) -> Result<()> {
-        self.frame.locator_uncheck(&self.selector, options).await
+    pub async fn uncheck(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::CheckOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_uncheck(&self.selector, options.clone())
+        })
+        .await
     }
 
     /// Sets the checkbox or radio button to the specified checked state.
@@ -295,8 +1038,9 @@ impl Locator {
     pub async fn set_checked(
         &self,
         checked: bool,
-        options: Option<crate::protocol::CheckOptions>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::CheckOptions>,
     ) -> Result<()> {
+        let options = options.into_options();
         if checked {
             self.check(options).await
         } else {
@@ -304,18 +1048,130 @@ impl Locator {
         }
     }
 
+    /// Drags this element onto `target`.
+    ///
+    /// Moves the mouse to the center of this element (or `source_position`),
+    /// presses the left button, moves to the center of `target` (or
+    /// `target_position`) and releases. Both locators must belong to the same
+    /// frame.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let card = page.get_by_text("Write docs", false);
+    /// card.drag_to(&page.locator("#done"), ()).await?;
+    ///
+    /// // Drop at the top of the column, above the existing cards
+    /// let options = DragToOptions::builder()
+    ///     .target_position(Position { x: 10.0, y: 5.0 })
+    ///     .build();
+    /// card.drag_to(&page.locator("#done"), options).await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-drag-to>
+    pub async fn drag_to(
+        &self,
+        target: &Locator,
+        options: impl crate::protocol::IntoOptions<crate::protocol::DragToOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_drag_to(&self.selector, &target.selector, options.clone())
+        })
+        .await
+    }
+
+    /// Dispatches a synthetic DOM event of `event_type` on the element.
+    ///
+    /// The event is composed, bubbling and cancelable by default; `event_init`
+    /// is any serializable init dictionary (a `serde_json::Value` or a struct)
+    /// merged over those defaults, or `()` for none. Playwright picks the event
+    /// class from the type (`MouseEvent` for `click`, `DragEvent` for
+    /// `dragstart`, ...) and falls back to `Event`, so custom events work too.
+    /// Use this when real input simulation is not possible; prefer
+    /// [`click`](Self::click) and friends otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// button.dispatch_event("click", (), None).await?;
+    /// page.locator("#card")
+    ///     .dispatch_event("dragstart", serde_json::json!({"clientX": 10}), None)
+    ///     .await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-dispatch-event>
+    pub async fn dispatch_event(
+        &self,
+        event_type: &str,
+        event_init: impl serde::Serialize,
+        options: impl crate::protocol::IntoOptions<crate::protocol::DispatchEventOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
+        let event_init = crate::protocol::serialize_argument(&event_init);
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_dispatch_event(
+                &self.selector,
+                event_type,
+                event_init.clone(),
+                options.clone(),
+            )
+        })
+        .await
+    }
+
     /// Hovers the mouse over the element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-hover>
-    pub async fn hover(&self, options: Option<crate::protocol::HoverOptions>) -> Result<()> {
-        self.frame.locator_hover(&self.selector, options).await
+    pub async fn hover(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::HoverOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_hover(&self.selector, options.clone())
+        })
+        .await
+    }
+
+    /// Taps the element with a touch event.
+    ///
+    /// Dispatches `touchstart` and `touchend` at the element's center, or at
+    /// `position`. The page's context must be created with
+    /// [`has_touch(true)`](crate::protocol::BrowserContextOptionsBuilder::has_touch),
+    /// e.g. through a mobile device profile; otherwise the tap fails.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let context = browser
+    ///     .new_context_with_options(BrowserContextOptions::builder().has_touch(true).build())
+    ///     .await?;
+    /// let page = context.new_page().await?;
+    /// page.locator("#menu-toggle").tap(None).await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-tap>
+    pub async fn tap(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::TapOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_tap(&self.selector, options.clone())
+        })
+        .await
     }
 
     /// Returns the value of the input, textarea, or select element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-input-value>
     pub async fn input_value(&self, _options: Option<()>) -> Result<String> {
-        self.frame.locator_input_value(&self.selector).await
+        self.frame()?.locator_input_value(&self.selector).await
     }
 
     /// Selects one or more options in a select element.
@@ -326,11 +1182,15 @@ impl Locator {
     pub async fn select_option(
         &self,
         value: impl Into<crate::protocol::SelectOption>,
-        options: Option<crate::protocol::SelectOptions>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::SelectOptions>,
     ) -> Result<Vec<String>> {
-        self.frame
-            .locator_select_option(&self.selector, value.into(), options)
-            .await
+        let options = options.into_options();
+        let value = value.into();
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_select_option(&self.selector, value.clone(), options.clone())
+        })
+        .await
     }
 
     /// Selects multiple options in a select element.
@@ -341,65 +1201,83 @@ impl Locator {
     pub async fn select_option_multiple(
         &self,
         values: &[impl Into<crate::protocol::SelectOption> + Clone],
-        options: Option<crate::protocol::SelectOptions>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::SelectOptions>,
     ) -> Result<Vec<String>> {
+        let options = options.into_options();
         let select_options: Vec<crate::protocol::SelectOption> =
             values.iter().map(|v| v.clone().into()).collect();
-        self.frame
-            .locator_select_option_multiple(&self.selector, select_options, options)
-            .await
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_select_option_multiple(
+                &self.selector,
+                select_options.clone(),
+                options.clone(),
+            )
+        })
+        .await
     }
 
-    /// Sets the file path(s) to upload to a file input element.
+    /// Sets the files of a file input element, for upload flows.
+    ///
+    /// Accepts one or more paths, or [`FilePayload`](crate::protocol::FilePayload)s
+    /// with explicit contents; an empty list (or [`InputFiles::none`]) clears the
+    /// selection.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let upload = page.locator("input[type=file]");
+    /// upload.set_input_files("fixtures/avatar.png", None).await?;
+    /// upload.set_input_files(["a.txt", "b.txt"], None).await?;
+    /// upload.set_input_files(InputFiles::none(), None).await?;
+    /// ```
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
     pub async fn set_input_files(
         &self,
-        file: &std::path::PathBuf,
-        _options: Option<()>,
+        files: impl Into<InputFiles>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::SetInputFilesOptions>,
     ) -> Result<()> {
-        self.frame
-            .locator_set_input_files(&self.selector, file)
-            .await
+        let options = options.into_options();
+        let files = files.into();
+        let frame = self.frame()?;
+        self.act(&frame, || {
+            frame.locator_set_input_files(&self.selector, &files, options.clone())
+        })
+        .await
     }
 
-    /// Sets multiple file paths to upload to a file input element.
+    /// Returns the element's position and size, or `None` if it is not visible.
     ///
-    /// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
-    pub async fn set_input_files_multiple(
-        &self,
-        files: &[&std::path::PathBuf],
-        _options: Option<()>,
-    ) -> Result<()> {
-        self.frame
-            .locator_set_input_files_multiple(&self.selector, files)
-            .await
-    }
-
-    /// Sets a file to upload using FilePayload (explicit name, mimeType, buffer).
+    /// Coordinates are relative to the main frame viewport, so they can be fed
+    /// straight to `page.mouse()`. Does not wait for the element to appear;
+    /// fails with `ElementNotFound` if nothing matches.
     ///
-    /// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
-    pub async fn set_input_files_payload(
-        &self,
-        file: crate::protocol::FilePayload,
-        _options: Option<()>,
-    ) -> Result<()> {
-        self.frame
-            .locator_set_input_files_payload(&self.selector, file)
-            .await
-    }
-
-    /// Sets multiple files to upload using FilePayload.
+    /// # Example
     ///
-    /// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
-    pub async fn set_input_files_payload_multiple(
-        &self,
-        files: &[crate::protocol::FilePayload],
-        _options: Option<()>,
-    ) -> Result<()> {
-        self.frame
-            .locator_set_input_files_payload_multiple(&self.selector, files)
-            .await
+    /// ```ignore
+    /// let bbox = page.locator("#slider").bounding_box().await?.expect("visible");
+    /// let (x, y) = bbox.center();
+    /// page.mouse().move_to(x as i32, y as i32, None).await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-bounding-box>
+    pub async fn bounding_box(&self) -> Result<Option<crate::protocol::BoundingBox>> {
+        let element = self
+            .frame()?
+            .query_selector(&self.selector)
+            .await?
+            .ok_or_else(|| {
+                crate::error::Error::ElementNotFound(format!(
+                    "Element not found: {}",
+                    self.selector
+                ))
+            })?;
+        let bbox = element.bounding_box().await;
+        if let Err(e) = element.dispose().await {
+            tracing::debug!("Failed to dispose element handle: {}", e);
+        }
+        bbox
     }
 
     /// Takes a screenshot of the element and returns the image bytes.
@@ -410,11 +1288,12 @@ impl Locator {
     /// See: <https://playwright.dev/docs/api/class-locator#locator-screenshot>
     pub async fn screenshot(
         &self,
-        options: Option<crate::protocol::ScreenshotOptions>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::ScreenshotOptions>,
     ) -> Result<Vec<u8>> {
+        let options = options.into_options();
         // Query for the element using strict mode (should return exactly one)
         let element = self
-            .frame
+            .frame()?
             .query_selector(&self.selector)
             .await?
             .ok_or_else(|| {
@@ -427,6 +1306,41 @@ impl Locator {
         // Delegate to ElementHandle.screenshot()
         element.screenshot(options).await
     }
+
+    /// Takes a screenshot of the element, saves it to `path` and returns the bytes.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let options = ScreenshotOptions::builder()
+    ///     .animations(ScreenshotAnimations::Disabled)
+    ///     .mask(vec![page.locator("#cart .updated-at")])
+    ///     .build();
+    /// page.locator("#cart")
+    ///     .screenshot_to_file(Path::new("cart.png"), Some(options))
+    ///     .await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-locator#locator-screenshot>
+    pub async fn screenshot_to_file(
+        &self,
+        path: &std::path::Path,
+        options: impl crate::protocol::IntoOptions<crate::protocol::ScreenshotOptions>,
+    ) -> Result<Vec<u8>> {
+        let bytes = self.screenshot(options).await?;
+
+        tokio::fs::write(path, &bytes).await.map_err(|e| {
+            crate::error::Error::ProtocolError(format!("Failed to write screenshot file: {}", e))
+        })?;
+
+        Ok(bytes)
+    }
+}
+
+impl From<&Locator> for Locator {
+    fn from(locator: &Locator) -> Self {
+        locator.clone()
+    }
 }
 
 impl std::fmt::Debug for Locator {
@@ -436,3 +1350,82 @@ impl std::fmt::Debug for Locator {
             .finish()
     }
 }
+
+/// Whether the action failed because a navigation replaced the document
+fn is_context_destroyed(error: &Error) -> bool {
+    matches!(error.inner(), Error::ProtocolError(message)
+        if message.contains("Execution context was destroyed"))
+}
+
+/// Whether the frame's browser context retries actions interrupted by a navigation
+fn retries_on_navigation(frame: &Frame) -> bool {
+    crate::protocol::diagnostics::owning_context(frame)
+        .is_some_and(|context| context.retries_on_navigation())
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "mock")]
+    #[tokio::test]
+    async fn test_retry_on_navigation() {
+        use crate::mock::MockDriver;
+
+        const DESTROYED: &str =
+            "Execution context was destroyed, most likely because of a navigation";
+        let driver = MockDriver::new();
+        let page = driver.new_page().await.unwrap();
+        let button = page.locator("button");
+
+        driver.clear_calls();
+        driver.fail_once("click", DESTROYED);
+        assert!(button.click(None).await.is_err());
+        assert_eq!(driver.calls_to("click").len(), 1);
+
+        page.context().unwrap().set_retry_on_navigation(true);
+        driver.clear_calls();
+        driver.fail_once("click", DESTROYED);
+        button.click(None).await.unwrap();
+        assert_eq!(driver.calls_to("click").len(), 2);
+
+        // Only once, and only for destroyed contexts
+        driver.clear_calls();
+        driver
+            .fail_once("click", DESTROYED)
+            .fail_once("click", DESTROYED);
+        assert!(button.click(None).await.is_err());
+        driver.fail_once("click", "Element is not attached to the DOM");
+        assert!(button.click(None).await.is_err());
+        assert_eq!(driver.calls_to("click").len(), 3);
+    }
+
+    #[cfg(feature = "mock")]
+    #[tokio::test]
+    async fn test_missing_frame_fails_the_action() {
+        use super::{FrameRef, Locator};
+        use crate::server::channel_owner::ChannelOwner;
+
+        let driver = crate::mock::MockDriver::new();
+        let page = driver.new_page().await.unwrap();
+        driver.clear_calls();
+
+        // Creating the locator does not need the frame
+        let frame = FrameRef::Lazy {
+            guid: std::sync::Arc::from("frame@gone"),
+            connection: page.connection(),
+        };
+        let button = Locator::new(frame, "button".to_string()).first();
+        assert_eq!(button.frame_guid(), "frame@gone");
+
+        let result = button.click(None).await;
+        assert!(
+            matches!(result, Err(crate::Error::TargetClosed { .. })),
+            "{:?}",
+            result
+        );
+        assert!(driver.calls_to("click").is_empty());
+
+        // Page locators resolve the live main frame
+        page.locator("button").click(None).await.unwrap();
+        assert_eq!(driver.calls_to("click").len(), 1);
+    }
+}
@@ -12,56 +12,137 @@
 // - Objects communicate with the server via their Channel
 
 pub mod action_options;
+#[cfg(feature = "api-request")]
+pub mod api_request_context;
 pub mod artifact;
+pub mod batch;
 pub mod browser;
 pub mod browser_context;
 pub mod browser_type;
+pub mod capabilities;
+pub mod cdp;
+pub mod cdp_session;
 pub mod click;
+pub mod clock;
+pub mod component_testing;
+pub mod context_pool;
+pub mod crash;
+pub mod describe;
+pub mod determinism;
+pub mod diagnostics;
 pub mod dialog;
+pub mod dom_diff;
 pub mod download;
 pub mod element_handle;
+#[doc(hidden)]
 pub mod evaluate_conversion;
+pub mod expect_request;
+pub mod extract;
 pub mod file_payload;
+pub mod filter;
+pub mod form;
 pub mod frame;
+pub mod frame_locator;
+pub mod get_by;
+pub mod health;
+pub mod heap_monitor;
+pub mod init_script;
 pub mod keyboard;
+#[cfg(feature = "api-request")]
+pub mod link_check;
 pub mod locator;
 pub mod mouse;
+pub mod navigation;
+pub mod options;
 pub mod page;
+pub mod pagination;
 pub mod playwright;
+pub mod readiness;
 pub mod request;
 pub mod response;
+#[doc(hidden)]
 pub mod root;
 pub mod route;
 pub mod screenshot;
 pub mod select_option;
+pub mod selectors;
+pub mod session_manager;
+pub mod session_recording;
+pub mod shadow_root;
+pub mod stability;
+pub mod stats;
+#[cfg(feature = "trace")]
+pub mod trace;
+pub mod wasm;
 
 pub use action_options::{
-    CheckOptions, FillOptions, HoverOptions, KeyboardOptions, MouseOptions, PressOptions,
-    SelectOptions,
+    CheckOptions, DispatchEventOptions, DragToOptions, FillOptions, HoverOptions, KeyboardOptions,
+    MouseOptions, PressOptions, SelectOptions, SetInputFilesOptions, TapOptions,
 };
+#[cfg(feature = "api-request")]
+pub use api_request_context::{APIRequestContext, APIResponse, FetchOptions};
+pub use batch::{Batch, BatchKey, BatchResults};
 pub use browser::Browser;
+#[cfg(feature = "video")]
+pub use browser_context::RecordVideo;
 pub use browser_context::{
     BrowserContext, BrowserContextOptions, BrowserContextOptionsBuilder, Cookie, Geolocation,
     LocalStorageItem, Origin, StorageState, Viewport,
 };
 pub use browser_type::BrowserType;
+pub use capabilities::{Capability, DriverCapabilities, DriverVersion};
+pub use cdp::NetworkConditions;
+pub use cdp_session::CDPSession;
 pub use click::{ClickOptions, KeyboardModifier, MouseButton, Position};
+pub use clock::Clock;
+pub use component_testing::{ComponentDescriptor, ComponentTesting, DevServer, DevServerCommand};
+pub use context_pool::{ContextPool, PooledContext, RecyclePolicy};
+pub use crash::{CrashKind, CrashReport};
+pub use describe::{ElementPreview, LocatorDescription, SelectorStep};
+pub use determinism::Determinism;
+pub use diagnostics::{Diagnostics, DiagnosticsPolicy};
 pub use dialog::Dialog;
+pub use dom_diff::{DomDiff, DomNode, DomSnapshot, NodeChange};
 pub use download::Download;
-pub use element_handle::ElementHandle;
+pub use element_handle::{BoundingBox, ElementHandle, ElementHandleGuard};
+#[doc(hidden)]
 pub use evaluate_conversion::{parse_result, parse_value, serialize_argument, serialize_null};
-pub use file_payload::{FilePayload, FilePayloadBuilder};
+pub use expect_request::{ExpectRequest, NavigationWithRequest};
+pub use extract::{ExtractSchema, Field};
+pub use file_payload::{FilePayload, FilePayloadBuilder, InputFiles};
+pub use filter::{FilterOptions, FilterOptionsBuilder};
+pub use form::{FormMapping, FormTarget};
 pub use frame::Frame;
+pub use frame_locator::FrameLocator;
+pub use get_by::{GetByRoleOptions, GetByRoleOptionsBuilder, TextMatch};
+pub use health::{DrainReport, HealthReport};
+pub use heap_monitor::{HeapMonitor, HeapSample};
+pub use init_script::Script;
 pub use keyboard::Keyboard;
+#[cfg(feature = "api-request")]
+pub use link_check::{LinkCheckOptions, LinkCheckResult, LinkReport, LinkStatus};
 pub use locator::Locator;
 pub use mouse::Mouse;
+pub use navigation::{Navigation, NavigationKind};
+pub use options::IntoOptions;
 pub use page::{AddStyleTagOptions, GotoOptions, Page, Response, WaitUntil};
+pub use pagination::Pages;
 pub use playwright::Playwright;
 pub use request::Request;
 pub use response::ResponseObject;
+#[doc(hidden)]
 pub use root::Root;
 pub use route::{
     ContinueOptions, ContinueOptionsBuilder, FulfillOptions, FulfillOptionsBuilder, Route,
 };
-pub use screenshot::{ScreenshotClip, ScreenshotOptions, ScreenshotType};
+pub use screenshot::{ScreenshotAnimations, ScreenshotClip, ScreenshotOptions, ScreenshotType};
 pub use select_option::SelectOption;
+pub use selectors::Selectors;
+pub use session_manager::SessionManager;
+pub use session_recording::{RecordedSession, ReplayOptions, ReplayReport, SessionEntry};
+pub use shadow_root::ShadowRoot;
+pub use stability::StabilityOptions;
+pub use stats::PlaywrightStats;
+#[cfg(feature = "trace")]
+pub use trace::{Tracing, TracingStartOptions, TracingStartOptionsBuilder};
+pub use wasm::{Hydration, PanicKind, WasmPanic};
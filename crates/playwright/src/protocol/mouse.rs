@@ -28,8 +28,9 @@ impl Mouse {
         &self,
         x: i32,
         y: i32,
-        options: Option<crate::protocol::MouseOptions>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::MouseOptions>,
     ) -> Result<()> {
+        let options = options.into_options();
         self.page.mouse_move(x, y, options).await
     }
 
@@ -40,8 +41,9 @@ impl Mouse {
         &self,
         x: i32,
         y: i32,
-        options: Option<crate::protocol::MouseOptions>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::MouseOptions>,
     ) -> Result<()> {
+        let options = options.into_options();
         self.page.mouse_click(x, y, options).await
     }
 
@@ -52,22 +54,31 @@ impl Mouse {
         &self,
         x: i32,
         y: i32,
-        options: Option<crate::protocol::MouseOptions>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::MouseOptions>,
     ) -> Result<()> {
+        let options = options.into_options();
         self.page.mouse_dblclick(x, y, options).await
     }
 
     /// Dispatches a `mousedown` event.
     ///
     /// See: <https://playwright.dev/docs/api/class-mouse#mouse-down>
-    pub async fn down(&self, options: Option<crate::protocol::MouseOptions>) -> Result<()> {
+    pub async fn down(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::MouseOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
         self.page.mouse_down(options).await
     }
 
     /// Dispatches a `mouseup` event.
     ///
     /// See: <https://playwright.dev/docs/api/class-mouse#mouse-up>
-    pub async fn up(&self, options: Option<crate::protocol::MouseOptions>) -> Result<()> {
+    pub async fn up(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::MouseOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
         self.page.mouse_up(options).await
     }
 
@@ -0,0 +1,79 @@
+// Navigation - Committed frame navigations
+//
+// The driver reports every committed navigation of a frame with a "navigated"
+// event. Navigations that load a new document carry `newDocument`; same-document
+// navigations (history.pushState/replaceState, fragment changes) do not. SPAs
+// change routes with the latter, so they are surfaced separately through
+// Page::on_url_changed and Page::expect_url_change.
+
+use serde_json::Value;
+
+/// How a frame navigated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NavigationKind {
+    /// A new document was loaded
+    Document,
+    /// The URL changed without loading a new document (`history.pushState`,
+    /// `history.replaceState` or a fragment change)
+    SameDocument,
+}
+
+/// A committed navigation of a frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Navigation {
+    /// URL after the navigation
+    pub url: String,
+    /// Whether a new document was loaded
+    pub kind: NavigationKind,
+}
+
+impl Navigation {
+    /// Whether this was a soft (same-document) navigation
+    pub fn is_same_document(&self) -> bool {
+        self.kind == NavigationKind::SameDocument
+    }
+
+    /// Parses a "navigated" event; `None` for failed navigations
+    pub(crate) fn from_event(params: &Value) -> Option<Self> {
+        if params.get("error").is_some() {
+            return None;
+        }
+        Some(Self {
+            url: params.get("url")?.as_str()?.to_string(),
+            kind: if params.get("newDocument").is_some() {
+                NavigationKind::Document
+            } else {
+                NavigationKind::SameDocument
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_navigation_from_event() {
+        let full = Navigation::from_event(&json!({
+            "url": "https://example.com/",
+            "name": "",
+            "newDocument": {"request": {"guid": "request@1"}}
+        }))
+        .unwrap();
+        assert_eq!(full.kind, NavigationKind::Document);
+        assert_eq!(full.url, "https://example.com/");
+
+        let soft = Navigation::from_event(&json!({"url": "https://example.com/cart", "name": ""}))
+            .unwrap();
+        assert!(soft.is_same_document());
+
+        let failed = Navigation::from_event(&json!({
+            "url": "https://example.com/",
+            "name": "",
+            "error": "net::ERR_ABORTED"
+        }));
+        assert!(failed.is_none());
+    }
+}
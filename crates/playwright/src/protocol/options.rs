@@ -0,0 +1,316 @@
+// Options arguments
+//
+// Actions take their options as `impl IntoOptions<XOptions>` rather than
+// `Option<XOptions>`, so call sites can pass the options directly, `None`,
+// `()` for the defaults, or an `Option` they already hold. The trait is sealed:
+// it is implemented for every options type in this module and nothing else.
+//
+// Options structs sent to the driver as-is are declared with `options!`, which
+// generates the struct, its builder and the protocol JSON, so adding a field
+// is a one-line change that cannot drift between the three.
+//
+// Options structs not declared with `options!`, and why:
+// - Never sent to the driver, so there is no protocol JSON to keep in sync:
+//   `GetByRoleOptions` and `FilterOptions` (compiled into selectors),
+//   `StabilityOptions`, `LinkCheckOptions` and `ReplayOptions` (used by
+//   client-side loops, with non-optional fields and their own defaults) and
+//   `cdp::TracingStartOptions` (sent over CDP, not the Playwright protocol).
+// - Encoded field by field for the driver: `GotoOptions` (`wait_for_ready` is
+//   handled client-side, `wait_until` is sent as a lifecycle event name),
+//   `AddStyleTagOptions` (files are read client-side), `ContinueOptions` and
+//   `FulfillOptions` (headers as name/value arrays, base64 bodies),
+//   `FetchOptions` (form and multipart bodies), and `LaunchOptions` and
+//   `BrowserContextOptions` (nested structures and per-browser defaults).
+
+use crate::protocol::{
+    CheckOptions, ClickOptions, ContinueOptions, DispatchEventOptions, DragToOptions, FillOptions,
+    FulfillOptions, GetByRoleOptions, GotoOptions, HoverOptions, KeyboardOptions, MouseOptions,
+    PressOptions, ScreenshotOptions, SelectOptions, SetInputFilesOptions, StabilityOptions,
+    TapOptions,
+};
+
+/// Declares a protocol options struct and its builder.
+///
+/// Every field becomes `pub field: Option<T>` with a builder setter taking `T`,
+/// or `impl Into<T>` for fields marked `#[builder(into)]`. `to_json()`
+/// serializes the set fields with camelCase keys, after filling in the
+/// `= default` of fields that have one. Fields accept `#[serde(...)]`
+/// attributes after their docs, e.g. to rename a key or to send a `Duration`
+/// as protocol milliseconds. Attributes on the struct, such as docs or extra
+/// derives, are kept:
+///
+/// ```ignore
+/// options! {
+///     /// Fill options
+///     pub struct FillOptions => FillOptionsBuilder {
+///         /// Whether to bypass actionability checks
+///         pub force: bool,
+///         /// Maximum time
+///         #[serde(with = "crate::protocol::options::millis")]
+///         pub timeout: Duration = crate::DEFAULT_TIMEOUT,
+///         /// Name shown in traces
+///         #[builder(into)]
+///         pub title: String,
+///     }
+/// }
+/// ```
+macro_rules! options {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident => $builder:ident {
+            $(
+                $(#[doc = $field_doc:expr])*
+                $(#[builder($setter:ident)])?
+                $(#[serde($($serde:tt)*)])*
+                pub $field:ident: $ty:ty $(= $default:expr)?,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, serde::Serialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct $name {
+            $(
+                $(#[doc = $field_doc])*
+                #[serde(skip_serializing_if = "Option::is_none")]
+                $(#[serde($($serde)*)])*
+                pub $field: Option<$ty>,
+            )*
+        }
+
+        impl $name {
+            #[doc = concat!("Create a new builder for ", stringify!($name))]
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+
+            /// Convert options to JSON value for protocol, with defaults filled in
+            pub(crate) fn to_json(&self) -> serde_json::Value {
+                #[allow(unused_mut)]
+                let mut options = self.clone();
+                $($(
+                    if options.$field.is_none() {
+                        options.$field = Some($default);
+                    }
+                )?)*
+                serde_json::to_value(&options).expect("options serialize to JSON")
+            }
+        }
+
+        #[doc = concat!("Builder for ", stringify!($name))]
+        #[derive(Debug, Clone, Default)]
+        pub struct $builder {
+            $($field: Option<$ty>,)*
+        }
+
+        impl $builder {
+            $(
+                $crate::protocol::options::options_setter! {
+                    [$($setter)?] $(#[doc = $field_doc])* $field: $ty
+                }
+            )*
+
+            #[doc = concat!("Build the ", stringify!($name))]
+            pub fn build(self) -> $name {
+                $name {
+                    $($field: self.$field,)*
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use options;
+
+/// Builder setter generated by `options!`, taking `impl Into<T>` for `[into]`
+macro_rules! options_setter {
+    ([] $(#[doc = $doc:expr])* $field:ident: $ty:ty) => {
+        $(#[doc = $doc])*
+        pub fn $field(mut self, $field: $ty) -> Self {
+            self.$field = Some($field);
+            self
+        }
+    };
+    ([into] $(#[doc = $doc:expr])* $field:ident: $ty:ty) => {
+        $(#[doc = $doc])*
+        pub fn $field(mut self, $field: impl Into<$ty>) -> Self {
+            self.$field = Some($field.into());
+            self
+        }
+    };
+}
+
+pub(crate) use options_setter;
+
+/// Serde conversion of `Option<Duration>` to and from protocol milliseconds
+pub(crate) mod millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<Duration>,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match value {
+            Some(duration) => serializer.serialize_f64(duration.as_secs_f64() * 1000.0),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Option<Duration>, D::Error> {
+        Option::<f64>::deserialize(deserializer)?
+            .map(|ms| Duration::try_from_secs_f64(ms / 1000.0).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+mod sealed {
+    pub trait Sealed<T> {}
+}
+
+/// Conversion into optional action options.
+///
+/// Implemented for `T`, `Option<T>` and `()` for every options type `T`:
+///
+/// ```ignore
+/// locator.click(()).await?;
+/// locator.click(None).await?;
+/// locator.click(ClickOptions::builder().force(true).build()).await?;
+/// locator.click(Some(ClickOptions::builder().force(true).build())).await?;
+/// ```
+pub trait IntoOptions<T>: sealed::Sealed<T> {
+    /// Returns the options, or `None` for the defaults
+    fn into_options(self) -> Option<T>;
+}
+
+macro_rules! into_options {
+    ($($options:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed<$options> for $options {}
+            impl sealed::Sealed<$options> for Option<$options> {}
+            impl sealed::Sealed<$options> for () {}
+
+            impl IntoOptions<$options> for $options {
+                fn into_options(self) -> Option<$options> {
+                    Some(self)
+                }
+            }
+
+            impl IntoOptions<$options> for Option<$options> {
+                fn into_options(self) -> Option<$options> {
+                    self
+                }
+            }
+
+            impl IntoOptions<$options> for () {
+                fn into_options(self) -> Option<$options> {
+                    None
+                }
+            }
+        )*
+    };
+}
+
+into_options!(
+    CheckOptions,
+    ClickOptions,
+    ContinueOptions,
+    DispatchEventOptions,
+    DragToOptions,
+    FillOptions,
+    FulfillOptions,
+    GetByRoleOptions,
+    GotoOptions,
+    HoverOptions,
+    KeyboardOptions,
+    MouseOptions,
+    PressOptions,
+    ScreenshotOptions,
+    SelectOptions,
+    SetInputFilesOptions,
+    StabilityOptions,
+    TapOptions,
+);
+
+#[cfg(feature = "api-request")]
+into_options!(
+    crate::protocol::FetchOptions,
+    crate::protocol::LinkCheckOptions
+);
+
+#[cfg(feature = "trace")]
+into_options!(crate::protocol::TracingStartOptions);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    options! {
+        /// Options exercising the macro
+        pub struct ExampleOptions => ExampleOptionsBuilder {
+            /// Renamed key
+            #[serde(rename = "type")]
+            pub kind: String,
+            /// camelCase key
+            pub no_wait_after: bool,
+            /// Setter taking `impl Into<String>`
+            #[builder(into)]
+            pub title: String,
+            /// Filled in when unset, sent as milliseconds
+            #[serde(with = "millis")]
+            pub timeout: Duration = Duration::from_secs(1),
+        }
+    }
+
+    #[test]
+    fn test_options_macro() {
+        assert_eq!(
+            ExampleOptions::default().to_json(),
+            serde_json::json!({"timeout": 1000.0})
+        );
+
+        let options = ExampleOptions::builder()
+            .kind("png".to_string())
+            .no_wait_after(true)
+            .title("checkout")
+            .timeout(Duration::from_millis(50))
+            .build();
+        assert_eq!(options.kind.as_deref(), Some("png"));
+        assert_eq!(
+            options.to_json(),
+            serde_json::json!({"type": "png", "noWaitAfter": true, "title": "checkout", "timeout": 50.0})
+        );
+    }
+
+    #[test]
+    fn test_millis_round_trip() {
+        #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+        struct Timeout {
+            #[serde(default, with = "millis")]
+            timeout: Option<Duration>,
+        }
+
+        let json = serde_json::json!({"timeout": 1500.0});
+        let parsed: Timeout = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(parsed.timeout, Some(Duration::from_millis(1500)));
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), json);
+        assert!(serde_json::from_value::<Timeout>(serde_json::json!({"timeout": -1.0})).is_err());
+    }
+
+    fn timeout(options: impl IntoOptions<FillOptions>) -> Option<Duration> {
+        options.into_options().and_then(|o| o.timeout)
+    }
+
+    #[test]
+    fn test_into_options() {
+        let half_second = Duration::from_millis(500);
+        let options = FillOptions::builder().timeout(half_second).build();
+        assert_eq!(timeout(options.clone()), Some(half_second));
+        assert_eq!(timeout(Some(options)), Some(half_second));
+        assert_eq!(timeout(None), None);
+        assert_eq!(timeout(()), None);
+    }
+}
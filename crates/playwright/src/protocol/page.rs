@@ -4,7 +4,12 @@
 // Pages are isolated tabs or windows within a context.
 
 use crate::error::{Error, Result};
-use crate::protocol::{Dialog, Download, Route};
+use crate::protocol::cdp::HeapUsage;
+use crate::protocol::init_script::InitScriptRegistry;
+use crate::protocol::{
+    CDPSession, Dialog, Download, ExpectRequest, HeapMonitor, Navigation, NavigationWithRequest,
+    NetworkConditions, Request, Route, Script,
+};
 use crate::server::channel::Channel;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
 use base64::Engine;
@@ -14,6 +19,7 @@ use std::any::Any;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::{Arc, Mutex, RwLock};
+use tokio::sync::broadcast;
 
 /// Page represents a web page within a browser context.
 ///
@@ -26,7 +32,7 @@ use std::sync::{Arc, Mutex, RwLock};
 /// # Example
 ///
 /// ```ignore
-/// use playwright_rs::protocol::{Playwright, ScreenshotOptions, ScreenshotType, AddStyleTagOptions};
+/// use playwright_rs::{Playwright, ScreenshotOptions, ScreenshotType, AddStyleTagOptions};
 /// use std::path::PathBuf;
 ///
 /// #[tokio::main]
@@ -59,7 +65,7 @@ use std::sync::{Arc, Mutex, RwLock};
 ///     assert_eq!(title, "Test Page");
 ///
 ///     // Demonstrate locator()
-///     let heading = page.locator("#heading").await;
+///     let heading = page.locator("#heading");
 ///     let text = heading.text_content().await?;
 ///     assert_eq!(text, Some("Hello World".to_string()));
 ///
@@ -143,6 +149,12 @@ pub struct Page {
     download_handlers: Arc<Mutex<Vec<DownloadHandler>>>,
     /// Dialog event handlers
     dialog_handlers: Arc<Mutex<Vec<DialogHandler>>>,
+    /// Same-document navigation handlers
+    url_changed_handlers: Arc<Mutex<Vec<UrlChangedHandler>>>,
+    /// Content hashes of registered init scripts
+    init_scripts: Arc<InitScriptRegistry>,
+    /// Lazily created CDP session for Chromium-only helpers (network emulation, metrics)
+    cdp_session: Arc<tokio::sync::Mutex<Option<CDPSession>>>,
 }
 
 /// Type alias for boxed route handler future
@@ -154,6 +166,9 @@ type DownloadHandlerFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
 /// Type alias for boxed dialog handler future
 type DialogHandlerFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
 
+/// Type alias for boxed URL change handler future
+type UrlChangedHandlerFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
+
 /// Storage for a single route handler
 #[derive(Clone)]
 struct RouteHandlerEntry {
@@ -167,6 +182,9 @@ type DownloadHandler = Arc<dyn Fn(Download) -> DownloadHandlerFuture + Send + Sy
 /// Dialog event handler
 type DialogHandler = Arc<dyn Fn(Dialog) -> DialogHandlerFuture + Send + Sync>;
 
+/// URL change event handler
+type UrlChangedHandler = Arc<dyn Fn(Navigation) -> UrlChangedHandlerFuture + Send + Sync>;
+
 impl Page {
     /// Creates a new Page from protocol initialization
     ///
@@ -221,6 +239,9 @@ impl Page {
             route_handlers,
             download_handlers,
             dialog_handlers,
+            url_changed_handlers: Arc::new(Mutex::new(Vec::new())),
+            init_scripts: Arc::new(InitScriptRegistry::default()),
+            cdp_session: Arc::new(tokio::sync::Mutex::new(None)),
         })
     }
 
@@ -252,13 +273,27 @@ impl Page {
         Ok(frame.clone())
     }
 
+    /// Refers to the main frame for locators, which resolve it when they act
+    fn main_frame_ref(&self) -> crate::protocol::locator::FrameRef {
+        crate::protocol::locator::FrameRef::Lazy {
+            guid: Arc::clone(&self.main_frame_guid),
+            connection: self.connection(),
+        }
+    }
+
     /// Returns the current URL of the page.
     ///
     /// This returns the last committed URL. Initially, pages are at "about:blank".
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-url>
     pub fn url(&self) -> String {
-        // Return a clone of the current URL
+        // The main frame tracks every committed navigation, including
+        // same-document ones
+        if let Some(frame) = self.connection().find_object(&self.main_frame_guid) {
+            if let Some(frame) = frame.as_any().downcast_ref::<crate::protocol::Frame>() {
+                return frame.url();
+            }
+        }
         self.url.read().unwrap().clone()
     }
 
@@ -299,7 +334,16 @@ impl Page {
     /// - Network error
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-goto>
-    pub async fn goto(&self, url: &str, options: Option<GotoOptions>) -> Result<Option<Response>> {
+    pub async fn goto(
+        &self,
+        url: &str,
+        options: impl crate::protocol::IntoOptions<GotoOptions>,
+    ) -> Result<Option<Response>> {
+        let options = options.into_options();
+        let ready = options
+            .as_ref()
+            .and_then(|o| o.wait_for_ready.clone().map(|names| (names, o.timeout)));
+
         // Delegate to main frame
         let frame = self.main_frame().await.map_err(|e| match e {
             Error::TargetClosed { context, .. } => Error::TargetClosed {
@@ -324,9 +368,88 @@ impl Page {
             }
         }
 
+        if let Some((names, timeout)) = ready {
+            self.wait_for_ready(&names, timeout).await?;
+        }
+
         Ok(response)
     }
 
+    /// Waits for readiness conditions registered on the page's context.
+    ///
+    /// Pass an empty slice to await every registered condition. `timeout`
+    /// defaults to 30 seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` for unknown names and `Error::Timeout`
+    /// (listing the unmet conditions) if they are not all met in time.
+    ///
+    /// See [`BrowserContext::add_readiness_condition`](crate::protocol::BrowserContext::add_readiness_condition).
+    pub async fn wait_for_ready<S: AsRef<str>>(
+        &self,
+        names: &[S],
+        timeout: Option<std::time::Duration>,
+    ) -> Result<()> {
+        let names: Vec<String> = names.iter().map(|n| n.as_ref().to_string()).collect();
+        let conditions = self.context()?.readiness().select(&names)?;
+        if conditions.is_empty() {
+            return Ok(());
+        }
+
+        let timeout = timeout.unwrap_or(std::time::Duration::from_millis(
+            crate::DEFAULT_TIMEOUT_MS as u64,
+        ));
+        let script = crate::protocol::readiness::wait_script(&conditions, timeout)?;
+        let value = self
+            .main_frame()
+            .await?
+            .evaluate::<()>(&script, None)
+            .await?;
+        crate::protocol::readiness::check_result(value, timeout)
+    }
+
+    /// Waits until the WASM app has hydrated.
+    ///
+    /// Awaits the signal registered by
+    /// [`BrowserContext::enable_wasm`](crate::protocol::BrowserContext::enable_wasm).
+    /// `timeout` defaults to 30 seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if WASM support was not enabled on the
+    /// context, and `Error::Timeout` if the app does not hydrate in time.
+    pub async fn wait_for_hydration(&self, timeout: Option<std::time::Duration>) -> Result<()> {
+        self.wait_for_ready(&[crate::protocol::wasm::WASM_HYDRATED], timeout)
+            .await
+    }
+
+    /// Returns the Rust panics captured in the current document.
+    ///
+    /// Captures `panicked at` messages logged by `console_error_panic_hook` and
+    /// uncaught `WebAssembly.RuntimeError`s. Requires
+    /// [`BrowserContext::enable_wasm`](crate::protocol::BrowserContext::enable_wasm)
+    /// to have been called before the page navigated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if the capture script is not installed
+    /// in the current document.
+    pub async fn wasm_panics(&self) -> Result<Vec<crate::protocol::WasmPanic>> {
+        let value = self
+            .main_frame()
+            .await?
+            .evaluate::<()>(crate::protocol::wasm::PANICS_SCRIPT, None)
+            .await?;
+        if value.is_null() {
+            return Err(Error::InvalidArgument(
+                "WASM panic capture is not installed; call BrowserContext::enable_wasm() before navigating"
+                    .to_string(),
+            ));
+        }
+        serde_json::from_value(value).map_err(Error::from)
+    }
+
     /// Returns the browser context that the page belongs to.
     pub fn context(&self) -> Result<crate::protocol::BrowserContext> {
         let parent = self.base.parent().ok_or_else(|| Error::TargetClosed {
@@ -366,18 +489,219 @@ impl Page {
     /// Creates a locator for finding elements on the page.
     ///
     /// Locators are the central piece of Playwright's auto-waiting and retry-ability.
-    /// They don't execute queries until an action is performed.
+    /// They don't execute queries until an action is performed, so creating one
+    /// is synchronous and it can be kept and reused across navigations. Actions
+    /// on a locator whose page has closed fail with `Error::TargetClosed`.
     ///
     /// # Arguments
     ///
     /// * `selector` - CSS selector or other locating strategy
     ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let submit = page.locator("button[type=submit]");
+    /// submit.click(None).await?;
+    /// expect(&submit).to_be_disabled().await?;
+    /// ```
+    ///
     /// See: <https://playwright.dev/docs/api/class-page#page-locator>
-    pub async fn locator(&self, selector: &str) -> crate::protocol::Locator {
-        // Get the main frame
-        let frame = self.main_frame().await.expect("Main frame should exist");
+    pub fn locator(&self, selector: &str) -> crate::protocol::Locator {
+        crate::protocol::Locator::new(self.main_frame_ref(), selector.to_string())
+    }
+
+    /// Locates an iframe, to reach elements inside it.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let payments = page.frame_locator("iframe#payments");
+    /// payments.get_by_role("button", None).click(None).await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-frame-locator>
+    pub fn frame_locator(&self, selector: &str) -> crate::protocol::FrameLocator {
+        crate::protocol::FrameLocator::new(self.main_frame_ref(), selector.to_string())
+    }
+
+    /// Locates elements by ARIA role, accessible name and state.
+    ///
+    /// `role` is an ARIA role such as `"button"`, `"heading"` or `"checkbox"`;
+    /// implicit roles of native elements count (`<button>`, `<h1>`, ...).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use playwright_rs::GetByRoleOptions;
+    ///
+    /// page.get_by_role("button", Some(GetByRoleOptions::builder().name("Sign in").build()))
+    ///     .click(None)
+    ///     .await?;
+    /// let title = page
+    ///     .get_by_role("heading", Some(GetByRoleOptions::builder().level(1).build()));
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-get-by-role>
+    pub fn get_by_role(
+        &self,
+        role: &str,
+        options: impl crate::protocol::IntoOptions<crate::protocol::GetByRoleOptions>,
+    ) -> crate::protocol::Locator {
+        let options = options.into_options();
+        self.locator(&crate::protocol::get_by::role_selector(
+            role,
+            options.as_ref(),
+        ))
+    }
+
+    /// Locates elements by the text they contain.
+    ///
+    /// Strings match a case-insensitive substring of the element's text with
+    /// whitespace normalized; `exact` requires a case-sensitive, full-string
+    /// match. Regexes (see [`TextMatch`](crate::protocol::TextMatch)) are
+    /// matched as-is and ignore `exact`. The smallest element containing the
+    /// text is matched, so this never resolves to `<body>` for text in a child.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// page.get_by_text("Sign in", false).click(None).await?;
+    /// let welcome = page
+    ///     .get_by_text(regex::Regex::new(r"(?i)^welcome, \w+$").unwrap(), false);
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-get-by-text>
+    pub fn get_by_text(
+        &self,
+        text: impl Into<crate::protocol::TextMatch>,
+        exact: bool,
+    ) -> crate::protocol::Locator {
+        self.locator(&crate::protocol::get_by::text_selector(&text.into(), exact))
+    }
+
+    /// Locates form controls by the text of their label.
+    ///
+    /// Matches inputs labelled by a `<label>` (wrapping or via `for=`), by
+    /// `aria-labelledby`, or by `aria-label`. Text matching follows
+    /// [`get_by_text`](Self::get_by_text).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// page.get_by_label("Email", false).fill("ada@example.com", None).await?;
+    /// page.get_by_label("Password", true).fill("secret", None).await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-get-by-label>
+    pub fn get_by_label(
+        &self,
+        text: impl Into<crate::protocol::TextMatch>,
+        exact: bool,
+    ) -> crate::protocol::Locator {
+        self.locator(&crate::protocol::get_by::label_selector(
+            &text.into(),
+            exact,
+        ))
+    }
+
+    /// Locates inputs by their `placeholder` attribute.
+    ///
+    /// Strings match a case-insensitive substring unless `exact` is set;
+    /// regexes are matched as-is.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// page.get_by_placeholder("name@example.com", true)
+    ///     .fill("ada@example.com", None)
+    ///     .await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-get-by-placeholder>
+    pub fn get_by_placeholder(
+        &self,
+        text: impl Into<crate::protocol::TextMatch>,
+        exact: bool,
+    ) -> crate::protocol::Locator {
+        self.locator(&crate::protocol::get_by::placeholder_selector(
+            &text.into(),
+            exact,
+        ))
+    }
+
+    /// Locates elements, usually images, by their `alt` attribute.
+    ///
+    /// Matches any element with a matching `alt`, including `<img>`, `<area>`
+    /// and `<input type=image>`. Strings match a case-insensitive substring
+    /// unless `exact` is set; regexes are matched as-is.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let logo = page.get_by_alt_text("Company logo", true);
+    /// assert!(logo.is_visible().await?);
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-get-by-alt-text>
+    pub fn get_by_alt_text(
+        &self,
+        text: impl Into<crate::protocol::TextMatch>,
+        exact: bool,
+    ) -> crate::protocol::Locator {
+        self.locator(&crate::protocol::get_by::alt_text_selector(
+            &text.into(),
+            exact,
+        ))
+    }
+
+    /// Locates elements by their `title` attribute.
+    ///
+    /// Strings match a case-insensitive substring unless `exact` is set;
+    /// regexes are matched as-is.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let issues = page.get_by_title("Issues count", true);
+    /// assert_eq!(issues.text_content().await?.as_deref(), Some("25 issues"));
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-get-by-title>
+    pub fn get_by_title(
+        &self,
+        text: impl Into<crate::protocol::TextMatch>,
+        exact: bool,
+    ) -> crate::protocol::Locator {
+        self.locator(&crate::protocol::get_by::title_selector(
+            &text.into(),
+            exact,
+        ))
+    }
 
-        crate::protocol::Locator::new(Arc::new(frame), selector.to_string())
+    /// Locates elements by test id.
+    ///
+    /// Matches the `data-testid` attribute exactly, or the attribute configured
+    /// with [`Selectors::set_test_id_attribute`](crate::protocol::Selectors::set_test_id_attribute).
+    /// A regex matches the attribute value as-is.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// page.get_by_test_id("directions").click(None).await?;
+    ///
+    /// playwright.selectors().set_test_id_attribute("data-qa");
+    /// page.get_by_test_id("checkout").click(None).await?;
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-get-by-test-id>
+    pub fn get_by_test_id(
+        &self,
+        test_id: impl Into<crate::protocol::TextMatch>,
+    ) -> crate::protocol::Locator {
+        self.locator(&crate::protocol::get_by::test_id_selector(
+            &crate::protocol::selectors::test_id_attribute(),
+            &test_id.into(),
+        ))
     }
 
     /// Returns the keyboard instance for low-level keyboard control.
@@ -387,6 +711,13 @@ impl Page {
         crate::protocol::Keyboard::new(self.clone())
     }
 
+    /// Returns the clock of the page's browser context.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-clock>
+    pub fn clock(&self) -> Result<crate::protocol::Clock> {
+        Ok(self.context()?.clock())
+    }
+
     /// Returns the mouse instance for low-level mouse control.
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-mouse>
@@ -601,7 +932,11 @@ impl Page {
     /// about:blank). This matches Playwright's behavior across all language bindings.
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-reload>
-    pub async fn reload(&self, options: Option<GotoOptions>) -> Result<Option<Response>> {
+    pub async fn reload(
+        &self,
+        options: impl crate::protocol::IntoOptions<GotoOptions>,
+    ) -> Result<Option<Response>> {
+        let options = options.into_options();
         // Build params
         let mut params = serde_json::json!({});
 
@@ -723,8 +1058,9 @@ impl Page {
     /// See: <https://playwright.dev/docs/api/class-page#page-screenshot>
     pub async fn screenshot(
         &self,
-        options: Option<crate::protocol::ScreenshotOptions>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::ScreenshotOptions>,
     ) -> Result<Vec<u8>> {
+        let options = options.into_options();
         let params = if let Some(opts) = options {
             opts.to_json()
         } else {
@@ -758,7 +1094,7 @@ impl Page {
     pub async fn screenshot_to_file(
         &self,
         path: &std::path::Path,
-        options: Option<crate::protocol::ScreenshotOptions>,
+        options: impl crate::protocol::IntoOptions<crate::protocol::ScreenshotOptions>,
     ) -> Result<Vec<u8>> {
         // Get the screenshot bytes
         let bytes = self.screenshot(options).await?;
@@ -809,6 +1145,202 @@ impl Page {
         serde_json::from_value(result).map_err(Error::from)
     }
 
+    /// Extracts structured data from the page into `T` in a single round trip.
+    ///
+    /// Each field of `schema` maps an output key to a CSS selector and what to read
+    /// (text, attribute, HTML, number, nested lists). The schema is evaluated in the
+    /// page as one `evaluate` call and the result is deserialized into `T`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use playwright_rs::{ExtractSchema, Field};
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Article {
+    ///     headline: String,
+    ///     author: Option<String>,
+    ///     links: Vec<String>,
+    /// }
+    ///
+    /// let schema = ExtractSchema::new()
+    ///     .field("headline", Field::text("h1"))
+    ///     .field("author", Field::text(".byline"))
+    ///     .field("links", Field::attr("article a", "href").all());
+    /// let article: Article = page.extract(&schema).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if the extracted data does not deserialize into `T`.
+    pub async fn extract<T: serde::de::DeserializeOwned>(
+        &self,
+        schema: &crate::protocol::ExtractSchema,
+    ) -> Result<T> {
+        self.main_frame().await?.extract(schema).await
+    }
+
+    /// Captures a normalized snapshot of the main frame's DOM.
+    ///
+    /// Compare two snapshots with [`DomSnapshot::diff`](crate::protocol::DomSnapshot::diff).
+    pub async fn snapshot_dom(&self) -> Result<crate::protocol::DomSnapshot> {
+        self.main_frame().await?.snapshot_dom().await
+    }
+
+    /// Runs `action` and returns its result with the DOM changes it caused.
+    ///
+    /// Snapshots the main frame before and after the action. The action is
+    /// awaited as-is, so wait for any asynchronous updates inside it.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let save = page.locator("#save");
+    /// let ((), diff) = page.diff_dom(save.click(None)).await?;
+    /// if diff.is_empty() {
+    ///     eprintln!("clicking Save changed nothing");
+    /// }
+    /// ```
+    pub async fn diff_dom<T, F>(&self, action: F) -> Result<(T, crate::protocol::DomDiff)>
+    where
+        F: std::future::Future<Output = Result<T>>,
+    {
+        let frame = self.main_frame().await?;
+        let before = frame.snapshot_dom().await?;
+        let value = action.await?;
+        let after = frame.snapshot_dom().await?;
+        Ok((value, before.diff(&after)))
+    }
+
+    /// Checks every link on the page and reports broken ones.
+    ///
+    /// Collects the http(s) `href` of each `<a>` element, de-duplicates them and
+    /// sends HEAD requests (falling back to GET when HEAD is rejected) through
+    /// the context's [`APIRequestContext`](crate::protocol::APIRequestContext),
+    /// so requests carry the context's cookies.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use playwright_rs::LinkCheckOptions;
+    ///
+    /// let report = page
+    ///     .check_links(Some(LinkCheckOptions::new().concurrency(4)))
+    ///     .await?;
+    /// for link in report.broken() {
+    ///     eprintln!("{}: {:?}", link.url, link.status);
+    /// }
+    /// ```
+    #[cfg(feature = "api-request")]
+    pub async fn check_links(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::LinkCheckOptions>,
+    ) -> Result<crate::protocol::LinkReport> {
+        let options = options.into_options().unwrap_or_default();
+        let hrefs = self
+            .main_frame()
+            .await?
+            .evaluate::<()>(crate::protocol::link_check::LINKS_SCRIPT, None)
+            .await?;
+        let hrefs: Vec<String> = serde_json::from_value(hrefs)?;
+        let urls = crate::protocol::link_check::collect_links(
+            hrefs,
+            &self.url(),
+            options.same_origin_only,
+        );
+        let request = self.context()?.request().await?;
+        crate::protocol::link_check::check_links(request, urls, &options).await
+    }
+
+    /// Fills a form from a serializable struct.
+    ///
+    /// Each field of `data` is written to the control given by `mapping`
+    /// (default: `[name="<field>"]`), then read back to check the value stuck.
+    /// See [`FormMapping`](crate::protocol::FormMapping) for how values map to controls.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` naming the field if a value cannot be
+    /// applied or does not read back as written (e.g. truncated by `maxlength`).
+    pub async fn fill_form<T: serde::Serialize>(
+        &self,
+        data: &T,
+        mapping: Option<&crate::protocol::FormMapping>,
+    ) -> Result<()> {
+        let data = serde_json::to_value(data)?;
+        let default_mapping = crate::protocol::FormMapping::default();
+        let frame = Arc::new(self.main_frame().await?);
+        crate::protocol::form::fill_form(frame, &data, mapping.unwrap_or(&default_mapping)).await
+    }
+
+    /// Runs a [`Batch`](crate::protocol::Batch) of read-only queries in one round trip.
+    ///
+    /// Useful when the driver is remote and each protocol call is expensive.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use playwright_rs::Batch;
+    ///
+    /// let mut batch = Batch::new();
+    /// let title = batch.text_content("h1");
+    /// let rows = batch.count("table tr");
+    /// let results = page.run_batch(&batch).await?;
+    /// println!("{:?} has {} rows", results.get(title)?, results.get(rows)?);
+    /// ```
+    pub async fn run_batch(
+        &self,
+        batch: &crate::protocol::Batch,
+    ) -> Result<crate::protocol::BatchResults> {
+        self.main_frame().await?.run_batch(batch).await
+    }
+
+    /// Waits until the main frame reaches the given load state.
+    ///
+    /// See [`Frame::wait_for_load_state`](crate::protocol::Frame::wait_for_load_state).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-load-state>
+    pub async fn wait_for_load_state(
+        &self,
+        state: Option<WaitUntil>,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<()> {
+        self.main_frame()
+            .await?
+            .wait_for_load_state(state, timeout)
+            .await
+    }
+
+    /// Waits until the page is visually and functionally settled.
+    ///
+    /// Combines network idle, finished animations and a DOM quiet period, for
+    /// apps where the load state alone does not mean the page is ready (e.g.
+    /// before screenshots).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use playwright_rs::StabilityOptions;
+    /// use std::time::Duration;
+    ///
+    /// page.goto("https://example.com/dashboard", None).await?;
+    /// page.wait_for_stable(Some(
+    ///     StabilityOptions::new().quiet_period(Duration::from_secs(1)),
+    /// ))
+    /// .await?;
+    /// let png = page.screenshot(None).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Timeout` if the page does not settle within the timeout.
+    pub async fn wait_for_stable(
+        &self,
+        options: impl crate::protocol::IntoOptions<crate::protocol::StabilityOptions>,
+    ) -> Result<()> {
+        self.main_frame().await?.wait_for_stable(options).await
+    }
+
     /// Evaluates a JavaScript expression and returns the result as a String.
     ///
     /// # Arguments
@@ -837,24 +1369,191 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-route>
     pub async fn route<F, Fut>(&self, pattern: &str, handler: F) -> Result<()>
+    where
+        F: Fn(Route) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.add_route_handler(pattern, handler).await?;
+        Ok(())
+    }
+
+    /// Registers a route handler and returns it so it can be removed individually
+    async fn add_route_handler<F, Fut>(
+        &self,
+        pattern: &str,
+        handler: F,
+    ) -> Result<Arc<dyn Fn(Route) -> RouteHandlerFuture + Send + Sync>>
     where
         F: Fn(Route) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
         // 1. Wrap handler in Arc with type erasure
-        let handler =
+        let handler: Arc<dyn Fn(Route) -> RouteHandlerFuture + Send + Sync> =
             Arc::new(move |route: Route| -> RouteHandlerFuture { Box::pin(handler(route)) });
 
         // 2. Store in handlers list
         self.route_handlers.lock().unwrap().push(RouteHandlerEntry {
             pattern: pattern.to_string(),
-            handler,
+            handler: Arc::clone(&handler),
         });
 
         // 3. Enable network interception via protocol
         self.enable_network_interception().await?;
 
-        Ok(())
+        Ok(handler)
+    }
+
+    /// Removes a single route handler returned by `add_route_handler`
+    async fn remove_route_handler(
+        &self,
+        handler: &Arc<dyn Fn(Route) -> RouteHandlerFuture + Send + Sync>,
+    ) -> Result<()> {
+        self.route_handlers
+            .lock()
+            .unwrap()
+            .retain(|entry| !Arc::ptr_eq(&entry.handler, handler));
+        self.enable_network_interception().await
+    }
+
+    /// Navigates to `url` while waiting for a sub-resource request matching a pattern.
+    ///
+    /// The first matching request is captured (and optionally blocked). With
+    /// [`ExpectRequest::stop_navigation`] the navigation is stopped as soon as the
+    /// request is seen. The captured request is returned even if the navigation
+    /// fails or is stopped; see [`NavigationWithRequest::is_partial()`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use playwright_rs::ExpectRequest;
+    ///
+    /// let result = page
+    ///     .goto_expecting_request(
+    ///         "https://example.com",
+    ///         ExpectRequest::new("**/tracker.js").abort_request(true),
+    ///     )
+    ///     .await?;
+    /// assert!(result.response.is_none()); // blocked
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - No matching request is seen within the timeout (`Error::Timeout`)
+    /// - The navigation fails before a matching request is seen
+    pub async fn goto_expecting_request(
+        &self,
+        url: &str,
+        expect: ExpectRequest,
+    ) -> Result<NavigationWithRequest> {
+        let timeout = expect.timeout.unwrap_or(std::time::Duration::from_millis(
+            crate::DEFAULT_TIMEOUT_MS as u64,
+        ));
+
+        // Capture the first matching request through a temporary route
+        let (request_tx, mut request_rx) = tokio::sync::oneshot::channel::<Request>();
+        let request_tx = Arc::new(Mutex::new(Some(request_tx)));
+        let abort_request = expect.abort_request;
+        let handler = self
+            .add_route_handler(&expect.pattern, move |route: Route| {
+                if let Some(tx) = request_tx.lock().unwrap().take() {
+                    let _ = tx.send(route.request());
+                }
+                async move {
+                    if abort_request {
+                        route.abort(Some("blockedbyclient")).await
+                    } else {
+                        route.continue_(None).await
+                    }
+                }
+            })
+            .await?;
+
+        let goto = self.goto(url, expect.goto_options.clone());
+        tokio::pin!(goto);
+        let deadline = tokio::time::sleep(timeout);
+        tokio::pin!(deadline);
+
+        let mut navigation: Option<Result<Option<Response>>> = None;
+        let mut request: Option<Request> = None;
+        let mut request_closed = false;
+        let mut navigation_stopped = false;
+
+        loop {
+            tokio::select! {
+                result = &mut goto, if navigation.is_none() => {
+                    let failed = result.is_err();
+                    navigation = Some(result);
+                    if request.is_some() || failed {
+                        break;
+                    }
+                }
+                captured = &mut request_rx, if request.is_none() && !request_closed => {
+                    match captured {
+                        Ok(captured) => request = Some(captured),
+                        Err(_) => request_closed = true,
+                    }
+                    if navigation.is_some() {
+                        break;
+                    }
+                    if request.is_some() && expect.stop_navigation {
+                        navigation_stopped = true;
+                        break;
+                    }
+                }
+                _ = &mut deadline => break,
+            }
+        }
+
+        let _ = self.remove_route_handler(&handler).await;
+
+        if navigation_stopped {
+            // Best effort: stop loading the rest of the page
+            let _ = tokio::time::timeout(
+                std::time::Duration::from_secs(1),
+                self.evaluate_expression("window.stop()"),
+            )
+            .await;
+        }
+
+        let request = match request {
+            Some(request) => request,
+            None => {
+                return match navigation {
+                    Some(Err(e)) => Err(e),
+                    _ => Err(Error::Timeout(format!(
+                        "Timeout {}ms exceeded waiting for request matching '{}'",
+                        timeout.as_millis(),
+                        expect.pattern
+                    ))),
+                };
+            }
+        };
+
+        // Blocked requests never get a response
+        let response = if expect.abort_request {
+            None
+        } else {
+            tokio::time::timeout(timeout, request.response())
+                .await
+                .ok()
+                .and_then(|r| r.ok())
+                .flatten()
+        };
+
+        let (navigation, navigation_error) = match navigation {
+            Some(Ok(response)) => (response, None),
+            Some(Err(e)) => (None, Some(e)),
+            None => (None, None),
+        };
+
+        Ok(NavigationWithRequest {
+            request,
+            response,
+            navigation,
+            navigation_error,
+            navigation_stopped,
+        })
     }
 
     /// Updates network interception patterns for this page
@@ -976,6 +1675,234 @@ impl Page {
         Ok(())
     }
 
+    /// Registers a handler for same-document navigations of the main frame.
+    ///
+    /// Called when the URL changes without loading a new document, i.e.
+    /// `history.pushState`, `history.replaceState` and fragment changes: the
+    /// way single-page apps change routes. Full navigations do not trigger it.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// page.on_url_changed(|navigation| async move {
+    ///     println!("route changed to {}", navigation.url);
+    ///     Ok(())
+    /// })
+    /// .await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the main frame is not available.
+    pub async fn on_url_changed<F, Fut>(&self, handler: F) -> Result<()>
+    where
+        F: Fn(Navigation) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let handler = Arc::new(move |navigation: Navigation| -> UrlChangedHandlerFuture {
+            Box::pin(handler(navigation))
+        });
+
+        // The first handler starts forwarding the main frame's navigations
+        let first = {
+            let mut handlers = self.url_changed_handlers.lock().unwrap();
+            handlers.push(handler);
+            handlers.len() == 1
+        };
+        if first {
+            let mut navigations = self.main_frame().await?.subscribe_navigations();
+            let handlers = Arc::clone(&self.url_changed_handlers);
+            tokio::spawn(async move {
+                loop {
+                    let navigation = match navigations.recv().await {
+                        Ok(navigation) => navigation,
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            tracing::warn!("Dropped {} URL change events", skipped);
+                            continue;
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    };
+                    if !navigation.is_same_document() {
+                        continue;
+                    }
+                    let handlers = handlers.lock().unwrap().clone();
+                    for handler in handlers {
+                        if let Err(e) = handler(navigation.clone()).await {
+                            tracing::warn!("URL change handler error: {}", e);
+                        }
+                    }
+                }
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Runs `action` and waits for the same-document navigation it causes.
+    ///
+    /// Subscribes before running `action`, so a route change that happens
+    /// while it runs is not missed. Full navigations are ignored. `timeout`
+    /// defaults to 30 seconds.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let link = page.locator("a[href='/cart']");
+    /// let navigation = page.expect_url_change(|| link.click(None), None).await?;
+    /// assert!(navigation.url.ends_with("/cart"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `action` if it fails, and `Error::Timeout` if no
+    /// same-document navigation happens in time.
+    pub async fn expect_url_change<F, Fut, T>(
+        &self,
+        action: F,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<Navigation>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let timeout = timeout.unwrap_or(crate::DEFAULT_TIMEOUT);
+        let mut navigations = self.main_frame().await?.subscribe_navigations();
+        action().await?;
+
+        let changed = tokio::time::timeout(timeout, async {
+            loop {
+                match navigations.recv().await {
+                    Ok(navigation) if navigation.is_same_document() => return Ok(navigation),
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(broadcast::error::RecvError::Closed) => {
+                        return Err(Error::TargetClosed {
+                            target_type: "Page".to_string(),
+                            context: "Page closed while waiting for a URL change".to_string(),
+                        })
+                    }
+                }
+            }
+        })
+        .await;
+        changed.unwrap_or_else(|_| {
+            Err(Error::Timeout(format!(
+                "Waiting for URL change exceeded {:?}",
+                timeout
+            )))
+        })
+    }
+
+    /// Emulates network conditions (latency and throughput) for this page.
+    ///
+    /// Chromium only; uses CDP `Network.emulateNetworkConditions`. Pass a preset such as
+    /// [`NetworkConditions::slow_3g()`] or custom values, or `None` to disable throttling.
+    /// To throttle every page in a context, use
+    /// `BrowserContextOptions::builder().network_conditions(...)`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use playwright_rs::NetworkConditions;
+    ///
+    /// page.emulate_network_conditions(Some(NetworkConditions::slow_3g())).await?;
+    /// page.goto("https://example.com", None).await?;
+    /// page.emulate_network_conditions(None).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the browser is not Chromium or the page has been closed.
+    ///
+    /// See: <https://chromedevtools.github.io/devtools-protocol/tot/Network/#method-emulateNetworkConditions>
+    pub async fn emulate_network_conditions(
+        &self,
+        conditions: Option<NetworkConditions>,
+    ) -> Result<()> {
+        let conditions = conditions.unwrap_or_else(NetworkConditions::no_throttling);
+        let session = self.cdp_session().await?;
+        session
+            .network()
+            .emulate_network_conditions(&conditions)
+            .await
+    }
+
+    /// Returns the page's current JS heap usage (Chromium only).
+    ///
+    /// See: <https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#method-getHeapUsage>
+    pub async fn heap_usage(&self) -> Result<HeapUsage> {
+        self.cdp_session()
+            .await?
+            .heap_profiler()
+            .get_heap_usage()
+            .await
+    }
+
+    /// Forces a JS garbage collection in the page (Chromium only).
+    ///
+    /// Useful before measuring heap usage so only retained memory is counted.
+    pub async fn collect_garbage(&self) -> Result<()> {
+        self.cdp_session()
+            .await?
+            .heap_profiler()
+            .collect_garbage()
+            .await
+    }
+
+    /// Captures a heap snapshot of the page (Chromium only).
+    ///
+    /// Returns the snapshot in `.heapsnapshot` JSON format; if `path` is given it is
+    /// also written to that file, ready to load in Chrome DevTools' Memory panel.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// page.take_heap_snapshot(Some("before.heapsnapshot".as_ref())).await?;
+    /// ```
+    ///
+    /// See: <https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-takeHeapSnapshot>
+    pub async fn take_heap_snapshot(&self, path: Option<&std::path::Path>) -> Result<String> {
+        let snapshot = self
+            .cdp_session()
+            .await?
+            .heap_profiler()
+            .take_snapshot()
+            .await?;
+
+        if let Some(path) = path {
+            tokio::fs::write(path, &snapshot).await?;
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Starts sampling the page's JS heap usage every `interval` (Chromium only).
+    ///
+    /// Call [`HeapMonitor::stop()`] to get the collected [`HeapSample`]s.
+    pub async fn monitor_heap(&self, interval: std::time::Duration) -> Result<HeapMonitor> {
+        if interval.is_zero() {
+            return Err(Error::InvalidArgument(
+                "Heap monitor interval must be greater than zero".to_string(),
+            ));
+        }
+        let session = self.cdp_session().await?;
+        Ok(HeapMonitor::start(session, interval))
+    }
+
+    /// Returns this page's CDP session, creating it on first use.
+    ///
+    /// The session is kept attached so emulation overrides stay in effect.
+    pub(crate) async fn cdp_session(&self) -> Result<CDPSession> {
+        let mut slot = self.cdp_session.lock().await;
+        if let Some(session) = slot.as_ref() {
+            return Ok(session.clone());
+        }
+
+        let session = self.context()?.new_cdp_session(self).await?;
+        session.network().enable().await?;
+        *slot = Some(session.clone());
+        Ok(session)
+    }
+
     /// Handles a download event from the protocol
     async fn on_download_event(&self, download: Download) {
         let handlers = self.download_handlers.lock().unwrap().clone();
@@ -1019,14 +1946,14 @@ impl Page {
     /// # Example
     ///
     /// ```no_run
-    /// # use playwright_rs::protocol::{Playwright, AddStyleTagOptions};
+    /// # use playwright_rs::{Playwright, AddStyleTagOptions};
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let playwright = Playwright::launch().await?;
     /// # let browser = playwright.chromium().launch().await?;
     /// # let context = browser.new_context().await?;
     /// # let page = context.new_page().await?;
-    /// use playwright_rs::protocol::AddStyleTagOptions;
+    /// use playwright_rs::AddStyleTagOptions;
     ///
     /// // With inline CSS
     /// page.add_style_tag(
@@ -1067,14 +1994,17 @@ impl Page {
     ///
     /// The script is evaluated after the document was created but before any of its scripts were run.
     ///
+    /// Scripts can be inline source or a [`Script::Path`]; file contents are cached, and
+    /// registering a script whose content was already added to this page is a no-op.
+    ///
     /// # Arguments
     ///
-    /// * `script` - JavaScript code to be injected into the page
+    /// * `script` - JavaScript source (`&str`/`String`) or a [`Script`] to be injected into the page
     ///
     /// # Example
     ///
     /// ```no_run
-    /// # use playwright_rs::protocol::Playwright;
+    /// # use playwright_rs::Playwright;
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let playwright = Playwright::launch().await?;
@@ -1082,15 +2012,31 @@ impl Page {
     /// # let context = browser.new_context().await?;
     /// # let page = context.new_page().await?;
     /// page.add_init_script("window.injected = 123;").await?;
+    ///
+    /// // From file
+    /// use playwright_rs::Script;
+    /// page.add_init_script(Script::Path("./scripts/setup.js".into())).await?;
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-add-init-script>
-    pub async fn add_init_script(&self, script: &str) -> Result<()> {
-        self.channel()
-            .send_no_result("addInitScript", serde_json::json!({ "source": script }))
-            .await
+    pub async fn add_init_script(&self, script: impl Into<Script>) -> Result<()> {
+        let source = script.into().resolve().await?;
+
+        // Skip scripts already registered on this page
+        let Some(hash) = self.init_scripts.insert(&source) else {
+            return Ok(());
+        };
+
+        let result = self
+            .channel()
+            .send_no_result("addInitScript", serde_json::json!({ "source": &*source }))
+            .await;
+        if result.is_err() {
+            self.init_scripts.remove(hash);
+        }
+        result
     }
 }
 
@@ -1230,9 +2176,23 @@ impl ChannelOwner for Page {
                 // Dialog events are handled by BrowserContext and forwarded to Page
                 // This case should not be reached, but keeping for completeness
             }
+            "crash" => {
+                let logs = self
+                    .connection()
+                    .driver_logs()
+                    .map(|logs| logs.lines())
+                    .unwrap_or_default();
+                let report = crate::protocol::CrashReport::from_logs(
+                    crate::protocol::CrashKind::Page,
+                    self.url(),
+                    &logs,
+                );
+                tracing::error!("{}", report);
+                self.connection().record_crash(report);
+            }
             _ => {
                 // Other events will be handled in future phases
-                // Events: load, domcontentloaded, close, crash, etc.
+                // Events: load, domcontentloaded, close, etc.
             }
         }
     }
@@ -1262,6 +2222,10 @@ pub struct GotoOptions {
     pub timeout: Option<std::time::Duration>,
     /// When to consider operation succeeded
     pub wait_until: Option<WaitUntil>,
+    /// Readiness conditions to await after navigating (`Page::goto` only)
+    ///
+    /// An empty list awaits every condition registered on the context.
+    pub wait_for_ready: Option<Vec<String>>,
 }
 
 impl GotoOptions {
@@ -1270,6 +2234,7 @@ impl GotoOptions {
         Self {
             timeout: None,
             wait_until: None,
+            wait_for_ready: None,
         }
     }
 
@@ -1284,6 +2249,18 @@ impl GotoOptions {
         self.wait_until = Some(wait_until);
         self
     }
+
+    /// Awaits the named readiness conditions after navigating
+    ///
+    /// See [`BrowserContext::add_readiness_condition`](crate::protocol::BrowserContext::add_readiness_condition).
+    pub fn wait_for_ready<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.wait_for_ready = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
 }
 
 impl Default for GotoOptions {
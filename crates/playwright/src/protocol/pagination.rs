@@ -0,0 +1,131 @@
+// Pagination - Walking paginated lists
+//
+// Used by Locator::iter_pages(). Yields the list once per page: first as it
+// is, then after each click on the "next" control, until that control is
+// gone, hidden or disabled. A page counts as loaded once the list's markup
+// differs from the previous page's, which covers both client-side paging and
+// paging through full navigations.
+
+use crate::error::{Error, Result};
+use crate::protocol::Locator;
+use std::time::Duration;
+
+/// How often to check whether the next page has rendered
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Async iterator over the pages of a paginated list.
+///
+/// Created by [`Locator::iter_pages`]. Call [`next`](Self::next) until it
+/// returns `None`.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut pages = page
+///     .locator("ul.results")
+///     .iter_pages(page.locator("button.next"))
+///     .max_pages(20);
+/// while let Some(list) = pages.next().await? {
+///     for title in list.locator("li h2").all_text_contents().await? {
+///         println!("{}", title);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Pages {
+    list: Locator,
+    next_button: Locator,
+    /// Markup of the page yielded last, `None` before the first page
+    last_markup: Option<String>,
+    yielded: usize,
+    max_pages: Option<usize>,
+    timeout: Duration,
+}
+
+impl Pages {
+    pub(crate) fn new(list: Locator, next_button: Locator) -> Self {
+        Self {
+            list,
+            next_button,
+            last_markup: None,
+            yielded: 0,
+            max_pages: None,
+            timeout: crate::DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Stops after `max_pages` pages, including the first
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Sets how long to wait for each next page to render (default: 30 seconds)
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Number of pages yielded so far
+    pub fn pages_seen(&self) -> usize {
+        self.yielded
+    }
+
+    /// Advances to the next page and returns the list scoped to it.
+    ///
+    /// The first call returns the current page without clicking. Returns
+    /// `None` once the next control is missing, hidden or disabled, or
+    /// [`max_pages`](Self::max_pages) pages were yielded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Timeout` if the list does not change within the
+    /// timeout after clicking next, or the error of the click itself.
+    pub async fn next(&mut self) -> Result<Option<Locator>> {
+        if self.max_pages.is_some_and(|max| self.yielded >= max) {
+            return Ok(None);
+        }
+
+        let Some(previous) = self.last_markup.clone() else {
+            self.last_markup = Some(self.list.inner_html().await?);
+            self.yielded += 1;
+            return Ok(Some(self.list.clone()));
+        };
+
+        if !self.has_next().await? {
+            return Ok(None);
+        }
+        self.next_button.click(None).await?;
+
+        let markup = tokio::time::timeout(self.timeout, async {
+            loop {
+                // Errors while a new document loads just mean "not yet"
+                match self.list.inner_html().await {
+                    Ok(markup) if markup != previous => return markup,
+                    _ => tokio::time::sleep(POLL_INTERVAL).await,
+                }
+            }
+        })
+        .await
+        .map_err(|_| {
+            Error::Timeout(format!(
+                "Page {} of '{}' did not render within {:?} of clicking '{}'",
+                self.yielded + 1,
+                self.list.selector(),
+                self.timeout,
+                self.next_button.selector()
+            ))
+        })?;
+
+        self.last_markup = Some(markup);
+        self.yielded += 1;
+        Ok(Some(self.list.clone()))
+    }
+
+    /// Whether the next control can be clicked; does not wait for it
+    async fn has_next(&self) -> Result<bool> {
+        Ok(self.next_button.count().await? > 0
+            && self.next_button.is_visible().await?
+            && self.next_button.is_enabled().await?)
+    }
+}
@@ -7,16 +7,27 @@
 // - Python: playwright-python/playwright/_impl/_playwright.py
 // - Protocol: protocol.yml (Playwright interface)
 
+use crate::api::PlaywrightConfig;
 use crate::error::Result;
-use crate::protocol::BrowserType;
+use crate::protocol::health::{DrainReport, HealthReport};
+use crate::protocol::session_recording::{
+    RecordedSession, ReplayOptions, ReplayReport, SessionLog,
+};
+use crate::protocol::stats::{process_memory_bytes, PlaywrightStats};
+use crate::protocol::{Browser, BrowserType};
 use crate::server::channel::Channel;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
-use crate::server::connection::ConnectionLike;
+use crate::server::connection::{ConnectionLike, DriverProcess};
 use crate::server::playwright_server::PlaywrightServer;
 use parking_lot::Mutex;
 use serde_json::Value;
 use std::any::Any;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
+
+/// Polling interval while `drain()` waits for open pages
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 /// Playwright is the root object that provides access to browser types.
 ///
@@ -26,7 +37,7 @@ use std::sync::Arc;
 /// # Example
 ///
 /// ```ignore
-/// use playwright_rs::protocol::Playwright;
+/// use playwright_rs::Playwright;
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -71,6 +82,8 @@ pub struct Playwright {
     /// - Taking ownership during shutdown (Option::take)
     /// - Interior mutability (Mutex)
     server: Arc<Mutex<Option<PlaywrightServer>>>,
+    /// Set by `drain()`; readiness checks fail from then on
+    draining: Arc<AtomicBool>,
 }
 
 impl Playwright {
@@ -90,13 +103,26 @@ impl Playwright {
     /// - Protocol initialization fails
     /// - Server doesn't respond within timeout (30s)
     pub async fn launch() -> Result<Self> {
+        Self::launch_with_config(PlaywrightConfig::default()).await
+    }
+
+    /// Launches Playwright with custom driver configuration.
+    ///
+    /// Use this to pass environment variables (e.g., `DEBUG=pw:api`, `PWDEBUG`,
+    /// proxy settings) to the driver without changing the current process's
+    /// environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Playwright::launch`].
+    pub async fn launch_with_config(config: PlaywrightConfig) -> Result<Self> {
         use crate::server::connection::Connection;
         use crate::server::playwright_server::PlaywrightServer;
         use crate::server::transport::PipeTransport;
 
         // 1. Launch Playwright server
         tracing::debug!("Launching Playwright server");
-        let mut server = PlaywrightServer::launch().await?;
+        let mut server = PlaywrightServer::launch_with_config(&config).await?;
 
         // 2. Take stdio streams from server process
         let stdin = server.process.stdin.take().ok_or_else(|| {
@@ -110,19 +136,113 @@ impl Playwright {
         // 3. Create transport and connection
         tracing::debug!("Creating transport and connection");
         let (transport, message_rx) = PipeTransport::new(stdin, stdout);
-        let connection: Arc<Connection<_, _>> = Arc::new(Connection::new(transport, message_rx));
+        let logs = server.logs.clone();
+        let mut connection = Connection::new(transport, message_rx).with_driver_logs(logs.clone());
+        if let Some(version) = server.driver_version() {
+            connection = connection.with_driver_version(version);
+        }
+        if config.record_session {
+            connection = connection.with_session_log(Arc::new(SessionLog::default()));
+        }
+        #[cfg(feature = "deadlock-detection")]
+        if let Some(detection) = &config.deadlock_detection {
+            connection = connection.with_message_history(detection.history);
+        }
+        // The server is owned through this slot from initialization on; shutdown
+        // empties it first, so an exit seen while it is still filled is a crash
+        let server_slot: Arc<Mutex<Option<PlaywrightServer>>> = Arc::new(Mutex::new(None));
+        let probe_slot = Arc::downgrade(&server_slot);
+        let connection = Arc::new(connection.with_driver_probe(move || {
+            match probe_slot.upgrade().as_ref().map(|slot| slot.lock()) {
+                Some(mut slot) => slot
+                    .as_mut()
+                    .map_or(DriverProcess::Released, PlaywrightServer::process_state),
+                None => DriverProcess::Released,
+            }
+        }));
 
         // 4. Spawn connection message loop in background
-        let conn_for_loop: Arc<Connection<_, _>> = Arc::clone(&connection);
+        let conn_for_loop = Arc::clone(&connection);
         tokio::spawn(async move {
             conn_for_loop.run().await;
         });
 
         // 5. Initialize Playwright (sends initialize message, waits for Playwright object)
         tracing::debug!("Initializing Playwright protocol");
+        let playwright_obj = connection
+            .initialize_playwright()
+            .await
+            .map_err(|e| logs.attach_to(e))?;
+
+        // 6. Start the connection watchdog, owned by the server so it stops first on shutdown
+        if let Some(keep_alive) = config.keep_alive.clone() {
+            server.attach_keep_alive(crate::server::keep_alive::spawn_watchdog(
+                Arc::downgrade(&connection),
+                keep_alive,
+            ));
+        }
+
+        #[cfg(feature = "deadlock-detection")]
+        if let Some(detection) = config.deadlock_detection.clone() {
+            connection.capture_call_stacks(true);
+            server.attach_deadlock_detector(crate::server::deadlock::spawn_detector(
+                Arc::downgrade(&connection),
+                detection,
+            ));
+        }
+
+        // 7. Downcast to Playwright type
+        *server_slot.lock() = Some(server);
+        Self::from_initialized(playwright_obj, server_slot)
+    }
+
+    /// Connects to a Playwright server over a custom transport.
+    ///
+    /// Use this when the driver is not a local child process, e.g. a driver
+    /// on another machine reached through an SSH tunnel. `transport` sends
+    /// messages, `receiver` reads them into the channel whose receiving end is
+    /// `message_rx`. No server process is owned, so closing the returned
+    /// `Playwright` leaves the driver running.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    /// let (writer, reader) = open_tunnel("build-host").await?;
+    /// let playwright = Playwright::connect_with_transport(
+    ///     TunnelSender { writer },
+    ///     TunnelReceiver { reader, messages: tx },
+    ///     rx,
+    /// )
+    /// .await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the protocol initialization fails or the server
+    /// doesn't respond within 30s.
+    pub async fn connect_with_transport(
+        transport: impl crate::server::transport::Transport,
+        receiver: impl crate::server::transport::TransportReceiver,
+        message_rx: tokio::sync::mpsc::UnboundedReceiver<Value>,
+    ) -> Result<Self> {
+        use crate::server::connection::Connection;
+
+        let connection = Arc::new(Connection::with_transport(transport, receiver, message_rx));
+        let conn_for_loop = Arc::clone(&connection);
+        tokio::spawn(async move {
+            conn_for_loop.run().await;
+        });
+
         let playwright_obj = connection.initialize_playwright().await?;
+        Self::from_initialized(playwright_obj, Arc::new(Mutex::new(None)))
+    }
 
-        // 6. Downcast to Playwright type
+    /// Takes ownership of the Playwright object created during initialization
+    fn from_initialized(
+        playwright_obj: Arc<dyn ChannelOwner>,
+        server: Arc<Mutex<Option<PlaywrightServer>>>,
+    ) -> Result<Self> {
         let playwright = playwright_obj
             .as_any()
             .downcast_ref::<Playwright>()
@@ -140,7 +260,8 @@ impl Playwright {
             chromium: Arc::clone(&playwright.chromium),
             firefox: Arc::clone(&playwright.firefox),
             webkit: Arc::clone(&playwright.webkit),
-            server: Arc::new(Mutex::new(Some(server))),
+            server,
+            draining: Arc::new(AtomicBool::new(false)),
         })
     }
 
@@ -209,6 +330,7 @@ impl Playwright {
             firefox,
             webkit,
             server: Arc::new(Mutex::new(None)), // No server for protocol-created objects
+            draining: Arc::new(AtomicBool::new(false)),
         })
     }
 
@@ -237,6 +359,224 @@ impl Playwright {
             .expect("webkit should be BrowserType")
     }
 
+    /// Returns the selector configuration.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-playwright#playwright-selectors>
+    pub fn selectors(&self) -> crate::protocol::Selectors {
+        crate::protocol::Selectors::new()
+    }
+
+    /// Returns the most recent lines written by the Playwright driver.
+    ///
+    /// Includes driver diagnostics and, when enabled with
+    /// [`PlaywrightConfig::capture_browser_output`](crate::PlaywrightConfig::capture_browser_output),
+    /// browser process output (`DEBUG=pw:browser`). The buffer keeps the last
+    /// [`DEFAULT_DRIVER_LOG_CAPACITY`](crate::server::driver_logs::DEFAULT_DRIVER_LOG_CAPACITY)
+    /// lines. Returns an empty list when not connected to a local driver.
+    pub fn driver_logs(&self) -> Vec<String> {
+        self.connection()
+            .driver_logs()
+            .map(|logs| logs.lines())
+            .unwrap_or_default()
+    }
+
+    /// Returns the version of the Playwright driver, if known.
+    ///
+    /// Read from the driver's `package.json` at launch; `None` for
+    /// connections made with [`connect_with_transport`](Self::connect_with_transport).
+    pub fn driver_version(&self) -> Option<crate::protocol::DriverVersion> {
+        self.connection().driver_version()
+    }
+
+    /// Returns which version-gated APIs the driver supports.
+    ///
+    /// APIs that need a newer driver than the installed one fail with
+    /// [`Error::UnsupportedByDriver`](crate::Error::UnsupportedByDriver);
+    /// check up front to skip them instead.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use playwright_rs::Capability;
+    ///
+    /// if playwright.capabilities().supports(Capability::AriaSnapshot) {
+    ///     let snapshot = page.locator("main").aria_snapshot().await?;
+    /// }
+    /// ```
+    pub fn capabilities(&self) -> crate::protocol::DriverCapabilities {
+        crate::protocol::DriverCapabilities {
+            version: self.driver_version(),
+        }
+    }
+
+    /// Returns the crashes seen so far, oldest first.
+    ///
+    /// Lists the driver exiting while in use, browsers that disconnected
+    /// without [`Browser::close`] and crashed pages. Calls cut short by a
+    /// driver crash fail with [`Error::Crashed`](crate::Error::Crashed)
+    /// carrying the same report.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let Err(e) = run_suite(&playwright).await {
+    ///     for report in playwright.crash_reports() {
+    ///         eprintln!("{}", report);
+    ///     }
+    /// }
+    /// ```
+    pub fn crash_reports(&self) -> Vec<crate::protocol::CrashReport> {
+        self.connection().crash_reports()
+    }
+
+    /// Returns counts of live objects, pending calls and driver memory usage.
+    ///
+    /// Useful for capacity planning and spotting leaked contexts or handles in
+    /// long-running services. `driver_memory_bytes` is only available on Linux.
+    pub async fn stats(&self) -> PlaywrightStats {
+        let connection = self.connection();
+        let driver_pid = self
+            .server
+            .lock()
+            .as_ref()
+            .and_then(|server| server.process.id());
+
+        PlaywrightStats {
+            objects: connection.object_counts(),
+            pending_calls: connection.pending_calls().await,
+            driver_memory_bytes: driver_pid.and_then(process_memory_bytes),
+        }
+    }
+
+    /// Returns a health snapshot for liveness and readiness probes.
+    ///
+    /// Does not send any protocol messages, so it is cheap enough to call on
+    /// every probe. See [`HealthReport`] for the checks.
+    pub async fn health(&self) -> HealthReport {
+        let connection = self.connection();
+        let driver_running = self
+            .server
+            .lock()
+            .as_mut()
+            .map(|server| matches!(server.process.try_wait(), Ok(None)));
+        let activity = connection.transport_activity();
+        let objects = connection.object_counts();
+        let browsers = self.browsers();
+
+        HealthReport {
+            driver_running,
+            transport_open: activity.as_ref().map_or(true, |a| !a.is_closed()),
+            pending_calls: connection.pending_calls().await,
+            idle_for: activity.map_or(Duration::ZERO, |a| a.idle_for()),
+            browsers: browsers.len(),
+            connected_browsers: browsers.iter().filter(|b| b.is_connected()).count(),
+            open_pages: objects.get("Page").copied().unwrap_or(0),
+            draining: self.is_draining(),
+        }
+    }
+
+    /// Returns everything recorded so far, if session recording is enabled.
+    ///
+    /// See [`PlaywrightConfig::record_session`](crate::PlaywrightConfig::record_session).
+    pub fn recorded_session(&self) -> Option<RecordedSession> {
+        self.connection().session_log().map(|log| log.snapshot())
+    }
+
+    /// Replays a recorded session against this instance.
+    ///
+    /// Every recorded call is re-sent in order, with object references mapped
+    /// to the objects created during this replay. This instance must have been
+    /// launched with `record_session(true)` so those creations can be tracked.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if recording is not enabled, and the
+    /// error of the first failing call otherwise.
+    pub async fn replay(
+        &self,
+        session: &RecordedSession,
+        options: ReplayOptions,
+    ) -> Result<ReplayReport> {
+        let connection = self.connection();
+        let log = connection.session_log().ok_or_else(|| {
+            crate::error::Error::InvalidArgument(
+                "replay() requires PlaywrightConfig::record_session(true)".to_string(),
+            )
+        })?;
+        crate::protocol::session_recording::replay(connection, &log, session, &options).await
+    }
+
+    /// Returns whether [`drain`](Playwright::drain) has been called
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    /// Gracefully drains this instance, e.g. from a Kubernetes preStop hook.
+    ///
+    /// Marks the instance as draining (so [`HealthReport::is_ready`] fails),
+    /// waits up to `grace_period` for open pages to be closed by in-flight
+    /// work, then closes every browser and shuts down the driver.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // On SIGTERM
+    /// let report = playwright.drain(Duration::from_secs(20)).await?;
+    /// tracing::info!("drained, {} page(s) abandoned", report.abandoned_pages);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the driver fails to shut down. Browser close errors
+    /// are logged and do not stop the drain.
+    pub async fn drain(&self, grace_period: Duration) -> Result<DrainReport> {
+        self.draining.store(true, Ordering::SeqCst);
+        let started = tokio::time::Instant::now();
+        let deadline = started + grace_period;
+
+        let mut open_pages = self.open_pages();
+        while open_pages > 0 && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+            open_pages = self.open_pages();
+        }
+        let waited = started.elapsed();
+
+        let mut browsers_closed = 0;
+        for browser in self.browsers() {
+            if !browser.is_connected() {
+                continue;
+            }
+            match browser.close().await {
+                Ok(()) => browsers_closed += 1,
+                Err(e) => tracing::warn!("Failed to close browser during drain: {}", e),
+            }
+        }
+
+        self.shutdown().await?;
+        Ok(DrainReport {
+            waited,
+            abandoned_pages: open_pages,
+            browsers_closed,
+        })
+    }
+
+    fn open_pages(&self) -> usize {
+        self.connection()
+            .object_counts()
+            .get("Page")
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Browsers currently registered on the connection
+    fn browsers(&self) -> Vec<Browser> {
+        self.connection()
+            .objects()
+            .iter()
+            .filter_map(|object| object.as_any().downcast_ref::<Browser>().cloned())
+            .collect()
+    }
+
     /// Shuts down the Playwright server gracefully.
     ///
     /// This method should be called when you're done using Playwright to ensure
@@ -327,6 +667,7 @@ impl Drop for Playwright {
     fn drop(&mut self) {
         if let Some(mut server) = self.server.lock().take() {
             tracing::debug!("Drop: Force-killing Playwright server");
+            server.stop_keep_alive();
 
             // We can't call async shutdown in Drop, so use blocking kill
             // This is less graceful but ensures the process terminates
@@ -361,4 +702,4 @@ impl std::fmt::Debug for Playwright {
 // - A real Connection with object registry
 // - BrowserType objects already created and registered
 // - Protocol messages from the server
-// See: crates/playwright-core/tests/connection_integration.rs
+// See: crates/playwright/tests/connection_integration.rs
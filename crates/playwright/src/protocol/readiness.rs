@@ -0,0 +1,173 @@
+// Readiness conditions - Named, reusable "app is ready" checks
+//
+// Teams usually have a handful of app-specific readiness signals ("hydrated",
+// "router idle", "fonts loaded"). Registering them once on a BrowserContext
+// lets page.wait_for_ready() and goto() await them by name instead of
+// repeating ad-hoc polling code in every test.
+
+use crate::error::{Error, Result};
+use parking_lot::Mutex;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Interval between predicate checks inside the page
+const POLL_INTERVAL_MS: u64 = 50;
+
+/// A named JavaScript readiness predicate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ReadinessCondition {
+    pub(crate) name: String,
+    pub(crate) predicate: String,
+}
+
+/// Readiness conditions registered on a BrowserContext
+#[derive(Debug, Default)]
+pub(crate) struct ReadinessRegistry {
+    conditions: Mutex<Vec<ReadinessCondition>>,
+}
+
+impl ReadinessRegistry {
+    /// Adds a condition, replacing any existing condition with the same name
+    pub(crate) fn insert(&self, name: String, predicate: String) {
+        let mut conditions = self.conditions.lock();
+        match conditions.iter_mut().find(|c| c.name == name) {
+            Some(existing) => existing.predicate = predicate,
+            None => conditions.push(ReadinessCondition { name, predicate }),
+        }
+    }
+
+    /// Removes a condition, returning whether it existed
+    pub(crate) fn remove(&self, name: &str) -> bool {
+        let mut conditions = self.conditions.lock();
+        let before = conditions.len();
+        conditions.retain(|c| c.name != name);
+        conditions.len() != before
+    }
+
+    /// Names of all registered conditions, in registration order
+    pub(crate) fn names(&self) -> Vec<String> {
+        self.conditions
+            .lock()
+            .iter()
+            .map(|c| c.name.clone())
+            .collect()
+    }
+
+    /// Looks up conditions by name; an empty list selects all of them
+    pub(crate) fn select(&self, names: &[String]) -> Result<Vec<ReadinessCondition>> {
+        let conditions = self.conditions.lock();
+        if names.is_empty() {
+            return Ok(conditions.clone());
+        }
+        names
+            .iter()
+            .map(|name| {
+                conditions
+                    .iter()
+                    .find(|c| &c.name == name)
+                    .cloned()
+                    .ok_or_else(|| {
+                        Error::InvalidArgument(format!(
+                            "No readiness condition named '{}' is registered",
+                            name
+                        ))
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Builds a script polling every condition until all are truthy or `timeout` elapses
+///
+/// Predicates are embedded as source rather than `eval`ed, so they work on
+/// pages with a strict Content-Security-Policy. A predicate may be an
+/// expression or a (possibly async) function.
+pub(crate) fn wait_script(conditions: &[ReadinessCondition], timeout: Duration) -> Result<String> {
+    let mut checks = String::new();
+    for condition in conditions {
+        checks.push_str(&format!(
+            "  [{}, async () => {{ const v = ({}); return typeof v === 'function' ? await v() : await v; }}],\n",
+            serde_json::to_string(&condition.name)?,
+            condition.predicate
+        ));
+    }
+    Ok(format!(
+        r#"async () => {{
+  const checks = [
+{checks}  ];
+  const deadline = performance.now() + {timeout};
+  let pending = checks.map(([name]) => name);
+  while (true) {{
+    const next = [];
+    for (const [name, check] of checks) {{
+      if (!pending.includes(name)) continue;
+      let ok = false;
+      try {{ ok = !!(await check()); }} catch (e) {{ ok = false; }}
+      if (!ok) next.push(name);
+    }}
+    pending = next;
+    if (pending.length === 0 || performance.now() >= deadline) return pending;
+    await new Promise((r) => setTimeout(r, {poll}));
+  }}
+}}"#,
+        checks = checks,
+        timeout = timeout.as_millis(),
+        poll = POLL_INTERVAL_MS
+    ))
+}
+
+/// Converts the script result (names still pending) into a Result
+pub(crate) fn check_result(value: serde_json::Value, timeout: Duration) -> Result<()> {
+    let pending: Vec<String> = Vec::<String>::deserialize(value)?;
+    if pending.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Timeout(format!(
+            "Readiness condition(s) {:?} not met within {:?}",
+            pending, timeout
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_insert_replace_remove() {
+        let registry = ReadinessRegistry::default();
+        registry.insert("hydrated".into(), "window.__hydrated".into());
+        registry.insert("fonts".into(), "document.fonts.status === 'loaded'".into());
+        registry.insert("hydrated".into(), "window.__app?.ready".into());
+
+        assert_eq!(registry.names(), vec!["hydrated", "fonts"]);
+        let selected = registry.select(&["hydrated".to_string()]).unwrap();
+        assert_eq!(selected[0].predicate, "window.__app?.ready");
+        assert_eq!(registry.select(&[]).unwrap().len(), 2);
+        assert!(matches!(
+            registry.select(&["missing".to_string()]),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        assert!(registry.remove("fonts"));
+        assert!(!registry.remove("fonts"));
+    }
+
+    #[test]
+    fn test_wait_script_embeds_predicates() {
+        let conditions = vec![ReadinessCondition {
+            name: "it's ready".into(),
+            predicate: "() => window.ready".into(),
+        }];
+        let script = wait_script(&conditions, Duration::from_secs(2)).unwrap();
+        assert!(script.contains(r#"["it's ready", async () => { const v = (() => window.ready);"#));
+        assert!(script.contains("performance.now() + 2000"));
+    }
+
+    #[test]
+    fn test_check_result() {
+        assert!(check_result(serde_json::json!([]), Duration::ZERO).is_ok());
+        let err = check_result(serde_json::json!(["hydrated"]), Duration::ZERO).unwrap_err();
+        assert!(err.to_string().contains("hydrated"));
+    }
+}
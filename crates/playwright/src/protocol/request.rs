@@ -4,7 +4,10 @@
 // In Playwright's architecture, navigation creates a Request which receives a Response.
 
 use crate::error::Result;
+use crate::protocol::response::resolve_response;
+use crate::protocol::Response;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
+use serde::Deserialize;
 use serde_json::Value;
 use std::any::Any;
 use std::sync::Arc;
@@ -71,6 +74,36 @@ impl Request {
             .unwrap_or("other")
     }
 
+    /// Returns the response for this request, waiting until it is received.
+    ///
+    /// Returns `None` if the request failed or was aborted before a response arrived.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-request#request-response>
+    pub async fn response(&self) -> Result<Option<Response>> {
+        #[derive(Deserialize)]
+        struct ResponseResult {
+            response: Option<ResponseReference>,
+        }
+
+        #[derive(Deserialize)]
+        struct ResponseReference {
+            #[serde(deserialize_with = "crate::server::connection::deserialize_arc_str")]
+            guid: Arc<str>,
+        }
+
+        let result: ResponseResult = self
+            .channel()
+            .send("response", serde_json::json!({}))
+            .await?;
+
+        match result.response {
+            Some(reference) => Ok(Some(
+                resolve_response(self.connection(), &reference.guid).await?,
+            )),
+            None => Ok(None),
+        }
+    }
+
     /// Check if this request is for a navigation (main document).
     ///
     /// A navigation request is when the request is for the main frame's document.
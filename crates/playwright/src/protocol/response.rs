@@ -4,8 +4,10 @@
 // Response objects are created by the server when Frame.goto() or similar navigation
 // methods complete successfully.
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::protocol::Response;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
+use crate::server::connection::ConnectionLike;
 use serde_json::Value;
 use std::any::Any;
 use std::sync::Arc;
@@ -43,6 +45,65 @@ impl ResponseObject {
     }
 }
 
+/// Looks up a Response object by GUID and converts it to a [`Response`].
+///
+/// The server may reference a Response before its `__create__` message has been
+/// processed, so the lookup is retried briefly.
+// TODO(Phase 4+): Implement proper GUID replacement like Python's _replace_guids_with_channels
+//   - Eliminates retry loop for better performance
+//   - See: playwright-python's _replace_guids_with_channels method
+pub(crate) async fn resolve_response(
+    connection: Arc<dyn ConnectionLike>,
+    guid: &str,
+) -> Result<Response> {
+    let response_arc = {
+        let mut attempts = 0;
+        let max_attempts = 20; // 20 * 50ms = 1 second max wait
+        loop {
+            match connection.get_object(guid).await {
+                Ok(obj) => break obj,
+                Err(_) if attempts < max_attempts => {
+                    attempts += 1;
+                    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    };
+
+    // We extract Response data from the initializer rather than wrapping the protocol object
+    let initializer = response_arc.initializer();
+
+    // Extract response data from initializer
+    let status = initializer["status"]
+        .as_u64()
+        .ok_or_else(|| Error::ProtocolError("Response missing status".to_string()))?
+        as u16;
+
+    // Convert headers from array format to HashMap
+    let headers = initializer["headers"]
+        .as_array()
+        .ok_or_else(|| Error::ProtocolError("Response missing headers".to_string()))?
+        .iter()
+        .filter_map(|h| {
+            let name = h["name"].as_str()?;
+            let value = h["value"].as_str()?;
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect();
+
+    Ok(Response {
+        url: initializer["url"]
+            .as_str()
+            .ok_or_else(|| Error::ProtocolError("Response missing url".to_string()))?
+            .to_string(),
+        status,
+        status_text: initializer["statusText"].as_str().unwrap_or("").to_string(),
+        ok: (200..300).contains(&status), // Compute ok from status code
+        headers,
+    })
+}
+
 impl ChannelOwner for ResponseObject {
     fn guid(&self) -> &str {
         self.base.guid()
@@ -199,4 +199,4 @@ impl std::fmt::Debug for Root {
 // Note: Root object testing is done via integration tests since it requires:
 // - A real Connection to send messages
 // - A real Playwright server to respond
-// See: crates/playwright-core/tests/initialization_integration.rs
+// See: crates/playwright/tests/initialization_integration.rs
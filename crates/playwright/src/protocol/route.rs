@@ -122,7 +122,11 @@ impl Route {
     /// * `overrides` - Optional modifications to apply to the request
     ///
     /// See: <https://playwright.dev/docs/api/class-route#route-continue>
-    pub async fn continue_(&self, overrides: Option<ContinueOptions>) -> Result<()> {
+    pub async fn continue_(
+        &self,
+        overrides: impl crate::protocol::IntoOptions<ContinueOptions>,
+    ) -> Result<()> {
+        let overrides = overrides.into_options();
         let mut params = json!({
             "isFallback": false
         });
@@ -192,7 +196,11 @@ impl Route {
     /// or wait for a newer Playwright version that supports response body fulfillment.
     ///
     /// See: <https://playwright.dev/docs/api/class-route#route-fulfill>
-    pub async fn fulfill(&self, options: Option<FulfillOptions>) -> Result<()> {
+    pub async fn fulfill(
+        &self,
+        options: impl crate::protocol::IntoOptions<FulfillOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
         let opts = options.unwrap_or_default();
 
         // Build the response object for the protocol
@@ -2,14 +2,17 @@
 //
 // Provides configuration for page and element screenshots, matching Playwright's API.
 
+use super::options::options;
+use crate::protocol::Locator;
 use serde::Serialize;
+use std::time::Duration;
 
 /// Screenshot image format
 ///
 /// # Example
 ///
 /// ```ignore
-/// use playwright_rs::protocol::ScreenshotType;
+/// use playwright_rs::ScreenshotType;
 ///
 /// let screenshot_type = ScreenshotType::Jpeg;
 /// ```
@@ -22,6 +25,19 @@ pub enum ScreenshotType {
     Jpeg,
 }
 
+/// How CSS animations and transitions are handled while taking a screenshot
+///
+/// See: <https://playwright.dev/docs/api/class-page#page-screenshot-option-animations>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreenshotAnimations {
+    /// Finite animations are fast-forwarded to completion, infinite ones are
+    /// cancelled to their initial state
+    Disabled,
+    /// Animations are left running (default)
+    Allow,
+}
+
 /// Clip region for screenshot
 ///
 /// Specifies a rectangular region to capture.
@@ -29,7 +45,7 @@ pub enum ScreenshotType {
 /// # Example
 ///
 /// ```ignore
-/// use playwright_rs::protocol::ScreenshotClip;
+/// use playwright_rs::ScreenshotClip;
 ///
 /// let clip = ScreenshotClip {
 ///     x: 10.0,
@@ -50,161 +66,91 @@ pub struct ScreenshotClip {
     pub height: f64,
 }
 
-/// Screenshot options
-///
-/// Configuration options for page and element screenshots.
-///
-/// Use the builder pattern to construct options:
-///
-/// # Example
-///
-/// ```ignore
-/// use playwright_rs::protocol::{ScreenshotOptions, ScreenshotType, ScreenshotClip};
-///
-/// // JPEG with quality
-/// let options = ScreenshotOptions::builder()
-///     .screenshot_type(ScreenshotType::Jpeg)
-///     .quality(80)
-///     .build();
-///
-/// // Full page screenshot
-/// let options = ScreenshotOptions::builder()
-///     .full_page(true)
-///     .build();
-///
-/// // Clip region
-/// let clip = ScreenshotClip {
-///     x: 10.0,
-///     y: 10.0,
-///     width: 200.0,
-///     height: 100.0,
-/// };
-/// let options = ScreenshotOptions::builder()
-///     .clip(clip)
-///     .build();
-/// ```
-///
-/// See: <https://playwright.dev/docs/api/class-page#page-screenshot>
-#[derive(Debug, Clone, Default)]
-pub struct ScreenshotOptions {
-    /// Image format (png or jpeg)
-    pub screenshot_type: Option<ScreenshotType>,
-    /// JPEG quality (0-100), only applies to jpeg format
-    pub quality: Option<u8>,
-    /// Capture full scrollable page
-    pub full_page: Option<bool>,
-    /// Clip region to capture
-    pub clip: Option<ScreenshotClip>,
-    /// Hide default white background (PNG only)
-    pub omit_background: Option<bool>,
-    /// Screenshot timeout in milliseconds
-    pub timeout: Option<f64>,
-}
-
-impl ScreenshotOptions {
-    /// Create a new builder for ScreenshotOptions
-    pub fn builder() -> ScreenshotOptionsBuilder {
-        ScreenshotOptionsBuilder::default()
-    }
-
-    /// Convert options to JSON value for protocol
-    pub(crate) fn to_json(&self) -> serde_json::Value {
-        let mut json = serde_json::json!({});
-
-        if let Some(screenshot_type) = &self.screenshot_type {
-            json["type"] = serde_json::to_value(screenshot_type).unwrap();
-        }
-
-        if let Some(quality) = self.quality {
-            json["quality"] = serde_json::json!(quality);
-        }
-
-        if let Some(full_page) = self.full_page {
-            json["fullPage"] = serde_json::json!(full_page);
-        }
-
-        if let Some(clip) = &self.clip {
-            json["clip"] = serde_json::to_value(clip).unwrap();
-        }
-
-        if let Some(omit_background) = self.omit_background {
-            json["omitBackground"] = serde_json::json!(omit_background);
-        }
-
-        // Timeout is required in Playwright 1.56.1+
-        if let Some(timeout) = self.timeout {
-            json["timeout"] = serde_json::json!(timeout);
-        } else {
-            json["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
-        }
-
-        json
-    }
-}
-
-/// Builder for ScreenshotOptions
-///
-/// Provides a fluent API for constructing screenshot options.
-#[derive(Debug, Clone, Default)]
-pub struct ScreenshotOptionsBuilder {
-    screenshot_type: Option<ScreenshotType>,
-    quality: Option<u8>,
-    full_page: Option<bool>,
-    clip: Option<ScreenshotClip>,
-    omit_background: Option<bool>,
-    timeout: Option<f64>,
-}
-
-impl ScreenshotOptionsBuilder {
-    /// Set the screenshot format (png or jpeg)
-    pub fn screenshot_type(mut self, screenshot_type: ScreenshotType) -> Self {
-        self.screenshot_type = Some(screenshot_type);
-        self
-    }
-
-    /// Set JPEG quality (0-100)
+options! {
+    /// Screenshot options
     ///
-    /// Only applies when screenshot_type is Jpeg.
-    pub fn quality(mut self, quality: u8) -> Self {
-        self.quality = Some(quality);
-        self
-    }
-
-    /// Capture full scrollable page beyond viewport
-    pub fn full_page(mut self, full_page: bool) -> Self {
-        self.full_page = Some(full_page);
-        self
-    }
-
-    /// Set clip region to capture
-    pub fn clip(mut self, clip: ScreenshotClip) -> Self {
-        self.clip = Some(clip);
-        self
-    }
-
-    /// Hide default white background (creates transparent PNG)
-    pub fn omit_background(mut self, omit_background: bool) -> Self {
-        self.omit_background = Some(omit_background);
-        self
-    }
-
-    /// Set screenshot timeout in milliseconds
-    pub fn timeout(mut self, timeout: f64) -> Self {
-        self.timeout = Some(timeout);
-        self
+    /// Configuration options for page and element screenshots.
+    ///
+    /// Use the builder pattern to construct options:
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use playwright_rs::{ScreenshotAnimations, ScreenshotOptions, ScreenshotType, ScreenshotClip};
+    ///
+    /// // JPEG with quality
+    /// let options = ScreenshotOptions::builder()
+    ///     .screenshot_type(ScreenshotType::Jpeg)
+    ///     .quality(80)
+    ///     .build();
+    ///
+    /// // Full page screenshot
+    /// let options = ScreenshotOptions::builder()
+    ///     .full_page(true)
+    ///     .build();
+    ///
+    /// // Clip region
+    /// let clip = ScreenshotClip {
+    ///     x: 10.0,
+    ///     y: 10.0,
+    ///     width: 200.0,
+    ///     height: 100.0,
+    /// };
+    /// let options = ScreenshotOptions::builder()
+    ///     .clip(clip)
+    ///     .build();
+    ///
+    /// // Stable capture of a component: no animations, dynamic parts masked
+    /// let options = ScreenshotOptions::builder()
+    ///     .animations(ScreenshotAnimations::Disabled)
+    ///     .mask(vec![page.locator(".timestamp")])
+    ///     .build();
+    /// ```
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-screenshot>
+    pub struct ScreenshotOptions => ScreenshotOptionsBuilder {
+        /// Image format (png or jpeg)
+        #[serde(rename = "type")]
+        pub screenshot_type: ScreenshotType,
+        /// JPEG quality (0-100), only applies to jpeg format
+        pub quality: u8,
+        /// Capture full scrollable page beyond viewport
+        pub full_page: bool,
+        /// Clip region to capture
+        pub clip: ScreenshotClip,
+        /// Hide default white background (creates transparent PNG)
+        pub omit_background: bool,
+        /// How CSS animations and transitions are handled
+        pub animations: ScreenshotAnimations,
+        /// Elements to cover with a box of `mask_color`, e.g. timestamps or
+        /// avatars that change between runs
+        #[serde(serialize_with = "serialize_mask")]
+        pub mask: Vec<Locator>,
+        /// CSS color of the mask boxes (default pink `#FF00FF`)
+        #[builder(into)]
+        pub mask_color: String,
+        /// Screenshot timeout (required by Playwright 1.56.1+)
+        #[serde(with = "crate::protocol::options::millis")]
+        pub timeout: Duration = crate::DEFAULT_TIMEOUT,
     }
+}
 
-    /// Build the ScreenshotOptions
-    pub fn build(self) -> ScreenshotOptions {
-        ScreenshotOptions {
-            screenshot_type: self.screenshot_type,
-            quality: self.quality,
-            full_page: self.full_page,
-            clip: self.clip,
-            omit_background: self.omit_background,
-            timeout: self.timeout,
-        }
-    }
+/// Sends masked locators as the driver's `{frame, selector}` pairs
+fn serialize_mask<S: serde::Serializer>(
+    mask: &Option<Vec<Locator>>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    let mask: Option<Vec<serde_json::Value>> = mask.as_ref().map(|mask| {
+        mask.iter()
+            .map(|locator| {
+                serde_json::json!({
+                    "frame": { "guid": locator.frame_guid() },
+                    "selector": locator.selector(),
+                })
+            })
+            .collect()
+    });
+    mask.serialize(serializer)
 }
 
 #[cfg(test)]
@@ -268,13 +214,26 @@ mod tests {
         assert_eq!(json["omitBackground"], true);
     }
 
+    #[test]
+    fn test_builder_animations_and_mask_color() {
+        let options = ScreenshotOptions::builder()
+            .animations(ScreenshotAnimations::Disabled)
+            .mask_color("#000000")
+            .build();
+
+        let json = options.to_json();
+        assert_eq!(json["animations"], "disabled");
+        assert_eq!(json["maskColor"], "#000000");
+        assert!(json.get("mask").is_none());
+    }
+
     #[test]
     fn test_builder_multiple_options() {
         let options = ScreenshotOptions::builder()
             .screenshot_type(ScreenshotType::Jpeg)
             .quality(90)
             .full_page(true)
-            .timeout(5000.0)
+            .timeout(Duration::from_secs(5))
             .build();
 
         let json = options.to_json();
@@ -9,7 +9,7 @@
 /// # Example
 ///
 /// ```ignore
-/// use playwright_rs::protocol::SelectOption;
+/// use playwright_rs::SelectOption;
 ///
 /// // Select by value
 /// let opt = SelectOption::Value("option1".to_string());
@@ -0,0 +1,57 @@
+// Selectors - Process-wide selector configuration
+//
+// Mirrors playwright.selectors in the other bindings. The test id attribute is
+// a client-side setting: get_by_test_id() embeds it in the `internal:testid`
+// selector it builds, so changing it affects locators created afterwards in
+// every context, like the module-level setting in playwright-core.
+//
+// See: https://playwright.dev/docs/api/class-selectors
+
+use parking_lot::RwLock;
+
+/// Attribute used by `get_by_test_id` unless changed
+pub const DEFAULT_TEST_ID_ATTRIBUTE: &str = "data-testid";
+
+static TEST_ID_ATTRIBUTE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Selector configuration, obtained via [`Playwright::selectors`](crate::protocol::Playwright::selectors).
+///
+/// # Example
+///
+/// ```ignore
+/// playwright.selectors().set_test_id_attribute("data-qa");
+/// page.get_by_test_id("checkout").click(None).await?; // [data-qa="checkout"]
+/// ```
+///
+/// See: <https://playwright.dev/docs/api/class-selectors>
+#[derive(Debug, Clone, Copy)]
+pub struct Selectors {
+    _private: (),
+}
+
+impl Selectors {
+    pub(crate) fn new() -> Self {
+        Self { _private: () }
+    }
+
+    /// Sets the attribute matched by `get_by_test_id` (default: `data-testid`).
+    ///
+    /// Applies process-wide to locators created after the call.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-selectors#selectors-set-test-id-attribute>
+    pub fn set_test_id_attribute(&self, attribute: impl Into<String>) {
+        *TEST_ID_ATTRIBUTE.write() = Some(attribute.into());
+    }
+
+    /// The attribute currently matched by `get_by_test_id`
+    pub fn test_id_attribute(&self) -> String {
+        test_id_attribute()
+    }
+}
+
+pub(crate) fn test_id_attribute() -> String {
+    TEST_ID_ATTRIBUTE
+        .read()
+        .clone()
+        .unwrap_or_else(|| DEFAULT_TEST_ID_ATTRIBUTE.to_string())
+}
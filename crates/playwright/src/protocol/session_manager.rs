@@ -0,0 +1,290 @@
+// Session manager - Logging in once and reusing the authenticated state
+//
+// Logging in through the UI in every test is slow. SessionManager runs a
+// user-provided login closure once per user, captures the context's storage
+// state (cookies + localStorage), optionally persists it to disk, and creates
+// pre-authenticated contexts from it. An optional validator detects expired
+// sessions; failed validation or an explicit invalidate() discards the cached
+// state so the next request logs in again.
+
+use crate::error::{Error, Result};
+use crate::protocol::{Browser, BrowserContext, BrowserContextOptions, Page, StorageState};
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+type SessionFuture<T> = Pin<Box<dyn Future<Output = Result<T>> + Send>>;
+
+/// Login closure: receives a fresh page and the user name
+type LoginFn = Arc<dyn Fn(Page, String) -> SessionFuture<()> + Send + Sync>;
+
+/// Validator closure: receives a page in a restored context, returns whether it is logged in
+type ValidateFn = Arc<dyn Fn(Page, String) -> SessionFuture<bool> + Send + Sync>;
+
+struct CachedSession {
+    state: StorageState,
+    captured_at: Instant,
+}
+
+/// Hands out pre-authenticated browser contexts, logging in once per user.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::SessionManager;
+///
+/// let sessions = SessionManager::new(browser.clone(), |page, user| async move {
+///     page.goto("https://app.example.com/login", None).await?;
+///     page.locator("#user").fill(&user, None).await?;
+///     page.locator("#password").fill("secret", None).await?;
+///     page.locator("button[type=submit]").click(None).await?;
+///     page.wait_for_load_state(None, None).await?;
+///     Ok(())
+/// })
+/// .cache_dir(".auth")
+/// .validate(|page, _user| async move {
+///     page.goto("https://app.example.com/account", None).await?;
+///     Ok(!page.url().contains("/login"))
+/// });
+///
+/// let context = sessions.context("alice").await?; // logs in
+/// let again = sessions.context("alice").await?;   // reuses the cached state
+/// ```
+pub struct SessionManager {
+    browser: Browser,
+    login: LoginFn,
+    validate: Option<ValidateFn>,
+    context_options: BrowserContextOptions,
+    cache_dir: Option<PathBuf>,
+    max_age: Option<Duration>,
+    sessions: Mutex<HashMap<String, Arc<Mutex<Option<CachedSession>>>>>,
+}
+
+impl SessionManager {
+    /// Creates a manager that logs users in with `login`
+    pub fn new<F, Fut>(browser: Browser, login: F) -> Self
+    where
+        F: Fn(Page, String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        Self {
+            browser,
+            login: Arc::new(move |page, user| -> SessionFuture<()> { Box::pin(login(page, user)) }),
+            validate: None,
+            context_options: BrowserContextOptions::default(),
+            cache_dir: None,
+            max_age: None,
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Checks restored sessions before handing them out.
+    ///
+    /// Called with a page in the restored context; return `Ok(false)` if the
+    /// session is no longer authenticated. The cached state is then discarded
+    /// and the user logs in again.
+    pub fn validate<F, Fut>(mut self, validate: F) -> Self
+    where
+        F: Fn(Page, String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<bool>> + Send + 'static,
+    {
+        self.validate = Some(Arc::new(move |page, user| -> SessionFuture<bool> {
+            Box::pin(validate(page, user))
+        }));
+        self
+    }
+
+    /// Options for every context created by the manager (including login contexts).
+    ///
+    /// Any storage state in the options is replaced by the user's session.
+    pub fn context_options(mut self, options: BrowserContextOptions) -> Self {
+        self.context_options = options;
+        self
+    }
+
+    /// Persists storage state to `<dir>/<user>.json` so sessions survive restarts
+    pub fn cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    /// Logs in again once a cached session is older than `max_age`
+    ///
+    /// Applies to sessions captured by this manager; states loaded from
+    /// `cache_dir` are aged from the moment they are loaded.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Returns a new context authenticated as `user`.
+    ///
+    /// Logs in on first use (or after invalidation). Concurrent requests for
+    /// the same user wait for a single login.
+    pub async fn context(&self, user: &str) -> Result<BrowserContext> {
+        let slot = self.slot(user).await;
+        let mut session = slot.lock().await;
+
+        if session.as_ref().is_some_and(|s| {
+            self.max_age
+                .is_some_and(|max| s.captured_at.elapsed() >= max)
+        }) {
+            *session = None;
+        }
+        if session.is_none() {
+            *session = self.load(user).await;
+        }
+
+        if let Some(cached) = session.as_ref() {
+            let context = self.new_context(Some(cached.state.clone())).await?;
+            if self.is_valid(&context, user).await? {
+                return Ok(context);
+            }
+            tracing::debug!("Cached session for '{}' failed validation", user);
+            context.close().await?;
+            *session = None;
+            self.remove_file(user).await?;
+        }
+
+        let state = self.log_in(user).await?;
+        self.save(user, &state).await?;
+        let context = self.new_context(Some(state.clone())).await?;
+        *session = Some(CachedSession {
+            state,
+            captured_at: Instant::now(),
+        });
+        Ok(context)
+    }
+
+    /// Discards the cached session for `user`, e.g. after a 401 mid-test.
+    ///
+    /// The next [`context`](SessionManager::context) call logs in again.
+    pub async fn invalidate(&self, user: &str) -> Result<()> {
+        let slot = self.slot(user).await;
+        *slot.lock().await = None;
+        self.remove_file(user).await
+    }
+
+    /// Returns the cached storage state for `user`, if any
+    pub async fn storage_state(&self, user: &str) -> Option<StorageState> {
+        let slot = self.slot(user).await;
+        let session = slot.lock().await;
+        session.as_ref().map(|s| s.state.clone())
+    }
+
+    async fn slot(&self, user: &str) -> Arc<Mutex<Option<CachedSession>>> {
+        let mut sessions = self.sessions.lock().await;
+        Arc::clone(sessions.entry(user.to_string()).or_default())
+    }
+
+    async fn new_context(&self, state: Option<StorageState>) -> Result<BrowserContext> {
+        let mut options = self.context_options.clone();
+        options.storage_state = state;
+        options.storage_state_path = None;
+        self.browser.new_context_with_options(options).await
+    }
+
+    async fn log_in(&self, user: &str) -> Result<StorageState> {
+        let context = self.new_context(None).await?;
+        let result = async {
+            let page = context.new_page().await?;
+            (self.login)(page, user.to_string()).await?;
+            context.storage_state().await
+        }
+        .await;
+        let _ = context.close().await;
+        result
+    }
+
+    async fn is_valid(&self, context: &BrowserContext, user: &str) -> Result<bool> {
+        let Some(validate) = &self.validate else {
+            return Ok(true);
+        };
+        let page = context.new_page().await?;
+        let valid = validate(page.clone(), user.to_string()).await?;
+        page.close().await?;
+        Ok(valid)
+    }
+
+    fn path(&self, user: &str) -> Option<PathBuf> {
+        self.cache_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{}.json", file_name(user))))
+    }
+
+    async fn load(&self, user: &str) -> Option<CachedSession> {
+        let path = self.path(user)?;
+        let content = tokio::fs::read_to_string(&path).await.ok()?;
+        match serde_json::from_str(&content) {
+            Ok(state) => Some(CachedSession {
+                state,
+                captured_at: Instant::now(),
+            }),
+            Err(e) => {
+                tracing::warn!("Ignoring unreadable session file {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    async fn save(&self, user: &str, state: &StorageState) -> Result<()> {
+        let Some(path) = self.path(user) else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await?;
+        }
+        tokio::fs::write(&path, serde_json::to_vec_pretty(state)?).await?;
+        Ok(())
+    }
+
+    async fn remove_file(&self, user: &str) -> Result<()> {
+        let Some(path) = self.path(user) else {
+            return Ok(());
+        };
+        match tokio::fs::remove_file(&path).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(Error::Io(e)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Debug for SessionManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionManager")
+            .field("cache_dir", &self.cache_dir)
+            .field("max_age", &self.max_age)
+            .finish()
+    }
+}
+
+/// Maps a user name to a safe file name
+fn file_name(user: &str) -> String {
+    user.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>()
+        .trim_start_matches('.')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name("alice"), "alice");
+        assert_eq!(file_name("bob@example.com"), "bob_example.com");
+        assert_eq!(file_name("../etc/passwd"), "_etc_passwd");
+    }
+}
@@ -0,0 +1,490 @@
+// Session recording - Replayable logs of protocol calls
+//
+// When enabled via PlaywrightConfig::record_session, the connection logs every
+// protocol call (target object, method, arguments) and every object the driver
+// creates. The log can be saved as JSON and replayed against a fresh instance,
+// giving bug reports an exact reproduction that does not depend on test code.
+//
+// Object GUIDs differ between runs, so replay maps recorded GUIDs to live ones
+// by creation order: the n-th object of a given type created under a given
+// parent in the recording corresponds to the n-th such object in the replay.
+
+use crate::error::{Error, Result};
+use crate::server::connection::ConnectionLike;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Current recording format version
+const FORMAT_VERSION: u32 = 1;
+
+/// One step of a recorded session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum SessionEntry {
+    /// A protocol call sent to the driver
+    Call {
+        /// Target object GUID
+        guid: String,
+        /// Protocol method (e.g. "goto", "click", "fill")
+        method: String,
+        /// Method arguments
+        params: Value,
+    },
+    /// An object created by the driver
+    #[serde(rename_all = "camelCase")]
+    Create {
+        /// New object GUID
+        guid: String,
+        /// Parent object GUID
+        parent: String,
+        /// Protocol type name (e.g. "Page")
+        type_name: String,
+    },
+}
+
+/// A recorded session that can be saved, loaded and replayed.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::{Playwright, PlaywrightConfig};
+/// use playwright_rs::{RecordedSession, ReplayOptions};
+///
+/// // Record
+/// let playwright =
+///     Playwright::launch_with_config(PlaywrightConfig::new().record_session(true)).await?;
+/// // ... drive the browser ...
+/// playwright.recorded_session().unwrap().save("repro.json").await?;
+///
+/// // Replay later, e.g. from a bug report
+/// let session = RecordedSession::load("repro.json").await?;
+/// let playwright =
+///     Playwright::launch_with_config(PlaywrightConfig::new().record_session(true)).await?;
+/// playwright.replay(&session, ReplayOptions::new()).await?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedSession {
+    /// Format version
+    pub version: u32,
+    /// Calls and object creations in the order they happened
+    pub entries: Vec<SessionEntry>,
+}
+
+impl RecordedSession {
+    /// Number of recorded calls
+    pub fn call_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| matches!(e, SessionEntry::Call { .. }))
+            .count()
+    }
+
+    /// Parses a session from JSON
+    pub fn from_json(json: &str) -> Result<Self> {
+        let session: Self = serde_json::from_str(json)?;
+        if session.version != FORMAT_VERSION {
+            return Err(Error::InvalidArgument(format!(
+                "Unsupported session recording version {} (expected {})",
+                session.version, FORMAT_VERSION
+            )));
+        }
+        Ok(session)
+    }
+
+    /// Serializes the session as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Loads a session from a JSON file
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_json(&tokio::fs::read_to_string(path).await?)
+    }
+
+    /// Saves the session to a JSON file
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(tokio::fs::write(path, self.to_json()?).await?)
+    }
+}
+
+/// Options for [`Playwright::replay`](crate::protocol::Playwright::replay)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayOptions {
+    /// Pause between calls, e.g. to watch a headed replay
+    pub delay: Option<Duration>,
+    /// How long to wait for the driver to create an object a call refers to
+    pub object_timeout: Duration,
+}
+
+impl Default for ReplayOptions {
+    fn default() -> Self {
+        Self {
+            delay: None,
+            object_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+impl ReplayOptions {
+    /// Creates options with the defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a pause between calls
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Sets how long to wait for referenced objects to be created
+    pub fn object_timeout(mut self, timeout: Duration) -> Self {
+        self.object_timeout = timeout;
+        self
+    }
+}
+
+/// Outcome of a successful replay
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayReport {
+    /// Number of calls replayed
+    pub calls: usize,
+    /// Result of each call, in order
+    pub results: Vec<Value>,
+}
+
+/// Append-only log of calls and creations, attached to a connection (internal)
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct SessionLog {
+    entries: Mutex<Vec<SessionEntry>>,
+}
+
+impl SessionLog {
+    pub(crate) fn record_call(&self, guid: &str, method: &str, params: &Value) {
+        // The handshake is performed by every instance and is not replayed
+        if guid.is_empty() && method == "initialize" {
+            return;
+        }
+        self.entries.lock().push(SessionEntry::Call {
+            guid: guid.to_string(),
+            method: method.to_string(),
+            params: params.clone(),
+        });
+    }
+
+    pub(crate) fn record_create(&self, guid: &str, parent: &str, type_name: &str) {
+        self.entries.lock().push(SessionEntry::Create {
+            guid: guid.to_string(),
+            parent: parent.to_string(),
+            type_name: type_name.to_string(),
+        });
+    }
+
+    pub(crate) fn snapshot(&self) -> RecordedSession {
+        RecordedSession {
+            version: FORMAT_VERSION,
+            entries: self.entries.lock().clone(),
+        }
+    }
+
+    /// GUID of the `ordinal`-th object of `type_name` created under `parent`
+    fn nth_created(&self, parent: &str, type_name: &str, ordinal: usize) -> Option<String> {
+        self.entries
+            .lock()
+            .iter()
+            .filter_map(|entry| match entry {
+                SessionEntry::Create {
+                    guid,
+                    parent: p,
+                    type_name: t,
+                } if p == parent && t == type_name => Some(guid.clone()),
+                _ => None,
+            })
+            .nth(ordinal)
+    }
+}
+
+/// Where a recorded object sits in the creation order
+#[derive(Debug, Clone)]
+struct CreationKey {
+    parent: String,
+    type_name: String,
+    ordinal: usize,
+}
+
+/// Maps recorded GUIDs to the GUIDs of the corresponding live objects
+struct GuidMapper<'a> {
+    recorded: HashMap<String, CreationKey>,
+    live: &'a SessionLog,
+    resolved: HashMap<String, String>,
+    timeout: Duration,
+}
+
+impl<'a> GuidMapper<'a> {
+    fn new(session: &RecordedSession, live: &'a SessionLog, timeout: Duration) -> Self {
+        let mut counts: HashMap<(String, String), usize> = HashMap::new();
+        let mut recorded = HashMap::new();
+        for entry in &session.entries {
+            if let SessionEntry::Create {
+                guid,
+                parent,
+                type_name,
+            } = entry
+            {
+                let count = counts
+                    .entry((parent.clone(), type_name.clone()))
+                    .or_insert(0);
+                recorded.insert(
+                    guid.clone(),
+                    CreationKey {
+                        parent: parent.clone(),
+                        type_name: type_name.clone(),
+                        ordinal: *count,
+                    },
+                );
+                *count += 1;
+            }
+        }
+        Self {
+            recorded,
+            live,
+            resolved: HashMap::from([(String::new(), String::new())]),
+            timeout,
+        }
+    }
+
+    async fn map(&mut self, recorded_guid: &str) -> Result<String> {
+        // Resolve the chain of unresolved ancestors, outermost first
+        let mut chain = Vec::new();
+        let mut current = recorded_guid.to_string();
+        while !self.resolved.contains_key(&current) {
+            let key = self.recorded.get(&current).cloned().ok_or_else(|| {
+                Error::InvalidArgument(format!(
+                    "Recorded session refers to unknown object '{}'",
+                    current
+                ))
+            })?;
+            chain.push((current, key.clone()));
+            current = key.parent;
+        }
+
+        for (guid, key) in chain.into_iter().rev() {
+            let live_parent = self.resolved[&key.parent].clone();
+            let live_guid = self.wait_for_live(&live_parent, &key).await?;
+            self.resolved.insert(guid, live_guid);
+        }
+        Ok(self.resolved[recorded_guid].clone())
+    }
+
+    async fn wait_for_live(&self, live_parent: &str, key: &CreationKey) -> Result<String> {
+        let deadline = tokio::time::Instant::now() + self.timeout;
+        loop {
+            if let Some(guid) = self
+                .live
+                .nth_created(live_parent, &key.type_name, key.ordinal)
+            {
+                return Ok(guid);
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Err(Error::Timeout(format!(
+                    "Replay: {} #{} was not created within {:?}",
+                    key.type_name, key.ordinal, self.timeout
+                )));
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    }
+
+    /// Rewrites every `{"guid": ...}` reference to a recorded object in `value`
+    async fn map_value(&mut self, value: &Value) -> Result<Value> {
+        let mut guids = Vec::new();
+        collect_guids(value, &mut guids);
+        for guid in guids {
+            if self.recorded.contains_key(&guid) {
+                self.map(&guid).await?;
+            }
+        }
+        Ok(rewrite_guids(value, &self.resolved))
+    }
+}
+
+fn collect_guids(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map {
+                match (key.as_str(), item) {
+                    ("guid", Value::String(guid)) => out.push(guid.clone()),
+                    _ => collect_guids(item, out),
+                }
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|item| collect_guids(item, out)),
+        _ => {}
+    }
+}
+
+fn rewrite_guids(value: &Value, resolved: &HashMap<String, String>) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, item)| {
+                    let mapped = match (key.as_str(), item) {
+                        ("guid", Value::String(guid)) => resolved
+                            .get(guid)
+                            .map(|live| Value::String(live.clone()))
+                            .unwrap_or_else(|| item.clone()),
+                        _ => rewrite_guids(item, resolved),
+                    };
+                    (key.clone(), mapped)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| rewrite_guids(item, resolved))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Replays `session` on `connection`, whose own creations are logged in `live`
+pub(crate) async fn replay(
+    connection: Arc<dyn ConnectionLike>,
+    live: &SessionLog,
+    session: &RecordedSession,
+    options: &ReplayOptions,
+) -> Result<ReplayReport> {
+    let mut mapper = GuidMapper::new(session, live, options.object_timeout);
+    let mut results = Vec::new();
+
+    for (step, entry) in session.entries.iter().enumerate() {
+        let SessionEntry::Call {
+            guid,
+            method,
+            params,
+        } = entry
+        else {
+            continue;
+        };
+
+        let live_guid = mapper.map(guid).await?;
+        let live_params = mapper.map_value(params).await?;
+        tracing::debug!("Replay step {}: {}.{}", step, live_guid, method);
+        let result = connection
+            .send_message(&live_guid, method, live_params)
+            .await
+            .map_err(|e| {
+                tracing::error!("Replay step {} ({}) failed: {}", step, method, e);
+                e
+            })?;
+        results.push(result);
+
+        if let Some(delay) = options.delay {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    Ok(ReplayReport {
+        calls: results.len(),
+        results,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create(guid: &str, parent: &str, type_name: &str) -> SessionEntry {
+        SessionEntry::Create {
+            guid: guid.into(),
+            parent: parent.into(),
+            type_name: type_name.into(),
+        }
+    }
+
+    #[test]
+    fn test_log_skips_handshake() {
+        let log = SessionLog::default();
+        log.record_call("", "initialize", &serde_json::json!({}));
+        log.record_call(
+            "page@1",
+            "goto",
+            &serde_json::json!({"url": "https://example.com"}),
+        );
+        assert_eq!(log.snapshot().call_count(), 1);
+    }
+
+    #[test]
+    fn test_json_round_trip_and_version_check() {
+        let log = SessionLog::default();
+        log.record_create("page@1", "context@1", "Page");
+        log.record_call("page@1", "goto", &serde_json::json!({"url": "about:blank"}));
+        let session = log.snapshot();
+
+        let json = session.to_json().unwrap();
+        assert!(json.contains(r#""kind": "create""#));
+        assert!(json.contains(r#""typeName": "Page""#));
+        assert_eq!(RecordedSession::from_json(&json).unwrap(), session);
+
+        let future = json.replace(r#""version": 1"#, r#""version": 99"#);
+        assert!(matches!(
+            RecordedSession::from_json(&future),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_guid_mapping_by_creation_order() {
+        let session = RecordedSession {
+            version: FORMAT_VERSION,
+            entries: vec![
+                create("context@a", "browser@a", "BrowserContext"),
+                create("page@a1", "context@a", "Page"),
+                create("page@a2", "context@a", "Page"),
+            ],
+        };
+        let live = SessionLog::default();
+        live.record_create("context@b", "browser@b", "BrowserContext");
+        live.record_create("page@b1", "context@b", "Page");
+        live.record_create("page@b2", "context@b", "Page");
+
+        let mut mapper = GuidMapper::new(&session, &live, Duration::from_millis(50));
+        mapper
+            .resolved
+            .insert("browser@a".to_string(), "browser@b".to_string());
+
+        assert_eq!(mapper.map("page@a2").await.unwrap(), "page@b2");
+        let params = mapper
+            .map_value(&serde_json::json!({"page": {"guid": "page@a1"}, "guid": "not-an-object"}))
+            .await
+            .unwrap();
+        assert_eq!(
+            params,
+            serde_json::json!({"page": {"guid": "page@b1"}, "guid": "not-an-object"})
+        );
+
+        assert!(matches!(
+            mapper.map("page@unknown").await,
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_missing_live_object_times_out() {
+        let session = RecordedSession {
+            version: FORMAT_VERSION,
+            entries: vec![create("page@a", "", "Page")],
+        };
+        let live = SessionLog::default();
+        let mut mapper = GuidMapper::new(&session, &live, Duration::from_millis(30));
+        assert!(matches!(mapper.map("page@a").await, Err(Error::Timeout(_))));
+    }
+}
@@ -0,0 +1,90 @@
+// Shadow roots - Access to a web component's open shadow tree
+//
+// Playwright's selector engines (CSS, text, role, label, placeholder, alt text,
+// title and test id) pierce open shadow roots automatically, so most tests never
+// need this. ShadowRoot is for the cases that do: asserting a component renders
+// into a shadow tree, reading its markup, or scoping queries to one host.
+//
+// XPath does not pierce shadow roots, and closed shadow roots are not reachable
+// by any engine.
+
+use crate::error::{Error, Result};
+use crate::protocol::Locator;
+
+/// Reports the shadow root mode of the first matched element
+const MODE_SCRIPT: &str = r#"(elements) => {
+  if (!elements.length) return null;
+  const root = elements[0].shadowRoot;
+  return root ? root.mode : '';
+}"#;
+
+/// Returns the shadow root's markup
+const INNER_HTML_SCRIPT: &str = r#"(elements) => {
+  const root = elements.length ? elements[0].shadowRoot : null;
+  return root ? root.innerHTML : null;
+}"#;
+
+/// The open shadow root of a web component host.
+///
+/// Obtained from [`Locator::shadow_root`].
+///
+/// # Example
+///
+/// ```ignore
+/// let card = page.locator("user-card");
+/// let root = card.shadow_root().await?.expect("user-card renders into a shadow root");
+/// root.locator("button").click(None).await?;
+/// println!("{}", root.inner_html().await?);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShadowRoot {
+    host: Locator,
+    mode: String,
+}
+
+impl ShadowRoot {
+    /// Locator for the host element
+    pub fn host(&self) -> &Locator {
+        &self.host
+    }
+
+    /// The shadow root mode (always `"open"`; closed roots are not accessible)
+    pub fn mode(&self) -> &str {
+        &self.mode
+    }
+
+    /// Creates a locator scoped to this host.
+    ///
+    /// Matches inside the shadow tree (and the host's light DOM children).
+    pub fn locator(&self, selector: &str) -> Locator {
+        self.host.locator(selector)
+    }
+
+    /// Returns the shadow root's HTML markup.
+    pub async fn inner_html(&self) -> Result<String> {
+        let value = self
+            .host
+            .frame()?
+            .eval_on_selector_all(self.host.selector(), INNER_HTML_SCRIPT, &())
+            .await?;
+        value.as_str().map(str::to_string).ok_or_else(|| {
+            Error::ElementNotFound(format!("{} (shadow root)", self.host.selector()))
+        })
+    }
+}
+
+/// Resolves the shadow root of the first element matching `host`
+pub(crate) async fn shadow_root(host: &Locator) -> Result<Option<ShadowRoot>> {
+    let mode = host
+        .frame()?
+        .eval_on_selector_all(host.selector(), MODE_SCRIPT, &())
+        .await?;
+    match mode.as_str() {
+        None => Err(Error::ElementNotFound(host.selector().to_string())),
+        Some("") => Ok(None),
+        Some(mode) => Ok(Some(ShadowRoot {
+            host: host.clone(),
+            mode: mode.to_string(),
+        })),
+    }
+}
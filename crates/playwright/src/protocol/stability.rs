@@ -0,0 +1,137 @@
+// Stability - Waiting until a page stops changing
+//
+// Load states say when resources finished loading, not when client-side
+// rendering is done. wait_for_stable() additionally waits for running
+// animations to finish and for a quiet period with no DOM mutations and no
+// newly completed resource loads.
+
+use serde::Serialize;
+use std::time::Duration;
+
+/// JavaScript resolving to `true` once the page stayed quiet for `quietMs`, or `false` on timeout
+///
+/// Infinite animations (spinners, marquees) are ignored, as they never finish.
+pub(crate) const STABILITY_SCRIPT: &str = r#"({ quietMs, timeoutMs, animations }) => new Promise((resolve) => {
+  const start = performance.now();
+  let lastChange = start;
+  const touch = () => { lastChange = performance.now(); };
+
+  const mutations = new MutationObserver(touch);
+  mutations.observe(document, { subtree: true, childList: true, attributes: true, characterData: true });
+  let resources = null;
+  if (typeof PerformanceObserver !== 'undefined') {
+    resources = new PerformanceObserver(touch);
+    try { resources.observe({ type: 'resource' }); } catch (e) { resources = null; }
+  }
+  const animating = () => animations && typeof document.getAnimations === 'function' &&
+    document.getAnimations().some((a) => {
+      if (a.playState !== 'running') return false;
+      const timing = a.effect && a.effect.getComputedTiming ? a.effect.getComputedTiming() : null;
+      return !timing || Number.isFinite(timing.endTime);
+    });
+
+  const finish = (stable) => {
+    clearInterval(timer);
+    mutations.disconnect();
+    if (resources) resources.disconnect();
+    resolve(stable);
+  };
+  const timer = setInterval(() => {
+    const now = performance.now();
+    if (document.readyState !== 'complete' || animating()) touch();
+    if (now - lastChange >= quietMs) finish(true);
+    else if (now - start >= timeoutMs) finish(false);
+  }, 50);
+})"#;
+
+/// Options for [`Page::wait_for_stable`](crate::protocol::Page::wait_for_stable).
+///
+/// By default waits for the `networkidle` load state, then for 500ms without
+/// DOM mutations, completed resource loads or running (finite) animations,
+/// giving up after 30 seconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StabilityOptions {
+    /// How long the page must stay unchanged
+    pub quiet_period: Duration,
+    /// Overall time limit
+    pub timeout: Duration,
+    /// Wait for the `networkidle` load state first
+    pub network_idle: bool,
+    /// Treat running animations as activity
+    pub animations: bool,
+}
+
+impl Default for StabilityOptions {
+    fn default() -> Self {
+        Self {
+            quiet_period: Duration::from_millis(500),
+            timeout: Duration::from_millis(crate::DEFAULT_TIMEOUT_MS as u64),
+            network_idle: true,
+            animations: true,
+        }
+    }
+}
+
+impl StabilityOptions {
+    /// Creates options with the defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how long the page must stay unchanged
+    pub fn quiet_period(mut self, period: Duration) -> Self {
+        self.quiet_period = period;
+        self
+    }
+
+    /// Sets the overall time limit
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets whether to wait for the `networkidle` load state first
+    pub fn network_idle(mut self, enabled: bool) -> Self {
+        self.network_idle = enabled;
+        self
+    }
+
+    /// Sets whether running animations count as activity
+    pub fn animations(mut self, enabled: bool) -> Self {
+        self.animations = enabled;
+        self
+    }
+
+    pub(crate) fn script_arg(&self, remaining: Duration) -> ScriptArg {
+        ScriptArg {
+            quiet_ms: self.quiet_period.as_millis() as u64,
+            timeout_ms: remaining.as_millis() as u64,
+            animations: self.animations,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ScriptArg {
+    quiet_ms: u64,
+    timeout_ms: u64,
+    animations: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_arg_serialization() {
+        let options = StabilityOptions::new()
+            .quiet_period(Duration::from_secs(1))
+            .animations(false);
+        let arg = serde_json::to_value(options.script_arg(Duration::from_secs(5))).unwrap();
+        assert_eq!(
+            arg,
+            serde_json::json!({"quietMs": 1000, "timeoutMs": 5000, "animations": false})
+        );
+    }
+}
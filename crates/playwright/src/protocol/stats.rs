@@ -0,0 +1,133 @@
+// Resource accounting - Snapshot of live protocol objects and driver usage
+//
+// Playwright::stats() reports how many objects (browsers, contexts, pages,
+// handles, ...) are alive on the connection, how many protocol calls are in
+// flight, and the driver process's resident memory where the platform exposes it.
+// Intended for capacity planning and leak detection in long-running services.
+
+use std::collections::HashMap;
+
+/// Counts of live resources on a Playwright connection.
+///
+/// Objects are counted by protocol type name (e.g. `"Page"`, `"BrowserContext"`,
+/// `"ElementHandle"`) as registered by the driver; an object stops being counted
+/// once the driver disposes it.
+///
+/// # Example
+///
+/// ```ignore
+/// let stats = playwright.stats().await;
+/// println!(
+///     "{} contexts, {} pages, {} handles, {} calls in flight",
+///     stats.contexts(),
+///     stats.pages(),
+///     stats.handles(),
+///     stats.pending_calls
+/// );
+/// if let Some(rss) = stats.driver_memory_bytes {
+///     println!("driver RSS: {} MiB", rss / 1024 / 1024);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlaywrightStats {
+    /// Live objects keyed by protocol type name
+    pub objects: HashMap<String, usize>,
+    /// Protocol calls still waiting for a response
+    pub pending_calls: usize,
+    /// Resident memory of the driver process, when it can be inspected (Linux only)
+    pub driver_memory_bytes: Option<u64>,
+}
+
+impl PlaywrightStats {
+    /// Number of live objects of the given protocol type
+    pub fn count(&self, type_name: &str) -> usize {
+        self.objects.get(type_name).copied().unwrap_or(0)
+    }
+
+    /// Number of open browsers
+    pub fn browsers(&self) -> usize {
+        self.count("Browser")
+    }
+
+    /// Number of open browser contexts
+    pub fn contexts(&self) -> usize {
+        self.count("BrowserContext")
+    }
+
+    /// Number of open pages
+    pub fn pages(&self) -> usize {
+        self.count("Page")
+    }
+
+    /// Number of live element and JavaScript handles
+    pub fn handles(&self) -> usize {
+        self.count("ElementHandle") + self.count("JSHandle")
+    }
+
+    /// Total number of live objects
+    pub fn total_objects(&self) -> usize {
+        self.objects.values().sum()
+    }
+}
+
+/// Resident set size of a process in bytes, read from `/proc/<pid>/status`
+#[cfg(target_os = "linux")]
+pub(crate) fn process_memory_bytes(pid: u32) -> Option<u64> {
+    let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    parse_vm_rss(&status)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn process_memory_bytes(_pid: u32) -> Option<u64> {
+    None
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_vm_rss(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kib: u64 = line
+        .trim_start_matches("VmRSS:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_accessors() {
+        let stats = PlaywrightStats {
+            objects: HashMap::from([
+                ("Page".to_string(), 3),
+                ("BrowserContext".to_string(), 2),
+                ("ElementHandle".to_string(), 4),
+                ("JSHandle".to_string(), 1),
+            ]),
+            pending_calls: 1,
+            driver_memory_bytes: None,
+        };
+        assert_eq!(stats.pages(), 3);
+        assert_eq!(stats.contexts(), 2);
+        assert_eq!(stats.handles(), 5);
+        assert_eq!(stats.browsers(), 0);
+        assert_eq!(stats.total_objects(), 10);
+    }
+
+    #[test]
+    fn test_parse_vm_rss() {
+        let status = "Name:\tnode\nVmPeak:\t  900000 kB\nVmRSS:\t  123456 kB\nThreads:\t11\n";
+        assert_eq!(parse_vm_rss(status), Some(123456 * 1024));
+        assert_eq!(parse_vm_rss("Name:\tnode\n"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_process_memory_of_current_process() {
+        assert!(process_memory_bytes(std::process::id()).unwrap_or(0) > 0);
+    }
+}
@@ -0,0 +1,279 @@
+// Tracing protocol object
+//
+// Records Playwright traces (actions, DOM snapshots, screenshots, network) that
+// can be opened with `npx playwright show-trace trace.zip` or at
+// trace.playwright.dev. Every BrowserContext owns one Tracing object, created
+// by the driver right after the context.
+//
+// Stopping with a path asks the driver for a zip archive (an Artifact), saves
+// it and deletes the driver-side copy; stopping without a path discards the
+// recording.
+//
+// See: https://playwright.dev/docs/api/class-tracing
+
+use super::options::options;
+use crate::error::{Error, Result};
+use crate::protocol::artifact::Artifact;
+use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::any::Any;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Records Playwright traces for a browser context.
+///
+/// Obtained via [`BrowserContext::tracing`](crate::protocol::BrowserContext::tracing).
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::TracingStartOptions;
+///
+/// let tracing = context.tracing().await?;
+/// tracing
+///     .start(Some(TracingStartOptions::builder().screenshots(true).snapshots(true).build()))
+///     .await?;
+/// page.goto("https://example.com", None).await?;
+/// tracing.stop(Some(Path::new("trace.zip"))).await?;
+/// ```
+///
+/// See: <https://playwright.dev/docs/api/class-tracing>
+#[derive(Clone)]
+pub struct Tracing {
+    base: ChannelOwnerImpl,
+}
+
+impl Tracing {
+    /// Creates a new Tracing object from protocol initialization
+    ///
+    /// This is called by the object factory when the server sends a `__create__` message
+    /// for a Tracing object.
+    pub fn new(
+        parent: Arc<dyn ChannelOwner>,
+        type_name: String,
+        guid: Arc<str>,
+        initializer: Value,
+    ) -> Result<Self> {
+        let base = ChannelOwnerImpl::new(
+            ParentOrConnection::Parent(parent),
+            type_name,
+            guid,
+            initializer,
+        );
+
+        Ok(Self { base })
+    }
+
+    /// Starts recording a trace.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if tracing was already started or the context is closed.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-tracing#tracing-start>
+    pub async fn start(
+        &self,
+        options: impl crate::protocol::IntoOptions<TracingStartOptions>,
+    ) -> Result<()> {
+        let options = options.into_options();
+        let options = options.unwrap_or_default();
+        self.channel()
+            .send_no_result("tracingStart", options.to_json())
+            .await?;
+        self.start_chunk(options.title.as_deref()).await
+    }
+
+    /// Starts a new trace chunk, e.g. one per test while tracing stays on.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-tracing#tracing-start-chunk>
+    pub async fn start_chunk(&self, title: Option<&str>) -> Result<()> {
+        let params = match title {
+            Some(title) => json!({ "title": title }),
+            None => json!({}),
+        };
+        self.channel()
+            .send_no_result("tracingStartChunk", params)
+            .await
+    }
+
+    /// Stops the current chunk, saving it to `path` or discarding it when `None`.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-tracing#tracing-stop-chunk>
+    pub async fn stop_chunk(&self, path: Option<&Path>) -> Result<()> {
+        #[derive(Deserialize)]
+        struct StopChunkResponse {
+            artifact: Option<GuidRef>,
+        }
+
+        #[derive(Deserialize)]
+        struct GuidRef {
+            guid: String,
+        }
+
+        let Some(path) = path else {
+            return self
+                .channel()
+                .send_no_result("tracingStopChunk", json!({ "mode": "discard" }))
+                .await;
+        };
+
+        let response: StopChunkResponse = self
+            .channel()
+            .send("tracingStopChunk", json!({ "mode": "archive" }))
+            .await?;
+        let guid = response
+            .artifact
+            .ok_or_else(|| Error::ProtocolError("Trace archive was not produced".to_string()))?
+            .guid;
+        let artifact_arc = self.connection().get_object(&guid).await?;
+        let artifact = artifact_arc
+            .as_any()
+            .downcast_ref::<Artifact>()
+            .ok_or_else(|| {
+                Error::ProtocolError(format!(
+                    "Expected Artifact object, got {}",
+                    artifact_arc.type_name()
+                ))
+            })?;
+
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(dir).await?;
+        }
+        artifact.save_as(path).await?;
+        artifact.delete().await
+    }
+
+    /// Opens a named group; actions until [`group_end`](Self::group_end) are
+    /// nested under it in the trace viewer. Groups can be nested.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-tracing#tracing-group>
+    pub async fn group(&self, name: &str) -> Result<()> {
+        self.channel()
+            .send_no_result("tracingGroup", json!({ "name": name }))
+            .await
+    }
+
+    /// Closes the group opened last by [`group`](Self::group).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-tracing#tracing-group-end>
+    pub async fn group_end(&self) -> Result<()> {
+        self.channel()
+            .send_no_result("tracingGroupEnd", json!({}))
+            .await
+    }
+
+    /// Stops tracing, saving the trace to `path` or discarding it when `None`.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-tracing#tracing-stop>
+    pub async fn stop(&self, path: Option<&Path>) -> Result<()> {
+        self.stop_chunk(path).await?;
+        self.channel()
+            .send_no_result("tracingStop", json!({}))
+            .await
+    }
+}
+
+impl ChannelOwner for Tracing {
+    fn guid(&self) -> &str {
+        self.base.guid()
+    }
+
+    fn type_name(&self) -> &str {
+        self.base.type_name()
+    }
+
+    fn parent(&self) -> Option<Arc<dyn ChannelOwner>> {
+        self.base.parent()
+    }
+
+    fn connection(&self) -> Arc<dyn crate::server::connection::ConnectionLike> {
+        self.base.connection()
+    }
+
+    fn initializer(&self) -> &Value {
+        self.base.initializer()
+    }
+
+    fn channel(&self) -> &crate::server::channel::Channel {
+        self.base.channel()
+    }
+
+    fn dispose(&self, reason: crate::server::channel_owner::DisposeReason) {
+        self.base.dispose(reason)
+    }
+
+    fn adopt(&self, child: Arc<dyn ChannelOwner>) {
+        self.base.adopt(child)
+    }
+
+    fn add_child(&self, guid: Arc<str>, child: Arc<dyn ChannelOwner>) {
+        self.base.add_child(guid, child)
+    }
+
+    fn remove_child(&self, guid: &str) {
+        self.base.remove_child(guid)
+    }
+
+    fn on_event(&self, _method: &str, _params: Value) {
+        // Tracing doesn't emit events
+    }
+
+    fn was_collected(&self) -> bool {
+        self.base.was_collected()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl std::fmt::Debug for Tracing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tracing")
+            .field("guid", &self.guid())
+            .finish()
+    }
+}
+
+options! {
+    /// Options for [`Tracing::start`]
+    ///
+    /// See: <https://playwright.dev/docs/api/class-tracing#tracing-start>
+    #[derive(PartialEq)]
+    pub struct TracingStartOptions => TracingStartOptionsBuilder {
+        /// Prefix for the driver-side trace files
+        #[builder(into)]
+        pub name: String,
+        /// Capture screenshots during tracing
+        pub screenshots: bool,
+        /// Capture DOM snapshots on every action
+        pub snapshots: bool,
+        /// Title shown in the trace viewer, sent with the first chunk
+        #[builder(into)]
+        #[serde(skip)]
+        pub title: String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_options_serialization() {
+        let options = TracingStartOptions::builder()
+            .screenshots(true)
+            .snapshots(true)
+            .title("checkout")
+            .build();
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            json!({ "screenshots": true, "snapshots": true })
+        );
+        assert_eq!(
+            serde_json::to_value(TracingStartOptions::default()).unwrap(),
+            json!({})
+        );
+    }
+}
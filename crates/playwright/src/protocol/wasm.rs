@@ -0,0 +1,156 @@
+// WASM helpers - Testing Rust/WebAssembly frontends (Leptos, Yew, Dioxus, ...)
+//
+// Rust web apps render nothing useful until their WASM bundle has been fetched,
+// instantiated and has hydrated the DOM, and a Rust panic in the browser only
+// surfaces as a console message or a `RuntimeError: unreachable`. An init
+// script installed by BrowserContext::enable_wasm() tracks both: it wraps
+// `WebAssembly.instantiate*`, listens for Trunk's `TrunkApplicationStarted`
+// event and records panics in `window.__pwWasm`. Hydration is awaited through
+// the readiness registry, so `goto(..., wait_for_ready)` works unchanged.
+//
+// Dev servers for these frameworks are started with DevServerCommand::trunk,
+// ::cargo_leptos and ::dioxus.
+
+use serde::Deserialize;
+
+/// Name of the readiness condition registered by `BrowserContext::enable_wasm`
+pub const WASM_HYDRATED: &str = "wasm-hydrated";
+
+/// Init script tracking WASM instantiation, Trunk startup and panics
+pub(crate) const HOOKS_SCRIPT: &str = r#"(() => {
+  if (window.__pwWasm) return;
+  const state = window.__pwWasm = { instantiated: 0, pending: 0, trunkStarted: false, panics: [] };
+  const record = (kind, message) => state.panics.push({ kind, message: String(message) });
+
+  if (typeof WebAssembly !== 'undefined') {
+    for (const name of ['instantiate', 'instantiateStreaming']) {
+      const original = WebAssembly[name];
+      if (typeof original !== 'function') continue;
+      WebAssembly[name] = function (...args) {
+        state.pending++;
+        return original.apply(this, args).then(
+          (result) => { state.pending--; state.instantiated++; return result; },
+          (error) => { state.pending--; throw error; });
+      };
+    }
+  }
+  addEventListener('TrunkApplicationStarted', () => { state.trunkStarted = true; });
+
+  // console_error_panic_hook reports panics through console.error
+  const consoleError = console.error;
+  console.error = function (...args) {
+    const message = args.map(String).join(' ');
+    if (message.includes('panicked at')) record('panic', message);
+    return consoleError.apply(this, args);
+  };
+  // Without the hook a panic aborts with a WebAssembly.RuntimeError
+  const isTrap = (error) => typeof WebAssembly !== 'undefined' && error instanceof WebAssembly.RuntimeError;
+  addEventListener('error', (event) => {
+    if (isTrap(event.error)) record('trap', event.error);
+  });
+  addEventListener('unhandledrejection', (event) => {
+    if (isTrap(event.reason)) record('trap', event.reason);
+  });
+})();"#;
+
+/// When a WASM app counts as hydrated.
+///
+/// # Example
+///
+/// ```ignore
+/// use playwright_rs::Hydration;
+///
+/// // Leptos app that flags hydration itself
+/// context.enable_wasm(Hydration::Predicate("() => window.__LEPTOS_HYDRATED === true".into())).await?;
+/// // Trunk-built app
+/// context.enable_wasm(Hydration::TrunkStarted).await?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hydration {
+    /// Every `WebAssembly.instantiate*` call has finished and at least one succeeded
+    WasmInstantiated,
+    /// Trunk's loader has dispatched `TrunkApplicationStarted` (Trunk 0.19+)
+    TrunkStarted,
+    /// An element matching the CSS selector exists, e.g. `body[data-hydrated]`
+    Selector(String),
+    /// A JavaScript expression or (possibly async) function that is truthy once hydrated
+    Predicate(String),
+}
+
+impl Hydration {
+    /// Readiness predicate for this signal
+    pub(crate) fn predicate(&self) -> String {
+        match self {
+            Hydration::WasmInstantiated => {
+                "() => !!window.__pwWasm && window.__pwWasm.instantiated > 0 && window.__pwWasm.pending === 0"
+                    .to_string()
+            }
+            Hydration::TrunkStarted => "() => !!window.__pwWasm && window.__pwWasm.trunkStarted".to_string(),
+            Hydration::Selector(selector) => format!(
+                "() => !!document.querySelector({})",
+                serde_json::Value::String(selector.clone())
+            ),
+            Hydration::Predicate(predicate) => predicate.clone(),
+        }
+    }
+}
+
+/// How a WASM panic surfaced in the page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PanicKind {
+    /// A `panicked at ...` message logged via `console.error` (console_error_panic_hook)
+    Panic,
+    /// An uncaught `WebAssembly.RuntimeError`, e.g. `unreachable` after a panic without the hook
+    Trap,
+}
+
+/// A panic captured from a WASM app.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct WasmPanic {
+    /// How the panic surfaced
+    pub kind: PanicKind,
+    /// Console message or error text, including the Rust source location when available
+    pub message: String,
+}
+
+impl std::fmt::Display for WasmPanic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Script reading the captured panics, or null when the hooks are not installed
+pub(crate) const PANICS_SCRIPT: &str = "() => window.__pwWasm ? window.__pwWasm.panics : null";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hydration_predicates() {
+        assert!(Hydration::WasmInstantiated
+            .predicate()
+            .contains("pending === 0"));
+        assert_eq!(
+            Hydration::Selector(r#"body[data-state="ready"]"#.into()).predicate(),
+            r#"() => !!document.querySelector("body[data-state=\"ready\"]")"#
+        );
+        assert_eq!(
+            Hydration::Predicate("window.ready".into()).predicate(),
+            "window.ready"
+        );
+    }
+
+    #[test]
+    fn test_panic_deserialize() {
+        let panics: Vec<WasmPanic> = serde_json::from_value(serde_json::json!([
+            { "kind": "panic", "message": "panicked at src/lib.rs:3:5:\nboom" },
+            { "kind": "trap", "message": "RuntimeError: unreachable" }
+        ]))
+        .unwrap();
+        assert_eq!(panics[0].kind, PanicKind::Panic);
+        assert_eq!(panics[1].kind, PanicKind::Trap);
+        assert_eq!(panics[1].to_string(), "RuntimeError: unreachable");
+    }
+}
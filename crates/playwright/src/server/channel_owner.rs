@@ -359,6 +359,11 @@ impl ChannelOwnerImpl {
         self.children.lock().insert(guid, child);
     }
 
+    /// Returns a snapshot of this object's children.
+    pub fn children(&self) -> Vec<Arc<dyn ChannelOwner>> {
+        self.children.lock().values().cloned().collect()
+    }
+
     /// Removes a child from this parent's registry.
     pub fn remove_child(&self, guid: &str) {
         // Create Arc<str> for lookup
@@ -389,4 +394,4 @@ impl ChannelOwnerImpl {
 // - Real Connection with object registry
 // - Multiple connected objects (parent-child relationships)
 // - Protocol messages from the server
-// See: crates/playwright-core/tests/connection_integration.rs
+// See: crates/playwright/tests/connection_integration.rs
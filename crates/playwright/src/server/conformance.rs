@@ -0,0 +1,380 @@
+// Protocol conformance - Replaying captured driver traffic through Connection
+//
+// A fixture is a captured protocol log: the requests a client sent and the
+// responses, events and `__create__`/`__dispose__` messages the driver sent
+// back. Replaying it issues the recorded requests through a real Connection
+// and feeds the recorded driver messages in, in order, so the object factory,
+// every initializer parser and the event handlers run exactly as against a
+// live driver, but without launching one.
+//
+// Bumping the driver version then comes down to capturing fresh logs and
+// checking that the replay still reports no failures; unknown object types
+// show up in the report instead of being silently dropped.
+//
+// Fixture format: one message per line, `SEND ► {json}` for client-to-driver
+// and `◀ RECV {json}` for driver-to-client, the markers the Node client prints
+// with `DEBUG=pw:protocol`. Anything before the marker (timestamps, the debug
+// namespace) and lines without a marker are ignored, so a raw debug log can be
+// used as-is.
+
+use crate::error::{Error, Result};
+use crate::server::channel_owner::ChannelOwner;
+use crate::server::connection::{Connection, ConnectionLike};
+use crate::server::transport::{BoxFuture, Transport, TransportReceiver};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// Marker of a client-to-driver message
+const SEND_MARKER: &str = "SEND ►";
+
+/// Marker of a driver-to-client message
+const RECV_MARKER: &str = "◀ RECV";
+
+/// How long to wait for the connection to issue or finish a replayed call
+const STEP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// One captured protocol message
+#[derive(Debug, Clone, PartialEq)]
+pub enum CapturedMessage {
+    /// Sent by the client to the driver
+    Send(Value),
+    /// Sent by the driver to the client
+    Recv(Value),
+}
+
+/// A captured protocol log
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProtocolFixture {
+    /// Messages in the order they crossed the wire
+    pub messages: Vec<CapturedMessage>,
+}
+
+impl ProtocolFixture {
+    /// Parses a captured log
+    pub fn parse(log: &str) -> Result<Self> {
+        let mut messages = Vec::new();
+        for (number, line) in log.lines().enumerate() {
+            let (json, send) = if let Some(at) = line.find(SEND_MARKER) {
+                (&line[at + SEND_MARKER.len()..], true)
+            } else if let Some(at) = line.find(RECV_MARKER) {
+                (&line[at + RECV_MARKER.len()..], false)
+            } else {
+                continue;
+            };
+            let message: Value = serde_json::from_str(json.trim()).map_err(|e| {
+                Error::InvalidArgument(format!("Line {}: invalid message: {}", number + 1, e))
+            })?;
+            messages.push(if send {
+                CapturedMessage::Send(message)
+            } else {
+                CapturedMessage::Recv(message)
+            });
+        }
+        Ok(Self { messages })
+    }
+
+    /// Loads a captured log from a file
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::parse(&tokio::fs::read_to_string(path).await?)
+    }
+
+    /// Protocol types of every object the driver created, with their GUIDs
+    fn created(&self) -> Vec<(String, String)> {
+        let mut created: Vec<(String, String)> = Vec::new();
+        for message in &self.messages {
+            let CapturedMessage::Recv(message) = message else {
+                continue;
+            };
+            match message["method"].as_str() {
+                Some("__create__") => created.push((
+                    message["params"]["guid"].as_str().unwrap_or("").to_string(),
+                    message["params"]["type"].as_str().unwrap_or("").to_string(),
+                )),
+                Some("__dispose__") => {
+                    let guid = message["guid"].as_str().unwrap_or("");
+                    created.retain(|(created_guid, _)| created_guid != guid);
+                }
+                _ => {}
+            }
+        }
+        created
+    }
+}
+
+/// Outcome of replaying a fixture
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConformanceReport {
+    /// Number of recorded requests replayed
+    pub calls: usize,
+    /// Live objects after the replay, counted by protocol type
+    pub objects: BTreeMap<String, usize>,
+    /// Protocol types the driver created that the object factory rejected
+    pub unsupported: BTreeSet<String>,
+    /// Calls issued by the crate itself (e.g. event subscriptions), answered
+    /// with an empty result
+    pub unscripted_calls: usize,
+    /// Everything that did not behave as recorded
+    pub failures: Vec<String>,
+}
+
+impl ConformanceReport {
+    /// Whether the replay behaved as recorded
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Replays a captured log through a fresh [`Connection`].
+///
+/// Each recorded request is issued through the connection (the `initialize`
+/// handshake through [`Connection::initialize_playwright`]) and answered with
+/// the recorded response, whose result must come back unchanged. Driver
+/// messages are fed in recorded order. Afterwards every object the driver
+/// created and did not dispose must be registered with its protocol type;
+/// types the object factory does not know are listed as unsupported rather
+/// than failures.
+///
+/// # Errors
+///
+/// Returns an error only if the replay cannot run at all; mismatches are
+/// collected in the report.
+pub async fn replay(fixture: &ProtocolFixture) -> Result<ConformanceReport> {
+    let (inbound, message_rx) = mpsc::unbounded_channel();
+    let (outbound, mut requests) = mpsc::unbounded_channel();
+    let connection = Arc::new(Connection::with_transport(
+        ReplayTransport { outbound },
+        NoopReceiver,
+        message_rx,
+    ));
+    let conn_for_loop = Arc::clone(&connection);
+    let message_loop = tokio::spawn(async move { conn_for_loop.run().await });
+
+    let mut report = ConformanceReport::default();
+    // Recorded request id -> live request id and the call in flight
+    let mut in_flight: HashMap<u64, (Value, Call)> = HashMap::new();
+
+    for message in &fixture.messages {
+        match message {
+            CapturedMessage::Send(request) => {
+                report.calls += 1;
+                let guid = request["guid"].as_str().unwrap_or("").to_string();
+                let method = request["method"].as_str().unwrap_or("").to_string();
+                let params = request.get("params").cloned().unwrap_or(Value::Null);
+
+                let conn = Arc::clone(&connection);
+                let handshake = guid.is_empty() && method == "initialize";
+                let task = if handshake {
+                    tokio::spawn(
+                        async move { conn.initialize_playwright().await.map(|_| Value::Null) },
+                    )
+                } else {
+                    let (guid, method) = (guid.clone(), method.clone());
+                    tokio::spawn(async move {
+                        ConnectionLike::send_message(&*conn, &guid, &method, params).await
+                    })
+                };
+
+                // Wait for the call to reach the wire, answering anything else the
+                // crate sends on its own in the meantime
+                let live_id = loop {
+                    let next = tokio::time::timeout(STEP_TIMEOUT, requests.recv()).await;
+                    let Ok(Some(live)) = next else {
+                        report
+                            .failures
+                            .push(format!("{} {}: request was never sent", guid, method));
+                        break None;
+                    };
+                    if live["guid"] == guid.as_str() && live["method"] == method.as_str() {
+                        break Some(live["id"].clone());
+                    }
+                    report.unscripted_calls += 1;
+                    let _ = inbound.send(json!({"id": live["id"], "result": {}}));
+                };
+                match (live_id, request["id"].as_u64()) {
+                    (Some(live_id), Some(recorded_id)) => {
+                        in_flight.insert(recorded_id, (live_id, Call { task, handshake }));
+                    }
+                    _ => task.abort(),
+                }
+            }
+            CapturedMessage::Recv(message) => {
+                let Some(recorded_id) = message.get("id").and_then(Value::as_u64) else {
+                    let _ = inbound.send(message.clone());
+                    continue;
+                };
+                let Some((live_id, call)) = in_flight.remove(&recorded_id) else {
+                    report
+                        .failures
+                        .push(format!("Response {} has no replayed request", recorded_id));
+                    continue;
+                };
+                let mut response = message.clone();
+                response["id"] = live_id;
+                let _ = inbound.send(response);
+
+                let outcome = tokio::time::timeout(STEP_TIMEOUT, call.task).await;
+                check_outcome(&mut report, recorded_id, message, call.handshake, outcome);
+            }
+        }
+    }
+
+    for (recorded_id, (_, call)) in in_flight {
+        report
+            .failures
+            .push(format!("Request {} has no recorded response", recorded_id));
+        call.task.abort();
+    }
+
+    // Closing the channel ends the message loop once every message is dispatched
+    drop(inbound);
+    if let Err(e) = message_loop.await {
+        report
+            .failures
+            .push(format!("Message loop panicked: {}", e));
+    }
+
+    let live: Vec<Arc<dyn ChannelOwner>> = ConnectionLike::objects(&*connection);
+    for object in &live {
+        *report
+            .objects
+            .entry(object.type_name().to_string())
+            .or_insert(0) += 1;
+    }
+    for (guid, type_name) in fixture.created() {
+        match live.iter().find(|object| object.guid() == guid) {
+            Some(object) if object.type_name() != type_name => report.failures.push(format!(
+                "{} was created as {} but registered as {}",
+                guid,
+                type_name,
+                object.type_name()
+            )),
+            Some(_) => {}
+            None => {
+                report.unsupported.insert(type_name);
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// A replayed request waiting for its recorded response
+struct Call {
+    task: JoinHandle<Result<Value>>,
+    /// The `initialize` handshake, which returns the Playwright object
+    /// rather than the raw result
+    handshake: bool,
+}
+
+/// Compares a finished call with its recorded response
+fn check_outcome(
+    report: &mut ConformanceReport,
+    recorded_id: u64,
+    recorded: &Value,
+    handshake: bool,
+    outcome: std::result::Result<
+        std::result::Result<Result<Value>, tokio::task::JoinError>,
+        tokio::time::error::Elapsed,
+    >,
+) {
+    let result = match outcome {
+        Err(_) => {
+            report
+                .failures
+                .push(format!("Request {} did not complete", recorded_id));
+            return;
+        }
+        Ok(Err(e)) => {
+            report
+                .failures
+                .push(format!("Request {} panicked: {}", recorded_id, e));
+            return;
+        }
+        Ok(Ok(result)) => result,
+    };
+
+    match (result, recorded.get("error")) {
+        (Ok(_), Some(_)) => report.failures.push(format!(
+            "Request {} succeeded but the driver reported an error",
+            recorded_id
+        )),
+        (Err(_), Some(_)) => {}
+        (Err(e), None) => report
+            .failures
+            .push(format!("Request {} failed: {}", recorded_id, e)),
+        (Ok(_), None) if handshake => {}
+        (Ok(value), None) => {
+            let expected = recorded.get("result").cloned().unwrap_or(Value::Null);
+            if value != expected {
+                report.failures.push(format!(
+                    "Request {} returned {} instead of {}",
+                    recorded_id, value, expected
+                ));
+            }
+        }
+    }
+}
+
+/// Hands requests to the replay loop
+struct ReplayTransport {
+    outbound: mpsc::UnboundedSender<Value>,
+}
+
+impl Transport for ReplayTransport {
+    fn send(&mut self, message: Value) -> BoxFuture<'_, Result<()>> {
+        let sent = self
+            .outbound
+            .send(message)
+            .map_err(|_| Error::TransportError("Replay finished".to_string()));
+        Box::pin(async move { sent })
+    }
+}
+
+/// Nothing to read: the replay loop feeds driver messages in directly
+struct NoopReceiver;
+
+impl TransportReceiver for NoopReceiver {
+    fn run(self: Box<Self>) -> BoxFuture<'static, Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ignores_noise() {
+        let log = "\
+            2024-01-01T00:00:00.000Z pw:protocol SEND ► {\"id\":1,\"guid\":\"\",\"method\":\"initialize\"}\n\
+            unrelated output\n\
+            pw:protocol ◀ RECV {\"id\":1,\"result\":{}}\n";
+        let fixture = ProtocolFixture::parse(log).unwrap();
+        assert_eq!(fixture.messages.len(), 2);
+        assert!(matches!(&fixture.messages[0], CapturedMessage::Send(m) if m["id"] == 1));
+        assert!(matches!(&fixture.messages[1], CapturedMessage::Recv(m) if m["id"] == 1));
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_json() {
+        let err = ProtocolFixture::parse("SEND ► {oops").unwrap_err();
+        assert!(err.to_string().contains("Line 1"));
+    }
+
+    #[tokio::test]
+    async fn test_replay_reports_unanswered_request() {
+        let fixture = ProtocolFixture::parse(
+            "SEND ► {\"id\":0,\"guid\":\"page@1\",\"method\":\"title\",\"params\":{}}",
+        )
+        .unwrap();
+        let report = replay(&fixture).await.unwrap();
+        assert_eq!(report.calls, 1);
+        assert!(!report.is_ok());
+        assert!(report.failures[0].contains("no recorded response"));
+    }
+}
@@ -54,14 +54,25 @@
 //! - Java: `com/microsoft/playwright/impl/Connection.java`
 //! - .NET: `Microsoft.Playwright/Core/Connection.cs`
 
+#[cfg(feature = "deadlock-detection")]
+use crate::api::deadlock::MessageDirection;
 use crate::error::{Error, Result};
-use crate::server::transport::PipeTransport;
+use crate::protocol::capabilities::DriverVersion;
+use crate::protocol::crash::{CrashKind, CrashReport};
+use crate::protocol::session_recording::SessionLog;
+#[cfg(feature = "deadlock-detection")]
+use crate::server::deadlock::MessageHistory;
+use crate::server::driver_logs::DriverLogs;
+use crate::server::keep_alive::ConnectionActivity;
+use crate::server::transport::{PipeTransport, Transport, TransportReceiver};
 use parking_lot::Mutex as ParkingLotMutex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::backtrace::{Backtrace, BacktraceStatus};
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex as TokioMutex;
 use tokio::sync::{mpsc, oneshot};
 
@@ -70,8 +81,8 @@ use std::pin::Pin;
 
 /// Trait defining the interface that ChannelOwner needs from a Connection
 ///
-/// This trait allows ChannelOwner to work with Connection without needing to know
-/// the generic parameters W and R. The Connection struct implements this trait.
+/// This trait lets ChannelOwner hold the connection as a trait object, independent
+/// of the transport underneath. The Connection struct implements this trait.
 pub trait ConnectionLike: Send + Sync {
     /// Send a message to the Playwright server and await response
     fn send_message(
@@ -93,8 +104,85 @@ pub trait ConnectionLike: Send + Sync {
 
     /// Get an object by GUID
     fn get_object(&self, guid: &str) -> AsyncChannelOwnerResult<'_>;
+
+    /// Looks up a registered object by GUID without waiting
+    fn find_object(&self, guid: &str) -> Option<Arc<dyn ChannelOwner>> {
+        self.objects()
+            .into_iter()
+            .find(|object| object.guid() == guid)
+    }
+
+    /// Captured driver logs, if this connection is backed by a local driver process
+    fn driver_logs(&self) -> Option<DriverLogs> {
+        None
+    }
+
+    /// Number of registered objects, keyed by protocol type name
+    fn object_counts(&self) -> HashMap<String, usize> {
+        HashMap::new()
+    }
+
+    /// Number of requests still waiting for a response
+    fn pending_calls(&self) -> Pin<Box<dyn Future<Output = usize> + Send + '_>> {
+        Box::pin(async { 0 })
+    }
+
+    /// Requests still waiting for a response, oldest first
+    fn pending_call_details(&self) -> Vec<PendingCall> {
+        Vec::new()
+    }
+
+    /// All registered objects
+    fn objects(&self) -> Vec<Arc<dyn ChannelOwner>> {
+        Vec::new()
+    }
+
+    /// Inbound traffic and transport state, if tracked by this connection
+    fn transport_activity(&self) -> Option<Arc<ConnectionActivity>> {
+        None
+    }
+
+    /// Session recording log, if recording is enabled
+    fn session_log(&self) -> Option<Arc<SessionLog>> {
+        None
+    }
+
+    /// Version of the driver behind this connection, if known
+    fn driver_version(&self) -> Option<DriverVersion> {
+        None
+    }
+
+    /// Crashes recorded on this connection, oldest first
+    fn crash_reports(&self) -> Vec<CrashReport> {
+        Vec::new()
+    }
+
+    /// Records a crash so it is listed by [`crash_reports`](Self::crash_reports)
+    fn record_crash(&self, _report: CrashReport) {}
+
+    /// Starts or stops recording where each request was made
+    ///
+    /// Enables are counted, so every `true` must be paired with a `false`.
+    fn capture_call_stacks(&self, _enabled: bool) {}
 }
 
+/// State of the driver process when the transport closed
+#[derive(Debug)]
+pub(crate) enum DriverProcess {
+    /// The process is being shut down by its owner; not a crash
+    Released,
+    /// The process is still running
+    Running,
+    /// The process exited, with its status if it could be read
+    Exited(Option<std::process::ExitStatus>),
+}
+
+/// Reports the state of the driver process behind a connection
+pub(crate) type DriverProbe = Box<dyn Fn() -> DriverProcess + Send + Sync>;
+
+/// How long to wait for the driver process to exit after its pipe closed
+const DRIVER_EXIT_GRACE: Duration = Duration::from_secs(1);
+
 // Type alias for complex async return type
 type AsyncChannelOwnerResult<'a> =
     Pin<Box<dyn Future<Output = Result<Arc<dyn ChannelOwner>>> + Send + 'a>>;
@@ -302,6 +390,31 @@ pub enum Message {
     Event(Event),
 }
 
+/// A request still waiting for its response.
+#[derive(Debug, Clone)]
+pub struct PendingCall {
+    /// Request ID, unique per connection
+    pub id: u32,
+    /// Target object GUID
+    pub guid: String,
+    /// Protocol method, e.g. `"goto"`
+    pub method: String,
+    /// Time since the request was sent
+    pub elapsed: Duration,
+    /// Call stack of the request, if it was sent while call stacks were being
+    /// recorded (during harness tests with a timeout, or with deadlock detection)
+    /// and `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` enabled backtrace capture
+    pub backtrace: Option<String>,
+}
+
+/// Bookkeeping for a request in flight
+struct CallInfo {
+    guid: String,
+    method: String,
+    started: Instant,
+    backtrace: Option<Backtrace>,
+}
+
 /// Type alias for the object registry mapping GUIDs to ChannelOwner objects
 type ObjectRegistry = HashMap<Arc<str>, Arc<dyn ChannelOwner>>;
 
@@ -326,33 +439,42 @@ type ObjectRegistry = HashMap<Arc<str>, Arc<dyn ChannelOwner>>;
 /// - `AtomicU32` for thread-safe ID generation
 /// - `Arc<Mutex<HashMap>>` for callback storage
 /// - `tokio::sync::oneshot` for request/response correlation
-pub struct Connection<W, R>
-where
-    W: tokio::io::AsyncWrite + Unpin + Send + Sync + 'static,
-    R: tokio::io::AsyncRead + Unpin + Send + Sync + 'static,
-{
+pub struct Connection {
     /// Sequential request ID counter (atomic for thread safety)
     last_id: AtomicU32,
     /// Pending request callbacks keyed by request ID
     callbacks: Arc<TokioMutex<HashMap<u32, oneshot::Sender<Result<Value>>>>>,
-    /// Stdin for sending messages (mutex-wrapped for concurrent sends)
-    stdin: Arc<TokioMutex<W>>,
+    /// What each pending request is, for diagnostics
+    calls: Arc<ParkingLotMutex<HashMap<u32, CallInfo>>>,
+    /// Sending half of the transport (mutex-wrapped for concurrent sends)
+    transport: Arc<TokioMutex<Box<dyn Transport>>>,
     /// Receiver for incoming messages from transport
     message_rx: Arc<TokioMutex<Option<mpsc::UnboundedReceiver<Value>>>>,
     /// Receiver half of transport (owned by run loop, only needed once)
-    transport_receiver: Arc<TokioMutex<Option<crate::server::transport::PipeTransportReceiver<R>>>>,
+    transport_receiver: Arc<TokioMutex<Option<Box<dyn TransportReceiver>>>>,
     /// Registry of all protocol objects by GUID (parking_lot for sync+async access)
     objects: Arc<ParkingLotMutex<ObjectRegistry>>,
+    /// Captured stderr of the driver process backing this connection
+    driver_logs: Option<DriverLogs>,
+    /// Inbound traffic and transport state, polled by the keep-alive watchdog
+    activity: Arc<ConnectionActivity>,
+    /// Log of calls and object creations, when session recording is enabled
+    session_log: Option<Arc<SessionLog>>,
+    /// Version of the driver process, read when it was launched
+    driver_version: Option<DriverVersion>,
+    /// Recent messages, for deadlock reports
+    #[cfg(feature = "deadlock-detection")]
+    message_history: Option<Arc<MessageHistory>>,
+    /// Reports the driver process state, to tell a crash from a shutdown
+    driver_probe: Option<DriverProbe>,
+    /// Driver, browser and page crashes seen on this connection
+    crashes: ParkingLotMutex<Vec<CrashReport>>,
+    /// Number of active requests to record call stacks; capturing a backtrace
+    /// on every call is too slow to do unconditionally
+    call_stack_capture: AtomicUsize,
 }
 
-// Type alias for Connection using concrete transport (most common case)
-pub type RealConnection = Connection<tokio::process::ChildStdin, tokio::process::ChildStdout>;
-
-impl<W, R> Connection<W, R>
-where
-    W: tokio::io::AsyncWrite + Unpin + Send + Sync + 'static,
-    R: tokio::io::AsyncRead + Unpin + Send + Sync + 'static,
-{
+impl Connection {
     /// Create a new Connection with the given transport
     ///
     /// # Arguments
@@ -376,22 +498,143 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    pub fn new(transport: PipeTransport<W, R>, message_rx: mpsc::UnboundedReceiver<Value>) -> Self {
+    pub fn new<W, R>(
+        transport: PipeTransport<W, R>,
+        message_rx: mpsc::UnboundedReceiver<Value>,
+    ) -> Self
+    where
+        W: tokio::io::AsyncWrite + Unpin + Send + 'static,
+        R: tokio::io::AsyncRead + Unpin + Send + 'static,
+    {
         // Split transport into send and receive parts
         // This prevents deadlock: stdin can be locked for sends while
         // the transport receiver runs independently
-        let (stdin, transport_receiver) = transport.into_parts();
+        let (sender, receiver) = transport.into_parts();
+        Self::with_transport(sender, receiver, message_rx)
+    }
 
+    /// Create a new Connection over a custom transport
+    ///
+    /// `receiver` forwards incoming messages to the channel whose receiving
+    /// end is `message_rx`.
+    pub fn with_transport(
+        transport: impl Transport,
+        receiver: impl TransportReceiver,
+        message_rx: mpsc::UnboundedReceiver<Value>,
+    ) -> Self {
         Self {
             last_id: AtomicU32::new(0),
             callbacks: Arc::new(TokioMutex::new(HashMap::new())),
-            stdin: Arc::new(TokioMutex::new(stdin)),
+            calls: Arc::new(ParkingLotMutex::new(HashMap::new())),
+            transport: Arc::new(TokioMutex::new(Box::new(transport))),
             message_rx: Arc::new(TokioMutex::new(Some(message_rx))),
-            transport_receiver: Arc::new(TokioMutex::new(Some(transport_receiver))),
+            transport_receiver: Arc::new(TokioMutex::new(Some(Box::new(receiver)))),
             objects: Arc::new(ParkingLotMutex::new(HashMap::new())),
+            driver_logs: None,
+            activity: Arc::new(ConnectionActivity::default()),
+            session_log: None,
+            driver_version: None,
+            #[cfg(feature = "deadlock-detection")]
+            message_history: None,
+            driver_probe: None,
+            crashes: ParkingLotMutex::new(Vec::new()),
+            call_stack_capture: AtomicUsize::new(0),
         }
     }
 
+    /// Attaches the driver log buffer so protocol objects can include it in errors
+    pub fn with_driver_logs(mut self, logs: DriverLogs) -> Self {
+        self.driver_logs = Some(logs);
+        self
+    }
+
+    /// Records the driver version so newer APIs can be gated on it
+    pub fn with_driver_version(mut self, version: DriverVersion) -> Self {
+        self.driver_version = Some(version);
+        self
+    }
+
+    /// Lets the connection tell a driver crash from a shutdown when its pipe closes
+    pub(crate) fn with_driver_probe(
+        mut self,
+        probe: impl Fn() -> DriverProcess + Send + Sync + 'static,
+    ) -> Self {
+        self.driver_probe = Some(Box::new(probe));
+        self
+    }
+
+    /// Keeps the last `capacity` protocol messages for deadlock reports
+    #[cfg(feature = "deadlock-detection")]
+    pub fn with_message_history(mut self, capacity: usize) -> Self {
+        self.message_history = Some(Arc::new(MessageHistory::new(capacity)));
+        self
+    }
+
+    /// Recent protocol messages, if kept
+    #[cfg(feature = "deadlock-detection")]
+    pub fn message_history(&self) -> Option<Arc<MessageHistory>> {
+        self.message_history.clone()
+    }
+
+    /// Records calls and object creations for later replay
+    pub(crate) fn with_session_log(mut self, log: Arc<SessionLog>) -> Self {
+        self.session_log = Some(log);
+        self
+    }
+
+    /// Inbound traffic and transport state of this connection
+    pub fn activity(&self) -> Arc<ConnectionActivity> {
+        Arc::clone(&self.activity)
+    }
+
+    /// Number of requests still waiting for a response
+    pub async fn pending_calls(&self) -> usize {
+        self.callbacks.lock().await.len()
+    }
+
+    /// Starts or stops recording where each request was made, for
+    /// [`pending_call_details`](Self::pending_call_details)
+    ///
+    /// Enables are counted, so every `true` must be paired with a `false`.
+    pub fn capture_call_stacks(&self, enabled: bool) {
+        if enabled {
+            self.call_stack_capture.fetch_add(1, Ordering::Relaxed);
+        } else {
+            let _ = self.call_stack_capture.fetch_update(
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+                |count| count.checked_sub(1),
+            );
+        }
+    }
+
+    /// Requests still waiting for a response, oldest first
+    pub fn pending_call_details(&self) -> Vec<PendingCall> {
+        let mut calls: Vec<_> = self
+            .calls
+            .lock()
+            .iter()
+            .map(|(id, call)| {
+                (
+                    *id,
+                    PendingCall {
+                        id: *id,
+                        guid: call.guid.clone(),
+                        method: call.method.clone(),
+                        elapsed: call.started.elapsed(),
+                        backtrace: call
+                            .backtrace
+                            .as_ref()
+                            .filter(|backtrace| backtrace.status() == BacktraceStatus::Captured)
+                            .map(|backtrace| backtrace.to_string()),
+                    },
+                )
+            })
+            .collect();
+        calls.sort_by_key(|(id, _)| *id);
+        calls.into_iter().map(|(_, call)| call).collect()
+    }
+
     /// Send a message to the Playwright server and await response
     ///
     /// This method:
@@ -429,8 +672,29 @@ where
         // Create oneshot channel for response
         let (tx, rx) = oneshot::channel();
 
-        // Store callback
-        self.callbacks.lock().await.insert(id, tx);
+        // Store callback; once the transport has closed nothing would complete it
+        {
+            let mut callbacks = self.callbacks.lock().await;
+            if self.activity.is_closed() {
+                return Err(self.closed_error());
+            }
+            callbacks.insert(id, tx);
+        }
+        self.calls.lock().insert(
+            id,
+            CallInfo {
+                guid: guid.to_string(),
+                method: method.to_string(),
+                started: Instant::now(),
+                // Only captures when enabled via RUST_BACKTRACE / RUST_LIB_BACKTRACE
+                backtrace: (self.call_stack_capture.load(Ordering::Relaxed) > 0)
+                    .then(Backtrace::capture),
+            },
+        );
+
+        if let Some(log) = &self.session_log {
+            log.record_call(guid, method, &params);
+        }
 
         // Build request with metadata
         let request = Request {
@@ -441,16 +705,19 @@ where
             metadata: Metadata::now(),
         };
 
-        // Send via stdin using the helper function
+        // Send via the transport
         let request_value = serde_json::to_value(&request)?;
         tracing::debug!("Request JSON: {}", request_value);
+        #[cfg(feature = "deadlock-detection")]
+        if let Some(history) = &self.message_history {
+            history.record(MessageDirection::Sent, &request_value);
+        }
 
-        match crate::server::transport::send_message(&mut *self.stdin.lock().await, request_value)
-            .await
-        {
+        match self.transport.lock().await.send(request_value).await {
             Ok(()) => tracing::debug!("Message sent successfully, awaiting response"),
             Err(e) => {
                 tracing::error!("Failed to send message: {:?}", e);
+                self.calls.lock().remove(&id);
                 return Err(e);
             }
         }
@@ -614,6 +881,11 @@ where
             .expect("run() can only be called once - message receiver already taken");
 
         while let Some(message_value) = message_rx.recv().await {
+            self.activity.touch();
+            #[cfg(feature = "deadlock-detection")]
+            if let Some(history) = &self.message_history {
+                history.record(MessageDirection::Received, &message_value);
+            }
             // Parse message as Response or Event
             match serde_json::from_value::<Message>(message_value) {
                 Ok(message) => {
@@ -628,11 +900,56 @@ where
         }
 
         tracing::debug!("Message loop ended (transport closed)");
+        if let Some(report) = self.detect_driver_crash().await {
+            tracing::error!("{}", report);
+            self.crashes.lock().push(report);
+        }
+        self.activity.mark_closed();
+
+        // Fail calls still waiting; their responses can no longer arrive
+        let pending: Vec<_> = self.callbacks.lock().await.drain().collect();
+        self.calls.lock().clear();
+        for (_, callback) in pending {
+            let _ = callback.send(Err(self.closed_error()));
+        }
 
         // Wait for transport task to finish
         let _ = transport_handle.await;
     }
 
+    /// Error for calls on a closed connection: the driver crash, if there was one
+    fn closed_error(&self) -> Error {
+        match self.crashes.lock().first() {
+            Some(report) if report.kind == CrashKind::Driver => {
+                Error::Crashed(Box::new(report.clone()))
+            }
+            _ => Error::ChannelClosed,
+        }
+    }
+
+    /// Builds a crash report if the driver process exited on its own
+    ///
+    /// The pipe usually closes just before the process is reaped, so the exit
+    /// status is polled for a short while.
+    async fn detect_driver_crash(&self) -> Option<CrashReport> {
+        let probe = self.driver_probe.as_ref()?;
+        let deadline = Instant::now() + DRIVER_EXIT_GRACE;
+        let status = loop {
+            match probe() {
+                DriverProcess::Released => return None,
+                DriverProcess::Exited(status) => break status,
+                DriverProcess::Running if Instant::now() >= deadline => break None,
+                DriverProcess::Running => tokio::time::sleep(Duration::from_millis(50)).await,
+            }
+        };
+        let logs = self
+            .driver_logs
+            .as_ref()
+            .map(|logs| logs.lines())
+            .unwrap_or_default();
+        Some(CrashReport::driver_exit(status, &logs))
+    }
+
     /// Dispatch an incoming message from the transport
     ///
     /// This method:
@@ -659,6 +976,7 @@ where
         match message {
             Message::Response(response) => {
                 tracing::debug!("Processing response for ID: {}", response.id);
+                self.calls.lock().remove(&response.id);
                 // Correlate response with pending request
                 let callback = self
                     .callbacks
@@ -737,6 +1055,10 @@ where
             event.guid
         );
 
+        if let Some(log) = &self.session_log {
+            log.record_create(&object_guid, &event.guid, &type_name);
+        }
+
         let initializer = event.params["initializer"].clone();
 
         // Determine parent
@@ -868,16 +1190,44 @@ fn parse_protocol_error(error: ErrorPayload) -> Error {
             target_type: "target".to_string(),
             context: error.message,
         },
-        _ => Error::ProtocolError(error.message),
+        _ => parse_strict_mode_violation(&error.message)
+            .unwrap_or(Error::ProtocolError(error.message)),
     }
 }
 
+/// Parses the driver's strict mode error:
+///
+/// ```text
+/// strict mode violation: locator('button') resolved to 2 elements:
+///     1) <button>Save</button> aka getByRole('button', { name: 'Save' })
+///     2) <button>Cancel</button> aka getByRole('button', { name: 'Cancel' })
+///
+/// Call log: ...
+/// ```
+fn parse_strict_mode_violation(message: &str) -> Option<Error> {
+    let rest = message.split("strict mode violation: ").nth(1)?;
+    let (header, list) = rest.split_once('\n').unwrap_or((rest, ""));
+    let (selector, count) = header.rsplit_once(" resolved to ")?;
+    let count = count.split(' ').next()?.parse().ok()?;
+    let candidates = list
+        .lines()
+        .map(str::trim)
+        .take_while(|line| !line.is_empty())
+        .filter_map(|line| {
+            let (number, candidate) = line.split_once(") ")?;
+            number.parse::<usize>().ok()?;
+            Some(candidate.to_string())
+        })
+        .collect();
+    Some(Error::StrictModeViolation {
+        selector: selector.to_string(),
+        count,
+        candidates,
+    })
+}
+
 // Implement ConnectionLike trait for Connection
-impl<W, R> ConnectionLike for Connection<W, R>
-where
-    W: tokio::io::AsyncWrite + Unpin + Send + Sync + 'static,
-    R: tokio::io::AsyncRead + Unpin + Send + Sync + 'static,
-{
+impl ConnectionLike for Connection {
     fn send_message(
         &self,
         guid: &str,
@@ -909,10 +1259,62 @@ where
         })
     }
 
+    fn driver_logs(&self) -> Option<DriverLogs> {
+        self.driver_logs.clone()
+    }
+
+    fn object_counts(&self) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for object in self.objects.lock().values() {
+            *counts.entry(object.type_name().to_string()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    fn pending_calls(&self) -> Pin<Box<dyn Future<Output = usize> + Send + '_>> {
+        Box::pin(Connection::pending_calls(self))
+    }
+
+    fn pending_call_details(&self) -> Vec<PendingCall> {
+        Connection::pending_call_details(self)
+    }
+
+    fn objects(&self) -> Vec<Arc<dyn ChannelOwner>> {
+        self.objects.lock().values().cloned().collect()
+    }
+
+    fn transport_activity(&self) -> Option<Arc<ConnectionActivity>> {
+        Some(self.activity())
+    }
+
+    fn session_log(&self) -> Option<Arc<SessionLog>> {
+        self.session_log.clone()
+    }
+
+    fn driver_version(&self) -> Option<DriverVersion> {
+        self.driver_version
+    }
+
+    fn crash_reports(&self) -> Vec<CrashReport> {
+        self.crashes.lock().clone()
+    }
+
+    fn record_crash(&self, report: CrashReport) {
+        self.crashes.lock().push(report);
+    }
+
+    fn capture_call_stacks(&self, enabled: bool) {
+        Connection::capture_call_stacks(self, enabled)
+    }
+
+    fn find_object(&self, guid: &str) -> Option<Arc<dyn ChannelOwner>> {
+        self.objects.lock().get(guid).cloned()
+    }
+
     fn get_object(&self, guid: &str) -> AsyncChannelOwnerResult<'_> {
         let guid_arc: Arc<str> = Arc::from(guid);
         Box::pin(async move {
-            self.objects.lock().get(&guid_arc).cloned().ok_or_else(|| {
+            self.find_object(&guid_arc).ok_or_else(|| {
                 // Determine target type from GUID prefix
                 let target_type = if guid_arc.starts_with("page@") {
                     "Page"
@@ -941,11 +1343,7 @@ mod tests {
     use tokio::io::duplex;
 
     // Helper to create test connection with mock transport
-    fn create_test_connection() -> (
-        Connection<tokio::io::DuplexStream, tokio::io::DuplexStream>,
-        tokio::io::DuplexStream,
-        tokio::io::DuplexStream,
-    ) {
+    fn create_test_connection() -> (Connection, tokio::io::DuplexStream, tokio::io::DuplexStream) {
         let (stdin_read, stdin_write) = duplex(1024);
         let (stdout_read, stdout_write) = duplex(1024);
 
@@ -985,6 +1383,70 @@ mod tests {
         assert_eq!(request.params["url"], "https://example.com");
     }
 
+    /// In-memory transport standing in for a user-supplied one
+    struct ChannelTransport(mpsc::UnboundedSender<Value>);
+
+    impl Transport for ChannelTransport {
+        fn send(&mut self, message: Value) -> crate::server::transport::BoxFuture<'_, Result<()>> {
+            let result = self
+                .0
+                .send(message)
+                .map_err(|_| Error::TransportError("server gone".to_string()));
+            Box::pin(async move { result })
+        }
+    }
+
+    struct ChannelReceiver {
+        incoming: mpsc::UnboundedReceiver<Value>,
+        messages: mpsc::UnboundedSender<Value>,
+    }
+
+    impl TransportReceiver for ChannelReceiver {
+        fn run(mut self: Box<Self>) -> crate::server::transport::BoxFuture<'static, Result<()>> {
+            Box::pin(async move {
+                while let Some(message) = self.incoming.recv().await {
+                    if self.messages.send(message).is_err() {
+                        break;
+                    }
+                }
+                Ok(())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_transport_round_trip() {
+        let (to_server, mut server_rx) = mpsc::unbounded_channel();
+        let (server_tx, incoming) = mpsc::unbounded_channel();
+        let (messages, message_rx) = mpsc::unbounded_channel();
+        let connection = Arc::new(Connection::with_transport(
+            ChannelTransport(to_server),
+            ChannelReceiver { incoming, messages },
+            message_rx,
+        ));
+        let conn_for_loop = Arc::clone(&connection);
+        tokio::spawn(async move { conn_for_loop.run().await });
+
+        // Fake server: answer each request with its method name
+        tokio::spawn(async move {
+            while let Some(request) = server_rx.recv().await {
+                let response = serde_json::json!({
+                    "id": request["id"],
+                    "result": {"method": request["method"]},
+                });
+                if server_tx.send(response).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let result = connection
+            .send_message("page@abc123", "title", Value::Null)
+            .await
+            .unwrap();
+        assert_eq!(result["method"], "title");
+    }
+
     #[tokio::test]
     async fn test_dispatch_response_success() {
         let (connection, _, _) = create_test_connection();
@@ -1011,6 +1473,70 @@ mod tests {
         assert_eq!(result["status"], "ok");
     }
 
+    #[tokio::test]
+    async fn test_pending_call_details() {
+        let (connection, _stdin_read, _stdout_write) = create_test_connection();
+        let connection = Arc::new(connection);
+
+        let sender = Arc::clone(&connection);
+        let call = tokio::spawn(async move {
+            sender
+                .send_message("page@abc123", "goto", serde_json::json!({}))
+                .await
+        });
+        while connection.pending_call_details().is_empty() {
+            tokio::task::yield_now().await;
+        }
+        let pending = connection.pending_call_details();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].guid, "page@abc123");
+        assert_eq!(pending[0].method, "goto");
+
+        let response = Message::Response(Response {
+            id: 0,
+            result: None,
+            error: None,
+        });
+        connection.dispatch(response).await.unwrap();
+        call.await.unwrap().unwrap();
+        assert!(connection.pending_call_details().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_call_stacks_only_recorded_when_enabled() {
+        let (connection, _stdin_read, _stdout_write) = create_test_connection();
+        let connection = Arc::new(connection);
+        let recorded = |connection: &Connection| {
+            connection
+                .calls
+                .lock()
+                .values()
+                .filter(|call| call.backtrace.is_some())
+                .count()
+        };
+
+        for enabled in [false, true] {
+            if enabled {
+                connection.capture_call_stacks(true);
+            }
+            let sender = Arc::clone(&connection);
+            tokio::spawn(async move {
+                sender
+                    .send_message("page@abc123", "goto", serde_json::json!({}))
+                    .await
+            });
+            while connection.calls.lock().len() < 1 + enabled as usize {
+                tokio::task::yield_now().await;
+            }
+            assert_eq!(recorded(&connection), enabled as usize);
+        }
+
+        // Disabling more often than enabling does not underflow
+        connection.capture_call_stacks(false);
+        connection.capture_call_stacks(false);
+        assert_eq!(connection.call_stack_capture.load(Ordering::Relaxed), 0);
+    }
+
     #[tokio::test]
     async fn test_dispatch_response_error() {
         let (connection, _, _) = create_test_connection();
@@ -1199,4 +1725,108 @@ mod tests {
         });
         assert!(matches!(error, Error::ProtocolError(_)));
     }
+
+    #[tokio::test]
+    async fn test_driver_exit_fails_pending_calls_with_crash_report() {
+        let (stdin_read, stdin_write) = duplex(1024);
+        let (stdout_read, stdout_write) = duplex(1024);
+        let (transport, message_rx) = PipeTransport::new(stdin_write, stdout_read);
+        let logs = DriverLogs::new(10);
+        logs.push("FATAL ERROR: Reached heap limit Allocation failed");
+        let connection = Arc::new(
+            Connection::new(transport, message_rx)
+                .with_driver_logs(logs)
+                .with_driver_probe(|| DriverProcess::Exited(None)),
+        );
+        let loop_connection = Arc::clone(&connection);
+        tokio::spawn(async move { loop_connection.run().await });
+
+        let call_connection = Arc::clone(&connection);
+        let call = tokio::spawn(async move {
+            call_connection
+                .send_message("page@1", "goto", serde_json::json!({}))
+                .await
+        });
+        while connection.pending_calls().await == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        // The driver dies: both pipes close
+        drop(stdout_write);
+        drop(stdin_read);
+
+        let Err(Error::Crashed(report)) = call.await.unwrap() else {
+            panic!("expected a crash error");
+        };
+        assert_eq!(report.kind, CrashKind::Driver);
+        assert!(report.stderr_tail[0].contains("heap limit"));
+        assert_eq!(connection.crash_reports().len(), 1);
+
+        // Later calls fail the same way instead of hanging
+        let later = connection.send_message("page@1", "title", serde_json::json!({}));
+        assert!(matches!(later.await, Err(Error::Crashed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_released_driver_is_not_a_crash() {
+        let (stdin_read, stdin_write) = duplex(1024);
+        let (stdout_read, stdout_write) = duplex(1024);
+        let (transport, message_rx) = PipeTransport::new(stdin_write, stdout_read);
+        let connection = Arc::new(
+            Connection::new(transport, message_rx).with_driver_probe(|| DriverProcess::Released),
+        );
+        let loop_connection = Arc::clone(&connection);
+        let run = tokio::spawn(async move { loop_connection.run().await });
+
+        drop(stdout_write);
+        drop(stdin_read);
+        run.await.unwrap();
+
+        assert!(connection.crash_reports().is_empty());
+        let call = connection.send_message("page@1", "title", serde_json::json!({}));
+        assert!(matches!(call.await, Err(Error::ChannelClosed)));
+    }
+
+    #[test]
+    fn test_parse_strict_mode_violation() {
+        let error = parse_protocol_error(ErrorPayload {
+            message: "strict mode violation: locator('li') resolved to 12 elements:\n    \
+                      1) <li>One</li> aka getByText('One')\n    \
+                      2) <li>Two</li> aka getByText('Two')\n    \
+                      ...\n\nCall log:\n  - waiting for locator('li')\n"
+                .to_string(),
+            name: Some("Error".to_string()),
+            stack: None,
+        });
+        let Error::StrictModeViolation {
+            selector,
+            count,
+            candidates,
+        } = &error
+        else {
+            panic!("expected a strict mode violation, got {:?}", error);
+        };
+        assert_eq!(selector, "locator('li')");
+        assert_eq!(*count, 12);
+        assert_eq!(
+            candidates,
+            &[
+                "<li>One</li> aka getByText('One')",
+                "<li>Two</li> aka getByText('Two')"
+            ]
+        );
+        assert_eq!(
+            error.to_string(),
+            "Strict mode violation: locator('li') resolved to 12 elements:\n    \
+             1) <li>One</li> aka getByText('One')\n    \
+             2) <li>Two</li> aka getByText('Two')\n    ..."
+        );
+
+        let other = parse_protocol_error(ErrorPayload {
+            message: "Element is not attached to the DOM".to_string(),
+            name: Some("Error".to_string()),
+            stack: None,
+        });
+        assert!(matches!(other, Error::ProtocolError(_)));
+    }
 }
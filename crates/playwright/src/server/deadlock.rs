@@ -0,0 +1,248 @@
+// Deadlock detector - Reporting calls whose response never arrives
+//
+// The connection keeps the last protocol messages in a MessageHistory. The
+// detector polls the connection's pending calls and, while the transport is
+// still open, reports calls older than the threshold with that history
+// through the DeadlockDetection callback.
+
+use crate::api::deadlock::{DeadlockDetection, DeadlockReport, MessageDirection, RecentMessage};
+use crate::server::connection::{Connection, PendingCall};
+use parking_lot::Mutex;
+use serde_json::Value;
+use std::collections::{HashSet, VecDeque};
+use std::sync::Weak;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+/// Longest message summary kept, in bytes
+const MAX_SUMMARY_LEN: usize = 300;
+
+/// The last protocol messages of a connection
+#[derive(Debug)]
+pub struct MessageHistory {
+    capacity: usize,
+    messages: Mutex<VecDeque<(MessageDirection, Instant, String)>>,
+}
+
+impl MessageHistory {
+    /// Creates a history keeping the last `capacity` messages
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            messages: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Records a message
+    pub fn record(&self, direction: MessageDirection, message: &Value) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut summary = message.to_string();
+        if summary.len() > MAX_SUMMARY_LEN {
+            let mut end = MAX_SUMMARY_LEN;
+            while !summary.is_char_boundary(end) {
+                end -= 1;
+            }
+            summary.truncate(end);
+            summary.push('…');
+        }
+
+        let mut messages = self.messages.lock();
+        if messages.len() == self.capacity {
+            messages.pop_front();
+        }
+        messages.push_back((direction, Instant::now(), summary));
+    }
+
+    /// Recorded messages, oldest first
+    pub fn recent(&self) -> Vec<RecentMessage> {
+        self.messages
+            .lock()
+            .iter()
+            .map(|(direction, at, summary)| RecentMessage {
+                direction: *direction,
+                age: at.elapsed(),
+                summary: summary.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Picks the calls to report: waiting at least `threshold` and not reported yet
+///
+/// Forgets reported calls that have since completed.
+fn stuck_calls(
+    pending: &[PendingCall],
+    threshold: Duration,
+    reported: &mut HashSet<u32>,
+) -> Vec<PendingCall> {
+    reported.retain(|id| pending.iter().any(|call| call.id == *id));
+    pending
+        .iter()
+        .filter(|call| call.elapsed >= threshold && reported.insert(call.id))
+        .cloned()
+        .collect()
+}
+
+/// Running detector task, aborted when dropped
+#[derive(Debug)]
+pub struct DeadlockDetectorHandle {
+    task: JoinHandle<()>,
+}
+
+impl Drop for DeadlockDetectorHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Spawns a deadlock detector for the connection
+///
+/// The task ends when the connection is dropped or its transport closes.
+pub fn spawn_detector(
+    connection: Weak<Connection>,
+    config: DeadlockDetection,
+) -> DeadlockDetectorHandle {
+    let task = tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(config.check_interval.max(Duration::from_millis(1)));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut reported = HashSet::new();
+
+        loop {
+            interval.tick().await;
+            let Some(connection) = connection.upgrade() else {
+                break;
+            };
+            let activity = connection.activity();
+            if activity.is_closed() {
+                break;
+            }
+            let pending = connection.pending_call_details();
+            let stuck = stuck_calls(&pending, config.threshold, &mut reported);
+            if stuck.is_empty() {
+                continue;
+            }
+
+            let report = DeadlockReport {
+                stuck,
+                pending,
+                idle_for: activity.idle_for(),
+                recent_messages: connection
+                    .message_history()
+                    .map(|history| history.recent())
+                    .unwrap_or_default(),
+            };
+            drop(connection);
+
+            tracing::error!("{}", report);
+            if let Some(handler) = config.handler() {
+                handler(&report);
+            }
+        }
+    });
+
+    DeadlockDetectorHandle { task }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::transport::PipeTransport;
+    use std::sync::Arc;
+    use tokio::io::duplex;
+
+    fn call(id: u32, elapsed: Duration) -> PendingCall {
+        PendingCall {
+            id,
+            guid: "page@1".to_string(),
+            method: "goto".to_string(),
+            elapsed,
+            backtrace: None,
+        }
+    }
+
+    #[test]
+    fn test_stuck_calls_reported_once() {
+        let threshold = Duration::from_secs(10);
+        let mut reported = HashSet::new();
+
+        let pending = vec![call(1, threshold), call(2, Duration::ZERO)];
+        let stuck = stuck_calls(&pending, threshold, &mut reported);
+        assert_eq!(stuck.len(), 1);
+        assert_eq!(stuck[0].id, 1);
+        assert!(stuck_calls(&pending, threshold, &mut reported).is_empty());
+
+        // Call 1 completes, call 2 gets stuck
+        let pending = vec![call(2, threshold)];
+        let stuck = stuck_calls(&pending, threshold, &mut reported);
+        assert_eq!(stuck[0].id, 2);
+        assert_eq!(reported, HashSet::from([2]));
+    }
+
+    #[test]
+    fn test_message_history_keeps_latest() {
+        let history = MessageHistory::new(2);
+        for id in 0..3 {
+            history.record(MessageDirection::Sent, &serde_json::json!({ "id": id }));
+        }
+        history.record(
+            MessageDirection::Received,
+            &serde_json::json!({ "long": "é".repeat(400) }),
+        );
+
+        let recent = history.recent();
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].summary, r#"{"id":2}"#);
+        assert_eq!(recent[1].direction, MessageDirection::Received);
+        assert!(recent[1].summary.len() <= MAX_SUMMARY_LEN + '…'.len_utf8());
+        assert!(recent[1].summary.ends_with('…'));
+    }
+
+    #[tokio::test]
+    async fn test_detector_reports_unanswered_call() {
+        let (_stdin_read, stdin_write) = duplex(4096);
+        let (stdout_read, _stdout_write) = duplex(4096);
+        let (transport, message_rx) = PipeTransport::new(stdin_write, stdout_read);
+        let connection = Arc::new(Connection::new(transport, message_rx).with_message_history(10));
+
+        let loop_connection = Arc::clone(&connection);
+        tokio::spawn(async move { loop_connection.run().await });
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let config = DeadlockDetection::new(Duration::from_millis(50))
+            .check_interval(Duration::from_millis(10))
+            .on_deadlock(move |report| {
+                let _ = tx.send(report.clone());
+            });
+        let _detector = spawn_detector(Arc::downgrade(&connection), config);
+
+        let call_connection = Arc::clone(&connection);
+        tokio::spawn(async move {
+            let _ = call_connection
+                .send_message(
+                    "page@1",
+                    "goto",
+                    serde_json::json!({"url": "https://example.com"}),
+                )
+                .await;
+        });
+
+        let report = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("deadlock reported")
+            .unwrap();
+        assert_eq!(report.stuck.len(), 1);
+        assert_eq!(report.stuck[0].method, "goto");
+        assert_eq!(report.pending.len(), 1);
+        assert_eq!(report.recent_messages.len(), 1);
+        assert_eq!(report.recent_messages[0].direction, MessageDirection::Sent);
+        assert!(report.recent_messages[0].summary.contains("example.com"));
+
+        // Reported once
+        assert!(tokio::time::timeout(Duration::from_millis(200), rx.recv())
+            .await
+            .is_err());
+    }
+}
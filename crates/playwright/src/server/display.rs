@@ -0,0 +1,204 @@
+// Display server support for headed browsers on Linux
+//
+// Headed browsers need an X server. On CI machines, containers, and WSL without
+// WSLg there usually isn't one, and the resulting launch failure is a long,
+// generic protocol error. This module:
+// - Recognizes those failures so they can be surfaced as `Error::DisplayNotAvailable`
+//   with platform-specific guidance
+// - Optionally starts a private Xvfb server for the driver (`PlaywrightConfig::xvfb`)
+
+use crate::error::{Error, Result};
+use crate::server::driver_logs::DriverLogs;
+use std::path::Path;
+use std::time::Duration;
+use tokio::process::{Child, Command};
+
+/// Fragments of browser/driver errors caused by a missing display server
+const MISSING_DISPLAY_MARKERS: [&str; 5] = [
+    "without having a XServer running",
+    "Missing X server or $DISPLAY",
+    "cannot open display",
+    "Unable to open X display",
+    "no DISPLAY environment variable",
+];
+
+/// Returns true if an error message indicates a missing display server
+pub(crate) fn is_missing_display(message: &str) -> bool {
+    MISSING_DISPLAY_MARKERS
+        .iter()
+        .any(|marker| message.contains(marker))
+}
+
+/// Attaches the tail of the driver log to a launch error, converting errors
+/// caused by a missing display into `Error::DisplayNotAvailable`.
+///
+/// Only the driver's own error is checked, so display failures left in the log
+/// by earlier launches do not reclassify unrelated errors. The original error,
+/// with the log attached, follows the guidance.
+pub(crate) fn map_launch_error(error: Error, logs: Option<&DriverLogs>) -> Error {
+    let missing_display = is_missing_display(&error.to_string());
+    let error = match logs {
+        Some(logs) => logs.attach_to(error),
+        None => error,
+    };
+    if !missing_display {
+        return error;
+    }
+    Error::DisplayNotAvailable(format!("{}\n\n{}", guidance(is_wsl()), error))
+}
+
+/// Builds the suggestion text for a missing display
+fn guidance(wsl: bool) -> String {
+    let mut text = String::from(
+        "Launch headless with LaunchOptions::default().headless(true), \
+         or start Playwright with PlaywrightConfig::new().xvfb(true) to run headed browsers \
+         in a virtual X server (requires the xvfb package), \
+         or wrap your command with `xvfb-run -a`.",
+    );
+    if wsl {
+        text.push_str(
+            " On WSL, update to a version with WSLg (`wsl --update`) or point DISPLAY \
+             at an X server running on Windows.",
+        );
+    }
+    text
+}
+
+/// Returns true when running under Windows Subsystem for Linux
+pub(crate) fn is_wsl() -> bool {
+    cfg!(target_os = "linux")
+        && std::fs::read_to_string("/proc/version")
+            .map(|v| v.to_ascii_lowercase().contains("microsoft"))
+            .unwrap_or(false)
+}
+
+/// A private Xvfb server started for the driver process.
+///
+/// The server is killed when this value is dropped.
+#[derive(Debug)]
+pub struct Xvfb {
+    process: Child,
+    display: String,
+}
+
+impl Xvfb {
+    /// Starts Xvfb on the first free display number at or above `:99`
+    pub(crate) async fn start() -> Result<Self> {
+        if !cfg!(target_os = "linux") {
+            return Err(Error::LaunchFailed(
+                "Xvfb is only supported on Linux".to_string(),
+            ));
+        }
+
+        let number = (99..200)
+            .find(|n| !Path::new(&format!("/tmp/.X{}-lock", n)).exists())
+            .ok_or_else(|| Error::LaunchFailed("No free X display number found".to_string()))?;
+        let display = format!(":{}", number);
+
+        let process = Command::new("Xvfb")
+            .arg(&display)
+            .args(["-screen", "0", "1280x720x24", "-nolisten", "tcp"])
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| {
+                Error::LaunchFailed(format!(
+                    "Failed to start Xvfb ({}). Install it with your package manager, e.g. `apt-get install xvfb`.",
+                    e
+                ))
+            })?;
+
+        // Wait for the X socket to appear
+        let socket = format!("/tmp/.X11-unix/X{}", number);
+        let mut xvfb = Self { process, display };
+        for _ in 0..50 {
+            if Path::new(&socket).exists() {
+                tracing::debug!("Started Xvfb on display {}", xvfb.display);
+                return Ok(xvfb);
+            }
+            if let Ok(Some(status)) = xvfb.process.try_wait() {
+                return Err(Error::LaunchFailed(format!(
+                    "Xvfb exited immediately with status: {}",
+                    status
+                )));
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+
+        let _ = xvfb.process.start_kill();
+        Err(Error::LaunchFailed(format!(
+            "Timed out waiting for Xvfb to start on display {}",
+            xvfb.display
+        )))
+    }
+
+    /// Returns the `DISPLAY` value for this server (e.g., ":99")
+    pub fn display(&self) -> &str {
+        &self.display
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_missing_display() {
+        let message = "Protocol error: browserType.launch: Target page, context or browser has been closed\n\
+            Browser logs:\n\
+            Looks like you launched a headed browser without having a XServer running.\n\
+            Set either 'headless: true' or use 'xvfb-run <your-playwright-app>' before running Playwright.";
+        assert!(is_missing_display(message));
+        assert!(!is_missing_display("Timeout 30000ms exceeded"));
+    }
+
+    #[test]
+    fn test_map_launch_error() {
+        let logs = DriverLogs::new(10);
+        logs.push("pw:browser [pid=1][err] Missing X server or $DISPLAY");
+
+        let err = map_launch_error(
+            Error::ProtocolError("[pid=1][err] Error: cannot open display: :0".to_string()),
+            Some(&logs),
+        );
+        match err {
+            Error::DisplayNotAvailable(message) => {
+                assert!(message.contains("headless(true)"), "{}", message);
+                assert!(message.contains("cannot open display: :0"), "{}", message);
+                assert!(message.contains("Driver log (last 1 lines)"), "{}", message);
+            }
+            other => panic!("Expected DisplayNotAvailable, got {:?}", other),
+        }
+
+        let err = map_launch_error(Error::Timeout("launch".to_string()), None);
+        assert!(matches!(err, Error::Timeout(_)));
+    }
+
+    #[test]
+    fn test_stale_display_failure_in_logs_is_ignored() {
+        // Left behind by an earlier headed launch
+        let logs = DriverLogs::new(10);
+        logs.push("pw:browser [pid=1][err] Error: cannot open display: :0");
+
+        let err = map_launch_error(
+            Error::ProtocolError("Executable doesn't exist at /ms-playwright/chrome".to_string()),
+            Some(&logs),
+        );
+        match err {
+            Error::ProtocolError(message) => {
+                assert!(message.contains("Executable doesn't exist"), "{}", message);
+                assert!(message.contains("cannot open display"), "{}", message);
+            }
+            other => panic!("Expected ProtocolError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_guidance_mentions_wsl() {
+        assert!(guidance(true).contains("WSLg"));
+        assert!(!guidance(false).contains("WSLg"));
+        assert!(guidance(false).contains("headless(true)"));
+    }
+}
@@ -0,0 +1,131 @@
+// Driver log capture
+//
+// The Playwright driver (Node.js) writes diagnostics to stderr, including the
+// browser process output when `DEBUG=pw:browser` is enabled. Instead of inheriting
+// stderr, we capture it into a bounded in-memory ring buffer (echoing driver lines
+// to our own stderr) so that:
+// - Users can inspect recent driver output via `Playwright::driver_logs()`
+// - Launch failures carry the tail of the log, which usually explains the failure
+//   (missing system libraries, sandbox errors, crashed browser, ...)
+
+use crate::error::Error;
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// Default number of log lines retained
+pub const DEFAULT_DRIVER_LOG_CAPACITY: usize = 1000;
+
+/// Number of trailing log lines attached to launch errors
+pub const ERROR_LOG_TAIL_LINES: usize = 20;
+
+/// Bounded, shared buffer of driver and browser log lines.
+///
+/// Cloning is cheap - all clones share the same buffer. When the buffer is full,
+/// the oldest lines are discarded.
+#[derive(Clone, Debug)]
+pub struct DriverLogs {
+    lines: Arc<Mutex<VecDeque<String>>>,
+    capacity: usize,
+}
+
+impl DriverLogs {
+    /// Creates an empty buffer retaining at most `capacity` lines
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: Arc::new(Mutex::new(VecDeque::with_capacity(capacity.min(64)))),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Appends a line, evicting the oldest line if the buffer is full
+    pub fn push(&self, line: impl Into<String>) {
+        let mut lines = self.lines.lock();
+        if lines.len() == self.capacity {
+            lines.pop_front();
+        }
+        lines.push_back(line.into());
+    }
+
+    /// Returns all retained lines, oldest first
+    pub fn lines(&self) -> Vec<String> {
+        self.lines.lock().iter().cloned().collect()
+    }
+
+    /// Returns the last `n` retained lines, oldest first
+    pub fn tail(&self, n: usize) -> Vec<String> {
+        let lines = self.lines.lock();
+        let skip = lines.len().saturating_sub(n);
+        lines.iter().skip(skip).cloned().collect()
+    }
+
+    /// Appends the tail of the log to an error's message.
+    ///
+    /// Only message-carrying launch/server/protocol errors are extended; other
+    /// variants (and errors raised while the log is empty) are returned unchanged.
+    pub fn attach_to(&self, error: Error) -> Error {
+        let tail = self.tail(ERROR_LOG_TAIL_LINES);
+        if tail.is_empty() {
+            return error;
+        }
+        let suffix = format!(
+            "\n\nDriver log (last {} lines):\n{}",
+            tail.len(),
+            tail.join("\n")
+        );
+        match error {
+            Error::LaunchFailed(msg) => Error::LaunchFailed(msg + &suffix),
+            Error::ServerError(msg) => Error::ServerError(msg + &suffix),
+            Error::ProtocolError(msg) => Error::ProtocolError(msg + &suffix),
+            Error::Timeout(msg) => Error::Timeout(msg + &suffix),
+            other => other,
+        }
+    }
+}
+
+impl Default for DriverLogs {
+    fn default() -> Self {
+        Self::new(DEFAULT_DRIVER_LOG_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_driver_logs_rotate() {
+        let logs = DriverLogs::new(3);
+        for i in 0..5 {
+            logs.push(format!("line {}", i));
+        }
+        assert_eq!(logs.lines(), vec!["line 2", "line 3", "line 4"]);
+        assert_eq!(logs.tail(2), vec!["line 3", "line 4"]);
+        assert_eq!(logs.tail(10).len(), 3);
+    }
+
+    #[test]
+    fn test_attach_to_launch_error() {
+        let logs = DriverLogs::new(10);
+        logs.push("error while loading shared libraries: libnss3.so");
+
+        let err = logs.attach_to(Error::LaunchFailed("exited".to_string()));
+        let msg = err.to_string();
+        assert!(msg.contains("exited"));
+        assert!(msg.contains("libnss3.so"));
+
+        // Other variants are left untouched
+        let err = logs.attach_to(Error::ChannelClosed);
+        assert!(matches!(err, Error::ChannelClosed));
+    }
+
+    #[test]
+    fn test_attach_to_with_empty_log() {
+        let logs = DriverLogs::default();
+        let err = logs.attach_to(Error::LaunchFailed("exited".to_string()));
+        assert_eq!(
+            err.to_string(),
+            Error::LaunchFailed("exited".to_string()).to_string()
+        );
+    }
+}
@@ -0,0 +1,221 @@
+// Keep-alive watchdog - Stall detection for the driver connection
+//
+// The connection records when it last received a message and whether its
+// transport has closed. The watchdog polls that state together with the number
+// of pending calls and reports stalls through the KeepAlive callback.
+
+use crate::api::keep_alive::{KeepAlive, StallEvent, StallReason};
+use crate::server::connection::Connection;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Weak;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+/// Inbound traffic and transport state of a connection
+#[derive(Debug)]
+pub struct ConnectionActivity {
+    last_received: Mutex<Instant>,
+    closed: AtomicBool,
+}
+
+impl Default for ConnectionActivity {
+    fn default() -> Self {
+        Self {
+            last_received: Mutex::new(Instant::now()),
+            closed: AtomicBool::new(false),
+        }
+    }
+}
+
+impl ConnectionActivity {
+    /// Records that a message was received
+    pub fn touch(&self) {
+        *self.last_received.lock() = Instant::now();
+    }
+
+    /// Records that the transport has closed
+    pub fn mark_closed(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+    }
+
+    /// Time since the last message was received
+    pub fn idle_for(&self) -> Duration {
+        self.last_received.lock().elapsed()
+    }
+
+    /// Whether the transport has closed
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+}
+
+/// Decides whether the current connection state is a stall
+///
+/// `reported` tracks whether the ongoing unresponsive episode was already
+/// reported, so each episode produces a single event. A closed transport is
+/// always reported; the watchdog stops afterwards.
+fn check(
+    closed: bool,
+    idle_for: Duration,
+    pending_calls: usize,
+    idle_timeout: Duration,
+    reported: &mut bool,
+) -> Option<StallEvent> {
+    let reason = if closed {
+        StallReason::TransportClosed
+    } else if pending_calls > 0 && idle_for >= idle_timeout {
+        if std::mem::replace(reported, true) {
+            return None;
+        }
+        StallReason::Unresponsive
+    } else {
+        *reported = false;
+        return None;
+    };
+
+    Some(StallEvent {
+        reason,
+        idle_for,
+        pending_calls,
+    })
+}
+
+/// Running watchdog task, aborted when dropped
+#[derive(Debug)]
+pub struct KeepAliveHandle {
+    task: JoinHandle<()>,
+}
+
+impl Drop for KeepAliveHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Spawns a watchdog for the connection
+///
+/// The task ends when the connection is dropped or after reporting a closed
+/// transport. Drop the returned handle before an intentional shutdown so the
+/// closing transport is not reported as a stall.
+pub fn spawn_watchdog(connection: Weak<Connection>, config: KeepAlive) -> KeepAliveHandle {
+    let task = tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(config.check_interval.max(Duration::from_millis(1)));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut reported = false;
+
+        loop {
+            interval.tick().await;
+            let Some(connection) = connection.upgrade() else {
+                break;
+            };
+            let activity = connection.activity();
+            let pending_calls = connection.pending_calls().await;
+            drop(connection);
+
+            let Some(event) = check(
+                activity.is_closed(),
+                activity.idle_for(),
+                pending_calls,
+                config.idle_timeout,
+                &mut reported,
+            ) else {
+                continue;
+            };
+
+            tracing::warn!(
+                "Driver connection stalled ({:?}): {} pending call(s), idle for {:?}",
+                event.reason,
+                event.pending_calls,
+                event.idle_for
+            );
+            let closed = event.reason == StallReason::TransportClosed;
+            if let Some(handler) = config.stall_handler() {
+                handler(event);
+            }
+            if closed {
+                break;
+            }
+        }
+    });
+
+    KeepAliveHandle { task }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::transport::PipeTransport;
+    use std::sync::Arc;
+    use tokio::io::duplex;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    #[test]
+    fn test_check_reports_each_episode_once() {
+        let mut reported = false;
+
+        // Idle without pending calls is not a stall
+        assert!(check(false, TIMEOUT * 2, 0, TIMEOUT, &mut reported).is_none());
+
+        let event = check(false, TIMEOUT, 2, TIMEOUT, &mut reported).expect("stall");
+        assert_eq!(event.reason, StallReason::Unresponsive);
+        assert_eq!(event.pending_calls, 2);
+        assert!(check(false, TIMEOUT * 2, 2, TIMEOUT, &mut reported).is_none());
+
+        // Traffic resumes, then stalls again
+        assert!(check(false, Duration::ZERO, 2, TIMEOUT, &mut reported).is_none());
+        assert!(check(false, TIMEOUT, 1, TIMEOUT, &mut reported).is_some());
+    }
+
+    #[test]
+    fn test_check_reports_closed_transport() {
+        let mut reported = true;
+        let event = check(true, Duration::ZERO, 0, TIMEOUT, &mut reported).expect("stall");
+        assert_eq!(event.reason, StallReason::TransportClosed);
+    }
+
+    #[tokio::test]
+    async fn test_watchdog_reports_unresponsive_then_closed() {
+        let (_stdin_read, stdin_write) = duplex(1024);
+        let (stdout_read, stdout_write) = duplex(1024);
+        let (transport, message_rx) = PipeTransport::new(stdin_write, stdout_read);
+        let connection = Arc::new(Connection::new(transport, message_rx));
+
+        let loop_connection = Arc::clone(&connection);
+        tokio::spawn(async move { loop_connection.run().await });
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let config = KeepAlive::new(Duration::from_millis(50))
+            .check_interval(Duration::from_millis(10))
+            .on_stalled(move |event| {
+                let _ = tx.send(event);
+            });
+        let _watchdog = spawn_watchdog(Arc::downgrade(&connection), config);
+
+        // A call the driver never answers
+        let call_connection = Arc::clone(&connection);
+        tokio::spawn(async move {
+            let _ = call_connection
+                .send_message("page@1", "goto", serde_json::json!({}))
+                .await;
+        });
+
+        let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("stall reported")
+            .unwrap();
+        assert_eq!(event.reason, StallReason::Unresponsive);
+        assert_eq!(event.pending_calls, 1);
+        assert!(event.idle_for >= Duration::from_millis(50));
+
+        // Driver goes away
+        drop(stdout_write);
+        let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("close reported")
+            .unwrap();
+        assert_eq!(event.reason, StallReason::TransportClosed);
+    }
+}
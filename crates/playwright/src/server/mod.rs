@@ -12,10 +12,21 @@ pub mod channel;
 #[doc(hidden)]
 pub mod channel_owner;
 #[doc(hidden)]
+pub mod conformance;
+#[doc(hidden)]
 pub mod connection;
+#[cfg(feature = "deadlock-detection")]
+#[doc(hidden)]
+pub mod deadlock;
+#[doc(hidden)]
+pub mod display;
 #[doc(hidden)]
 pub mod driver;
 #[doc(hidden)]
+pub mod driver_logs;
+#[doc(hidden)]
+pub mod keep_alive;
+#[doc(hidden)]
 pub mod object_factory;
 #[doc(hidden)]
 pub mod playwright_server;
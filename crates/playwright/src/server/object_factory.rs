@@ -10,12 +10,18 @@
 //
 // The object factory maps protocol type names (strings) to Rust constructors.
 // When the server sends a `__create__` message, the factory instantiates
-// the appropriate Rust object based on the type name.
+// the appropriate Rust object based on the type name. Types whose API is
+// compiled out by a cargo feature (Tracing, APIRequestContext) still get a
+// bare ChannelOwner, so the object tree and their children stay consistent.
 
 use crate::error::{Error, Result};
+#[cfg(feature = "api-request")]
+use crate::protocol::APIRequestContext;
+#[cfg(feature = "trace")]
+use crate::protocol::Tracing;
 use crate::protocol::{
-    artifact::Artifact, Browser, BrowserContext, BrowserType, Dialog, Frame, Page, Playwright,
-    Request, ResponseObject, Route,
+    artifact::Artifact, Browser, BrowserContext, BrowserType, CDPSession, Dialog, Frame, Page,
+    Playwright, Request, ResponseObject, Route,
 };
 use crate::server::channel_owner::{ChannelOwner, ParentOrConnection};
 use serde_json::Value;
@@ -246,6 +252,21 @@ pub async fn create_object(
             Arc::new(Artifact::new(parent_owner, type_name, guid, initializer)?)
         }
 
+        #[cfg(feature = "trace")]
+        "Tracing" => {
+            // Tracing has BrowserContext as parent
+            let parent_owner = match parent {
+                ParentOrConnection::Parent(p) => p,
+                ParentOrConnection::Connection(_) => {
+                    return Err(Error::ProtocolError(
+                        "Tracing must have BrowserContext as parent".to_string(),
+                    ))
+                }
+            };
+
+            Arc::new(Tracing::new(parent_owner, type_name, guid, initializer)?)
+        }
+
         "Dialog" => {
             // Dialog has Page as parent
             let parent_owner = match parent {
@@ -260,6 +281,46 @@ pub async fn create_object(
             Arc::new(Dialog::new(parent_owner, type_name, guid, initializer)?)
         }
 
+        "CDPSession" => {
+            // CDPSession has BrowserContext (page sessions) or Browser (browser sessions) as parent
+            let parent_owner = match parent {
+                ParentOrConnection::Parent(p) => p,
+                ParentOrConnection::Connection(_) => {
+                    return Err(Error::ProtocolError(
+                        "CDPSession must have BrowserContext or Browser as parent".to_string(),
+                    ))
+                }
+            };
+
+            Arc::new(CDPSession::new(parent_owner, type_name, guid, initializer)?)
+        }
+
+        #[cfg(feature = "api-request")]
+        "APIRequestContext" => {
+            // APIRequestContext has Browser (context requests) or Playwright as parent
+            let parent_owner = match parent {
+                ParentOrConnection::Parent(p) => p,
+                ParentOrConnection::Connection(_) => {
+                    return Err(Error::ProtocolError(
+                        "APIRequestContext must have a parent".to_string(),
+                    ))
+                }
+            };
+
+            Arc::new(APIRequestContext::new(
+                parent_owner,
+                type_name,
+                guid,
+                initializer,
+            )?)
+        }
+
+        // API compiled out - keep the object so the protocol tree stays intact
+        #[cfg(not(feature = "trace"))]
+        "Tracing" => Arc::new(Disabled::new(parent, type_name, guid, initializer)),
+        #[cfg(not(feature = "api-request"))]
+        "APIRequestContext" => Arc::new(Disabled::new(parent, type_name, guid, initializer)),
+
         _ => {
             // Unknown type - log warning and return error
             tracing::warn!("Unknown protocol type: {}", type_name);
@@ -273,7 +334,85 @@ pub async fn create_object(
     Ok(object)
 }
 
+/// Protocol object whose Rust API is disabled by a cargo feature
+#[cfg(not(all(feature = "trace", feature = "api-request")))]
+struct Disabled {
+    base: crate::server::channel_owner::ChannelOwnerImpl,
+}
+
+#[cfg(not(all(feature = "trace", feature = "api-request")))]
+impl Disabled {
+    fn new(
+        parent: ParentOrConnection,
+        type_name: String,
+        guid: Arc<str>,
+        initializer: Value,
+    ) -> Self {
+        Self {
+            base: crate::server::channel_owner::ChannelOwnerImpl::new(
+                parent,
+                type_name,
+                guid,
+                initializer,
+            ),
+        }
+    }
+}
+
+#[cfg(not(all(feature = "trace", feature = "api-request")))]
+impl ChannelOwner for Disabled {
+    fn guid(&self) -> &str {
+        self.base.guid()
+    }
+
+    fn type_name(&self) -> &str {
+        self.base.type_name()
+    }
+
+    fn parent(&self) -> Option<Arc<dyn ChannelOwner>> {
+        self.base.parent()
+    }
+
+    fn connection(&self) -> Arc<dyn crate::server::connection::ConnectionLike> {
+        self.base.connection()
+    }
+
+    fn initializer(&self) -> &Value {
+        self.base.initializer()
+    }
+
+    fn channel(&self) -> &crate::server::channel::Channel {
+        self.base.channel()
+    }
+
+    fn dispose(&self, reason: crate::server::channel_owner::DisposeReason) {
+        self.base.dispose(reason)
+    }
+
+    fn adopt(&self, child: Arc<dyn ChannelOwner>) {
+        self.base.adopt(child)
+    }
+
+    fn add_child(&self, guid: Arc<str>, child: Arc<dyn ChannelOwner>) {
+        self.base.add_child(guid, child)
+    }
+
+    fn remove_child(&self, guid: &str) {
+        self.base.remove_child(guid)
+    }
+
+    fn on_event(&self, _method: &str, _params: Value) {}
+
+    fn was_collected(&self) -> bool {
+        self.base.was_collected()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
 // Note: Object factory testing is done via integration tests since it requires:
 // - Real Connection with object registry
 // - Protocol messages from the server
-// See: crates/playwright-core/tests/connection_integration.rs
+// See: crates/playwright/tests/connection_integration.rs
@@ -3,9 +3,19 @@
 // Handles downloading, launching, and managing the lifecycle of the Playwright
 // Node.js server process.
 
+use crate::api::PlaywrightConfig;
+use crate::protocol::DriverVersion;
+use crate::server::connection::DriverProcess;
+#[cfg(feature = "deadlock-detection")]
+use crate::server::deadlock::DeadlockDetectorHandle;
+use crate::server::display::Xvfb;
 use crate::server::driver::get_driver_executable;
+use crate::server::driver_logs::DriverLogs;
+use crate::server::keep_alive::KeepAliveHandle;
 use crate::{Error, Result};
-use tokio::process::{Child, Command};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStderr, Command};
+use tokio::task::JoinHandle;
 
 /// Manages the Playwright server process lifecycle
 ///
@@ -32,6 +42,26 @@ pub struct PlaywrightServer {
     /// In production code, you should use the Connection layer instead of
     /// accessing the process directly.
     pub process: Child,
+
+    /// Captured driver stderr (and browser output when `DEBUG=pw:browser` is enabled)
+    pub logs: DriverLogs,
+
+    /// Private X server the driver runs against, when enabled via `PlaywrightConfig::xvfb`
+    ///
+    /// Killed when the server is dropped.
+    xvfb: Option<Xvfb>,
+
+    /// Connection watchdog, when enabled via `PlaywrightConfig::keep_alive`
+    ///
+    /// Stopped before the driver is shut down so the closing pipe is not reported.
+    keep_alive: Option<KeepAliveHandle>,
+
+    /// Deadlock detector, when enabled via `PlaywrightConfig::deadlock_detection`
+    #[cfg(feature = "deadlock-detection")]
+    deadlock_detector: Option<DeadlockDetectorHandle>,
+
+    /// Version from the driver's package.json, if it could be read
+    driver_version: Option<DriverVersion>,
 }
 
 impl PlaywrightServer {
@@ -41,6 +71,11 @@ impl PlaywrightServer {
     /// 1. Check if the Playwright driver exists (download if needed)
     /// 2. Launch the server using `node <driver>/cli.js run-driver`
     /// 3. Set environment variable `PW_LANG_NAME=rust`
+    /// 4. Capture the driver's stderr into an in-memory [`DriverLogs`] buffer,
+    ///    echoing each line to this process's stderr
+    ///
+    /// Browser process output is only captured when enabled with
+    /// [`PlaywrightConfig::capture_browser_output`].
     ///
     /// # Errors
     ///
@@ -49,12 +84,26 @@ impl PlaywrightServer {
     ///
     /// See: <https://playwright.dev/docs/api>
     pub async fn launch() -> Result<Self> {
+        Self::launch_with_config(&PlaywrightConfig::default()).await
+    }
+
+    /// Launch the Playwright server process with custom configuration
+    ///
+    /// Environment variables from `config` are applied to the driver process only,
+    /// after the defaults described in [`PlaywrightServer::launch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::ServerNotFound` if the driver cannot be located.
+    /// Returns `Error::LaunchFailed` if the process fails to start.
+    pub async fn launch_with_config(config: &PlaywrightConfig) -> Result<Self> {
         // Get the driver executable paths
         // The driver should already be downloaded by build.rs
         let (node_exe, cli_js) = get_driver_executable()?;
 
         // Launch the server process
-        let mut child = Command::new(&node_exe)
+        let mut command = Command::new(&node_exe);
+        command
             .arg(&cli_js)
             .arg("run-driver")
             .env("PW_LANG_NAME", "rust")
@@ -62,20 +111,56 @@ impl PlaywrightServer {
             .env("PW_CLI_DISPLAY_VERSION", env!("CARGO_PKG_VERSION"))
             .stdin(std::process::Stdio::piped())
             .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::inherit())
+            .stderr(std::process::Stdio::piped());
+
+        // Capture browser process output in the driver log when asked to, unless
+        // the user chose their own debug channels
+        let capture_browser_output = config.capture_browser_output
+            && std::env::var_os("DEBUG").is_none()
+            && !config.env.contains_key("DEBUG");
+        if capture_browser_output {
+            command.env("DEBUG", "pw:browser");
+        }
+        command.envs(&config.env);
+
+        // Optionally give headed browsers a virtual display
+        let xvfb = if config.xvfb {
+            let xvfb = Xvfb::start().await?;
+            command.env("DISPLAY", xvfb.display());
+            Some(xvfb)
+        } else {
+            None
+        };
+
+        let mut child = command
             .spawn()
             .map_err(|e| Error::LaunchFailed(format!("Failed to spawn process: {}", e)))?;
 
+        // Capture stderr into the driver log buffer
+        let logs = config
+            .driver_log_capacity
+            .map(DriverLogs::new)
+            .unwrap_or_default();
+        let log_reader = child
+            .stderr
+            .take()
+            .map(|stderr| spawn_log_reader(stderr, logs.clone(), capture_browser_output));
+
         // Check if process started successfully
         // Give it a moment to potentially fail
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
 
         match child.try_wait() {
             Ok(Some(status)) => {
-                return Err(Error::LaunchFailed(format!(
+                // Let the reader drain whatever the driver wrote before exiting
+                if let Some(reader) = log_reader {
+                    let _ =
+                        tokio::time::timeout(std::time::Duration::from_millis(500), reader).await;
+                }
+                return Err(logs.attach_to(Error::LaunchFailed(format!(
                     "Server process exited immediately with status: {}",
                     status
-                )));
+                ))));
             }
             Ok(None) => {
                 // Process is still running, good!
@@ -88,7 +173,54 @@ impl PlaywrightServer {
             }
         }
 
-        Ok(Self { process: child })
+        Ok(Self {
+            process: child,
+            logs,
+            xvfb,
+            keep_alive: None,
+            #[cfg(feature = "deadlock-detection")]
+            deadlock_detector: None,
+            driver_version: DriverVersion::from_cli_js(&cli_js),
+        })
+    }
+
+    /// Version of the running driver, if it could be determined
+    pub fn driver_version(&self) -> Option<DriverVersion> {
+        self.driver_version
+    }
+
+    /// Whether the driver process is still running, without waiting for it
+    pub(crate) fn process_state(&mut self) -> DriverProcess {
+        match self.process.try_wait() {
+            Ok(Some(status)) => DriverProcess::Exited(Some(status)),
+            Ok(None) => DriverProcess::Running,
+            Err(_) => DriverProcess::Exited(None),
+        }
+    }
+
+    /// Attaches the connection watchdog so it stops with the server
+    pub(crate) fn attach_keep_alive(&mut self, handle: KeepAliveHandle) {
+        self.keep_alive = Some(handle);
+    }
+
+    /// Attaches the deadlock detector so it stops with the server
+    #[cfg(feature = "deadlock-detection")]
+    pub(crate) fn attach_deadlock_detector(&mut self, handle: DeadlockDetectorHandle) {
+        self.deadlock_detector = Some(handle);
+    }
+
+    /// Stops the connection watchdogs ahead of an intentional shutdown
+    pub(crate) fn stop_keep_alive(&mut self) {
+        self.keep_alive = None;
+        #[cfg(feature = "deadlock-detection")]
+        {
+            self.deadlock_detector = None;
+        }
+    }
+
+    /// Returns the `DISPLAY` of the private Xvfb server, if one was started
+    pub fn xvfb_display(&self) -> Option<&str> {
+        self.xvfb.as_ref().map(Xvfb::display)
     }
 
     /// Shut down the server gracefully
@@ -108,6 +240,8 @@ impl PlaywrightServer {
     ///
     /// Returns an error if the shutdown fails or times out.
     pub async fn shutdown(mut self) -> Result<()> {
+        self.stop_keep_alive();
+
         #[cfg(windows)]
         {
             // Windows-specific cleanup: Close stdio pipes BEFORE killing process
@@ -169,6 +303,8 @@ impl PlaywrightServer {
     ///
     /// Returns an error if the kill operation fails.
     pub async fn kill(mut self) -> Result<()> {
+        self.stop_keep_alive();
+
         #[cfg(windows)]
         {
             // Windows: Close pipes before killing
@@ -201,10 +337,59 @@ impl PlaywrightServer {
     }
 }
 
+/// Reads the driver's stderr into `logs` and tracing until the pipe closes,
+/// echoing it to our own stderr
+///
+/// Browser output requested through `PlaywrightConfig::capture_browser_output`
+/// is kept in the buffer only, so the terminal shows what it would have shown
+/// had stderr been inherited.
+fn spawn_log_reader(
+    stderr: ChildStderr,
+    logs: DriverLogs,
+    capture_browser_output: bool,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut lines = BufReader::new(stderr).lines();
+        let mut parent_stderr = tokio::io::stderr();
+        while let Ok(Some(line)) = lines.next_line().await {
+            tracing::debug!(target: "playwright::driver", "{}", line);
+            if !(capture_browser_output && is_browser_output(&line)) {
+                let _ = parent_stderr
+                    .write_all(format!("{}\n", line).as_bytes())
+                    .await;
+            }
+            logs.push(line);
+        }
+    })
+}
+
+/// Whether a driver log line was written by the `pw:browser` debug channel
+fn is_browser_output(line: &str) -> bool {
+    line.split_whitespace()
+        .take(2)
+        .any(|word| word == "pw:browser")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_browser_output() {
+        assert!(is_browser_output(
+            "2025-01-01T00:00:00.000Z pw:browser [pid=42][err] Received signal 11"
+        ));
+        assert!(is_browser_output(
+            "pw:browser <launching> /ms-playwright/chrome"
+        ));
+        assert!(!is_browser_output(
+            "2025-01-01T00:00:00.000Z pw:api => page.goto"
+        ));
+        assert!(!is_browser_output(
+            "Error: page closed (see pw:browser logs)"
+        ));
+    }
+
     #[tokio::test]
     async fn test_server_launch_and_shutdown() {
         // This test will attempt to launch the Playwright server
@@ -5,6 +5,8 @@
 
 use crate::{Error, Result};
 use serde_json::Value as JsonValue;
+use std::future::Future;
+use std::pin::Pin;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tokio::sync::mpsc;
 
@@ -47,13 +49,58 @@ where
     Ok(())
 }
 
-/// Transport trait for abstracting communication mechanisms
+/// Boxed, sendable future returned by the transport traits
 ///
-/// Playwright server communication happens over stdio pipes using
-/// length-prefixed JSON messages.
-pub trait Transport: Send + Sync {
+/// The traits return boxed futures rather than using `async fn` so they stay
+/// object-safe and build on the crate's MSRV.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Sending half of a connection to the Playwright server
+///
+/// The driver is normally reached over stdio pipes ([`PipeTransport`]), but
+/// any channel that carries the protocol's JSON messages will do, e.g. a pipe
+/// tunneled over SSH. Implement this together with [`TransportReceiver`] and
+/// pass both to [`Playwright::connect_with_transport`](crate::Playwright::connect_with_transport).
+///
+/// The trait is object-safe; the connection holds it as `Box<dyn Transport>`.
+pub trait Transport: Send + 'static {
     /// Send a JSON message to the server
-    fn send(&mut self, message: JsonValue) -> impl std::future::Future<Output = Result<()>> + Send;
+    fn send(&mut self, message: JsonValue) -> BoxFuture<'_, Result<()>>;
+}
+
+/// Receiving half of a connection to the Playwright server
+///
+/// Runs in its own task, separate from [`Transport`], so a slow read never
+/// blocks a send. It owns the sender of the channel whose receiver is handed
+/// to the connection, the same shape [`PipeTransport::new`] returns.
+///
+/// # Example
+///
+/// ```ignore
+/// struct TunnelReceiver {
+///     stream: TunnelStream,
+///     messages: mpsc::UnboundedSender<serde_json::Value>,
+/// }
+///
+/// impl TransportReceiver for TunnelReceiver {
+///     fn run(mut self: Box<Self>) -> BoxFuture<'static, Result<()>> {
+///         Box::pin(async move {
+///             while let Some(message) = self.stream.next_message().await? {
+///                 if self.messages.send(message).is_err() {
+///                     break;
+///                 }
+///             }
+///             Ok(())
+///         })
+///     }
+/// }
+/// ```
+pub trait TransportReceiver: Send + 'static {
+    /// Reads messages and forwards them to the connection's channel
+    ///
+    /// Returns when the server closes the stream, the channel is closed, or
+    /// reading fails.
+    fn run(self: Box<Self>) -> BoxFuture<'static, Result<()>>;
 }
 
 /// Pipe-based transport for communicating with Playwright server
@@ -106,6 +153,16 @@ where
     message_tx: mpsc::UnboundedSender<JsonValue>,
 }
 
+/// Send-only part of PipeTransport
+///
+/// Holds stdin so the connection can send while the receiver owns stdout.
+pub struct PipeTransportSender<W>
+where
+    W: AsyncWrite + Unpin + Send,
+{
+    stdin: W,
+}
+
 /// Receive-only part of PipeTransport
 ///
 /// This struct only contains stdout and the message channel,
@@ -243,18 +300,18 @@ where
         (transport, message_rx)
     }
 
-    /// Split the transport into stdin and the rest
+    /// Split the transport into its sending and receiving halves
     ///
     /// This allows Connection to hold stdin separately (for sending)
     /// while run() owns stdout (for receiving).
     ///
     /// # Returns
     ///
-    /// Returns (stdin, self_without_stdin) where self_without_stdin
-    /// can still run the receive loop but cannot send.
-    pub fn into_parts(self) -> (W, PipeTransportReceiver<R>) {
+    /// Returns (sender, receiver) where the receiver can still run the
+    /// receive loop but cannot send.
+    pub fn into_parts(self) -> (PipeTransportSender<W>, PipeTransportReceiver<R>) {
         (
-            self.stdin,
+            PipeTransportSender { stdin: self.stdin },
             PipeTransportReceiver {
                 stdout: self.stdout,
                 message_tx: self.message_tx,
@@ -385,11 +442,29 @@ where
 
 impl<W, R> Transport for PipeTransport<W, R>
 where
-    W: AsyncWrite + Unpin + Send + Sync,
-    R: AsyncRead + Unpin + Send + Sync,
+    W: AsyncWrite + Unpin + Send + 'static,
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    fn send(&mut self, message: JsonValue) -> BoxFuture<'_, Result<()>> {
+        Box::pin(self.send_internal(message))
+    }
+}
+
+impl<W> Transport for PipeTransportSender<W>
+where
+    W: AsyncWrite + Unpin + Send + 'static,
+{
+    fn send(&mut self, message: JsonValue) -> BoxFuture<'_, Result<()>> {
+        Box::pin(send_message(&mut self.stdin, message))
+    }
+}
+
+impl<R> TransportReceiver for PipeTransportReceiver<R>
+where
+    R: AsyncRead + Unpin + Send + 'static,
 {
-    async fn send(&mut self, message: JsonValue) -> Result<()> {
-        self.send_internal(message).await
+    fn run(self: Box<Self>) -> BoxFuture<'static, Result<()>> {
+        Box::pin(PipeTransportReceiver::run(*self))
     }
 }
 
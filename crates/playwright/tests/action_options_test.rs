@@ -10,6 +10,7 @@
 // - Select options (force, timeout)
 // - Keyboard options (delay)
 // - Mouse options (button, click_count, delay, steps)
+// - Passing options directly, or () for the defaults (IntoOptions)
 // - Cross-browser compatibility
 //
 // Performance Optimization (Phase 6):
@@ -25,6 +26,7 @@ use playwright_rs::protocol::action_options::{
     SelectOptions,
 };
 use playwright_rs::protocol::{GotoOptions, MouseButton, Playwright, Position};
+use std::time::Duration;
 use test_server::TestServer;
 
 // ============================================================================
@@ -50,7 +52,7 @@ async fn test_action_options_methods() {
         .await
         .expect("Failed to navigate");
 
-    let input = page.locator("#input").await;
+    let input = page.locator("#input");
     let options = FillOptions::builder().force(true).build();
     input
         .fill("Hello World", Some(options))
@@ -67,12 +69,14 @@ async fn test_action_options_methods() {
         .await
         .expect("Failed to navigate");
 
-    let input = page.locator("#input").await;
+    let input = page.locator("#input");
     input.click(None).await.expect("Failed to click");
 
-    let options = PressOptions::builder().delay(50.0).build();
+    let options = PressOptions::builder()
+        .delay(Duration::from_millis(50))
+        .build();
     input
-        .press("Enter", Some(options))
+        .press("Enter", options)
         .await
         .expect("Failed to press with delay");
 
@@ -86,7 +90,7 @@ async fn test_action_options_methods() {
         .await
         .expect("Failed to navigate");
 
-    let checkbox = page.locator("#checkbox").await;
+    let checkbox = page.locator("#checkbox");
     let options = CheckOptions::builder().force(true).build();
     checkbox
         .check(Some(options))
@@ -98,7 +102,7 @@ async fn test_action_options_methods() {
         "Checkbox should be checked"
     );
 
-    let checked_checkbox = page.locator("#checked-checkbox").await;
+    let checked_checkbox = page.locator("#checked-checkbox");
     let trial_options = CheckOptions::builder().trial(true).build();
     checked_checkbox
         .uncheck(Some(trial_options))
@@ -117,16 +121,16 @@ async fn test_action_options_methods() {
         .await
         .expect("Failed to navigate");
 
-    let button = page.locator("#hover-button").await;
+    let button = page.locator("#hover-button");
     let options = HoverOptions::builder()
         .position(Position { x: 5.0, y: 5.0 })
         .build();
     button
-        .hover(Some(options))
+        .hover(options)
         .await
         .expect("Failed to hover with position");
 
-    let tooltip = page.locator("#tooltip").await;
+    let tooltip = page.locator("#tooltip");
     assert!(
         tooltip.is_visible().await.unwrap(),
         "Tooltip should be visible after hover"
@@ -139,7 +143,7 @@ async fn test_action_options_methods() {
         .await
         .expect("Failed to navigate");
 
-    let select = page.locator("#single-select").await;
+    let select = page.locator("#single-select");
     let options = SelectOptions::builder().force(true).build();
     let selected = select
         .select_option("apple", Some(options))
@@ -155,11 +159,13 @@ async fn test_action_options_methods() {
         .await
         .expect("Failed to navigate");
 
-    let input = page.locator("#keyboard-input").await;
-    input.click(None).await.expect("Failed to click input");
+    let input = page.locator("#keyboard-input");
+    input.click(()).await.expect("Failed to click input");
 
     let keyboard = page.keyboard();
-    let options = KeyboardOptions::builder().delay(10.0).build();
+    let options = KeyboardOptions::builder()
+        .delay(Duration::from_millis(10))
+        .build();
     keyboard
         .type_text("Hello", Some(options))
         .await
@@ -181,12 +187,7 @@ async fn test_action_options_methods() {
         .await
         .expect("Failed to click with options");
 
-    let result = page
-        .locator("#mouse-result")
-        .await
-        .inner_text()
-        .await
-        .unwrap();
+    let result = page.locator("#mouse-result").inner_text().await.unwrap();
     assert_eq!(result, "Clicked");
 
     tracing::info!("✓ Mouse click with options works");
@@ -222,12 +223,12 @@ async fn test_cross_browser_smoke() {
     firefox_page
         .goto(
             &format!("{}/input.html", server.url()),
-            Some(GotoOptions::new().timeout(std::time::Duration::from_secs(60))),
+            Some(GotoOptions::new().timeout(Duration::from_secs(60))),
         )
         .await
         .expect("Failed to navigate");
 
-    let firefox_input = firefox_page.locator("#input").await;
+    let firefox_input = firefox_page.locator("#input");
     let options = FillOptions::builder().force(true).build();
     firefox_input
         .fill("Firefox Test", Some(options))
@@ -252,12 +253,12 @@ async fn test_cross_browser_smoke() {
     webkit_page
         .goto(
             &format!("{}/checkbox.html", server.url()),
-            Some(GotoOptions::new().timeout(std::time::Duration::from_secs(60))),
+            Some(GotoOptions::new().timeout(Duration::from_secs(60))),
         )
         .await
         .expect("Failed to navigate");
 
-    let webkit_checkbox = webkit_page.locator("#checkbox").await;
+    let webkit_checkbox = webkit_page.locator("#checkbox");
     let options = CheckOptions::builder().force(true).build();
     webkit_checkbox
         .check(Some(options))
@@ -43,7 +43,7 @@ async fn test_click_actions() {
         .await
         .expect("Failed to navigate");
 
-    let button = page.locator("#btn").await;
+    let button = page.locator("#btn");
     button.click(None).await.expect("Failed to click button");
 
     let text = button.text_content().await.expect("Failed to get text");
@@ -54,7 +54,7 @@ async fn test_click_actions() {
         .await
         .expect("Failed to navigate");
 
-    let div = page.locator("#target").await;
+    let div = page.locator("#target");
     div.dblclick(None).await.expect("Failed to double-click");
 
     let text = div.text_content().await.expect("Failed to get text");
@@ -87,7 +87,7 @@ async fn test_fill_and_clear_actions() {
         .expect("Failed to navigate");
 
     // Test 1: Fill input field
-    let input = page.locator("#name").await;
+    let input = page.locator("#name");
     input
         .fill("John Doe", None)
         .await
@@ -100,7 +100,7 @@ async fn test_fill_and_clear_actions() {
     assert_eq!(value, "John Doe");
 
     // Test 2: Fill textarea
-    let textarea = page.locator("#bio").await;
+    let textarea = page.locator("#bio");
     textarea
         .fill("Hello\nWorld", None)
         .await
@@ -117,7 +117,7 @@ async fn test_fill_and_clear_actions() {
         .await
         .expect("Failed to navigate");
 
-    let clear_input = page.locator("#input").await;
+    let clear_input = page.locator("#input");
 
     // Verify initial value
     let initial_value = clear_input
@@ -166,7 +166,7 @@ async fn test_keyboard_actions() {
         .expect("Failed to navigate");
 
     // Test: Press Enter key changes input value via JavaScript
-    let input = page.locator("#input").await;
+    let input = page.locator("#input");
     input.click(None).await.expect("Failed to focus input");
     input
         .press("Enter", None)
@@ -216,7 +216,7 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to navigate");
 
-    let firefox_button = firefox_page.locator("#btn").await;
+    let firefox_button = firefox_page.locator("#btn");
     firefox_button
         .click(None)
         .await
@@ -246,7 +246,7 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to navigate");
 
-    let webkit_input = webkit_page.locator("#name").await;
+    let webkit_input = webkit_page.locator("#name");
     webkit_input
         .fill("Test", None)
         .await
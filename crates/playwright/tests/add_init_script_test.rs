@@ -5,11 +5,12 @@
 // - Page.add_init_script() - scripts applied to specific page
 // - Multiple pages inheriting context scripts
 // - Script execution before page scripts
+// - Script::Path file-based scripts and duplicate registration
 // - Cross-browser compatibility
 
 mod test_server;
 
-use playwright_rs::protocol::Playwright;
+use playwright_rs::protocol::{Playwright, Script};
 use test_server::TestServer;
 
 mod common;
@@ -348,3 +349,70 @@ async fn test_add_init_script_webkit() {
     browser.close().await.expect("Failed to close browser");
     server.shutdown();
 }
+
+#[tokio::test]
+async fn test_add_init_script_from_path() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+
+    let script_path = std::env::temp_dir().join("playwright_rs_init_script_test.js");
+    std::fs::write(
+        &script_path,
+        "window.initCount = (window.initCount || 0) + 1;",
+    )
+    .expect("Failed to write script file");
+
+    // The same file is registered on two contexts (read once, then cached)
+    let context1 = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    let context2 = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+
+    for context in [&context1, &context2] {
+        context
+            .add_init_script(Script::Path(script_path.clone()))
+            .await
+            .expect("Failed to add init script from path");
+        // Registering the same content again is a no-op
+        context
+            .add_init_script(Script::Path(script_path.clone()))
+            .await
+            .expect("Failed to re-add init script");
+
+        let page = context.new_page().await.expect("Failed to create page");
+        page.goto(&format!("{}/input.html", server.url()), None)
+            .await
+            .expect("Failed to navigate");
+
+        let count = page
+            .evaluate_value("window.initCount")
+            .await
+            .expect("Failed to evaluate initCount");
+        assert_eq!(count.trim(), "1", "Script should run exactly once");
+
+        // Script is re-applied after navigation
+        page.reload(None).await.expect("Failed to reload");
+        let count = page
+            .evaluate_value("window.initCount")
+            .await
+            .expect("Failed to evaluate initCount");
+        assert_eq!(count.trim(), "1", "Script should run once per document");
+    }
+
+    std::fs::remove_file(&script_path).ok();
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
@@ -0,0 +1,160 @@
+// Integration tests for harness artifact retention and test timeouts
+//
+// Tests cover:
+// - Tracing::start()/stop() writes a trace archive; stop(None) discards it
+// - retain-on-failure keeps the trace and screenshot of a failed attempt and
+//   leaves nothing behind for the passing retry
+// - on-first-retry records video only on the first retry
+// - BrowserContext::pages()
+// - An expired test timeout reports the pending click and saves a screenshot
+
+#![cfg(feature = "harness")]
+
+use playwright_rs::harness::{self, ArtifactMode, ArtifactPolicy};
+use playwright_rs::protocol::Playwright;
+use playwright_rs::Error;
+use std::time::Duration;
+
+mod common;
+
+#[tokio::test]
+async fn test_tracing_start_stop() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+    assert_eq!(context.pages().len(), 1);
+
+    let dir = std::env::temp_dir().join(format!("playwright_rs_trace_{}", std::process::id()));
+    let trace = dir.join("trace.zip");
+    let tracing = context.tracing().await.expect("Failed to get tracing");
+
+    tracing.start(None).await.expect("Failed to start tracing");
+    page.goto("data:text/html,<h1>traced</h1>", None)
+        .await
+        .expect("Failed to navigate");
+    tracing
+        .stop(Some(&trace))
+        .await
+        .expect("Failed to stop tracing");
+    assert!(std::fs::metadata(&trace).expect("Trace missing").len() > 0);
+
+    tracing
+        .start(None)
+        .await
+        .expect("Failed to restart tracing");
+    tracing.stop(None).await.expect("Failed to discard trace");
+
+    context.close().await.expect("Failed to close context");
+    browser.close().await.expect("Failed to close browser");
+    std::fs::remove_dir_all(&dir).expect("Failed to clean up");
+}
+
+#[tokio::test]
+async fn test_retain_on_failure_and_on_first_retry() {
+    common::init_tracing();
+    let output = std::env::temp_dir().join(format!("playwright_rs_results_{}", std::process::id()));
+    std::env::set_var(harness::artifacts::OUTPUT_DIR_ENV, &output);
+
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+
+    harness::test("flaky checkout")
+        .retries(1)
+        .artifacts(
+            ArtifactPolicy::new()
+                .trace(ArtifactMode::RetainOnFailure)
+                .screenshot(ArtifactMode::RetainOnFailure)
+                .video(ArtifactMode::OnFirstRetry),
+        )
+        .run(|info| {
+            let browser = browser.clone();
+            async move {
+                let context = info.new_context(&browser, None).await?;
+                let page = context.new_page().await?;
+                page.goto("data:text/html,<button>Pay</button>", None)
+                    .await?;
+                if info.retry == 0 {
+                    return Err(Error::InvalidArgument("first attempt fails".into()));
+                }
+                Ok(())
+            }
+        })
+        .await
+        .expect("Retry should pass");
+
+    let failed = output.join("flaky-checkout");
+    assert!(failed.join("trace.zip").exists());
+    assert!(failed.join("test-failed-1.png").exists());
+    assert!(!failed.join("videos").exists());
+
+    let retried = output.join("flaky-checkout-retry1");
+    assert!(!retried.join("trace.zip").exists());
+    assert!(!retried.join("test-finished-1.png").exists());
+    let videos = std::fs::read_dir(retried.join("videos"))
+        .expect("First retry should keep its video")
+        .count();
+    assert_eq!(videos, 1);
+
+    browser.close().await.expect("Failed to close browser");
+    std::fs::remove_dir_all(&output).expect("Failed to clean up");
+}
+
+#[tokio::test]
+async fn test_timeout_reports_pending_calls() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+
+    let err = harness::test("stuck click")
+        .timeout(Duration::from_secs(1))
+        .run(|info| {
+            let browser = browser.clone();
+            async move {
+                let context = info.new_context(&browser, None).await?;
+                let page = context.new_page().await?;
+                page.goto("data:text/html,<p>no button here</p>", None)
+                    .await?;
+                page.locator("#missing").click(None).await
+            }
+        })
+        .await
+        .expect_err("Test should time out");
+
+    let Error::Timeout(message) = err else {
+        panic!("Expected a timeout error, got {:?}", err);
+    };
+    assert!(message.starts_with("Test 'stuck click' timed out after 1s"));
+    assert!(message.contains(".click (waiting"), "{}", message);
+    let screenshot = message
+        .lines()
+        .find_map(|line| line.strip_prefix("Screenshot: "))
+        .map(std::path::PathBuf::from)
+        .expect("Timeout should capture a screenshot");
+    assert!(screenshot.ends_with("stuck-click/test-timeout-1.png"));
+
+    browser.close().await.expect("Failed to close browser");
+    let _ = std::fs::remove_dir_all(screenshot.parent().unwrap());
+}
@@ -6,7 +6,9 @@
 // - expect().to_be_visible() - auto-retry until visible
 // - expect().to_be_hidden() - auto-retry until hidden
 // - expect().not().to_be_visible() - negation support
+// - expect(&locator) - borrowing the locator
 // - Timeout behavior
+// - Reusing one configured expectation for several assertions
 // - Cross-browser compatibility
 //
 // Performance Optimization (Phase 6):
@@ -44,14 +46,14 @@ async fn test_to_be_visible_assertions() {
         .expect("Failed to navigate");
 
     // Test 1: Element that is already visible should pass immediately
-    let button = page.locator("#btn").await;
+    let button = page.locator("#btn");
     expect(button)
         .to_be_visible()
         .await
         .expect("Button should be visible");
 
     // Test 2: Negation - element should NOT be visible
-    let nonexistent = page.locator("#does-not-exist").await;
+    let nonexistent = page.locator("#does-not-exist");
     expect(nonexistent.clone())
         .not()
         .to_be_visible()
@@ -93,7 +95,7 @@ async fn test_to_be_visible_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let delayed = page.locator("#delayed-element").await;
+    let delayed = page.locator("#delayed-element");
     let start = std::time::Instant::now();
 
     expect(delayed)
@@ -125,12 +127,30 @@ async fn test_to_be_visible_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let slow = page.locator("#slow-element").await;
+    let slow = page.locator("#slow-element");
     expect(slow)
         .to_be_visible()
         .await
         .expect("Should wait up to 5s by default");
 
+    // Test 6: One configured expectation runs several assertions
+    let slow = expect(page.locator("#slow-element"))
+        .with_timeout(std::time::Duration::from_secs(2))
+        .with_poll_interval(std::time::Duration::from_millis(50));
+    slow.to_be_visible().await.expect("Should be visible");
+    slow.to_have_text("Slow element")
+        .await
+        .expect("Should have text");
+    slow.to_contain_text("Slow")
+        .await
+        .expect("Should contain text");
+    slow.clone()
+        .not()
+        .to_be_hidden()
+        .await
+        .expect("Should NOT be hidden");
+    slow.to_be_enabled().await.expect("Original is not negated");
+
     browser.close().await.expect("Failed to close browser");
     server.shutdown();
 }
@@ -158,13 +178,20 @@ async fn test_to_be_hidden_assertions() {
         .expect("Failed to navigate");
 
     // Test 1: Element that doesn't exist should be considered hidden
-    let nonexistent = page.locator("#does-not-exist").await;
+    let nonexistent = page.locator("#does-not-exist");
     expect(nonexistent)
         .to_be_hidden()
         .await
         .expect("Nonexistent element should be hidden");
 
     // Test 2: Auto-retry - assertion should wait until element becomes hidden
+    // expect() borrows the locator, so it stays usable afterwards
+    let button = page.locator("#btn");
+    expect(&button)
+        .to_be_visible()
+        .await
+        .expect("Button should be visible before hiding");
+
     page.evaluate_expression(
         r#"
         const btn = document.getElementById('btn');
@@ -176,10 +203,9 @@ async fn test_to_be_hidden_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let button = page.locator("#btn").await;
     let start = std::time::Instant::now();
 
-    expect(button)
+    expect(&button)
         .to_be_hidden()
         .await
         .expect("Button should eventually be hidden");
@@ -224,7 +250,7 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to navigate");
 
-    let firefox_button = firefox_page.locator("#btn").await;
+    let firefox_button = firefox_page.locator("#btn");
     expect(firefox_button)
         .to_be_visible()
         .await
@@ -245,7 +271,7 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to navigate");
 
-    let webkit_nonexistent = webkit_page.locator("#does-not-exist").await;
+    let webkit_nonexistent = webkit_page.locator("#does-not-exist");
     expect(webkit_nonexistent)
         .to_be_hidden()
         .await
@@ -274,7 +300,7 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to inject script");
 
-    let webkit_delayed = webkit_page.locator("#delayed-webkit").await;
+    let webkit_delayed = webkit_page.locator("#delayed-webkit");
     expect(webkit_delayed)
         .to_be_visible()
         .await
@@ -0,0 +1,125 @@
+// Integration tests for attribute assertions
+//
+// Tests cover:
+// - expect().to_have_attribute() exact match and polling until the value changes
+// - not().to_have_attribute() passing for other values and missing attributes
+// - Failure messages for wrong and missing values
+// - expect().to_have_attribute_regex() and its negation
+
+use playwright_rs::protocol::Playwright;
+use playwright_rs::{expect, Error};
+use std::time::Duration;
+
+mod common;
+
+const PAGE: &str = "data:text/html,<a id='docs' href='/docs' target='_blank'>Docs</a>\
+    <button id='save'>Save</button>";
+
+#[tokio::test]
+async fn test_to_have_attribute() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(PAGE, None).await.expect("Failed to navigate");
+
+    let link = page.locator("#docs");
+    expect(&link)
+        .to_have_attribute("href", "/docs")
+        .await
+        .expect("Link should point to /docs");
+    expect(&link)
+        .not()
+        .to_have_attribute("href", "/blog")
+        .await
+        .expect("Link should not point to /blog");
+
+    // A missing attribute never matches
+    let save = page.locator("#save");
+    expect(&save)
+        .not()
+        .to_have_attribute("aria-busy", "true")
+        .await
+        .expect("Button should not be busy");
+
+    // Polls until the attribute changes
+    page.evaluate::<(), ()>(
+        "setTimeout(() => document.getElementById('save').setAttribute('aria-busy', 'true'), 200)",
+        None,
+    )
+    .await
+    .expect("Failed to schedule change");
+    expect(&save)
+        .to_have_attribute("aria-busy", "true")
+        .await
+        .expect("Button should become busy");
+
+    let result = expect(&link)
+        .with_timeout(Duration::from_millis(300))
+        .to_have_attribute("href", "/blog")
+        .await;
+    match result {
+        Err(Error::AssertionTimeout(message)) => {
+            assert!(message.contains("but had '/docs'"), "{}", message)
+        }
+        other => panic!("Expected AssertionTimeout, got {:?}", other),
+    }
+
+    let result = expect(&link)
+        .with_timeout(Duration::from_millis(300))
+        .to_have_attribute("rel", "noopener")
+        .await;
+    match result {
+        Err(Error::AssertionTimeout(message)) => {
+            assert!(message.contains("the attribute was missing"), "{}", message)
+        }
+        other => panic!("Expected AssertionTimeout, got {:?}", other),
+    }
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[cfg(feature = "regex")]
+#[tokio::test]
+async fn test_to_have_attribute_regex() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(PAGE, None).await.expect("Failed to navigate");
+
+    let link = page.locator("#docs");
+    expect(&link)
+        .to_have_attribute_regex("href", r"^/do")
+        .await
+        .expect("Link should match pattern");
+    expect(&link)
+        .not()
+        .to_have_attribute_regex("href", r"^https?://")
+        .await
+        .expect("Link should be relative");
+    expect(&link)
+        .not()
+        .to_have_attribute_regex("rel", r".*")
+        .await
+        .expect("Missing attribute should not match any pattern");
+
+    let result = expect(&link)
+        .to_have_attribute_regex("href", r"(unclosed")
+        .await;
+    assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,52 @@
+// Integration tests for batched reads (page.run_batch)
+//
+// Tests cover:
+// - Mixed single-element and multi-element reads in one batch
+// - Missing elements produce None / false
+
+mod test_server;
+
+use playwright_rs::protocol::{Batch, Playwright};
+use test_server::TestServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_page_run_batch() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(&format!("{}/locators.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    let mut batch = Batch::new();
+    let heading = batch.text_content("h1");
+    let paragraphs = batch.count("p");
+    let texts = batch.all_text_contents("p");
+    let first_id = batch.get_attribute("p", "id");
+    let hidden_visible = batch.is_visible("#hidden");
+    let nested_visible = batch.is_visible("#nested");
+    let missing = batch.text_content(".does-not-exist");
+
+    let results = page.run_batch(&batch).await.expect("Failed to run batch");
+
+    assert_eq!(results.get(heading).unwrap().as_deref(), Some("Test Page"));
+    assert_eq!(results.get(paragraphs).unwrap(), 4);
+    assert_eq!(results.get(texts).unwrap()[3], "Fourth paragraph");
+    assert_eq!(results.get(first_id).unwrap().as_deref(), Some("p1"));
+    assert!(!results.get(hidden_visible).unwrap());
+    assert!(results.get(nested_visible).unwrap());
+    assert_eq!(results.get(missing).unwrap(), None);
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
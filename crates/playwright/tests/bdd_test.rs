@@ -0,0 +1,68 @@
+// Integration tests for harness::BrowserWorld
+//
+// Tests cover:
+// - Built-in steps: go to, fill (by label and placeholder), click, should see, URL contains
+// - Unknown steps return Ok(false)
+// - reset() opens a fresh context
+
+#![cfg(feature = "harness")]
+
+use playwright_rs::harness::BrowserWorld;
+use playwright_rs::protocol::Playwright;
+
+mod common;
+
+const LOGIN: &str = "data:text/html,<label>Email <input id='email'></label>\
+    <input placeholder='Password' type='password'>\
+    <button onclick=\"document.getElementById('msg').textContent = 'Welcome, ' + \
+    document.getElementById('email').value; location.hash = 'dashboard'\">Sign in</button>\
+    <p id='msg'></p>";
+
+#[tokio::test]
+async fn test_builtin_steps() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let mut world = BrowserWorld::new(&browser, None)
+        .await
+        .expect("Failed to create world");
+
+    let scenario = [
+        format!("Given I am on \"{}\"", LOGIN),
+        "When I fill in \"Email\" with \"ada@example.com\"".to_string(),
+        "And I fill \"Password\" with \"secret\"".to_string(),
+        "And I click \"Sign in\"".to_string(),
+        "Then I should see \"Welcome, ada@example.com\"".to_string(),
+        "And the URL should contain \"#dashboard\"".to_string(),
+        "But I should not see \"Error\"".to_string(),
+    ];
+    for step in &scenario {
+        assert!(
+            world.run_step(step).await.expect("Step failed"),
+            "Step should be recognized: {}",
+            step
+        );
+    }
+
+    assert!(!world
+        .run_step("I do something custom")
+        .await
+        .expect("Unknown steps are not errors"));
+
+    let old_context = world.context().clone();
+    world.reset().await.expect("Failed to reset");
+    assert!(
+        old_context.new_page().await.is_err(),
+        "Old context is closed"
+    );
+    assert_eq!(world.page().url(), "about:blank");
+
+    world.close().await.expect("Failed to close world");
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,72 @@
+// Integration tests for the multi-browser matrix runner
+//
+// Tests cover:
+// - for_each_browser() runs the body once per selected browser
+// - Errors from the body stop the matrix and are returned
+
+#![cfg(feature = "harness")]
+
+mod test_server;
+
+use playwright_rs::harness::{for_each_browser, selected_browsers};
+use playwright_rs::protocol::Playwright;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use test_server::TestServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_for_each_browser_runs_selected_browsers() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+
+    let runs = AtomicUsize::new(0);
+    let url = format!("{}/locators.html", server.url());
+
+    for_each_browser(&playwright, |browser_type| {
+        let url = url.clone();
+        let runs = &runs;
+        async move {
+            let browser = browser_type.launch().await?;
+            let page = browser.new_page().await?;
+            page.goto(&url, None).await?;
+            assert!(page.locator("h1").count().await? > 0);
+            runs.fetch_add(1, Ordering::SeqCst);
+            browser.close().await
+        }
+    })
+    .await
+    .expect("Browser matrix failed");
+
+    assert_eq!(
+        runs.load(Ordering::SeqCst),
+        selected_browsers().unwrap().len(),
+        "Body should run once per selected browser"
+    );
+
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_for_each_browser_stops_on_error() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+
+    let runs = AtomicUsize::new(0);
+    let result = for_each_browser(&playwright, |_browser_type| {
+        let runs = &runs;
+        async move {
+            runs.fetch_add(1, Ordering::SeqCst);
+            Err(playwright_rs::Error::InvalidArgument("boom".to_string()))
+        }
+    })
+    .await;
+
+    assert!(result.is_err(), "Error should propagate");
+    assert_eq!(runs.load(Ordering::SeqCst), 1, "Matrix should stop early");
+}
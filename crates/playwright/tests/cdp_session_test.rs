@@ -0,0 +1,177 @@
+// Integration tests for CDPSession and the typed CDP domain wrappers
+//
+// Tests cover:
+// - context.new_cdp_session(&page) and raw send()
+// - Typed Performance metrics
+// - Typed Network events
+// - Emulation overrides
+// - Tracing start/end collects trace events
+// - browser.new_browser_cdp_session()
+//
+// CDP is Chromium-only.
+
+mod test_server;
+
+use playwright_rs::protocol::cdp::TracingStartOptions;
+use playwright_rs::protocol::Playwright;
+use std::sync::{Arc, Mutex};
+use test_server::TestServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_cdp_session_raw_send() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+
+    let session = context
+        .new_cdp_session(&page)
+        .await
+        .expect("Failed to create CDP session");
+
+    let result = session
+        .send(
+            "Runtime.evaluate",
+            Some(serde_json::json!({ "expression": "1 + 2", "returnByValue": true })),
+        )
+        .await
+        .expect("Failed to send CDP command");
+    assert_eq!(result["result"]["value"], 3);
+
+    session.detach().await.expect("Failed to detach");
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[tokio::test]
+async fn test_cdp_typed_domains() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+    let session = context
+        .new_cdp_session(&page)
+        .await
+        .expect("Failed to create CDP session");
+
+    // Network events
+    let urls = Arc::new(Mutex::new(Vec::new()));
+    let urls_clone = Arc::clone(&urls);
+    session
+        .network()
+        .on_response_received(move |event| {
+            let urls = Arc::clone(&urls_clone);
+            async move {
+                urls.lock().unwrap().push(event.response.url);
+                Ok(())
+            }
+        })
+        .await
+        .expect("Failed to register handler");
+    session
+        .network()
+        .enable()
+        .await
+        .expect("Failed to enable Network");
+
+    page.goto(&format!("{}/locators.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    assert!(
+        urls.lock()
+            .unwrap()
+            .iter()
+            .any(|u| u.ends_with("/locators.html")),
+        "Should receive Network.responseReceived for the document"
+    );
+
+    // Performance metrics
+    session
+        .performance()
+        .enable()
+        .await
+        .expect("Failed to enable Performance");
+    let heap = session
+        .performance()
+        .get_metric("JSHeapUsedSize")
+        .await
+        .expect("Failed to get metrics");
+    assert!(heap.unwrap_or(0.0) > 0.0);
+
+    // Emulation
+    session
+        .emulation()
+        .set_timezone_override("Asia/Tokyo")
+        .await
+        .expect("Failed to override timezone");
+    let tz = page
+        .evaluate_value("Intl.DateTimeFormat().resolvedOptions().timeZone")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(tz, "Asia/Tokyo");
+
+    // Tracing
+    session
+        .tracing()
+        .start(TracingStartOptions::new().category("devtools.timeline"))
+        .await
+        .expect("Failed to start tracing");
+    page.reload(None).await.expect("Failed to reload");
+    let events = session
+        .tracing()
+        .end()
+        .await
+        .expect("Failed to end tracing");
+    assert!(!events.is_empty(), "Trace should contain events");
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_browser_cdp_session() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+
+    let session = browser
+        .new_browser_cdp_session()
+        .await
+        .expect("Failed to create browser CDP session");
+    let version = session
+        .send("Browser.getVersion", None)
+        .await
+        .expect("Failed to get version");
+    assert!(version["product"].as_str().unwrap_or("").contains("Chrome"));
+
+    browser.close().await.expect("Failed to close browser");
+}
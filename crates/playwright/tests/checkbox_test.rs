@@ -36,7 +36,7 @@ async fn test_check_unchecked_checkbox() {
         .expect("Failed to navigate");
 
     // Test: Check an unchecked checkbox
-    let checkbox = page.locator("#checkbox").await;
+    let checkbox = page.locator("#checkbox");
 
     // Verify it's initially unchecked
     let is_checked = checkbox.is_checked().await.expect("Failed to check state");
@@ -74,7 +74,7 @@ async fn test_check_is_idempotent() {
         .await
         .expect("Failed to navigate");
 
-    let checkbox = page.locator("#checked-checkbox").await;
+    let checkbox = page.locator("#checked-checkbox");
 
     // Verify it's already checked
     let is_checked = checkbox.is_checked().await.expect("Failed to check state");
@@ -112,7 +112,7 @@ async fn test_uncheck_checked_checkbox() {
         .await
         .expect("Failed to navigate");
 
-    let checkbox = page.locator("#checked-checkbox").await;
+    let checkbox = page.locator("#checked-checkbox");
 
     // Verify it's initially checked
     let is_checked = checkbox.is_checked().await.expect("Failed to check state");
@@ -150,7 +150,7 @@ async fn test_uncheck_is_idempotent() {
         .await
         .expect("Failed to navigate");
 
-    let checkbox = page.locator("#checkbox").await;
+    let checkbox = page.locator("#checkbox");
 
     // Verify it's initially unchecked
     let is_checked = checkbox.is_checked().await.expect("Failed to check state");
@@ -189,7 +189,7 @@ async fn test_check_radio_button() {
         .expect("Failed to navigate");
 
     // Test: Check a radio button
-    let radio = page.locator("#radio1").await;
+    let radio = page.locator("#radio1");
 
     // Verify it's initially unchecked
     let is_checked = radio.is_checked().await.expect("Failed to check state");
@@ -225,8 +225,8 @@ async fn test_hover() {
         .expect("Failed to navigate");
 
     // Test: Hover shows hidden element
-    let button = page.locator("#hover-button").await;
-    let tooltip = page.locator("#tooltip").await;
+    let button = page.locator("#hover-button");
+    let tooltip = page.locator("#tooltip");
 
     // Verify tooltip is initially hidden
     let is_visible = tooltip
@@ -269,7 +269,7 @@ async fn test_check_firefox() {
         .await
         .expect("Failed to navigate");
 
-    let checkbox = page.locator("#checkbox").await;
+    let checkbox = page.locator("#checkbox");
     checkbox
         .check(None)
         .await
@@ -300,11 +300,11 @@ async fn test_hover_webkit() {
         .await
         .expect("Failed to navigate");
 
-    let button = page.locator("#hover-button").await;
+    let button = page.locator("#hover-button");
     button.hover(None).await.expect("Failed to hover");
 
     // Verify hover worked (tooltip should be visible)
-    let tooltip = page.locator("#tooltip").await;
+    let tooltip = page.locator("#tooltip");
     let is_visible = tooltip
         .is_visible()
         .await
@@ -0,0 +1,91 @@
+// Integration tests for component testing
+//
+// Tests cover:
+// - Mounting a component with props into the harness page
+// - Interacting with the mounted component through the returned Locator
+// - Hooks config, remounting and unmounting
+
+mod test_server;
+
+use playwright_rs::protocol::{ComponentDescriptor, ComponentTesting, DevServer, Playwright};
+use test_server::TestServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_mount_component() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let dev_server = DevServer::external(server.url());
+    let ct = ComponentTesting::new(&dev_server);
+
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    let counter = ct
+        .mount(
+            &page,
+            &ComponentDescriptor::new("Counter")
+                .prop("label", "Clicks")
+                .prop("start", 1)
+                .prop("step", 2),
+        )
+        .await
+        .expect("Failed to mount Counter");
+    assert_eq!(
+        counter
+            .locator(".label")
+            .inner_text()
+            .await
+            .expect("Failed to read label"),
+        "Clicks"
+    );
+    counter
+        .locator("button")
+        .click(None)
+        .await
+        .expect("Failed to click");
+    assert_eq!(
+        counter
+            .locator("output")
+            .inner_text()
+            .await
+            .expect("Failed to read count"),
+        "3"
+    );
+
+    // Remount a different component with hooks config
+    ct.unmount(&page).await.expect("Failed to unmount");
+    let greeting = ct
+        .mount(
+            &page,
+            &ComponentDescriptor::new("Greeting")
+                .prop("name", "Ada")
+                .hooks_config(serde_json::json!({ "greeting": "Welcome" })),
+        )
+        .await
+        .expect("Failed to mount Greeting");
+    assert_eq!(
+        greeting
+            .inner_text()
+            .await
+            .expect("Failed to read greeting"),
+        "Welcome, Ada!"
+    );
+
+    // Unknown components surface the harness error
+    assert!(ct
+        .mount(&page, &ComponentDescriptor::new("Missing"))
+        .await
+        .is_err());
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
@@ -0,0 +1,51 @@
+// Integration tests for ContextPool
+//
+// Tests cover:
+// - Released contexts are reused
+// - Contexts exceeding max_pages are closed and replaced on the next acquire
+
+use playwright_rs::protocol::{ContextPool, Playwright, RecyclePolicy};
+use playwright_rs::server::channel_owner::ChannelOwner;
+
+mod common;
+
+#[tokio::test]
+async fn test_context_pool_reuses_and_recycles() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+
+    let pool = ContextPool::new(browser.clone(), None, RecyclePolicy::new().max_pages(2));
+
+    let first_guid = {
+        let mut context = pool.acquire().await.expect("Failed to acquire context");
+        let page = context.new_page().await.expect("Failed to create page");
+        page.close().await.expect("Failed to close page");
+        context.guid().to_string()
+    };
+    assert_eq!(pool.idle_count(), 1);
+
+    // Reused: one page served so far, below the limit
+    {
+        let mut context = pool.acquire().await.expect("Failed to acquire context");
+        assert_eq!(context.guid(), first_guid);
+        context.new_page().await.expect("Failed to create page");
+        assert_eq!(context.pages_served(), 2);
+    }
+
+    // Limit reached: the context is replaced transparently
+    let context = pool.acquire().await.expect("Failed to acquire context");
+    assert_ne!(context.guid(), first_guid);
+    assert_eq!(context.pages_served(), 0);
+    assert_eq!(pool.recycled_count(), 1);
+    drop(context);
+
+    pool.close().await.expect("Failed to close pool");
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,77 @@
+// Integration tests for crash reports
+//
+// Tests cover:
+// - A crashed renderer is recorded as a page crash
+// - Closing a browser normally records no crash
+// - A browser whose process dies gets a crash report
+
+use playwright_rs::protocol::{CrashKind, Playwright};
+use std::time::Duration;
+
+mod common;
+
+#[tokio::test]
+async fn test_page_crash_is_reported() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    // Chromium kills the renderer when navigating here
+    let _ = page.goto("chrome://crash", None).await;
+
+    let mut reports = playwright.crash_reports();
+    for _ in 0..50 {
+        if !reports.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        reports = playwright.crash_reports();
+    }
+    assert_eq!(reports.len(), 1, "Expected one crash report: {:?}", reports);
+    assert_eq!(reports[0].kind, CrashKind::Page);
+    assert!(browser.crash_report().is_none());
+
+    browser.close().await.expect("Failed to close browser");
+    assert!(browser.crash_report().is_none());
+}
+
+#[tokio::test]
+async fn test_browser_crash_is_reported() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    // Kill the browser process behind Playwright's back
+    let _ = page.goto("chrome://inducebrowsercrashforrealz", None).await;
+
+    for _ in 0..50 {
+        if !browser.is_connected() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let report = browser
+        .crash_report()
+        .expect("Failed to get browser crash report");
+    assert_eq!(report.kind, CrashKind::Browser);
+    assert_eq!(report.target, "chromium");
+    assert!(!report.stderr_tail.is_empty());
+    assert!(playwright
+        .crash_reports()
+        .iter()
+        .any(|crash| crash.kind == CrashKind::Browser));
+}
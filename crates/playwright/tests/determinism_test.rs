@@ -0,0 +1,117 @@
+// Integration tests for Clock and the Determinism preset
+//
+// Tests cover:
+// - Date.now() is fixed at the configured time
+// - Math.random produces the same sequence on every page
+// - Animations are disabled by an injected stylesheet
+
+mod test_server;
+
+use playwright_rs::protocol::{Determinism, Playwright};
+use test_server::TestServer;
+
+mod common;
+
+const RANDOMS: &str = "[Math.random(), Math.random(), Math.random()].join(',')";
+
+#[tokio::test]
+async fn test_determinism_preset() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+
+    Determinism::new()
+        .seed(7)
+        .apply(&context)
+        .await
+        .expect("Failed to apply determinism preset");
+
+    let first = context.new_page().await.expect("Failed to create page");
+    first
+        .goto(&format!("{}/input.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+    let second = context.new_page().await.expect("Failed to create page");
+    second
+        .goto(&format!("{}/input.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    let now = first
+        .evaluate_value("Date.now()")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(
+        now.parse::<f64>().unwrap(),
+        Determinism::DEFAULT_TIME,
+        "Date.now() should be fixed"
+    );
+
+    let first_randoms = first.evaluate_value(RANDOMS).await.unwrap();
+    let second_randoms = second.evaluate_value(RANDOMS).await.unwrap();
+    assert_eq!(
+        first_randoms, second_randoms,
+        "Math.random should be seeded"
+    );
+
+    let styles = first
+        .evaluate_value("document.querySelectorAll('style[data-playwright-determinism]').length")
+        .await
+        .unwrap();
+    assert_eq!(
+        styles, "1",
+        "Animation-disabling stylesheet should be injected"
+    );
+
+    context.close().await.expect("Failed to close context");
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_clock_run_for_advances_fake_timers() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    let clock = page.clock().expect("Failed to get clock");
+    clock
+        .install(Some(Determinism::DEFAULT_TIME))
+        .await
+        .expect("Failed to install clock");
+    clock
+        .pause_at(Determinism::DEFAULT_TIME)
+        .await
+        .expect("Failed to pause clock");
+
+    page.evaluate_value("window.fired = false; setTimeout(() => window.fired = true, 5000); 0")
+        .await
+        .unwrap();
+    assert_eq!(page.evaluate_value("window.fired").await.unwrap(), "false");
+
+    clock
+        .run_for(std::time::Duration::from_secs(5))
+        .await
+        .expect("Failed to run clock");
+    assert_eq!(page.evaluate_value("window.fired").await.unwrap(), "true");
+
+    browser.close().await.expect("Failed to close browser");
+}
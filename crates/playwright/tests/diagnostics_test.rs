@@ -0,0 +1,90 @@
+// Integration tests for DiagnosticsPolicy
+//
+// Tests cover:
+// - Timed-out actions carry a screenshot and DOM snapshot
+// - Strict mode violations are captured
+// - Without a policy, errors are returned unchanged
+
+mod test_server;
+
+use playwright_rs::protocol::{ClickOptions, DiagnosticsPolicy, Playwright};
+use playwright_rs::Error;
+use std::time::Duration;
+use test_server::TestServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_failed_actions_capture_diagnostics() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+
+    page.goto(&format!("{}/locators.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    let quick = || {
+        Some(
+            ClickOptions::builder()
+                .timeout(Duration::from_millis(500))
+                .build(),
+        )
+    };
+
+    // No policy: the original error is returned
+    let err = page
+        .locator("#missing")
+        .click(quick())
+        .await
+        .expect_err("Click on missing element should fail");
+    assert!(matches!(err, Error::Timeout(_)));
+    assert!(err.diagnostics().is_none());
+
+    let dir =
+        std::env::temp_dir().join(format!("playwright_rs_diagnostics_{}", std::process::id()));
+    context.set_diagnostics_policy(Some(DiagnosticsPolicy::new(&dir)));
+
+    // Timeout
+    let err = page
+        .locator("#missing")
+        .click(quick())
+        .await
+        .expect_err("Click on missing element should fail");
+    assert!(matches!(err.inner(), Error::Timeout(_)));
+    let diagnostics = err.diagnostics().expect("Diagnostics should be attached");
+    let screenshot = diagnostics.screenshot.as_ref().expect("Missing screenshot");
+    assert!(std::fs::read(screenshot)
+        .expect("Failed to read screenshot")
+        .starts_with(b"\x89PNG"));
+    let dom = diagnostics
+        .dom_snapshot
+        .as_ref()
+        .expect("Missing DOM snapshot");
+    let html = std::fs::read_to_string(dom).expect("Failed to read DOM snapshot");
+    assert!(html.contains("First paragraph"));
+
+    // Strict mode violation (4 paragraphs)
+    let err = page
+        .locator("p")
+        .click(quick())
+        .await
+        .expect_err("Strict click on 4 elements should fail");
+    assert!(err.diagnostics().is_some(), "Strict violation: {}", err);
+
+    std::fs::remove_dir_all(&dir).ok();
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
@@ -0,0 +1,97 @@
+// Integration tests for Locator::dispatch_event
+//
+// Tests cover:
+// - Dispatching a click with no event init
+// - Custom events with a serde_json::Value init (detail, bubbling)
+// - A typed, serializable init struct for a DragEvent
+
+use playwright_rs::protocol::Playwright;
+use serde::Serialize;
+
+mod common;
+
+const PAGE: &str = "data:text/html,\
+    <button onclick='this.textContent=`Clicked`'>Go</button>\
+    <div id='list'><span id='item'>Item</span></div>\
+    <div id='card' draggable='true'>Card</div>\
+    <script>\
+    document.getElementById('list').addEventListener('item:selected', e => {\
+      e.currentTarget.dataset.selected = e.detail.id;\
+    });\
+    document.getElementById('card').addEventListener('dragstart', e => {\
+      e.target.dataset.start = e.clientX + ',' + e.clientY;\
+    });\
+    </script>";
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PointerInit {
+    client_x: i32,
+    client_y: i32,
+}
+
+#[tokio::test]
+async fn test_dispatch_event() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(PAGE, None).await.expect("Failed to navigate");
+
+    // Click with the default init
+    let button = page.locator("button");
+    button
+        .dispatch_event("click", (), None)
+        .await
+        .expect("Failed to dispatch click");
+    assert_eq!(
+        button.inner_text().await.expect("Failed to read button"),
+        "Clicked"
+    );
+
+    // A custom event bubbles up to the list with its detail
+    page.locator("#item")
+        .dispatch_event(
+            "item:selected",
+            serde_json::json!({"detail": {"id": "42"}}),
+            None,
+        )
+        .await
+        .expect("Failed to dispatch custom event");
+    assert_eq!(
+        page.locator("#list")
+            .get_attribute("data-selected")
+            .await
+            .expect("Failed to read attribute")
+            .as_deref(),
+        Some("42")
+    );
+
+    // Typed init struct for a drag event
+    let card = page.locator("#card");
+    card.dispatch_event(
+        "dragstart",
+        PointerInit {
+            client_x: 10,
+            client_y: 20,
+        },
+        None,
+    )
+    .await
+    .expect("Failed to dispatch dragstart");
+    assert_eq!(
+        card.get_attribute("data-start")
+            .await
+            .expect("Failed to read attribute")
+            .as_deref(),
+        Some("10,20")
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
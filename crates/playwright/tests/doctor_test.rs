@@ -0,0 +1,50 @@
+// Integration tests for environment diagnostics and Docker presets
+//
+// Tests cover:
+// - doctor() locates the driver and reports each browser
+// - LaunchOptions::docker_defaults() launches Chromium
+
+use playwright_rs::api::{doctor, LaunchOptions};
+use playwright_rs::protocol::Playwright;
+
+mod common;
+
+#[tokio::test]
+async fn test_doctor_reports_browsers() {
+    common::init_tracing();
+    let report = doctor().await;
+
+    assert!(
+        report.driver.is_some(),
+        "Driver should be found: {}",
+        report
+    );
+    assert!(report.driver_error.is_none(), "{}", report);
+
+    let names: Vec<_> = report.browsers.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(names, vec!["chromium", "firefox", "webkit"]);
+
+    // Every problem comes with a suggestion
+    let problems = report.browsers.iter().filter(|b| !b.is_ok()).count();
+    assert!(report.suggestions().len() >= problems);
+}
+
+#[tokio::test]
+async fn test_docker_defaults_launch_chromium() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+
+    let browser = playwright
+        .chromium()
+        .launch_with_options(LaunchOptions::docker_defaults())
+        .await
+        .expect("Failed to launch browser with docker defaults");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto("data:text/html,<h1>docker</h1>", None)
+        .await
+        .expect("Failed to navigate");
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,70 @@
+// Integration tests for DOM snapshots and diffs
+//
+// Tests cover:
+// - Actions that change nothing produce an empty diff
+// - Text, attribute and form-state changes are reported
+// - Added and removed elements are reported
+
+use playwright_rs::protocol::Playwright;
+
+mod common;
+
+#[tokio::test]
+async fn test_diff_dom() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.goto(
+        "data:text/html,<button id='noop'>Noop</button>\
+         <button id='go' onclick=\"status.textContent='Done';status.className='ok';\
+         toast.remove();list.appendChild(document.createElement('li'))\">Go</button>\
+         <p id='status'>Idle</p><div id='toast'>Hello</div><ul id='list'></ul>\
+         <input id='name'>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let noop = page.locator("#noop");
+    let ((), diff) = page
+        .diff_dom(noop.click(None))
+        .await
+        .expect("Failed to diff no-op click");
+    assert!(diff.is_empty(), "Unexpected changes: {}", diff);
+
+    let go = page.locator("#go");
+    let ((), diff) = page
+        .diff_dom(go.click(None))
+        .await
+        .expect("Failed to diff click");
+    assert_eq!(diff.removed, vec!["html > body > div#toast"]);
+    assert_eq!(diff.added, vec!["html > body > ul#list > li"]);
+    let status = diff
+        .changed
+        .iter()
+        .find(|c| c.path == "html > body > p#status")
+        .expect("Status change missing");
+    assert_eq!(status.text, Some(("Idle".to_string(), "Done".to_string())));
+    assert!(status.attributes.contains_key("class"));
+
+    // Form state is captured as a pseudo-attribute
+    let before = page.snapshot_dom().await.expect("Failed to snapshot");
+    page.locator("#name")
+        .fill("Ada", None)
+        .await
+        .expect("Failed to fill");
+    let after = page.snapshot_dom().await.expect("Failed to snapshot");
+    let diff = before.diff(&after);
+    assert_eq!(diff.changed.len(), 1);
+    assert!(diff.changed[0].attributes.contains_key(":value"));
+
+    browser.close().await.expect("Failed to close browser");
+}
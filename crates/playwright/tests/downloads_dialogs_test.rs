@@ -61,7 +61,7 @@ async fn test_download_methods() -> Result<(), Box<dyn std::error::Error>> {
     )
     .await?;
 
-    let locator = page.locator("#download-link").await;
+    let locator = page.locator("#download-link");
     locator.click(None).await?;
 
     tokio::time::sleep(Duration::from_millis(500)).await;
@@ -106,7 +106,7 @@ async fn test_download_methods() -> Result<(), Box<dyn std::error::Error>> {
     )
     .await?;
 
-    let locator = page.locator("#dl").await;
+    let locator = page.locator("#dl");
     locator.click(None).await?;
 
     tokio::time::sleep(Duration::from_millis(500)).await;
@@ -176,7 +176,7 @@ async fn test_dialog_alert_methods() -> Result<(), Box<dyn std::error::Error>> {
     )
     .await?;
 
-    let locator = page.locator("button").await;
+    let locator = page.locator("button");
     locator.click(None).await?;
 
     tokio::time::sleep(Duration::from_millis(300)).await;
@@ -241,7 +241,7 @@ async fn test_dialog_confirm_methods() -> Result<(), Box<dyn std::error::Error>>
         )
         .await?;
 
-    let locator = page1.locator("button").await;
+    let locator = page1.locator("button");
     locator.click(None).await?;
 
     tokio::time::sleep(Duration::from_millis(300)).await;
@@ -279,7 +279,7 @@ async fn test_dialog_confirm_methods() -> Result<(), Box<dyn std::error::Error>>
         )
         .await?;
 
-    let locator = page2.locator("button").await;
+    let locator = page2.locator("button");
     locator.click(None).await?;
 
     tokio::time::sleep(Duration::from_millis(300)).await;
@@ -344,7 +344,7 @@ async fn test_dialog_prompt_methods() -> Result<(), Box<dyn std::error::Error>>
         )
         .await?;
 
-    let locator = page1.locator("button").await;
+    let locator = page1.locator("button");
     locator.click(None).await?;
 
     tokio::time::sleep(Duration::from_millis(300)).await;
@@ -389,7 +389,7 @@ async fn test_dialog_prompt_methods() -> Result<(), Box<dyn std::error::Error>>
         )
         .await?;
 
-    let locator = page2.locator("button").await;
+    let locator = page2.locator("button");
     locator.click(None).await?;
 
     tokio::time::sleep(Duration::from_millis(300)).await;
@@ -445,7 +445,7 @@ async fn test_cross_browser_smoke() -> Result<(), Box<dyn std::error::Error>> {
         )
         .await?;
 
-    let locator = firefox_page.locator("button").await;
+    let locator = firefox_page.locator("button");
     locator.click(None).await?;
 
     tokio::time::sleep(Duration::from_millis(300)).await;
@@ -489,7 +489,7 @@ async fn test_cross_browser_smoke() -> Result<(), Box<dyn std::error::Error>> {
         )
         .await?;
 
-    let locator = webkit_page.locator("#dl").await;
+    let locator = webkit_page.locator("#dl");
     locator.click(None).await?;
 
     tokio::time::sleep(Duration::from_millis(500)).await;
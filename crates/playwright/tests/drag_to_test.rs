@@ -0,0 +1,83 @@
+// Integration tests for Locator::drag_to
+//
+// Tests cover:
+// - Dragging a card between kanban columns (HTML5 drag and drop)
+// - trial: actionability checks without dropping
+// - target_position: drop point relative to the target element
+
+use playwright_rs::protocol::{DragToOptions, Playwright, Position};
+
+mod common;
+
+const BOARD: &str = "data:text/html,\
+    <style>.col{display:inline-block;width:150px;height:200px;border:1px solid}</style>\
+    <div class='col' id='todo'><div draggable='true' id='card'>Write docs</div></div>\
+    <div class='col' id='done'></div>\
+    <script>\
+    const card = document.getElementById('card');\
+    card.addEventListener('dragstart', e => e.dataTransfer.setData('text/plain', card.id));\
+    for (const col of document.querySelectorAll('.col')) {\
+      col.addEventListener('dragover', e => e.preventDefault());\
+      col.addEventListener('drop', e => {\
+        e.preventDefault();\
+        col.appendChild(card);\
+        col.dataset.dropY = Math.round(e.offsetY);\
+      });\
+    }\
+    </script>";
+
+#[tokio::test]
+async fn test_drag_to() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(BOARD, None).await.expect("Failed to navigate");
+
+    let card = page.locator("#card");
+    let done = page.locator("#done");
+    let done_count = || async {
+        done.locator("#card")
+            .count()
+            .await
+            .expect("Failed to count")
+    };
+
+    // A trial run checks actionability but does not drop
+    card.drag_to(&done, DragToOptions::builder().trial(true).build())
+        .await
+        .expect("Trial drag failed");
+    assert_eq!(done_count().await, 0);
+
+    // Drop near the top of the column
+    card.drag_to(
+        &done,
+        DragToOptions::builder()
+            .target_position(Position { x: 20.0, y: 10.0 })
+            .build(),
+    )
+    .await
+    .expect("Failed to drag card");
+    assert_eq!(done_count().await, 1);
+    assert_eq!(
+        done.get_attribute("data-drop-y")
+            .await
+            .expect("Failed to read drop position")
+            .as_deref(),
+        Some("10")
+    );
+
+    // And back, with default options
+    card.drag_to(&page.locator("#todo"), ())
+        .await
+        .expect("Failed to drag card back");
+    assert_eq!(done_count().await, 0);
+
+    browser.close().await.expect("Failed to close browser");
+}
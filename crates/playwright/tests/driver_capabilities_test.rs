@@ -0,0 +1,53 @@
+// Integration tests for driver capability detection
+//
+// Tests cover:
+// - Reading the launched driver's version
+// - Capability checks against the bundled driver
+// - Locator::aria_snapshot on a supported driver
+
+use playwright_rs::protocol::{Capability, Playwright};
+
+mod common;
+
+#[tokio::test]
+async fn test_driver_capabilities() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+
+    let version = playwright
+        .driver_version()
+        .expect("Failed to read driver version");
+    assert!(version >= "1.49".parse().expect("Failed to parse version"));
+
+    let capabilities = playwright.capabilities();
+    assert_eq!(capabilities.version, Some(version));
+    assert!(capabilities.supports(Capability::Clock));
+    capabilities
+        .require(Capability::AriaSnapshot)
+        .expect("Bundled driver should support ARIA snapshots");
+
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html,<main><h1>Title</h1><button>Save</button></main>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let snapshot = page
+        .locator("main")
+        .aria_snapshot()
+        .await
+        .expect("Failed to take ARIA snapshot");
+    assert!(snapshot.contains("heading \"Title\""), "{}", snapshot);
+    assert!(snapshot.contains("button \"Save\""), "{}", snapshot);
+
+    browser.close().await.expect("Failed to close browser");
+}
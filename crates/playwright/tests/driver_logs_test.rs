@@ -0,0 +1,59 @@
+// Integration tests for driver log capture
+//
+// Tests cover:
+// - Playwright.driver_logs() captures browser launch output (DEBUG=pw:browser)
+// - Launch errors include the tail of the driver log
+
+use playwright_rs::api::LaunchOptions;
+use playwright_rs::protocol::Playwright;
+
+mod common;
+
+#[tokio::test]
+async fn test_driver_logs_capture_browser_launch() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    browser.close().await.expect("Failed to close browser");
+
+    // Driver output is captured asynchronously
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    let logs = playwright.driver_logs();
+    if std::env::var_os("DEBUG").is_none() {
+        assert!(
+            logs.iter().any(|line| line.contains("<launching>")),
+            "Expected browser launch in driver logs, got: {:?}",
+            logs
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_launch_error_includes_driver_log_tail() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+
+    let options = LaunchOptions::default().executable_path("/nonexistent/browser".to_string());
+    let err = playwright
+        .chromium()
+        .launch_with_options(options)
+        .await
+        .expect_err("Launch with a bogus executable should fail");
+
+    let message = err.to_string();
+    assert!(
+        message.contains("/nonexistent/browser"),
+        "Error should mention the executable: {}",
+        message
+    );
+}
@@ -8,10 +8,13 @@
 // - page.query_selector_all() returns multiple ElementHandles
 // - ElementHandle.screenshot() captures element screenshot
 // - locator.screenshot() delegates to ElementHandle
+// - locator.bounding_box() / ElementHandle.bounding_box()
+// - locator.screenshot_to_file() with mask/animations options
+// - ElementHandle click/fill/get_attribute/owner_frame/dispose
 
 mod test_server;
 
-use playwright_rs::protocol::Playwright;
+use playwright_rs::protocol::{BoundingBox, Playwright, ScreenshotAnimations, ScreenshotOptions};
 use test_server::TestServer;
 
 mod common;
@@ -171,7 +174,7 @@ async fn test_locator_screenshot_via_element_handle() {
         .expect("Failed to navigate");
 
     // Test: locator.screenshot() delegates to ElementHandle
-    let locator = page.locator("h1").await;
+    let locator = page.locator("h1");
     let bytes = locator
         .screenshot(None)
         .await
@@ -189,6 +192,132 @@ async fn test_locator_screenshot_via_element_handle() {
     server.shutdown();
 }
 
+#[tokio::test]
+async fn test_locator_screenshot_to_file_with_mask() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.goto(
+        "data:text/html,<div id='card' style='width:200px;height:100px;background:white'>\
+         <span id='time'>12:00:01</span></div>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let card = page.locator("#card");
+    let options = || {
+        ScreenshotOptions::builder()
+            .animations(ScreenshotAnimations::Disabled)
+            .mask(vec![card.locator("#time")])
+            .build()
+    };
+    let path = std::env::temp_dir().join(format!(
+        "playwright_rs_locator_screenshot_{}.png",
+        std::process::id()
+    ));
+    let bytes = card
+        .screenshot_to_file(&path, Some(options()))
+        .await
+        .expect("Failed to take locator screenshot");
+    assert_eq!(&bytes[0..4], &[0x89, 0x50, 0x4E, 0x47]);
+    assert_eq!(
+        std::fs::read(&path).expect("Failed to read screenshot file"),
+        bytes
+    );
+
+    // The masked timestamp no longer affects the image
+    page.evaluate_expression("document.getElementById('time').textContent = '12:34:56'")
+        .await
+        .expect("Failed to update time");
+    let again = card
+        .screenshot(Some(options()))
+        .await
+        .expect("Failed to take locator screenshot");
+    assert_eq!(
+        again, bytes,
+        "Masked content should not change the screenshot"
+    );
+
+    std::fs::remove_file(&path).expect("Failed to remove screenshot file");
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[tokio::test]
+async fn test_bounding_box() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.goto(
+        "data:text/html,<body style='margin:0'>\
+         <div id='box' style='position:absolute;left:10px;top:20px;width:100px;height:50px'></div>\
+         <div id='hidden' style='display:none'>Hidden</div></body>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let bbox = page
+        .locator("#box")
+        .bounding_box()
+        .await
+        .expect("Failed to get bounding box")
+        .expect("Visible element should have a bounding box");
+    assert_eq!(
+        bbox,
+        BoundingBox {
+            x: 10.0,
+            y: 20.0,
+            width: 100.0,
+            height: 50.0
+        }
+    );
+    assert_eq!(bbox.center(), (60.0, 45.0));
+
+    // Hidden elements have no box
+    let hidden = page
+        .locator("#hidden")
+        .bounding_box()
+        .await
+        .expect("Failed to get bounding box");
+    assert_eq!(hidden, None);
+
+    // Missing elements are an error
+    assert!(page.locator("#missing").bounding_box().await.is_err());
+
+    // Same box through an element handle
+    let handle = page
+        .query_selector("#box")
+        .await
+        .expect("Failed to query")
+        .expect("Element not found");
+    assert_eq!(
+        handle
+            .bounding_box()
+            .await
+            .expect("Failed to get bounding box"),
+        Some(bbox)
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
+
 // Cross-browser tests
 
 #[tokio::test]
@@ -262,3 +391,84 @@ async fn test_element_handle_screenshot_webkit() {
     browser.close().await.expect("Failed to close browser");
     server.shutdown();
 }
+
+#[tokio::test]
+async fn test_element_handle_actions() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html,<input id='name' data-kind='text'>\
+        <button onclick=\"this.textContent = 'Clicked'\">Go</button>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let input = page
+        .query_selector("#name")
+        .await
+        .expect("Failed to query selector")
+        .expect("Should find input");
+    assert_eq!(
+        input
+            .get_attribute("data-kind")
+            .await
+            .expect("Failed to get attribute"),
+        Some("text".to_string())
+    );
+    assert_eq!(
+        input
+            .get_attribute("missing")
+            .await
+            .expect("Failed to get attribute"),
+        None
+    );
+    input.fill("Ada", None).await.expect("Failed to fill");
+    assert_eq!(
+        page.locator("#name")
+            .input_value(None)
+            .await
+            .expect("Failed to read value"),
+        "Ada"
+    );
+
+    let button = page
+        .query_selector("button")
+        .await
+        .expect("Failed to query selector")
+        .expect("Should find button");
+    button.click(None).await.expect("Failed to click");
+    assert_eq!(
+        page.locator("button")
+            .text_content()
+            .await
+            .expect("Failed to read text"),
+        Some("Clicked".to_string())
+    );
+
+    let frame = button
+        .owner_frame()
+        .await
+        .expect("Failed to get owner frame")
+        .expect("Attached element should have an owner frame");
+    assert_eq!(
+        frame.url(),
+        page.main_frame()
+            .await
+            .expect("Failed to get main frame")
+            .url()
+    );
+
+    button.dispose().await.expect("Failed to dispose");
+    assert!(button.click(None).await.is_err());
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,95 @@
+// Integration tests for Locator.element_handles()
+//
+// Tests cover:
+// - locator.element_handles() returns a guard for every match
+// - Guards dereference to ElementHandle and remain usable
+// - Explicit dispose() succeeds
+// - Dropping guards disposes handles in the background without breaking the page
+
+mod test_server;
+
+use playwright_rs::protocol::Playwright;
+use test_server::TestServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_locator_element_handles_returns_all_matches() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.goto(&format!("{}/locators.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    let handles = page
+        .locator("p")
+        .element_handles()
+        .await
+        .expect("Failed to get element handles");
+
+    // locators.html has 4 paragraphs
+    assert_eq!(handles.len(), 4, "Should return a handle per paragraph");
+
+    // Guards deref to ElementHandle
+    let bytes = handles[0]
+        .screenshot(None)
+        .await
+        .expect("Failed to screenshot via guard");
+    assert!(!bytes.is_empty());
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_element_handle_guard_dispose() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.goto(&format!("{}/locators.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    let mut handles = page
+        .locator("p")
+        .element_handles()
+        .await
+        .expect("Failed to get element handles");
+
+    // Explicit disposal reports errors to the caller
+    let first = handles.remove(0);
+    first
+        .dispose()
+        .await
+        .expect("Explicit dispose should succeed");
+
+    // Remaining guards are disposed on drop
+    drop(handles);
+
+    // Give the background disposals a moment to run, then verify the page still works
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    let count = page.locator("p").count().await.expect("Failed to count");
+    assert_eq!(count, 4, "Disposing handles must not affect the DOM");
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
@@ -0,0 +1,142 @@
+// Integration tests for Page::goto_expecting_request()
+//
+// Tests cover:
+// - Capturing a sub-resource request and its response during navigation
+// - Blocking the matched request
+// - Timing out when no request matches
+// - request.response() and removal of the temporary route
+
+mod test_server;
+
+use playwright_rs::protocol::{ExpectRequest, FulfillOptions, Playwright};
+use std::time::Duration;
+use test_server::TestServer;
+
+mod common;
+
+const LANDING_HTML: &str = r#"<!DOCTYPE html>
+<html><body>
+<h1>Landing</h1>
+<script src="/tracker.js"></script>
+</body></html>"#;
+
+#[tokio::test]
+async fn test_goto_expecting_request_captures_sub_resource() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.route("**/landing.html", |route| async move {
+        route
+            .fulfill(Some(
+                FulfillOptions::builder()
+                    .body_string(LANDING_HTML)
+                    .content_type("text/html")
+                    .build(),
+            ))
+            .await
+    })
+    .await
+    .expect("Failed to register route");
+
+    let result = page
+        .goto_expecting_request(
+            &format!("{}/landing.html", server.url()),
+            ExpectRequest::new("**/tracker.js"),
+        )
+        .await
+        .expect("Should capture tracker request");
+
+    assert!(result.request.url().ends_with("/tracker.js"));
+    assert!(!result.is_partial());
+    let response = result
+        .response
+        .expect("Continued request should get a response");
+    assert_eq!(response.status(), 404);
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_goto_expecting_request_abort() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.route("**/landing.html", |route| async move {
+        route
+            .fulfill(Some(
+                FulfillOptions::builder()
+                    .body_string(LANDING_HTML)
+                    .content_type("text/html")
+                    .build(),
+            ))
+            .await
+    })
+    .await
+    .expect("Failed to register route");
+
+    let result = page
+        .goto_expecting_request(
+            &format!("{}/landing.html", server.url()),
+            ExpectRequest::new("**/tracker.js").abort_request(true),
+        )
+        .await
+        .expect("Should capture tracker request");
+
+    assert!(result.request.url().ends_with("/tracker.js"));
+    assert!(result.response.is_none(), "Blocked request has no response");
+    assert!(result.navigation.is_some(), "Page itself still loads");
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_goto_expecting_request_timeout() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    let err = page
+        .goto_expecting_request(
+            &format!("{}/locators.html", server.url()),
+            ExpectRequest::new("**/never.js").timeout(Duration::from_millis(500)),
+        )
+        .await
+        .expect_err("No request should match");
+    assert!(matches!(err, playwright_rs::Error::Timeout(_)));
+
+    // The temporary route is removed; normal navigation still works
+    page.goto(&format!("{}/locators.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
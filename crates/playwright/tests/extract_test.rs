@@ -0,0 +1,115 @@
+// Integration tests for schema-driven extraction (page.extract)
+//
+// Tests cover:
+// - Text, attribute, number, count, and `.all()` fields
+// - Nested lists with `:scope`
+// - Missing elements map to None
+// - Type mismatches return InvalidArgument
+
+mod test_server;
+
+use playwright_rs::protocol::{ExtractSchema, Field, FulfillOptions, Playwright};
+use serde::Deserialize;
+use test_server::TestServer;
+
+mod common;
+
+const PRODUCT_HTML: &str = r#"<!DOCTYPE html>
+<html><body>
+  <h1> Espresso Machine </h1>
+  <span class="price">$1,299.00</span>
+  <img class="hero" src="/img/espresso.png">
+  <ul><li class="tag">kitchen</li><li class="tag">coffee</li></ul>
+  <div class="review" data-stars="5"><span class="author">Ana</span></div>
+  <div class="review" data-stars="3"><span class="author">Ben</span></div>
+</body></html>"#;
+
+#[derive(Debug, Deserialize)]
+struct Product {
+    title: String,
+    price: Option<f64>,
+    image: Option<String>,
+    discount: Option<String>,
+    tags: Vec<String>,
+    review_count: usize,
+    reviews: Vec<Review>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Review {
+    author: String,
+    stars: String,
+}
+
+#[tokio::test]
+async fn test_page_extract() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.route("**/product.html", |route| async move {
+        route
+            .fulfill(Some(
+                FulfillOptions::builder()
+                    .body_string(PRODUCT_HTML)
+                    .content_type("text/html")
+                    .build(),
+            ))
+            .await
+    })
+    .await
+    .expect("Failed to register route");
+    page.goto(&format!("{}/product.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    let schema = ExtractSchema::new()
+        .field("title", Field::text("h1"))
+        .field("price", Field::number(".price"))
+        .field("image", Field::attr("img.hero", "src"))
+        .field("discount", Field::text(".discount"))
+        .field("tags", Field::text(".tag").all())
+        .field("review_count", Field::count(".review"))
+        .field(
+            "reviews",
+            Field::list(
+                ".review",
+                ExtractSchema::new()
+                    .field("author", Field::text(".author"))
+                    .field("stars", Field::attr(":scope", "data-stars")),
+            ),
+        );
+
+    let product: Product = page.extract(&schema).await.expect("Failed to extract");
+    assert_eq!(product.title, "Espresso Machine");
+    assert_eq!(product.price, Some(1299.0));
+    assert_eq!(product.image.as_deref(), Some("/img/espresso.png"));
+    assert_eq!(product.discount, None);
+    assert_eq!(product.tags, vec!["kitchen", "coffee"]);
+    assert_eq!(product.review_count, 2);
+    assert_eq!(product.reviews[1].author, "Ben");
+    assert_eq!(product.reviews[1].stars, "3");
+
+    // Required field missing from the page
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Strict {
+        discount: String,
+    }
+    let err = page
+        .extract::<Strict>(&ExtractSchema::new().field("discount", Field::text(".discount")))
+        .await
+        .expect_err("Missing required field should fail");
+    assert!(matches!(err, playwright_rs::Error::InvalidArgument(_)));
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
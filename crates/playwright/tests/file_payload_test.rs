@@ -39,9 +39,9 @@ async fn test_file_payload_basic() {
         .buffer(b"Test file content".to_vec())
         .build();
 
-    let input = page.locator("#upload").await;
+    let input = page.locator("#upload");
     input
-        .set_input_files_payload(file_payload, None)
+        .set_input_files(file_payload, None)
         .await
         .expect("Failed to upload file");
 
@@ -88,9 +88,9 @@ async fn test_file_payload_multiple() {
         .buffer(b"{\"key\": \"value\"}".to_vec())
         .build();
 
-    let input = page.locator("#upload").await;
+    let input = page.locator("#upload");
     input
-        .set_input_files_payload_multiple(&[file1, file2], None)
+        .set_input_files(vec![file1, file2], None)
         .await
         .expect("Failed to upload files");
 
@@ -130,9 +130,9 @@ async fn test_file_payload_custom_mime_type() {
         .buffer(b"col1,col2\nval1,val2".to_vec())
         .build();
 
-    let input = page.locator("#upload").await;
+    let input = page.locator("#upload");
     input
-        .set_input_files_payload(file_payload, None)
+        .set_input_files(file_payload, None)
         .await
         .expect("Failed to upload file");
 
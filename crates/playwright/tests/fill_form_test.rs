@@ -0,0 +1,98 @@
+// Integration tests for Page.fill_form()
+//
+// Tests cover:
+// - Filling text inputs, selects and checkboxes from a struct
+// - Selector, label and default name mappings
+// - Validation errors name the offending field
+
+use playwright_rs::protocol::{FormMapping, Playwright};
+use playwright_rs::Error;
+use serde::Serialize;
+
+mod common;
+
+#[derive(Serialize)]
+struct Signup {
+    email: String,
+    full_name: String,
+    age: u32,
+    plan: String,
+    terms: bool,
+}
+
+const FORM: &str = "data:text/html,<form>\
+    <input id='email' type='email'>\
+    <label>Full name <input id='full'></label>\
+    <input name='age' type='number'>\
+    <select name='plan'><option value='free'>Free</option><option value='pro'>Pro</option></select>\
+    <input name='terms' type='checkbox'>\
+    <input id='short' maxlength='3'>\
+    </form>";
+
+#[tokio::test]
+async fn test_fill_form() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(FORM, None).await.expect("Failed to navigate");
+
+    let signup = Signup {
+        email: "ada@example.com".to_string(),
+        full_name: "Ada Lovelace".to_string(),
+        age: 36,
+        plan: "pro".to_string(),
+        terms: true,
+    };
+    let mapping = FormMapping::new()
+        .selector("email", "#email")
+        .label("full_name", "Full name");
+    page.fill_form(&signup, Some(&mapping))
+        .await
+        .expect("Failed to fill form");
+
+    let value = |selector: &'static str| {
+        let page = page.clone();
+        async move {
+            page.locator(selector)
+                .input_value(None)
+                .await
+                .expect("Failed to read value")
+        }
+    };
+    assert_eq!(value("#email").await, "ada@example.com");
+    assert_eq!(value("#full").await, "Ada Lovelace");
+    assert_eq!(value("[name=age]").await, "36");
+    assert_eq!(value("[name=plan]").await, "pro");
+    assert!(page
+        .locator("[name=terms]")
+        .is_checked()
+        .await
+        .expect("Failed to read checkbox"));
+
+    // Values that don't stick are reported with the field name
+    #[derive(Serialize)]
+    struct Short {
+        code: String,
+    }
+    let err = page
+        .fill_form(
+            &Short {
+                code: "toolong".to_string(),
+            },
+            Some(&FormMapping::new().selector("code", "#short")),
+        )
+        .await;
+    match err {
+        Err(Error::InvalidArgument(message)) => assert!(message.contains("'code'"), "{}", message),
+        other => panic!("Expected InvalidArgument, got {:?}", other),
+    }
+
+    browser.close().await.expect("Failed to close browser");
+}
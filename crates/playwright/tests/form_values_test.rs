@@ -0,0 +1,110 @@
+// Integration tests for form state assertions
+//
+// Tests cover:
+// - Locator::form_values() for text, number, select, multi-select, radio and checkbox controls
+// - expect().to_have_form_values() passing after the form changes
+// - Failure message listing every mismatched field
+// - not().to_have_form_values()
+
+use playwright_rs::protocol::Playwright;
+use playwright_rs::{expect, Error};
+use serde_json::json;
+use std::time::Duration;
+
+mod common;
+
+const FORM: &str = "data:text/html,<form id='signup'>\
+    <input name='email' value='ada@example.com'>\
+    <input name='age' type='number' value='36'>\
+    <select name='plan'><option value='free'>Free</option><option value='pro' selected>Pro</option></select>\
+    <select name='toppings' multiple><option selected>ham</option><option>olives</option><option selected>corn</option></select>\
+    <input type='radio' name='size' value='s'><input type='radio' name='size' value='m' checked>\
+    <input type='radio' name='color' value='red'>\
+    <input type='checkbox' name='terms'>\
+    <input type='checkbox' name='days' value='mon' checked><input type='checkbox' name='days' value='tue'>\
+    <input type='file' name='avatar'>\
+    <button name='go'>Go</button>\
+    </form>";
+
+#[tokio::test]
+async fn test_form_values() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(FORM, None).await.expect("Failed to navigate");
+
+    let form = page.locator("#signup");
+    let values = form.form_values().await.expect("Failed to read form");
+    assert_eq!(
+        serde_json::Value::Object(values),
+        json!({
+            "email": "ada@example.com",
+            "age": "36",
+            "plan": "pro",
+            "toppings": ["ham", "corn"],
+            "size": "m",
+            "color": null,
+            "terms": false,
+            "days": ["mon"],
+        })
+    );
+
+    expect(&form)
+        .to_have_form_values(&json!({
+            "email": "ada@example.com",
+            "age": 36,
+            "toppings": ["corn", "ham"],
+            "size": "m",
+        }))
+        .await
+        .expect("Form should have the initial values");
+
+    // Polls until the form changes
+    page.evaluate::<(), ()>(
+        "setTimeout(() => { document.querySelector('[name=terms]').checked = true }, 200)",
+        None,
+    )
+    .await
+    .expect("Failed to schedule change");
+    expect(&form)
+        .to_have_form_values(&json!({ "terms": true }))
+        .await
+        .expect("Checkbox should become checked");
+
+    // Every mismatch is listed
+    let result = expect(&form)
+        .with_timeout(Duration::from_millis(300))
+        .to_have_form_values(&json!({ "email": "grace@example.com", "plan": "free", "size": "m" }))
+        .await;
+    match result {
+        Err(Error::AssertionTimeout(message)) => {
+            assert!(
+                message.contains("email: expected \"grace@example.com\", got \"ada@example.com\""),
+                "{}",
+                message
+            );
+            assert!(
+                message.contains("plan: expected \"free\", got \"pro\""),
+                "{}",
+                message
+            );
+            assert!(!message.contains("size"), "{}", message);
+        }
+        other => panic!("Expected AssertionTimeout, got {:?}", other),
+    }
+
+    expect(&form)
+        .not()
+        .to_have_form_values(&json!({ "plan": "free" }))
+        .await
+        .expect("Plan should not be free");
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,125 @@
+// Integration tests for FrameLocator
+//
+// Tests cover:
+// - page.frame_locator(): locator(), get_by_*() inside an iframe
+// - Actions inside an iframe (fill, click) and reading the result
+// - Nested iframes via frame_locator().frame_locator()
+// - locator.frame_locator() and first()/nth() over several iframes
+// - locator.content_frame() and frame_locator.owner()
+
+use playwright_rs::protocol::{GetByRoleOptions, Playwright};
+
+mod common;
+
+const PAGE: &str = "data:text/html,\
+    <h1>Checkout</h1>\
+    <iframe id='payments' srcdoc=\"\
+        <label>Card number <input></label>\
+        <button onclick='document.body.dataset.paid=1;this.textContent=`Paid`'>Pay</button>\
+        <iframe id='inner' srcdoc='<p>Secure badge</p>'></iframe>\"></iframe>\
+    <section id='ads'>\
+        <iframe srcdoc='<p>First ad</p>'></iframe>\
+        <iframe srcdoc='<p>Second ad</p>'></iframe>\
+    </section>";
+
+#[tokio::test]
+async fn test_frame_locator() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(PAGE, None).await.expect("Failed to navigate");
+
+    // Elements inside the iframe are unreachable from the page...
+    assert_eq!(
+        page.get_by_role("button", None)
+            .count()
+            .await
+            .expect("Failed to count"),
+        0
+    );
+
+    // ...but reachable through a frame locator
+    let payments = page.frame_locator("#payments");
+    let card = payments.get_by_label("Card number", false);
+    card.fill("4242 4242 4242 4242", None)
+        .await
+        .expect("Failed to fill card number");
+    assert_eq!(
+        card.input_value(None)
+            .await
+            .expect("Failed to read card number"),
+        "4242 4242 4242 4242"
+    );
+    let pay = payments.get_by_role(
+        "button",
+        Some(GetByRoleOptions::builder().name("Pay").build()),
+    );
+    pay.click(None).await.expect("Failed to click Pay");
+    assert_eq!(
+        payments
+            .locator("body")
+            .get_attribute("data-paid")
+            .await
+            .expect("Failed to read attribute")
+            .as_deref(),
+        Some("1")
+    );
+
+    // Nested iframes
+    let badge = payments
+        .frame_locator("#inner")
+        .get_by_text("Secure", false);
+    assert_eq!(
+        badge.inner_text().await.expect("Failed to read badge"),
+        "Secure badge"
+    );
+
+    // Scoped to a locator, picking one of several iframes
+    let ads = page.locator("#ads").frame_locator("iframe");
+    assert_eq!(
+        ads.first()
+            .locator("p")
+            .inner_text()
+            .await
+            .expect("Failed to read first ad"),
+        "First ad"
+    );
+    assert_eq!(
+        ads.nth(1)
+            .locator("p")
+            .inner_text()
+            .await
+            .expect("Failed to read second ad"),
+        "Second ad"
+    );
+
+    // Moving between the iframe element and its content
+    let payments_frame = page.locator("#payments").content_frame();
+    assert_eq!(
+        payments_frame
+            .get_by_role("button", None)
+            .inner_text()
+            .await
+            .expect("Failed to read button through content frame"),
+        "Paid"
+    );
+    let owner = payments.owner();
+    assert_eq!(
+        owner
+            .get_attribute("id")
+            .await
+            .expect("Failed to read iframe id")
+            .as_deref(),
+        Some("payments")
+    );
+    assert_eq!(owner.count().await.expect("Failed to count owners"), 1);
+
+    browser.close().await.expect("Failed to close browser");
+}
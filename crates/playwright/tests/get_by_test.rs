@@ -0,0 +1,443 @@
+// Integration tests for user-facing locators (get_by_*)
+//
+// Tests cover:
+// - get_by_role(): role, name (substring/exact/regex), states, level, hidden
+// - get_by_text(): substring, exact, regex, scoped to a locator
+// - get_by_label(): <label for>, wrapping <label>, aria-label, aria-labelledby
+// - get_by_placeholder(): substring, exact, regex
+// - get_by_alt_text(): <img> and <area>, exact, regex
+// - get_by_title(): substring, exact, regex, scoped to a locator
+// - get_by_test_id(): default data-testid, custom attribute via selectors()
+
+#![cfg(feature = "regex")]
+
+use playwright_rs::protocol::{GetByRoleOptions, Page, Playwright};
+
+mod common;
+
+const PAGE: &str = "data:text/html,\
+    <h1>Account</h1><h2>Settings</h2>\
+    <button>Sign in</button><button>Sign in with SSO</button>\
+    <button aria-pressed='true'>Bold</button>\
+    <input type='checkbox' aria-label='Remember me' checked>\
+    <input type='checkbox' aria-label='Newsletter'>\
+    <button disabled>Delete</button>\
+    <button style='display:none'>Hidden action</button>";
+
+async fn count_role(page: &Page, role: &str, options: Option<GetByRoleOptions>) -> usize {
+    page.get_by_role(role, options)
+        .count()
+        .await
+        .expect("Failed to count")
+}
+
+#[tokio::test]
+async fn test_get_by_role() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(PAGE, None).await.expect("Failed to navigate");
+
+    let name = |name: &str| GetByRoleOptions::builder().name(name);
+
+    assert_eq!(count_role(&page, "heading", None).await, 2);
+    assert_eq!(
+        count_role(
+            &page,
+            "heading",
+            Some(GetByRoleOptions::builder().level(2).build())
+        )
+        .await,
+        1
+    );
+
+    // Substring, case-insensitive by default; exact narrows it down
+    assert_eq!(
+        count_role(&page, "button", Some(name("sign in").build())).await,
+        2
+    );
+    assert_eq!(
+        count_role(&page, "button", Some(name("Sign in").exact(true).build())).await,
+        1
+    );
+    assert_eq!(
+        count_role(
+            &page,
+            "button",
+            Some(
+                GetByRoleOptions::builder()
+                    .name(regex::Regex::new(r"SSO$").unwrap())
+                    .build()
+            )
+        )
+        .await,
+        1
+    );
+
+    // States
+    assert_eq!(
+        count_role(
+            &page,
+            "checkbox",
+            Some(GetByRoleOptions::builder().checked(true).build())
+        )
+        .await,
+        1
+    );
+    assert_eq!(
+        count_role(
+            &page,
+            "button",
+            Some(GetByRoleOptions::builder().pressed(true).build())
+        )
+        .await,
+        1
+    );
+    assert_eq!(
+        count_role(
+            &page,
+            "button",
+            Some(GetByRoleOptions::builder().disabled(true).build())
+        )
+        .await,
+        1
+    );
+
+    // Hidden elements are excluded unless requested
+    assert_eq!(
+        count_role(&page, "button", Some(name("Hidden").build())).await,
+        0
+    );
+    assert_eq!(
+        count_role(
+            &page,
+            "button",
+            Some(name("Hidden").include_hidden(true).build())
+        )
+        .await,
+        1
+    );
+
+    // Scoped to a locator
+    let body = page.locator("body");
+    body.get_by_role("checkbox", Some(name("Newsletter").build()))
+        .check(None)
+        .await
+        .expect("Failed to check newsletter");
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[tokio::test]
+async fn test_get_by_text() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html,<div id='nav'><a>Sign in</a><a>Sign  in with SSO</a></div>\
+         <p>Welcome, <b>Ada</b></p>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let count = |locator: playwright_rs::protocol::Locator| async move {
+        locator.count().await.expect("Failed to count")
+    };
+
+    // Substring match is case-insensitive and normalizes whitespace
+    assert_eq!(count(page.get_by_text("sign in", false)).await, 2);
+    assert_eq!(count(page.get_by_text("sign in with sso", false)).await, 1);
+    assert_eq!(count(page.get_by_text("Sign in", true)).await, 1);
+    assert_eq!(count(page.get_by_text("sign in", true)).await, 0);
+
+    // Regex
+    assert_eq!(
+        count(page.get_by_text(regex::Regex::new(r"SSO$").unwrap(), false)).await,
+        1
+    );
+
+    // The smallest element containing the text matches, not <body>
+    let welcome = page
+        .get_by_text("Welcome", false)
+        .inner_html()
+        .await
+        .expect("Failed to read paragraph");
+    assert_eq!(welcome, "Welcome, <b>Ada</b>");
+
+    // Scoped to a locator
+    let nav = page.locator("#nav");
+    assert_eq!(count(nav.get_by_text("Welcome", false)).await, 0);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[tokio::test]
+async fn test_get_by_label() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html,<form id='signup'>\
+         <label for='email'>Email address</label><input id='email'>\
+         <label>Password <input id='password' type='password'></label>\
+         <input id='search' aria-label='Search'>\
+         <span id='zip-label'>Postal code</span><input id='zip' aria-labelledby='zip-label'>\
+         </form>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let id_of = |locator: playwright_rs::protocol::Locator| async move {
+        locator
+            .get_attribute("id")
+            .await
+            .expect("Failed to read id")
+            .expect("Control should have an id")
+    };
+
+    assert_eq!(id_of(page.get_by_label("email", false)).await, "email");
+    assert_eq!(id_of(page.get_by_label("Password", true)).await, "password");
+    assert_eq!(id_of(page.get_by_label("Search", true)).await, "search");
+    assert_eq!(
+        id_of(page.get_by_label(regex::Regex::new(r"^Postal").unwrap(), false)).await,
+        "zip"
+    );
+    assert_eq!(
+        page.get_by_label("Email", true)
+            .count()
+            .await
+            .expect("Failed to count"),
+        0,
+        "exact requires the full label text"
+    );
+
+    let form = page.locator("#signup");
+    form.get_by_label("Email address", true)
+        .fill("ada@example.com", None)
+        .await
+        .expect("Failed to fill");
+    assert_eq!(
+        page.locator("#email")
+            .input_value(None)
+            .await
+            .expect("Failed to read value"),
+        "ada@example.com"
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[tokio::test]
+async fn test_get_by_placeholder() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html,<input id='email' placeholder='name@example.com'>\
+         <input id='search' placeholder='Search products'><textarea id='notes' placeholder='Notes'></textarea>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let count = |locator: playwright_rs::protocol::Locator| async move {
+        locator.count().await.expect("Failed to count")
+    };
+
+    assert_eq!(
+        count(page.get_by_placeholder("EXAMPLE.COM", false)).await,
+        1
+    );
+    assert_eq!(count(page.get_by_placeholder("example.com", true)).await, 0);
+    assert_eq!(count(page.get_by_placeholder("Notes", true)).await, 1);
+    assert_eq!(
+        count(page.get_by_placeholder(regex::Regex::new(r"^(Search|Notes)").unwrap(), false)).await,
+        2
+    );
+
+    page.get_by_placeholder("Search", false)
+        .fill("laptop", None)
+        .await
+        .expect("Failed to fill");
+    assert_eq!(
+        page.locator("#search")
+            .input_value(None)
+            .await
+            .expect("Failed to read value"),
+        "laptop"
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[tokio::test]
+async fn test_get_by_alt_text() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html,<img id='logo' alt='Company logo' src='data:,'>\
+         <img id='hero' alt='Hero banner' src='data:,'>\
+         <map name='m'><area id='area' alt='Company site' href='/about' shape='rect' coords='0,0,1,1'></map>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let count = |locator: playwright_rs::protocol::Locator| async move {
+        locator.count().await.expect("Failed to count")
+    };
+
+    assert_eq!(count(page.get_by_alt_text("company", false)).await, 2);
+    assert_eq!(count(page.get_by_alt_text("Company logo", true)).await, 1);
+    assert_eq!(count(page.get_by_alt_text("company logo", true)).await, 0);
+    assert_eq!(
+        count(page.get_by_alt_text(regex::Regex::new(r"banner$").unwrap(), false)).await,
+        1
+    );
+    assert_eq!(
+        page.get_by_alt_text("Company site", true)
+            .get_attribute("id")
+            .await
+            .expect("Failed to read id")
+            .as_deref(),
+        Some("area")
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[tokio::test]
+async fn test_get_by_title() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html,<span id='issues' title='Issues count'>25 issues</span>\
+         <div id='toolbar'><button title='Bold text'>B</button><button title='Italic text'>I</button></div>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let count = |locator: playwright_rs::protocol::Locator| async move {
+        locator.count().await.expect("Failed to count")
+    };
+
+    assert_eq!(
+        page.get_by_title("Issues count", true)
+            .text_content()
+            .await
+            .expect("Failed to read text")
+            .as_deref(),
+        Some("25 issues")
+    );
+    assert_eq!(count(page.get_by_title("issues", false)).await, 1);
+    assert_eq!(count(page.get_by_title("issues", true)).await, 0);
+    assert_eq!(
+        count(page.get_by_title(regex::Regex::new(r"(?i)^(bold|italic)").unwrap(), false)).await,
+        2
+    );
+
+    let toolbar = page.locator("#toolbar");
+    assert_eq!(count(toolbar.get_by_title("text", false)).await, 2);
+    assert_eq!(count(toolbar.get_by_title("Issues", false)).await, 0);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[tokio::test]
+async fn test_get_by_test_id() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html,<div id='cart'><button data-testid='checkout' data-qa='pay'>Checkout</button></div>\
+         <button data-testid='checkout-later'>Later</button>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let count = |locator: playwright_rs::protocol::Locator| async move {
+        locator.count().await.expect("Failed to count")
+    };
+
+    // Test ids match exactly
+    assert_eq!(count(page.get_by_test_id("checkout")).await, 1);
+    assert_eq!(count(page.get_by_test_id("check")).await, 0);
+    assert_eq!(
+        count(page.get_by_test_id(regex::Regex::new("^checkout").unwrap())).await,
+        2
+    );
+    let cart = page.locator("#cart");
+    assert_eq!(count(cart.get_by_test_id("checkout-later")).await, 0);
+
+    let selectors = playwright.selectors();
+    selectors.set_test_id_attribute("data-qa");
+    assert_eq!(selectors.test_id_attribute(), "data-qa");
+    let pay = page.get_by_test_id("pay");
+    selectors.set_test_id_attribute("data-testid");
+
+    assert_eq!(
+        pay.text_content()
+            .await
+            .expect("Failed to read text")
+            .as_deref(),
+        Some("Checkout")
+    );
+    assert_eq!(count(page.get_by_test_id("pay")).await, 0);
+
+    browser.close().await.expect("Failed to close browser");
+}
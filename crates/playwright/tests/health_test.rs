@@ -0,0 +1,54 @@
+// Integration tests for Playwright::health() and Playwright::drain()
+//
+// Tests cover:
+// - A freshly launched instance is live, and ready once a browser is open
+// - drain() waits for in-flight pages, closes browsers and stops the driver
+
+use playwright_rs::protocol::Playwright;
+use std::time::Duration;
+
+mod common;
+
+const STALL_AFTER: Duration = Duration::from_secs(30);
+
+#[tokio::test]
+async fn test_health_and_drain() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+
+    let health = playwright.health().await;
+    assert_eq!(health.driver_running, Some(true));
+    assert!(health.is_live(STALL_AFTER));
+    assert!(!health.is_ready(1, STALL_AFTER), "No browser yet");
+
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    let health = playwright.health().await;
+    assert!(health.is_ready(1, STALL_AFTER));
+    assert_eq!(health.open_pages, 1);
+
+    // In-flight work finishes shortly after the drain starts
+    let in_flight = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        page.close().await.expect("Failed to close page");
+    });
+
+    let report = playwright
+        .drain(Duration::from_secs(10))
+        .await
+        .expect("Failed to drain");
+    in_flight.await.unwrap();
+
+    assert!(playwright.is_draining());
+    assert_eq!(report.abandoned_pages, 0);
+    assert_eq!(report.browsers_closed, 1);
+    assert!(report.waited >= Duration::from_millis(200));
+    assert!(!playwright.health().await.is_ready(0, STALL_AFTER));
+}
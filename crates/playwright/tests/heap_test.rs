@@ -0,0 +1,85 @@
+// Integration tests for heap metrics and snapshots (Chromium only)
+//
+// Tests cover:
+// - page.heap_usage() returns non-zero sizes
+// - page.take_heap_snapshot() returns snapshot JSON and writes it to disk
+// - page.monitor_heap() collects a time series
+
+mod test_server;
+
+use playwright_rs::protocol::{HeapMonitor, Playwright};
+use std::time::Duration;
+use test_server::TestServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_heap_usage_and_snapshot() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(&format!("{}/locators.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    page.collect_garbage()
+        .await
+        .expect("Failed to collect garbage");
+    let usage = page.heap_usage().await.expect("Failed to get heap usage");
+    assert!(usage.used_size > 0);
+    assert!(usage.total_size >= usage.used_size);
+
+    let path = std::env::temp_dir().join(format!(
+        "playwright_rs_heap_{}.heapsnapshot",
+        std::process::id()
+    ));
+    let snapshot = page
+        .take_heap_snapshot(Some(&path))
+        .await
+        .expect("Failed to take heap snapshot");
+    let parsed: serde_json::Value =
+        serde_json::from_str(&snapshot).expect("Snapshot should be valid JSON");
+    assert!(parsed.get("snapshot").is_some());
+    assert!(path.exists(), "Snapshot should be written to disk");
+    std::fs::remove_file(&path).ok();
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_monitor_heap() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    let monitor = page
+        .monitor_heap(Duration::from_millis(50))
+        .await
+        .expect("Failed to start heap monitor");
+    page.evaluate_expression("window.leak = new Array(100000).fill('x')")
+        .await
+        .expect("Failed to allocate");
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let samples = monitor.stop().await.expect("Failed to stop heap monitor");
+    assert!(samples.len() >= 2, "Should collect several samples");
+    assert!(HeapMonitor::peak(&samples) > 0);
+
+    browser.close().await.expect("Failed to close browser");
+}
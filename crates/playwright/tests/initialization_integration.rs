@@ -53,7 +53,7 @@ async fn test_initialize_playwright_with_real_server() {
     let (transport, message_rx) = PipeTransport::new(stdin, stdout);
 
     // 3. Create connection
-    let connection: Arc<Connection<_, _>> = Arc::new(Connection::new(transport, message_rx));
+    let connection: Arc<Connection> = Arc::new(Connection::new(transport, message_rx));
 
     // 4. Spawn connection message loop
     let conn_for_loop = Arc::clone(&connection);
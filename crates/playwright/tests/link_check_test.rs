@@ -0,0 +1,97 @@
+// Integration tests for APIRequestContext and Page.check_links()
+//
+// Tests cover:
+// - context.request() GET/HEAD requests and response bodies
+// - check_links() reports broken links and de-duplicates URLs
+
+#![cfg(feature = "api-request")]
+
+mod test_server;
+
+use playwright_rs::protocol::{LinkStatus, Playwright};
+use test_server::TestServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_api_request_context() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    let request = context
+        .request()
+        .await
+        .expect("Failed to get request context");
+
+    let response = request
+        .get(&format!("{}/locators.html", server.url()), None)
+        .await
+        .expect("Failed to GET");
+    assert!(response.ok());
+    let body = response.text().await.expect("Failed to read body");
+    assert!(body.contains("First paragraph"));
+    response
+        .dispose()
+        .await
+        .expect("Failed to dispose response");
+
+    let response = request
+        .head(&format!("{}/does-not-exist", server.url()), None)
+        .await
+        .expect("Failed to HEAD");
+    assert_eq!(response.status(), 404);
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_check_links() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    let base = server.url();
+    page.goto(
+        &format!(
+            "data:text/html,<a href='{base}/locators.html'>ok</a>\
+             <a href='{base}/locators.html#top'>dup</a>\
+             <a href='{base}/missing'>missing</a>\
+             <a href='mailto:someone@example.com'>mail</a>"
+        ),
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let report = page.check_links(None).await.expect("Failed to check links");
+    assert_eq!(report.results.len(), 2, "Duplicates and mailto are skipped");
+    assert_eq!(report.results[0].status, LinkStatus::Status(200));
+
+    let broken: Vec<_> = report.broken().collect();
+    assert_eq!(broken.len(), 1);
+    assert_eq!(broken[0].url, format!("{}/missing", base));
+    assert_eq!(broken[0].status, LinkStatus::Status(404));
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
@@ -0,0 +1,79 @@
+// Integration tests for Locator.deep()
+//
+// Tests cover:
+// - Elements in the main document resolve without entering frames
+// - Elements in nested iframes are found and remain actionable
+// - Open shadow roots are pierced
+// - Missing elements time out
+
+use playwright_rs::protocol::Playwright;
+use playwright_rs::Error;
+use std::time::Duration;
+
+mod common;
+
+#[tokio::test]
+async fn test_locator_deep() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.goto(
+        "data:text/html,<p id='top'>Top</p>\
+         <iframe srcdoc=\"<p>Outer</p><iframe srcdoc='<button id=deep onclick=&quot;this.textContent=`Clicked`&quot;>Deep</button>'></iframe>\"></iframe>\
+         <div id='host'></div>\
+         <script>document.getElementById('host').attachShadow({mode: 'open'}).innerHTML = '<span id=shadow>Shadow</span>';</script>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    // Main document: selector is unchanged
+    let top = page
+        .locator("#top")
+        .deep(None)
+        .await
+        .expect("Failed to resolve #top");
+    assert_eq!(top.selector(), "#top");
+
+    // Shadow DOM is pierced by the selector engine itself
+    let shadow = page
+        .locator("#shadow")
+        .deep(None)
+        .await
+        .expect("Failed to resolve shadow element");
+    assert_eq!(
+        shadow.text_content().await.expect("Failed to read text"),
+        Some("Shadow".to_string())
+    );
+
+    // Nested iframes
+    let deep = page
+        .locator("#deep")
+        .deep(Some(Duration::from_secs(5)))
+        .await
+        .expect("Failed to resolve nested iframe element");
+    assert!(deep.selector().contains("enter-frame"));
+    deep.click(None).await.expect("Failed to click");
+    assert_eq!(
+        deep.inner_text().await.expect("Failed to read text"),
+        "Clicked"
+    );
+
+    // Missing
+    let err = page
+        .locator("#missing")
+        .deep(Some(Duration::from_millis(300)))
+        .await
+        .expect_err("Missing element should time out");
+    assert!(matches!(err, Error::Timeout(_)));
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,71 @@
+// Integration tests for Locator.describe()
+//
+// Tests cover:
+// - Match counts for each step of a selector chain
+// - Element previews (tag, id, classes, text, visibility)
+// - Locators that match nothing report the failing step
+
+mod test_server;
+
+use playwright_rs::protocol::Playwright;
+use test_server::TestServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_locator_describe() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.goto(&format!("{}/locators.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    // Matching locator: previews every paragraph
+    let description = page
+        .locator("p")
+        .describe()
+        .await
+        .expect("Failed to describe locator");
+    assert_eq!(description.count, 4);
+    assert_eq!(description.elements.len(), 4);
+    assert_eq!(description.elements[0].tag, "p");
+    assert_eq!(description.elements[0].id.as_deref(), Some("p1"));
+    assert_eq!(description.elements[0].text, "First paragraph");
+    assert!(description.elements[0].visible);
+
+    // Hidden elements are flagged
+    let hidden = page
+        .locator("#hidden")
+        .describe()
+        .await
+        .expect("Failed to describe hidden locator");
+    assert!(!hidden.elements[0].visible);
+
+    // Chain that stops matching partway
+    let description = page
+        .locator(".container >> button >> nth=0")
+        .describe()
+        .await
+        .expect("Failed to describe chained locator");
+    assert_eq!(description.count, 0);
+    let counts: Vec<usize> = description.steps.iter().map(|s| s.count).collect();
+    assert_eq!(counts, vec![1, 0, 0]);
+    assert_eq!(
+        description.first_failing_step().map(|s| s.part.as_str()),
+        Some("button")
+    );
+    assert!(description.to_string().contains("no matches from here"));
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
@@ -0,0 +1,116 @@
+// Integration tests for Locator::evaluate and Locator::evaluate_all
+//
+// Tests cover:
+// - Evaluating with no argument into a primitive
+// - A serializable argument and a typed, deserialized return value
+// - Strict mode: fails with the candidates when the locator matches several elements
+// - evaluate_all over every match, with an argument, and with no matches
+
+use playwright_rs::protocol::Playwright;
+use serde::{Deserialize, Serialize};
+
+mod common;
+
+const PAGE: &str = "data:text/html,\
+    <h1 data-id='7'>Title</h1>\
+    <input id='name' value='Ada'>\
+    <li>One</li><li>Two</li>";
+
+#[derive(Serialize)]
+struct Suffix {
+    text: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct Summary {
+    tag_name: String,
+    id: u32,
+    label: String,
+}
+
+#[tokio::test]
+async fn test_locator_evaluate() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(PAGE, None).await.expect("Failed to navigate");
+
+    // No argument
+    let value: String = page
+        .locator("#name")
+        .evaluate("el => el.value", None::<&()>)
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(value, "Ada");
+
+    // Typed argument and return value
+    let summary: Summary = page
+        .locator("h1")
+        .evaluate(
+            "(el, arg) => ({ tagName: el.tagName, id: Number(el.dataset.id), label: el.textContent + arg.text })",
+            Some(&Suffix {
+                text: "!".to_string(),
+            }),
+        )
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(
+        summary,
+        Summary {
+            tag_name: "H1".to_string(),
+            id: 7,
+            label: "Title!".to_string(),
+        }
+    );
+
+    // Strict mode violation
+    let result: Result<String, _> = page
+        .locator("li")
+        .evaluate("el => el.textContent", None::<&()>)
+        .await;
+    match result {
+        Err(playwright_rs::Error::StrictModeViolation {
+            count, candidates, ..
+        }) => {
+            assert_eq!(count, 2);
+            assert_eq!(candidates.len(), 2);
+            assert!(candidates[0].contains("One"));
+        }
+        other => panic!("Expected strict mode violation, got {:?}", other),
+    }
+
+    // evaluate_all receives every match
+    let items: Vec<String> = page
+        .locator("li")
+        .evaluate_all("els => els.map(e => e.textContent)", None::<&()>)
+        .await
+        .expect("Failed to evaluate all");
+    assert_eq!(items, ["One", "Two"]);
+
+    let joined: String = page
+        .locator("li")
+        .evaluate_all(
+            "(els, sep) => els.map(e => e.textContent).join(sep)",
+            Some(&", "),
+        )
+        .await
+        .expect("Failed to evaluate all");
+    assert_eq!(joined, "One, Two");
+
+    let count: usize = page
+        .locator("table")
+        .evaluate_all("els => els.length", None::<&()>)
+        .await
+        .expect("Failed to evaluate all with no matches");
+    assert_eq!(count, 0);
+
+    browser.close().await.expect("Failed to close browser");
+}
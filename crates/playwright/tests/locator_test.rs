@@ -3,10 +3,14 @@
 // Following TDD: Write tests first (Red), then implement (Green)
 //
 // Tests cover:
-// - Locator creation (page.locator)
-// - Locator chaining (first, last, nth, locator)
-// - Query methods (count, text_content, inner_text, inner_html, get_attribute)
+// - Locator creation (page.locator), reused across navigations
+// - Locator chaining (first, last, nth, all, locator)
+// - Multi-level locator() scoping with selector engines
+// - Query methods (count, text_content, inner_text, all_text_contents,
+//   all_inner_texts, inner_html, get_attribute)
 // - State queries (is_visible, is_enabled, is_checked, is_editable)
+// - filter() with has_text, has_text_regex, has_not_text, has, has_not
+// - and_() / or_() combinators
 //
 // Performance Optimization (Phase 6):
 // - Combined related tests to minimize browser launches
@@ -15,7 +19,8 @@
 
 mod test_server;
 
-use playwright_rs::protocol::Playwright;
+use playwright_rs::protocol::{FilterOptions, Locator, Playwright};
+use std::time::Duration;
 use test_server::TestServer;
 
 mod common;
@@ -43,11 +48,11 @@ async fn test_locator_query_methods() {
         .expect("Failed to navigate");
 
     // Test 1: Create a locator
-    let heading = page.locator("h1").await;
+    let heading = page.locator("h1");
     assert_eq!(heading.selector(), "h1");
 
     // Test 2: Count elements
-    let paragraphs = page.locator("p").await;
+    let paragraphs = page.locator("p");
     let count = paragraphs.count().await.expect("Failed to get count");
     assert_eq!(count, 3); // locator.html has exactly 3 paragraphs
 
@@ -65,6 +70,29 @@ async fn test_locator_query_methods() {
         .expect("Failed to get inner text");
     assert_eq!(inner, "Test Page");
 
+    // Test 5: Bulk text readers
+    let expected = ["First paragraph", "Second paragraph", "Third paragraph"];
+    assert_eq!(
+        paragraphs
+            .all_text_contents()
+            .await
+            .expect("Failed to get text contents"),
+        expected
+    );
+    assert_eq!(
+        paragraphs
+            .all_inner_texts()
+            .await
+            .expect("Failed to get inner texts"),
+        expected
+    );
+    let missing = page.locator(".does-not-exist");
+    assert!(missing
+        .all_inner_texts()
+        .await
+        .expect("Failed to get inner texts")
+        .is_empty());
+
     browser.close().await.expect("Failed to close browser");
     server.shutdown();
 }
@@ -91,7 +119,7 @@ async fn test_locator_chaining_methods() {
         .await
         .expect("Failed to navigate");
 
-    let paragraphs = page.locator("p").await;
+    let paragraphs = page.locator("p");
 
     // Test 1: Get first paragraph
     let first = paragraphs.first();
@@ -120,8 +148,26 @@ async fn test_locator_chaining_methods() {
         .expect("Failed to get text content");
     assert_eq!(text, Some("Second paragraph".to_string()));
 
-    // Test 4: Nested locators
-    let container = page.locator(".container").await;
+    // Test 4: One locator per match
+    let all = paragraphs.all().await.expect("Failed to list paragraphs");
+    assert_eq!(all.len(), 3);
+    assert_eq!(all[2].selector(), "p >> nth=2");
+    let mut texts = Vec::new();
+    for paragraph in &all {
+        texts.push(
+            paragraph
+                .text_content()
+                .await
+                .expect("Failed to get text content"),
+        );
+    }
+    assert_eq!(
+        texts,
+        ["First paragraph", "Second paragraph", "Third paragraph"].map(|t| Some(t.to_string()))
+    );
+
+    // Test 5: Nested locators
+    let container = page.locator(".container");
     let nested = container.locator("#nested");
     assert_eq!(nested.selector(), ".container >> #nested");
     let text = nested
@@ -134,6 +180,58 @@ async fn test_locator_chaining_methods() {
     server.shutdown();
 }
 
+#[tokio::test]
+async fn test_locator_nested_scoping() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html,<button>Help</button>\
+         <div class='card'><h2>Lamp</h2><div class='actions'><button>Buy lamp</button></div></div>\
+         <div class='card'><h2>Desk</h2><div class='actions'><button>Buy desk</button>\
+         <button>Save desk</button></div></div>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let desk = page.locator(".card").nth(1);
+    let actions = desk.locator("css=.actions");
+    let buttons = actions.locator("button");
+    assert_eq!(
+        buttons.selector(),
+        ".card >> nth=1 >> css=.actions >> button"
+    );
+    assert_eq!(
+        buttons
+            .all_inner_texts()
+            .await
+            .expect("Failed to read buttons"),
+        ["Buy desk", "Save desk"]
+    );
+
+    // Other selector engines chain the same way
+    let heading = desk.locator("xpath=.//h2");
+    assert_eq!(
+        heading.inner_text().await.expect("Failed to read heading"),
+        "Desk"
+    );
+    let save = desk.locator(".actions").locator("text=Save");
+    assert_eq!(
+        save.inner_text().await.expect("Failed to read button"),
+        "Save desk"
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
+
 // ============================================================================
 // Locator State Methods
 // ============================================================================
@@ -157,7 +255,7 @@ async fn test_locator_state_methods() {
         .expect("Failed to navigate");
 
     // Test 1: Check visibility for visible element
-    let heading = page.locator("h1").await;
+    let heading = page.locator("h1");
     let visible = heading
         .is_visible()
         .await
@@ -165,7 +263,7 @@ async fn test_locator_state_methods() {
     assert!(visible);
 
     // Test 2: Hidden element should not be visible
-    let hidden = page.locator("#hidden").await;
+    let hidden = page.locator("#hidden");
     let hidden_visible = hidden
         .is_visible()
         .await
@@ -205,7 +303,7 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to navigate");
 
-    let firefox_heading = firefox_page.locator("h1").await;
+    let firefox_heading = firefox_page.locator("h1");
     let text = firefox_heading
         .text_content()
         .await
@@ -227,7 +325,7 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to navigate");
 
-    let webkit_heading = webkit_page.locator("h1").await;
+    let webkit_heading = webkit_page.locator("h1");
     let visible = webkit_heading
         .is_visible()
         .await
@@ -237,3 +335,200 @@ async fn test_cross_browser_smoke() {
     webkit.close().await.expect("Failed to close WebKit");
     server.shutdown();
 }
+
+#[tokio::test]
+async fn test_locator_first_matching() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    // Variant B appears after a delay; variant A never does
+    page.goto(
+        "data:text/html,<div id='root'></div><script>setTimeout(() => \
+         document.getElementById('root').innerHTML = '<button id=\"variant-b\">Buy</button>', 200)</script>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let variants = [page.locator("#variant-a"), page.locator("#variant-b")];
+    let (index, matched) = Locator::first_matching(&variants, None)
+        .await
+        .expect("A variant should appear");
+    assert_eq!(index, 1);
+    assert_eq!(matched.selector(), "#variant-b");
+
+    let err = Locator::first_matching(&variants[..1], Some(Duration::from_millis(300)))
+        .await
+        .expect_err("Missing variant should time out");
+    assert!(matches!(err, playwright_rs::Error::Timeout(_)));
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[tokio::test]
+async fn test_locator_filter() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html,<ul>\
+         <li><h3>Product 1</h3><button>Add to cart</button></li>\
+         <li><h3>Product 2</h3><button>Add to cart</button></li>\
+         <li><h3>Product 3</h3><span>Sold out</span></li>\
+         </ul>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let items = page.locator("li");
+    let count = |locator: Locator| async move { locator.count().await.expect("Failed to count") };
+
+    let row = items.filter(FilterOptions::builder().has_text("product 2").build());
+    assert_eq!(count(row.clone()).await, 1);
+    assert_eq!(
+        row.locator("h3")
+            .text_content()
+            .await
+            .expect("Failed to read text")
+            .as_deref(),
+        Some("Product 2")
+    );
+
+    #[cfg(feature = "regex")]
+    {
+        let regex = regex::Regex::new(r"Product [13]").unwrap();
+        assert_eq!(
+            count(items.filter(FilterOptions::builder().has_text_regex(&regex).build())).await,
+            2
+        );
+    }
+    assert_eq!(
+        count(items.filter(FilterOptions::builder().has_not_text("Sold out").build())).await,
+        2
+    );
+
+    let button = page.locator("button");
+    assert_eq!(
+        count(items.filter(FilterOptions::builder().has(button.clone()).build())).await,
+        2
+    );
+    let sold_out = items.filter(FilterOptions::builder().has_not(button).build());
+    assert_eq!(
+        sold_out
+            .locator("h3")
+            .text_content()
+            .await
+            .expect("Failed to read text")
+            .as_deref(),
+        Some("Product 3")
+    );
+
+    // Chained filters narrow further
+    let none = items
+        .filter(FilterOptions::builder().has_text("Product 1").build())
+        .filter(FilterOptions::builder().has_text("Sold out").build());
+    assert_eq!(count(none).await, 0);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[tokio::test]
+async fn test_locator_and_or() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html,<button data-testid='subscribe'>Subscribe</button>\
+         <button>Cancel</button><a data-testid='subscribe-link'>Subscribe</a>\
+         <div role='dialog'>Confirm security settings</div>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let count = |locator: Locator| async move { locator.count().await.expect("Failed to count") };
+    let buttons = page.get_by_role("button", None);
+    let subscribe = page.get_by_text("Subscribe", false);
+
+    let both = buttons.and_(&subscribe);
+    assert_eq!(count(both.clone()).await, 1);
+    assert_eq!(
+        both.get_attribute("data-testid")
+            .await
+            .expect("Failed to read attribute")
+            .as_deref(),
+        Some("subscribe")
+    );
+
+    assert_eq!(count(buttons.or_(&subscribe)).await, 3);
+    let missing = page.locator("#new-email");
+    let dialog = page.get_by_role("dialog", None);
+    assert_eq!(
+        missing
+            .or_(&dialog)
+            .inner_text()
+            .await
+            .expect("Failed to read text"),
+        "Confirm security settings"
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[tokio::test]
+async fn test_locator_reused_across_navigations() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    // Created before the page has content; resolved only when used
+    let heading = page.locator("h1");
+    let submit = page.get_by_role("button", None);
+
+    page.goto("data:text/html,<h1>First</h1><button>Go</button>", None)
+        .await
+        .expect("Failed to navigate");
+    assert_eq!(heading.inner_text().await.expect("Failed to read"), "First");
+    assert_eq!(submit.count().await.expect("Failed to count"), 1);
+
+    page.goto("data:text/html,<h1>Second</h1>", None)
+        .await
+        .expect("Failed to navigate");
+    assert_eq!(
+        heading.inner_text().await.expect("Failed to read"),
+        "Second"
+    );
+    assert_eq!(submit.count().await.expect("Failed to count"), 0);
+
+    browser.close().await.expect("Failed to close browser");
+}
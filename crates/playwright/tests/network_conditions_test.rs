@@ -0,0 +1,86 @@
+// Integration tests for network condition emulation (Chromium only)
+//
+// Tests cover:
+// - page.emulate_network_conditions() with the offline preset
+// - Resetting with None restores connectivity
+// - Context-wide throttling via BrowserContextOptions::network_conditions()
+
+mod test_server;
+
+use playwright_rs::protocol::{BrowserContextOptions, NetworkConditions, Playwright};
+use std::time::{Duration, Instant};
+use test_server::TestServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_page_emulate_network_conditions_offline() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.emulate_network_conditions(Some(NetworkConditions::offline()))
+        .await
+        .expect("Failed to emulate offline");
+    let result = page
+        .goto(&format!("{}/locators.html", server.url()), None)
+        .await;
+    assert!(result.is_err(), "Navigation should fail while offline");
+
+    page.emulate_network_conditions(None)
+        .await
+        .expect("Failed to reset network conditions");
+    page.goto(&format!("{}/locators.html", server.url()), None)
+        .await
+        .expect("Navigation should succeed after reset");
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_context_network_conditions() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+
+    let latency = 500.0;
+    let options = BrowserContextOptions::builder()
+        .network_conditions(NetworkConditions {
+            latency,
+            ..NetworkConditions::no_throttling()
+        })
+        .build();
+    let context = browser
+        .new_context_with_options(options)
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+
+    let start = Instant::now();
+    page.goto(&format!("{}/locators.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+    assert!(
+        start.elapsed() >= Duration::from_millis(latency as u64),
+        "Navigation should be delayed by the emulated latency"
+    );
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
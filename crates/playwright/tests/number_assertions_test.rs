@@ -0,0 +1,92 @@
+// Integration tests for numeric text assertions
+//
+// Tests cover:
+// - expect().to_have_number() with currency symbols and locale separators
+// - Tolerance bounds and polling until the number changes
+// - not().to_have_number()
+// - Failure messages for wrong numbers and text without a number
+
+use playwright_rs::protocol::Playwright;
+use playwright_rs::{expect, Error};
+use std::time::Duration;
+
+mod common;
+
+#[tokio::test]
+async fn test_to_have_number() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html;charset=utf-8,<span id='usd'>$1,234.50</span>\
+        <span id='eur'>1.234,50 %E2%82%AC</span>\
+        <span id='cpu'>42.7%</span>\
+        <span id='status'>Loading...</span>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    expect(page.locator("#usd"))
+        .to_have_number(1234.5, 0.0)
+        .await
+        .expect("US-formatted price should parse");
+    expect(page.locator("#eur"))
+        .to_have_number(1234.5, 0.001)
+        .await
+        .expect("German-formatted price should parse");
+
+    let cpu = page.locator("#cpu");
+    expect(&cpu)
+        .to_have_number(43.0, 0.5)
+        .await
+        .expect("CPU should be within tolerance");
+    expect(&cpu)
+        .not()
+        .to_have_number(43.0, 0.1)
+        .await
+        .expect("CPU should be outside a tighter tolerance");
+
+    let result = expect(&cpu)
+        .with_timeout(Duration::from_millis(300))
+        .to_have_number(50.0, 1.0)
+        .await;
+    match result {
+        Err(Error::AssertionTimeout(message)) => {
+            assert!(message.contains("but had 42.7 ('42.7%')"), "{}", message)
+        }
+        other => panic!("Expected AssertionTimeout, got {:?}", other),
+    }
+
+    // Polls until the text holds a number
+    let status = page.locator("#status");
+    let result = expect(&status)
+        .with_timeout(Duration::from_millis(300))
+        .to_have_number(3.0, 0.0)
+        .await;
+    match result {
+        Err(Error::AssertionTimeout(message)) => {
+            assert!(message.contains("had no number"), "{}", message)
+        }
+        other => panic!("Expected AssertionTimeout, got {:?}", other),
+    }
+    page.evaluate::<(), ()>(
+        "setTimeout(() => { document.getElementById('status').textContent = '3 results' }, 200)",
+        None,
+    )
+    .await
+    .expect("Failed to schedule change");
+    expect(&status)
+        .to_have_number(3.0, 0.0)
+        .await
+        .expect("Status should show three results");
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,67 @@
+// Integration tests for Locator::iter_pages
+//
+// Tests cover:
+// - Walking every page of a client-side paginated list
+// - Stopping when the next button is disabled
+// - max_pages() limiting the walk
+
+use playwright_rs::protocol::Playwright;
+
+mod common;
+
+// Three pages of two items; "Next" is disabled on the last page
+const PAGE: &str = "data:text/html,\
+    <ul id='results'></ul><button id='next'>Next</button>\
+    <script>\
+    const items = ['a','b','c','d','e','f']; let page = 0;\
+    function render() {\
+      document.getElementById('results').innerHTML =\
+        items.slice(page * 2, page * 2 + 2).map(i => '<li>' + i + '</li>').join('');\
+      document.getElementById('next').disabled = page == 2;\
+    }\
+    document.getElementById('next').onclick = () => { page++; setTimeout(render, 50); };\
+    render();\
+    </script>";
+
+#[tokio::test]
+async fn test_iter_pages() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(PAGE, None).await.expect("Failed to navigate");
+
+    // Walks all three pages, then stops at the disabled button
+    let mut pages = page.locator("#results").iter_pages(page.locator("#next"));
+    let mut items = Vec::new();
+    while let Some(list) = pages.next().await.expect("Failed to turn page") {
+        items.push(
+            list.locator("li")
+                .all_text_contents()
+                .await
+                .expect("Failed to read items"),
+        );
+    }
+    assert_eq!(items, vec![vec!["a", "b"], vec!["c", "d"], vec!["e", "f"]]);
+    assert_eq!(pages.pages_seen(), 3);
+
+    // max_pages stops early
+    page.goto(PAGE, None).await.expect("Failed to navigate");
+    let mut pages = page
+        .locator("#results")
+        .iter_pages(page.locator("#next"))
+        .max_pages(2);
+    let mut count = 0;
+    while pages.next().await.expect("Failed to turn page").is_some() {
+        count += 1;
+    }
+    assert_eq!(count, 2);
+
+    browser.close().await.expect("Failed to close browser");
+}
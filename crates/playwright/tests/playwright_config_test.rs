@@ -0,0 +1,64 @@
+// Integration tests for PlaywrightConfig
+//
+// Tests cover:
+// - Playwright::launch_with_config() passes env vars to the driver process
+// - Driver log capacity is honored
+
+use playwright_rs::{Playwright, PlaywrightConfig};
+
+mod common;
+
+#[tokio::test]
+async fn test_launch_with_config_debug_channels() {
+    common::init_tracing();
+    let config = PlaywrightConfig::new()
+        .debug("pw:api")
+        .driver_log_capacity(10_000);
+    let playwright = Playwright::launch_with_config(config)
+        .await
+        .expect("Failed to launch Playwright");
+
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto("data:text/html,<h1>hi</h1>", None)
+        .await
+        .expect("Failed to navigate");
+    browser.close().await.expect("Failed to close browser");
+
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    // pw:api logs each API call, e.g. "pw:api navigating to ..."
+    let logs = playwright.driver_logs();
+    assert!(
+        logs.iter().any(|line| line.contains("pw:api")),
+        "Expected pw:api output in driver logs, got: {:?}",
+        logs
+    );
+
+    playwright.shutdown().await.expect("Failed to shutdown");
+}
+
+#[tokio::test]
+async fn test_driver_log_capacity() {
+    common::init_tracing();
+    let config = PlaywrightConfig::new().debug("pw:*").driver_log_capacity(5);
+    let playwright = Playwright::launch_with_config(config)
+        .await
+        .expect("Failed to launch Playwright");
+
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    browser.close().await.expect("Failed to close browser");
+
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    assert!(playwright.driver_logs().len() <= 5);
+
+    playwright.shutdown().await.expect("Failed to shutdown");
+}
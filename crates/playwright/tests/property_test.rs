@@ -0,0 +1,96 @@
+// Integration tests for harness::property (requires the `proptest` feature)
+//
+// Tests cover:
+// - tricky_text() inputs survive fill() / input_value() unchanged in a textarea
+// - Random interaction sequences run against a form without errors
+//
+// Run with: cargo test --features proptest --test property_test
+
+#![cfg(feature = "proptest")]
+
+use playwright_rs::harness::property::{
+    check, interactions, reproduction, tricky_text, Action, Config,
+};
+use playwright_rs::protocol::Playwright;
+
+mod common;
+
+const FORM: &str = "data:text/html,<form onsubmit='return false'>\
+    <input id='name'><textarea id='bio'></textarea><button id='save'>Save</button></form>";
+
+#[tokio::test]
+async fn test_fill_round_trips_tricky_text() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(FORM, None).await.expect("Failed to navigate");
+
+    let bio = page.locator("#bio");
+    let result = check(Config::with_cases(24), tricky_text(), |text| {
+        let bio = bio.clone();
+        async move {
+            bio.fill(&text, None).await?;
+            let value = bio.input_value(None).await?;
+            if value != text {
+                return Err(format!("textarea holds {:?}", value).into());
+            }
+            Ok(())
+        }
+    })
+    .await;
+    if let Err(failure) = result {
+        panic!("{}", failure);
+    }
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+#[tokio::test]
+async fn test_random_interactions_on_form() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    let selectors = vec!["#name".to_string(), "#bio".to_string(), "#save".to_string()];
+    let result = check(
+        Config::with_cases(8),
+        interactions(selectors, 1..6),
+        |steps| {
+            let page = page.clone();
+            async move {
+                page.goto(FORM, None).await?;
+                for step in steps.iter().filter(|s| {
+                    // fill()/clear() on a button is an error by design
+                    s.selector != "#save" || matches!(s.action, Action::Click | Action::Press(_))
+                }) {
+                    step.apply(&page).await?;
+                }
+                Ok(())
+            }
+        },
+    )
+    .await;
+    if let Err(failure) = result {
+        panic!(
+            "{}\nReproduction:\n{}",
+            failure,
+            reproduction(&failure.minimal)
+        );
+    }
+
+    browser.close().await.expect("Failed to close browser");
+}
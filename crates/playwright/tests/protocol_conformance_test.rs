@@ -0,0 +1,51 @@
+// Integration tests for protocol conformance replay
+//
+// Replays captured driver traffic through a real Connection; no driver or
+// browser is launched.
+//
+// Tests cover:
+// - Navigation session: launch, context, page, goto, evaluate, dialogs, close
+// - Every object the driver created is registered with its protocol type
+// - Types the crate does not model are reported instead of dropped
+
+use playwright_rs::server::conformance::{replay, ProtocolFixture};
+
+mod common;
+
+const NAVIGATION: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/protocol/navigation.log"
+);
+
+#[tokio::test]
+async fn test_navigation_fixture_conforms() {
+    common::init_tracing();
+    let fixture = ProtocolFixture::load(NAVIGATION)
+        .await
+        .expect("Failed to load fixture");
+    let report = replay(&fixture).await.expect("Failed to replay fixture");
+
+    assert!(report.is_ok(), "Replay failures: {:#?}", report.failures);
+    assert_eq!(report.calls, 13);
+
+    // Closing the browser disposes everything created under it
+    assert_eq!(report.objects.get("Playwright"), Some(&1));
+    assert_eq!(report.objects.get("BrowserType"), Some(&3));
+    for type_name in [
+        "Browser",
+        "BrowserContext",
+        "Page",
+        "Request",
+        "ElementHandle",
+    ] {
+        assert!(
+            !report.objects.contains_key(type_name),
+            "{} outlived the browser: {:?}",
+            type_name,
+            report.objects
+        );
+    }
+
+    assert!(report.unsupported.contains("Android"));
+    assert!(report.unsupported.contains("Electron"));
+}
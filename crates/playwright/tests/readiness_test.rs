@@ -0,0 +1,72 @@
+// Integration tests for named readiness conditions
+//
+// Tests cover:
+// - goto() awaits conditions requested via GotoOptions::wait_for_ready
+// - page.wait_for_ready() with function and async predicates
+// - Unmet conditions time out and are named in the error
+
+use playwright_rs::protocol::{GotoOptions, Playwright};
+use std::time::Duration;
+
+mod common;
+
+#[tokio::test]
+async fn test_readiness_conditions() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+
+    context.add_readiness_condition("hydrated", "() => window.__hydrated === true");
+    context.add_readiness_condition(
+        "data",
+        "async () => document.querySelectorAll('li').length === 3",
+    );
+    context.add_readiness_condition("never", "false");
+    assert_eq!(
+        context.readiness_conditions(),
+        vec!["hydrated", "data", "never"]
+    );
+
+    let page = context.new_page().await.expect("Failed to create page");
+    page.goto(
+        "data:text/html,<ul></ul><script>setTimeout(() => { \
+         document.querySelector('ul').innerHTML = '<li>a</li><li>b</li><li>c</li>'; \
+         window.__hydrated = true; }, 300)</script>",
+        Some(GotoOptions::new().wait_for_ready(["hydrated"])),
+    )
+    .await
+    .expect("Failed to navigate");
+    assert_eq!(
+        page.evaluate_value("window.__hydrated").await.unwrap(),
+        "true"
+    );
+
+    page.wait_for_ready(&["hydrated", "data"], None)
+        .await
+        .expect("Conditions should be met");
+
+    let err = page
+        .wait_for_ready::<&str>(&[], Some(Duration::from_millis(300)))
+        .await
+        .expect_err("'never' should not be met");
+    assert!(matches!(err, playwright_rs::Error::Timeout(_)));
+    assert!(err.to_string().contains("never"));
+
+    assert!(context.remove_readiness_condition("never"));
+    page.wait_for_ready::<&str>(&[], None)
+        .await
+        .expect("Remaining conditions should be met");
+
+    context.close().await.expect("Failed to close context");
+    browser.close().await.expect("Failed to close browser");
+}
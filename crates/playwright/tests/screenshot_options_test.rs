@@ -146,7 +146,7 @@ async fn test_screenshot_element_and_locator_with_options() {
     assert_eq!(&bytes[0..2], &[0xFF, 0xD8], "Should be JPEG");
 
     // Test 2: Locator screenshot with options
-    let locator = page.locator("h1").await;
+    let locator = page.locator("h1");
     let options = ScreenshotOptions::builder()
         .screenshot_type(ScreenshotType::Jpeg)
         .quality(85)
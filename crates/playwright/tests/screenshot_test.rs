@@ -144,7 +144,7 @@ async fn test_locator_screenshot() {
         .expect("Failed to navigate");
 
     // Test: Element screenshot via locator
-    let heading = page.locator("h1").await;
+    let heading = page.locator("h1");
     let bytes = heading
         .screenshot(None)
         .await
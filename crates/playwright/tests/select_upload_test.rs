@@ -17,7 +17,7 @@
 mod common;
 mod test_server;
 
-use playwright_rs::protocol::{Playwright, SelectOption};
+use playwright_rs::protocol::{InputFiles, Playwright, SelectOption};
 use std::fs;
 use std::io::Write;
 use test_server::TestServer;
@@ -45,7 +45,7 @@ async fn test_select_option_methods() {
         .expect("Failed to navigate");
 
     // Test 1: Select option by value
-    let select = page.locator("#single-select").await;
+    let select = page.locator("#single-select");
     let selected = select
         .select_option("banana", None)
         .await
@@ -67,7 +67,7 @@ async fn test_select_option_methods() {
     assert_eq!(selected, vec!["cherry"]);
 
     // Test 4: Select option by index when options have no value attribute
-    let select_no_value = page.locator("#select-by-index").await;
+    let select_no_value = page.locator("#select-by-index");
     let selected = select_no_value
         .select_option(SelectOption::Index(1), None)
         .await
@@ -102,7 +102,7 @@ async fn test_select_multiple_options() {
         .expect("Failed to navigate");
 
     // Test 1: Select multiple options with string values
-    let select = page.locator("#multi-select").await;
+    let select = page.locator("#multi-select");
     let selected = select
         .select_option_multiple(&["red", "blue"], None)
         .await
@@ -154,14 +154,14 @@ async fn test_file_upload_methods() {
     file.write_all(b"Test file content")
         .expect("Failed to write to test file");
 
-    let input = page.locator("#single-file").await;
+    let input = page.locator("#single-file");
     input
         .set_input_files(&test_file, None)
         .await
         .expect("Failed to set input file");
 
     // Verify file was uploaded by checking the displayed info
-    let info = page.locator("#file-info").await;
+    let info = page.locator("#file-info");
     let text = info.text_content().await.expect("Failed to get text");
     assert!(text.unwrap().contains("playwright_test_file.txt"));
 
@@ -179,24 +179,28 @@ async fn test_file_upload_methods() {
         .write_all(b"Test file 2 content")
         .expect("Failed to write to test file 2");
 
-    let multi_input = page.locator("#multi-file").await;
+    let multi_input = page.locator("#multi-file");
     multi_input
-        .set_input_files_multiple(&[&test_file1, &test_file2], None)
+        .set_input_files([&test_file1, &test_file2], None)
         .await
         .expect("Failed to set multiple input files");
 
     // Verify files were uploaded
-    let info = page.locator("#file-info").await;
+    let info = page.locator("#file-info");
     let text = info.text_content().await.expect("Failed to get text");
     let text_content = text.unwrap();
     assert!(text_content.contains("playwright_test_file1.txt"));
     assert!(text_content.contains("playwright_test_file2.txt"));
 
-    // Test 3: Clear file input by passing empty array
+    // Test 3: Clear file inputs with InputFiles::none() and an empty list
     input
-        .set_input_files_multiple(&[], None)
+        .set_input_files(InputFiles::none(), None)
         .await
         .expect("Failed to clear input files");
+    multi_input
+        .set_input_files(Vec::<std::path::PathBuf>::new(), None)
+        .await
+        .expect("Failed to clear input files with an empty list");
 
     // Cleanup
     fs::remove_file(test_file).expect("Failed to remove test file");
@@ -235,7 +239,7 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to navigate");
 
-    let firefox_select = firefox_page.locator("#single-select").await;
+    let firefox_select = firefox_page.locator("#single-select");
     let selected = firefox_select
         .select_option("cherry", None)
         .await
@@ -263,7 +267,7 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to navigate");
 
-    let webkit_select = webkit_page.locator("#single-select").await;
+    let webkit_select = webkit_page.locator("#single-select");
     let selected = webkit_select
         .select_option(SelectOption::Index(2), None)
         .await
@@ -282,7 +286,7 @@ async fn test_cross_browser_smoke() {
     file.write_all(b"WebKit test content")
         .expect("Failed to write to test file");
 
-    let webkit_input = webkit_page.locator("#single-file").await;
+    let webkit_input = webkit_page.locator("#single-file");
     webkit_input
         .set_input_files(&test_file, None)
         .await
@@ -0,0 +1,110 @@
+// Integration tests for SessionManager
+//
+// Tests cover:
+// - Login runs once per user and contexts start authenticated
+// - invalidate() and failed validation trigger a new login
+// - Sessions persisted in cache_dir are reused by a new manager
+
+mod test_server;
+
+use playwright_rs::protocol::{Page, Playwright, SessionManager};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use test_server::TestServer;
+
+mod common;
+
+async fn cookie(page: &Page, url: &str) -> String {
+    page.goto(url, None).await.expect("Failed to navigate");
+    page.evaluate_value("document.cookie")
+        .await
+        .expect("Failed to read cookie")
+}
+
+#[tokio::test]
+async fn test_session_manager() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+
+    let url = format!("{}/locators.html", server.url());
+    let logins = Arc::new(AtomicUsize::new(0));
+    let dir = std::env::temp_dir().join(format!("playwright_rs_sessions_{}", std::process::id()));
+
+    let make_manager = || {
+        let url = url.clone();
+        let logins = Arc::clone(&logins);
+        SessionManager::new(browser.clone(), move |page, user| {
+            let url = url.clone();
+            let logins = Arc::clone(&logins);
+            async move {
+                logins.fetch_add(1, Ordering::SeqCst);
+                page.goto(&url, None).await?;
+                page.evaluate_expression(&format!(
+                    "document.cookie = 'user={}; max-age=3600'",
+                    user
+                ))
+                .await
+            }
+        })
+        .cache_dir(&dir)
+    };
+
+    let sessions = make_manager();
+    let context = sessions
+        .context("alice")
+        .await
+        .expect("Failed to get context");
+    let page = context.new_page().await.expect("Failed to create page");
+    assert!(cookie(&page, &url).await.contains("user=alice"));
+    context.close().await.expect("Failed to close context");
+
+    // Cached: no second login
+    let context = sessions
+        .context("alice")
+        .await
+        .expect("Failed to get context");
+    context.close().await.expect("Failed to close context");
+    assert_eq!(logins.load(Ordering::SeqCst), 1);
+
+    // Persisted state is reused by a new manager
+    let restored = make_manager();
+    let context = restored
+        .context("alice")
+        .await
+        .expect("Failed to get context");
+    context.close().await.expect("Failed to close context");
+    assert_eq!(logins.load(Ordering::SeqCst), 1);
+
+    // Invalidation forces a new login
+    restored
+        .invalidate("alice")
+        .await
+        .expect("Failed to invalidate");
+    let context = restored
+        .context("alice")
+        .await
+        .expect("Failed to get context");
+    context.close().await.expect("Failed to close context");
+    assert_eq!(logins.load(Ordering::SeqCst), 2);
+
+    // Failed validation forces a new login
+    let validated = make_manager().validate(|_page, _user| async { Ok(false) });
+    let context = validated
+        .context("alice")
+        .await
+        .expect("Failed to get context");
+    context.close().await.expect("Failed to close context");
+    assert_eq!(logins.load(Ordering::SeqCst), 3);
+
+    std::fs::remove_dir_all(&dir).ok();
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
@@ -0,0 +1,84 @@
+// Integration tests for session recording and replay
+//
+// Tests cover:
+// - Calls are recorded and survive a JSON round trip
+// - Replaying on a fresh instance reproduces the same page state
+// - Replay requires recording to be enabled
+
+use playwright_rs::protocol::{Playwright, RecordedSession, ReplayOptions};
+use playwright_rs::PlaywrightConfig;
+
+mod common;
+
+#[tokio::test]
+async fn test_record_and_replay_session() {
+    common::init_tracing();
+    let config = PlaywrightConfig::new().record_session(true);
+
+    let playwright = Playwright::launch_with_config(config.clone())
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto("data:text/html,<input id='name'>", None)
+        .await
+        .expect("Failed to navigate");
+    page.locator("#name")
+        .fill("replayed", None)
+        .await
+        .expect("Failed to fill");
+    let recorded_value = page
+        .evaluate_value("document.querySelector('#name').value")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(recorded_value, "replayed");
+
+    let session = playwright
+        .recorded_session()
+        .expect("Recording should be enabled");
+    browser.close().await.expect("Failed to close browser");
+    playwright.shutdown().await.expect("Failed to shut down");
+
+    let session = RecordedSession::from_json(&session.to_json().unwrap()).unwrap();
+    assert!(session.call_count() >= 4);
+
+    let replayer = Playwright::launch_with_config(config)
+        .await
+        .expect("Failed to launch Playwright");
+    let report = replayer
+        .replay(&session, ReplayOptions::new())
+        .await
+        .expect("Replay failed");
+    assert_eq!(report.calls, session.call_count());
+
+    // The evaluate call before browser.close() returns the same value again
+    let replayed_value = report
+        .results
+        .iter()
+        .rev()
+        .find_map(|result| result.get("value").and_then(|v| v.get("s")))
+        .and_then(|v| v.as_str());
+    assert_eq!(replayed_value, Some("replayed"));
+
+    replayer.shutdown().await.expect("Failed to shut down");
+}
+
+#[tokio::test]
+async fn test_replay_requires_recording() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    assert!(playwright.recorded_session().is_none());
+
+    let session = RecordedSession::from_json(r#"{"version": 1, "entries": []}"#).unwrap();
+    let err = playwright
+        .replay(&session, ReplayOptions::new())
+        .await
+        .expect_err("Replay without recording should fail");
+    assert!(matches!(err, playwright_rs::Error::InvalidArgument(_)));
+}
@@ -33,7 +33,7 @@ async fn test_set_checked_true_on_checkbox() {
         .expect("Failed to navigate");
 
     // Test: set_checked(true) should check the checkbox
-    let checkbox = page.locator("#checkbox").await;
+    let checkbox = page.locator("#checkbox");
 
     // Verify it starts unchecked
     let initially_checked = checkbox.is_checked().await.expect("Failed to check state");
@@ -75,7 +75,7 @@ async fn test_set_checked_false_on_checkbox() {
         .expect("Failed to navigate");
 
     // Test: set_checked(false) should uncheck the checkbox
-    let checkbox = page.locator("#checkbox").await;
+    let checkbox = page.locator("#checkbox");
 
     // First check it
     checkbox.check(None).await.expect("Failed to check");
@@ -118,7 +118,7 @@ async fn test_set_checked_idempotent() {
         .expect("Failed to navigate");
 
     // Test: set_checked() should be idempotent
-    let checkbox = page.locator("#checkbox").await;
+    let checkbox = page.locator("#checkbox");
 
     // Set to checked twice
     checkbox
@@ -169,8 +169,8 @@ async fn test_set_checked_on_radio_button() {
         .expect("Failed to navigate");
 
     // Test: set_checked() works on radio buttons
-    let radio1 = page.locator("#radio1").await;
-    let radio2 = page.locator("#radio2").await;
+    let radio1 = page.locator("#radio1");
+    let radio2 = page.locator("#radio2");
 
     // Set radio1 to checked
     radio1
@@ -224,11 +224,11 @@ async fn test_set_checked_with_options() {
         .expect("Failed to navigate");
 
     // Test: set_checked() accepts CheckOptions
-    let checkbox = page.locator("#checkbox").await;
+    let checkbox = page.locator("#checkbox");
 
     // Use timeout option (10 seconds in milliseconds)
     let options = playwright_rs::protocol::CheckOptions {
-        timeout: Some(10000.0),
+        timeout: Some(std::time::Duration::from_secs(10)),
         ..Default::default()
     };
 
@@ -265,7 +265,7 @@ async fn test_set_checked_firefox() {
         .expect("Failed to navigate");
 
     // Test set_checked on Firefox
-    let checkbox = page.locator("#checkbox").await;
+    let checkbox = page.locator("#checkbox");
 
     checkbox
         .set_checked(true, None)
@@ -304,7 +304,7 @@ async fn test_set_checked_webkit() {
         .expect("Failed to navigate");
 
     // Test set_checked on WebKit
-    let checkbox = page.locator("#checkbox").await;
+    let checkbox = page.locator("#checkbox");
 
     checkbox
         .set_checked(true, None)
@@ -0,0 +1,141 @@
+// Integration tests for shadow DOM support
+//
+// Tests cover:
+// - Every selector engine pierces open shadow roots (CSS, text, role, label,
+//   placeholder, alt text, title, test id), including nested components
+// - XPath and closed shadow roots are not pierced
+// - locator.shadow_root() access and scoping
+
+mod test_server;
+
+use playwright_rs::protocol::Playwright;
+use playwright_rs::Error;
+use test_server::TestServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_selector_engines_pierce_open_shadow_roots() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.goto(&format!("{}/shadow.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    let piercing = [
+        "user-card button",
+        "h2",
+        "text=Ada Lovelace",
+        "internal:text=\"Follow\"i",
+        "internal:role=button[name=\"Follow\"i]",
+        "internal:label=\"Email\"i",
+        "internal:attr=[placeholder=\"you@example.com\"i]",
+        "internal:attr=[alt=\"Avatar\"i]",
+        "internal:attr=[title=\"Card title\"i]",
+        "internal:testid=[data-testid=\"email\"s]",
+        // Nested component inside a shadow tree
+        ".badge",
+    ];
+    for selector in piercing {
+        let count = page
+            .locator(selector)
+            .count()
+            .await
+            .expect("Failed to count");
+        assert_eq!(
+            count, 1,
+            "'{}' should pierce the open shadow root",
+            selector
+        );
+    }
+
+    let not_piercing = ["xpath=//button", ".secret"];
+    for selector in not_piercing {
+        let count = page
+            .locator(selector)
+            .count()
+            .await
+            .expect("Failed to count");
+        assert_eq!(count, 0, "'{}' should not match", selector);
+    }
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_locator_shadow_root() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.goto(&format!("{}/shadow.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    let root = page
+        .locator("#open-card")
+        .shadow_root()
+        .await
+        .expect("Failed to get shadow root")
+        .expect("user-card should have an open shadow root");
+    assert_eq!(root.mode(), "open");
+    assert!(root
+        .inner_html()
+        .await
+        .expect("Failed to read shadow HTML")
+        .contains("<button>Follow</button>"));
+    assert_eq!(
+        root.locator("button")
+            .inner_text()
+            .await
+            .expect("Failed to read button text"),
+        "Follow"
+    );
+    root.locator("input")
+        .fill("ada@example.com", None)
+        .await
+        .expect("Failed to fill input in shadow root");
+
+    // Closed roots and plain elements have no accessible shadow root
+    let closed = page
+        .locator("#closed-card")
+        .shadow_root()
+        .await
+        .expect("Failed to query closed card");
+    assert!(closed.is_none());
+    let plain = page
+        .locator("body")
+        .shadow_root()
+        .await
+        .expect("Failed to query body");
+    assert!(plain.is_none());
+
+    let err = page
+        .locator("#missing")
+        .shadow_root()
+        .await
+        .expect_err("Missing host should fail");
+    assert!(matches!(err, Error::ElementNotFound(_)));
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
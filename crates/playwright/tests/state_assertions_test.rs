@@ -44,7 +44,7 @@ async fn test_button_state_assertions() {
         .await
         .expect("Failed to navigate");
 
-    let button = page.locator("#btn").await;
+    let button = page.locator("#btn");
     expect(button)
         .to_be_enabled()
         .await
@@ -67,7 +67,7 @@ async fn test_button_state_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let disabled_button = page.locator("#disabled-btn").await;
+    let disabled_button = page.locator("#disabled-btn");
     expect(disabled_button.clone())
         .to_be_disabled()
         .await
@@ -90,7 +90,7 @@ async fn test_button_state_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let delayed_button = page.locator("#delayed-btn").await;
+    let delayed_button = page.locator("#delayed-btn");
     expect(delayed_button)
         .to_be_enabled()
         .await
@@ -142,7 +142,7 @@ async fn test_checkbox_state_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let checked_checkbox = page.locator("#checked-box").await;
+    let checked_checkbox = page.locator("#checked-box");
     expect(checked_checkbox)
         .to_be_checked()
         .await
@@ -161,7 +161,7 @@ async fn test_checkbox_state_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let unchecked_checkbox = page.locator("#unchecked-box").await;
+    let unchecked_checkbox = page.locator("#unchecked-box");
     expect(unchecked_checkbox)
         .to_be_unchecked()
         .await
@@ -184,7 +184,7 @@ async fn test_checkbox_state_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let delayed_checkbox = page.locator("#delayed-checkbox").await;
+    let delayed_checkbox = page.locator("#delayed-checkbox");
     expect(delayed_checkbox)
         .to_be_checked()
         .await
@@ -228,7 +228,7 @@ async fn test_editable_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let editable_input = page.locator("#editable-input").await;
+    let editable_input = page.locator("#editable-input");
     expect(editable_input)
         .to_be_editable()
         .await
@@ -247,7 +247,7 @@ async fn test_editable_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let readonly_input = page.locator("#readonly-input").await;
+    let readonly_input = page.locator("#readonly-input");
     expect(readonly_input)
         .not()
         .to_be_editable()
@@ -293,7 +293,7 @@ async fn test_focus_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let focused_input = page.locator("#focused-input").await;
+    let focused_input = page.locator("#focused-input");
     expect(focused_input)
         .to_be_focused()
         .await
@@ -311,7 +311,7 @@ async fn test_focus_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let unfocused_input = page.locator("#unfocused-input").await;
+    let unfocused_input = page.locator("#unfocused-input");
     expect(unfocused_input)
         .not()
         .to_be_focused()
@@ -334,7 +334,7 @@ async fn test_focus_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let delayed_focused_input = page.locator("#delayed-focused-input").await;
+    let delayed_focused_input = page.locator("#delayed-focused-input");
     expect(delayed_focused_input)
         .to_be_focused()
         .await
@@ -373,7 +373,7 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to navigate");
 
-    let firefox_button = firefox_page.locator("#btn").await;
+    let firefox_button = firefox_page.locator("#btn");
     expect(firefox_button)
         .to_be_enabled()
         .await
@@ -394,7 +394,7 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to navigate");
 
-    let webkit_button = webkit_page.locator("#btn").await;
+    let webkit_button = webkit_page.locator("#btn");
     expect(webkit_button)
         .to_be_enabled()
         .await
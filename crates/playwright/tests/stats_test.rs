@@ -0,0 +1,50 @@
+// Integration tests for Playwright::stats()
+//
+// Tests cover:
+// - Page and context counts track creation and closing
+// - No calls are pending between operations
+// - Driver memory is reported on Linux
+
+use playwright_rs::protocol::Playwright;
+
+mod common;
+
+#[tokio::test]
+async fn test_stats_track_pages_and_contexts() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+
+    let before = playwright.stats().await;
+    assert_eq!(before.browsers(), 1);
+    assert_eq!(before.pages(), 0);
+
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+
+    let during = playwright.stats().await;
+    assert_eq!(during.contexts(), before.contexts() + 1);
+    assert_eq!(during.pages(), 1);
+    assert_eq!(during.pending_calls, 0);
+    if cfg!(target_os = "linux") {
+        assert!(during.driver_memory_bytes.unwrap_or(0) > 0);
+    }
+
+    page.close().await.expect("Failed to close page");
+    context.close().await.expect("Failed to close context");
+
+    let after = playwright.stats().await;
+    assert_eq!(after.pages(), 0);
+    assert_eq!(after.contexts(), before.contexts());
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,101 @@
+// Integration tests for Locator::tap
+//
+// Tests cover:
+// - Tapping fires touchstart/touchend and the synthesized click
+// - Tap options: position, modifiers and trial
+// - Tapping fails in a context without touch support
+
+use playwright_rs::protocol::{
+    BrowserContextOptions, KeyboardModifier, Playwright, Position, TapOptions,
+};
+
+mod common;
+
+const PAGE: &str = "data:text/html,\
+    <button id='menu' style='width:200px;height:100px'>Menu</button>\
+    <script>\
+    const log = [];\
+    const menu = document.getElementById('menu');\
+    for (const type of ['touchstart', 'touchend', 'click']) {\
+      menu.addEventListener(type, e => {\
+        log.push(type);\
+        menu.dataset.log = log.join(',');\
+      });\
+    }\
+    menu.addEventListener('click', e => {\
+      menu.dataset.at = e.offsetX + ',' + e.offsetY;\
+      menu.dataset.alt = e.altKey;\
+    });\
+    </script>";
+
+#[tokio::test]
+async fn test_tap() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let context = browser
+        .new_context_with_options(BrowserContextOptions::builder().has_touch(true).build())
+        .await
+        .expect("Failed to create touch context");
+    let page = context.new_page().await.expect("Failed to create page");
+    page.goto(PAGE, None).await.expect("Failed to navigate");
+    let menu = page.locator("#menu");
+
+    // Trial runs the checks only
+    menu.tap(TapOptions::builder().trial(true).build())
+        .await
+        .expect("Failed to trial tap");
+    assert_eq!(
+        menu.get_attribute("data-log")
+            .await
+            .expect("Failed to read attribute"),
+        None
+    );
+
+    // A real tap fires touch events and the click
+    menu.tap(
+        TapOptions::builder()
+            .position(Position { x: 10.0, y: 20.0 })
+            .modifiers(vec![KeyboardModifier::Alt])
+            .build(),
+    )
+    .await
+    .expect("Failed to tap");
+    assert_eq!(
+        menu.get_attribute("data-log")
+            .await
+            .expect("Failed to read attribute")
+            .as_deref(),
+        Some("touchstart,touchend,click")
+    );
+    assert_eq!(
+        menu.get_attribute("data-at")
+            .await
+            .expect("Failed to read attribute")
+            .as_deref(),
+        Some("10,20")
+    );
+    assert_eq!(
+        menu.get_attribute("data-alt")
+            .await
+            .expect("Failed to read attribute")
+            .as_deref(),
+        Some("true")
+    );
+
+    context.close().await.expect("Failed to close context");
+
+    // Without touch support the tap is rejected
+    let page = browser.new_page().await.expect("Failed to create page");
+    page.goto(PAGE, None).await.expect("Failed to navigate");
+    let result = page.locator("#menu").tap(None).await;
+    assert!(result.is_err(), "Expected tap without hasTouch to fail");
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,71 @@
+// Integration tests for harness::TestApp
+//
+// Tests cover:
+// - Serving an axum Router on an ephemeral port via TestApp::serve()
+// - new_context() sets base_url, so relative goto() URLs hit the app
+// - Closing the context shuts the app down
+
+#![cfg(feature = "harness")]
+
+use axum::{routing::get, Router};
+use playwright_rs::harness::TestApp;
+use playwright_rs::protocol::Playwright;
+use std::time::Duration;
+
+mod common;
+
+#[tokio::test]
+async fn test_app_serves_context_and_stops_on_close() {
+    common::init_tracing();
+    let router = Router::new().route(
+        "/hello",
+        get(|| async { axum::response::Html("<h1>Hello from the app</h1>") }),
+    );
+    let app = TestApp::serve(|listener, shutdown| async move {
+        axum::serve(listener, router)
+            .with_graceful_shutdown(shutdown)
+            .await
+    })
+    .await
+    .expect("Failed to start app");
+    let address = app.url().trim_start_matches("http://").to_string();
+
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let context = app
+        .new_context(&browser)
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+
+    page.goto("/hello", None)
+        .await
+        .expect("Relative URL should resolve against the app");
+    let heading = page
+        .locator("h1")
+        .text_content()
+        .await
+        .expect("Failed to read heading");
+    assert_eq!(heading.as_deref(), Some("Hello from the app"));
+
+    context.close().await.expect("Failed to close context");
+
+    // The listener is dropped once the app has shut down
+    let mut stopped = false;
+    for _ in 0..50 {
+        if tokio::net::TcpStream::connect(&address).await.is_err() {
+            stopped = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(stopped, "App should stop when its context closes");
+
+    browser.close().await.expect("Failed to close browser");
+}
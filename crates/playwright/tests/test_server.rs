@@ -40,7 +40,10 @@ impl TestServer {
             .route("/upload.html", get(upload_page))
             .route("/keyboard_mouse.html", get(keyboard_mouse_page))
             .route("/click_options.html", get(click_options_page))
-            .route("/text.html", get(text_page));
+            .route("/text.html", get(text_page))
+            .route("/shadow.html", get(shadow_page))
+            .route("/wasm.html", get(wasm_page))
+            .route("/playwright/index.html", get(component_harness_page));
 
         // Bind to port 0 to get any available port
         let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
@@ -466,3 +469,113 @@ async fn text_page() -> Response<Body> {
         ))
         .unwrap()
 }
+
+async fn shadow_page() -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header("Content-Type", "text/html")
+        .body(Body::from(
+            r#"<!DOCTYPE html>
+<html>
+<head><title>Shadow DOM Test</title></head>
+<body>
+  <user-card id="open-card"></user-card>
+  <secret-card id="closed-card"></secret-card>
+  <script>
+    customElements.define('user-card', class extends HTMLElement {
+      constructor() {
+        super();
+        const root = this.attachShadow({ mode: 'open' });
+        root.innerHTML = `
+          <h2 title="Card title">Ada Lovelace</h2>
+          <label>Email <input placeholder="you@example.com" data-testid="email"></label>
+          <img alt="Avatar" src="data:,">
+          <button>Follow</button>
+          <inner-badge></inner-badge>`;
+      }
+    });
+    customElements.define('inner-badge', class extends HTMLElement {
+      constructor() {
+        super();
+        this.attachShadow({ mode: 'open' }).innerHTML = '<span class="badge">Pro</span>';
+      }
+    });
+    customElements.define('secret-card', class extends HTMLElement {
+      constructor() {
+        super();
+        this.attachShadow({ mode: 'closed' }).innerHTML = '<span class="secret">Hidden</span>';
+      }
+    });
+  </script>
+</body>
+</html>"#,
+        ))
+        .unwrap()
+}
+
+/// Component testing harness implementing the window.__pwMount contract with
+/// plain DOM "components"
+async fn component_harness_page() -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header("Content-Type", "text/html")
+        .body(Body::from(
+            r#"<!DOCTYPE html>
+<html>
+<head><title>Component Harness</title></head>
+<body>
+  <div id="root"></div>
+  <script>
+    const components = {
+      Counter(root, props) {
+        let count = props.start || 0;
+        root.innerHTML = `<span class="label">${props.label}</span>
+          <output>${count}</output><button>+</button>`;
+        root.querySelector('button').addEventListener('click', () => {
+          count += props.step || 1;
+          root.querySelector('output').textContent = count;
+        });
+      },
+      Greeting(root, props, hooksConfig) {
+        const greeting = (hooksConfig && hooksConfig.greeting) || 'Hello';
+        root.innerHTML = `<p>${greeting}, ${props.name}!</p>`;
+      },
+    };
+    window.__pwMount = async (name, props, root, hooksConfig) => {
+      const component = components[name];
+      if (!component) throw new Error(`Unknown component ${name}`);
+      component(root, props, hooksConfig);
+    };
+    window.__pwUnmount = async (root) => { root.innerHTML = ''; };
+  </script>
+</body>
+</html>"#,
+        ))
+        .unwrap()
+}
+
+async fn wasm_page() -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header("Content-Type", "text/html")
+        .body(Body::from(
+            r#"<!DOCTYPE html>
+<html>
+<head><title>WASM App Test</title></head>
+<body>
+  <div id="app">Loading...</div>
+  <button id="panic" onclick="console.error('panicked at src/lib.rs:7:5:\nboom')">Panic</button>
+  <button id="trap" onclick="setTimeout(() => { throw new WebAssembly.RuntimeError('unreachable'); })">Trap</button>
+  <script>
+    // Instantiate an empty module after a delay, then "hydrate" the DOM
+    setTimeout(async () => {
+      await WebAssembly.instantiate(new Uint8Array([0, 97, 115, 109, 1, 0, 0, 0]));
+      document.getElementById('app').textContent = 'Hydrated';
+      document.body.dataset.hydrated = 'true';
+    }, 300);
+  </script>
+</body>
+</html>"#,
+        ))
+        .unwrap()
+}
@@ -7,6 +7,7 @@
 // - expect().to_contain_text() - substring match
 // - expect().to_have_value() - input value match
 // - Regex pattern support for all
+// - Text normalization: whitespace, ignore_case, normalize_unicode, ignore_soft_hyphens
 // - Auto-retry behavior
 // - Cross-browser compatibility
 //
@@ -15,6 +16,8 @@
 // - Removed redundant cross-browser tests (Rust bindings use same protocol for all browsers)
 // - Expected speedup: ~73% (15 tests → 4 tests)
 
+#![cfg(feature = "regex")]
+
 mod common;
 mod test_server;
 
@@ -44,14 +47,14 @@ async fn test_to_have_text_assertions() {
         .expect("Failed to navigate");
 
     // Test 1: Exact text match
-    let heading = page.locator("h1").await;
+    let heading = page.locator("h1");
     expect(heading.clone())
         .to_have_text("Welcome to Playwright")
         .await
         .expect("Heading should have exact text");
 
     // Test 2: Text with whitespace trimming
-    let paragraph = page.locator("#whitespace").await;
+    let paragraph = page.locator("#whitespace");
     expect(paragraph)
         .to_have_text("Text with whitespace")
         .await
@@ -90,7 +93,7 @@ async fn test_to_have_text_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let div = page.locator("#changing-text").await;
+    let div = page.locator("#changing-text");
     let start = std::time::Instant::now();
 
     expect(div)
@@ -131,7 +134,7 @@ async fn test_to_contain_text_assertions() {
         .expect("Failed to navigate");
 
     // Test 1: Substring match
-    let paragraph = page.locator("#long-text").await;
+    let paragraph = page.locator("#long-text");
     expect(paragraph.clone())
         .to_contain_text("middle of the text")
         .await
@@ -154,6 +157,71 @@ async fn test_to_contain_text_assertions() {
     server.shutdown();
 }
 
+// ============================================================================
+// Text normalization options
+// ============================================================================
+
+#[tokio::test]
+async fn test_text_normalization_options() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    // Decomposed "é" (e + combining acute accent) and a soft hyphen
+    page.goto(
+        "data:text/html;charset=utf-8,<p id='city'>Cafe%CC%81  de   PARIS</p>\
+        <p id='word'>Silben%C2%ADtrennung</p>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    let city = page.locator("#city");
+    expect(&city)
+        .to_have_text("Cafe\u{301} de PARIS")
+        .await
+        .expect("Whitespace should be collapsed");
+    expect(&city)
+        .with_timeout(std::time::Duration::from_millis(300))
+        .not()
+        .to_have_text("Café de PARIS")
+        .await
+        .expect("Different code points should not match by default");
+    expect(&city)
+        .normalize_unicode()
+        .ignore_case()
+        .to_have_text("café de paris")
+        .await
+        .expect("Should match after NFC and lowercasing");
+    expect(&city)
+        .ignore_case()
+        .to_contain_text_regex(r"de paris$")
+        .await
+        .expect("Regex should match case-insensitively");
+
+    let word = page.locator("#word");
+    expect(&word)
+        .with_timeout(std::time::Duration::from_millis(300))
+        .not()
+        .to_have_text("Silbentrennung")
+        .await
+        .expect("Soft hyphens should count by default");
+    expect(&word)
+        .ignore_soft_hyphens()
+        .to_have_text("Silbentrennung")
+        .await
+        .expect("Should match ignoring soft hyphens");
+
+    browser.close().await.expect("Failed to close browser");
+}
+
 // ============================================================================
 // to_have_value() Assertions
 // ============================================================================
@@ -177,14 +245,14 @@ async fn test_to_have_value_assertions() {
         .expect("Failed to navigate");
 
     // Test 1: Input with value should match
-    let input = page.locator("#name-input").await;
+    let input = page.locator("#name-input");
     expect(input.clone())
         .to_have_value("John Doe")
         .await
         .expect("Input should have value");
 
     // Test 2: Empty input should have empty value
-    let empty_input = page.locator("#empty-input").await;
+    let empty_input = page.locator("#empty-input");
     expect(empty_input)
         .to_have_value("")
         .await
@@ -216,7 +284,7 @@ async fn test_to_have_value_assertions() {
     .await
     .expect("Failed to inject script");
 
-    let changing_input = page.locator("#changing-input").await;
+    let changing_input = page.locator("#changing-input");
     expect(changing_input)
         .to_have_value("updated")
         .await
@@ -255,7 +323,7 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to navigate");
 
-    let firefox_heading = firefox_page.locator("h1").await;
+    let firefox_heading = firefox_page.locator("h1");
     expect(firefox_heading)
         .to_have_text("Welcome to Playwright")
         .await
@@ -276,13 +344,13 @@ async fn test_cross_browser_smoke() {
         .await
         .expect("Failed to navigate");
 
-    let webkit_paragraph = webkit_page.locator("#long-text").await;
+    let webkit_paragraph = webkit_page.locator("#long-text");
     expect(webkit_paragraph.clone())
         .to_contain_text("middle of the text")
         .await
         .expect("to_contain_text should work in WebKit");
 
-    let webkit_input = webkit_page.locator("#name-input").await;
+    let webkit_input = webkit_page.locator("#name-input");
     expect(webkit_input)
         .to_have_value("John Doe")
         .await
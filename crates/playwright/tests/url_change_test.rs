@@ -0,0 +1,81 @@
+// Integration tests for same-document (SPA) navigations
+//
+// Tests cover:
+// - expect_url_change() resolving on a fragment change made by a click
+// - on_url_changed() firing for soft navigations only
+// - Page::url() following same-document navigations
+
+use playwright_rs::protocol::{NavigationKind, Playwright};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+mod common;
+
+const PAGE: &str = "data:text/html,\
+    <button id='cart' onclick='location.hash=\"cart\"'>Cart</button>\
+    <button id='home' onclick='location.hash=\"home\"'>Home</button>";
+
+#[tokio::test]
+async fn test_url_change_events() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_by_handler = Arc::clone(&seen);
+    page.on_url_changed(move |navigation| {
+        let seen = Arc::clone(&seen_by_handler);
+        async move {
+            seen.lock().unwrap().push(navigation.url);
+            Ok(())
+        }
+    })
+    .await
+    .expect("Failed to register handler");
+
+    // A full navigation does not count as a URL change
+    page.goto(PAGE, None).await.expect("Failed to navigate");
+
+    let cart = page.locator("#cart");
+    let navigation = page
+        .expect_url_change(|| cart.click(None), Some(Duration::from_secs(5)))
+        .await
+        .expect("Failed to observe URL change");
+    assert_eq!(navigation.kind, NavigationKind::SameDocument);
+    assert!(navigation.url.ends_with("#cart"), "{}", navigation.url);
+    assert!(page.url().ends_with("#cart"), "{}", page.url());
+
+    page.locator("#home")
+        .click(None)
+        .await
+        .expect("Failed to click");
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while seen.lock().unwrap().len() < 2 {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .expect("Handler was not called for both route changes");
+    let seen = seen.lock().unwrap().clone();
+    assert!(
+        seen[0].ends_with("#cart") && seen[1].ends_with("#home"),
+        "{:?}",
+        seen
+    );
+
+    // No route change: times out
+    let err = page
+        .expect_url_change(|| async { Ok(()) }, Some(Duration::from_millis(200)))
+        .await
+        .expect_err("Expected a timeout");
+    assert!(matches!(err, playwright_rs::Error::Timeout(_)));
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,66 @@
+// Integration tests for Page::wait_for_load_state() and Page::wait_for_stable()
+//
+// Tests cover:
+// - Load states reached during goto are observed
+// - wait_for_stable() waits out delayed DOM updates
+// - A page that never settles times out
+
+use playwright_rs::protocol::{Playwright, StabilityOptions, WaitUntil};
+use std::time::Duration;
+
+mod common;
+
+#[tokio::test]
+async fn test_wait_for_load_state_and_stable() {
+    common::init_tracing();
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    // Content keeps changing for ~600ms after load
+    page.goto(
+        "data:text/html,<div id='out'>0</div><script>let n = 0; const t = setInterval(() => { \
+         document.getElementById('out').textContent = ++n; if (n === 6) clearInterval(t); }, 100)</script>",
+        None,
+    )
+    .await
+    .expect("Failed to navigate");
+
+    page.wait_for_load_state(Some(WaitUntil::Load), None)
+        .await
+        .expect("Load state should already be reached");
+
+    page.wait_for_stable(Some(
+        StabilityOptions::new().quiet_period(Duration::from_millis(300)),
+    ))
+    .await
+    .expect("Page should settle");
+    assert_eq!(
+        page.evaluate_value("document.getElementById('out').textContent")
+            .await
+            .unwrap(),
+        "6"
+    );
+
+    // Never settles
+    page.evaluate_expression("setInterval(() => document.body.dataset.tick = Date.now(), 50)")
+        .await
+        .expect("Failed to start ticking");
+    let err = page
+        .wait_for_stable(Some(
+            StabilityOptions::new()
+                .network_idle(false)
+                .timeout(Duration::from_millis(800)),
+        ))
+        .await
+        .expect_err("Ticking page should not settle");
+    assert!(matches!(err, playwright_rs::Error::Timeout(_)));
+
+    browser.close().await.expect("Failed to close browser");
+}
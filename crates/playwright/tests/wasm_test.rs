@@ -0,0 +1,134 @@
+// Integration tests for the Rust/WASM frontend helpers
+//
+// Tests cover:
+// - BrowserContext::enable_wasm() with Hydration::WasmInstantiated and Hydration::Selector
+// - Page::wait_for_hydration() waits for the app to instantiate and render
+// - Page::wasm_panics() captures console_error_panic_hook messages and RuntimeError traps
+// - wasm_panics() without enable_wasm() is an InvalidArgument error
+
+mod test_server;
+
+use playwright_rs::protocol::{Hydration, PanicKind, Playwright};
+use playwright_rs::Error;
+use test_server::TestServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_wait_for_hydration_after_instantiation() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    context
+        .enable_wasm(Hydration::WasmInstantiated)
+        .await
+        .expect("Failed to enable WASM support");
+    let page = context.new_page().await.expect("Failed to create page");
+
+    page.goto(&format!("{}/wasm.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+    page.wait_for_hydration(None)
+        .await
+        .expect("App should hydrate");
+
+    let text = page
+        .locator("#app")
+        .text_content()
+        .await
+        .expect("Failed to read text");
+    assert_eq!(text.as_deref(), Some("Hydrated"));
+    assert!(page
+        .wasm_panics()
+        .await
+        .expect("Failed to read panics")
+        .is_empty());
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_wasm_panics_are_captured() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    context
+        .enable_wasm(Hydration::Selector("body[data-hydrated]".into()))
+        .await
+        .expect("Failed to enable WASM support");
+    let page = context.new_page().await.expect("Failed to create page");
+
+    page.goto(&format!("{}/wasm.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+    page.wait_for_hydration(Some(std::time::Duration::from_secs(5)))
+        .await
+        .expect("App should hydrate");
+
+    page.locator("#panic")
+        .click(None)
+        .await
+        .expect("Failed to click");
+    page.locator("#trap")
+        .click(None)
+        .await
+        .expect("Failed to click");
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let panics = page.wasm_panics().await.expect("Failed to read panics");
+    assert_eq!(panics.len(), 2, "Expected a panic and a trap: {:?}", panics);
+    assert_eq!(panics[0].kind, PanicKind::Panic);
+    assert!(panics[0].message.contains("src/lib.rs:7:5"));
+    assert_eq!(panics[1].kind, PanicKind::Trap);
+    assert!(panics[1].message.contains("unreachable"));
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_wasm_panics_requires_enable_wasm() {
+    common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = Playwright::launch()
+        .await
+        .expect("Failed to launch Playwright");
+    let browser = playwright
+        .chromium()
+        .launch()
+        .await
+        .expect("Failed to launch browser");
+    let page = browser.new_page().await.expect("Failed to create page");
+
+    page.goto(&format!("{}/wasm.html", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+    let err = page.wasm_panics().await.unwrap_err();
+    assert!(matches!(err, Error::InvalidArgument(_)));
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}